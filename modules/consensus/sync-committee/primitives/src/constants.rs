@@ -58,18 +58,21 @@ pub const MAX_EXTRA_DATA_BYTES: usize = 32;
 pub const DEPOSIT_PROOF_LENGTH: usize = 33;
 
 pub const DOMAIN_SYNC_COMMITTEE: DomainType = DomainType::SyncCommittee;
+pub const DOMAIN_BEACON_ATTESTER: DomainType = DomainType::BeaconAttester;
 pub const FINALIZED_ROOT_INDEX: u64 = 52;
 pub const EXECUTION_PAYLOAD_INDEX: u64 = 56;
 pub const NEXT_SYNC_COMMITTEE_INDEX: u64 = 55;
 pub const BLOCK_ROOTS_INDEX: u64 = 37;
 pub const HISTORICAL_ROOTS_INDEX: u64 = 39;
 pub const HISTORICAL_BATCH_BLOCK_ROOTS_INDEX: u64 = 2;
+pub const VALIDATORS_INDEX: u64 = 43;
 
 pub const FINALIZED_ROOT_INDEX_LOG2: u64 = 5;
 pub const EXECUTION_PAYLOAD_INDEX_LOG2: u64 = 5;
 pub const NEXT_SYNC_COMMITTEE_INDEX_LOG2: u64 = 5;
 pub const BLOCK_ROOTS_INDEX_LOG2: u64 = 5;
 pub const HISTORICAL_ROOTS_INDEX_LOG2: u64 = 5;
+pub const VALIDATORS_INDEX_LOG2: u64 = 5;
 pub const ETH1_DATA_VOTES_BOUND_ETH: usize = (EPOCHS_PER_ETH1_VOTING_PERIOD * 32) as usize;
 pub const ETH1_DATA_VOTES_BOUND_GNO: usize = (EPOCHS_PER_ETH1_VOTING_PERIOD * 16) as usize;
 
@@ -1,4 +1,3 @@
-mod middleware;
 #[warn(unused_imports)]
 #[warn(unused_variables)]
 mod responses;
@@ -8,7 +7,6 @@ mod routes;
 mod test;
 
 use crate::{
-	middleware::SwitchProviderMiddleware,
 	responses::{
 		finality_checkpoint_response::FinalityCheckpoint,
 		sync_committee_response::NodeSyncCommittee,
@@ -19,11 +17,10 @@ use anyhow::anyhow;
 use bls_on_arkworks::{point_to_pubkey, types::G1ProjectivePoint};
 use log::trace;
 use primitive_types::H256;
-use reqwest::{Client, Url};
-use reqwest_chain::ChainMiddleware;
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use reqwest::{Client, Response, StatusCode, Url};
+use rpc_utils::{EndpointPool, ErrorKind, PoolConfig};
 use ssz_rs::{Merkleized, Node};
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 use sync_committee_primitives::{
 	consensus_types::{BeaconBlock, BeaconBlockHeader, BeaconState, Checkpoint, Validator},
 	constants::{
@@ -66,7 +63,9 @@ pub type BeaconStateType<const ETH1_DATA_VOTES_BOUND: usize> = BeaconState<
 pub struct SyncCommitteeProver<C: Config, const ETH1_DATA_VOTES_BOUND: usize> {
 	pub primary_url: String,
 	pub providers: Vec<String>,
-	pub client: ClientWithMiddleware,
+	pub client: Client,
+	/// Health records of the beacon nodes
+	pub pool: Arc<EndpointPool>,
 	pub phantom: PhantomData<C>,
 }
 
@@ -77,6 +76,7 @@ impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> Clone
 		Self {
 			primary_url: self.primary_url.clone(),
 			client: self.client.clone(),
+			pool: self.pool.clone(),
 			providers: self.providers.clone(),
 			phantom: PhantomData,
 		}
@@ -85,16 +85,26 @@ impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> Clone
 
 impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> SyncCommitteeProver<C, ETH1_DATA_VOTES_BOUND> {
 	pub fn new(providers: Vec<String>) -> Self {
-		let client = ClientBuilder::new(Client::new())
-			.with(ChainMiddleware::new(SwitchProviderMiddleware::_new(providers.clone())))
-			.build();
+		Self::new_with_config(providers, Default::default())
+	}
+
+	/// Create a prover that spreads requests across the beacon nodes as described by the config.
+	/// The health of the beacon nodes is checked in the background, so this must be called within
+	/// a tokio runtime.
+	pub fn new_with_config(providers: Vec<String>, config: PoolConfig) -> Self {
+		let pool = EndpointPool::new(providers.clone(), config)
+			.expect("There must be atleast one provider");
 
-		SyncCommitteeProver::<C, ETH1_DATA_VOTES_BOUND> {
-			primary_url: providers.get(0).expect("There must be atleast one provider").clone(),
+		let prover = SyncCommitteeProver::<C, ETH1_DATA_VOTES_BOUND> {
+			primary_url: providers[0].clone(),
 			providers,
-			client,
+			client: Client::new(),
+			pool: Arc::new(pool),
 			phantom: PhantomData,
-		}
+		};
+		prover.spawn_health_check();
+
+		prover
 	}
 	#[instrument(level = "trace", target = "sync-committee-prover", skip(self))]
 	pub async fn fetch_finalized_checkpoint(
@@ -102,11 +112,8 @@ impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> SyncCommitteeProver<C, ETH1_
 		state_id: Option<&str>,
 	) -> Result<FinalityCheckpoint, anyhow::Error> {
 		trace!(target: "sync-committee-prover", "Fetching finalized checkpoint {state_id:?}");
-		let full_url = self.generate_route(&finality_checkpoints(state_id.unwrap_or("head")))?;
 		let response = self
-			.client
-			.get(full_url)
-			.send()
+			.get(&finality_checkpoints(state_id.unwrap_or("head")))
 			.await
 			.map_err(|e| anyhow!("Failed to fetch finalized checkpoint due to error {e:?}"))?;
 
@@ -121,11 +128,10 @@ impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> SyncCommitteeProver<C, ETH1_
 	pub async fn fetch_header(&self, block_id: &str) -> Result<BeaconBlockHeader, anyhow::Error> {
 		trace!(target: "sync-committee-prover", "Fetching Header {block_id}");
 		let path = header_route(block_id);
-		let full_url = self.generate_route(&path)?;
-		let response =
-			self.client.get(full_url).send().await.map_err(|e| {
-				anyhow!("Failed to fetch header with id {block_id} due to error {e:?}")
-			})?;
+		let response = self
+			.get(&path)
+			.await
+			.map_err(|e| anyhow!("Failed to fetch header with id {block_id} due to error {e:?}"))?;
 
 		let response_data = response
 			.json::<responses::beacon_block_header_response::Response>()
@@ -162,12 +168,10 @@ impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> SyncCommitteeProver<C, ETH1_
 	> {
 		trace!(target: "sync-committee-prover", "Fetching block {block_id}");
 		let path = block_route(block_id);
-		let full_url = self.generate_route(&path)?;
-
-		let response =
-			self.client.get(full_url).send().await.map_err(|e| {
-				anyhow!("Failed to fetch block with id {block_id} due to error {e:?}")
-			})?;
+		let response = self
+			.get(&path)
+			.await
+			.map_err(|e| anyhow!("Failed to fetch block with id {block_id} due to error {e:?}"))?;
 
 		let response_data = response
 			.json::<responses::beacon_block_response::Response>()
@@ -184,9 +188,7 @@ impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> SyncCommitteeProver<C, ETH1_
 		state_id: &str,
 	) -> Result<NodeSyncCommittee, anyhow::Error> {
 		let path = sync_committee_route(state_id);
-		let full_url = self.generate_route(&path)?;
-
-		let response = self.client.get(full_url).send().await?;
+		let response = self.get(&path).await?;
 
 		let response_data = response.json::<responses::sync_committee_response::Response>().await?;
 
@@ -201,9 +203,7 @@ impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> SyncCommitteeProver<C, ETH1_
 		validator_index: &str,
 	) -> Result<Validator, anyhow::Error> {
 		let path = validator_route(state_id, validator_index);
-		let full_url = self.generate_route(&path)?;
-
-		let response = self.client.get(full_url).send().await?;
+		let response = self.get(&path).await?;
 
 		let response_data = response.json::<responses::validator_response::Response>().await?;
 
@@ -219,9 +219,7 @@ impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> SyncCommitteeProver<C, ETH1_
 	) -> Result<BeaconStateType<ETH1_DATA_VOTES_BOUND>, anyhow::Error> {
 		trace!(target: "sync-committee-prover", "Fetching beacon state {state_id}");
 		let path = beacon_state_route(state_id);
-		let full_url = self.generate_route(&path)?;
-
-		let response = self.client.get(full_url).send().await.map_err(|e| {
+		let response = self.get(&path).await.map_err(|e| {
 			anyhow!("Failed to fetch beacon state with id {state_id} due to error {e:?}")
 		})?;
 
@@ -237,9 +235,59 @@ impl<C: Config, const ETH1_DATA_VOTES_BOUND: usize> SyncCommitteeProver<C, ETH1_
 		Ok(beacon_state)
 	}
 
-	fn generate_route(&self, path: &str) -> Result<Url, anyhow::Error> {
-		let url = Url::parse(&format!("{}{}", self.primary_url.clone(), path))?;
-		Ok(url)
+	/// Send a get request for the path to the healthiest beacon node
+	async fn get(&self, path: &str) -> Result<Response, anyhow::Error> {
+		self.pool
+			.execute(
+				|index| async move {
+					let url = Url::parse(&format!("{}{}", self.providers[index], path))
+						.map_err(|err| (ErrorKind::Fatal, anyhow!(err)))?;
+					let response = self
+						.client
+						.get(url.clone())
+						.send()
+						.await
+						.map_err(|err| (ErrorKind::Transient, anyhow!(err)))?;
+					let kind = match response.status() {
+						StatusCode::OK => return Ok(response),
+						// The node has not seen this block or state
+						StatusCode::NOT_FOUND => ErrorKind::Unavailable,
+						status
+							if status.is_client_error() &&
+								status != StatusCode::TOO_MANY_REQUESTS =>
+							ErrorKind::Fatal,
+						_ => ErrorKind::Transient,
+					};
+					Err((
+						kind,
+						anyhow!("Request to {url} failed with status {}", response.status()),
+					))
+				},
+				|(kind, _)| *kind,
+			)
+			.await
+			.map_err(|(_, err)| err)
+	}
+
+	/// Periodically check the head slot of every beacon node so that nodes lagging behind the
+	/// others are avoided.
+	fn spawn_health_check(&self) {
+		let client = self.client.clone();
+		let providers = self.providers.clone();
+		self.pool.spawn_health_check(move |index| {
+			let url = format!("{}{}", providers[index], header_route("head"));
+			let client = client.clone();
+			async move {
+				let response = client
+					.get(url)
+					.send()
+					.await?
+					.error_for_status()?
+					.json::<responses::beacon_block_header_response::Response>()
+					.await?;
+				Ok(response.data.header.message.slot)
+			}
+		});
 	}
 
 	/// Fetches the latest finality update that can be verified by (state_period..=state_period+1)
@@ -41,7 +41,35 @@ pub fn verify_aggregate_signature(
 	signature: &Signature,
 ) -> anyhow::Result<()> {
 	let subset_aggregate = subtract_points_from_aggregate(aggregate, non_participants)?;
-	let aggregate_key_point: G1AffinePoint = subset_aggregate.into();
+	verify_signature_for_point(subset_aggregate.into(), msg, signature)
+}
+
+/// Verifies an aggregate bls12-381 signature produced by the given set of participants, i.e the
+/// `FastAggregateVerify` variant used for beacon chain attestations.
+pub fn verify_fast_aggregate_signature(
+	participants: &[BlsPublicKey],
+	msg: Vec<u8>,
+	signature: &Signature,
+) -> anyhow::Result<()> {
+	if participants.is_empty() {
+		Err(anyhow!("No participants in aggregate signature"))?
+	}
+
+	let aggregate = participants
+		.iter()
+		.map(|point| pubkey_to_projective(point))
+		.collect::<Result<Vec<_>, _>>()?
+		.into_iter()
+		.fold(G1ProjectivePoint::default(), |acc, point| acc + point);
+
+	verify_signature_for_point(aggregate.into(), msg, signature)
+}
+
+fn verify_signature_for_point(
+	aggregate_key_point: G1AffinePoint,
+	msg: Vec<u8>,
+	signature: &Signature,
+) -> anyhow::Result<()> {
 	let signature = bls::signature_to_point(signature).map_err(|e| anyhow!("{:?}", e))?;
 
 	if !bls::signature_subgroup_check(signature) {
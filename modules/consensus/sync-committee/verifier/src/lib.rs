@@ -19,7 +19,7 @@ use sync_committee_primitives::{
 		FINALIZED_ROOT_INDEX, FINALIZED_ROOT_INDEX_LOG2, NEXT_SYNC_COMMITTEE_INDEX,
 		NEXT_SYNC_COMMITTEE_INDEX_LOG2,
 	},
-	types::{VerifierState, VerifierStateUpdate},
+	types::{ExecutionPayloadProof, VerifierState, VerifierStateUpdate},
 	util::{
 		compute_domain, compute_epoch_at_slot, compute_fork_version, compute_signing_root,
 		compute_sync_committee_period_at_slot, should_have_sync_committee_update,
@@ -31,6 +31,102 @@ pub fn verify_sync_committee_attestation<C: Config>(
 	trusted_state: VerifierState,
 	mut update: VerifierStateUpdate,
 ) -> Result<VerifierState, Error> {
+	if update.attested_header.slot <= trusted_state.finalized_header.slot ||
+		update.finality_proof.epoch <= trusted_state.latest_finalized_epoch
+	{
+		Err(Error::InvalidUpdate("Update is expired".into()))?
+	}
+
+	verify_signed_finalized_header::<C>(&trusted_state, &mut update)?;
+
+	let state_period = trusted_state.state_period;
+	let update_signature_period = compute_sync_committee_period_at_slot::<C>(update.signature_slot);
+
+	// verify the associated execution header of the finalized beacon header.
+	verify_execution_payload_proof::<C>(
+		update.execution_payload,
+		&update.finalized_header.state_root,
+	)?;
+
+	if let Some(mut sync_committee_update) = update.sync_committee_update.clone() {
+		let sync_root = sync_committee_update
+			.next_sync_committee
+			.hash_tree_root()
+			.map_err(|_| Error::MerkleizationError("Failed to hash next sync committee".into()))?;
+
+		let is_merkle_branch_valid = is_valid_merkle_branch(
+			&sync_root,
+			sync_committee_update.next_sync_committee_branch.iter(),
+			NEXT_SYNC_COMMITTEE_INDEX_LOG2 as usize,
+			NEXT_SYNC_COMMITTEE_INDEX as usize,
+			&update.attested_header.state_root,
+		);
+
+		if !is_merkle_branch_valid {
+			Err(Error::InvalidMerkleBranch("Next sync committee branch".into()))?;
+		}
+	}
+
+	let verifier_state = if should_have_sync_committee_update(state_period, update_signature_period)
+	{
+		if let Some(sync_committee_update) = update.sync_committee_update {
+			VerifierState {
+				finalized_header: update.finalized_header,
+				latest_finalized_epoch: update.finality_proof.epoch,
+				current_sync_committee: trusted_state.next_sync_committee,
+				next_sync_committee: sync_committee_update.next_sync_committee,
+				state_period: state_period + 1,
+			}
+		} else {
+			Err(Error::InvalidUpdate("Expected sync committee update to be present".into()))?
+		}
+	} else {
+		VerifierState {
+			finalized_header: update.finalized_header,
+			latest_finalized_epoch: update.finality_proof.epoch,
+			..trusted_state
+		}
+	};
+
+	Ok(verifier_state)
+}
+
+/// Verifies that the sync committee of the trusted state has signed two conflicting finalized
+/// headers for the same slot. Unlike [`verify_sync_committee_attestation`], the updates are not
+/// required to be newer than the trusted state, since one of them may already have been accepted.
+pub fn verify_fraud_proof<C: Config>(
+	trusted_state: &VerifierState,
+	mut update_1: VerifierStateUpdate,
+	mut update_2: VerifierStateUpdate,
+) -> Result<(), Error> {
+	if update_1.finalized_header.slot != update_2.finalized_header.slot {
+		Err(Error::InvalidUpdate("Finalized headers are not for the same slot".into()))?
+	}
+
+	let root_1 = update_1
+		.finalized_header
+		.hash_tree_root()
+		.map_err(|_| Error::MerkleizationError("Error hashing finalized header".into()))?;
+	let root_2 = update_2
+		.finalized_header
+		.hash_tree_root()
+		.map_err(|_| Error::MerkleizationError("Error hashing finalized header".into()))?;
+	if root_1 == root_2 {
+		Err(Error::InvalidUpdate("Finalized headers do not conflict".into()))?
+	}
+
+	verify_signed_finalized_header::<C>(trusted_state, &mut update_1)?;
+	verify_signed_finalized_header::<C>(trusted_state, &mut update_2)?;
+
+	Ok(())
+}
+
+/// Verifies that the attested header was signed by a super majority of the current or next sync
+/// committee of the trusted state, and that it commits to the finalized header.
+fn verify_signed_finalized_header<C: Config>(
+	trusted_state: &VerifierState,
+	update: &mut VerifierStateUpdate,
+) -> Result<(), Error> {
 	if update.finality_proof.finality_branch.len() != FINALIZED_ROOT_INDEX_LOG2 as usize &&
 		update.sync_committee_update.is_some() &&
 		update.sync_committee_update.as_ref().unwrap().next_sync_committee_branch.len() !=
@@ -40,7 +136,7 @@ pub fn verify_sync_committee_attestation<C: Config>(
 	}
 
 	// Verify sync committee has super majority participants
-	let sync_committee_bits = update.sync_aggregate.sync_committee_bits;
+	let sync_committee_bits = &update.sync_aggregate.sync_committee_bits;
 	let sync_aggregate_participants: u64 =
 		sync_committee_bits.iter().as_bitslice().count_ones() as u64;
 
@@ -63,24 +159,16 @@ pub fn verify_sync_committee_attestation<C: Config>(
 		Err(Error::InvalidUpdate("State period does not contain signature period".into()))?
 	}
 
-	if update.attested_header.slot <= trusted_state.finalized_header.slot ||
-		update.finality_proof.epoch <= trusted_state.latest_finalized_epoch
-	{
-		Err(Error::InvalidUpdate("Update is expired".into()))?
-	}
-
 	// Verify sync committee aggregate signature
 	let sync_committee = if update_signature_period == state_period {
-		trusted_state.current_sync_committee.clone()
+		&trusted_state.current_sync_committee
 	} else {
-		trusted_state.next_sync_committee.clone()
+		&trusted_state.next_sync_committee
 	};
 
-	let sync_committee_pubkeys = sync_committee.public_keys;
-
 	let non_participant_pubkeys = sync_committee_bits
 		.iter()
-		.zip(sync_committee_pubkeys.iter())
+		.zip(sync_committee.public_keys.iter())
 		.filter_map(|(bit, key)| if !(*bit) { Some(key.clone()) } else { None })
 		.collect::<Vec<_>>();
 
@@ -130,8 +218,15 @@ pub fn verify_sync_committee_attestation<C: Config>(
 		Err(Error::InvalidMerkleBranch("Finality branch".into()))?;
 	}
 
-	// verify the associated execution header of the finalized beacon header.
-	let mut execution_payload = update.execution_payload;
+	Ok(())
+}
+
+/// Verifies that the execution payload proof is committed to by the beacon state with the given
+/// state root.
+pub fn verify_execution_payload_proof<C: Config>(
+	mut execution_payload: ExecutionPayloadProof,
+	beacon_state_root: &Root,
+) -> Result<(), Error> {
 	let execution_payload_root = calculate_multi_merkle_root(
 		&[
 			Node::from_bytes(execution_payload.state_root.as_ref().try_into().expect("Infallible")),
@@ -156,52 +251,12 @@ pub fn verify_sync_committee_attestation<C: Config>(
 		execution_payload.execution_payload_branch.iter(),
 		EXECUTION_PAYLOAD_INDEX_LOG2 as usize,
 		EXECUTION_PAYLOAD_INDEX as usize,
-		&update.finalized_header.state_root,
+		beacon_state_root,
 	);
 
 	if !is_merkle_branch_valid {
 		Err(Error::InvalidMerkleBranch("Execution payload branch".into()))?;
 	}
 
-	if let Some(mut sync_committee_update) = update.sync_committee_update.clone() {
-		let sync_root = sync_committee_update
-			.next_sync_committee
-			.hash_tree_root()
-			.map_err(|_| Error::MerkleizationError("Failed to hash next sync committee".into()))?;
-
-		let is_merkle_branch_valid = is_valid_merkle_branch(
-			&sync_root,
-			sync_committee_update.next_sync_committee_branch.iter(),
-			NEXT_SYNC_COMMITTEE_INDEX_LOG2 as usize,
-			NEXT_SYNC_COMMITTEE_INDEX as usize,
-			&update.attested_header.state_root,
-		);
-
-		if !is_merkle_branch_valid {
-			Err(Error::InvalidMerkleBranch("Next sync committee branch".into()))?;
-		}
-	}
-
-	let verifier_state = if should_have_sync_committee_update(state_period, update_signature_period)
-	{
-		if let Some(sync_committee_update) = update.sync_committee_update {
-			VerifierState {
-				finalized_header: update.finalized_header,
-				latest_finalized_epoch: update.finality_proof.epoch,
-				current_sync_committee: trusted_state.next_sync_committee,
-				next_sync_committee: sync_committee_update.next_sync_committee,
-				state_period: state_period + 1,
-			}
-		} else {
-			Err(Error::InvalidUpdate("Expected sync committee update to be present".into()))?
-		}
-	} else {
-		VerifierState {
-			finalized_header: update.finalized_header,
-			latest_finalized_epoch: update.finality_proof.epoch,
-			..trusted_state
-		}
-	};
-
-	Ok(verifier_state)
+	Ok(())
 }
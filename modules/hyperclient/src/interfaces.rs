@@ -69,9 +69,9 @@ impl TryFrom<JsClientConfig> for ClientConfig {
 					let bytes =
 						from_hex(&val.state_machine).map_err(|err| anyhow!("Hex: {err:?}"))?;
 					StateMachine::from_str(&String::from_utf8(bytes)?)
-						.map_err(|e| anyhow!("{e:?}"))?
+						.map_err(|e| anyhow!("{e}"))?
 				} else {
-					StateMachine::from_str(&val.state_machine).map_err(|e| anyhow!("{e:?}"))?
+					StateMachine::from_str(&val.state_machine).map_err(|e| anyhow!("{e}"))?
 				};
 				let conf = EvmConfig {
 					rpc_url: val.rpc_url.clone(),
@@ -165,16 +165,16 @@ impl TryFrom<JsPost> for PostRequest {
 	fn try_from(value: JsPost) -> Result<Self, Self::Error> {
 		let source = if value.source.starts_with("0x") {
 			let string = String::from_utf8(from_hex(&value.source)?)?;
-			StateMachine::from_str(&string).map_err(|e| anyhow!("{e:?}"))?
+			StateMachine::from_str(&string).map_err(|e| anyhow!("{e}"))?
 		} else {
-			StateMachine::from_str(&value.source).map_err(|e| anyhow!("{e:?}"))?
+			StateMachine::from_str(&value.source).map_err(|e| anyhow!("{e}"))?
 		};
 
 		let dest = if value.dest.starts_with("0x") {
 			let string = String::from_utf8(from_hex(&value.dest)?)?;
-			StateMachine::from_str(&string).map_err(|e| anyhow!("{e:?}"))?
+			StateMachine::from_str(&string).map_err(|e| anyhow!("{e}"))?
 		} else {
-			StateMachine::from_str(&value.dest).map_err(|e| anyhow!("{e:?}"))?
+			StateMachine::from_str(&value.dest).map_err(|e| anyhow!("{e}"))?
 		};
 
 		let post = PostRequest {
@@ -227,16 +227,16 @@ impl TryFrom<JsGet> for GetRequest {
 	fn try_from(value: JsGet) -> Result<Self, Self::Error> {
 		let source = if value.source.starts_with("0x") {
 			let string = String::from_utf8(from_hex(&value.source)?)?;
-			StateMachine::from_str(&string).map_err(|e| anyhow!("{e:?}"))?
+			StateMachine::from_str(&string).map_err(|e| anyhow!("{e}"))?
 		} else {
-			StateMachine::from_str(&value.source).map_err(|e| anyhow!("{e:?}"))?
+			StateMachine::from_str(&value.source).map_err(|e| anyhow!("{e}"))?
 		};
 
 		let dest = if value.dest.starts_with("0x") {
 			let string = String::from_utf8(from_hex(&value.dest)?)?;
-			StateMachine::from_str(&string).map_err(|e| anyhow!("{e:?}"))?
+			StateMachine::from_str(&string).map_err(|e| anyhow!("{e}"))?
 		} else {
-			StateMachine::from_str(&value.dest).map_err(|e| anyhow!("{e:?}"))?
+			StateMachine::from_str(&value.dest).map_err(|e| anyhow!("{e}"))?
 		};
 
 		let keys = value
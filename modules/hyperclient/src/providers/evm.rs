@@ -703,6 +703,8 @@ impl Client for EvmClient {
 						Ok(calldata)
 					},
 					RequestResponse::Request(..) => Err(anyhow!("Get requests cannot be relayed"))?,
+					RequestResponse::Batch(..) =>
+						Err(anyhow!("Request batches cannot be relayed to EVM hosts"))?,
 				}
 			},
 			_ => Err(anyhow!("Unsupported message"))?,
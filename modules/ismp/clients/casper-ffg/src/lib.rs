@@ -14,3 +14,145 @@
 // limitations under the License.
 
 //! ISMP Consensus Client for the Beacon Chain's Casper-FFG Consensus Protocol
+//!
+//! Unlike the sync committee client, this client verifies finality directly from attestation
+//! aggregates of the full validator set: a checkpoint is finalized once a supermajority link (2/3
+//! of the active balance) connects it to a justified checkpoint in the following epoch.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod types;
+pub mod verifier;
+
+#[cfg(test)]
+mod tests;
+
+use alloc::{boxed::Box, collections::BTreeMap, format, string::ToString, vec, vec::Vec};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use evm_common::{construct_intermediate_state, EvmStateMachine};
+use ismp::{
+	consensus::{
+		ConsensusClient, ConsensusClientId, ConsensusStateId, StateMachineClient,
+		VerifiedCommitments,
+	},
+	error::Error,
+	host::{IsmpHost, StateMachine},
+	messaging::StateCommitmentHeight,
+};
+use sync_committee_primitives::constants::Config;
+use types::{CasperFfgUpdate, ConsensusState};
+use verifier::{verify_casper_ffg_update, verify_fraud_proof};
+
+/// Consensus client id for the Casper FFG consensus client
+pub const CASPER_FFG_CONSENSUS_CLIENT_ID: ConsensusClientId = *b"CFFG";
+
+/// Ethereum mainnet chain id
+pub const ETHEREUM_CHAIN_ID: u32 = 1;
+/// Sepolia chain id
+pub const SEPOLIA_CHAIN_ID: u32 = 11155111;
+
+/// The Casper FFG consensus client
+pub struct CasperFfgConsensusClient<H: IsmpHost, C: Config, T: pallet_ismp_host_executive::Config>(
+	PhantomData<(H, C, T)>,
+);
+
+impl<H: IsmpHost, C: Config, T: pallet_ismp_host_executive::Config> Default
+	for CasperFfgConsensusClient<H, C, T>
+{
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<H: IsmpHost, C: Config, T: pallet_ismp_host_executive::Config> Clone
+	for CasperFfgConsensusClient<H, C, T>
+{
+	fn clone(&self) -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<
+		H: IsmpHost + Send + Sync + Default + 'static,
+		C: Config + Send + Sync + Default + 'static,
+		T: pallet_ismp_host_executive::Config + 'static,
+	> ConsensusClient for CasperFfgConsensusClient<H, C, T>
+{
+	fn verify_consensus(
+		&self,
+		_host: &dyn IsmpHost,
+		consensus_state_id: ConsensusStateId,
+		trusted_consensus_state: Vec<u8>,
+		consensus_proof: Vec<u8>,
+	) -> Result<(Vec<u8>, VerifiedCommitments), Error> {
+		let update = CasperFfgUpdate::decode(&mut &consensus_proof[..])
+			.map_err(|_| Error::Custom("Cannot decode casper ffg update".to_string()))?;
+
+		let consensus_state = ConsensusState::decode(&mut &trusted_consensus_state[..])
+			.map_err(|_| Error::Custom("Cannot decode trusted consensus state".to_string()))?;
+
+		let (new_consensus_state, execution_payload) =
+			verify_casper_ffg_update::<C>(consensus_state, update)?;
+
+		let mut state_machine_map: BTreeMap<StateMachine, Vec<StateCommitmentHeight>> =
+			BTreeMap::new();
+
+		if let Some(execution_payload) = execution_payload {
+			let state_machine = StateMachine::Evm(new_consensus_state.chain_id);
+			let intermediate_state = construct_intermediate_state(
+				state_machine,
+				consensus_state_id,
+				execution_payload.block_number,
+				execution_payload.timestamp,
+				&execution_payload.state_root[..],
+			)?;
+
+			state_machine_map.insert(
+				state_machine,
+				vec![StateCommitmentHeight {
+					commitment: intermediate_state.commitment,
+					height: intermediate_state.height.height,
+				}],
+			);
+		}
+
+		Ok((new_consensus_state.encode(), state_machine_map))
+	}
+
+	fn verify_fraud_proof(
+		&self,
+		_host: &dyn IsmpHost,
+		trusted_consensus_state: Vec<u8>,
+		proof_1: Vec<u8>,
+		proof_2: Vec<u8>,
+	) -> Result<(), Error> {
+		let update_1 = CasperFfgUpdate::decode(&mut &proof_1[..]).map_err(|_| {
+			Error::Custom("Cannot decode casper ffg update for proof 1".to_string())
+		})?;
+		let update_2 = CasperFfgUpdate::decode(&mut &proof_2[..]).map_err(|_| {
+			Error::Custom("Cannot decode casper ffg update for proof 2".to_string())
+		})?;
+
+		let consensus_state = ConsensusState::decode(&mut &trusted_consensus_state[..])
+			.map_err(|_| Error::Custom("Cannot decode trusted consensus state".to_string()))?;
+
+		verify_fraud_proof::<C>(&consensus_state, update_1, update_2)
+	}
+
+	fn consensus_client_id(&self) -> ConsensusClientId {
+		CASPER_FFG_CONSENSUS_CLIENT_ID
+	}
+
+	fn state_machine(&self, id: StateMachine) -> Result<Box<dyn StateMachineClient>, Error> {
+		match id {
+			StateMachine::Evm(chain_id)
+				if chain_id == ETHEREUM_CHAIN_ID || chain_id == SEPOLIA_CHAIN_ID =>
+				Ok(Box::new(<EvmStateMachine<H, T>>::default())),
+			state_machine =>
+				Err(Error::Custom(format!("Unsupported state machine: {state_machine:?}"))),
+		}
+	}
+}
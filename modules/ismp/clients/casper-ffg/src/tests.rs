@@ -0,0 +1,548 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	types::{
+		AggregateAttestation, CasperFfgUpdate, ConsensusState, FinalizedHeaderProof,
+		SupermajorityLink, ValidatorSetUpdate, ValidatorsProof,
+	},
+	verifier::{
+		calculate_multi_merkle_root, validator_generalized_index, validators_root,
+		verify_casper_ffg_update, verify_finalized_header, verify_fraud_proof,
+		verify_supermajority_link, verify_validator_set_update, MAX_EFFECTIVE_BALANCE,
+		VALIDATOR_REGISTRY_DEPTH,
+	},
+};
+use bls::DST_ETHEREUM;
+use ssz_rs::{Merkleized, Node};
+use std::collections::{BTreeMap, BTreeSet};
+use sync_committee_primitives::{
+	consensus_types::{AttestationData, BeaconBlockHeader, Checkpoint, Validator},
+	constants::{
+		devnet::Devnet, BlsPublicKey, Config, Root, DOMAIN_BEACON_ATTESTER,
+		EXECUTION_PAYLOAD_INDEX, VALIDATORS_INDEX,
+	},
+	types::ExecutionPayloadProof,
+	util::{compute_domain, compute_fork_version, compute_signing_root},
+};
+
+const VALIDATOR_COUNT: u64 = 8;
+const EFFECTIVE_BALANCE: u64 = 32_000_000_000;
+const FAR_FUTURE_EPOCH: u64 = u64::MAX;
+
+fn secret_key_seed(index: u64) -> Vec<u8> {
+	vec![index as u8 + 1; 32]
+}
+
+fn validator(index: u64) -> Validator {
+	let public_key = bls::sk_to_pk(bls::keygen(&secret_key_seed(index)));
+	Validator {
+		public_key: BlsPublicKey::try_from(public_key.as_slice()).unwrap(),
+		effective_balance: EFFECTIVE_BALANCE,
+		activation_epoch: 0,
+		exit_epoch: FAR_FUTURE_EPOCH,
+		withdrawable_epoch: FAR_FUTURE_EPOCH,
+		..Default::default()
+	}
+}
+
+fn validators() -> Vec<Validator> {
+	(0..VALIDATOR_COUNT).map(validator).collect()
+}
+
+fn checkpoint(epoch: u64) -> Checkpoint {
+	Checkpoint { epoch, root: Node::from_bytes([epoch as u8; 32]) }
+}
+
+fn trusted_state() -> ConsensusState {
+	ConsensusState {
+		justified_checkpoint: checkpoint(10),
+		finalized_checkpoint: checkpoint(9),
+		finalized_header: BeaconBlockHeader::default(),
+		validators_root: validators_root(validators()).unwrap(),
+		total_active_balance: VALIDATOR_COUNT * EFFECTIVE_BALANCE,
+		chain_id: 1,
+	}
+}
+
+fn link(source: Checkpoint, target: Checkpoint) -> AttestationData {
+	AttestationData {
+		slot: target.epoch * Devnet::SLOTS_PER_EPOCH,
+		index: 0,
+		beacon_block_root: target.root,
+		source,
+		target,
+	}
+}
+
+/// Produces an aggregate attestation over `data`, signed by the given signers but claiming the
+/// given participants.
+fn attest_as(participants: &[u64], signers: &[u64], data: AttestationData) -> AggregateAttestation {
+	let domain = compute_domain(
+		DOMAIN_BEACON_ATTESTER,
+		Some(compute_fork_version::<Devnet>(data.target.epoch)),
+		Some(Root::from_bytes(Devnet::GENESIS_VALIDATORS_ROOT)),
+		Devnet::GENESIS_FORK_VERSION,
+	)
+	.unwrap();
+	let signing_root = compute_signing_root(&mut data.clone(), domain).unwrap();
+
+	let signatures = signers
+		.iter()
+		.map(|index| {
+			bls::sign(
+				bls::keygen(&secret_key_seed(*index)),
+				&signing_root.as_bytes().to_vec(),
+				&DST_ETHEREUM.as_bytes().to_vec(),
+			)
+			.unwrap()
+		})
+		.collect::<Vec<_>>();
+	let signature = bls::aggregate(&signatures).unwrap();
+
+	AggregateAttestation {
+		participants: participants.to_vec(),
+		data,
+		signature: signature.as_slice().try_into().unwrap(),
+	}
+}
+
+fn attest(participants: &[u64], data: AttestationData) -> AggregateAttestation {
+	attest_as(participants, participants, data)
+}
+
+fn update(attestations: Vec<AggregateAttestation>) -> CasperFfgUpdate {
+	CasperFfgUpdate {
+		validators: participants_proof(&validators(), &attestations),
+		attestations,
+		finalized: None,
+	}
+}
+
+fn hash_pair(left: &Node, right: &Node) -> Node {
+	let mut preimage = Vec::with_capacity(64);
+	preimage.extend_from_slice(left.as_bytes());
+	preimage.extend_from_slice(right.as_bytes());
+	Node::from_bytes(sp_core::hashing::sha2_256(&preimage))
+}
+
+/// Builds a merkle tree of the given depth where all leaves not in `leaves` are zero, returning
+/// every node by its generalized index.
+fn merkle_tree(depth: u32, leaves: &[(u64, Node)]) -> BTreeMap<u64, Node> {
+	let first_leaf = 1u64 << depth;
+	let mut tree = (first_leaf..first_leaf * 2)
+		.map(|index| (index, Node::default()))
+		.collect::<BTreeMap<_, _>>();
+	tree.extend(leaves.iter().copied());
+	for index in (1..first_leaf).rev() {
+		let node = hash_pair(&tree[&(index * 2)], &tree[&(index * 2 + 1)]);
+		tree.insert(index, node);
+	}
+	tree
+}
+
+/// The siblings on the path from the node at `index` to the root.
+fn branch(tree: &BTreeMap<u64, Node>, mut index: u64) -> Vec<Node> {
+	let mut branch = vec![];
+	while index > 1 {
+		branch.push(tree[&(index ^ 1)]);
+		index /= 2;
+	}
+	branch
+}
+
+/// The helper nodes of a multiproof for the nodes at `indices`, in descending order of their
+/// generalized index.
+fn multi_proof(node: impl Fn(u64) -> Node, indices: &[u64]) -> Vec<Node> {
+	let (mut paths, mut siblings) = (BTreeSet::new(), BTreeSet::new());
+	for mut index in indices.iter().copied() {
+		while index > 1 {
+			paths.insert(index);
+			siblings.insert(index ^ 1);
+			index /= 2;
+		}
+	}
+	siblings.difference(&paths).rev().map(|index| node(*index)).collect()
+}
+
+fn u64_root(mut value: u64) -> Node {
+	value.hash_tree_root().unwrap()
+}
+
+/// The node at the generalized index in the tree of a validator registry, the subtrees without
+/// validators are zero hashes.
+fn registry_node(validators: &[Validator], index: u64) -> Node {
+	if index == 1 {
+		return hash_pair(&registry_node(validators, 2), &u64_root(validators.len() as u64))
+	}
+	if index == 3 {
+		return u64_root(validators.len() as u64)
+	}
+
+	let height = VALIDATOR_REGISTRY_DEPTH - (63 - index.leading_zeros());
+	let first_validator = (index << height) - (1 << VALIDATOR_REGISTRY_DEPTH);
+	if first_validator >= validators.len() as u64 {
+		return (0..height).fold(Node::default(), |node, _| hash_pair(&node, &node))
+	}
+	if height == 0 {
+		return validators[first_validator as usize].clone().hash_tree_root().unwrap()
+	}
+	hash_pair(&registry_node(validators, index * 2), &registry_node(validators, index * 2 + 1))
+}
+
+/// Proves the given validators of the registry.
+fn validators_proof(validators: &[Validator], indices: &[u64]) -> ValidatorsProof {
+	let generalized_indices =
+		indices.iter().copied().map(validator_generalized_index).collect::<Vec<_>>();
+	ValidatorsProof {
+		validators: indices
+			.iter()
+			.map(|index| (*index, validators[*index as usize].clone()))
+			.collect(),
+		multi_proof: multi_proof(|index| registry_node(validators, index), &generalized_indices),
+	}
+}
+
+/// Proves every validator participating in the attestations.
+fn participants_proof(
+	validators: &[Validator],
+	attestations: &[AggregateAttestation],
+) -> ValidatorsProof {
+	let participants = attestations
+		.iter()
+		.flat_map(|attestation| attestation.participants.iter().copied())
+		.filter(|index| *index < validators.len() as u64)
+		.collect::<BTreeSet<_>>();
+	validators_proof(validators, &participants.into_iter().collect::<Vec<_>>())
+}
+
+/// Verifies the attestations with a proof of their participants in the given registry.
+fn verify_link(
+	state: &ConsensusState,
+	validators: &[Validator],
+	attestations: Vec<AggregateAttestation>,
+) -> Result<SupermajorityLink, ismp::error::Error> {
+	let proof = participants_proof(validators, &attestations);
+	verify_supermajority_link::<Devnet>(state, proof, attestations)
+}
+
+/// A finalized header whose beacon state commits to an execution payload and the given validator
+/// registry.
+fn finalized_header_proof(next_validators: Vec<Validator>) -> FinalizedHeaderProof {
+	let mut execution_payload = ExecutionPayloadProof {
+		state_root: [7u8; 32].into(),
+		block_number: 100,
+		timestamp: 1_000,
+		multi_proof: vec![],
+		execution_payload_branch: vec![],
+	};
+	let indices = [
+		Devnet::EXECUTION_PAYLOAD_STATE_ROOT_INDEX,
+		Devnet::EXECUTION_PAYLOAD_BLOCK_NUMBER_INDEX,
+		Devnet::EXECUTION_PAYLOAD_TIMESTAMP_INDEX,
+	];
+	let payload_tree = merkle_tree(
+		5,
+		&[
+			(indices[0], Node::from_bytes(execution_payload.state_root.0)),
+			(indices[1], u64_root(execution_payload.block_number)),
+			(indices[2], u64_root(execution_payload.timestamp)),
+		],
+	);
+	execution_payload.multi_proof = multi_proof(|index| payload_tree[&index], &indices);
+
+	let state_tree = merkle_tree(
+		5,
+		&[
+			(EXECUTION_PAYLOAD_INDEX, payload_tree[&1]),
+			(VALIDATORS_INDEX, validators_root(next_validators.clone()).unwrap()),
+		],
+	);
+	execution_payload.execution_payload_branch = branch(&state_tree, EXECUTION_PAYLOAD_INDEX);
+
+	FinalizedHeaderProof {
+		header: BeaconBlockHeader {
+			slot: 10 * Devnet::SLOTS_PER_EPOCH,
+			proposer_index: 3,
+			state_root: state_tree[&1],
+			..Default::default()
+		},
+		execution_payload,
+		validator_set_update: Some(ValidatorSetUpdate {
+			validators_root: validators_root(next_validators.clone()).unwrap(),
+			validators_branch: branch(&state_tree, VALIDATORS_INDEX),
+			total_active_balance: next_validators.len() as u64 * EFFECTIVE_BALANCE,
+		}),
+	}
+}
+
+/// A link from the checkpoint of the finalized header to the checkpoint of the next epoch.
+fn finalizing_link(proof: &FinalizedHeaderProof) -> SupermajorityLink {
+	let source = Checkpoint { epoch: 10, root: proof.header.clone().hash_tree_root().unwrap() };
+	SupermajorityLink {
+		source,
+		target: checkpoint(11),
+		attesting_balance: 6 * EFFECTIVE_BALANCE,
+		total_active_balance: VALIDATOR_COUNT * EFFECTIVE_BALANCE,
+	}
+}
+
+#[test]
+fn supermajority_link_is_verified() {
+	let state = trusted_state();
+	let attestation = attest(&[0, 1, 2, 3, 4, 5], link(checkpoint(10), checkpoint(11)));
+
+	let link = verify_link(&state, &validators(), vec![attestation]).unwrap();
+
+	assert_eq!(link.attesting_balance, 6 * EFFECTIVE_BALANCE);
+	assert_eq!(link.total_active_balance, VALIDATOR_COUNT * EFFECTIVE_BALANCE);
+	assert!(link.finalizes_source());
+}
+
+#[test]
+fn supermajority_link_across_multiple_aggregates() {
+	let state = trusted_state();
+	let data = link(checkpoint(10), checkpoint(11));
+	let attestations = vec![attest(&[0, 1, 2], data.clone()), attest(&[3, 4, 5, 6], data)];
+
+	let link = verify_link(&state, &validators(), attestations).unwrap();
+
+	assert_eq!(link.attesting_balance, 7 * EFFECTIVE_BALANCE);
+}
+
+#[test]
+fn insufficient_participation_is_rejected() {
+	let state = trusted_state();
+	let attestation = attest(&[0, 1, 2, 3, 4], link(checkpoint(10), checkpoint(11)));
+
+	assert!(verify_link(&state, &validators(), vec![attestation]).is_err());
+}
+
+#[test]
+fn overlapping_aggregates_are_not_double_counted() {
+	let state = trusted_state();
+	let data = link(checkpoint(10), checkpoint(11));
+	let attestations = vec![attest(&[0, 1, 2, 3], data.clone()), attest(&[2, 3, 4], data)];
+
+	assert!(verify_link(&state, &validators(), attestations).is_err());
+}
+
+#[test]
+fn slashed_and_inactive_validators_do_not_count() {
+	let mut state = trusted_state();
+	let mut validators = validators();
+	validators[0].slashed = true;
+	validators[1].exit_epoch = 11;
+	state.validators_root = validators_root(validators.clone()).unwrap();
+	state.total_active_balance = 7 * EFFECTIVE_BALANCE;
+	let attestation = attest(&[0, 1, 2, 3, 4, 5], link(checkpoint(10), checkpoint(11)));
+
+	// validator 1 has exited, so only 4 of the remaining 7 active validators count
+	assert!(verify_link(&state, &validators, vec![attestation]).is_err());
+}
+
+#[test]
+fn participants_must_match_the_trusted_root() {
+	let state = trusted_state();
+	let mut validators = validators();
+	validators[0].effective_balance = 10 * EFFECTIVE_BALANCE;
+	let attestation = attest(&[0, 1], link(checkpoint(10), checkpoint(11)));
+
+	// with an inflated balance, validators 0 and 1 would be a supermajority
+	assert!(verify_link(&state, &validators, vec![attestation]).is_err());
+}
+
+#[test]
+fn participants_must_be_proven() {
+	let state = trusted_state();
+	let attestation = attest(&[0, 1, 2, 3, 4, 5], link(checkpoint(10), checkpoint(11)));
+	let proof = validators_proof(&validators(), &[0, 1, 2, 3, 4]);
+
+	assert!(verify_supermajority_link::<Devnet>(&state, proof, vec![attestation]).is_err());
+}
+
+#[test]
+fn validators_multiproof_is_verified() {
+	let validators = validators();
+	let root = validators_root(validators.clone()).unwrap();
+	let indices = [1, 4, 5].map(validator_generalized_index);
+	let leaves = [1, 4, 5].map(|index| validators[index].clone().hash_tree_root().unwrap());
+	let proof = validators_proof(&validators, &[1, 4, 5]).multi_proof;
+
+	assert_eq!(calculate_multi_merkle_root(&leaves, &proof, &indices).unwrap(), root);
+	assert_ne!(
+		calculate_multi_merkle_root(&leaves, &proof, &[0, 4, 5].map(validator_generalized_index))
+			.unwrap(),
+		root
+	);
+	assert!(calculate_multi_merkle_root(&leaves, &proof[1..], &indices).is_err());
+}
+
+#[test]
+fn unsorted_participants_are_rejected() {
+	let state = trusted_state();
+	let attestation = attest(&[0, 1, 2, 3, 5, 4], link(checkpoint(10), checkpoint(11)));
+
+	assert!(verify_link(&state, &validators(), vec![attestation]).is_err());
+}
+
+#[test]
+fn link_must_originate_from_justified_checkpoint() {
+	let state = trusted_state();
+	let attestation = attest(&[0, 1, 2, 3, 4, 5], link(checkpoint(9), checkpoint(11)));
+
+	assert!(verify_link(&state, &validators(), vec![attestation]).is_err());
+}
+
+#[test]
+fn conflicting_votes_in_aggregate_are_rejected() {
+	let state = trusted_state();
+	let attestations = vec![
+		attest(&[0, 1, 2], link(checkpoint(10), checkpoint(11))),
+		attest(&[3, 4, 5], link(checkpoint(10), checkpoint(12))),
+	];
+
+	assert!(verify_link(&state, &validators(), attestations).is_err());
+}
+
+#[test]
+fn forged_participants_are_rejected() {
+	let state = trusted_state();
+	let attestation =
+		attest_as(&[0, 1, 2, 3, 4, 5], &[0, 1, 2, 3, 4, 7], link(checkpoint(10), checkpoint(11)));
+
+	assert!(verify_link(&state, &validators(), vec![attestation]).is_err());
+}
+
+#[test]
+fn non_consecutive_link_only_justifies_target() {
+	let state = trusted_state();
+	let update = update(vec![attest(&[0, 1, 2, 3, 4, 5], link(checkpoint(10), checkpoint(12)))]);
+
+	let (new_state, execution_payload) =
+		verify_casper_ffg_update::<Devnet>(state.clone(), update).unwrap();
+
+	assert_eq!(new_state.justified_checkpoint, checkpoint(12));
+	assert_eq!(new_state.finalized_checkpoint, state.finalized_checkpoint);
+	assert!(execution_payload.is_none());
+}
+
+#[test]
+fn finalizing_update_requires_finalized_header_proof() {
+	let state = trusted_state();
+	let update = update(vec![attest(&[0, 1, 2, 3, 4, 5], link(checkpoint(10), checkpoint(11)))]);
+
+	assert!(verify_casper_ffg_update::<Devnet>(state, update).is_err());
+}
+
+#[test]
+fn finalizing_update_rotates_the_validator_set() {
+	let next_validators = (0..VALIDATOR_COUNT + 2).map(validator).collect::<Vec<_>>();
+	let proof = finalized_header_proof(next_validators.clone());
+	let source = finalizing_link(&proof).source;
+	let mut state = trusted_state();
+	state.justified_checkpoint = source.clone();
+
+	let update = CasperFfgUpdate {
+		finalized: Some(proof.clone()),
+		..update(vec![attest(&[0, 1, 2, 3, 4, 5], link(source.clone(), checkpoint(11)))])
+	};
+	let (new_state, execution_payload) = verify_casper_ffg_update::<Devnet>(state, update).unwrap();
+
+	assert_eq!(new_state.justified_checkpoint, checkpoint(11));
+	assert_eq!(new_state.finalized_checkpoint, source);
+	assert_eq!(new_state.finalized_header, proof.header);
+	assert_eq!(new_state.validators_root, validators_root(next_validators).unwrap());
+	assert_eq!(new_state.total_active_balance, (VALIDATOR_COUNT + 2) * EFFECTIVE_BALANCE);
+	assert_eq!(execution_payload.unwrap().block_number, 100);
+}
+
+#[test]
+fn validator_set_rotation_is_bounded_by_the_churn_limit() {
+	let mut proof = finalized_header_proof(validators());
+	let source = finalizing_link(&proof).source;
+	let mut state = trusted_state();
+	state.justified_checkpoint = source.clone();
+	// a single epoch has passed since the trusted finalized checkpoint
+	proof.validator_set_update.as_mut().unwrap().total_active_balance =
+		VALIDATOR_COUNT * EFFECTIVE_BALANCE + 9 * MAX_EFFECTIVE_BALANCE;
+
+	let update = CasperFfgUpdate {
+		finalized: Some(proof),
+		..update(vec![attest(&[0, 1, 2, 3, 4, 5], link(source, checkpoint(11)))])
+	};
+	assert!(verify_casper_ffg_update::<Devnet>(state, update).is_err());
+}
+
+#[test]
+fn finalized_header_must_match_the_finalized_checkpoint() {
+	let proof = finalized_header_proof(validators());
+	let mut link = finalizing_link(&proof);
+	link.source.root = Node::from_bytes([1u8; 32]);
+
+	assert!(verify_finalized_header::<Devnet>(&link, proof).is_err());
+}
+
+#[test]
+fn finalized_header_rejects_invalid_execution_payload() {
+	let mut proof = finalized_header_proof(validators());
+	let link = finalizing_link(&proof);
+	assert!(verify_finalized_header::<Devnet>(&link, proof.clone()).is_ok());
+
+	proof.execution_payload.block_number += 1;
+	assert!(verify_finalized_header::<Devnet>(&link, proof).is_err());
+}
+
+#[test]
+fn validator_set_update_is_verified_against_the_beacon_state() {
+	let next_validators = (0..VALIDATOR_COUNT + 2).map(validator).collect::<Vec<_>>();
+	let proof = finalized_header_proof(next_validators);
+	let state_root = proof.header.state_root;
+	let mut update = proof.validator_set_update.unwrap();
+	assert!(verify_validator_set_update(&update, &state_root).is_ok());
+
+	update.validators_root = validators_root(validators()).unwrap();
+	assert!(verify_validator_set_update(&update, &state_root).is_err());
+}
+
+fn conflicting_checkpoint(epoch: u64) -> Checkpoint {
+	Checkpoint { epoch, root: Node::from_bytes([0xff; 32]) }
+}
+
+#[test]
+fn conflicting_links_in_the_same_epoch_are_fraud() {
+	let state = trusted_state();
+	let honest = update(vec![attest(&[0, 1, 2, 3, 4, 5], link(checkpoint(10), checkpoint(11)))]);
+	let conflicting =
+		update(vec![attest(&[2, 3, 4, 5, 6, 7], link(checkpoint(10), conflicting_checkpoint(11)))]);
+
+	assert!(verify_fraud_proof::<Devnet>(&state, honest, conflicting).is_ok());
+}
+
+#[test]
+fn invalid_fraud_proofs_are_rejected() {
+	let state = trusted_state();
+	let honest = update(vec![attest(&[0, 1, 2, 3, 4, 5], link(checkpoint(10), checkpoint(11)))]);
+	let later = update(vec![attest(&[0, 1, 2, 3, 4, 5], link(checkpoint(10), checkpoint(12)))]);
+	let minority =
+		update(vec![attest(&[3, 4, 5], link(checkpoint(10), conflicting_checkpoint(11)))]);
+
+	// identical links
+	assert!(verify_fraud_proof::<Devnet>(&state, honest.clone(), honest.clone()).is_err());
+	// links to different epochs
+	assert!(verify_fraud_proof::<Devnet>(&state, honest.clone(), later).is_err());
+	// the conflicting link lacks a supermajority
+	assert!(verify_fraud_proof::<Devnet>(&state, honest, minority).is_err());
+}
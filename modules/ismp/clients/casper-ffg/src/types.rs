@@ -0,0 +1,127 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types used by the Casper FFG consensus client
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use ssz_rs::Node;
+use sync_committee_primitives::{
+	consensus_types::{AttestationData, BeaconBlockHeader, Checkpoint, Validator},
+	constants::{BlsSignature, Epoch, Root, ValidatorIndex},
+	types::ExecutionPayloadProof,
+};
+
+/// The trusted state of the Casper FFG light client.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub struct ConsensusState {
+	/// The latest justified checkpoint, every supermajority link must originate from it.
+	pub justified_checkpoint: Checkpoint,
+	/// The latest finalized checkpoint
+	pub finalized_checkpoint: Checkpoint,
+	/// The beacon block header of the latest finalized checkpoint
+	pub finalized_header: BeaconBlockHeader,
+	/// `hash_tree_root` of the tracked validator set, proven against the beacon state of the
+	/// finalized header. Updates only supply the participating validators, proven against it.
+	pub validators_root: Root,
+	/// The total effective balance of the active validators in the tracked validator set, every
+	/// supermajority link must be attested by 2/3 of it.
+	pub total_active_balance: u64,
+	/// The execution layer chain id
+	pub chain_id: u32,
+}
+
+/// An aggregate attestation from a subset of the tracked validator set.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub struct AggregateAttestation {
+	/// Indices of the participating validators in the tracked validator set, in ascending order.
+	pub participants: Vec<ValidatorIndex>,
+	/// The attested data, containing the source and target checkpoints of the vote.
+	pub data: AttestationData,
+	/// The aggregate signature of all participants over the attested data.
+	pub signature: BlsSignature,
+}
+
+/// The participating validators of an update, proven against the validators root of the trusted
+/// state.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub struct ValidatorsProof {
+	/// The participating validators with their indices in the tracked validator set, in ascending
+	/// order of their index.
+	pub validators: Vec<(ValidatorIndex, Validator)>,
+	/// Merkle multiproof for the validators in the tracked validator registry, the helper nodes
+	/// are in descending order of their generalized index.
+	pub multi_proof: Vec<Node>,
+}
+
+/// Proof of the validator registry in the beacon state of a finalized header.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub struct ValidatorSetUpdate {
+	/// `hash_tree_root(state.validators)` of the beacon state
+	pub validators_root: Root,
+	/// Merkle proof for `hash_tree_root(state.validators)` in the beacon state.
+	pub validators_branch: Vec<Node>,
+	/// The total effective balance of the active validators in the registry, it may only differ
+	/// from the trusted total active balance by the validator churn limit.
+	pub total_active_balance: u64,
+}
+
+/// Proof for the execution payload of the checkpoint finalized by a supermajority link.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub struct FinalizedHeaderProof {
+	/// The beacon block header whose root is the newly finalized checkpoint root.
+	pub header: BeaconBlockHeader,
+	/// Execution payload of the finalized header
+	pub execution_payload: ExecutionPayloadProof,
+	/// Optionally rotate the tracked validator set to the one in the finalized beacon state.
+	pub validator_set_update: Option<ValidatorSetUpdate>,
+}
+
+/// Data required to advance the state of the Casper FFG light client.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq)]
+pub struct CasperFfgUpdate {
+	/// The validators participating in the attestations
+	pub validators: ValidatorsProof,
+	/// Aggregate attestations which together form a supermajority link from the trusted justified
+	/// checkpoint to a new target checkpoint.
+	pub attestations: Vec<AggregateAttestation>,
+	/// Must be present if the link finalizes its source checkpoint, absent otherwise.
+	pub finalized: Option<FinalizedHeaderProof>,
+}
+
+/// The outcome of verifying a supermajority link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupermajorityLink {
+	/// The source checkpoint of the link
+	pub source: Checkpoint,
+	/// The target checkpoint of the link, this is now justified.
+	pub target: Checkpoint,
+	/// The total effective balance of the unslashed participants
+	pub attesting_balance: u64,
+	/// The total active balance of the trusted state
+	pub total_active_balance: u64,
+}
+
+impl SupermajorityLink {
+	/// A link between checkpoints of consecutive epochs finalizes its source checkpoint.
+	pub fn finalizes_source(&self) -> bool {
+		self.target.epoch == self.source.epoch + 1
+	}
+}
+
+/// Returns true if the validator is active at the given epoch.
+pub fn is_active_validator(validator: &Validator, epoch: Epoch) -> bool {
+	validator.activation_epoch <= epoch && epoch < validator.exit_epoch
+}
@@ -0,0 +1,354 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verification of Casper FFG supermajority links and finalized checkpoints.
+
+use crate::types::{
+	is_active_validator, AggregateAttestation, CasperFfgUpdate, ConsensusState,
+	FinalizedHeaderProof, SupermajorityLink, ValidatorSetUpdate, ValidatorsProof,
+};
+use alloc::{
+	collections::{BTreeMap, BTreeSet},
+	format,
+	string::ToString,
+	vec::Vec,
+};
+use ismp::error::Error;
+use ssz_rs::{prelude::is_valid_merkle_branch, List, Merkleized, Node};
+use sync_committee_primitives::{
+	consensus_types::Validator,
+	constants::{
+		Config, Root, ValidatorIndex, DOMAIN_BEACON_ATTESTER, VALIDATORS_INDEX,
+		VALIDATORS_INDEX_LOG2, VALIDATOR_REGISTRY_LIMIT,
+	},
+	types::ExecutionPayloadProof,
+	util::{compute_domain, compute_fork_version, compute_signing_root},
+};
+use sync_committee_verifier::{
+	crypto::verify_fast_aggregate_signature, verify_execution_payload_proof,
+};
+
+/// Depth of the validator registry tree, including the mix-in of the registry length.
+pub const VALIDATOR_REGISTRY_DEPTH: u32 = VALIDATOR_REGISTRY_LIMIT.trailing_zeros() + 1;
+/// The minimum number of validators that may be activated or exited in an epoch
+pub const MIN_PER_EPOCH_CHURN_LIMIT: u64 = 4;
+/// The active validator count is divided by this to get the number of validators that may be
+/// activated or exited in an epoch
+pub const CHURN_LIMIT_QUOTIENT: u64 = 65_536;
+/// The maximum effective balance of a validator in Gwei
+pub const MAX_EFFECTIVE_BALANCE: u64 = 32_000_000_000;
+
+/// Computes the `hash_tree_root` of a validator registry.
+pub fn validators_root(validators: Vec<Validator>) -> Result<Root, Error> {
+	let mut registry = List::<Validator, VALIDATOR_REGISTRY_LIMIT>::try_from(validators)
+		.map_err(|_| Error::Custom("Validator registry exceeds limit".to_string()))?;
+	registry
+		.hash_tree_root()
+		.map_err(|_| Error::Custom("Failed to hash validator registry".to_string()))
+}
+
+/// The generalized index of the validator at `index` in the validator registry tree.
+pub fn validator_generalized_index(index: ValidatorIndex) -> u64 {
+	(1u64 << VALIDATOR_REGISTRY_DEPTH) + index
+}
+
+fn hash_pair(left: &Node, right: &Node) -> Node {
+	let mut preimage = [0u8; 64];
+	preimage[..32].copy_from_slice(left.as_bytes());
+	preimage[32..].copy_from_slice(right.as_bytes());
+	Node::from_bytes(sp_io::hashing::sha2_256(&preimage))
+}
+
+/// The generalized indices of the helper nodes of a multiproof for the nodes at `indices`, in
+/// descending order.
+pub fn helper_indices(indices: &[u64]) -> Vec<u64> {
+	let (mut paths, mut siblings) = (BTreeSet::new(), BTreeSet::new());
+	for mut index in indices.iter().copied() {
+		while index > 1 {
+			paths.insert(index);
+			siblings.insert(index ^ 1);
+			index /= 2;
+		}
+	}
+	siblings.difference(&paths).rev().copied().collect()
+}
+
+/// Computes the root of a merkle multiproof for the leaves at the given generalized indices. The
+/// generalized indices are 64 bits wide, unlike those of `ssz_rs`, so that the validator registry
+/// tree can be used on 32 bit targets.
+pub fn calculate_multi_merkle_root(
+	leaves: &[Node],
+	proof: &[Node],
+	indices: &[u64],
+) -> Result<Node, Error> {
+	let helpers = helper_indices(indices);
+	if leaves.len() != indices.len() || proof.len() != helpers.len() {
+		Err(Error::Custom("Invalid multiproof length".to_string()))?
+	}
+
+	let mut nodes = indices
+		.iter()
+		.copied()
+		.zip(leaves.iter().copied())
+		.chain(helpers.into_iter().zip(proof.iter().copied()))
+		.collect::<BTreeMap<_, _>>();
+	// parents are computed deepest first, as they have lower generalized indices than their
+	// children
+	let mut pending = nodes.keys().copied().collect::<BTreeSet<_>>();
+	while let Some(index) = pending.pop_last() {
+		if index <= 1 || nodes.contains_key(&(index / 2)) {
+			continue
+		}
+		if let (Some(left), Some(right)) = (nodes.get(&(index & !1)), nodes.get(&(index | 1))) {
+			let parent = hash_pair(left, right);
+			nodes.insert(index / 2, parent);
+			pending.insert(index / 2);
+		}
+	}
+
+	nodes
+		.get(&1)
+		.copied()
+		.ok_or_else(|| Error::Custom("Incomplete multiproof".to_string()))
+}
+
+/// Verifies the participating validators against the validators root of the trusted state,
+/// returning them by their index.
+pub fn verify_validators_proof(
+	proof: ValidatorsProof,
+	validators_root: &Root,
+) -> Result<BTreeMap<ValidatorIndex, Validator>, Error> {
+	let ValidatorsProof { validators, multi_proof } = proof;
+	if validators.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+		Err(Error::Custom("Validators must be sorted and unique".to_string()))?
+	}
+
+	let mut leaves = Vec::with_capacity(validators.len());
+	let mut indices = Vec::with_capacity(validators.len());
+	for (index, validator) in validators.iter() {
+		if *index >= VALIDATOR_REGISTRY_LIMIT as u64 {
+			Err(Error::Custom(format!("Validator index {index} exceeds the registry limit")))?
+		}
+		let leaf = validator
+			.clone()
+			.hash_tree_root()
+			.map_err(|_| Error::Custom("Failed to hash validator".to_string()))?;
+		leaves.push(leaf);
+		indices.push(validator_generalized_index(*index));
+	}
+
+	if calculate_multi_merkle_root(&leaves, &multi_proof, &indices)? != *validators_root {
+		Err(Error::Custom("Validators do not match the trusted validators root".to_string()))?
+	}
+
+	Ok(validators.into_iter().collect())
+}
+
+/// Verifies that the aggregate attestations form a supermajority link from the trusted justified
+/// checkpoint, i.e the unslashed participants hold at least 2/3 of the total active balance of
+/// the trusted state. Every participant must be proven against the trusted validators root.
+pub fn verify_supermajority_link<C: Config>(
+	trusted_state: &ConsensusState,
+	validators: ValidatorsProof,
+	attestations: Vec<AggregateAttestation>,
+) -> Result<SupermajorityLink, Error> {
+	let validators = verify_validators_proof(validators, &trusted_state.validators_root)?;
+
+	let (source, target) = attestations
+		.first()
+		.map(|attestation| (attestation.data.source.clone(), attestation.data.target.clone()))
+		.ok_or_else(|| Error::Custom("Update contains no attestations".to_string()))?;
+
+	if source != trusted_state.justified_checkpoint {
+		Err(Error::Custom(
+			"Supermajority link does not originate from the justified checkpoint".to_string(),
+		))?
+	}
+
+	if target.epoch <= source.epoch {
+		Err(Error::Custom("Target epoch must be greater than the source epoch".to_string()))?
+	}
+
+	let domain = compute_domain(
+		DOMAIN_BEACON_ATTESTER,
+		Some(compute_fork_version::<C>(target.epoch)),
+		Some(Root::from_bytes(C::GENESIS_VALIDATORS_ROOT)),
+		C::GENESIS_FORK_VERSION,
+	)
+	.map_err(|_| Error::Custom("Failed to compute attester domain".to_string()))?;
+
+	let mut seen = BTreeSet::new();
+	let mut attesting_balance = 0u64;
+	for AggregateAttestation { participants, mut data, signature } in attestations {
+		if data.source != source || data.target != target {
+			Err(Error::Custom("Attestations do not vote for the same link".to_string()))?
+		}
+
+		if participants.windows(2).any(|pair| pair[0] >= pair[1]) {
+			Err(Error::Custom("Participants must be sorted and unique".to_string()))?
+		}
+
+		let mut public_keys = Vec::with_capacity(participants.len());
+		for index in participants {
+			let validator = validators.get(&index).ok_or_else(|| {
+				Error::Custom(format!("Missing proof for validator {index} in attestation"))
+			})?;
+			public_keys.push(validator.public_key.clone());
+
+			if seen.insert(index) &&
+				!validator.slashed &&
+				is_active_validator(validator, target.epoch)
+			{
+				attesting_balance = attesting_balance.saturating_add(validator.effective_balance);
+			}
+		}
+
+		let signing_root = compute_signing_root(&mut data, domain)
+			.map_err(|_| Error::Custom("Failed to compute signing root".to_string()))?;
+
+		verify_fast_aggregate_signature(&public_keys, signing_root.as_bytes().to_vec(), &signature)
+			.map_err(|e| Error::Custom(format!("Invalid aggregate attestation: {e:?}")))?;
+	}
+
+	let total_active_balance = trusted_state.total_active_balance;
+	if (attesting_balance as u128) * 3 < (total_active_balance as u128) * 2 {
+		Err(Error::Custom(format!(
+			"Insufficient attesting balance {attesting_balance}, total active balance is {total_active_balance}"
+		)))?
+	}
+
+	Ok(SupermajorityLink { source, target, attesting_balance, total_active_balance })
+}
+
+/// Verifies that the finalized header is the block of the finalized checkpoint and that the
+/// execution payload and optional validator registry are committed to by its beacon state.
+pub fn verify_finalized_header<C: Config>(
+	link: &SupermajorityLink,
+	proof: FinalizedHeaderProof,
+) -> Result<(FinalizedHeaderProof, Option<ValidatorSetUpdate>), Error> {
+	let FinalizedHeaderProof { mut header, execution_payload, validator_set_update } = proof;
+
+	let header_root = header
+		.hash_tree_root()
+		.map_err(|_| Error::Custom("Failed to hash finalized header".to_string()))?;
+	if header_root != link.source.root {
+		Err(Error::Custom("Finalized header does not match the finalized checkpoint".to_string()))?
+	}
+
+	verify_execution_payload_proof::<C>(execution_payload.clone(), &header.state_root)
+		.map_err(|e| Error::Custom(format!("Invalid execution payload proof: {e}")))?;
+
+	if let Some(update) = validator_set_update.as_ref() {
+		verify_validator_set_update(update, &header.state_root)?;
+	}
+
+	Ok((
+		FinalizedHeaderProof { header, execution_payload, validator_set_update: None },
+		validator_set_update,
+	))
+}
+
+/// Verifies the validator registry root against the given beacon state root.
+pub fn verify_validator_set_update(
+	update: &ValidatorSetUpdate,
+	beacon_state_root: &Root,
+) -> Result<(), Error> {
+	let is_merkle_branch_valid = is_valid_merkle_branch(
+		&update.validators_root,
+		update.validators_branch.iter(),
+		VALIDATORS_INDEX_LOG2 as usize,
+		VALIDATORS_INDEX as usize,
+		beacon_state_root,
+	);
+
+	if !is_merkle_branch_valid {
+		Err(Error::Custom("Invalid validator registry branch".to_string()))?
+	}
+
+	Ok(())
+}
+
+/// The largest change of the total active balance over the given number of epochs. The balance
+/// that can be activated or exited in an epoch is bounded by the validator churn limit.
+pub fn max_balance_churn(total_active_balance: u64, epochs: u64) -> u64 {
+	let churn = (total_active_balance / CHURN_LIMIT_QUOTIENT)
+		.max(MIN_PER_EPOCH_CHURN_LIMIT * MAX_EFFECTIVE_BALANCE);
+	// validators may be activated and exited in the same epoch
+	churn.saturating_mul(2).saturating_mul(epochs)
+}
+
+/// Verifies a [`CasperFfgUpdate`] against the trusted consensus state, returning the new consensus
+/// state alongside the execution payload of a newly finalized checkpoint, if any.
+pub fn verify_casper_ffg_update<C: Config>(
+	trusted_state: ConsensusState,
+	update: CasperFfgUpdate,
+) -> Result<(ConsensusState, Option<ExecutionPayloadProof>), Error> {
+	let CasperFfgUpdate { validators, attestations, finalized } = update;
+	let link = verify_supermajority_link::<C>(&trusted_state, validators, attestations)?;
+
+	let is_new_finality =
+		link.finalizes_source() && link.source.epoch > trusted_state.finalized_checkpoint.epoch;
+
+	let mut new_state =
+		ConsensusState { justified_checkpoint: link.target.clone(), ..trusted_state };
+
+	let execution_payload = match (is_new_finality, finalized) {
+		(true, Some(proof)) => {
+			let (proof, validator_set_update) = verify_finalized_header::<C>(&link, proof)?;
+			if let Some(update) = validator_set_update {
+				let epochs = link.source.epoch - new_state.finalized_checkpoint.epoch;
+				let churn = max_balance_churn(new_state.total_active_balance, epochs);
+				if update.total_active_balance.abs_diff(new_state.total_active_balance) > churn {
+					Err(Error::Custom(
+						"Total active balance changed by more than the churn limit".to_string(),
+					))?
+				}
+				new_state.validators_root = update.validators_root;
+				new_state.total_active_balance = update.total_active_balance;
+			}
+			new_state.finalized_checkpoint = link.source;
+			new_state.finalized_header = proof.header;
+			Some(proof.execution_payload)
+		},
+		(true, None) => Err(Error::Custom("Missing finalized header proof".to_string()))?,
+		(false, Some(_)) =>
+			Err(Error::Custom("Update does not finalize a new checkpoint".to_string()))?,
+		(false, None) => None,
+	};
+
+	Ok((new_state, execution_payload))
+}
+
+/// Verifies that both updates carry supermajority links from the trusted justified checkpoint to
+/// distinct targets in the same epoch. This can only happen if at least 1/3 of the validator set
+/// has double voted.
+pub fn verify_fraud_proof<C: Config>(
+	trusted_state: &ConsensusState,
+	update_1: CasperFfgUpdate,
+	update_2: CasperFfgUpdate,
+) -> Result<(), Error> {
+	let link_1 =
+		verify_supermajority_link::<C>(trusted_state, update_1.validators, update_1.attestations)
+			.map_err(|e| Error::Custom(format!("Failed to verify first link: {e:?}")))?;
+	let link_2 =
+		verify_supermajority_link::<C>(trusted_state, update_2.validators, update_2.attestations)
+			.map_err(|e| Error::Custom(format!("Failed to verify second link: {e:?}")))?;
+
+	if link_1.target.epoch != link_2.target.epoch || link_1.target.root == link_2.target.root {
+		Err(Error::Custom("Invalid Fraud proof".to_string()))?
+	}
+
+	Ok(())
+}
@@ -17,10 +17,19 @@
 
 use core::{marker::PhantomData, time::Duration};
 
-use alloc::{boxed::Box, collections::BTreeMap, format, string::ToString, vec::Vec};
+use alloc::{
+	boxed::Box,
+	collections::{BTreeMap, BTreeSet},
+	format,
+	string::ToString,
+	vec,
+	vec::Vec,
+};
 use codec::{Decode, Encode};
 use core::fmt::Debug;
 use cumulus_pallet_parachain_system::{RelaychainDataProvider, RelaychainStateProvider};
+use cumulus_primitives_core::relay_chain;
+use finality_grandpa::Message;
 use frame_support::traits::Get;
 use ismp::{
 	consensus::{
@@ -34,6 +43,7 @@ use ismp::{
 use pallet_ismp::{ConsensusDigest, ISMP_ID};
 use primitive_types::H256;
 use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+use sp_consensus_grandpa::{AuthorityId, AuthorityList, AuthoritySignature, RoundNumber, SetId};
 use sp_runtime::{
 	app_crypto::sp_core::storage::StorageKey,
 	generic::Header,
@@ -64,6 +74,71 @@ pub struct ParachainConsensusProof {
 	pub storage_proof: Vec<Vec<u8>>,
 }
 
+/// A relay chain state proof of the header of a single parachain.
+#[derive(Debug, Encode, Decode)]
+pub struct ParachainHeaderProof {
+	/// The parachain whose header is proven
+	pub para_id: u32,
+	/// Relay chain state proof of the parachain header
+	pub proof: ParachainConsensusProof,
+}
+
+/// A GRANDPA vote signed by a relay chain authority.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Encode, Decode)]
+pub struct SignedGrandpaVote {
+	/// The authority that signed the vote
+	pub identity: AuthorityId,
+	/// The vote, either a prevote or a precommit
+	pub message: Message<H256, u32>,
+	/// The authority's signature over the vote
+	pub signature: AuthoritySignature,
+}
+
+/// Votes of relay chain GRANDPA authorities for the same block in a round.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Encode, Decode)]
+pub struct GrandpaVotes {
+	/// Relay chain height at which the authority set is proven
+	pub relay_height: u32,
+	/// Relay chain state proof of `Grandpa::CurrentSetId` and `Grandpa::Authorities`
+	pub authority_set_proof: Vec<Vec<u8>>,
+	/// The authority set id of the votes
+	pub set_id: SetId,
+	/// The voting round
+	pub round: RoundNumber,
+	/// The votes, which must all be prevotes or all be precommits for the same block
+	pub votes: Vec<SignedGrandpaVote>,
+}
+
+/// The block that a supermajority of the GRANDPA authorities voted for in a round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupermajorityVote {
+	/// The authority set id of the votes
+	pub set_id: SetId,
+	/// The voting round
+	pub round: RoundNumber,
+	/// Whether the votes are precommits rather than prevotes
+	pub precommit: bool,
+	/// Hash and number of the block that was voted for
+	pub target: (H256, u32),
+}
+
+/// Evidence submitted in a fraud proof against the parachain consensus client. Both proofs of a
+/// [`FraudProofMessage`](ismp::messaging::FraudProofMessage) must be of the same kind.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Encode, Decode)]
+pub enum ParachainFraudProof {
+	/// Relay chain state proofs of different heads for the same parachain at the same relay
+	/// chain height.
+	#[codec(index = 0)]
+	ParachainHeader(ParachainHeaderProof),
+	/// Votes of a supermajority of the relay chain GRANDPA authorities for different blocks in the
+	/// same round, which requires more than 1/3 of the authorities to equivocate.
+	#[codec(index = 1)]
+	GrandpaVotes(GrandpaVotes),
+}
+
 /// [`ConsensusClientId`] for [`ParachainConsensusClient`]
 pub const PARACHAIN_CONSENSUS_ID: ConsensusClientId = *b"PARA";
 
@@ -85,18 +160,10 @@ where
 				Error::Custom(format!("Cannot decode parachain consensus proof: {e:?}"))
 			})?;
 
-		// first check our oracle's registry
-		let root = R::state_root(update.relay_height)
-			// not in our registry? ask parachain_system.
-			.or_else(|| {
-				let state = RelaychainDataProvider::<T>::current_relay_chain_state();
-
-				if state.number == update.relay_height {
-					Some(state.state_root)
-				} else {
-					None
-				}
-			})
+		// first check our oracle's registry, then parachain_system.
+		let root = relay_chain_state_roots::<T, R>(update.relay_height)
+			.into_iter()
+			.next()
 			// well, we couldn't find it
 			.ok_or_else(|| {
 				Error::Custom(format!("Cannot find relay chain height: {}", update.relay_height))
@@ -194,10 +261,52 @@ where
 		&self,
 		_host: &dyn IsmpHost,
 		_trusted_consensus_state: Vec<u8>,
-		_proof_1: Vec<u8>,
-		_proof_2: Vec<u8>,
+		proof_1: Vec<u8>,
+		proof_2: Vec<u8>,
 	) -> Result<(), Error> {
-		// There are no fraud proofs for the parachain client
+		let proof_1 = ParachainFraudProof::decode(&mut &proof_1[..])
+			.map_err(|e| Error::Custom(format!("Cannot decode first fraud proof: {e:?}")))?;
+		let proof_2 = ParachainFraudProof::decode(&mut &proof_2[..])
+			.map_err(|e| Error::Custom(format!("Cannot decode second fraud proof: {e:?}")))?;
+
+		match (proof_1, proof_2) {
+			(
+				ParachainFraudProof::ParachainHeader(proof_1),
+				ParachainFraudProof::ParachainHeader(proof_2),
+			) => {
+				if proof_1.para_id != proof_2.para_id ||
+					proof_1.proof.relay_height != proof_2.proof.relay_height
+				{
+					Err(Error::Custom(
+						"Fraud proofs must be for the same parachain at the same relay chain height"
+							.to_string(),
+					))?
+				}
+
+				if !Parachains::<T>::contains_key(proof_1.para_id) {
+					Err(Error::Custom(format!(
+						"Parachain with id {} not registered",
+						proof_1.para_id
+					)))?
+				}
+
+				let header_1 = verify_parachain_header_proof::<T, R>(proof_1)?;
+				let header_2 = verify_parachain_header_proof::<T, R>(proof_2)?;
+
+				// The relay chain state commits to a single head per parachain, conflicting heads
+				// can only be proven if conflicting relay chain states were accepted at this
+				// height.
+				if header_1 == header_2 {
+					Err(Error::Custom("Invalid Fraud proof".to_string()))?
+				}
+			},
+			(
+				ParachainFraudProof::GrandpaVotes(votes_1),
+				ParachainFraudProof::GrandpaVotes(votes_2),
+			) => verify_grandpa_fraud_proof::<T, R>(votes_1, votes_2)?,
+			_ => Err(Error::Custom("Fraud proofs must be of the same kind".to_string()))?,
+		}
+
 		Ok(())
 	}
 
@@ -221,6 +330,235 @@ where
 	}
 }
 
+/// Returns the relay chain state roots known to this parachain at the given height. This is the
+/// root recorded by our oracle followed by the root reported by parachain_system, if it differs.
+fn relay_chain_state_roots<T, R>(height: relay_chain::BlockNumber) -> Vec<relay_chain::Hash>
+where
+	R: RelayChainOracle,
+	T: super::Config,
+{
+	let mut roots = R::state_root(height).into_iter().collect::<Vec<_>>();
+
+	let state = RelaychainDataProvider::<T>::current_relay_chain_state();
+	if state.number == height && !roots.contains(&state.state_root) {
+		roots.push(state.state_root)
+	}
+
+	roots
+}
+
+/// Reads the given storage keys from a storage proof of the relay chain state at `relay_height`,
+/// the proof may be against any of the relay chain state roots known at this height.
+fn read_relay_chain_proof<T, R>(
+	relay_height: relay_chain::BlockNumber,
+	storage_proof: Vec<Vec<u8>>,
+	keys: Vec<Vec<u8>>,
+) -> Result<BTreeMap<Vec<u8>, Option<Vec<u8>>>, Error>
+where
+	R: RelayChainOracle,
+	T: super::Config,
+{
+	let roots = relay_chain_state_roots::<T, R>(relay_height);
+	if roots.is_empty() {
+		Err(Error::Custom(format!("Cannot find relay chain height: {relay_height}")))?
+	}
+
+	roots
+		.into_iter()
+		.find_map(|root| {
+			read_proof_check::<BlakeTwo256, _>(
+				&root,
+				StorageProof::new(storage_proof.clone()),
+				keys.clone(),
+			)
+			.ok()
+		})
+		.ok_or_else(|| {
+			Error::Custom(format!("Invalid relay chain state proof at height {relay_height}"))
+		})
+}
+
+/// Verifies a relay chain state proof of a parachain header and returns the encoded header.
+fn verify_parachain_header_proof<T, R>(proof: ParachainHeaderProof) -> Result<Vec<u8>, Error>
+where
+	R: RelayChainOracle,
+	T: super::Config,
+{
+	let ParachainHeaderProof {
+		para_id,
+		proof: ParachainConsensusProof { relay_height, storage_proof },
+	} = proof;
+	let key = parachain_header_storage_key(para_id).0;
+
+	read_relay_chain_proof::<T, R>(relay_height, storage_proof, vec![key.clone()])?
+		.remove(&key)
+		.flatten()
+		.ok_or_else(|| {
+			Error::Custom(format!("Parachain header for {para_id} not found in relay chain state"))
+		})
+}
+
+/// Reads the GRANDPA authority set id and authorities from a relay chain state proof.
+fn verify_grandpa_authority_set<T, R>(
+	relay_height: relay_chain::BlockNumber,
+	storage_proof: Vec<Vec<u8>>,
+) -> Result<(SetId, AuthorityList), Error>
+where
+	R: RelayChainOracle,
+	T: super::Config,
+{
+	let set_id_key = grandpa_current_set_id_storage_key().0;
+	let authorities_key = grandpa_authorities_storage_key().0;
+
+	let mut values = read_relay_chain_proof::<T, R>(
+		relay_height,
+		storage_proof,
+		vec![set_id_key.clone(), authorities_key.clone()],
+	)?;
+
+	let set_id = values.remove(&set_id_key).flatten().ok_or_else(|| {
+		Error::Custom("GRANDPA set id not found in relay chain state".to_string())
+	})?;
+	let set_id = SetId::decode(&mut &set_id[..])
+		.map_err(|e| Error::Custom(format!("Cannot decode GRANDPA set id: {e:?}")))?;
+
+	let authorities = values.remove(&authorities_key).flatten().ok_or_else(|| {
+		Error::Custom("GRANDPA authorities not found in relay chain state".to_string())
+	})?;
+	let authorities = AuthorityList::decode(&mut &authorities[..])
+		.map_err(|e| Error::Custom(format!("Cannot decode GRANDPA authorities: {e:?}")))?;
+
+	Ok((set_id, authorities))
+}
+
+/// Verifies that both sets of votes are signed by a supermajority of the relay chain GRANDPA
+/// authorities, and that they vote for different blocks in the same round. The authority set of
+/// each is proven against the relay chain state known to this parachain.
+fn verify_grandpa_fraud_proof<T, R>(
+	votes_1: GrandpaVotes,
+	votes_2: GrandpaVotes,
+) -> Result<(), Error>
+where
+	R: RelayChainOracle,
+	T: super::Config,
+{
+	let (set_id_1, authorities_1) = verify_grandpa_authority_set::<T, R>(
+		votes_1.relay_height,
+		votes_1.authority_set_proof.clone(),
+	)?;
+	let (set_id_2, authorities_2) = verify_grandpa_authority_set::<T, R>(
+		votes_2.relay_height,
+		votes_2.authority_set_proof.clone(),
+	)?;
+
+	if set_id_1 != set_id_2 || authorities_1 != authorities_2 {
+		Err(Error::Custom("Votes must be from the same GRANDPA authority set".to_string()))?
+	}
+
+	let vote_1 = verify_grandpa_votes(votes_1, set_id_1, &authorities_1)?;
+	let vote_2 = verify_grandpa_votes(votes_2, set_id_2, &authorities_2)?;
+
+	verify_conflicting_votes(vote_1, vote_2)
+}
+
+/// Verifies that the votes are all of the same kind for the same block, and are signed by a
+/// supermajority of the given GRANDPA authority set.
+pub fn verify_grandpa_votes(
+	votes: GrandpaVotes,
+	set_id: SetId,
+	authorities: &AuthorityList,
+) -> Result<SupermajorityVote, Error> {
+	if votes.set_id != set_id {
+		Err(Error::Custom(format!(
+			"Votes are for set id {}, but the relay chain set id is {set_id}",
+			votes.set_id
+		)))?
+	}
+
+	let (precommit, target) = votes
+		.votes
+		.first()
+		.and_then(|vote| vote_target(&vote.message))
+		.ok_or_else(|| Error::Custom("Expected a prevote or precommit".to_string()))?;
+
+	let mut voters = BTreeSet::new();
+	let mut voted_weight = 0u64;
+	for vote in votes.votes {
+		if vote_target(&vote.message) != Some((precommit, target)) {
+			Err(Error::Custom("Votes must be of the same kind for the same block".to_string()))?
+		}
+
+		let (_, weight) = authorities
+			.iter()
+			.find(|(id, _)| *id == vote.identity)
+			.ok_or_else(|| Error::Custom("Voter is not a GRANDPA authority".to_string()))?;
+
+		if !voters.insert(vote.identity.clone()) {
+			Err(Error::Custom("Duplicate vote from a GRANDPA authority".to_string()))?
+		}
+
+		let payload = (&vote.message, votes.round, set_id).encode();
+		if !sp_io::crypto::ed25519_verify(vote.signature.as_ref(), &payload, vote.identity.as_ref())
+		{
+			Err(Error::Custom("Invalid GRANDPA vote signature".to_string()))?
+		}
+
+		voted_weight = voted_weight.saturating_add(*weight);
+	}
+
+	let total_weight =
+		authorities.iter().fold(0u64, |acc, (_, weight)| acc.saturating_add(*weight));
+	// same threshold as the GRANDPA voter set
+	let threshold = total_weight - total_weight.saturating_sub(1) / 3;
+	if voted_weight < threshold {
+		Err(Error::Custom(format!(
+			"Votes have a weight of {voted_weight}, a supermajority requires {threshold}"
+		)))?
+	}
+
+	Ok(SupermajorityVote { set_id, round: votes.round, precommit, target })
+}
+
+/// Two supermajorities voting for different blocks in the same round can only exist if more than
+/// 1/3 of the authorities have equivocated.
+pub fn verify_conflicting_votes(
+	vote_1: SupermajorityVote,
+	vote_2: SupermajorityVote,
+) -> Result<(), Error> {
+	if vote_1.set_id != vote_2.set_id ||
+		vote_1.round != vote_2.round ||
+		vote_1.precommit != vote_2.precommit
+	{
+		Err(Error::Custom("Votes must be of the same kind in the same round and set".to_string()))?
+	}
+
+	if vote_1.target == vote_2.target {
+		Err(Error::Custom("Invalid Fraud proof".to_string()))?
+	}
+
+	Ok(())
+}
+
+/// Returns whether the vote is a precommit and the block it votes for, `None` for primary
+/// proposals.
+fn vote_target(message: &Message<H256, u32>) -> Option<(bool, (H256, u32))> {
+	match message {
+		Message::Prevote(vote) => Some((false, (vote.target_hash, vote.target_number))),
+		Message::Precommit(vote) => Some((true, (vote.target_hash, vote.target_number))),
+		Message::PrimaryPropose(_) => None,
+	}
+}
+
+/// Returns the storage key for the current GRANDPA authority set id on the relay chain.
+pub fn grandpa_current_set_id_storage_key() -> StorageKey {
+	StorageKey(frame_support::storage::storage_prefix(b"Grandpa", b"CurrentSetId").to_vec())
+}
+
+/// Returns the storage key for the current GRANDPA authorities on the relay chain.
+pub fn grandpa_authorities_storage_key() -> StorageKey {
+	StorageKey(frame_support::storage::storage_prefix(b"Grandpa", b"Authorities").to_vec())
+}
+
 /// This returns the storage key for a parachain header on the relay chain.
 pub fn parachain_header_storage_key(para_id: u32) -> StorageKey {
 	let mut storage_key = frame_support::storage::storage_prefix(b"Paras", b"Heads").to_vec();
@@ -229,3 +567,118 @@ pub fn parachain_header_storage_key(para_id: u32) -> StorageKey {
 	storage_key.extend_from_slice(&encoded_para_id);
 	StorageKey(storage_key)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use finality_grandpa::{Precommit, Prevote};
+	use sp_core::{ed25519, Pair};
+
+	const SET_ID: SetId = 3;
+	const ROUND: RoundNumber = 10;
+
+	fn pair(index: u8) -> ed25519::Pair {
+		ed25519::Pair::from_seed(&[index; 32])
+	}
+
+	fn authorities() -> AuthorityList {
+		(0..4).map(|index| (AuthorityId::from(pair(index).public()), 1)).collect()
+	}
+
+	fn precommit(block: u8) -> Message<H256, u32> {
+		Message::Precommit(Precommit { target_hash: H256::repeat_byte(block), target_number: 100 })
+	}
+
+	fn sign(signer: u8, message: Message<H256, u32>, round: RoundNumber) -> SignedGrandpaVote {
+		let signature = pair(signer).sign(&(&message, round, SET_ID).encode());
+		SignedGrandpaVote {
+			identity: AuthorityId::from(pair(signer).public()),
+			message,
+			signature: AuthoritySignature::from(signature),
+		}
+	}
+
+	fn votes(signers: &[u8], message: Message<H256, u32>) -> GrandpaVotes {
+		GrandpaVotes {
+			relay_height: 0,
+			authority_set_proof: vec![],
+			set_id: SET_ID,
+			round: ROUND,
+			votes: signers.iter().map(|signer| sign(*signer, message.clone(), ROUND)).collect(),
+		}
+	}
+
+	#[test]
+	fn supermajority_votes_are_verified() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let vote =
+				verify_grandpa_votes(votes(&[0, 1, 2], precommit(1)), SET_ID, &authorities())
+					.unwrap();
+
+			assert_eq!(
+				vote,
+				SupermajorityVote {
+					set_id: SET_ID,
+					round: ROUND,
+					precommit: true,
+					target: (H256::repeat_byte(1), 100)
+				}
+			);
+		})
+	}
+
+	#[test]
+	fn invalid_votes_are_rejected() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let authorities = authorities();
+
+			// a single authority is not a supermajority
+			assert!(verify_grandpa_votes(votes(&[0], precommit(1)), SET_ID, &authorities).is_err());
+			// neither are duplicate votes
+			assert!(verify_grandpa_votes(votes(&[0, 1, 1], precommit(1)), SET_ID, &authorities)
+				.is_err());
+			// unknown voter
+			assert!(verify_grandpa_votes(votes(&[0, 1, 5], precommit(1)), SET_ID, &authorities)
+				.is_err());
+			// wrong authority set
+			assert!(verify_grandpa_votes(
+				votes(&[0, 1, 2], precommit(1)),
+				SET_ID + 1,
+				&authorities
+			)
+			.is_err());
+
+			// votes for different blocks
+			let mut mixed = votes(&[0, 1], precommit(1));
+			mixed.votes.push(sign(2, precommit(2), ROUND));
+			assert!(verify_grandpa_votes(mixed, SET_ID, &authorities).is_err());
+
+			// a vote signed for another round
+			let mut forged = votes(&[0, 1], precommit(1));
+			forged.votes.push(sign(2, precommit(1), ROUND + 1));
+			assert!(verify_grandpa_votes(forged, SET_ID, &authorities).is_err());
+		})
+	}
+
+	#[test]
+	fn conflicting_supermajorities_are_fraud() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let authorities = authorities();
+			let vote_1 =
+				verify_grandpa_votes(votes(&[0, 1, 2], precommit(1)), SET_ID, &authorities)
+					.unwrap();
+			let vote_2 =
+				verify_grandpa_votes(votes(&[1, 2, 3], precommit(2)), SET_ID, &authorities)
+					.unwrap();
+
+			assert!(verify_conflicting_votes(vote_1.clone(), vote_2.clone()).is_ok());
+			assert!(verify_conflicting_votes(vote_1.clone(), vote_1.clone()).is_err());
+
+			let prevote =
+				Message::Prevote(Prevote { target_hash: H256::repeat_byte(2), target_number: 100 });
+			let vote_3 =
+				verify_grandpa_votes(votes(&[1, 2, 3], prevote), SET_ID, &authorities).unwrap();
+			assert!(verify_conflicting_votes(vote_1, vote_3).is_err());
+		})
+	}
+}
@@ -32,7 +32,7 @@ use ismp::{
 	},
 	error::Error,
 	host::StateMachine,
-	messaging::{hash_request, hash_response, Keccak256, Proof},
+	messaging::{hash_batch, hash_request, hash_response, Keccak256, Proof},
 	router::RequestResponse,
 };
 use trie_db::{DBValue, Trie, TrieDBBuilder};
@@ -90,6 +90,16 @@ pub fn req_res_commitment_key<H: Keccak256>(item: RequestResponse) -> Vec<Vec<u8
 				);
 				keys.push(key.0.to_vec())
 			},
+		RequestResponse::Batch(batches) =>
+			for batch in batches {
+				let commitment = hash_batch::<H>(&batch);
+				let key = derive_map_key_with_offset::<H>(
+					commitment.0.to_vec(),
+					REQUEST_COMMITMENTS_SLOT,
+					1,
+				);
+				keys.push(key.0.to_vec())
+			},
 	}
 
 	keys
@@ -112,6 +122,13 @@ pub fn req_res_receipt_keys<H: Keccak256>(item: RequestResponse) -> Vec<Vec<u8>>
 					derive_unhashed_map_key::<H>(commitment.0.to_vec(), RESPONSE_RECEIPTS_SLOT);
 				keys.push(key.0.to_vec())
 			},
+		RequestResponse::Batch(batches) =>
+			for batch in batches {
+				let commitment = hash_batch::<H>(&batch);
+				let key =
+					derive_unhashed_map_key::<H>(commitment.0.to_vec(), REQUEST_RECEIPTS_SLOT);
+				keys.push(key.0.to_vec())
+			},
 	}
 
 	keys
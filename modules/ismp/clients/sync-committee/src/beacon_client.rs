@@ -199,11 +199,26 @@ impl<
 	fn verify_fraud_proof(
 		&self,
 		_host: &dyn IsmpHost,
-		_trusted_consensus_state: Vec<u8>,
-		_proof_1: Vec<u8>,
-		_proof_2: Vec<u8>,
+		trusted_consensus_state: Vec<u8>,
+		proof_1: Vec<u8>,
+		proof_2: Vec<u8>,
 	) -> Result<(), Error> {
-		Err(Error::Custom("fraud proof verification unimplemented".to_string()))
+		let update_1 = BeaconClientUpdate::decode(&mut &proof_1[..]).map_err(|_| {
+			Error::Custom("Cannot decode beacon client update for proof 1".to_string())
+		})?;
+		let update_2 = BeaconClientUpdate::decode(&mut &proof_2[..]).map_err(|_| {
+			Error::Custom("Cannot decode beacon client update for proof 2".to_string())
+		})?;
+
+		let consensus_state = ConsensusState::decode(&mut &trusted_consensus_state[..])
+			.map_err(|_| Error::Custom("Cannot decode trusted consensus state".to_string()))?;
+
+		sync_committee_verifier::verify_fraud_proof::<C>(
+			&consensus_state.light_client_state,
+			update_1.consensus_update,
+			update_2.consensus_update,
+		)
+		.map_err(|e| Error::Custom(format!("Invalid fraud proof: {e}")))
 	}
 
 	fn consensus_client_id(&self) -> ConsensusClientId {
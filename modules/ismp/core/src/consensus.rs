@@ -22,7 +22,7 @@ use crate::{
 	prelude::Vec,
 	router::RequestResponse,
 };
-use alloc::{boxed::Box, collections::BTreeMap};
+use alloc::{boxed::Box, collections::BTreeMap, format};
 use codec::{Decode, Encode};
 use core::time::Duration;
 use primitive_types::H256;
@@ -155,6 +155,30 @@ pub trait ConsensusClient {
 	/// Return an implementation of a [`StateMachineClient`] for the given state machine.
 	/// NOTE:  Must return an error if the identifier is unknown or risk a critical vulnerability
 	fn state_machine(&self, id: StateMachine) -> Result<Box<dyn StateMachineClient>, Error>;
+
+	/// The version of the consensus state encoding used by this client. It should be bumped
+	/// whenever the encoding changes, alongside a migration in
+	/// [`ConsensusClient::migrate_consensus_state`].
+	fn version(&self) -> u32 {
+		0
+	}
+
+	/// Migrate a consensus state that was encoded by the given earlier version of this client to
+	/// the encoding of the current version.
+	fn migrate_consensus_state(
+		&self,
+		version: u32,
+		consensus_state: Vec<u8>,
+	) -> Result<Vec<u8>, Error> {
+		if version != self.version() {
+			Err(Error::Custom(format!(
+				"Cannot migrate consensus state from version {version} to {}",
+				self.version()
+			)))?
+		}
+
+		Ok(consensus_state)
+	}
 }
 
 /// A state machine client. An abstraction for the mechanism of state proof verification for state
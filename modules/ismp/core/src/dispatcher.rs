@@ -15,7 +15,10 @@
 
 //! Message dispatcher definitions
 
-use crate::{host::StateMachine, router::PostResponse};
+use crate::{
+	host::StateMachine,
+	router::{BatchItem, PostResponse},
+};
 use alloc::vec::Vec;
 use codec::{Decode, Encode};
 use primitive_types::H256;
@@ -52,6 +55,20 @@ pub struct DispatchGet {
 	pub timeout: u64,
 }
 
+/// Simplified POST batch, intended to be used for sending many small messages to the same
+/// destination state machine under a single commitment
+#[derive(Clone)]
+pub struct DispatchBatch {
+	/// The destination state machine of this batch.
+	pub dest: StateMachine,
+	/// Module identifier of the sending module
+	pub from: Vec<u8>,
+	/// Relative from the current timestamp at which this batch expires in seconds.
+	pub timeout: u64,
+	/// The messages in this batch
+	pub items: Vec<BatchItem>,
+}
+
 /// Simplified request, intended to be used for sending outgoing requests
 #[derive(Clone)]
 pub enum DispatchRequest {
@@ -59,6 +76,8 @@ pub enum DispatchRequest {
 	Post(DispatchPost),
 	/// The GET variant
 	Get(DispatchGet),
+	/// The POST batch variant
+	Batch(DispatchBatch),
 }
 
 /// Fee metadata for a dispatched request. Contains the account who paid for the request and how
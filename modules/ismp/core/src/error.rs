@@ -207,6 +207,37 @@ pub enum Error {
 		/// The request metadata
 		meta: Meta,
 	},
+	/// The consensus client for the given consensus state has been disabled
+	ConsensusClientDisabled {
+		/// The consensus state identifier
+		consensus_state_id: ConsensusStateId,
+	},
+	/// The consensus client for the given consensus state has been paused
+	ConsensusClientPaused {
+		/// The consensus state identifier
+		consensus_state_id: ConsensusStateId,
+	},
+	/// The request nonce does not match the next nonce expected on its ordered channel
+	RequestOutOfOrder {
+		/// The request metadata
+		meta: Meta,
+		/// The nonce expected for the next request on the channel
+		expected: u64,
+	},
+	/// The stored consensus state was encoded by a different version of its consensus client
+	ConsensusStateVersionMismatch {
+		/// The consensus state identifier
+		consensus_state_id: ConsensusStateId,
+		/// The version of the client that encoded the consensus state
+		stored: u32,
+		/// The version of the current consensus client
+		expected: u32,
+	},
+	/// The state commitment has been disputed and can't be used until the dispute is resolved
+	StateCommitmentDisputed {
+		/// The height of the state commitment
+		height: StateMachineHeight,
+	},
 }
 
 impl core::fmt::Display for Error {
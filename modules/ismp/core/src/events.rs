@@ -18,7 +18,7 @@
 use crate::{
 	consensus::{StateMachineHeight, StateMachineId},
 	host::StateMachine,
-	router::{GetRequest, GetResponse, PostRequest, PostResponse, Request, Response},
+	router::{GetRequest, GetResponse, PostBatch, PostRequest, PostResponse, Request, Response},
 };
 use alloc::vec::Vec;
 use codec::{Decode, Encode};
@@ -101,6 +101,10 @@ pub enum Event {
 	GetRequestHandled(RequestResponseHandled),
 	/// Emitted when a get request timeout is handled
 	GetRequestTimeoutHandled(TimeoutHandled),
+	/// An event that is emitted when a post request batch is dispatched
+	PostBatch(PostBatch),
+	/// Emitted when a post request batch timeout is handled
+	PostBatchTimeoutHandled(TimeoutHandled),
 }
 
 /// Minimal version of requests and responses
@@ -145,3 +149,9 @@ impl From<Response> for Meta {
 		Self { source: value.source_chain(), dest: value.dest_chain(), nonce: value.nonce() }
 	}
 }
+
+impl From<&PostBatch> for Meta {
+	fn from(value: &PostBatch) -> Self {
+		Self { source: value.source, dest: value.dest, nonce: value.nonce }
+	}
+}
@@ -24,10 +24,15 @@ use crate::{
 
 use crate::{consensus::ConsensusStateId, module::DispatchResult};
 use alloc::{boxed::Box, vec::Vec};
+pub use batch::{
+	handle_incoming_messages, handle_incoming_messages_with, MessageOutcome, MessageStatus,
+};
 pub use consensus::create_client;
 
+mod batch;
 mod consensus;
 mod request;
+mod request_batch;
 mod response;
 mod timeout;
 
@@ -46,7 +51,7 @@ pub enum MessageResult {
 	ConsensusMessage(Vec<Event>),
 	/// Result of freezing a consensus state.
 	FrozenClient(ConsensusStateId),
-	/// The [`DispatchResult`] for requests
+	/// The [`DispatchResult`] for requests, or for every batch of a batch message
 	Request(Vec<DispatchResult>),
 	/// The [`DispatchResult`] for responses
 	Response(Vec<DispatchResult>),
@@ -68,6 +73,7 @@ where
 		Message::Request(req) => request::handle(host, req),
 		Message::Response(resp) => response::handle(host, resp),
 		Message::Timeout(timeout) => timeout::handle(host, timeout),
+		Message::Batch(batch) => request_batch::handle(host, batch),
 	}
 }
 
@@ -106,6 +112,12 @@ where
 	let consensus_client = host.consensus_client(consensus_client_id)?;
 	// Ensure client is not frozen
 	host.is_consensus_client_frozen(proof_height.id.consensus_state_id)?;
+	// Ensure client has not been disabled
+	host.is_consensus_client_disabled(proof_height.id.consensus_state_id)?;
+	// Ensure the state commitment is not disputed by a fisherman
+	if host.is_state_commitment_disputed(proof_height) {
+		Err(Error::StateCommitmentDisputed { height: proof_height })?
+	}
 
 	// Ensure delay period has elapsed
 	if !verify_delay_passed(host, &proof_height)? {
@@ -0,0 +1,175 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ISMP batch handler
+//!
+//! Handles a batch of messages where every message succeeds or fails on its own, so that a single
+//! stale or invalid message does not revert the rest of the batch.
+
+use crate::{
+	error::Error,
+	handlers::{handle_incoming_message, MessageResult},
+	host::IsmpHost,
+	messaging::{ConsensusMessage, Message},
+};
+use alloc::{collections::BTreeSet, vec::Vec};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// The outcome of handling a single message in a batch
+#[derive(Debug)]
+pub enum MessageOutcome {
+	/// The message was handled successfully
+	Success(MessageResult),
+	/// The message has already been processed, either in an earlier block or earlier in the batch
+	AlreadyProcessed,
+	/// The challenge period for the state commitment the message was proven against has not yet
+	/// elapsed, the message can be retried later.
+	ChallengePeriodNotElapsed(anyhow::Error),
+	/// The consensus or state proof of the message could not be verified
+	ProofInvalid(anyhow::Error),
+	/// The message failed for any other reason
+	Failed(anyhow::Error),
+}
+
+/// The encodable status of a [`MessageOutcome`], suitable for events and runtime api responses.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum MessageStatus {
+	/// The message was handled successfully
+	Success,
+	/// The message has already been processed
+	AlreadyProcessed,
+	/// The challenge period has not yet elapsed
+	ChallengePeriodNotElapsed,
+	/// The consensus or state proof of the message could not be verified
+	ProofInvalid,
+	/// The message failed for any other reason
+	Failed,
+}
+
+impl MessageStatus {
+	/// Returns true if the message was handled successfully
+	pub fn is_success(&self) -> bool {
+		matches!(self, MessageStatus::Success)
+	}
+
+	/// Returns true if the message may succeed when it is submitted again at a later time.
+	pub fn is_retriable(&self) -> bool {
+		matches!(self, MessageStatus::ChallengePeriodNotElapsed)
+	}
+}
+
+impl MessageOutcome {
+	/// Returns the encodable status of this outcome
+	pub fn status(&self) -> MessageStatus {
+		match self {
+			MessageOutcome::Success(_) => MessageStatus::Success,
+			MessageOutcome::AlreadyProcessed => MessageStatus::AlreadyProcessed,
+			MessageOutcome::ChallengePeriodNotElapsed(_) =>
+				MessageStatus::ChallengePeriodNotElapsed,
+			MessageOutcome::ProofInvalid(_) => MessageStatus::ProofInvalid,
+			MessageOutcome::Failed(_) => MessageStatus::Failed,
+		}
+	}
+
+	/// Returns true if the message was handled successfully
+	pub fn is_success(&self) -> bool {
+		matches!(self, MessageOutcome::Success(_))
+	}
+
+	/// Returns the error the message failed with, if any
+	pub fn error(&self) -> Option<&anyhow::Error> {
+		match self {
+			MessageOutcome::ChallengePeriodNotElapsed(e) |
+			MessageOutcome::ProofInvalid(e) |
+			MessageOutcome::Failed(e) => Some(e),
+			MessageOutcome::Success(_) | MessageOutcome::AlreadyProcessed => None,
+		}
+	}
+}
+
+impl From<Result<MessageResult, anyhow::Error>> for MessageOutcome {
+	fn from(result: Result<MessageResult, anyhow::Error>) -> Self {
+		let error = match result {
+			Ok(result) => return MessageOutcome::Success(result),
+			Err(error) => error,
+		};
+
+		match error.downcast_ref::<Error>() {
+			Some(Error::DuplicateRequest { .. } | Error::DuplicateResponse { .. }) =>
+				MessageOutcome::AlreadyProcessed,
+			Some(Error::ChallengePeriodNotElapsed { .. }) =>
+				MessageOutcome::ChallengePeriodNotElapsed(error),
+			Some(
+				Error::ConsensusProofVerificationFailed { .. } |
+				Error::RequestVerificationFailed { .. } |
+				Error::ResponseVerificationFailed { .. } |
+				Error::RequestTimeoutVerificationFailed { .. } |
+				Error::MembershipProofVerificationFailed(_) |
+				Error::NonMembershipProofVerificationFailed(_),
+			) => MessageOutcome::ProofInvalid(error),
+			_ => MessageOutcome::Failed(error),
+		}
+	}
+}
+
+/// Handles a batch of messages, returning an outcome for each message in the order they were
+/// provided. Messages are handled in order, so consensus updates placed before the requests they
+/// prove will be applied first. Identical consensus updates within the batch are only verified
+/// once, the duplicates are reported as [`MessageOutcome::AlreadyProcessed`].
+///
+/// Unlike [`handle_incoming_message`], a failing message does not affect the other messages in
+/// the batch. The dispatch results of individual requests, responses and timeouts are reported
+/// in the [`MessageResult`] of a successful message.
+pub fn handle_incoming_messages<H>(host: &H, messages: Vec<Message>) -> Vec<MessageOutcome>
+where
+	H: IsmpHost,
+{
+	handle_incoming_messages_with(host, messages, handle_incoming_message)
+}
+
+/// Like [`handle_incoming_messages`], but every message is handled by the provided closure. This
+/// allows hosts to isolate the side effects of each message, for instance by running the
+/// handler in a storage transaction that is rolled back when it fails.
+pub fn handle_incoming_messages_with<H, F>(
+	host: &H,
+	messages: Vec<Message>,
+	mut handler: F,
+) -> Vec<MessageOutcome>
+where
+	H: IsmpHost,
+	F: FnMut(&H, Message) -> Result<MessageResult, anyhow::Error>,
+{
+	let mut consensus_updates = BTreeSet::new();
+	messages
+		.into_iter()
+		.map(|message| {
+			if let Message::Consensus(ConsensusMessage {
+				consensus_state_id,
+				ref consensus_proof,
+				..
+			}) = message
+			{
+				let update = (consensus_state_id, H::keccak256(consensus_proof));
+				if !consensus_updates.insert(update) {
+					return MessageOutcome::AlreadyProcessed;
+				}
+			}
+
+			handler(host, message).into()
+		})
+		.collect()
+}
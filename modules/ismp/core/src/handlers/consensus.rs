@@ -16,7 +16,7 @@
 //! The ISMP consensus handler
 
 use crate::{
-	consensus::{StateMachineHeight, StateMachineId},
+	consensus::{ConsensusClient, ConsensusStateId, StateMachineHeight, StateMachineId},
 	error::Error,
 	events::{Event, StateMachineUpdated},
 	handlers::{ConsensusClientCreatedResult, MessageResult},
@@ -35,7 +35,10 @@ where
 	)?;
 	let consensus_client = host.consensus_client(consensus_client_id)?;
 	let trusted_state = host.consensus_state(msg.consensus_state_id)?;
+	check_consensus_state_version(host, msg.consensus_state_id, &*consensus_client)?;
 	host.is_consensus_client_frozen(msg.consensus_state_id)?;
+	host.is_consensus_client_disabled(msg.consensus_state_id)?;
+	host.is_consensus_client_paused(msg.consensus_state_id)?;
 	host.is_expired(msg.consensus_state_id)?;
 
 	let (new_state, intermediate_states) = consensus_client.verify_consensus(
@@ -135,6 +138,7 @@ where
 
 	let consensus_client = host.consensus_client(consensus_client_id)?;
 	let trusted_state = host.consensus_state(msg.consensus_state_id)?;
+	check_consensus_state_version(host, msg.consensus_state_id, &*consensus_client)?;
 
 	consensus_client.verify_fraud_proof(host, trusted_state, msg.proof_1, msg.proof_2)?;
 
@@ -144,3 +148,23 @@ where
 
 	Ok(MessageResult::FrozenClient(msg.consensus_state_id))
 }
+
+/// Ensure the stored consensus state was encoded by the current version of its consensus client
+/// before it is decoded. Outdated consensus states have to be migrated first.
+fn check_consensus_state_version<H>(
+	host: &H,
+	consensus_state_id: ConsensusStateId,
+	consensus_client: &dyn ConsensusClient,
+) -> Result<(), Error>
+where
+	H: IsmpHost,
+{
+	if let Some(stored) = host.consensus_state_version(consensus_state_id) {
+		let expected = consensus_client.version();
+		if stored != expected {
+			Err(Error::ConsensusStateVersionMismatch { consensus_state_id, stored, expected })?
+		}
+	}
+
+	Ok(())
+}
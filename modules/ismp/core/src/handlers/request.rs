@@ -21,11 +21,19 @@ use crate::{
 	handlers::{validate_state_machine, MessageResult},
 	host::{IsmpHost, StateMachine},
 	messaging::{hash_request, RequestMessage},
-	router::{Request, RequestResponse},
+	module::{ModuleError, ModuleErrorCode},
+	router::{ChannelId, Request, RequestResponse},
 };
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 
 /// Validate the state machine, verify the request message and dispatch the message to the modules
+///
+/// Requests that have already been delivered are skipped, the message is only rejected as a
+/// duplicate if every request in it has already been delivered.
+///
+/// Requests on ordered channels must carry the nonce expected next on their channel, taking the
+/// earlier requests in the message into account. A request on an ordered channel is not delivered
+/// if an earlier request on the same channel failed in its module callback.
 pub fn handle<H>(host: &H, msg: RequestMessage) -> Result<MessageResult, anyhow::Error>
 where
 	H: IsmpHost,
@@ -41,13 +49,21 @@ where
 	};
 
 	let router = host.ismp_router();
-	for req in msg.requests.iter() {
-		let req = Request::Post(req.clone());
-		// If a receipt exists for any request then it's a duplicate and it is not dispatched
-		if host.request_receipt(&req).is_some() {
-			Err(Error::DuplicateRequest { meta: req.clone().into() })?
+	// Requests that already have a receipt are duplicates and are not dispatched again
+	let (duplicates, requests): (Vec<_>, Vec<_>) = msg
+		.requests
+		.iter()
+		.cloned()
+		.partition(|post| host.request_receipt(&Request::Post(post.clone())).is_some());
+	if requests.is_empty() {
+		if let Some(post) = duplicates.into_iter().next() {
+			Err(Error::DuplicateRequest { meta: Request::Post(post).into() })?
 		}
+	}
 
+	let mut channel_nonces = BTreeMap::new();
+	for post in requests.iter() {
+		let req = Request::Post(post.clone());
 		// can't dispatch timed out requests
 		if req.timed_out(host.timestamp()) {
 			Err(Error::RequestTimeout { meta: req.clone().into() })?
@@ -68,27 +84,54 @@ where
 		{
 			Err(Error::RequestProxyProhibited { meta: req.clone().into() })?
 		}
+
+		// requests on ordered channels must be delivered in the order they were sent
+		let channel = ChannelId::from(post);
+		let expected = channel_nonces
+			.get(&channel)
+			.copied()
+			.or_else(|| host.next_channel_nonce(&channel));
+		if let Some(expected) = expected {
+			if post.nonce != expected {
+				Err(Error::RequestOutOfOrder { meta: req.clone().into(), expected })?
+			}
+			channel_nonces.insert(channel, expected + 1);
+		}
 	}
 
-	// Verify membership proof
+	// Verify membership proof, the proof is over every request in the message
 
 	let state = host.state_machine_commitment(msg.proof.height)?;
 	state_machine.verify_membership(
 		host,
-		RequestResponse::Request(msg.requests.clone().into_iter().map(Request::Post).collect()),
+		RequestResponse::Request(msg.requests.into_iter().map(Request::Post).collect()),
 		state,
 		&msg.proof,
 	)?;
 
-	let result = msg
-		.requests
+	let result = requests
 		.into_iter()
 		.map(|request| {
 			let wrapped_req = Request::Post(request.clone());
-			let lambda = || {
-				let cb = router.module_for_id(request.to.clone())?;
+			let lambda = || -> Result<Event, ModuleError> {
+				let internal_error =
+					|e: Error| ModuleError::new(ModuleErrorCode::Internal, request.to.clone(), e);
+				let cb = router.module_for_id(request.to.clone()).map_err(|e| {
+					ModuleError::new(ModuleErrorCode::ModuleNotFound, request.to.clone(), e)
+				})?;
+				// An earlier request on an ordered channel may have failed in its module callback
+				let channel = ChannelId::from(&request);
+				let ordered = match host.next_channel_nonce(&channel) {
+					Some(expected) if expected != request.nonce => Err(ModuleError::new(
+						ModuleErrorCode::InvalidRequest,
+						request.to.clone(),
+						Error::RequestOutOfOrder { meta: wrapped_req.clone().into(), expected },
+					))?,
+					Some(_) => true,
+					None => false,
+				};
 				// Store request receipt to prevent reentrancy attack
-				host.store_request_receipt(&wrapped_req, &msg.signer)?;
+				host.store_request_receipt(&wrapped_req, &msg.signer).map_err(internal_error)?;
 				let res = cb.on_accept(request.clone()).map(|_| {
 					let commitment = hash_request::<H>(&wrapped_req);
 					Event::PostRequestHandled(RequestResponseHandled {
@@ -98,13 +141,15 @@ where
 				});
 				// Delete receipt if module callback failed so it can be timed out
 				if res.is_err() {
-					host.delete_request_receipt(&wrapped_req)?;
+					host.delete_request_receipt(&wrapped_req).map_err(internal_error)?;
+				} else if ordered {
+					host.store_next_channel_nonce(&channel, request.nonce + 1)
+						.map_err(internal_error)?;
 				}
-				Ok(res)
+				res
 			};
 
-			let res = lambda().and_then(|res| res);
-			res
+			lambda().map_err(|e| e.with_meta(&wrapped_req))
 		})
 		.collect::<Vec<_>>();
 
@@ -0,0 +1,160 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ISMP request batch handler
+
+use crate::{
+	error::Error,
+	events::{Event, Meta, RequestResponseHandled},
+	handlers::{validate_state_machine, MessageResult},
+	host::{IsmpHost, StateMachine},
+	messaging::{hash_batch, BatchMessage},
+	module::{ModuleError, ModuleErrorCode},
+	router::{ChannelId, RequestResponse},
+};
+use alloc::{
+	collections::{BTreeMap, BTreeSet},
+	vec::Vec,
+};
+
+/// Validate the state machine, verify the batch message and deliver every batch to the modules.
+///
+/// The items of a batch are routed to their modules in order and delivery stops at the first
+/// item that fails, in which case the batch result is the error of that item. Hosts must revert
+/// the side effects of a failed batch, for instance by handling the message in a storage
+/// transaction, for the batch to be delivered atomically.
+///
+/// Every item is on the channel of its receiving module and carries the nonce of its batch. The
+/// same ordering rules as for requests apply to the items on ordered channels.
+pub fn handle<H>(host: &H, msg: BatchMessage) -> Result<MessageResult, anyhow::Error>
+where
+	H: IsmpHost,
+{
+	let signer = msg.signer.clone();
+	let state_machine = validate_state_machine(host, msg.proof.height)?;
+	let consensus_clients = host.consensus_clients();
+	let check_state_machine_client = |state_machine: StateMachine| {
+		consensus_clients
+			.iter()
+			.find_map(|client| client.state_machine(state_machine).ok())
+			.is_none()
+	};
+
+	let mut channel_nonces = BTreeMap::new();
+	for batch in msg.batches.iter() {
+		let meta = Meta::from(batch);
+		// If a receipt exists for any batch then it's a duplicate and it is not delivered
+		if host.batch_receipt(batch).is_some() {
+			Err(Error::DuplicateRequest { meta: meta.clone() })?
+		}
+
+		// can't deliver timed out batches
+		if batch.timed_out(host.timestamp()) {
+			Err(Error::RequestTimeout { meta: meta.clone() })?
+		}
+
+		// either the host is a router and can accept batches on behalf of any chain
+		// or the batch must be intended for this chain
+		if batch.dest != host.host_state_machine() && !host.is_router() {
+			Err(Error::InvalidRequestDestination { meta: meta.clone() })?
+		}
+
+		// check if the source chain does not match the proof metadata in which case
+		// the proof metadata must be the configured proxy
+		// and we must not have a configured state machine client for the destination
+		if batch.source != msg.proof.height.id.state_id &&
+			!(host.is_allowed_proxy(&msg.proof.height.id.state_id) &&
+				check_state_machine_client(batch.source))
+		{
+			Err(Error::RequestProxyProhibited { meta: meta.clone() })?
+		}
+
+		// batches on ordered channels must be delivered in the order they were sent
+		let channels = batch.requests().iter().map(ChannelId::from).collect::<BTreeSet<_>>();
+		for channel in channels {
+			let expected = channel_nonces
+				.get(&channel)
+				.copied()
+				.or_else(|| host.next_channel_nonce(&channel));
+			if let Some(expected) = expected {
+				if batch.nonce != expected {
+					Err(Error::RequestOutOfOrder { meta: meta.clone(), expected })?
+				}
+				channel_nonces.insert(channel, expected + 1);
+			}
+		}
+	}
+
+	// Verify membership proof
+	let state = host.state_machine_commitment(msg.proof.height)?;
+	state_machine.verify_membership(
+		host,
+		RequestResponse::Batch(msg.batches.clone()),
+		state,
+		&msg.proof,
+	)?;
+
+	let router = host.ismp_router();
+	let mut results = Vec::with_capacity(msg.batches.len());
+	for batch in msg.batches {
+		// An earlier batch on an ordered channel may have failed in a module callback
+		let requests = batch.requests();
+		let ordered = requests
+			.iter()
+			.map(ChannelId::from)
+			.filter_map(|channel| host.next_channel_nonce(&channel).map(|next| (channel, next)))
+			.collect::<BTreeMap<_, _>>();
+		let out_of_order = ordered.iter().find(|(_, expected)| batch.nonce != **expected);
+		if let Some((channel, expected)) = out_of_order {
+			results.push(Err(ModuleError::new(
+				ModuleErrorCode::InvalidRequest,
+				channel.module.clone(),
+				Error::RequestOutOfOrder { meta: Meta::from(&batch), expected: *expected },
+			)
+			.with_meta(&batch)));
+			continue;
+		}
+
+		// Store batch receipt to prevent reentrancy attack
+		host.store_batch_receipt(&batch, &signer)?;
+		let delivered = requests.into_iter().try_for_each(|request| {
+			let cb = router.module_for_id(request.to.clone()).map_err(|e| {
+				ModuleError::new(ModuleErrorCode::ModuleNotFound, request.to.clone(), e)
+			})?;
+			cb.on_accept(request)
+		});
+		let delivered = delivered.map_err(|e| e.with_meta(&batch));
+
+		let result = match delivered {
+			Ok(()) => {
+				for channel in ordered.into_keys() {
+					host.store_next_channel_nonce(&channel, batch.nonce + 1)?;
+				}
+				Ok(Event::PostRequestHandled(RequestResponseHandled {
+					commitment: hash_batch::<H>(&batch),
+					relayer: signer.clone(),
+				}))
+			},
+			Err(err) => {
+				// Delete receipt if any item failed, the batch was not delivered
+				host.delete_batch_receipt(&batch)?;
+				Err(err)
+			},
+		};
+		results.push(result);
+	}
+
+	Ok(MessageResult::Request(results))
+}
@@ -23,7 +23,7 @@ use crate::{
 	messaging::{hash_request, hash_response, ResponseMessage},
 	router::{GetResponse, Request, RequestResponse, Response, StorageValue},
 };
-use alloc::{vec, vec::Vec};
+use alloc::{string::ToString, vec, vec::Vec};
 
 /// Validate the state machine, verify the response message and dispatch the message to the modules
 pub fn handle<H>(host: &H, msg: ResponseMessage) -> Result<MessageResult, anyhow::Error>
@@ -89,13 +89,16 @@ where
 					let cb = router.module_for_id(response.destination_module())?;
 					// Store response receipt to prevent reentrancy attack
 					host.store_response_receipt(&response, &msg.signer)?;
-					let res = cb.on_response(response.clone()).map(|_| {
-						let commitment = hash_response::<H>(&response);
-						Event::PostResponseHandled(RequestResponseHandled {
-							commitment,
-							relayer: signer.clone(),
-						})
-					});
+					let res = cb
+						.on_response(response.clone())
+						.map_err(|e| e.with_meta(&response))
+						.map(|_| {
+							let commitment = hash_response::<H>(&response);
+							Event::PostResponseHandled(RequestResponseHandled {
+								commitment,
+								relayer: signer.clone(),
+							})
+						});
 					// Delete receipt if module callback failed so it can be timed out
 					if res.is_err() {
 						host.delete_response_receipt(&response)?;
@@ -162,6 +165,7 @@ where
 					host.store_response_receipt(&response, &msg.signer)?;
 					let res = cb
 						.on_response(Response::Get(GetResponse { get: request.clone(), values }))
+						.map_err(|e| e.with_meta(&wrapped_req))
 						.map(|_| {
 							let commitment = hash_request::<H>(&wrapped_req);
 							Event::GetRequestHandled(RequestResponseHandled {
@@ -177,6 +181,8 @@ where
 				})
 				.collect::<Result<Vec<_>, _>>()?
 		},
+		RequestResponse::Batch(_) =>
+			Err(Error::Custom("Request batches must be delivered in a batch message".to_string()))?,
 	};
 
 	Ok(MessageResult::Response(result))
@@ -20,8 +20,8 @@ use crate::{
 	events::{Event, TimeoutHandled},
 	handlers::{validate_state_machine, MessageResult},
 	host::{IsmpHost, StateMachine},
-	messaging::{hash_post_response, hash_request, TimeoutMessage},
-	router::Response,
+	messaging::{hash_batch, hash_post_response, hash_request, TimeoutMessage},
+	router::{Request, RequestResponse, Response},
 };
 use alloc::vec::Vec;
 
@@ -88,14 +88,17 @@ where
 					if host.host_state_machine() != request.source_chain() {
 						signer = host.delete_request_receipt(&request).ok();
 					}
-					let res = cb.on_timeout(request.clone().into()).map(|_| {
-						let commitment = hash_request::<H>(&request);
-						Event::PostRequestTimeoutHandled(TimeoutHandled {
-							commitment,
-							source: request.source_chain(),
-							dest: request.dest_chain(),
-						})
-					});
+					let res = cb
+						.on_timeout(request.clone().into())
+						.map_err(|e| e.with_meta(&request))
+						.map(|_| {
+							let commitment = hash_request::<H>(&request);
+							Event::PostRequestTimeoutHandled(TimeoutHandled {
+								commitment,
+								source: request.source_chain(),
+								dest: request.dest_chain(),
+							})
+						});
 					// If module callback failed restore commitment so it can be retried
 					if res.is_err() {
 						host.store_request_commitment(&request, meta)?;
@@ -158,14 +161,17 @@ where
 						signer =
 							host.delete_response_receipt(&Response::Post(response.clone())).ok();
 					}
-					let res = cb.on_timeout(response.clone().into()).map(|_| {
-						let commitment = hash_post_response::<H>(&response);
-						Event::PostResponseTimeoutHandled(TimeoutHandled {
-							commitment,
-							source: response.source_chain(),
-							dest: response.dest_chain(),
-						})
-					});
+					let res = cb
+						.on_timeout(response.clone().into())
+						.map_err(|e| e.with_meta(&response))
+						.map(|_| {
+							let commitment = hash_post_response::<H>(&response);
+							Event::PostResponseTimeoutHandled(TimeoutHandled {
+								commitment,
+								source: response.source_chain(),
+								dest: response.dest_chain(),
+							})
+						});
 					// If module callback failed restore commitment so it can be retried
 					if res.is_err() {
 						host.store_response_commitment(&response, meta)?;
@@ -206,14 +212,17 @@ where
 					let cb = router.module_for_id(request.source_module())?;
 					// Delete commitment to prevent reentrancy
 					let meta = host.delete_request_commitment(&request)?;
-					let res = cb.on_timeout(request.clone().into()).map(|_| {
-						let commitment = hash_request::<H>(&request);
-						Event::GetRequestTimeoutHandled(TimeoutHandled {
-							commitment,
-							source: request.source_chain(),
-							dest: request.dest_chain(),
-						})
-					});
+					let res = cb
+						.on_timeout(request.clone().into())
+						.map_err(|e| e.with_meta(&request))
+						.map(|_| {
+							let commitment = hash_request::<H>(&request);
+							Event::GetRequestTimeoutHandled(TimeoutHandled {
+								commitment,
+								source: request.source_chain(),
+								dest: request.dest_chain(),
+							})
+						});
 					// If module callback failed, restore commitment so it can be retried
 					if res.is_err() {
 						host.store_request_commitment(&request, meta)?;
@@ -222,6 +231,69 @@ where
 				})
 				.collect::<Result<Vec<_>, _>>()?
 		},
+		TimeoutMessage::PostBatch { batches, timeout_proof } => {
+			let state_machine = validate_state_machine(host, timeout_proof.height)?;
+			let state = host.state_machine_commitment(timeout_proof.height)?;
+
+			for batch in &batches {
+				if batch.dest != timeout_proof.height.id.state_id &&
+					!(host.is_allowed_proxy(&timeout_proof.height.id.state_id) &&
+						check_state_machine_client(batch.dest))
+				{
+					Err(Error::RequestProxyProhibited { meta: batch.into() })?
+				}
+
+				// Ensure a commitment exists for all batches
+				let commitment = hash_batch::<H>(batch);
+				if host.request_commitment(commitment).is_err() {
+					Err(Error::UnknownRequest { meta: batch.into() })?
+				}
+
+				if !batch.timed_out(state.timestamp()) {
+					Err(Error::RequestTimeoutNotElapsed {
+						meta: batch.into(),
+						timeout_timestamp: batch.timeout(),
+						state_machine_time: state.timestamp(),
+					})?
+				}
+			}
+
+			let keys =
+				state_machine.receipts_state_trie_key(RequestResponse::Batch(batches.clone()));
+			let values = state_machine.verify_state_proof(host, keys, state, &timeout_proof)?;
+			if values.into_iter().any(|(_key, val)| val.is_some()) {
+				Err(Error::Custom("Some batches in the message have been delivered".into()))?
+			}
+
+			let router = host.ismp_router();
+			batches
+				.into_iter()
+				.map(|batch| {
+					let cb = router.module_for_id(batch.from.clone())?;
+					// Delete commitment to prevent rentrancy attack
+					let meta = host.delete_batch_commitment(&batch)?;
+					// Every item of the batch is timed out, the batch is only timed out if the
+					// sending module handles all of them
+					let res = batch
+						.requests()
+						.into_iter()
+						.try_for_each(|request| cb.on_timeout(Request::Post(request).into()))
+						.map_err(|e| e.with_meta(&batch))
+						.map(|_| {
+							Event::PostBatchTimeoutHandled(TimeoutHandled {
+								commitment: hash_batch::<H>(&batch),
+								source: batch.source,
+								dest: batch.dest,
+							})
+						});
+					// If module callback failed restore commitment so it can be retried
+					if res.is_err() {
+						host.store_batch_commitment(&batch, meta)?;
+					}
+					Ok::<_, anyhow::Error>(res)
+				})
+				.collect::<Result<Vec<_>, _>>()?
+		},
 	};
 
 	Ok(MessageResult::Timeout(results))
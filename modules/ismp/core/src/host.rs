@@ -23,7 +23,7 @@ use crate::{
 	error::Error,
 	messaging::Keccak256,
 	prelude::Vec,
-	router::{IsmpRouter, PostResponse, Request, Response},
+	router::{ChannelId, IsmpRouter, PostBatch, PostResponse, Request, Response},
 };
 use alloc::{boxed::Box, format, string::String};
 use codec::{Decode, Encode};
@@ -74,6 +74,32 @@ pub trait IsmpHost: Keccak256 {
 	fn is_consensus_client_frozen(&self, consensus_state_id: ConsensusStateId)
 		-> Result<(), Error>;
 
+	/// Checks if the consensus client of a consensus state has been disabled, should return
+	/// Ok(()) if it isn't or [`Error::ConsensusClientDisabled`] if it is. Disabled clients can
+	/// neither be updated nor used to verify messages.
+	fn is_consensus_client_disabled(
+		&self,
+		_consensus_state_id: ConsensusStateId,
+	) -> Result<(), Error> {
+		Ok(())
+	}
+
+	/// Checks if the consensus client of a consensus state has been paused, should return Ok(())
+	/// if it isn't or [`Error::ConsensusClientPaused`] if it is. Paused clients cannot be updated,
+	/// but the state commitments they have already verified can still be used.
+	fn is_consensus_client_paused(
+		&self,
+		_consensus_state_id: ConsensusStateId,
+	) -> Result<(), Error> {
+		Ok(())
+	}
+
+	/// Returns the version of the consensus client that encoded the stored consensus state, or
+	/// `None` if the host does not track consensus state versions.
+	fn consensus_state_version(&self, _consensus_state_id: ConsensusStateId) -> Option<u32> {
+		None
+	}
+
 	/// Should return an error if request commitment does not exist in storage
 	fn request_commitment(&self, req: H256) -> Result<(), Error>;
 
@@ -90,6 +116,9 @@ pub trait IsmpHost: Keccak256 {
 	/// Implementors should store both the request and response objects
 	fn response_receipt(&self, res: &Response) -> Option<()>;
 
+	/// Should return Some(()) if a receipt for this request batch exists in storage
+	fn batch_receipt(&self, batch: &PostBatch) -> Option<()>;
+
 	/// Store a map of consensus_state_id to the consensus_client_id
 	/// Should return an error if the consensus_state_id already exists
 	fn store_consensus_state_id(
@@ -153,6 +182,10 @@ pub trait IsmpHost: Keccak256 {
 	/// Returns the scale encoded commitment metadata
 	fn delete_response_commitment(&self, res: &PostResponse) -> Result<Vec<u8>, Error>;
 
+	/// Delete a request batch commitment from storage, used when a batch is timed out.
+	/// Returns the scale encoded commitment metadata
+	fn delete_batch_commitment(&self, batch: &PostBatch) -> Result<Vec<u8>, Error>;
+
 	/// Delete a request receipt from storage, used when a request is timed out.
 	/// Should only ever be called by a routing state machine
 	/// Returns the signer
@@ -163,10 +196,18 @@ pub trait IsmpHost: Keccak256 {
 	/// Returns the signer
 	fn delete_response_receipt(&self, res: &Response) -> Result<Vec<u8>, Error>;
 
+	/// Delete a request batch receipt from storage, used when a batch could not be delivered.
+	/// Returns the signer
+	fn delete_batch_receipt(&self, batch: &PostBatch) -> Result<Vec<u8>, Error>;
+
 	/// Stores a receipt for an incoming request after it is successfully routed to a module.
 	/// Prevents duplicate incoming requests from being processed. Includes the relayer account
 	fn store_request_receipt(&self, req: &Request, signer: &Vec<u8>) -> Result<(), Error>;
 
+	/// Stores a receipt for an incoming request batch. Prevents duplicate incoming batches from
+	/// being processed. Includes the relayer account
+	fn store_batch_receipt(&self, batch: &PostBatch, signer: &Vec<u8>) -> Result<(), Error>;
+
 	/// Stores a receipt that shows that the given request has received a response. Includes the
 	/// relayer account
 	/// Implementors should map the request commitment to the response object commitment.
@@ -178,6 +219,9 @@ pub trait IsmpHost: Keccak256 {
 	/// Stores a commitment for an outgoing response alongside some scale encoded metadata
 	fn store_response_commitment(&self, res: &PostResponse, meta: Vec<u8>) -> Result<(), Error>;
 
+	/// Stores a commitment for an outgoing request batch alongside some scale encoded metadata
+	fn store_batch_commitment(&self, batch: &PostBatch, meta: Vec<u8>) -> Result<(), Error>;
+
 	/// Should return a handle to the consensus client based on the id
 	fn consensus_client(&self, id: ConsensusClientId) -> Result<Box<dyn ConsensusClient>, Error> {
 		self.consensus_clients()
@@ -234,6 +278,35 @@ pub trait IsmpHost: Keccak256 {
 			.map(|proxy| proxy == self.host_state_machine())
 			.unwrap_or(false)
 	}
+
+	/// Returns the nonce of the next request expected on the channel if the channel is ordered.
+	/// Requests on channels that are not ordered may be delivered in any order, which is the
+	/// default.
+	fn next_channel_nonce(&self, _channel: &ChannelId) -> Option<u64> {
+		None
+	}
+
+	/// Stores the nonce of the next request expected on an ordered channel, after a request on
+	/// the channel has been delivered.
+	fn store_next_channel_nonce(&self, _channel: &ChannelId, _nonce: u64) -> Result<(), Error> {
+		Err(Error::Custom("Ordered channels are not supported by this host".into()))
+	}
+
+	/// Returns true if the state commitment at the height has been disputed, in which case it
+	/// can't be used to verify proofs until the dispute is resolved. State commitments are not
+	/// disputed by default.
+	fn is_state_commitment_disputed(&self, _height: StateMachineHeight) -> bool {
+		false
+	}
+
+	/// Marks the state commitment at the height as disputed, or resolves its dispute.
+	fn store_state_commitment_disputed(
+		&self,
+		_height: StateMachineHeight,
+		_disputed: bool,
+	) -> Result<(), Error> {
+		Err(Error::Custom("Disputing state commitments is not supported by this host".into()))
+	}
 }
 
 /// Currently supported state machines.
@@ -268,6 +341,10 @@ pub enum StateMachine {
 	/// Tendermint chains
 	#[codec(index = 4)]
 	Tendermint(ConsensusStateId),
+	/// Chains identified by a variable-length chain id, such as Cosmos chains (`osmosis-1`) or
+	/// other non-EVM families like Solana and Move based chains.
+	#[codec(index = 5)]
+	Generic(ChainId),
 }
 
 impl StateMachine {
@@ -297,80 +374,343 @@ impl Display for StateMachine {
 			},
 			StateMachine::Polkadot(id) => format!("POLKADOT-{id}"),
 			StateMachine::Kusama(id) => format!("KUSAMA-{id}"),
-			StateMachine::Substrate(id) => {
-				format!(
-					"SUBSTRATE-{}",
-					String::from_utf8(id.to_vec()).map_err(|_| core::fmt::Error)?
-				)
-			},
-			StateMachine::Tendermint(id) => format!(
-				"TNDRMINT-{}",
-				String::from_utf8(id.to_vec()).map_err(|_| core::fmt::Error)?
-			),
+			StateMachine::Substrate(id) => format!("SUBSTRATE-{}", format_fixed_id(id)),
+			StateMachine::Tendermint(id) => format!("TNDRMINT-{}", format_fixed_id(id)),
+			StateMachine::Generic(id) => format!("GENERIC-{id}"),
 		};
 		write!(f, "{}", str)
 	}
 }
 
+/// Errors encountered when parsing a [`StateMachine`] from its string representation.
+///
+/// The canonical grammar is `<PREFIX>-<id>`, where the prefix determines the id:
+/// - `EVM-`, `POLKADOT-` and `KUSAMA-` take a decimal `u32` without sign or leading zeros.
+/// - `SUBSTRATE-` and `TNDRMINT-` take exactly 4 bytes. Ids that are not valid UTF-8 are written as
+///   `0x` followed by their lowercase hex encoding instead.
+/// - `GENERIC-` takes a [`ChainId`], which may itself contain dashes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateMachineParseError {
+	/// The string does not start with a known state machine prefix
+	UnknownPrefix(String),
+	/// The id is not a canonical decimal `u32`
+	InvalidNumericId(String),
+	/// The id does not have the expected length in bytes
+	InvalidIdLength {
+		/// The expected length
+		expected: usize,
+		/// The length of the supplied id
+		found: usize,
+	},
+	/// The id is not the lowercase hex encoding of 4 bytes that are not valid UTF-8
+	InvalidHexId(String),
+	/// The chain id of a `GENERIC-` state machine is invalid
+	InvalidChainId(ChainIdError),
+}
+
+impl Display for StateMachineParseError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			StateMachineParseError::UnknownPrefix(name) =>
+				write!(f, "Unknown state machine: {name}"),
+			StateMachineParseError::InvalidNumericId(id) =>
+				write!(f, "Invalid state machine id {id:?}, expected a decimal u32"),
+			StateMachineParseError::InvalidIdLength { expected, found } =>
+				write!(f, "Invalid state machine id length {found}, expected {expected} bytes"),
+			StateMachineParseError::InvalidHexId(id) =>
+				write!(f, "Invalid state machine id {id:?}, expected the hex encoding of 4 bytes"),
+			StateMachineParseError::InvalidChainId(err) => write!(f, "Invalid chain id: {err}"),
+		}
+	}
+}
+
+impl From<ChainIdError> for StateMachineParseError {
+	fn from(value: ChainIdError) -> Self {
+		StateMachineParseError::InvalidChainId(value)
+	}
+}
+
+/// Parses a decimal `u32` without sign or leading zeros, so that it displays as the same string.
+fn parse_numeric_id(id: &str) -> Result<u32, StateMachineParseError> {
+	let is_canonical = !id.is_empty() &&
+		id.bytes().all(|byte| byte.is_ascii_digit()) &&
+		(id == "0" || !id.starts_with('0'));
+
+	is_canonical
+		.then(|| u32::from_str(id).ok())
+		.flatten()
+		.ok_or_else(|| StateMachineParseError::InvalidNumericId(id.into()))
+}
+
+/// Formats an id of 4 bytes, ids that are not valid UTF-8 are hex encoded
+fn format_fixed_id(id: &ConsensusStateId) -> String {
+	match core::str::from_utf8(id) {
+		Ok(id) => id.into(),
+		Err(_) => format!("0x{}", hex::encode(id)),
+	}
+}
+
+/// Parses an id of exactly 4 bytes, or the hex encoding of 4 bytes that are not valid UTF-8
+fn parse_fixed_id(id: &str) -> Result<ConsensusStateId, StateMachineParseError> {
+	if let Ok(bytes) = id.as_bytes().try_into() {
+		return Ok(bytes)
+	}
+
+	let Some(hex_id) = id.strip_prefix("0x") else {
+		return Err(StateMachineParseError::InvalidIdLength { expected: 4, found: id.len() })
+	};
+	let mut bytes = [0u8; 4];
+	hex::decode_to_slice(hex_id, &mut bytes)
+		.ok()
+		// ids that are valid UTF-8 or use uppercase hex have another canonical form
+		.filter(|_| format_fixed_id(&bytes) == id)
+		.map(|_| bytes)
+		.ok_or_else(|| StateMachineParseError::InvalidHexId(id.into()))
+}
+
 impl FromStr for StateMachine {
-	type Err = String;
+	type Err = StateMachineParseError;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let s = match s {
-			name if name.starts_with("EVM-") => {
-				let id = name
-					.split('-')
-					.last()
-					.and_then(|id| u32::from_str(id).ok())
-					.ok_or_else(|| format!("invalid state machine: {name}"))?;
-				StateMachine::Evm(id)
-			},
-			name if name.starts_with("POLKADOT-") => {
-				let id = name
-					.split('-')
-					.last()
-					.and_then(|id| u32::from_str(id).ok())
-					.ok_or_else(|| format!("invalid state machine: {name}"))?;
-				StateMachine::Polkadot(id)
-			},
-			name if name.starts_with("KUSAMA-") => {
-				let id = name
-					.split('-')
-					.last()
-					.and_then(|id| u32::from_str(id).ok())
-					.ok_or_else(|| format!("invalid state machine: {name}"))?;
-				StateMachine::Kusama(id)
-			},
-			name if name.starts_with("SUBSTRATE-") => {
-				let name = name
-					.split('-')
-					.last()
-					.ok_or_else(|| format!("invalid state machine: {name}"))?;
-				let mut id = [0u8; 4];
-				id.copy_from_slice(name.as_bytes());
-				StateMachine::Substrate(id)
-			},
-			name if name.starts_with("TNDRMINT-") => {
-				let name = name
-					.split('-')
-					.last()
-					.ok_or_else(|| format!("invalid state machine: {name}"))?;
-				let mut id = [0u8; 4];
-				id.copy_from_slice(name.as_bytes());
-				StateMachine::Tendermint(id)
-			},
-			name => Err(format!("Unknown state machine: {name}"))?,
+		let (prefix, id) = s
+			.split_once('-')
+			.ok_or_else(|| StateMachineParseError::UnknownPrefix(s.into()))?;
+
+		let state_machine = match prefix {
+			"EVM" => StateMachine::Evm(parse_numeric_id(id)?),
+			"POLKADOT" => StateMachine::Polkadot(parse_numeric_id(id)?),
+			"KUSAMA" => StateMachine::Kusama(parse_numeric_id(id)?),
+			"SUBSTRATE" => StateMachine::Substrate(parse_fixed_id(id)?),
+			"TNDRMINT" => StateMachine::Tendermint(parse_fixed_id(id)?),
+			"GENERIC" => StateMachine::Generic(ChainId::from_str(id)?),
+			_ => Err(StateMachineParseError::UnknownPrefix(s.into()))?,
 		};
 
-		Ok(s)
+		Ok(state_machine)
+	}
+}
+
+/// The maximum length of a [`ChainId`], this is the maximum length of a CAIP-2 chain reference.
+pub const MAX_CHAIN_ID_LEN: usize = 32;
+
+/// A non-empty chain identifier of at most [`MAX_CHAIN_ID_LEN`] bytes, made up of ascii
+/// alphanumeric characters and any of `-`, `_`, `.` or `:`.
+///
+/// It is stored inline so that [`StateMachine`] remains `Copy`, but it is SCALE encoded like a
+/// `Vec<u8>` and serialized as a string.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChainId {
+	len: u8,
+	bytes: [u8; MAX_CHAIN_ID_LEN],
+}
+
+impl ChainId {
+	/// Returns the chain id as bytes
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.bytes[..self.len as usize]
+	}
+
+	/// Returns the chain id as a string slice
+	pub fn as_str(&self) -> &str {
+		// only ascii characters are allowed in a chain id
+		core::str::from_utf8(self.as_bytes()).expect("Chain ids are valid ascii; qed")
+	}
+
+	fn is_valid_char(byte: u8) -> bool {
+		byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b':')
+	}
+}
+
+/// Errors encountered when constructing a [`ChainId`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainIdError {
+	/// The chain id is empty
+	Empty,
+	/// The chain id is longer than [`MAX_CHAIN_ID_LEN`] bytes
+	TooLong(usize),
+	/// The chain id contains a byte that is not allowed
+	InvalidCharacter(u8),
+}
+
+impl Display for ChainIdError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			ChainIdError::Empty => write!(f, "chain id cannot be empty"),
+			ChainIdError::TooLong(len) =>
+				write!(f, "chain id is {len} bytes long, the maximum is {MAX_CHAIN_ID_LEN}"),
+			ChainIdError::InvalidCharacter(byte) =>
+				write!(f, "invalid character {:?} in chain id", char::from(*byte)),
+		}
+	}
+}
+
+impl TryFrom<&[u8]> for ChainId {
+	type Error = ChainIdError;
+
+	fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+		if value.is_empty() {
+			Err(ChainIdError::Empty)?
+		}
+
+		if value.len() > MAX_CHAIN_ID_LEN {
+			Err(ChainIdError::TooLong(value.len()))?
+		}
+
+		if let Some(byte) = value.iter().find(|byte| !Self::is_valid_char(**byte)) {
+			Err(ChainIdError::InvalidCharacter(*byte))?
+		}
+
+		let mut bytes = [0u8; MAX_CHAIN_ID_LEN];
+		bytes[..value.len()].copy_from_slice(value);
+		Ok(ChainId { len: value.len() as u8, bytes })
+	}
+}
+
+impl FromStr for ChainId {
+	type Err = ChainIdError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		ChainId::try_from(s.as_bytes())
+	}
+}
+
+impl Display for ChainId {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl core::fmt::Debug for ChainId {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{:?}", self.as_str())
+	}
+}
+
+impl PartialOrd for ChainId {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for ChainId {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		self.as_bytes().cmp(other.as_bytes())
+	}
+}
+
+impl Encode for ChainId {
+	fn size_hint(&self) -> usize {
+		self.as_bytes().size_hint()
+	}
+
+	fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+		self.as_bytes().encode_to(dest)
+	}
+}
+
+impl codec::EncodeLike for ChainId {}
+
+impl Decode for ChainId {
+	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+		let len = codec::Compact::<u32>::decode(input)?.0 as usize;
+		if len > MAX_CHAIN_ID_LEN {
+			Err("Chain id exceeds the maximum length")?
+		}
+
+		let mut bytes = [0u8; MAX_CHAIN_ID_LEN];
+		input.read(&mut bytes[..len])?;
+
+		ChainId::try_from(&bytes[..len]).map_err(|_| "Invalid chain id".into())
+	}
+}
+
+impl scale_info::TypeInfo for ChainId {
+	type Identity = Self;
+
+	fn type_info() -> scale_info::Type {
+		scale_info::Type::builder()
+			.path(scale_info::Path::new("ChainId", module_path!()))
+			.composite(
+				scale_info::build::Fields::unnamed()
+					.field(|f| f.ty::<Vec<u8>>().type_name("Vec<u8>")),
+			)
+	}
+}
+
+impl serde::Serialize for ChainId {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(self.as_str())
+	}
+}
+
+impl<'de> serde::Deserialize<'de> for ChainId {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let id = String::deserialize(deserializer)?;
+		ChainId::from_str(&id).map_err(serde::de::Error::custom)
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::host::StateMachine;
-	use alloc::string::ToString;
+	use crate::{
+		host::{ChainId, ChainIdError, StateMachine, StateMachineParseError, MAX_CHAIN_ID_LEN},
+		router::{PostRequest, Request},
+	};
+	use alloc::{
+		string::{String, ToString},
+		vec,
+	};
+	use codec::{Decode, Encode};
 	use core::str::FromStr;
+	use proptest::prelude::*;
+
+	fn fixed_id() -> impl Strategy<Value = [u8; 4]> {
+		prop_oneof![
+			"\\PC{1,4}"
+				.prop_filter_map("id must be 4 bytes", |id: String| id.as_bytes().try_into().ok()),
+			any::<[u8; 4]>(),
+		]
+	}
+
+	fn chain_id() -> impl Strategy<Value = ChainId> {
+		"[A-Za-z0-9._:-]{1,32}".prop_map(|id: String| ChainId::from_str(&id).unwrap())
+	}
+
+	fn state_machine() -> impl Strategy<Value = StateMachine> {
+		prop_oneof![
+			any::<u32>().prop_map(StateMachine::Evm),
+			any::<u32>().prop_map(StateMachine::Polkadot),
+			any::<u32>().prop_map(StateMachine::Kusama),
+			fixed_id().prop_map(StateMachine::Substrate),
+			fixed_id().prop_map(StateMachine::Tendermint),
+			chain_id().prop_map(StateMachine::Generic),
+		]
+	}
+
+	proptest! {
+		#[test]
+		fn state_machine_string_roundtrip(state_machine in state_machine()) {
+			let name = state_machine.to_string();
+			prop_assert_eq!(StateMachine::from_str(&name), Ok(state_machine));
+		}
+
+		#[test]
+		fn state_machine_codec_roundtrip(state_machine in state_machine()) {
+			let encoded = state_machine.encode();
+			prop_assert_eq!(StateMachine::decode(&mut &*encoded), Ok(state_machine));
+
+			let json = serde_json::to_string(&state_machine).unwrap();
+			prop_assert_eq!(serde_json::from_str::<StateMachine>(&json).unwrap(), state_machine);
+		}
+
+		#[test]
+		fn parsed_state_machines_are_canonical(name in "(EVM|POLKADOT|KUSAMA|SUBSTRATE|TNDRMINT|GENERIC|evm)?-?\\PC{0,40}") {
+			// parsing arbitrary input must never panic, and anything accepted displays identically
+			if let Ok(state_machine) = StateMachine::from_str(&name) {
+				prop_assert_eq!(state_machine.to_string(), name);
+			}
+		}
+	}
 
 	#[test]
 	fn state_machine_conversions() {
@@ -383,4 +723,112 @@ mod tests {
 		assert_eq!(grandpa, StateMachine::from_str(&grandpa_string).unwrap());
 		assert_eq!(beefy, StateMachine::from_str(&beefy_string).unwrap());
 	}
+
+	#[test]
+	fn generic_state_machine_conversions() {
+		let osmosis = StateMachine::Generic(ChainId::from_str("osmosis-1").unwrap());
+
+		assert_eq!(osmosis.to_string(), "GENERIC-osmosis-1");
+		assert_eq!(osmosis, StateMachine::from_str(&osmosis.to_string()).unwrap());
+		assert_eq!(osmosis, StateMachine::decode(&mut &*osmosis.encode()).unwrap());
+
+		let json = serde_json::to_string(&osmosis).unwrap();
+		assert_eq!(json, r#"{"Generic":"osmosis-1"}"#);
+		assert_eq!(osmosis, serde_json::from_str::<StateMachine>(&json).unwrap());
+	}
+
+	#[test]
+	fn generic_state_machines_in_request_commitments() {
+		let post = PostRequest {
+			source: StateMachine::Generic(ChainId::from_str("osmosis-1").unwrap()),
+			dest: StateMachine::Evm(1),
+			nonce: 0,
+			from: vec![],
+			to: vec![],
+			timeout_timestamp: 0,
+			body: vec![],
+		};
+
+		assert!(Request::Post(post).encode().starts_with(b"GENERIC-osmosis-1EVM-1"));
+	}
+
+	#[test]
+	fn chain_id_is_encoded_like_bytes() {
+		let id = ChainId::from_str("solana-mainnet").unwrap();
+		assert_eq!(id.encode(), b"solana-mainnet".to_vec().encode());
+
+		let too_long = vec![b'a'; MAX_CHAIN_ID_LEN + 1];
+		assert!(ChainId::decode(&mut &*too_long.encode()).is_err());
+		assert!(ChainId::decode(&mut &*b"bad id".to_vec().encode()).is_err());
+		assert!(ChainId::decode(&mut &*vec![0u8; 0].encode()).is_err());
+	}
+
+	#[test]
+	fn invalid_chain_ids_are_rejected() {
+		assert!(ChainId::from_str("").is_err());
+		assert!(ChainId::from_str("osmosis 1").is_err());
+		assert!(ChainId::from_str(&"a".repeat(MAX_CHAIN_ID_LEN + 1)).is_err());
+		assert!(ChainId::from_str(&"a".repeat(MAX_CHAIN_ID_LEN)).is_ok());
+		assert!(StateMachine::from_str("GENERIC-").is_err());
+	}
+
+	#[test]
+	fn state_machine_parse_errors() {
+		let cases = [
+			("EVM", StateMachineParseError::UnknownPrefix("EVM".into())),
+			("ETH-1", StateMachineParseError::UnknownPrefix("ETH-1".into())),
+			("EVM-", StateMachineParseError::InvalidNumericId("".into())),
+			("EVM-+1", StateMachineParseError::InvalidNumericId("+1".into())),
+			("KUSAMA-02000", StateMachineParseError::InvalidNumericId("02000".into())),
+			("POLKADOT-4294967296", StateMachineParseError::InvalidNumericId("4294967296".into())),
+			("SUBSTRATE-", StateMachineParseError::InvalidIdLength { expected: 4, found: 0 }),
+			("SUBSTRATE-hyb", StateMachineParseError::InvalidIdLength { expected: 4, found: 3 }),
+			("TNDRMINT-hybrid", StateMachineParseError::InvalidIdLength { expected: 4, found: 6 }),
+			("SUBSTRATE-0xff", StateMachineParseError::InvalidHexId("0xff".into())),
+			("SUBSTRATE-0xFF000102", StateMachineParseError::InvalidHexId("0xFF000102".into())),
+			// valid UTF-8 ids are not hex encoded
+			("TNDRMINT-0x68796272", StateMachineParseError::InvalidHexId("0x68796272".into())),
+			("GENERIC-", StateMachineParseError::InvalidChainId(ChainIdError::Empty)),
+			(
+				"GENERIC-osmosis 1",
+				StateMachineParseError::InvalidChainId(ChainIdError::InvalidCharacter(b' ')),
+			),
+		];
+
+		for (name, err) in cases {
+			assert_eq!(StateMachine::from_str(name), Err(err), "{name}");
+		}
+	}
+
+	#[test]
+	fn every_state_machine_variant_roundtrips() {
+		let state_machines = [
+			StateMachine::Evm(1),
+			StateMachine::Polkadot(3367),
+			StateMachine::Kusama(4009),
+			StateMachine::Substrate(*b"hybr"),
+			StateMachine::Substrate([0xff, 0, 1, 2]),
+			StateMachine::Tendermint(*b"osmo"),
+			StateMachine::Tendermint([0x80; 4]),
+			StateMachine::Generic(ChainId::from_str("osmosis-1").unwrap()),
+		];
+
+		for state_machine in state_machines {
+			let name = state_machine.to_string();
+			assert_eq!(StateMachine::from_str(&name), Ok(state_machine), "{name}");
+			assert_eq!(StateMachine::decode(&mut &*state_machine.encode()), Ok(state_machine));
+			let json = serde_json::to_string(&state_machine).unwrap();
+			assert_eq!(serde_json::from_str::<StateMachine>(&json).unwrap(), state_machine);
+		}
+
+		assert_eq!(StateMachine::Substrate([0xff, 0, 1, 2]).to_string(), "SUBSTRATE-0xff000102");
+		assert_eq!(StateMachine::Tendermint([0x80; 4]).to_string(), "TNDRMINT-0x80808080");
+	}
+
+	#[test]
+	fn ids_containing_dashes_are_preserved() {
+		assert_eq!(StateMachine::from_str("SUBSTRATE-a-bc"), Ok(StateMachine::Substrate(*b"a-bc")));
+		assert_eq!(StateMachine::from_str("TNDRMINT--abc"), Ok(StateMachine::Tendermint(*b"-abc")));
+		assert_eq!(StateMachine::from_str("EVM-1-2").map_err(|_| ()), Err(()));
+	}
 }
@@ -26,7 +26,9 @@ use crate::{
 	},
 	error::Error,
 	host::StateMachine,
-	router::{GetResponse, PostRequest, PostResponse, Request, RequestResponse, Response},
+	router::{
+		GetResponse, PostBatch, PostRequest, PostResponse, Request, RequestResponse, Response,
+	},
 };
 use alloc::{string::ToString, vec::Vec};
 use codec::{Decode, Encode};
@@ -115,6 +117,18 @@ pub struct RequestMessage {
 	pub signer: Vec<u8>,
 }
 
+/// A batch message holds request batches to be delivered from a source state machine. Every
+/// batch is delivered atomically, either all of its items are accepted or none of them are.
+#[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
+pub struct BatchMessage {
+	/// Request batches from source chain
+	pub batches: Vec<PostBatch>,
+	/// Membership batch proof for these batches
+	pub proof: Proof,
+	/// Signer information. Ideally should be their account identifier
+	pub signer: Vec<u8>,
+}
+
 /// A request message holds a batch of responses to be dispatched from a source state machine
 #[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
 pub struct ResponseMessage {
@@ -133,6 +147,8 @@ impl ResponseMessage {
 			RequestResponse::Response(responses) =>
 				responses.iter().map(|res| res.request()).collect(),
 			RequestResponse::Request(requests) => requests.clone(),
+			RequestResponse::Batch(batches) =>
+				batches.iter().flat_map(|batch| batch.requests()).map(Request::Post).collect(),
 		}
 	}
 
@@ -165,6 +181,13 @@ pub enum TimeoutMessage {
 		/// Requests that have timed out
 		requests: Vec<Request>,
 	},
+	/// A non membership proof for request batches
+	PostBatch {
+		/// Request batch timeouts
+		batches: Vec<PostBatch>,
+		/// Non membership proof for the receipts of these batches
+		timeout_proof: Proof,
+	},
 }
 
 impl TimeoutMessage {
@@ -175,12 +198,15 @@ impl TimeoutMessage {
 				requests.clone(),
 			TimeoutMessage::PostResponse { responses, .. } =>
 				responses.clone().into_iter().map(|res| res.request()).collect(),
+			TimeoutMessage::PostBatch { batches, .. } =>
+				batches.iter().flat_map(|batch| batch.requests()).map(Request::Post).collect(),
 		}
 	}
 	/// Returns the associated proof
 	pub fn timeout_proof(&self) -> Result<&Proof, Error> {
 		match self {
-			TimeoutMessage::Post { timeout_proof, .. } => Ok(timeout_proof),
+			TimeoutMessage::Post { timeout_proof, .. } |
+			TimeoutMessage::PostBatch { timeout_proof, .. } => Ok(timeout_proof),
 			_ => Err(Error::Custom("Method should not be called on Get request".to_string())),
 		}
 	}
@@ -214,6 +240,9 @@ pub enum Message {
 	/// A request timeout message
 	#[codec(index = 4)]
 	Timeout(TimeoutMessage),
+	/// A request batch message
+	#[codec(index = 5)]
+	Batch(BatchMessage),
 }
 
 /// A trait that returns a 256 bit keccak has of some bytes
@@ -230,6 +259,11 @@ pub fn hash_request<H: Keccak256>(req: &Request) -> H256 {
 	H::keccak256(&encoded)
 }
 
+/// Return the keccak256 hash of a request batch
+pub fn hash_batch<H: Keccak256>(batch: &PostBatch) -> H256 {
+	H::keccak256(&batch.encode())
+}
+
 /// Return the keccak256 of a response
 pub fn hash_response<H: Keccak256>(res: &Response) -> H256 {
 	match res {
@@ -16,25 +16,206 @@
 //! ISMPModule definition
 
 use crate::{
-	events::Event,
+	events::{Event, Meta},
 	router::{PostRequest, Response, Timeout},
 };
+use alloc::{
+	string::{String, ToString},
+	vec::Vec,
+};
+use codec::{Decode, Encode};
+use core::fmt;
+use scale_info::TypeInfo;
 
 /// A type alias for dispatch results
-pub type DispatchResult = Result<Event, anyhow::Error>;
+pub type DispatchResult = Result<Event, ModuleError>;
+
+/// The maximum length in bytes of a [`ModuleError`] message, longer messages are truncated.
+pub const MAX_MODULE_ERROR_MESSAGE_LEN: usize = 256;
+
+/// Broad classification of module failures, so that relayers can decide whether to retry a
+/// delivery.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum ModuleErrorCode {
+	/// No module is registered for the destination module id
+	#[codec(index = 0)]
+	ModuleNotFound,
+	/// The message body could not be decoded by the module
+	#[codec(index = 1)]
+	DecodeFailed,
+	/// The sender of the message is not allowed to call the module
+	#[codec(index = 2)]
+	Unauthorized,
+	/// The message was decoded but its contents are invalid, eg an unknown asset or amount
+	#[codec(index = 3)]
+	InvalidRequest,
+	/// The module does not support this kind of message
+	#[codec(index = 4)]
+	Unsupported,
+	/// The module cannot handle the message at the moment, eg due to insufficient liquidity.
+	/// Delivering the message again later may succeed.
+	#[codec(index = 5)]
+	Unavailable,
+	/// An unexpected failure while executing the module callback
+	#[codec(index = 6)]
+	Internal,
+	/// A module specific error code
+	#[codec(index = 7)]
+	Custom(u16),
+}
+
+impl ModuleErrorCode {
+	/// Returns true if delivering the message again at a later time may succeed
+	pub fn is_retriable(&self) -> bool {
+		matches!(self, ModuleErrorCode::Unavailable)
+	}
+}
+
+/// A structured error returned by an [`IsmpModule`] callback
+#[derive(Debug, Clone, Encode, TypeInfo, PartialEq, Eq)]
+pub struct ModuleError {
+	/// The error classification
+	code: ModuleErrorCode,
+	/// The id of the module that returned the error
+	module: Vec<u8>,
+	/// A descriptive message, at most [`MAX_MODULE_ERROR_MESSAGE_LEN`] bytes long
+	message: String,
+	/// The metadata of the request or response the module failed to handle, this is set by the
+	/// message handlers.
+	meta: Option<Meta>,
+}
+
+impl ModuleError {
+	/// Create a new module error, the message is truncated to [`MAX_MODULE_ERROR_MESSAGE_LEN`]
+	/// bytes.
+	pub fn new(code: ModuleErrorCode, module: impl Into<Vec<u8>>, message: impl ToString) -> Self {
+		let mut message = message.to_string();
+		if message.len() > MAX_MODULE_ERROR_MESSAGE_LEN {
+			let mut len = MAX_MODULE_ERROR_MESSAGE_LEN;
+			while !message.is_char_boundary(len) {
+				len -= 1;
+			}
+			message.truncate(len);
+		}
+		Self { code, module: module.into(), message, meta: None }
+	}
+
+	/// Attach the metadata of the request or response the module failed to handle
+	pub fn with_meta(mut self, meta: impl Into<Meta>) -> Self {
+		self.meta = Some(meta.into());
+		self
+	}
+
+	/// The error classification
+	pub fn code(&self) -> ModuleErrorCode {
+		self.code
+	}
+
+	/// The id of the module that returned the error
+	pub fn module(&self) -> &[u8] {
+		&self.module
+	}
+
+	/// The error message
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	/// The metadata of the request or response the module failed to handle
+	pub fn meta(&self) -> Option<&Meta> {
+		self.meta.as_ref()
+	}
+}
+
+impl Decode for ModuleError {
+	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+		let code = ModuleErrorCode::decode(input)?;
+		let module = Vec::<u8>::decode(input)?;
+		let message = String::decode(input)?;
+		if message.len() > MAX_MODULE_ERROR_MESSAGE_LEN {
+			Err("Module error message is too long")?
+		}
+		let meta = Option::<Meta>::decode(input)?;
+
+		Ok(Self { code, module, message, meta })
+	}
+}
+
+impl fmt::Display for ModuleError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"Module 0x{} failed with {:?}: {}",
+			hex::encode(&self.module),
+			self.code,
+			self.message
+		)
+	}
+}
+
+impl core::error::Error for ModuleError {}
 
 /// Individual modules which live on a state machine must conform to this interface in order to send
 /// and receive ISMP requests and responses
 pub trait IsmpModule {
 	/// Called by the message handler on a module, to notify module of a new POST request
 	/// the module may choose to respond immediately, or in a later block
-	fn on_accept(&self, request: PostRequest) -> Result<(), anyhow::Error>;
+	fn on_accept(&self, request: PostRequest) -> Result<(), ModuleError>;
 
 	/// Called by the message handler on a module, to notify module of a response to a previously
 	/// sent out request
-	fn on_response(&self, response: Response) -> Result<(), anyhow::Error>;
+	fn on_response(&self, response: Response) -> Result<(), ModuleError>;
 
 	/// Called by the message handler on a module, to notify module of requests that were previously
 	/// sent but have now timed-out
-	fn on_timeout(&self, request: Timeout) -> Result<(), anyhow::Error>;
+	fn on_timeout(&self, request: Timeout) -> Result<(), ModuleError>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::host::StateMachine;
+
+	#[test]
+	fn module_error_messages_are_bounded() {
+		let error =
+			ModuleError::new(ModuleErrorCode::Internal, b"module".to_vec(), "é".repeat(200));
+		assert!(error.message().len() <= MAX_MODULE_ERROR_MESSAGE_LEN);
+		assert_eq!(error.message(), "é".repeat(MAX_MODULE_ERROR_MESSAGE_LEN / 2));
+
+		let decoded = ModuleError::decode(&mut &*error.encode()).unwrap();
+		assert_eq!(decoded, error);
+
+		let oversized =
+			(ModuleErrorCode::Internal, b"module".to_vec(), "a".repeat(300), None::<Meta>).encode();
+		assert!(ModuleError::decode(&mut &*oversized).is_err());
+	}
+
+	#[test]
+	fn module_errors_can_be_downcast() {
+		let error = ModuleError::new(ModuleErrorCode::Unavailable, b"module".to_vec(), "paused");
+		let error = anyhow::Error::from(error);
+		let error = error.downcast_ref::<ModuleError>().unwrap();
+		assert!(error.code().is_retriable());
+		assert_eq!(error.module(), b"module");
+	}
+
+	#[test]
+	fn module_errors_keep_the_request_metadata() {
+		let meta = Meta {
+			source: StateMachine::Kusama(2000),
+			dest: StateMachine::Polkadot(3367),
+			nonce: 7,
+		};
+		let error = ModuleError::new(ModuleErrorCode::Unavailable, b"module".to_vec(), "paused")
+			.with_meta(meta.clone());
+		assert_eq!(error.meta(), Some(&meta));
+
+		let decoded = ModuleError::decode(&mut &*error.encode()).unwrap();
+		assert_eq!(decoded.meta(), Some(&meta));
+
+		let error = anyhow::Error::from(error);
+		assert_eq!(error.downcast_ref::<ModuleError>().unwrap().meta(), Some(&meta));
+	}
 }
@@ -81,6 +81,148 @@ impl core::fmt::Display for PostRequest {
 	}
 }
 
+/// Domain separator for batch commitments, so that a batch can never share a commitment with a
+/// [`PostRequest`], whose encoding always starts with the source state machine identifier.
+pub const BATCH_COMMITMENT_PREFIX: &[u8] = b"ISMP-BATCH";
+
+/// A single message in a [`PostBatch`].
+#[derive(
+	Debug,
+	Clone,
+	Encode,
+	Decode,
+	PartialEq,
+	Eq,
+	scale_info::TypeInfo,
+	serde::Deserialize,
+	serde::Serialize,
+)]
+pub struct BatchItem {
+	/// Module identifier of the receiving module
+	#[serde(with = "serde_hex_utils::as_hex")]
+	pub to: Vec<u8>,
+	/// Encoded request body
+	#[serde(with = "serde_hex_utils::as_hex")]
+	pub body: Vec<u8>,
+}
+
+/// A batch of POST requests sent by a module to a single destination state machine. The batch
+/// has a single nonce, commitment and receipt. It is proven and delivered as a whole, while each
+/// item is routed to its own receiving module.
+#[derive(
+	Debug,
+	Clone,
+	Encode,
+	Decode,
+	PartialEq,
+	Eq,
+	scale_info::TypeInfo,
+	serde::Deserialize,
+	serde::Serialize,
+)]
+pub struct PostBatch {
+	/// The source state machine of this batch.
+	#[serde(with = "serde_hex_utils::as_string")]
+	pub source: StateMachine,
+	/// The destination state machine of this batch.
+	#[serde(with = "serde_hex_utils::as_string")]
+	pub dest: StateMachine,
+	/// The nonce of this batch on the source chain
+	pub nonce: u64,
+	/// Module identifier of the sending module
+	#[serde(with = "serde_hex_utils::as_hex")]
+	pub from: Vec<u8>,
+	/// Timestamp which this batch expires in seconds.
+	pub timeout_timestamp: u64,
+	/// The messages in this batch, in the order they should be delivered
+	pub items: Vec<BatchItem>,
+}
+
+impl PostBatch {
+	/// Returns the timeout timestamp for a batch
+	pub fn timeout(&self) -> Duration {
+		get_timeout(self.timeout_timestamp)
+	}
+
+	/// Returns true if the destination chain timestamp has exceeded the batch timeout timestamp
+	pub fn timed_out(&self, proof_timestamp: Duration) -> bool {
+		proof_timestamp >= self.timeout()
+	}
+
+	/// Returns the items in this batch as the POST requests that will be routed to the receiving
+	/// modules. Every request shares the metadata of the batch.
+	pub fn requests(&self) -> Vec<PostRequest> {
+		self.items
+			.iter()
+			.map(|item| PostRequest {
+				source: self.source,
+				dest: self.dest,
+				nonce: self.nonce,
+				from: self.from.clone(),
+				to: item.to.clone(),
+				timeout_timestamp: self.timeout_timestamp,
+				body: item.body.clone(),
+			})
+			.collect()
+	}
+
+	/// Returns the encoded batch. Items are length prefixed, so that the boundaries between them
+	/// are committed to.
+	pub fn encode(&self) -> Vec<u8> {
+		let mut buf = Vec::new();
+		buf.extend_from_slice(BATCH_COMMITMENT_PREFIX);
+		buf.extend_from_slice(self.source.to_string().as_bytes());
+		buf.extend_from_slice(self.dest.to_string().as_bytes());
+		buf.extend_from_slice(&self.nonce.to_be_bytes());
+		buf.extend_from_slice(&self.timeout_timestamp.to_be_bytes());
+		buf.extend_from_slice(&(self.from.len() as u32).to_be_bytes());
+		buf.extend_from_slice(&self.from);
+		buf.extend_from_slice(&(self.items.len() as u32).to_be_bytes());
+		for item in &self.items {
+			buf.extend_from_slice(&(item.to.len() as u32).to_be_bytes());
+			buf.extend_from_slice(&item.to);
+			buf.extend_from_slice(&(item.body.len() as u32).to_be_bytes());
+			buf.extend_from_slice(&item.body);
+		}
+		buf
+	}
+}
+
+/// Identifies a channel, the sequence of POST requests sent from a source state machine to a
+/// receiving module on a destination state machine. A channel is ordered when both of its hosts
+/// order it: the source host assigns its requests consecutive nonces and the destination host only
+/// delivers them in that order, without gaps.
+#[derive(
+	Debug,
+	Clone,
+	Encode,
+	Decode,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	scale_info::TypeInfo,
+	serde::Deserialize,
+	serde::Serialize,
+)]
+pub struct ChannelId {
+	/// The source state machine of the channel.
+	#[serde(with = "serde_hex_utils::as_string")]
+	pub source: StateMachine,
+	/// The destination state machine of the channel.
+	#[serde(with = "serde_hex_utils::as_string")]
+	pub dest: StateMachine,
+	/// Module identifier of the receiving module
+	#[serde(with = "serde_hex_utils::as_hex")]
+	pub module: Vec<u8>,
+}
+
+impl From<&PostRequest> for ChannelId {
+	fn from(post: &PostRequest) -> Self {
+		ChannelId { source: post.source, dest: post.dest, module: post.to.clone() }
+	}
+}
+
 /// The ISMP GET request.
 #[derive(
 	Debug,
@@ -509,6 +651,8 @@ pub enum RequestResponse {
 	Request(Vec<Request>),
 	/// A batch of responses
 	Response(Vec<Response>),
+	/// A set of request batches
+	Batch(Vec<PostBatch>),
 }
 
 /// Timeout message
@@ -17,7 +17,7 @@
 
 extern crate alloc;
 
-use alloc::{boxed::Box, string::ToString, vec};
+use alloc::{boxed::Box, vec};
 use alloy_sol_types::SolType;
 use core::marker::PhantomData;
 use pallet_token_gateway::{
@@ -36,10 +36,9 @@ use frame_support::{
 
 use ismp::{
 	dispatcher::{DispatchPost, DispatchRequest, FeeMetadata, IsmpDispatcher},
-	events::Meta,
 	host::{IsmpHost, StateMachine},
 	messaging::hash_request,
-	module::IsmpModule,
+	module::{IsmpModule, ModuleError, ModuleErrorCode},
 	router::{Request, Timeout},
 };
 pub use pallet::*;
@@ -290,6 +289,11 @@ where
 #[derive(Clone)]
 pub struct Module<T>(PhantomData<T>);
 
+/// Returns a [`ModuleError`] originating from the asset gateway
+fn module_error(code: ModuleErrorCode, message: &str) -> ModuleError {
+	ModuleError::new(code, PALLET_ID.to_bytes(), message)
+}
+
 impl<T: Config> Default for Module<T> {
 	fn default() -> Self {
 		Self(PhantomData)
@@ -302,19 +306,15 @@ where
 	u128: From<<T::Assets as fungibles::Inspect<T::AccountId>>::Balance>,
 	T::AccountId: Into<[u8; 32]> + From<[u8; 32]>,
 {
-	fn on_accept(&self, post: ismp::router::PostRequest) -> Result<(), anyhow::Error> {
+	fn on_accept(&self, post: ismp::router::PostRequest) -> Result<(), ModuleError> {
 		let request = Request::Post(post.clone());
 		// Check that source module is equal to the known token gateway deployment address
 		ensure!(
 			request.source_module() == Pallet::<T>::token_gateway_address(&post.source).0.to_vec(),
-			ismp::error::Error::ModuleDispatchError {
-				msg: "Token Gateway: Unknown source contract address".to_string(),
-				meta: Meta {
-					source: request.source_chain(),
-					dest: request.dest_chain(),
-					nonce: request.nonce(),
-				},
-			}
+			module_error(
+				ModuleErrorCode::Unauthorized,
+				"Token Gateway: Unknown source contract address"
+			)
 		);
 
 		// parachains/solochains shouldn't be sending us a request.
@@ -323,49 +323,29 @@ where
 				request.source_chain(),
 				StateMachine::Kusama(_) | StateMachine::Polkadot(_) | StateMachine::Substrate(_)
 			),
-			ismp::error::Error::ModuleDispatchError {
-				msg: "Token Gateway: Illegal source chain".to_string(),
-				meta: Meta {
-					source: request.source_chain(),
-					dest: request.dest_chain(),
-					nonce: request.nonce(),
-				},
-			}
+			module_error(ModuleErrorCode::Unauthorized, "Token Gateway: Illegal source chain")
 		);
 
 		let body = Body::abi_decode(&mut &post.body[1..], true).map_err(|_| {
-			ismp::error::Error::ModuleDispatchError {
-				msg: "Token Gateway: Failed to decode request body".to_string(),
-				meta: Meta {
-					source: request.source_chain(),
-					dest: request.dest_chain(),
-					nonce: request.nonce(),
-				},
-			}
+			module_error(
+				ModuleErrorCode::DecodeFailed,
+				"Token Gateway: Failed to decode request body",
+			)
 		})?;
 
 		// Check that the asset id is equal to the known asset id
 		ensure!(
 			body.asset_id.0 == Pallet::<T>::dot_asset_id().0,
-			ismp::error::Error::ModuleDispatchError {
-				msg: "Token Gateway: AssetId is unknown".to_string(),
-				meta: Meta {
-					source: request.source_chain(),
-					dest: request.dest_chain(),
-					nonce: request.nonce(),
-				},
-			}
+			module_error(ModuleErrorCode::InvalidRequest, "Token Gateway: AssetId is unknown")
 		);
 
 		let amount =
 			convert_to_balance(U256::from_big_endian(&body.amount.to_be_bytes::<32>()), 18, 10)
-				.map_err(|_| ismp::error::Error::ModuleDispatchError {
-					msg: "Token Gateway: Trying to withdraw Invalid amount".to_string(),
-					meta: Meta {
-						source: request.source_chain(),
-						dest: request.dest_chain(),
-						nonce: request.nonce(),
-					},
+				.map_err(|_| {
+					module_error(
+						ModuleErrorCode::InvalidRequest,
+						"Token Gateway: Trying to withdraw Invalid amount",
+					)
 				})?;
 
 		let asset_id = Location::parent();
@@ -390,13 +370,11 @@ where
 			fee_asset_item,
 			weight_limit,
 		)
-		.map_err(|_| ismp::error::Error::ModuleDispatchError {
-			msg: "Token Gateway: Failed execute xcm to relay chain".to_string(),
-			meta: Meta {
-				source: request.source_chain(),
-				dest: request.dest_chain(),
-				nonce: request.nonce(),
-			},
+		.map_err(|_| {
+			module_error(
+				ModuleErrorCode::Unavailable,
+				"Token Gateway: Failed to execute xcm to relay chain",
+			)
 		})?;
 
 		Pallet::<T>::deposit_event(Event::<T>::AssetReceived {
@@ -408,19 +386,14 @@ where
 		Ok(())
 	}
 
-	fn on_response(&self, response: ismp::router::Response) -> Result<(), anyhow::Error> {
-		Err(ismp::error::Error::ModuleDispatchError {
-			msg: "Token Gateway does not accept responses".to_string(),
-			meta: Meta {
-				source: response.source_chain(),
-				dest: response.dest_chain(),
-				nonce: response.nonce(),
-			},
-		}
-		.into())
+	fn on_response(&self, _response: ismp::router::Response) -> Result<(), ModuleError> {
+		Err(module_error(
+			ModuleErrorCode::Unsupported,
+			"Token Gateway: Module does not accept responses",
+		))
 	}
 
-	fn on_timeout(&self, request: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, request: Timeout) -> Result<(), ModuleError> {
 		// We don't custody user funds, we send the dot back to the relaychain using xcm
 		match request {
 			Timeout::Request(Request::Post(post)) => {
@@ -429,24 +402,18 @@ where
 				let fee_metadata = pallet_ismp::child_trie::RequestCommitments::<T>::get(
 					commitment,
 				)
-				.ok_or_else(|| ismp::error::Error::ModuleDispatchError {
-					msg: "Token Gateway: Fee metadata could not be found for request".to_string(),
-					meta: Meta {
-						source: request.source_chain(),
-						dest: request.dest_chain(),
-						nonce: request.nonce(),
-					},
+				.ok_or_else(|| {
+					module_error(
+						ModuleErrorCode::InvalidRequest,
+						"Token Gateway: Fee metadata could not be found for request",
+					)
 				})?;
 				let beneficiary = fee_metadata.fee.payer;
 				let body = Body::abi_decode(&mut &post.body[1..], true).map_err(|_| {
-					ismp::error::Error::ModuleDispatchError {
-						msg: "Token Gateway: Failed to decode request body".to_string(),
-						meta: Meta {
-							source: request.source_chain(),
-							dest: request.dest_chain(),
-							nonce: request.nonce(),
-						},
-					}
+					module_error(
+						ModuleErrorCode::DecodeFailed,
+						"Token Gateway: Failed to decode request body",
+					)
 				})?;
 				// Send xcm back to relaychain
 
@@ -455,13 +422,11 @@ where
 					18,
 					10,
 				)
-				.map_err(|_| ismp::error::Error::ModuleDispatchError {
-					msg: "Token Gateway: Trying to withdraw Invalid amount".to_string(),
-					meta: Meta {
-						source: request.source_chain(),
-						dest: request.dest_chain(),
-						nonce: request.nonce(),
-					},
+				.map_err(|_| {
+					module_error(
+						ModuleErrorCode::InvalidRequest,
+						"Token Gateway: Trying to withdraw Invalid amount",
+					)
 				})?;
 				// We do an xcm limited reserve transfer from the pallet custody account to the user
 				// on the relaychain;
@@ -483,13 +448,11 @@ where
 					fee_asset_item,
 					weight_limit,
 				)
-				.map_err(|_| ismp::error::Error::ModuleDispatchError {
-					msg: "Token Gateway: Failed to execute xcm to relay chain".to_string(),
-					meta: Meta {
-						source: request.source_chain(),
-						dest: request.dest_chain(),
-						nonce: request.nonce(),
-					},
+				.map_err(|_| {
+					module_error(
+						ModuleErrorCode::Unavailable,
+						"Token Gateway: Failed to execute xcm to relay chain",
+					)
 				})?;
 
 				Pallet::<T>::deposit_event(Event::<T>::AssetRefunded {
@@ -500,21 +463,15 @@ where
 
 				Ok(())
 			},
-			Timeout::Request(Request::Get(get)) => Err(ismp::error::Error::ModuleDispatchError {
-				msg: "Tried to timeout unsupported request type".to_string(),
-				meta: Meta { source: get.source, dest: get.dest, nonce: get.nonce },
-			}
-			.into()),
-
-			Timeout::Response(response) => Err(ismp::error::Error::ModuleDispatchError {
-				msg: "Tried to timeout unsupported request type".to_string(),
-				meta: Meta {
-					source: response.source_chain(),
-					dest: response.dest_chain(),
-					nonce: response.nonce(),
-				},
-			}
-			.into()),
+			Timeout::Request(Request::Get(_)) => Err(module_error(
+				ModuleErrorCode::Unsupported,
+				"Token Gateway: Tried to timeout unsupported request type",
+			)),
+
+			Timeout::Response(_) => Err(module_error(
+				ModuleErrorCode::Unsupported,
+				"Token Gateway: Tried to timeout unsupported request type",
+			)),
 		}
 	}
 }
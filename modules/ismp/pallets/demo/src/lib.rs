@@ -26,9 +26,8 @@ use alloc::{
 };
 use frame_support::{traits::fungible::Mutate, PalletId};
 use ismp::{
-	error::Error as IsmpError,
 	host::StateMachine,
-	module::IsmpModule,
+	module::{IsmpModule, ModuleError, ModuleErrorCode},
 	router::{PostRequest, Request, Response, Timeout},
 };
 pub use pallet::*;
@@ -340,7 +339,7 @@ impl<T: Config> Default for IsmpModuleCallback<T> {
 }
 
 impl<T: Config> IsmpModule for IsmpModuleCallback<T> {
-	fn on_accept(&self, request: PostRequest) -> Result<(), anyhow::Error> {
+	fn on_accept(&self, request: PostRequest) -> Result<(), ModuleError> {
 		let source_chain = request.source;
 
 		match source_chain {
@@ -353,12 +352,14 @@ impl<T: Config> IsmpModule for IsmpModuleCallback<T> {
 					<Payload<T::AccountId, <T as Config>::Balance> as codec::Decode>::decode(
 						&mut &*request.body,
 					)
-					.map_err(|_| IsmpError::Custom("Failed to decode request data".to_string()))?;
+					.map_err(|_| {
+						module_error(ModuleErrorCode::DecodeFailed, "Failed to decode request data")
+					})?;
 				<T::NativeCurrency as Mutate<T::AccountId>>::mint_into(
 					&payload.to,
 					payload.amount.into(),
 				)
-				.map_err(|_| IsmpError::Custom("Failed to mint funds".to_string()))?;
+				.map_err(|_| module_error(ModuleErrorCode::Internal, "Failed to mint funds"))?;
 				Pallet::<T>::deposit_event(Event::<T>::BalanceReceived {
 					from: payload.from,
 					to: payload.to,
@@ -366,16 +367,20 @@ impl<T: Config> IsmpModule for IsmpModuleCallback<T> {
 					source_chain,
 				});
 			},
-			source => Err(IsmpError::Custom(format!("Unsupported source {source:?}")))?,
+			source => Err(module_error(
+				ModuleErrorCode::Unauthorized,
+				format!("Unsupported source {source:?}"),
+			))?,
 		}
 
 		Ok(())
 	}
 
-	fn on_response(&self, response: Response) -> Result<(), anyhow::Error> {
+	fn on_response(&self, response: Response) -> Result<(), ModuleError> {
 		match response {
-			Response::Post(_) => Err(IsmpError::Custom(
-				"Balance transfer protocol does not accept post responses".to_string(),
+			Response::Post(_) => Err(module_error(
+				ModuleErrorCode::Unsupported,
+				"Balance transfer protocol does not accept post responses",
 			))?,
 			Response::Get(res) => Pallet::<T>::deposit_event(Event::<T>::GetResponse(
 				res.values.into_iter().map(|storage_value| storage_value.value).collect(),
@@ -385,22 +390,27 @@ impl<T: Config> IsmpModule for IsmpModuleCallback<T> {
 		Ok(())
 	}
 
-	fn on_timeout(&self, timeout: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, timeout: Timeout) -> Result<(), ModuleError> {
 		let request = match timeout {
 			Timeout::Request(Request::Post(post)) => Request::Post(post),
-			_ => Err(IsmpError::Custom("Only Post requests allowed, found Get".to_string()))?,
+			_ => Err(module_error(
+				ModuleErrorCode::Unsupported,
+				"Only Post requests allowed, found Get",
+			))?,
 		};
 		let source_chain = request.source_chain();
 
 		let payload = <Payload<T::AccountId, <T as Config>::Balance> as codec::Decode>::decode(
 			&mut &*request.body().expect("Request has been checked; qed"),
 		)
-		.map_err(|_| IsmpError::Custom("Failed to decode request data".to_string()))?;
+		.map_err(|_| {
+			module_error(ModuleErrorCode::DecodeFailed, "Failed to decode request data")
+		})?;
 		<T::NativeCurrency as Mutate<T::AccountId>>::mint_into(
 			&payload.from,
 			payload.amount.into(),
 		)
-		.map_err(|_| IsmpError::Custom("Failed to mint funds".to_string()))?;
+		.map_err(|_| module_error(ModuleErrorCode::Internal, "Failed to mint funds"))?;
 		Pallet::<T>::deposit_event(Event::<T>::BalanceReceived {
 			from: payload.from,
 			to: payload.to,
@@ -410,3 +420,8 @@ impl<T: Config> IsmpModule for IsmpModuleCallback<T> {
 		Ok(())
 	}
 }
+
+/// Returns a [`ModuleError`] originating from this pallet
+fn module_error(code: ModuleErrorCode, message: impl ToString) -> ModuleError {
+	ModuleError::new(code, PALLET_ID.to_bytes(), message)
+}
@@ -14,23 +14,79 @@
 // limitations under the License.
 
 //! Enables fishermen keep hyperbridge safe by vetoing fraudulent state commitments.
+//!
+//! Fishermen are either added to a permissioned set by the admin, or bond to join while the pallet
+//! is permissionless. Bonded fishermen must back their vetoes with evidence. Evidence that can be
+//! verified on-chain vetoes the state commitment and is rewarded immediately, while the rest
+//! disputes the state commitment until it is resolved by the admin, which either vetoes the state
+//! commitment or slashes the bond of the fisherman if the veto was frivolous.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
 
 pub use pallet::*;
 
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use ismp::consensus::StateCommitment;
+
+/// Evidence that a state commitment misrepresents the state of its state machine
+#[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
+pub enum VetoEvidence {
+	/// Two valid consensus proofs which finalize conflicting views of the counterparty. These are
+	/// verified on-chain by the consensus client of the state machine.
+	ConflictingConsensus {
+		/// The first consensus proof
+		proof_1: Vec<u8>,
+		/// The second consensus proof
+		proof_2: Vec<u8>,
+	},
+	/// The state commitment finalized by the counterparty's consensus at the vetoed height. This
+	/// can't be verified on-chain, so it is rejected until it can be proven.
+	ConflictingStateCommitment {
+		/// The commitment observed by the fisherman
+		commitment: StateCommitment,
+	},
+	/// The counterparty has not finalized any block at the vetoed height
+	MissingBlock,
+}
+
+/// A veto whose evidence could not be verified on-chain, awaiting resolution by the admin
+#[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
+pub struct PendingVeto<AccountId, Balance> {
+	/// The fisherman that executed the veto
+	pub fisherman: AccountId,
+	/// The state commitment that was vetoed
+	pub commitment: StateCommitment,
+	/// The evidence provided by the fisherman
+	pub evidence: VetoEvidence,
+	/// The bond of the fisherman at the time of the veto, this is the most that can be slashed
+	pub bond: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use alloc::vec;
-	use frame_support::pallet_prelude::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, Mutate},
+			tokens::{Fortitude, Preservation},
+		},
+		PalletId,
+	};
 	use frame_system::pallet_prelude::*;
 	use ismp::{
 		consensus::{StateCommitment, StateMachineHeight},
 		events::StateCommitmentVetoed,
 		host::IsmpHost,
 	};
+	use pallet_ismp::RELAYER_FEE_ACCOUNT;
+	use sp_runtime::traits::{AccountIdConversion, Zero};
+
+	/// [`PalletId`] where the bonds of fishermen are held
+	pub const FISHERMEN_BOND_ACCOUNT: PalletId = PalletId(*b"FISHBOND");
 
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
@@ -44,6 +100,21 @@ pub mod pallet {
 
 		/// The underlying [`IsmpHost`] implementation
 		type IsmpHost: IsmpHost + Default;
+
+		/// The amount an account must bond to become a fisherman while the pallet is
+		/// permissionless
+		#[pallet::constant]
+		type FishermanBond: Get<Self::Balance>;
+
+		/// The reward paid out of the relayer fee pot for a successful veto
+		#[pallet::constant]
+		type VetoReward: Get<Self::Balance>;
+
+		/// Weight charged for every byte of the consensus proofs in
+		/// [`VetoEvidence::ConflictingConsensus`]. The cost of verifying them depends on the
+		/// consensus client, so it is charged by the size of the proofs.
+		#[pallet::constant]
+		type FraudProofWeightPerByte: Get<Weight>;
 	}
 
 	/// Set of whitelisted fishermen accounts
@@ -51,6 +122,31 @@ pub mod pallet {
 	#[pallet::getter(fn whitelist)]
 	pub type Fishermen<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
 
+	/// Whether any account may bond to become a fisherman
+	#[pallet::storage]
+	pub type Permissionless<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Bonds of the permissionless fishermen
+	#[pallet::storage]
+	pub type Bonds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::Balance, OptionQuery>;
+
+	/// Vetoes awaiting resolution, keyed by the height of the vetoed state commitment
+	#[pallet::storage]
+	pub type PendingVetoes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		StateMachineHeight,
+		PendingVeto<T::AccountId, T::Balance>,
+		OptionQuery,
+	>;
+
+	/// Height of the pending veto executed by each fisherman. A fisherman can only have one pending
+	/// veto at a time, so that its bond covers it, and can't withdraw its bond until the veto is
+	/// resolved.
+	#[pallet::storage]
+	pub type PendingVetoOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, StateMachineHeight, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Account Already Whitelisted
@@ -61,6 +157,23 @@ pub mod pallet {
 		UnauthorizedAction,
 		/// State commitment was not found
 		VetoFailed,
+		/// Fishermen can only bond while the pallet is permissionless
+		NotPermissionless,
+		/// Account has already bonded
+		AlreadyBonded,
+		/// Account has not bonded
+		NotBonded,
+		/// The account has a pending veto, which must be resolved before its bond can be withdrawn
+		/// or back another veto
+		HasPendingVetoes,
+		/// The evidence provided for the veto is invalid
+		InvalidEvidence,
+		/// No pending veto was found at the provided height
+		VetoNotFound,
+		/// A veto at the provided height is already awaiting resolution
+		VetoAlreadyPending,
+		/// The consensus client has already been frozen
+		ConsensusClientFrozen,
 	}
 
 	#[pallet::event]
@@ -72,6 +185,20 @@ pub mod pallet {
 		Removed { account: T::AccountId },
 		/// The provided state commitment was vetoed `state_machine` is by account
 		StateCommitmentVetoed { height: StateMachineHeight, commitment: StateCommitment },
+		/// Bonding to become a fisherman was enabled or disabled
+		PermissionlessSet { enabled: bool },
+		/// An account `account` has bonded `amount` to become a fisherman
+		Bonded { account: T::AccountId, amount: T::Balance },
+		/// An account `account` has withdrawn its bond of `amount`
+		Unbonded { account: T::AccountId, amount: T::Balance },
+		/// A veto at `height` is awaiting resolution, since its evidence could not be verified.
+		/// The state commitment is disputed until then, and only vetoed once the veto is resolved
+		/// as valid.
+		VetoPending { height: StateMachineHeight, account: T::AccountId },
+		/// The fisherman `account` was rewarded `amount` for a successful veto at `height`
+		VetoRewarded { height: StateMachineHeight, account: T::AccountId, amount: T::Balance },
+		/// The bond of the fisherman `account` was slashed for a frivolous veto at `height`
+		FishermanSlashed { height: StateMachineHeight, account: T::AccountId, amount: T::Balance },
 	}
 
 	#[pallet::call]
@@ -121,6 +248,208 @@ pub mod pallet {
 			let ismp_host = <T as Config>::IsmpHost::default();
 			let commitment =
 				ismp_host.state_machine_commitment(height).map_err(|_| Error::<T>::VetoFailed)?;
+			Self::veto(&ismp_host, height, commitment, &account)
+		}
+
+		/// Allow or disallow any account to bond to become a fisherman. Existing bonds are
+		/// unaffected.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as frame_system::Config>::DbWeight::get().writes(1))]
+		pub fn set_permissionless(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Permissionless::<T>::put(enabled);
+
+			Self::deposit_event(Event::PermissionlessSet { enabled });
+			Ok(())
+		}
+
+		/// Bond [`Config::FishermanBond`] to become a fisherman. Bonded fishermen may veto state
+		/// commitments with [`Pallet::veto_with_evidence`].
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as frame_system::Config>::DbWeight::get().reads_writes(4, 3))]
+		pub fn bond(origin: OriginFor<T>) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+			ensure!(Permissionless::<T>::get(), Error::<T>::NotPermissionless);
+			ensure!(!Bonds::<T>::contains_key(&account), Error::<T>::AlreadyBonded);
+
+			let amount = T::FishermanBond::get();
+			<T as pallet_ismp::Config>::Currency::transfer(
+				&account,
+				&FISHERMEN_BOND_ACCOUNT.into_account_truncating(),
+				amount,
+				Preservation::Preserve,
+			)?;
+			Bonds::<T>::insert(&account, amount);
+
+			Self::deposit_event(Event::Bonded { account, amount });
+			Ok(())
+		}
+
+		/// Withdraw the bond of a fisherman, once all of its vetoes have been resolved
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as frame_system::Config>::DbWeight::get().reads_writes(4, 3))]
+		pub fn unbond(origin: OriginFor<T>) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+			let amount = Bonds::<T>::get(&account).ok_or(Error::<T>::NotBonded)?;
+			ensure!(!PendingVetoOf::<T>::contains_key(&account), Error::<T>::HasPendingVetoes);
+
+			<T as pallet_ismp::Config>::Currency::transfer(
+				&FISHERMEN_BOND_ACCOUNT.into_account_truncating(),
+				&account,
+				amount,
+				Preservation::Expendable,
+			)?;
+			Bonds::<T>::remove(&account);
+
+			Self::deposit_event(Event::Unbonded { account, amount });
+			Ok(())
+		}
+
+		/// A bonded fisherman vetoes a [`StateCommitment`] it has determined to be fraudulent,
+		/// providing evidence for it. Conflicting consensus proofs are verified on-chain, in which
+		/// case the state commitment is vetoed, the consensus client is frozen and the fisherman is
+		/// rewarded immediately. A missing block is recorded as a pending veto for the admin to
+		/// resolve with [`Pallet::resolve_veto`], and the state commitment is disputed until then,
+		/// so that it can't be used to verify proofs. A fisherman can only have one pending veto at
+		/// a time.
+		#[pallet::call_index(6)]
+		#[pallet::weight(veto_weight::<T>(&evidence))]
+		pub fn veto_with_evidence(
+			origin: OriginFor<T>,
+			height: StateMachineHeight,
+			evidence: VetoEvidence,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+			let bond = Bonds::<T>::get(&account).ok_or(Error::<T>::NotBonded)?;
+			ensure!(!PendingVetoOf::<T>::contains_key(&account), Error::<T>::HasPendingVetoes);
+
+			let ismp_host = <T as Config>::IsmpHost::default();
+			let commitment =
+				ismp_host.state_machine_commitment(height).map_err(|_| Error::<T>::VetoFailed)?;
+
+			match evidence {
+				VetoEvidence::ConflictingConsensus { ref proof_1, ref proof_2 } => {
+					let consensus_state_id = height.id.consensus_state_id;
+					// A frozen client can't be frozen again, so only the first fraud proof against
+					// a consensus client is rewarded
+					ismp_host
+						.is_consensus_client_frozen(consensus_state_id)
+						.map_err(|_| Error::<T>::ConsensusClientFrozen)?;
+					let client_id = ismp_host
+						.consensus_client_id(consensus_state_id)
+						.ok_or(Error::<T>::InvalidEvidence)?;
+					let client = ismp_host
+						.consensus_client(client_id)
+						.map_err(|_| Error::<T>::InvalidEvidence)?;
+					let trusted_state = ismp_host
+						.consensus_state(consensus_state_id)
+						.map_err(|_| Error::<T>::InvalidEvidence)?;
+					client
+						.verify_fraud_proof(
+							&ismp_host,
+							trusted_state,
+							proof_1.clone(),
+							proof_2.clone(),
+						)
+						.map_err(|_| Error::<T>::InvalidEvidence)?;
+					ismp_host
+						.freeze_consensus_client(consensus_state_id)
+						.map_err(|_| Error::<T>::VetoFailed)?;
+
+					Self::veto(&ismp_host, height, commitment, &account)?;
+					Self::reward(height, account);
+					return Ok(());
+				},
+				// The observed commitment can't be checked against the counterparty on-chain
+				VetoEvidence::ConflictingStateCommitment { .. } =>
+					Err(Error::<T>::InvalidEvidence)?,
+				VetoEvidence::MissingBlock => {},
+			}
+
+			ensure!(!PendingVetoes::<T>::contains_key(height), Error::<T>::VetoAlreadyPending);
+			ismp_host
+				.store_state_commitment_disputed(height, true)
+				.map_err(|_| Error::<T>::VetoFailed)?;
+			PendingVetoes::<T>::insert(
+				height,
+				PendingVeto { fisherman: account.clone(), commitment, evidence, bond },
+			);
+			PendingVetoOf::<T>::insert(&account, height);
+			Self::deposit_event(Event::VetoPending { height, account });
+
+			Ok(())
+		}
+
+		/// Resolve a pending veto. Valid vetoes veto the state commitment and their fishermen are
+		/// rewarded, while frivolous vetoes lift the dispute on the state commitment and their
+		/// fishermen have their bond slashed into the relayer fee pot.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as frame_system::Config>::DbWeight::get().reads_writes(5, 7))]
+		pub fn resolve_veto(
+			origin: OriginFor<T>,
+			height: StateMachineHeight,
+			valid: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let veto = PendingVetoes::<T>::take(height).ok_or(Error::<T>::VetoNotFound)?;
+			PendingVetoOf::<T>::remove(&veto.fisherman);
+
+			let ismp_host = <T as Config>::IsmpHost::default();
+			if valid {
+				Self::veto(&ismp_host, height, veto.commitment, &veto.fisherman)?;
+				Self::reward(height, veto.fisherman);
+				return Ok(());
+			}
+
+			ismp_host
+				.store_state_commitment_disputed(height, false)
+				.map_err(|_| Error::<T>::VetoFailed)?;
+
+			// Only the fisherman's own bond is slashed, the bond account also holds the bonds of
+			// other fishermen
+			let amount =
+				Bonds::<T>::take(&veto.fisherman).unwrap_or_else(Zero::zero).min(veto.bond);
+			<T as pallet_ismp::Config>::Currency::transfer(
+				&FISHERMEN_BOND_ACCOUNT.into_account_truncating(),
+				&RELAYER_FEE_ACCOUNT.into_account_truncating(),
+				amount,
+				Preservation::Expendable,
+			)?;
+
+			Self::deposit_event(Event::FishermanSlashed {
+				height,
+				account: veto.fisherman,
+				amount,
+			});
+			Ok(())
+		}
+	}
+
+	/// Weight of [`Pallet::veto_with_evidence`], consensus proofs are charged by their size
+	fn veto_weight<T: Config>(evidence: &VetoEvidence) -> Weight {
+		let weight = <T as frame_system::Config>::DbWeight::get().reads_writes(6, 7);
+		match evidence {
+			VetoEvidence::ConflictingConsensus { proof_1, proof_2 } => {
+				let len = proof_1.len().saturating_add(proof_2.len()) as u64;
+				weight.saturating_add(T::FraudProofWeightPerByte::get().saturating_mul(len))
+			},
+			_ => weight,
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where
+		T::AccountId: AsRef<[u8]>,
+	{
+		/// Delete a vetoed state commitment
+		fn veto(
+			ismp_host: &<T as Config>::IsmpHost,
+			height: StateMachineHeight,
+			commitment: StateCommitment,
+			account: &T::AccountId,
+		) -> DispatchResult {
 			ismp_host.delete_state_commitment(height).map_err(|_| Error::<T>::VetoFailed)?;
 
 			Self::deposit_event(Event::StateCommitmentVetoed { height, commitment });
@@ -132,5 +461,31 @@ pub mod pallet {
 			);
 			Ok(())
 		}
+
+		/// Pay [`Config::VetoReward`] out of the relayer fee pot to the fisherman of a successful
+		/// veto, or as much of it as the pot can afford
+		fn reward(height: StateMachineHeight, account: T::AccountId) {
+			let pot: T::AccountId = RELAYER_FEE_ACCOUNT.into_account_truncating();
+			let available = <T as pallet_ismp::Config>::Currency::reducible_balance(
+				&pot,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			);
+			let amount = T::VetoReward::get().min(available);
+			if amount.is_zero() {
+				return;
+			}
+
+			if <T as pallet_ismp::Config>::Currency::transfer(
+				&pot,
+				&account,
+				amount,
+				Preservation::Preserve,
+			)
+			.is_ok()
+			{
+				Self::deposit_event(Event::VetoRewarded { height, account, amount });
+			}
+		}
 	}
 }
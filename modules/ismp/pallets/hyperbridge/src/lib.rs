@@ -56,7 +56,7 @@
 
 extern crate alloc;
 
-use alloc::{collections::BTreeMap, format};
+use alloc::{collections::BTreeMap, format, string::ToString};
 use codec::{Decode, Encode};
 use frame_support::{
 	sp_runtime::traits::AccountIdConversion,
@@ -65,7 +65,7 @@ use frame_support::{
 use ismp::{
 	dispatcher::{DispatchRequest, FeeMetadata, IsmpDispatcher},
 	host::StateMachine,
-	module::IsmpModule,
+	module::{IsmpModule, ModuleError, ModuleErrorCode},
 	router::{PostRequest, PostResponse, Response, Timeout},
 };
 pub use pallet::*;
@@ -186,18 +186,23 @@ where
 		request: DispatchRequest,
 		fee: FeeMetadata<Self::Account, Self::Balance>,
 	) -> Result<H256, anyhow::Error> {
-		let fees = match request {
-			DispatchRequest::Post(ref post) => {
+		let payload = match request {
+			DispatchRequest::Post(ref post) => Some((post.dest, post.body.len())),
+			// batches pay for the combined size of their items
+			DispatchRequest::Batch(ref batch) =>
+				Some((batch.dest, batch.items.iter().map(|item| item.body.len()).sum())),
+			DispatchRequest::Get(_) => None,
+		};
+
+		let fees = match payload {
+			Some((dest, size)) => {
 				let VersionedHostParams::V1(params) = Self::host_params();
 				let per_byte_fee: u128 =
-					(*params.per_byte_fees.get(&post.dest).unwrap_or(&params.default_per_byte_fee))
+					(*params.per_byte_fees.get(&dest).unwrap_or(&params.default_per_byte_fee))
 						.into();
 				// minimum fee is 32 bytes
-				let fees = if post.body.len() < 32 {
-					per_byte_fee * 32u128
-				} else {
-					per_byte_fee * post.body.len() as u128
-				};
+				let fees =
+					if size < 32 { per_byte_fee * 32u128 } else { per_byte_fee * size as u128 };
 
 				// collect protocol fees
 				if fees != 0 {
@@ -214,7 +219,7 @@ where
 
 				fees
 			},
-			DispatchRequest::Get(_) => Default::default(),
+			None => Default::default(),
 		};
 
 		let host = <T as Config>::IsmpHost::default();
@@ -293,16 +298,22 @@ where
 	T: Config,
 	T::Balance: Into<u128> + From<u128>,
 {
-	fn on_accept(&self, request: PostRequest) -> Result<(), anyhow::Error> {
+	fn on_accept(&self, request: PostRequest) -> Result<(), ModuleError> {
 		// this of course assumes that hyperbridge is configured as the coprocessor.
 		let source = request.source;
 		if Some(source) != T::Coprocessor::get() {
-			Err(ismp::Error::Custom(format!("Invalid request source: {source}")))?
+			Err(module_error(
+				ModuleErrorCode::Unauthorized,
+				format!("Invalid request source: {source}"),
+			))?
 		}
 
 		let message =
 			Message::<T::AccountId, T::Balance>::decode(&mut &request.body[..]).map_err(|err| {
-				ismp::Error::Custom(format!("Failed to decode per-byte fee: {err:?}"))
+				module_error(
+					ModuleErrorCode::DecodeFailed,
+					format!("Failed to decode per-byte fee: {err:?}"),
+				)
 			})?;
 
 		match message {
@@ -319,7 +330,10 @@ where
 					Preservation::Expendable,
 				)
 				.map_err(|err| {
-					ismp::Error::Custom(format!("Error withdrawing protocol fees: {err:?}"))
+					module_error(
+						ModuleErrorCode::Unavailable,
+						format!("Error withdrawing protocol fees: {err:?}"),
+					)
 				})?;
 
 				Self::deposit_event(Event::<T>::ProtocolRevenueWithdrawn { account, amount })
@@ -332,7 +346,10 @@ where
 					Preservation::Expendable,
 				)
 				.map_err(|err| {
-					ismp::Error::Custom(format!("Error withdrawing protocol fees: {err:?}"))
+					module_error(
+						ModuleErrorCode::Unavailable,
+						format!("Error withdrawing protocol fees: {err:?}"),
+					)
 				})?;
 
 				Self::deposit_event(Event::<T>::RelayerFeeWithdrawn { account, amount })
@@ -342,13 +359,18 @@ where
 		Ok(())
 	}
 
-	fn on_response(&self, _response: Response) -> Result<(), anyhow::Error> {
+	fn on_response(&self, _response: Response) -> Result<(), ModuleError> {
 		// this module does not expect responses
-		Err(ismp::Error::CannotHandleMessage.into())
+		Err(module_error(ModuleErrorCode::Unsupported, "Module does not accept responses"))
 	}
 
-	fn on_timeout(&self, _request: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, _request: Timeout) -> Result<(), ModuleError> {
 		// this module does not dispatch requests
-		Err(ismp::Error::CannotHandleMessage.into())
+		Err(module_error(ModuleErrorCode::Unsupported, "Module does not dispatch requests"))
 	}
 }
+
+/// Returns a [`ModuleError`] originating from this pallet
+fn module_error(code: ModuleErrorCode, message: impl ToString) -> ModuleError {
+	ModuleError::new(code, PALLET_HYPERBRIDGE_ID, message)
+}
@@ -18,9 +18,9 @@
 use crate::{
 	child_trie::{RequestCommitments, RequestReceipts, ResponseCommitments},
 	mmr::LeafIndexAndPos,
-	Config, Pallet, RELAYER_FEE_ACCOUNT,
+	ChannelNonces, Config, Pallet, RELAYER_FEE_ACCOUNT,
 };
-use alloc::{boxed::Box, format, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeSet, format, vec::Vec};
 use core::marker::PhantomData;
 use frame_support::traits::{fungible::Mutate, tokens::Preservation, UnixTime};
 use ismp::{
@@ -28,10 +28,13 @@ use ismp::{
 	dispatcher::{DispatchRequest, IsmpDispatcher},
 	error::Error as IsmpError,
 	events::Meta,
-	host::IsmpHost,
+	host::{IsmpHost, StateMachine},
 	messaging::{hash_post_response, hash_request},
-	module::IsmpModule,
-	router::{GetRequest, IsmpRouter, PostRequest, PostResponse, Request, Response, Timeout},
+	module::{IsmpModule, ModuleError, ModuleErrorCode},
+	router::{
+		ChannelId, GetRequest, IsmpRouter, PostBatch, PostRequest, PostResponse, Request, Response,
+		Timeout,
+	},
 };
 use sp_core::H256;
 use sp_runtime::traits::{AccountIdConversion, Zero};
@@ -82,6 +85,25 @@ where
 		}
 
 		let request = match request {
+			DispatchRequest::Batch(dispatch_batch) => {
+				let modules = dispatch_batch.items.iter().map(|item| item.to.clone());
+				let nonce = self.outgoing_nonce(dispatch_batch.dest, modules)?;
+				let batch = PostBatch {
+					source: self.host_state_machine(),
+					dest: dispatch_batch.dest,
+					nonce,
+					from: dispatch_batch.from,
+					timeout_timestamp: if dispatch_batch.timeout == 0 {
+						0
+					} else {
+						<T::TimestampProvider as UnixTime>::now()
+							.as_secs()
+							.saturating_add(dispatch_batch.timeout)
+					},
+					items: dispatch_batch.items,
+				};
+				return Ok(Pallet::<T>::dispatch_batch(batch, fee)?)
+			},
 			DispatchRequest::Get(dispatch_get) => {
 				let get = GetRequest {
 					source: self.host_state_machine(),
@@ -100,10 +122,12 @@ where
 				Request::Get(get)
 			},
 			DispatchRequest::Post(dispatch_post) => {
+				let module = core::iter::once(dispatch_post.to.clone());
+				let nonce = self.outgoing_nonce(dispatch_post.dest, module)?;
 				let post = PostRequest {
 					source: self.host_state_machine(),
 					dest: dispatch_post.dest,
-					nonce: self.next_nonce(),
+					nonce,
 					from: dispatch_post.from,
 					to: dispatch_post.to,
 					timeout_timestamp: if dispatch_post.timeout == 0 {
@@ -158,6 +182,35 @@ where
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Returns the nonce of an outgoing POST request or batch to the given modules. Requests on an
+	/// ordered outgoing channel are assigned the consecutive nonces of their channel, every other
+	/// request is assigned the next global request nonce. A batch can only be dispatched on a
+	/// single ordered channel, as it carries a single nonce.
+	fn outgoing_nonce(
+		&self,
+		dest: StateMachine,
+		modules: impl Iterator<Item = Vec<u8>>,
+	) -> Result<u64, IsmpError> {
+		let source = self.host_state_machine();
+		let ordered = modules
+			.map(|module| ChannelId { source, dest, module })
+			.filter(|channel| ChannelNonces::<T>::contains_key(channel))
+			.collect::<BTreeSet<_>>();
+		let mut ordered = ordered.into_iter();
+		match (ordered.next(), ordered.next()) {
+			(None, _) => Ok(self.next_nonce()),
+			(Some(channel), None) => {
+				let nonce = ChannelNonces::<T>::get(&channel).unwrap_or_default();
+				ChannelNonces::<T>::insert(&channel, nonce + 1);
+				Ok(nonce)
+			},
+			(Some(_), Some(_)) =>
+				Err(IsmpError::Custom("Request batch spans several ordered channels".into())),
+		}
+	}
+}
+
 /// An [`IsmpRouter`] implementation that delegates to an inner module which always refunds
 /// relayer fees on_timeout.
 pub(crate) struct RefundingRouter<T> {
@@ -199,27 +252,33 @@ impl<T: Config> RefundingModule<T> {
 }
 
 impl<T: Config> IsmpModule for RefundingModule<T> {
-	fn on_accept(&self, request: PostRequest) -> Result<(), anyhow::Error> {
+	fn on_accept(&self, request: PostRequest) -> Result<(), ModuleError> {
 		self.inner.on_accept(request)
 	}
 
-	fn on_response(&self, response: Response) -> Result<(), anyhow::Error> {
+	fn on_response(&self, response: Response) -> Result<(), ModuleError> {
 		self.inner.on_response(response)
 	}
 
-	fn on_timeout(&self, timeout: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, timeout: Timeout) -> Result<(), ModuleError> {
 		let result = self.inner.on_timeout(timeout.clone());
 
 		// only refund if module returns Ok(())
 		if result.is_ok() {
-			let fee_metadata = match timeout {
+			let (module, fee_metadata) = match timeout {
 				Timeout::Request(request) => {
 					let commitment = hash_request::<Pallet<T>>(&request);
-					RequestCommitments::<T>::get(commitment).map(|meta| meta.fee)
+					(
+						request.source_module(),
+						RequestCommitments::<T>::get(commitment).map(|meta| meta.fee),
+					)
 				},
 				Timeout::Response(response) => {
 					let commitment = hash_post_response::<Pallet<T>>(&response);
-					ResponseCommitments::<T>::get(commitment).map(|meta| meta.fee)
+					(
+						response.source_module(),
+						ResponseCommitments::<T>::get(commitment).map(|meta| meta.fee),
+					)
 				},
 			};
 
@@ -232,7 +291,11 @@ impl<T: Config> IsmpModule for RefundingModule<T> {
 						Preservation::Expendable,
 					)
 					.map_err(|err| {
-						IsmpError::Custom(format!("Error withdrawing request fees: {err:?}"))
+						ModuleError::new(
+							ModuleErrorCode::Internal,
+							module,
+							format!("Error withdrawing request fees: {err:?}"),
+						)
 					})?;
 				}
 			}
@@ -17,6 +17,7 @@
 
 use alloc::string::ToString;
 use codec::{Decode, Encode};
+use ismp::module::ModuleError;
 use sp_core::ConstU32;
 use sp_runtime::BoundedVec;
 use sp_std::prelude::*;
@@ -25,12 +26,30 @@ use sp_std::prelude::*;
 #[allow(missing_docs)]
 pub struct HandlingError {
 	message: BoundedVec<u8, ConstU32<1000>>,
+	/// The structured error, if the message failed in a module callback
+	module_error: Option<ModuleError>,
+}
+
+impl HandlingError {
+	/// The structured error, if the message failed in a module callback
+	pub fn module_error(&self) -> Option<&ModuleError> {
+		self.module_error.as_ref()
+	}
 }
 
 impl From<anyhow::Error> for HandlingError {
 	fn from(value: anyhow::Error) -> Self {
 		let mut message = value.to_string().as_bytes().to_vec();
 		message.truncate(1000);
-		Self { message: message.try_into().unwrap_or_default() }
+		Self {
+			message: message.try_into().unwrap_or_default(),
+			module_error: value.downcast_ref::<ModuleError>().cloned(),
+		}
+	}
+}
+
+impl From<ModuleError> for HandlingError {
+	fn from(value: ModuleError) -> Self {
+		anyhow::Error::from(value).into()
 	}
 }
@@ -48,6 +48,11 @@ impl<T: Config> TryFrom<PalletEvent<T>> for ismp::events::Event {
 
 				Ok(event)
 			},
+			PalletEvent::RequestBatch { commitment, .. } => Ok(ismp::events::Event::PostBatch(
+				Pallet::<T>::batch(commitment).ok_or_else(|| ())?,
+			)),
+			PalletEvent::PostBatchTimeoutHandled(handled) =>
+				Ok(ismp::events::Event::PostBatchTimeoutHandled(handled)),
 			PalletEvent::GetRequestTimeoutHandled(handled) =>
 				Ok(ismp::events::Event::GetRequestTimeoutHandled(handled)),
 			PalletEvent::GetRequestHandled(handled) =>
@@ -69,6 +74,11 @@ impl<T: Config> TryFrom<PalletEvent<T>> for ismp::events::Event {
 			PalletEvent::ConsensusClientCreated { .. } |
 			PalletEvent::ConsensusClientFrozen { .. } |
 			PalletEvent::Errors { .. } |
+			PalletEvent::BatchHandled { .. } |
+			PalletEvent::ChannelOrderingUpdated { .. } |
+			PalletEvent::ConsensusClientStatusUpdated { .. } |
+			PalletEvent::ConsensusClientDeprecationUpdated { .. } |
+			PalletEvent::ConsensusStateMigrated { .. } |
 			PalletEvent::__Ignore(_, _) => Err(()),
 		}
 	}
@@ -89,6 +99,8 @@ impl<T: Config> From<ismp::events::Event> for PalletEvent<T> {
 				PalletEvent::<T>::GetRequestHandled(handled),
 			ismp::events::Event::GetRequestTimeoutHandled(handled) =>
 				PalletEvent::<T>::GetRequestTimeoutHandled(handled),
+			ismp::events::Event::PostBatchTimeoutHandled(handled) =>
+				PalletEvent::<T>::PostBatchTimeoutHandled(handled),
 			ismp::events::Event::StateMachineUpdated(ev) => PalletEvent::<T>::StateMachineUpdated {
 				state_machine_id: ev.state_machine_id,
 				latest_height: ev.latest_height,
@@ -103,7 +115,8 @@ impl<T: Config> From<ismp::events::Event> for PalletEvent<T> {
 			ismp::events::Event::PostRequest(_) |
 			ismp::events::Event::PostResponse(_) |
 			ismp::events::Event::GetRequest(_) |
-			ismp::events::Event::GetResponse(_) => {
+			ismp::events::Event::GetResponse(_) |
+			ismp::events::Event::PostBatch(_) => {
 				unimplemented!("These events should not originate from handler")
 			},
 		}
@@ -18,8 +18,9 @@
 use crate::{
 	child_trie,
 	dispatcher::{RefundingRouter, RequestMetadata},
-	utils::{ConsensusClientProvider, ResponseReceipt},
-	ChallengePeriod, Config, ConsensusClientUpdateTime, ConsensusStateClient, ConsensusStates,
+	utils::{ConsensusClientProvider, ConsensusClientStatus, ResponseReceipt},
+	ChallengePeriod, ChannelNonces, Config, ConsensusClientStatuses, ConsensusClientUpdateTime,
+	ConsensusStateClient, ConsensusStateVersions, ConsensusStates, DisputedStateCommitments,
 	FrozenConsensusClients, LatestStateMachineHeight, Nonce, Pallet, Responded,
 	StateMachineUpdateTime, UnbondingPeriod,
 };
@@ -34,8 +35,8 @@ use ismp::{
 	},
 	error::Error,
 	host::{IsmpHost, StateMachine},
-	messaging::{hash_post_response, hash_request, hash_response},
-	router::{IsmpRouter, PostResponse, Request, Response},
+	messaging::{hash_batch, hash_post_response, hash_request, hash_response},
+	router::{ChannelId, IsmpRouter, PostBatch, PostResponse, Request, Response},
 };
 use sp_core::H256;
 use sp_runtime::SaturatedConversion;
@@ -98,6 +99,24 @@ impl<T: Config> IsmpHost for Pallet<T> {
 		Ok(())
 	}
 
+	fn is_consensus_client_disabled(&self, client: ConsensusStateId) -> Result<(), Error> {
+		if ConsensusClientStatuses::<T>::get(client) == ConsensusClientStatus::Disabled {
+			Err(Error::ConsensusClientDisabled { consensus_state_id: client })?
+		}
+		Ok(())
+	}
+
+	fn is_consensus_client_paused(&self, client: ConsensusStateId) -> Result<(), Error> {
+		if ConsensusClientStatuses::<T>::get(client) == ConsensusClientStatus::Paused {
+			Err(Error::ConsensusClientPaused { consensus_state_id: client })?
+		}
+		Ok(())
+	}
+
+	fn consensus_state_version(&self, client: ConsensusStateId) -> Option<u32> {
+		Some(ConsensusStateVersions::<T>::get(client))
+	}
+
 	fn request_commitment(&self, commitment: H256) -> Result<(), Error> {
 		let _ = child_trie::RequestCommitments::<T>::get(commitment)
 			.ok_or_else(|| Error::Custom("Request commitment not found".to_string()))?;
@@ -138,6 +157,11 @@ impl<T: Config> IsmpHost for Pallet<T> {
 		Some(())
 	}
 
+	fn batch_receipt(&self, batch: &PostBatch) -> Option<()> {
+		let commitment = hash_batch::<Self>(batch);
+		child_trie::RequestReceipts::<T>::get(commitment).map(|_| ())
+	}
+
 	fn store_consensus_state_id(
 		&self,
 		consensus_state_id: ConsensusStateId,
@@ -193,6 +217,7 @@ impl<T: Config> IsmpHost for Pallet<T> {
 
 	fn delete_state_commitment(&self, height: StateMachineHeight) -> Result<(), Error> {
 		child_trie::StateCommitments::<T>::remove(height);
+		DisputedStateCommitments::<T>::remove(height);
 
 		// technically any state commitment can be vetoed,
 		// safety check that it's the latest before resetting it.
@@ -235,6 +260,15 @@ impl<T: Config> IsmpHost for Pallet<T> {
 		Ok(meta.encode())
 	}
 
+	fn delete_batch_commitment(&self, batch: &PostBatch) -> Result<Vec<u8>, Error> {
+		let hash = hash_batch::<Self>(batch);
+		// We can't delete actual leaves in the mmr so this serves as a replacement for that
+		let meta = child_trie::RequestCommitments::<T>::get(hash)
+			.ok_or_else(|| Error::Custom("Batch Commitment not found".to_string()))?;
+		child_trie::RequestCommitments::<T>::remove(hash);
+		Ok(meta.encode())
+	}
+
 	fn delete_request_receipt(&self, req: &Request) -> Result<Vec<u8>, Error> {
 		let req_commitment = hash_request::<Self>(req);
 		let relayer = child_trie::RequestReceipts::<T>::get(req_commitment)
@@ -251,12 +285,26 @@ impl<T: Config> IsmpHost for Pallet<T> {
 		Ok(meta.relayer)
 	}
 
+	fn delete_batch_receipt(&self, batch: &PostBatch) -> Result<Vec<u8>, Error> {
+		let commitment = hash_batch::<Self>(batch);
+		let relayer = child_trie::RequestReceipts::<T>::get(commitment)
+			.ok_or_else(|| Error::Custom("Batch receipt not found".to_string()))?;
+		child_trie::RequestReceipts::<T>::remove(commitment);
+		Ok(relayer)
+	}
+
 	fn store_request_receipt(&self, req: &Request, signer: &Vec<u8>) -> Result<(), Error> {
 		let hash = hash_request::<Self>(req);
 		child_trie::RequestReceipts::<T>::insert(hash, signer);
 		Ok(())
 	}
 
+	fn store_batch_receipt(&self, batch: &PostBatch, signer: &Vec<u8>) -> Result<(), Error> {
+		let hash = hash_batch::<Self>(batch);
+		child_trie::RequestReceipts::<T>::insert(hash, signer);
+		Ok(())
+	}
+
 	fn store_response_receipt(&self, res: &Response, signer: &Vec<u8>) -> Result<(), Error> {
 		let hash = hash_request::<Self>(&res.request());
 		let response = hash_response::<Self>(&res);
@@ -296,6 +344,32 @@ impl<T: Config> IsmpHost for Pallet<T> {
 		Box::new(RefundingRouter::<T>::new(Box::new(T::Router::default())))
 	}
 
+	fn next_channel_nonce(&self, channel: &ChannelId) -> Option<u64> {
+		ChannelNonces::<T>::get(channel)
+	}
+
+	fn store_next_channel_nonce(&self, channel: &ChannelId, nonce: u64) -> Result<(), Error> {
+		ChannelNonces::<T>::insert(channel, nonce);
+		Ok(())
+	}
+
+	fn is_state_commitment_disputed(&self, height: StateMachineHeight) -> bool {
+		DisputedStateCommitments::<T>::contains_key(height)
+	}
+
+	fn store_state_commitment_disputed(
+		&self,
+		height: StateMachineHeight,
+		disputed: bool,
+	) -> Result<(), Error> {
+		if disputed {
+			DisputedStateCommitments::<T>::insert(height, ());
+		} else {
+			DisputedStateCommitments::<T>::remove(height);
+		}
+		Ok(())
+	}
+
 	fn store_request_commitment(&self, req: &Request, meta: Vec<u8>) -> Result<(), Error> {
 		let hash = hash_request::<Self>(req);
 		let leaf_meta = RequestMetadata::<T>::decode(&mut &*meta)
@@ -313,6 +387,14 @@ impl<T: Config> IsmpHost for Pallet<T> {
 		Responded::<T>::insert(req_commitment, true);
 		Ok(())
 	}
+
+	fn store_batch_commitment(&self, batch: &PostBatch, meta: Vec<u8>) -> Result<(), Error> {
+		let hash = hash_batch::<Self>(batch);
+		let leaf_meta = RequestMetadata::<T>::decode(&mut &*meta)
+			.map_err(|_| Error::Custom("Failed to decode leaf metadata".to_string()))?;
+		child_trie::RequestCommitments::<T>::insert(hash, leaf_meta);
+		Ok(())
+	}
 }
 
 impl<T: Config> ismp::messaging::Keccak256 for Pallet<T> {
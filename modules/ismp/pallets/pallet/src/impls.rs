@@ -19,17 +19,28 @@ use crate::{
 	child_trie::{RequestCommitments, ResponseCommitments},
 	dispatcher::{FeeMetadata, RequestMetadata},
 	mmr::{Leaf, LeafIndexAndPos, Proof, ProofKeys},
+	utils::ConsensusClientInfo,
 	weights::get_weight,
-	Config, Error, Event, NoOpMmrTree, Pallet, Responded,
+	Config, ConsensusClientStatuses, ConsensusStateClient, ConsensusStateVersions,
+	DeprecatedConsensusClients, Error, Event, FrozenConsensusClients, NoOpMmrTree, Pallet,
+	Responded,
 };
 use alloc::{string::ToString, vec, vec::Vec};
 use codec::Decode;
-use frame_support::dispatch::{DispatchResultWithPostInfo, Pays, PostDispatchInfo};
+use frame_support::{
+	dispatch::{DispatchResultWithPostInfo, Pays, PostDispatchInfo},
+	storage::{with_transaction_opaque_err, TransactionOutcome},
+};
 use frame_system::Phase;
 use ismp::{
-	handlers::{handle_incoming_message, MessageResult},
-	messaging::{hash_request, hash_response, Message},
-	router::{Request, Response},
+	consensus::ConsensusStateId,
+	handlers::{
+		handle_incoming_message, handle_incoming_messages_with, MessageOutcome, MessageResult,
+		MessageStatus,
+	},
+	host::IsmpHost,
+	messaging::{hash_batch, hash_request, hash_response, Message},
+	router::{PostBatch, Request, Response},
 };
 use log::debug;
 use mmr_primitives::{ForkIdentifier, MerkleMountainRangeTree};
@@ -106,6 +117,7 @@ impl<T: Config> Pallet<T> {
 					})
 					.flatten()
 					.collect::<Result<Vec<_>, _>>()
+					.map_err(anyhow::Error::from)
 			})
 			.map_err(|err| {
 				debug!(target: "ismp", "Handling Error {:?}", err);
@@ -124,6 +136,82 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Handles each message independently, messages that fail are rolled back without affecting
+	/// the rest of the batch. Fails only if none of the messages could be handled.
+	pub fn handle_messages_partially(messages: Vec<Message>) -> DispatchResultWithPostInfo {
+		let outcomes = Self::handle_messages_isolated(messages.clone());
+
+		let statuses = outcomes.iter().map(MessageOutcome::status).collect::<Vec<_>>();
+		let mut errors = vec![];
+		for outcome in outcomes {
+			match outcome {
+				MessageOutcome::Success(result) => match result {
+					MessageResult::Request(results) |
+					MessageResult::Response(results) |
+					MessageResult::Timeout(results) =>
+						for event in results.into_iter().flatten() {
+							Pallet::<T>::deposit_event(event.into())
+						},
+					MessageResult::ConsensusMessage(events) =>
+						for event in events {
+							Pallet::<T>::deposit_event(event.into())
+						},
+					MessageResult::FrozenClient(_) => {},
+				},
+				MessageOutcome::AlreadyProcessed => {},
+				MessageOutcome::ChallengePeriodNotElapsed(err) |
+				MessageOutcome::ProofInvalid(err) |
+				MessageOutcome::Failed(err) => {
+					debug!(target: "ismp", "Handling Error {:?}", err);
+					errors.push(err.into())
+				},
+			}
+		}
+
+		if !errors.is_empty() {
+			Pallet::<T>::deposit_event(Event::<T>::Errors { errors });
+		}
+
+		if !statuses.iter().any(MessageStatus::is_success) {
+			Err(Error::<T>::InvalidMessage)?
+		}
+		Pallet::<T>::deposit_event(Event::<T>::BatchHandled { statuses });
+
+		Ok(PostDispatchInfo {
+			actual_weight: Some(get_weight::<T>(&messages)),
+			pays_fee: Pays::Yes,
+		})
+	}
+
+	/// Handles each message in its own storage transaction. The transaction is rolled back if the
+	/// message fails or if any of its requests, responses or timeouts could not be dispatched, so
+	/// that the message can be retried as a whole.
+	pub fn handle_messages_isolated(messages: Vec<Message>) -> Vec<MessageOutcome> {
+		let host = Pallet::<T>::default();
+		handle_incoming_messages_with(&host, messages, |host, message| {
+			with_transaction_opaque_err(|| {
+				let result = match handle_incoming_message(host, message) {
+					Ok(result) => result,
+					Err(err) => return TransactionOutcome::Rollback(Err(err)),
+				};
+
+				match result {
+					MessageResult::Request(results) |
+					MessageResult::Response(results) |
+					MessageResult::Timeout(results)
+						if results.iter().any(|result| result.is_err()) =>
+						TransactionOutcome::Rollback(Err(results
+							.into_iter()
+							.find_map(|result| result.err())
+							.expect("At least one dispatch result is an error; qed")
+							.into())),
+					result => TransactionOutcome::Commit(Ok(result)),
+				}
+			})
+			.unwrap_or_else(|_| Err(anyhow::anyhow!("Exceeded the storage transaction limit")))
+		})
+	}
+
 	/// Dispatch an outgoing request, returns the request commitment
 	pub fn dispatch_request(request: Request, meta: FeeMetadata<T>) -> Result<H256, ismp::Error> {
 		let commitment = hash_request::<Pallet<T>>(&request);
@@ -158,6 +246,44 @@ impl<T: Config> Pallet<T> {
 		Ok(commitment)
 	}
 
+	/// Dispatch an outgoing request batch, returns the batch commitment. The batch is committed
+	/// alongside requests, so it can be proven and its fees claimed by its commitment.
+	pub fn dispatch_batch(batch: PostBatch, meta: FeeMetadata<T>) -> Result<H256, ismp::Error> {
+		if batch.items.is_empty() {
+			Err(ismp::Error::Custom("Request batch is empty".to_string()))?
+		}
+
+		let commitment = hash_batch::<Pallet<T>>(&batch);
+
+		if RequestCommitments::<T>::contains_key(commitment) {
+			Err(ismp::Error::Custom("Duplicate request batch".to_string()))?
+		}
+
+		let (dest_chain, source_chain, nonce) = (batch.dest, batch.source, batch.nonce);
+		let leaf_index_and_pos = T::Mmr::push(Leaf::Batch(batch));
+		// Deposit Event
+		Pallet::<T>::deposit_event(Event::RequestBatch {
+			request_nonce: nonce,
+			source_chain,
+			dest_chain,
+			commitment,
+		});
+
+		RequestCommitments::<T>::insert(
+			commitment,
+			RequestMetadata {
+				mmr: LeafIndexAndPos {
+					leaf_index: leaf_index_and_pos.index,
+					pos: leaf_index_and_pos.position,
+				},
+				fee: meta,
+				claimed: false,
+			},
+		);
+
+		Ok(commitment)
+	}
+
 	/// Dispatch an outgoing response, returns the response commitment
 	pub fn dispatch_response(
 		response: Response,
@@ -219,6 +345,27 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Gets the request batch from the offchain storage
+	pub fn batch(commitment: H256) -> Option<PostBatch> {
+		let pos = RequestCommitments::<T>::get(commitment)?.mmr.pos;
+		match T::Mmr::get_leaf(pos) {
+			Ok(Some(Leaf::Batch(batch))) => Some(batch),
+			_ => {
+				// Try getting the batch from the offchain db using `NoOpMmrTree`
+				let offchain_key = NoOpMmrTree::<T>::offchain_key(commitment);
+				let Some(elem) =
+					sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &offchain_key)
+				else {
+					None?
+				};
+				match Leaf::decode(&mut &*elem).ok() {
+					Some(Leaf::Batch(batch)) => Some(batch),
+					_ => None,
+				}
+			},
+		}
+	}
+
 	/// Gets the response from the offchain storage
 	pub fn response(commitment: H256) -> Option<Response> {
 		let pos = ResponseCommitments::<T>::get(commitment)?.mmr.pos;
@@ -285,6 +432,34 @@ impl<T: Config> Pallet<T> {
 	pub fn responses(commitments: Vec<H256>) -> Vec<Response> {
 		commitments.into_iter().filter_map(|cm| Self::response(cm)).collect()
 	}
+
+	/// Returns the registry information about the consensus client of a consensus state
+	pub fn consensus_client_info(
+		consensus_state_id: ConsensusStateId,
+	) -> Option<ConsensusClientInfo> {
+		let consensus_client_id = ConsensusStateClient::<T>::get(consensus_state_id)?;
+		let client_version = Pallet::<T>::default()
+			.consensus_client(consensus_client_id)
+			.ok()
+			.map(|client| client.version());
+
+		Some(ConsensusClientInfo {
+			consensus_state_id,
+			consensus_client_id,
+			status: ConsensusClientStatuses::<T>::get(consensus_state_id),
+			deprecated: DeprecatedConsensusClients::<T>::get(consensus_state_id),
+			frozen: FrozenConsensusClients::<T>::get(consensus_state_id),
+			state_version: ConsensusStateVersions::<T>::get(consensus_state_id),
+			client_version,
+		})
+	}
+
+	/// Returns the registry information about the consensus clients of all consensus states
+	pub fn consensus_client_registry() -> Vec<ConsensusClientInfo> {
+		ConsensusStateClient::<T>::iter_keys()
+			.filter_map(|consensus_state_id| Self::consensus_client_info(consensus_state_id))
+			.collect()
+	}
 }
 
 impl<T: Config> ForkIdentifier<T> for Pallet<T> {
@@ -64,6 +64,10 @@
 //! * `handle` - Handles incoming ISMP messages.
 //! * `handle_unsigned` Unsigned variant for handling incoming messages, enabled by `feature =
 //!   ["unsigned"]`
+//! * `handle_partial` - Handles incoming ISMP messages independently of each other, messages that
+//!   fail are reported in a `BatchHandled` event instead of reverting the whole batch.
+//! * `handle_unsigned_partial` Unsigned variant of `handle_partial`, enabled by `feature =
+//!   ["unsigned"]`
 //! * `create_consensus_client` - Handles creation of various properties for a particular consensus
 //!   client. Can only be called by the `AdminOrigin`.
 //! * `update_consensus_state` - Updates consensus client properties in storage. Can only be called
@@ -72,6 +76,15 @@
 //!   insufficient, perhaps due to a transaction fee spike on the destination chain. Allows a user
 //!   to add more funds to the message to be used for delivery and execution. Should never be called
 //!   on a completed message.
+//! * `set_channel_ordering` - Orders a channel to or from this state machine, so that its requests
+//!   are assigned consecutive nonces and delivered in that order, or makes it unordered again. Can
+//!   only be called by the `AdminOrigin`.
+//! * `set_consensus_client_status` - Enables, pauses or disables the consensus client of a
+//!   consensus state. Can only be called by the `AdminOrigin`.
+//! * `set_consensus_client_deprecated` - Marks the consensus client of a consensus state as
+//!   deprecated. Can only be called by the `AdminOrigin`.
+//! * `migrate_consensus_state` - Migrates a consensus state to the encoding of the current version
+//!   of its consensus client. Can only be called by the `AdminOrigin`.
 //!
 //! Please refer to the [`Call`](pallet/enum.Call.html) enum and its associated
 //! variants for documentation on each function.
@@ -86,7 +99,7 @@
 //! use ismp::Error;
 //! use pallet_ismp::NoOpMmrTree;
 //! use ismp::host::StateMachine;
-//! use ismp::module::IsmpModule;
+//! use ismp::module::{IsmpModule, ModuleError};
 //! use ismp::router::{IsmpRouter, Post, Response, Timeout};
 //!
 //! parameter_types! {
@@ -147,21 +160,21 @@
 //! impl IsmpModule for YourModule {
 //!     /// Called by the ISMP hanlder, to notify module of a new POST request
 //!     /// the module may choose to respond immediately, or in a later block
-//!     fn on_accept(&self, request: Post) -> Result<(), Error> {
+//!     fn on_accept(&self, request: Post) -> Result<(), ModuleError> {
 //!         // do something useful with the request
 //!         Ok(())
 //!     }
 //!
 //!     /// Called by the ISMP hanlder, to notify module of a response to a previously
 //!     /// sent out request
-//!     fn on_response(&self, response: Response) -> Result<(), Error> {
+//!     fn on_response(&self, response: Response) -> Result<(), ModuleError> {
 //!         // do something useful with the response
 //!         Ok(())
 //!     }
 //!
 //!     /// Called by the ISMP hanlder, to notify module of requests that were previously
 //!     /// sent but have now timed-out
-//! 	fn on_timeout(&self, request: Timeout) -> Result<(), Error> {
+//! 	fn on_timeout(&self, request: Timeout) -> Result<(), ModuleError> {
 //!         // revert any state changes that were made prior to dispatching the request
 //!         Ok(())
 //!     }
@@ -181,6 +194,7 @@ pub mod errors;
 pub mod events;
 pub mod host;
 mod impls;
+pub mod migrations;
 pub mod mmr;
 mod utils;
 pub mod weights;
@@ -224,10 +238,10 @@ pub mod pallet {
 			StateMachineId,
 		},
 		events::{RequestResponseHandled, TimeoutHandled},
-		handlers,
+		handlers::{self, MessageStatus},
 		host::{IsmpHost, StateMachine},
 		messaging::{CreateConsensusState, Message},
-		router::IsmpRouter,
+		router::{ChannelId, IsmpRouter},
 	};
 	use sp_core::{storage::ChildInfo, H256};
 	#[cfg(feature = "unsigned")]
@@ -317,7 +331,11 @@ pub mod pallet {
 
 	// Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
 	// method.
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -388,6 +406,39 @@ pub mod pallet {
 	#[pallet::getter(fn nonce)]
 	pub type Nonce<T> = StorageValue<_, u64, ValueQuery>;
 
+	/// The registry status of the consensus client of each consensus state, consensus clients
+	/// are enabled by default.
+	#[pallet::storage]
+	#[pallet::getter(fn consensus_client_status)]
+	pub type ConsensusClientStatuses<T: Config> =
+		StorageMap<_, Blake2_128Concat, ConsensusStateId, ConsensusClientStatus, ValueQuery>;
+
+	/// Consensus states whose consensus client has been marked as deprecated
+	#[pallet::storage]
+	#[pallet::getter(fn deprecated_consensus_clients)]
+	pub type DeprecatedConsensusClients<T: Config> =
+		StorageMap<_, Blake2_128Concat, ConsensusStateId, bool, ValueQuery>;
+
+	/// The version of the consensus client that encoded each consensus state
+	#[pallet::storage]
+	#[pallet::getter(fn consensus_state_version)]
+	pub type ConsensusStateVersions<T: Config> =
+		StorageMap<_, Blake2_128Concat, ConsensusStateId, u32, ValueQuery>;
+
+	/// The next nonce of each ordered channel, channels that are not present are unordered. For
+	/// incoming channels this is the nonce of the next request to be delivered, for outgoing
+	/// channels it is the nonce assigned to the next request dispatched on the channel.
+	#[pallet::storage]
+	#[pallet::getter(fn channel_nonce)]
+	pub type ChannelNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, ChannelId, u64, OptionQuery>;
+
+	/// State commitments disputed by a fisherman, they can't be used to verify proofs until the
+	/// dispute is resolved
+	#[pallet::storage]
+	pub type DisputedStateCommitments<T: Config> =
+		StorageMap<_, Blake2_128Concat, StateMachineHeight, (), OptionQuery>;
+
 	/// The child trie root of messages
 	#[pallet::storage]
 	#[pallet::getter(fn child_trie_root)]
@@ -486,6 +537,12 @@ pub mod pallet {
 			let result = handlers::create_client(&host, message)
 				.map_err(|_| Error::<T>::ConsensusClientCreationFailed)?;
 
+			let version = host
+				.consensus_client(result.consensus_client_id)
+				.map_err(|_| Error::<T>::ConsensusClientCreationFailed)?
+				.version();
+			ConsensusStateVersions::<T>::insert(result.consensus_state_id, version);
+
 			Self::deposit_event(Event::<T>::ConsensusClientCreated {
 				consensus_client_id: result.consensus_client_id,
 			});
@@ -564,6 +621,212 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Execute the provided batch of ISMP messages, handling each message independently.
+		/// Messages that fail are rolled back and reported, without reverting the rest of the
+		/// batch. This is an unsigned extrinsic that permits anyone to execute ISMP messages for
+		/// free, the batch is only admitted to the pool if all of its messages can be handled.
+		///
+		/// The dispatch origin for this call must be an unsigned one.
+		///
+		/// - `messages`: the messages to handle or process.
+		///
+		/// Emits [`Event::BatchHandled`] with the status of each message if successful.
+		#[cfg(feature = "unsigned")]
+		#[pallet::weight(get_weight::<T>(&messages))]
+		#[pallet::call_index(5)]
+		#[frame_support::transactional]
+		pub fn handle_unsigned_partial(
+			origin: OriginFor<T>,
+			messages: Vec<Message>,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+
+			Self::handle_messages_partially(messages)
+		}
+
+		/// Execute the provided batch of ISMP messages, handling each message independently.
+		/// Messages that fail are rolled back and reported, without reverting the rest of the
+		/// batch. This call fails only if none of the messages can be handled.
+		///
+		/// The dispatch origin for this call must be a signed one.
+		///
+		/// - `messages`: A set of ISMP [`Message`]s to handle or process.
+		///
+		/// Emits [`Event::BatchHandled`] with the status of each message if successful.
+		#[cfg(not(feature = "unsigned"))]
+		#[pallet::weight(get_weight::<T>(&messages))]
+		#[pallet::call_index(6)]
+		#[frame_support::transactional]
+		pub fn handle_partial(
+			origin: OriginFor<T>,
+			messages: Vec<Message>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			Self::handle_messages_partially(messages)
+		}
+
+		/// Orders a channel to or from this state machine, or makes it unordered again. Requests
+		/// dispatched on an ordered outgoing channel are assigned consecutive nonces, and requests
+		/// on an ordered incoming channel are only delivered if they carry the nonce expected next
+		/// on the channel. Both ends of a channel must be ordered with the same next nonce. A
+		/// request that times out blocks its incoming channel until the channel is updated past
+		/// its nonce. The dispatch origin for this call must be `T::AdminOrigin`.
+		///
+		/// Outgoing channels continue from the global request nonce if it is higher than
+		/// `next_nonce`, and the global request nonce continues past the channel nonces once the
+		/// channel is unordered, so request commitments stay unique.
+		///
+		/// - `channel`: The channel to update, either its source or its destination must be this
+		///   state machine.
+		/// - `next_nonce`: The next nonce of the channel, or `None` to make the channel unordered.
+		///
+		/// Emits [`Event::ChannelOrderingUpdated`] if successful.
+		#[pallet::weight(<T as frame_system::Config>::DbWeight::get().reads_writes(2, 2))]
+		#[pallet::call_index(7)]
+		pub fn set_channel_ordering(
+			origin: OriginFor<T>,
+			channel: ChannelId,
+			next_nonce: Option<u64>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let host = T::HostStateMachine::get();
+			if (channel.dest == host) == (channel.source == host) {
+				Err(Error::<T>::InvalidChannel)?
+			}
+			let outgoing = channel.source == host;
+
+			let next_nonce = match next_nonce {
+				Some(nonce) => {
+					let nonce = if outgoing { nonce.max(Nonce::<T>::get()) } else { nonce };
+					ChannelNonces::<T>::insert(&channel, nonce);
+					Some(nonce)
+				},
+				None => {
+					if let Some(nonce) = ChannelNonces::<T>::take(&channel) {
+						if outgoing {
+							Nonce::<T>::mutate(|next| *next = nonce.max(*next));
+						}
+					}
+					None
+				},
+			};
+
+			Self::deposit_event(Event::<T>::ChannelOrderingUpdated { channel, next_nonce });
+
+			Ok(())
+		}
+
+		/// Enable, pause or disable the consensus client of a consensus state. Paused clients
+		/// cannot be updated, disabled clients can also not be used to verify messages. The
+		/// dispatch origin for this call must be `T::AdminOrigin`.
+		///
+		/// - `consensus_state_id`: The consensus state identifier.
+		/// - `status`: The new status of the consensus client.
+		///
+		/// Emits [`Event::ConsensusClientStatusUpdated`] if successful.
+		#[pallet::weight(<T as frame_system::Config>::DbWeight::get().reads_writes(1, 1))]
+		#[pallet::call_index(8)]
+		pub fn set_consensus_client_status(
+			origin: OriginFor<T>,
+			consensus_state_id: ConsensusStateId,
+			status: ConsensusClientStatus,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				ConsensusStateClient::<T>::contains_key(consensus_state_id),
+				Error::<T>::UnknownConsensusState
+			);
+			ConsensusClientStatuses::<T>::insert(consensus_state_id, status);
+
+			Self::deposit_event(Event::<T>::ConsensusClientStatusUpdated {
+				consensus_state_id,
+				status,
+			});
+
+			Ok(())
+		}
+
+		/// Mark the consensus client of a consensus state as deprecated, or remove the mark.
+		/// Deprecated clients keep working, the mark signals that they are going to be disabled.
+		/// The dispatch origin for this call must be `T::AdminOrigin`.
+		///
+		/// - `consensus_state_id`: The consensus state identifier.
+		/// - `deprecated`: Whether the consensus client is deprecated.
+		///
+		/// Emits [`Event::ConsensusClientDeprecationUpdated`] if successful.
+		#[pallet::weight(<T as frame_system::Config>::DbWeight::get().reads_writes(1, 1))]
+		#[pallet::call_index(9)]
+		pub fn set_consensus_client_deprecated(
+			origin: OriginFor<T>,
+			consensus_state_id: ConsensusStateId,
+			deprecated: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				ConsensusStateClient::<T>::contains_key(consensus_state_id),
+				Error::<T>::UnknownConsensusState
+			);
+			if deprecated {
+				DeprecatedConsensusClients::<T>::insert(consensus_state_id, true);
+			} else {
+				DeprecatedConsensusClients::<T>::remove(consensus_state_id);
+			}
+
+			Self::deposit_event(Event::<T>::ConsensusClientDeprecationUpdated {
+				consensus_state_id,
+				deprecated,
+			});
+
+			Ok(())
+		}
+
+		/// Migrate the stored consensus state to the encoding of the current version of its
+		/// consensus client. The dispatch origin for this call must be `T::AdminOrigin`.
+		///
+		/// - `consensus_state_id`: The consensus state identifier.
+		///
+		/// Emits [`Event::ConsensusStateMigrated`] if successful.
+		#[pallet::weight(<T as frame_system::Config>::DbWeight::get().reads_writes(3, 2))]
+		#[pallet::call_index(10)]
+		pub fn migrate_consensus_state(
+			origin: OriginFor<T>,
+			consensus_state_id: ConsensusStateId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let host = Pallet::<T>::default();
+			let client_id = host
+				.consensus_client_id(consensus_state_id)
+				.ok_or(Error::<T>::UnknownConsensusState)?;
+			let client = host
+				.consensus_client(client_id)
+				.map_err(|_| Error::<T>::ConsensusStateMigrationFailed)?;
+			let consensus_state = host
+				.consensus_state(consensus_state_id)
+				.map_err(|_| Error::<T>::UnknownConsensusState)?;
+
+			let from_version = ConsensusStateVersions::<T>::get(consensus_state_id);
+			let to_version = client.version();
+			let consensus_state = client
+				.migrate_consensus_state(from_version, consensus_state)
+				.map_err(|_| Error::<T>::ConsensusStateMigrationFailed)?;
+			host.store_consensus_state(consensus_state_id, consensus_state)
+				.map_err(|_| Error::<T>::ConsensusStateMigrationFailed)?;
+			ConsensusStateVersions::<T>::insert(consensus_state_id, to_version);
+
+			Self::deposit_event(Event::<T>::ConsensusStateMigrated {
+				consensus_state_id,
+				from_version,
+				to_version,
+			});
+
+			Ok(())
+		}
 	}
 
 	/// Pallet Events
@@ -635,6 +898,54 @@ pub mod pallet {
 		PostResponseTimeoutHandled(TimeoutHandled),
 		/// Get request timeout handled
 		GetRequestTimeoutHandled(TimeoutHandled),
+		/// A batch of messages was handled in partial-success mode
+		BatchHandled {
+			/// The status of each message, in the order they were submitted
+			statuses: Vec<MessageStatus>,
+		},
+		/// The ordering of a channel was updated
+		ChannelOrderingUpdated {
+			/// The channel identifier
+			channel: ChannelId,
+			/// The next nonce of the channel, `None` if the channel is unordered
+			next_nonce: Option<u64>,
+		},
+		/// The status of a consensus client was updated
+		ConsensusClientStatusUpdated {
+			/// Consensus state id
+			consensus_state_id: ConsensusStateId,
+			/// The new status of the consensus client
+			status: ConsensusClientStatus,
+		},
+		/// A consensus client was marked as deprecated or the mark was removed
+		ConsensusClientDeprecationUpdated {
+			/// Consensus state id
+			consensus_state_id: ConsensusStateId,
+			/// Whether the consensus client is deprecated
+			deprecated: bool,
+		},
+		/// A consensus state was migrated to a new encoding
+		ConsensusStateMigrated {
+			/// Consensus state id
+			consensus_state_id: ConsensusStateId,
+			/// The version the consensus state was migrated from
+			from_version: u32,
+			/// The version the consensus state was migrated to
+			to_version: u32,
+		},
+		/// An Outgoing Request batch has been deposited
+		RequestBatch {
+			/// Chain that this batch will be routed to
+			dest_chain: StateMachine,
+			/// Source Chain for the batch
+			source_chain: StateMachine,
+			/// Batch nonce
+			request_nonce: u64,
+			/// Batch commitment
+			commitment: H256,
+		},
+		/// Post request batch timeout handled
+		PostBatchTimeoutHandled(TimeoutHandled),
 	}
 
 	/// Pallet errors
@@ -650,6 +961,12 @@ pub mod pallet {
 		UnbondingPeriodUpdateFailed,
 		/// Couldn't update challenge period
 		ChallengePeriodUpdateFailed,
+		/// Neither end of the channel is this state machine
+		InvalidChannel,
+		/// The consensus state does not exist
+		UnknownConsensusState,
+		/// Couldn't migrate the consensus state to the current consensus client version
+		ConsensusStateMigrationFailed,
 	}
 
 	/// This allows users execute ISMP datagrams for free. Use with caution.
@@ -666,41 +983,57 @@ pub mod pallet {
 		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
 			use ismp::{
 				handlers::MessageResult,
-				messaging::{hash_request, ConsensusMessage, FraudProofMessage, RequestMessage},
+				messaging::{
+					hash_batch, hash_request, BatchMessage, ConsensusMessage, FraudProofMessage,
+					RequestMessage, TimeoutMessage,
+				},
 				router::Request,
 			};
-			let messages = match call {
-				Call::handle_unsigned { messages } => messages,
+			let (messages, partial) = match call {
+				Call::handle_unsigned { messages } => (messages, false),
+				Call::handle_unsigned_partial { messages } => (messages, true),
 				_ => Err(TransactionValidityError::Invalid(InvalidTransaction::Call))?,
 			};
 
-			let host = Pallet::<T>::default();
-			let _ = messages
-				.iter()
-				.map(|msg| handlers::handle_incoming_message(&host, msg.clone()))
-				.collect::<Result<Vec<_>, _>>()
-				.map_err(|_err| {
-					log::info!(target: "ismp", "Validation Errors: {:#?}", _err);
-					TransactionValidityError::Invalid(InvalidTransaction::BadProof)
-				})?
-				.into_iter()
-				// check that requests will be successfully dispatched
-				// so we can not be spammed with failing txs
-				.map(|result| match result {
-					MessageResult::Request(results) |
-					MessageResult::Response(results) |
-					MessageResult::Timeout(results) =>
-						results.into_iter().map(|result| result.map(|_| ())).collect::<Vec<_>>(),
-					MessageResult::ConsensusMessage(_) | MessageResult::FrozenClient(_) => {
-						vec![Ok(())]
-					},
-				})
-				.flatten()
-				.collect::<Result<Vec<_>, _>>()
-				.map_err(|_err| {
-					log::info!(target: "ismp", "Validation Errors: {:#?}", _err);
-					TransactionValidityError::Invalid(InvalidTransaction::BadProof)
-				})?;
+			if partial {
+				// Every message must be handled successfully when the batch enters the pool, so
+				// that failing messages cannot be executed for free. The partial handling only
+				// tolerates messages that went stale before the batch was included, e.g. ones
+				// delivered by another relayer in the meantime.
+				let outcomes = Pallet::<T>::handle_messages_isolated(messages.clone());
+				if !outcomes.iter().all(|outcome| outcome.is_success()) {
+					log::info!(target: "ismp", "Validation Errors: {:#?}", outcomes);
+					Err(TransactionValidityError::Invalid(InvalidTransaction::BadProof))?
+				}
+			} else {
+				let host = Pallet::<T>::default();
+				let _ = messages
+					.iter()
+					.map(|msg| handlers::handle_incoming_message(&host, msg.clone()))
+					.collect::<Result<Vec<_>, _>>()
+					.map_err(|_err| {
+						log::info!(target: "ismp", "Validation Errors: {:#?}", _err);
+						TransactionValidityError::Invalid(InvalidTransaction::BadProof)
+					})?
+					.into_iter()
+					// check that requests will be successfully dispatched
+					// so we can not be spammed with failing txs
+					.map(|result| match result {
+						MessageResult::Request(results) |
+						MessageResult::Response(results) |
+						MessageResult::Timeout(results) =>
+							results.into_iter().map(|result| result.map(|_| ())).collect::<Vec<_>>(),
+						MessageResult::ConsensusMessage(_) | MessageResult::FrozenClient(_) => {
+							vec![Ok(())]
+						},
+					})
+					.flatten()
+					.collect::<Result<Vec<_>, _>>()
+					.map_err(|_err| {
+						log::info!(target: "ismp", "Validation Errors: {:#?}", _err);
+						TransactionValidityError::Invalid(InvalidTransaction::BadProof)
+					})?;
+			}
 
 			let mut requests = messages
 				.into_iter()
@@ -721,11 +1054,19 @@ pub mod pallet {
 						.iter()
 						.map(|request| hash_request::<Pallet<T>>(request))
 						.collect::<Vec<_>>(),
+					Message::Timeout(TimeoutMessage::PostBatch { batches, .. }) => batches
+						.iter()
+						.map(|batch| hash_batch::<Pallet<T>>(batch))
+						.collect::<Vec<_>>(),
 					Message::Timeout(message) => message
 						.requests()
 						.iter()
 						.map(|request| hash_request::<Pallet<T>>(request))
 						.collect::<Vec<_>>(),
+					Message::Batch(BatchMessage { batches, .. }) => batches
+						.iter()
+						.map(|batch| hash_batch::<Pallet<T>>(batch))
+						.collect::<Vec<_>>(),
 				})
 				.collect::<Vec<_>>();
 			requests.sort();
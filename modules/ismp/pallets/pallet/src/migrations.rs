@@ -0,0 +1,68 @@
+// Copyright (c) 2024 Polytope Labs.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for pallet-ismp
+
+/// Migration to storage version 1, which tracks the version of each stored consensus state.
+pub mod v1 {
+	use crate::{Config, ConsensusStateClient, ConsensusStateVersions, Pallet};
+	use core::marker::PhantomData;
+	use frame_support::{
+		migrations::VersionedMigration,
+		traits::{Get, UncheckedOnRuntimeUpgrade},
+		weights::Weight,
+	};
+	use ismp::host::IsmpHost;
+
+	/// Stores the version of every consensus state created before consensus state versions were
+	/// tracked. Consensus clients are compiled into the runtime, so these consensus states were
+	/// encoded by the current version of their consensus client.
+	pub struct StoreConsensusStateVersions<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for StoreConsensusStateVersions<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let host = Pallet::<T>::default();
+			let (mut reads, mut writes) = (0u64, 0u64);
+			for (consensus_state_id, consensus_client_id) in ConsensusStateClient::<T>::iter() {
+				reads += 2;
+				if ConsensusStateVersions::<T>::contains_key(consensus_state_id) {
+					continue;
+				}
+
+				match host.consensus_client(consensus_client_id) {
+					Ok(client) => {
+						ConsensusStateVersions::<T>::insert(consensus_state_id, client.version());
+						writes += 1;
+					},
+					Err(_) => log::warn!(
+						target: "ismp",
+						"No consensus client {consensus_client_id:?} for consensus state {consensus_state_id:?}"
+					),
+				}
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+
+	/// Migrates pallet-ismp from storage version 0 to 1, it is a no-op on later versions.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		StoreConsensusStateVersions<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
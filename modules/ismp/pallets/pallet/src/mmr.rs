@@ -17,7 +17,7 @@
 
 use codec::{Decode, Encode};
 use frame_support::__private::RuntimeDebug;
-use ismp::router::{Request, Response};
+use ismp::router::{PostBatch, Request, Response};
 use mmr_primitives::FullLeaf;
 use scale_info::TypeInfo;
 use sp_core::H256;
@@ -60,6 +60,8 @@ pub enum Leaf {
 	Request(Request),
 	/// A response variant
 	Response(Response),
+	/// A request batch variant
+	Batch(PostBatch),
 }
 
 impl FullLeaf for Leaf {
@@ -67,6 +69,7 @@ impl FullLeaf for Leaf {
 		match self {
 			Leaf::Request(req) => req.encode(),
 			Leaf::Response(res) => res.encode(),
+			Leaf::Batch(batch) => batch.encode(),
 		}
 	}
 }
@@ -20,7 +20,7 @@ use alloc::collections::BTreeMap;
 use codec::{Decode, Encode};
 use frame_support::PalletId;
 use ismp::{
-	consensus::{ConsensusClient, ConsensusStateId},
+	consensus::{ConsensusClient, ConsensusClientId, ConsensusStateId},
 	host::StateMachine,
 };
 use sp_core::{
@@ -67,6 +67,39 @@ pub struct ResponseReceipt {
 	pub relayer: Vec<u8>,
 }
 
+/// The status of a consensus client in the registry
+#[derive(Debug, Default, Clone, Copy, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
+pub enum ConsensusClientStatus {
+	/// The client can be updated and used to verify messages
+	#[default]
+	Enabled,
+	/// The client cannot be updated until it is enabled again, the state commitments it has
+	/// already verified can still be used to verify messages.
+	Paused,
+	/// The client can neither be updated nor used to verify messages
+	Disabled,
+}
+
+/// Registry information about the consensus client of a consensus state
+#[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
+pub struct ConsensusClientInfo {
+	/// Consensus state identifier
+	pub consensus_state_id: ConsensusStateId,
+	/// Identifier of the consensus client that verifies this consensus state
+	pub consensus_client_id: ConsensusClientId,
+	/// The status of the consensus client
+	pub status: ConsensusClientStatus,
+	/// Whether the consensus client has been marked as deprecated
+	pub deprecated: bool,
+	/// Whether the consensus client has been frozen by a fraud proof
+	pub frozen: bool,
+	/// The version of the encoding of the stored consensus state
+	pub state_version: u32,
+	/// The version of the compiled-in consensus client, `None` if the client is no longer
+	/// provided by the runtime
+	pub client_version: Option<u32>,
+}
+
 /// A  convenience trait that returns a list of all configured consensus clients
 /// This trait should be implemented in the runtime
 pub trait ConsensusClientProvider {
@@ -109,6 +109,8 @@ pub(crate) fn get_weight<T: Config>(messages: &[Message]) -> Weight {
 
 				acc + cb_weight
 			},
+			// batches are not delivered in response messages
+			RequestResponse::Batch(_) => acc,
 		},
 		Message::Timeout(msg) => match msg {
 			TimeoutMessage::Post { requests, .. } => {
@@ -152,6 +154,33 @@ pub(crate) fn get_weight<T: Config>(messages: &[Message]) -> Weight {
 				});
 				acc + cb_weight
 			},
+			TimeoutMessage::PostBatch { batches, .. } => {
+				let cb_weight = batches.iter().fold(Weight::zero(), |acc, batch| {
+					let source_module = ModuleId::from_bytes(batch.from.as_slice()).ok();
+					let handle = source_module
+						.map(|id| <T as Config>::WeightProvider::module_callback(id))
+						.flatten()
+						.unwrap_or(Box::new(()));
+					batch.requests().into_iter().fold(acc, |acc, req| {
+						acc + handle.on_timeout(&Timeout::Request(Request::Post(req)))
+					})
+				});
+				acc + cb_weight
+			},
+		},
+		Message::Batch(msg) => {
+			let cb_weight = msg.batches.iter().flat_map(|batch| batch.requests()).fold(
+				Weight::zero(),
+				|acc, req| {
+					let dest_module = ModuleId::from_bytes(req.to.as_slice()).ok();
+					let handle = dest_module
+						.map(|id| <T as Config>::WeightProvider::module_callback(id))
+						.flatten()
+						.unwrap_or(Box::new(()));
+					acc + handle.on_accept(&req)
+				},
+			);
+			acc + cb_weight
 		},
 		Message::Consensus(_) | Message::FraudProof(_) => acc,
 	})
@@ -0,0 +1,98 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks of the calls of pallet-ismp-relayer
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::withdrawal::MAX_WITHDRAWAL_BATCH_SIZE;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use sp_core::crypto::KeyTypeId;
+
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"rlyr");
+
+#[benchmarks(
+	where
+		<T as frame_system::Config>::Hash: From<H256>,
+		<T as frame_system::Config>::AccountId: From<[u8; 32]>,
+		T::Balance: Into<u128>,
+)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn withdraw_fees_batch(n: Linear<1, MAX_WITHDRAWAL_BATCH_SIZE>) -> Result<(), BenchmarkError> {
+		let public_key = sp_io::crypto::ed25519_generate(KEY_TYPE, None);
+		let address = public_key.0.to_vec();
+		// Withdrawals to EVM chains also read their host params and abi encode the request before
+		// it is dispatched, which makes them the most expensive
+		let chains = (0..n).map(|i| StateMachine::Evm(1000 + i)).collect::<Vec<_>>();
+		for chain in &chains {
+			HostParams::<T>::insert(chain, HostParam::EvmHostParam(Default::default()));
+			Fees::<T>::insert(chain, address.clone(), Pallet::<T>::min_withdrawal_amount());
+		}
+		let nonces = chains.iter().map(|chain| (0u64, *chain)).collect::<Vec<_>>();
+		let signature = sp_io::crypto::ed25519_sign(KEY_TYPE, &public_key, &batch_message(&nonces))
+			.ok_or(BenchmarkError::Stop("Failed to sign the batch withdrawal"))?;
+		let withdrawal_data = BatchWithdrawalInputData {
+			signature: Signature::Ed25519 {
+				public_key: address.clone(),
+				signature: signature.0.to_vec(),
+			},
+			dest_chains: chains
+				.try_into()
+				.map_err(|_| BenchmarkError::Stop("Too many chains in the batch"))?,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::None, withdrawal_data);
+
+		for chain in (0..n).map(|i| StateMachine::Evm(1000 + i)) {
+			assert_eq!(Fees::<T>::get(chain, address.clone()), U256::zero());
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_beneficiary() -> Result<(), BenchmarkError> {
+		let public_key = sp_io::crypto::ed25519_generate(KEY_TYPE, None);
+		let address = public_key.0.to_vec();
+		let beneficiary = [1u8; 32].to_vec();
+		let signature = sp_io::crypto::ed25519_sign(
+			KEY_TYPE,
+			&public_key,
+			&beneficiary_message(0, &address, &beneficiary),
+		)
+		.ok_or(BenchmarkError::Stop("Failed to sign the beneficiary"))?;
+		let beneficiary_data = BeneficiaryData {
+			address: address.clone(),
+			beneficiary: beneficiary.clone(),
+			signature: Signature::Ed25519 {
+				public_key: address.clone(),
+				signature: signature.0.to_vec(),
+			},
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::None, beneficiary_data);
+
+		assert_eq!(Beneficiaries::<T>::get(&address), Some(beneficiary));
+
+		Ok(())
+	}
+}
@@ -20,9 +20,17 @@
 
 extern crate alloc;
 
+mod benchmarking;
+pub mod weights;
 pub mod withdrawal;
 
-use crate::withdrawal::{Key, Signature, WithdrawalInputData, WithdrawalParams, WithdrawalProof};
+use crate::{
+	weights::WeightInfo,
+	withdrawal::{
+		BatchWithdrawalInputData, BeneficiaryData, Key, Signature, WithdrawalInputData,
+		WithdrawalParams, WithdrawalProof,
+	},
+};
 use alloc::{collections::BTreeMap, vec::Vec};
 use alloy_primitives::Address;
 use codec::Encode;
@@ -59,7 +67,9 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use ismp::host::StateMachine;
 
-	use crate::withdrawal::{WithdrawalInputData, WithdrawalProof};
+	use crate::withdrawal::{
+		BatchWithdrawalInputData, BeneficiaryData, WithdrawalInputData, WithdrawalProof,
+	};
 	use codec::Encode;
 	use sp_core::{Get, H256};
 
@@ -77,6 +87,9 @@ pub mod pallet {
 
 		/// The underlying [`IsmpHost`] implementation
 		type IsmpHost: IsmpHost + IsmpDispatcher<Account = Self::AccountId, Balance = Self::Balance>;
+
+		/// Weight information for the calls of this pallet
+		type WeightInfo: WeightInfo;
 	}
 
 	/// double map of address to source chain, which holds the amount of the relayer address
@@ -105,6 +118,18 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Address that is credited with the fees accrued by a delivery address
+	#[pallet::storage]
+	#[pallet::getter(fn beneficiary)]
+	pub type Beneficiaries<T: Config> =
+		StorageMap<_, Blake2_128Concat, Vec<u8>, Vec<u8>, OptionQuery>;
+
+	/// Nonce of the signatures that set the beneficiary of a delivery address
+	#[pallet::storage]
+	#[pallet::getter(fn beneficiary_nonce)]
+	pub type BeneficiaryNonce<T: Config> =
+		StorageMap<_, Blake2_128Concat, Vec<u8>, u64, ValueQuery>;
+
 	/// Default minimum withdrawal is $10
 	pub struct MinWithdrawal;
 
@@ -141,6 +166,12 @@ pub mod pallet {
 		ErrorCompletingCall,
 		/// Missing commitments
 		MissingCommitments,
+		/// Batch withdrawal without any chains
+		EmptyBatch,
+		/// A chain appears more than once in a batch withdrawal
+		DuplicateChain,
+		/// The beneficiary is not the same kind of address as the delivery address
+		InvalidBeneficiary,
 	}
 
 	/// Events emiited by the relayer pallet
@@ -164,6 +195,15 @@ pub mod pallet {
 			state_machine: StateMachine,
 			/// Amount withdrawn
 			amount: U256,
+			/// Address the fees are paid out to, the beneficiary of `address` if it has one
+			payee: BoundedVec<u8, ConstU32<32>>,
+		},
+		/// Fees accrued by the delivery `address` will be credited to `beneficiary`
+		BeneficiarySet {
+			/// delivery address
+			address: BoundedVec<u8, ConstU32<32>>,
+			/// beneficiary address
+			beneficiary: BoundedVec<u8, ConstU32<32>>,
 		},
 	}
 
@@ -202,6 +242,35 @@ pub mod pallet {
 			MinimumWithdrawalAmount::<T>::put(U256::from(amount * 1_000_000_000_000_000_000));
 			Ok(())
 		}
+
+		/// Withdraw the fees of an address on several chains with a single signature. The batch
+		/// fails if the balance on any of the chains is below the minimum withdrawal amount.
+		///
+		/// Only the fees of the address that signed the batch are withdrawn. Several addresses
+		/// controlled by the same key are not handled, the fees of each of them must be withdrawn
+		/// with a batch signed for that address.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_fees_batch(withdrawal_data.dest_chains.len() as u32))]
+		pub fn withdraw_fees_batch(
+			origin: OriginFor<T>,
+			withdrawal_data: BatchWithdrawalInputData,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::withdraw_batch(withdrawal_data)
+		}
+
+		/// Credit the fees accrued by a delivery address to a beneficiary. The beneficiary is set
+		/// with a signature from the delivery address, after which only the beneficiary can change
+		/// it.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_beneficiary())]
+		pub fn set_beneficiary(
+			origin: OriginFor<T>,
+			beneficiary_data: BeneficiaryData,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::register_beneficiary(beneficiary_data)
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -223,6 +292,10 @@ pub mod pallet {
 				Call::accumulate_fees { withdrawal_proof } =>
 					Self::accumulate(withdrawal_proof.clone()),
 				Call::withdraw_fees { withdrawal_data } => Self::withdraw(withdrawal_data.clone()),
+				Call::withdraw_fees_batch { withdrawal_data } =>
+					Self::withdraw_batch(withdrawal_data.clone()),
+				Call::set_beneficiary { beneficiary_data } =>
+					Self::register_beneficiary(beneficiary_data.clone()),
 				_ => Err(TransactionValidityError::Invalid(InvalidTransaction::Call))?,
 			};
 
@@ -234,6 +307,8 @@ pub mod pallet {
 			let encoding = match call {
 				Call::accumulate_fees { withdrawal_proof } => withdrawal_proof.encode(),
 				Call::withdraw_fees { withdrawal_data } => withdrawal_data.encode(),
+				Call::withdraw_fees_batch { withdrawal_data } => withdrawal_data.encode(),
+				Call::set_beneficiary { beneficiary_data } => beneficiary_data.encode(),
 				_ => unreachable!(),
 			};
 
@@ -257,22 +332,74 @@ where
 	T::Balance: Into<u128>,
 {
 	pub fn withdraw(withdrawal_data: WithdrawalInputData) -> DispatchResult {
-		let address = match withdrawal_data.signature.clone() {
+		let address = withdrawal_data.signature.signer();
+		let nonce = Nonce::<T>::get(address.clone(), withdrawal_data.dest_chain);
+		let msg = message(nonce, withdrawal_data.dest_chain);
+		Self::verify_signature(&withdrawal_data.signature, msg)?;
+
+		Self::withdraw_from(address, withdrawal_data.dest_chain)
+	}
+
+	pub fn withdraw_batch(withdrawal_data: BatchWithdrawalInputData) -> DispatchResult {
+		ensure!(!withdrawal_data.dest_chains.is_empty(), Error::<T>::EmptyBatch);
+		let unique = withdrawal_data.dest_chains.iter().collect::<hashbrown::HashSet<_>>();
+		ensure!(unique.len() == withdrawal_data.dest_chains.len(), Error::<T>::DuplicateChain);
+
+		let address = withdrawal_data.signature.signer();
+		let nonces = withdrawal_data
+			.dest_chains
+			.iter()
+			.map(|dest_chain| (Nonce::<T>::get(address.clone(), *dest_chain), *dest_chain))
+			.collect::<Vec<_>>();
+		Self::verify_signature(&withdrawal_data.signature, batch_message(&nonces))?;
+
+		for dest_chain in withdrawal_data.dest_chains {
+			Self::withdraw_from(address.clone(), dest_chain)?;
+		}
+
+		Ok(())
+	}
+
+	pub fn register_beneficiary(beneficiary_data: BeneficiaryData) -> DispatchResult {
+		let BeneficiaryData { address, beneficiary, signature } = beneficiary_data;
+		ensure!(beneficiary.len() == address.len(), Error::<T>::InvalidBeneficiary);
+		// Once a beneficiary is set only it can change it, so that a leaked delivery key can't
+		// redirect the fees
+		let authority = Beneficiaries::<T>::get(&address).unwrap_or_else(|| address.clone());
+		ensure!(signature.signer() == authority, Error::<T>::InvalidPublicKey);
+		let nonce = BeneficiaryNonce::<T>::get(&address);
+		Self::verify_signature(&signature, beneficiary_message(nonce, &address, &beneficiary))?;
+
+		BeneficiaryNonce::<T>::insert(&address, nonce + 1);
+		if beneficiary == address {
+			Beneficiaries::<T>::remove(&address);
+		} else {
+			Beneficiaries::<T>::insert(&address, beneficiary.clone());
+		}
+
+		Self::deposit_event(Event::<T>::BeneficiarySet {
+			address: sp_runtime::BoundedVec::truncate_from(address),
+			beneficiary: sp_runtime::BoundedVec::truncate_from(beneficiary),
+		});
+
+		Ok(())
+	}
+
+	/// Verify that `signature` was produced over `msg` by the address it claims to be from
+	pub fn verify_signature(signature: &Signature, msg: [u8; 32]) -> DispatchResult {
+		match signature {
 			Signature::Evm { address, signature } => {
 				if signature.len() != 65 {
 					Err(Error::<T>::InvalidSignature)?
 				}
-				let nonce = Nonce::<T>::get(address.clone(), withdrawal_data.dest_chain);
-				let msg = message(nonce, withdrawal_data.dest_chain);
 				let mut sig = [0u8; 65];
-				sig.copy_from_slice(&signature);
+				sig.copy_from_slice(signature);
 				let pub_key = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &msg)
 					.map_err(|_| Error::<T>::InvalidSignature)?;
 				let signer = sp_io::hashing::keccak_256(&pub_key[..])[12..].to_vec();
-				if signer != address {
+				if &signer != address {
 					Err(Error::<T>::InvalidPublicKey)?
 				}
-				address
 			},
 			Signature::Sr25519 { public_key, signature } => {
 				if signature.len() != 64 {
@@ -282,14 +409,11 @@ where
 				if public_key.len() != 32 {
 					Err(Error::<T>::InvalidPublicKey)?
 				}
-				let nonce = Nonce::<T>::get(public_key.clone(), withdrawal_data.dest_chain);
-				let msg = message(nonce, withdrawal_data.dest_chain);
 				let signature = signature.as_slice().try_into().expect("Infallible");
 				let pub_key = public_key.as_slice().try_into().expect("Infallible");
 				if !sp_io::crypto::sr25519_verify(&signature, &msg, &pub_key) {
 					Err(Error::<T>::InvalidSignature)?
 				}
-				public_key
 			},
 			Signature::Ed25519 { public_key, signature } => {
 				if signature.len() != 64 {
@@ -299,29 +423,32 @@ where
 				if public_key.len() != 32 {
 					Err(Error::<T>::InvalidPublicKey)?
 				}
-				let nonce = Nonce::<T>::get(public_key.clone(), withdrawal_data.dest_chain);
-				let msg = message(nonce, withdrawal_data.dest_chain);
 				let signature = signature.as_slice().try_into().expect("Infallible");
 				let pub_key = public_key.as_slice().try_into().expect("Infallible");
 				if !sp_io::crypto::ed25519_verify(&signature, &msg, &pub_key) {
 					Err(Error::<T>::InvalidSignature)?
 				}
-				public_key
 			},
-		};
-		let available_amount = Fees::<T>::get(withdrawal_data.dest_chain, address.clone());
+		}
+
+		Ok(())
+	}
+
+	/// Dispatch a request that pays out the fees of `address` on `dest_chain`. Fees that a delivery
+	/// address accrued before it set a beneficiary are paid out to the beneficiary.
+	fn withdraw_from(address: Vec<u8>, dest_chain: StateMachine) -> DispatchResult {
+		let available_amount = Fees::<T>::get(dest_chain, address.clone());
 
 		if available_amount < Self::min_withdrawal_amount() {
 			Err(Error::<T>::NotEnoughBalance)?
 		}
 
 		let dispatcher = <T as Config>::IsmpHost::default();
-		let relayer_manager_address = match withdrawal_data.dest_chain {
+		let relayer_manager_address = match dest_chain {
 			s if s.is_substrate() => PALLET_HYPERBRIDGE.0.to_vec(),
 			_ => {
-				let HostParam::EvmHostParam(params) =
-					HostParams::<T>::get(withdrawal_data.dest_chain)
-						.ok_or_else(|| Error::<T>::MissingMangerAddress)?
+				let HostParam::EvmHostParam(params) = HostParams::<T>::get(dest_chain)
+					.ok_or_else(|| Error::<T>::MissingMangerAddress)?
 				else {
 					Err(Error::<T>::MismatchedStateMachine)?
 				};
@@ -329,29 +456,30 @@ where
 				params.host_manager.0.to_vec()
 			},
 		};
-		Nonce::<T>::try_mutate(address.clone(), withdrawal_data.dest_chain, |value| {
+		Nonce::<T>::try_mutate(address.clone(), dest_chain, |value| {
 			*value += 1;
 			Ok::<(), ()>(())
 		})
 		.map_err(|_| Error::<T>::ErrorCompletingCall)?;
+		let payee = Self::fee_recipient(address.clone());
 		let params = WithdrawalParams {
-			beneficiary_address: address.clone(),
+			beneficiary_address: payee.clone(),
 			amount: available_amount.into(),
 			native: false,
 		};
 
-		let data = match withdrawal_data.dest_chain {
+		let data = match dest_chain {
 			s if s.is_evm() => params.abi_encode(),
 			_ => Message::WithdrawRelayerFees(WithdrawalRequest {
 				amount: params.amount.low_u128(),
-				account: AccountId32::try_from(&address[..])
+				account: AccountId32::try_from(&payee[..])
 					.map_err(|_| Error::<T>::InvalidPublicKey)?,
 			})
 			.encode(),
 		};
 
 		let post = DispatchPost {
-			dest: withdrawal_data.dest_chain,
+			dest: dest_chain,
 			from: MODULE_ID.to_vec(),
 			to: relayer_manager_address,
 			timeout: 0,
@@ -366,12 +494,13 @@ where
 			)
 			.map_err(|_| Error::<T>::DispatchFailed)?;
 
-		Fees::<T>::insert(withdrawal_data.dest_chain, address.clone(), U256::zero());
+		Fees::<T>::insert(dest_chain, address.clone(), U256::zero());
 
 		Self::deposit_event(Event::<T>::Withdraw {
 			address: sp_runtime::BoundedVec::truncate_from(address),
-			state_machine: withdrawal_data.dest_chain,
+			state_machine: dest_chain,
 			amount: available_amount,
+			payee: sp_runtime::BoundedVec::truncate_from(payee),
 		});
 
 		Ok(())
@@ -434,8 +563,9 @@ where
 			source_result,
 			dest_result,
 		)?;
-		let mut total_fee = hashbrown::HashMap::<Vec<u8>, U256>::new();
-		for (address, fee) in result.clone().into_iter() {
+		let mut total_fee = BTreeMap::<Vec<u8>, U256>::new();
+		for (address, fee) in result.into_iter() {
+			let address = Self::fee_recipient(address);
 			let _ = Fees::<T>::try_mutate(state_machine, address.clone(), |inner| {
 				*inner += fee;
 				let inner_fee = total_fee.entry(address).or_insert(U256::zero());
@@ -475,11 +605,11 @@ where
 			}
 		}
 
-		for address in result.keys().collect::<hashbrown::HashSet<_>>().into_iter() {
+		for (address, amount) in total_fee {
 			Self::deposit_event(Event::<T>::AccumulateFees {
-				address: sp_runtime::BoundedVec::truncate_from(address.to_vec()),
+				address: sp_runtime::BoundedVec::truncate_from(address),
 				state_machine,
-				amount: total_fee.remove(address).unwrap_or_default(),
+				amount,
 			});
 		}
 
@@ -743,13 +873,29 @@ where
 
 impl<T: Config> Pallet<T> {
 	pub fn accumulate_fee(state_machine: StateMachine, address: Vec<u8>, fee: U256) {
+		let address = Self::fee_recipient(address);
 		let _ = Fees::<T>::try_mutate(state_machine, address.clone(), |inner| {
 			*inner += fee;
 			Ok::<(), ()>(())
 		});
 	}
+
+	/// The address credited with the fees accrued by a delivery address
+	pub fn fee_recipient(address: Vec<u8>) -> Vec<u8> {
+		Beneficiaries::<T>::get(&address).unwrap_or(address)
+	}
 }
 
 pub fn message(nonce: u64, dest_chain: StateMachine) -> [u8; 32] {
 	sp_io::hashing::keccak_256(&(nonce, dest_chain).encode())
 }
+
+/// The message signed to set the beneficiary of a delivery address
+pub fn beneficiary_message(nonce: u64, address: &[u8], beneficiary: &[u8]) -> [u8; 32] {
+	sp_io::hashing::keccak_256(&(MODULE_ID, nonce, address, beneficiary).encode())
+}
+
+/// The message signed for a batch withdrawal, over the current nonce of every chain in the batch
+pub fn batch_message(nonces: &[(u64, StateMachine)]) -> [u8; 32] {
+	sp_io::hashing::keccak_256(&nonces.encode())
+}
@@ -0,0 +1,49 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights of the calls of pallet-ismp-relayer
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed by pallet-ismp-relayer. Runtimes generate their implementation with
+/// the benchmarks of this pallet.
+pub trait WeightInfo {
+	/// Weight of withdrawing fees on `n` chains with a single signature
+	fn withdraw_fees_batch(n: u32) -> Weight;
+	/// Weight of setting the beneficiary of a delivery address
+	fn set_beneficiary() -> Weight;
+}
+
+/// Weights for tests, these mirror the weights of the gargantua runtime
+impl WeightInfo for () {
+	fn withdraw_fees_batch(n: u32) -> Weight {
+		Weight::from_parts(64_823_000, 3_719)
+			.saturating_add(Weight::from_parts(71_904_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().reads(6_u64.saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes(6_u64.saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_617).saturating_mul(n.into()))
+	}
+
+	fn set_beneficiary() -> Weight {
+		Weight::from_parts(58_212_000, 3_541)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+}
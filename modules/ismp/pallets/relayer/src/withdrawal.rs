@@ -2,9 +2,13 @@ use alloy_primitives::{Address, B256};
 use alloy_rlp_derive::{RlpDecodable, RlpEncodable};
 use codec::{Decode, Encode};
 use ismp::{host::StateMachine, messaging::Proof};
-use sp_core::{H160, H256, U256};
+use sp_core::{ConstU32, H160, H256, U256};
+use sp_runtime::BoundedVec;
 use sp_std::prelude::*;
 
+/// Maximum number of chains that can be withdrawn from in a batch
+pub const MAX_WITHDRAWAL_BATCH_SIZE: u32 = 20;
+
 #[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
 pub enum Key {
 	Request(H256),
@@ -43,6 +47,25 @@ pub struct WithdrawalInputData {
 	pub dest_chain: StateMachine,
 }
 
+#[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
+pub struct BatchWithdrawalInputData {
+	/// Signature over the nonces of every chain in the batch to prove account ownership
+	pub signature: Signature,
+	/// Chains to withdraw funds from
+	pub dest_chains: BoundedVec<StateMachine, ConstU32<MAX_WITHDRAWAL_BATCH_SIZE>>,
+}
+
+#[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
+pub struct BeneficiaryData {
+	/// Address that delivers messages and accrues fees
+	pub address: Vec<u8>,
+	/// Address to credit with the fees accrued by `address`, setting it to `address` itself
+	/// removes the beneficiary
+	pub beneficiary: Vec<u8>,
+	/// Signature of `address`, or of its current beneficiary if one is set
+	pub signature: Signature,
+}
+
 #[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
 pub enum Signature {
 	/// An Evm Address and signature
@@ -53,6 +76,17 @@ pub enum Signature {
 	Ed25519 { public_key: Vec<u8>, signature: Vec<u8> },
 }
 
+impl Signature {
+	/// The address whose fees the signature authorizes withdrawing
+	pub fn signer(&self) -> Vec<u8> {
+		match self {
+			Signature::Evm { address, .. } => address.clone(),
+			Signature::Sr25519 { public_key, .. } => public_key.clone(),
+			Signature::Ed25519 { public_key, .. } => public_key.clone(),
+		}
+	}
+}
+
 #[derive(Debug, Clone, Encode, Decode, scale_info::TypeInfo, PartialEq, Eq)]
 pub struct WithdrawalParams {
 	pub beneficiary_address: Vec<u8>,
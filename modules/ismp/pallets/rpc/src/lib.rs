@@ -70,7 +70,7 @@ use codec::Encode;
 use ismp::{
 	consensus::{ConsensusClientId, StateMachineHeight, StateMachineId},
 	events::Event,
-	router::{Request, Response},
+	router::{ChannelId, Request, Response},
 };
 use jsonrpsee::types::ErrorObjectOwned;
 use pallet_ismp::{
@@ -194,6 +194,10 @@ where
 	#[method(name = "ismp_queryStateMachineLatestHeight")]
 	fn query_state_machine_latest_height(&self, id: StateMachineId) -> RpcResult<u64>;
 
+	/// Query the next nonce of a channel, returns `None` if the channel is unordered
+	#[method(name = "ismp_queryNextChannelNonce")]
+	fn query_next_channel_nonce(&self, channel: ChannelId) -> RpcResult<Option<u64>>;
+
 	/// Query ISMP Events that were deposited in a series of blocks
 	/// Using String keys because HashMap fails to deserialize when key is not a String
 	#[method(name = "ismp_queryEvents")]
@@ -383,6 +387,22 @@ where
 		})
 	}
 
+	fn query_next_channel_nonce(&self, channel: ChannelId) -> RpcResult<Option<u64>> {
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+		// Ordered channels were introduced in version 2 of the runtime api
+		let version = api
+			.api_version::<dyn IsmpRuntimeApi<Block, Block::Hash>>(at)
+			.ok()
+			.flatten()
+			.unwrap_or_default();
+		if version < 2 {
+			Err(runtime_error_into_rpc_error("Ordered channels are not supported by the runtime"))?
+		}
+		api.next_channel_nonce(at, channel)
+			.map_err(|_| runtime_error_into_rpc_error("Error fetching next channel nonce"))
+	}
+
 	fn query_events(
 		&self,
 		from: BlockNumberOrHash<Block::Hash>,
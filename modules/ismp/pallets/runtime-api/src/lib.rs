@@ -22,16 +22,20 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 use ismp::{
-	consensus::{ConsensusClientId, StateMachineHeight, StateMachineId},
+	consensus::{ConsensusClientId, ConsensusStateId, StateMachineHeight, StateMachineId},
 	host::StateMachine,
-	router::{Request, Response},
+	router::{ChannelId, Request, Response},
+};
+use pallet_ismp::{
+	mmr::{Leaf, Proof, ProofKeys},
+	ConsensusClientInfo,
 };
-use pallet_ismp::mmr::{Leaf, Proof, ProofKeys};
 use primitive_types::H256;
 use sp_mmr_primitives::Error;
 
 sp_api::decl_runtime_apis! {
 	/// Required runtime APIs needed for client subsystems like the RPC
+	#[api_version(3)]
 	pub trait IsmpRuntimeApi<Hash: codec::Codec> {
 		/// Should return the host's state machine identifier
 		fn host_state_machine() -> StateMachine;
@@ -64,5 +68,17 @@ sp_api::decl_runtime_apis! {
 
 		/// Fetch the responses for the given commitments.
 		fn responses(response_commitments: Vec<H256>) -> Vec<Response>;
+
+		/// Return the next nonce of the channel, if it is ordered
+		#[api_version(2)]
+		fn next_channel_nonce(channel: ChannelId) -> Option<u64>;
+
+		/// Return the registry information about the consensus client of a consensus state
+		#[api_version(3)]
+		fn consensus_client_info(id: ConsensusStateId) -> Option<ConsensusClientInfo>;
+
+		/// Return the registry information about the consensus clients of all consensus states
+		#[api_version(3)]
+		fn consensus_client_registry() -> Vec<ConsensusClientInfo>;
 	}
 }
@@ -21,6 +21,7 @@ use cumulus_pallet_parachain_system::ParachainSetCode;
 use frame_support::{
 	derive_impl, parameter_types,
 	traits::{ConstU32, ConstU64, Get},
+	weights::Weight,
 	PalletId,
 };
 use frame_system::{EnsureRoot, EventRecord};
@@ -33,9 +34,8 @@ use ismp::{
 	handlers,
 	host::{IsmpHost, StateMachine},
 	messaging::{CreateConsensusState, Proof, StateCommitmentHeight},
-	module::IsmpModule,
+	module::{IsmpModule, ModuleError, ModuleErrorCode},
 	router::{IsmpRouter, PostRequest, RequestResponse, Response, Timeout},
-	Error,
 };
 use ismp_sync_committee::constants::sepolia::Sepolia;
 use pallet_ismp::{mmr::Leaf, ModuleId};
@@ -81,6 +81,7 @@ frame_support::construct_runtime!(
 		TokenGovernor: pallet_token_governor,
 		Sudo: pallet_sudo,
 		IsmpSyncCommittee: ismp_sync_committee::pallet,
+		IsmpParachain: ismp_parachain,
 		TokenGateway: pallet_token_gateway,
 		TokenGatewayInspector: pallet_token_gateway_inspector,
 	}
@@ -138,9 +139,18 @@ impl pallet_balances::Config for Test {
 	type MaxFreezes = ();
 }
 
+parameter_types! {
+	pub const FishermanBond: Balance = 100 * UNIT;
+	pub const VetoReward: Balance = 10 * UNIT;
+	pub const FraudProofWeightPerByte: Weight = Weight::from_parts(100_000, 1);
+}
+
 impl pallet_fishermen::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type IsmpHost = Ismp;
+	type FishermanBond = FishermanBond;
+	type VetoReward = VetoReward;
+	type FraudProofWeightPerByte = FraudProofWeightPerByte;
 }
 
 impl pallet_sudo::Config for Test {
@@ -259,6 +269,11 @@ impl ismp_sync_committee::pallet::Config for Test {
 	type IsmpHost = Ismp;
 }
 
+impl ismp_parachain::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type IsmpHost = Ismp;
+}
+
 parameter_types! {
 	pub const TreasuryAccount: PalletId = PalletId(*b"treasury");
 }
@@ -279,6 +294,7 @@ impl pallet_mmr::Config for Test {
 impl pallet_ismp_relayer::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type IsmpHost = Ismp;
+	type WeightInfo = ();
 }
 
 impl pallet_ismp_host_executive::Config for Test {
@@ -294,16 +310,28 @@ impl pallet_call_decompressor::Config for Test {
 pub struct ErrorModule;
 
 impl IsmpModule for ErrorModule {
-	fn on_accept(&self, _request: PostRequest) -> Result<(), anyhow::Error> {
-		Err(Error::InsufficientProofHeight.into())
+	fn on_accept(&self, _request: PostRequest) -> Result<(), ModuleError> {
+		Err(ModuleError::new(
+			ModuleErrorCode::Internal,
+			ERROR_MODULE_ID,
+			"Error module always fails",
+		))
 	}
 
-	fn on_response(&self, _response: Response) -> Result<(), anyhow::Error> {
-		Err(Error::InsufficientProofHeight.into())
+	fn on_response(&self, _response: Response) -> Result<(), ModuleError> {
+		Err(ModuleError::new(
+			ModuleErrorCode::Internal,
+			ERROR_MODULE_ID,
+			"Error module always fails",
+		))
 	}
 
-	fn on_timeout(&self, _request: Timeout) -> Result<(), anyhow::Error> {
-		Err(Error::InsufficientProofHeight.into())
+	fn on_timeout(&self, _request: Timeout) -> Result<(), ModuleError> {
+		Err(ModuleError::new(
+			ModuleErrorCode::Internal,
+			ERROR_MODULE_ID,
+			"Error module always fails",
+		))
 	}
 }
 
@@ -341,15 +369,15 @@ where
 pub struct MockModule;
 
 impl IsmpModule for MockModule {
-	fn on_accept(&self, _request: PostRequest) -> Result<(), anyhow::Error> {
+	fn on_accept(&self, _request: PostRequest) -> Result<(), ModuleError> {
 		Ok(())
 	}
 
-	fn on_response(&self, _response: Response) -> Result<(), anyhow::Error> {
+	fn on_response(&self, _response: Response) -> Result<(), ModuleError> {
 		Ok(())
 	}
 
-	fn on_timeout(&self, _request: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, _request: Timeout) -> Result<(), ModuleError> {
 		Ok(())
 	}
 }
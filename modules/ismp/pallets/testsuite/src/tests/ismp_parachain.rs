@@ -0,0 +1,191 @@
+// Copyright (c) 2024 Polytope Labs.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use codec::Encode;
+use cumulus_primitives_core::PersistedValidationData;
+use ismp::{consensus::ConsensusClient, error::Error as IsmpError};
+use ismp_parachain::{
+	parachain_header_storage_key, GrandpaVotes, ParachainConsensusClient, ParachainConsensusProof,
+	ParachainFraudProof, ParachainHeaderProof, Parachains, RelayChainStateCommitments,
+};
+use sp_core::H256;
+use sp_runtime::traits::BlakeTwo256;
+use sp_trie::{LayoutV0, MemoryDB};
+use trie_db::{Recorder, Trie, TrieDBBuilder, TrieDBMutBuilder, TrieMut};
+
+use crate::runtime::{new_test_ext, Ismp, IsmpParachain, Test};
+
+const PARA_ID: u32 = 2000;
+const RELAY_HEIGHT: u32 = 100;
+
+/// Builds a relay chain state holding the given head of [`PARA_ID`], returns its state root and a
+/// storage proof of the head.
+fn relay_chain_state(head: &[u8]) -> (H256, Vec<Vec<u8>>) {
+	let key = parachain_header_storage_key(PARA_ID).0;
+	let mut db = MemoryDB::<BlakeTwo256>::default();
+	let mut root = H256::default();
+	let mut trie = TrieDBMutBuilder::<LayoutV0<BlakeTwo256>>::new(&mut db, &mut root).build();
+	trie.insert(&key, &head.to_vec().encode()).unwrap();
+	drop(trie);
+
+	let mut recorder = Recorder::<LayoutV0<BlakeTwo256>>::default();
+	let trie = TrieDBBuilder::<LayoutV0<BlakeTwo256>>::new(&db, &root)
+		.with_recorder(&mut recorder)
+		.build();
+	trie.get(&key).unwrap().unwrap();
+	drop(trie);
+
+	(root, recorder.drain().into_iter().map(|record| record.data).collect())
+}
+
+fn header_proof(para_id: u32, relay_height: u32, storage_proof: Vec<Vec<u8>>) -> Vec<u8> {
+	ParachainFraudProof::ParachainHeader(ParachainHeaderProof {
+		para_id,
+		proof: ParachainConsensusProof { relay_height, storage_proof },
+	})
+	.encode()
+}
+
+fn verify_fraud_proof(proof_1: Vec<u8>, proof_2: Vec<u8>) -> Result<(), IsmpError> {
+	ParachainConsensusClient::<Test, IsmpParachain>::default().verify_fraud_proof(
+		&Ismp::default(),
+		vec![],
+		proof_1,
+		proof_2,
+	)
+}
+
+fn assert_rejected(proof_1: Vec<u8>, proof_2: Vec<u8>, reason: &str) {
+	match verify_fraud_proof(proof_1, proof_2) {
+		Err(IsmpError::Custom(err)) => assert!(err.contains(reason), "{err}"),
+		res => panic!("Expected the fraud proof to be rejected, got {res:?}"),
+	}
+}
+
+#[test]
+fn should_accept_conflicting_parachain_headers() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let (root_1, proof_1) = relay_chain_state(b"head-1");
+		let (root_2, proof_2) = relay_chain_state(b"head-2");
+		Parachains::<Test>::insert(PARA_ID, 12_000);
+		// the relay chain state recorded by the oracle conflicts with the one reported by
+		// parachain-system at the same height
+		RelayChainStateCommitments::<Test>::insert(RELAY_HEIGHT, root_1);
+		frame_support::storage::unhashed::put(
+			&frame_support::storage::storage_prefix(b"ParachainSystem", b"ValidationData"),
+			&PersistedValidationData {
+				relay_parent_number: RELAY_HEIGHT,
+				relay_parent_storage_root: root_2,
+				..Default::default()
+			},
+		);
+
+		verify_fraud_proof(
+			header_proof(PARA_ID, RELAY_HEIGHT, proof_1),
+			header_proof(PARA_ID, RELAY_HEIGHT, proof_2),
+		)
+		.unwrap();
+	});
+}
+
+#[test]
+fn should_reject_invalid_parachain_header_fraud_proofs() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let (root, proof) = relay_chain_state(b"head-1");
+		let (_, unknown_proof) = relay_chain_state(b"head-2");
+		Parachains::<Test>::insert(PARA_ID, 12_000);
+		RelayChainStateCommitments::<Test>::insert(RELAY_HEIGHT, root);
+
+		// the relay chain state commits to a single head
+		assert_rejected(
+			header_proof(PARA_ID, RELAY_HEIGHT, proof.clone()),
+			header_proof(PARA_ID, RELAY_HEIGHT, proof.clone()),
+			"Invalid Fraud proof",
+		);
+		// the conflicting head is not part of a known relay chain state
+		assert_rejected(
+			header_proof(PARA_ID, RELAY_HEIGHT, proof.clone()),
+			header_proof(PARA_ID, RELAY_HEIGHT, unknown_proof.clone()),
+			"Invalid relay chain state proof",
+		);
+		assert_rejected(
+			header_proof(PARA_ID, RELAY_HEIGHT, proof.clone()),
+			header_proof(PARA_ID, RELAY_HEIGHT, vec![]),
+			"Invalid relay chain state proof",
+		);
+		// the proofs must be for the same parachain at the same height
+		assert_rejected(
+			header_proof(PARA_ID, RELAY_HEIGHT, proof.clone()),
+			header_proof(PARA_ID + 1, RELAY_HEIGHT, unknown_proof.clone()),
+			"same parachain at the same relay chain height",
+		);
+		assert_rejected(
+			header_proof(PARA_ID, RELAY_HEIGHT, proof.clone()),
+			header_proof(PARA_ID, RELAY_HEIGHT + 1, unknown_proof.clone()),
+			"same parachain at the same relay chain height",
+		);
+		assert_rejected(
+			header_proof(PARA_ID, RELAY_HEIGHT + 1, proof.clone()),
+			header_proof(PARA_ID, RELAY_HEIGHT + 1, unknown_proof.clone()),
+			"Cannot find relay chain height",
+		);
+		assert_rejected(
+			header_proof(PARA_ID + 1, RELAY_HEIGHT, proof),
+			header_proof(PARA_ID + 1, RELAY_HEIGHT, unknown_proof),
+			"not registered",
+		);
+	});
+}
+
+#[test]
+fn should_reject_mixed_and_undecodable_fraud_proofs() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let (root, proof) = relay_chain_state(b"head-1");
+		Parachains::<Test>::insert(PARA_ID, 12_000);
+		RelayChainStateCommitments::<Test>::insert(RELAY_HEIGHT, root);
+
+		let header_proof = header_proof(PARA_ID, RELAY_HEIGHT, proof);
+		let votes = ParachainFraudProof::GrandpaVotes(GrandpaVotes {
+			relay_height: RELAY_HEIGHT,
+			authority_set_proof: vec![],
+			set_id: 0,
+			round: 0,
+			votes: vec![],
+		})
+		.encode();
+
+		assert_rejected(header_proof.clone(), votes.clone(), "must be of the same kind");
+		assert_rejected(votes, header_proof.clone(), "must be of the same kind");
+
+		let truncated = header_proof[..header_proof.len() / 2].to_vec();
+		// unknown variant
+		let mut unknown = header_proof.clone();
+		unknown[0] = 2;
+		assert_rejected(vec![], header_proof.clone(), "Cannot decode first fraud proof");
+		assert_rejected(truncated.clone(), header_proof.clone(), "Cannot decode first fraud proof");
+		assert_rejected(unknown.clone(), header_proof.clone(), "Cannot decode first fraud proof");
+		assert_rejected(header_proof.clone(), vec![], "Cannot decode second fraud proof");
+		assert_rejected(header_proof.clone(), truncated, "Cannot decode second fraud proof");
+		assert_rejected(header_proof, unknown, "Cannot decode second fraud proof");
+	});
+}
@@ -15,14 +15,18 @@
 
 #![cfg(test)]
 
-use crate::runtime::{new_test_ext, Ismp, RuntimeOrigin, Test};
+use crate::runtime::{new_test_ext, setup_mock_client, Balances, Ismp, RuntimeOrigin, Test, UNIT};
+use frame_support::traits::fungible::{Inspect, Mutate};
 use ismp::{
 	consensus::{StateCommitment, StateMachineHeight, StateMachineId},
 	error::Error,
+	handlers::validate_state_machine,
 	host::{IsmpHost, StateMachine},
 };
+use pallet_fishermen::{VetoEvidence, FISHERMEN_BOND_ACCOUNT};
+use pallet_ismp::RELAYER_FEE_ACCOUNT;
 use sp_core::{crypto::AccountId32, H256};
-use sp_runtime::{DispatchError, ModuleError};
+use sp_runtime::{traits::AccountIdConversion, DispatchError, ModuleError};
 
 #[test]
 fn test_can_veto_state_commitments() {
@@ -88,3 +92,228 @@ fn test_can_veto_state_commitments() {
 		assert_eq!(pallet_fishermen::Fishermen::<Test>::get(account), None);
 	})
 }
+
+#[test]
+fn test_bonded_fishermen_can_veto_with_consensus_proofs() {
+	new_test_ext().execute_with(|| {
+		let host = Ismp::default();
+		let height = setup_mock_client::<_, Test>(&host);
+		let account: AccountId32 = H256::random().0.into();
+		let pot: AccountId32 = RELAYER_FEE_ACCOUNT.into_account_truncating();
+		Balances::mint_into(&account, 200 * UNIT).unwrap();
+		Balances::mint_into(&pot, 100 * UNIT).unwrap();
+
+		// bonding is disabled until the pallet is permissionless
+		let result = pallet_fishermen::Pallet::<Test>::bond(RuntimeOrigin::signed(account.clone()));
+		assert_eq!(
+			result,
+			Err(DispatchError::Module(ModuleError {
+				index: 9,
+				error: [4, 0, 0, 0],
+				message: Some("NotPermissionless"),
+			}))
+		);
+
+		pallet_fishermen::Pallet::<Test>::set_permissionless(RuntimeOrigin::root(), true).unwrap();
+		pallet_fishermen::Pallet::<Test>::bond(RuntimeOrigin::signed(account.clone())).unwrap();
+		assert_eq!(pallet_fishermen::Bonds::<Test>::get(&account), Some(100 * UNIT));
+		assert_eq!(
+			Balances::balance(&FISHERMEN_BOND_ACCOUNT.into_account_truncating()),
+			100 * UNIT
+		);
+
+		// conflicting state commitments can't be verified on-chain
+		let commitment = host.state_machine_commitment(height).unwrap();
+		let result = pallet_fishermen::Pallet::<Test>::veto_with_evidence(
+			RuntimeOrigin::signed(account.clone()),
+			height,
+			VetoEvidence::ConflictingStateCommitment {
+				commitment: StateCommitment { state_root: H256::random(), ..commitment },
+			},
+		);
+		assert_eq!(
+			result,
+			Err(DispatchError::Module(ModuleError {
+				index: 9,
+				error: [8, 0, 0, 0],
+				message: Some("InvalidEvidence"),
+			}))
+		);
+		assert!(!host.is_state_commitment_disputed(height));
+
+		pallet_fishermen::Pallet::<Test>::veto_with_evidence(
+			RuntimeOrigin::signed(account.clone()),
+			height,
+			VetoEvidence::ConflictingConsensus { proof_1: vec![1], proof_2: vec![2] },
+		)
+		.unwrap();
+
+		// the commitment is deleted, the client frozen and the fisherman rewarded immediately
+		assert!(matches!(
+			host.state_machine_commitment(height),
+			Err(Error::StateCommitmentNotFound { .. })
+		));
+		assert!(host.is_consensus_client_frozen(height.id.consensus_state_id).is_err());
+		assert_eq!(pallet_fishermen::PendingVetoes::<Test>::get(height), None);
+		assert_eq!(Balances::balance(&account), 110 * UNIT);
+
+		// the frozen client can't be vetoed again for another reward
+		let next_height = StateMachineHeight { height: height.height + 1, ..height };
+		host.store_state_machine_commitment(next_height, commitment).unwrap();
+		let result = pallet_fishermen::Pallet::<Test>::veto_with_evidence(
+			RuntimeOrigin::signed(account.clone()),
+			next_height,
+			VetoEvidence::ConflictingConsensus { proof_1: vec![1], proof_2: vec![2] },
+		);
+		assert_eq!(
+			result,
+			Err(DispatchError::Module(ModuleError {
+				index: 9,
+				error: [11, 0, 0, 0],
+				message: Some("ConsensusClientFrozen"),
+			}))
+		);
+		assert_eq!(host.state_machine_commitment(next_height).unwrap(), commitment);
+		assert_eq!(Balances::balance(&account), 110 * UNIT);
+
+		pallet_fishermen::Pallet::<Test>::unbond(RuntimeOrigin::signed(account.clone())).unwrap();
+		assert_eq!(pallet_fishermen::Bonds::<Test>::get(&account), None);
+		assert_eq!(Balances::balance(&account), 210 * UNIT);
+	})
+}
+
+#[test]
+fn test_frivolous_vetoes_are_slashed() {
+	new_test_ext().execute_with(|| {
+		let host = Ismp::default();
+		let height = setup_mock_client::<_, Test>(&host);
+		let commitment = host.state_machine_commitment(height).unwrap();
+		let account: AccountId32 = H256::random().0.into();
+		let other: AccountId32 = H256::random().0.into();
+		let pot: AccountId32 = RELAYER_FEE_ACCOUNT.into_account_truncating();
+		let bonds: AccountId32 = FISHERMEN_BOND_ACCOUNT.into_account_truncating();
+		Balances::mint_into(&account, 200 * UNIT).unwrap();
+		Balances::mint_into(&other, 200 * UNIT).unwrap();
+
+		pallet_fishermen::Pallet::<Test>::set_permissionless(RuntimeOrigin::root(), true).unwrap();
+		pallet_fishermen::Pallet::<Test>::bond(RuntimeOrigin::signed(account.clone())).unwrap();
+		pallet_fishermen::Pallet::<Test>::bond(RuntimeOrigin::signed(other.clone())).unwrap();
+		pallet_fishermen::Pallet::<Test>::veto_with_evidence(
+			RuntimeOrigin::signed(account.clone()),
+			height,
+			VetoEvidence::MissingBlock,
+		)
+		.unwrap();
+
+		// the commitment is left in place but can't be used to verify proofs until the veto is
+		// resolved
+		assert_eq!(host.state_machine_commitment(height).unwrap(), commitment);
+		assert!(host.is_state_commitment_disputed(height));
+		assert!(matches!(
+			validate_state_machine(&host, height),
+			Err(Error::StateCommitmentDisputed { .. })
+		));
+		assert!(pallet_fishermen::PendingVetoes::<Test>::get(height).is_some());
+		assert_eq!(pallet_fishermen::PendingVetoOf::<Test>::get(&account), Some(height));
+
+		// the bond only backs one pending veto at a time
+		let next_height = StateMachineHeight { height: height.height + 1, ..height };
+		host.store_state_machine_commitment(next_height, commitment).unwrap();
+		let result = pallet_fishermen::Pallet::<Test>::veto_with_evidence(
+			RuntimeOrigin::signed(account.clone()),
+			next_height,
+			VetoEvidence::MissingBlock,
+		);
+		assert_eq!(
+			result,
+			Err(DispatchError::Module(ModuleError {
+				index: 9,
+				error: [7, 0, 0, 0],
+				message: Some("HasPendingVetoes"),
+			}))
+		);
+
+		// the pending veto can't be replaced by another fisherman
+		let result = pallet_fishermen::Pallet::<Test>::veto_with_evidence(
+			RuntimeOrigin::signed(other.clone()),
+			height,
+			VetoEvidence::MissingBlock,
+		);
+		assert_eq!(
+			result,
+			Err(DispatchError::Module(ModuleError {
+				index: 9,
+				error: [10, 0, 0, 0],
+				message: Some("VetoAlreadyPending"),
+			}))
+		);
+
+		// the bond can't be withdrawn while the veto is pending
+		let result =
+			pallet_fishermen::Pallet::<Test>::unbond(RuntimeOrigin::signed(account.clone()));
+		assert_eq!(
+			result,
+			Err(DispatchError::Module(ModuleError {
+				index: 9,
+				error: [7, 0, 0, 0],
+				message: Some("HasPendingVetoes"),
+			}))
+		);
+
+		pallet_fishermen::Pallet::<Test>::resolve_veto(RuntimeOrigin::root(), height, false)
+			.unwrap();
+
+		// the commitment is kept and only the fisherman's own bond is slashed into the relayer
+		// fee pot
+		assert_eq!(host.state_machine_commitment(height).unwrap(), commitment);
+		assert_eq!(host.latest_commitment_height(height.id).unwrap(), height.height);
+		assert!(!host.is_state_commitment_disputed(height));
+		assert!(validate_state_machine(&host, height).is_ok());
+		assert_eq!(pallet_fishermen::PendingVetoes::<Test>::get(height), None);
+		assert_eq!(pallet_fishermen::PendingVetoOf::<Test>::get(&account), None);
+		assert_eq!(pallet_fishermen::Bonds::<Test>::get(&account), None);
+		assert_eq!(pallet_fishermen::Bonds::<Test>::get(&other), Some(100 * UNIT));
+		assert_eq!(Balances::balance(&account), 100 * UNIT);
+		assert_eq!(Balances::balance(&pot), 100 * UNIT);
+		assert_eq!(Balances::balance(&bonds), 100 * UNIT);
+	})
+}
+
+#[test]
+fn test_valid_pending_vetoes_delete_the_commitment() {
+	new_test_ext().execute_with(|| {
+		let host = Ismp::default();
+		let height = setup_mock_client::<_, Test>(&host);
+		let account: AccountId32 = H256::random().0.into();
+		let pot: AccountId32 = RELAYER_FEE_ACCOUNT.into_account_truncating();
+		Balances::mint_into(&account, 200 * UNIT).unwrap();
+		Balances::mint_into(&pot, 100 * UNIT).unwrap();
+
+		pallet_fishermen::Pallet::<Test>::set_permissionless(RuntimeOrigin::root(), true).unwrap();
+		pallet_fishermen::Pallet::<Test>::bond(RuntimeOrigin::signed(account.clone())).unwrap();
+		pallet_fishermen::Pallet::<Test>::veto_with_evidence(
+			RuntimeOrigin::signed(account.clone()),
+			height,
+			VetoEvidence::MissingBlock,
+		)
+		.unwrap();
+		assert!(host.state_machine_commitment(height).is_ok());
+		assert!(host.is_state_commitment_disputed(height));
+
+		pallet_fishermen::Pallet::<Test>::resolve_veto(RuntimeOrigin::root(), height, true)
+			.unwrap();
+
+		// the commitment is deleted and the fisherman rewarded, its bond is untouched
+		assert!(matches!(
+			host.state_machine_commitment(height),
+			Err(Error::StateCommitmentNotFound { .. })
+		));
+		assert!(!host.is_state_commitment_disputed(height));
+		assert_eq!(pallet_fishermen::PendingVetoes::<Test>::get(height), None);
+		assert_eq!(pallet_fishermen::Bonds::<Test>::get(&account), Some(100 * UNIT));
+		assert_eq!(Balances::balance(&account), 110 * UNIT);
+
+		pallet_fishermen::Pallet::<Test>::unbond(RuntimeOrigin::signed(account.clone())).unwrap();
+		assert_eq!(Balances::balance(&account), 210 * UNIT);
+	})
+}
@@ -15,9 +15,10 @@
 
 #![cfg(test)]
 
+use codec::Encode;
 use frame_support::traits::{
 	fungible::{Inspect, Mutate},
-	Time,
+	GetStorageVersion, OnRuntimeUpgrade, StorageVersion, Time,
 };
 use frame_system::Origin;
 use sp_core::{crypto::AccountId32, H256};
@@ -25,20 +26,32 @@ use sp_runtime::traits::AccountIdConversion;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use ismp::{
-	consensus::{StateMachineHeight, StateMachineId},
-	dispatcher::{DispatchGet, DispatchRequest, FeeMetadata, IsmpDispatcher},
+	consensus::{ConsensusClient, StateMachineHeight, StateMachineId},
+	dispatcher::{DispatchGet, DispatchPost, DispatchRequest, FeeMetadata, IsmpDispatcher},
+	error::Error as IsmpError,
+	handlers::handle_incoming_message,
 	host::{IsmpHost, StateMachine},
-	messaging::{hash_request, Message, Proof, ResponseMessage, TimeoutMessage},
-	router::{GetResponse, PostRequest, Request, RequestResponse, Response, Timeout},
+	messaging::{
+		hash_request, ConsensusMessage, FraudProofMessage, Message, Proof, RequestMessage,
+		ResponseMessage, TimeoutMessage,
+	},
+	router::{ChannelId, GetResponse, PostRequest, Request, RequestResponse, Response, Timeout},
+};
+use ismp_sync_committee::{
+	constants::sepolia::Sepolia, types::BeaconClientUpdate, SyncCommitteeConsensusClient,
+	BEACON_CONSENSUS_ID,
 };
 use ismp_testsuite::{
 	check_challenge_period, check_client_expiry, missing_state_commitment_check,
-	post_request_timeout_check, post_response_timeout_check, write_outgoing_commitments,
+	ordered_channel_check, post_request_timeout_check, post_response_timeout_check, sync_committee,
+	write_outgoing_commitments,
 };
 use pallet_ismp::{
 	child_trie::{RequestCommitments, RequestReceipts},
+	migrations::v1::MigrateV0ToV1,
 	mmr::Leaf,
-	FundMessageParams, MessageCommitment, RELAYER_FEE_ACCOUNT,
+	ConsensusClientStatus, ConsensusStateVersions, FundMessageParams, MessageCommitment,
+	RELAYER_FEE_ACCOUNT,
 };
 
 use crate::runtime::*;
@@ -122,6 +135,205 @@ fn should_reject_expired_check_clients() {
 	})
 }
 
+#[test]
+fn should_deliver_ordered_channel_requests_in_order() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		set_timestamp(None);
+		let host = Ismp::default();
+		let id = StateMachineId {
+			state_id: StateMachine::Evm(11155111),
+			consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+		};
+		host.store_challenge_period(id, 1_000_000).unwrap();
+		ordered_channel_check(&host).unwrap()
+	})
+}
+
+#[test]
+fn should_assign_consecutive_nonces_on_ordered_outgoing_channels() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		set_timestamp(None);
+		let host = Ismp::default();
+		let post = |to: Vec<u8>| DispatchPost {
+			dest: StateMachine::Evm(1),
+			from: vec![0u8; 32],
+			to,
+			timeout: 0,
+			body: vec![0u8; 64],
+		};
+		let dispatch = |to: Vec<u8>| {
+			host.dispatch_request(
+				DispatchRequest::Post(post(to)),
+				FeeMetadata { payer: [0u8; 32].into(), fee: 0 },
+			)
+			.unwrap();
+		};
+		let nonce = |index: u64| match Mmr::intermediate_leaves(index).unwrap() {
+			Leaf::Request(request) => request.nonce(),
+			_ => panic!("Leaf not found!"),
+		};
+
+		dispatch(vec![2u8; 32]);
+		dispatch(vec![2u8; 32]);
+
+		// outgoing channels continue from the global nonce
+		let channel = ChannelId {
+			source: host.host_state_machine(),
+			dest: StateMachine::Evm(1),
+			module: vec![1u8; 32],
+		};
+		Ismp::set_channel_ordering(Origin::<Test>::Root.into(), channel.clone(), Some(0)).unwrap();
+		assert_eq!(Ismp::channel_nonce(&channel), Some(2));
+
+		dispatch(vec![1u8; 32]);
+		dispatch(vec![2u8; 32]);
+		dispatch(vec![1u8; 32]);
+		assert_eq!((nonce(2), nonce(3), nonce(4)), (2, 2, 3));
+
+		// the global nonce continues past the channel nonces once the channel is unordered
+		Ismp::set_channel_ordering(Origin::<Test>::Root.into(), channel, None).unwrap();
+		dispatch(vec![1u8; 32]);
+		assert_eq!(nonce(5), 4);
+	})
+}
+
+#[test]
+fn should_enforce_consensus_client_registry_status() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let host = Ismp::default();
+		let height = setup_mock_client::<_, Test>(&host);
+
+		let consensus_message = Message::Consensus(ConsensusMessage {
+			consensus_proof: vec![],
+			consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+			signer: vec![],
+		});
+		let request_message = |nonce: u64| {
+			Message::Request(RequestMessage {
+				requests: vec![PostRequest {
+					source: StateMachine::Evm(1),
+					dest: host.host_state_machine(),
+					nonce,
+					from: vec![0u8; 32],
+					to: vec![0u8; 32],
+					timeout_timestamp: 0,
+					body: vec![],
+				}],
+				proof: Proof { height, proof: vec![] },
+				signer: vec![],
+			})
+		};
+
+		// paused clients cannot be updated, but their state commitments can still be used
+		Ismp::set_consensus_client_status(
+			Origin::<Test>::Root.into(),
+			MOCK_CONSENSUS_STATE_ID,
+			ConsensusClientStatus::Paused,
+		)
+		.unwrap();
+		let res = handle_incoming_message(&host, consensus_message.clone())
+			.map_err(|e| e.downcast::<IsmpError>().unwrap());
+		assert!(matches!(res, Err(IsmpError::ConsensusClientPaused { .. })));
+		handle_incoming_message(&host, request_message(0)).unwrap();
+
+		// disabled clients can also not be used to verify messages
+		Ismp::set_consensus_client_status(
+			Origin::<Test>::Root.into(),
+			MOCK_CONSENSUS_STATE_ID,
+			ConsensusClientStatus::Disabled,
+		)
+		.unwrap();
+		let res = handle_incoming_message(&host, request_message(1))
+			.map_err(|e| e.downcast::<IsmpError>().unwrap());
+		assert!(matches!(res, Err(IsmpError::ConsensusClientDisabled { .. })));
+
+		Ismp::set_consensus_client_status(
+			Origin::<Test>::Root.into(),
+			MOCK_CONSENSUS_STATE_ID,
+			ConsensusClientStatus::Enabled,
+		)
+		.unwrap();
+		handle_incoming_message(&host, consensus_message).unwrap();
+		handle_incoming_message(&host, request_message(1)).unwrap();
+
+		Ismp::set_consensus_client_deprecated(
+			Origin::<Test>::Root.into(),
+			MOCK_CONSENSUS_STATE_ID,
+			true,
+		)
+		.unwrap();
+		// consensus states already encoded by the current client version are left as is
+		Ismp::migrate_consensus_state(Origin::<Test>::Root.into(), MOCK_CONSENSUS_STATE_ID)
+			.unwrap();
+
+		let info = Ismp::consensus_client_info(MOCK_CONSENSUS_STATE_ID).unwrap();
+		assert_eq!(info.consensus_client_id, MOCK_CONSENSUS_CLIENT_ID);
+		assert_eq!(info.status, ConsensusClientStatus::Enabled);
+		assert!(info.deprecated);
+		assert!(!info.frozen);
+		assert_eq!(info.client_version, Some(info.state_version));
+		assert_eq!(Ismp::consensus_client_registry(), vec![info]);
+
+		// unknown consensus states cannot be registered
+		assert!(Ismp::set_consensus_client_status(
+			Origin::<Test>::Root.into(),
+			*b"none",
+			ConsensusClientStatus::Disabled,
+		)
+		.is_err());
+		assert!(Ismp::set_consensus_client_status(
+			Origin::<Test>::Signed(H256::random().0.into()).into(),
+			MOCK_CONSENSUS_STATE_ID,
+			ConsensusClientStatus::Disabled,
+		)
+		.is_err());
+	})
+}
+
+#[test]
+fn should_check_and_migrate_consensus_state_versions() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let host = Ismp::default();
+		setup_mock_client::<_, Test>(&host);
+
+		let consensus_message = Message::Consensus(ConsensusMessage {
+			consensus_proof: vec![],
+			consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+			signer: vec![],
+		});
+
+		// consensus states encoded by another version of the client are not decoded
+		ConsensusStateVersions::<Test>::insert(MOCK_CONSENSUS_STATE_ID, 1);
+		let res = handle_incoming_message(&host, consensus_message.clone())
+			.map_err(|e| e.downcast::<IsmpError>().unwrap());
+		assert!(matches!(
+			res,
+			Err(IsmpError::ConsensusStateVersionMismatch { stored: 1, expected: 0, .. })
+		));
+
+		// consensus states created before versions were tracked get the version of their client
+		ConsensusStateVersions::<Test>::remove(MOCK_CONSENSUS_STATE_ID);
+		StorageVersion::new(0).put::<Ismp>();
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Ismp::on_chain_storage_version(), StorageVersion::new(1));
+		assert_eq!(ConsensusStateVersions::<Test>::try_get(MOCK_CONSENSUS_STATE_ID), Ok(0));
+		handle_incoming_message(&host, consensus_message).unwrap();
+
+		// the migration only runs once
+		ConsensusStateVersions::<Test>::insert(MOCK_CONSENSUS_STATE_ID, 1);
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(ConsensusStateVersions::<Test>::get(MOCK_CONSENSUS_STATE_ID), 1);
+	})
+}
+
 #[test]
 fn should_handle_post_request_timeouts_correctly() {
 	let mut ext = new_test_ext();
@@ -402,3 +614,110 @@ fn test_fund_message() {
 		assert_eq!(metadata.fee.fee, 20 * UNIT);
 	});
 }
+
+#[test]
+fn sync_committee_client_should_only_be_frozen_by_valid_fraud_proofs() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		set_timestamp(None);
+		let host = Ismp::default();
+		let consensus_state_id = *b"ETH0";
+		let consensus_state = ismp_sync_committee::types::ConsensusState {
+			frozen_height: None,
+			light_client_state: sync_committee::trusted_state::<Sepolia>(),
+			l2_consensus: Default::default(),
+			chain_id: 11155111,
+		};
+		host.store_consensus_state(consensus_state_id, consensus_state.encode())
+			.unwrap();
+		host.store_consensus_state_id(consensus_state_id, BEACON_CONSENSUS_ID).unwrap();
+
+		let beacon_client_update = |consensus_update| BeaconClientUpdate {
+			consensus_update,
+			l2_oracle_payload: Default::default(),
+			dispute_game_payload: Default::default(),
+			arbitrum_payload: Default::default(),
+		};
+		let update = beacon_client_update(sync_committee::signed_update::<Sepolia>([1u8; 32]));
+		let conflicting_update =
+			beacon_client_update(sync_committee::signed_update::<Sepolia>([2u8; 32]));
+		let mut forged_update = sync_committee::signed_update::<Sepolia>([2u8; 32]);
+		forged_update.sync_aggregate.sync_committee_bits.set(0, false);
+		let forged_update = beacon_client_update(forged_update);
+
+		for (proof_1, proof_2) in [
+			(update.encode(), update.encode()),
+			(update.encode(), forged_update.encode()),
+			(vec![], vec![]),
+		] {
+			let fraud_proof = FraudProofMessage { proof_1, proof_2, consensus_state_id };
+			assert!(handle_incoming_message(&host, Message::FraudProof(fraud_proof)).is_err());
+		}
+		assert!(host.is_consensus_client_frozen(consensus_state_id).is_ok());
+
+		let fraud_proof = FraudProofMessage {
+			proof_1: update.encode(),
+			proof_2: conflicting_update.encode(),
+			consensus_state_id,
+		};
+		handle_incoming_message(&host, Message::FraudProof(fraud_proof)).unwrap();
+		assert!(host.is_consensus_client_frozen(consensus_state_id).is_err());
+	});
+}
+
+#[test]
+fn sync_committee_client_should_reject_malformed_fraud_proofs() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		let host = Ismp::default();
+		let client = SyncCommitteeConsensusClient::<Ismp, Sepolia, Test>::default();
+		let consensus_state = ismp_sync_committee::types::ConsensusState {
+			frozen_height: None,
+			light_client_state: sync_committee::trusted_state::<Sepolia>(),
+			l2_consensus: Default::default(),
+			chain_id: 11155111,
+		}
+		.encode();
+
+		let beacon_client_update = |consensus_update| BeaconClientUpdate {
+			consensus_update,
+			l2_oracle_payload: Default::default(),
+			dispute_game_payload: Default::default(),
+			arbitrum_payload: Default::default(),
+		};
+		let update =
+			beacon_client_update(sync_committee::signed_update::<Sepolia>([1u8; 32])).encode();
+		let conflicting_update =
+			beacon_client_update(sync_committee::signed_update::<Sepolia>([2u8; 32])).encode();
+		// the conflicting header is signed, but it has been tampered with after signing
+		let mut tampered_update = sync_committee::signed_update::<Sepolia>([2u8; 32]);
+		tampered_update.finalized_header.proposer_index += 1;
+		let tampered_update = beacon_client_update(tampered_update).encode();
+
+		let assert_rejected =
+			|consensus_state: &[u8], proof_1: &[u8], proof_2: &[u8], reason| match client
+				.verify_fraud_proof(
+					&host,
+					consensus_state.to_vec(),
+					proof_1.to_vec(),
+					proof_2.to_vec(),
+				) {
+				Err(IsmpError::Custom(err)) => assert!(err.contains(reason), "{err}"),
+				res => panic!("Expected the fraud proof to be rejected, got {res:?}"),
+			};
+
+		let truncated_update = &update[..update.len() / 2];
+		assert_rejected(&consensus_state, truncated_update, &conflicting_update, "proof 1");
+		assert_rejected(&consensus_state, &update, truncated_update, "proof 2");
+		assert_rejected(&consensus_state, &update, &[], "proof 2");
+		assert_rejected(&consensus_state[..4], &update, &conflicting_update, "consensus state");
+		assert_rejected(&consensus_state, &update, &tampered_update, "Invalid fraud proof");
+		assert_rejected(&consensus_state, &update, &update, "Invalid fraud proof");
+
+		client
+			.verify_fraud_proof(&host, consensus_state, update, conflicting_update)
+			.unwrap();
+	});
+}
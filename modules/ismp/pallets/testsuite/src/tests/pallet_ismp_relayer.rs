@@ -33,8 +33,11 @@ use pallet_ismp::{
 };
 use pallet_ismp_host_executive::{EvmHostParam, EvmHosts, HostParam};
 use pallet_ismp_relayer::{
-	self as pallet_ismp_relayer, message,
-	withdrawal::{Key, Signature, WithdrawalInputData, WithdrawalProof},
+	self as pallet_ismp_relayer, batch_message, beneficiary_message, message,
+	withdrawal::{
+		BatchWithdrawalInputData, BeneficiaryData, Key, Signature, WithdrawalInputData,
+		WithdrawalProof, MAX_WITHDRAWAL_BATCH_SIZE,
+	},
 };
 use sp_core::{Pair, H160, H256, U256};
 use sp_trie::LayoutV0;
@@ -43,8 +46,8 @@ use substrate_state_machine::{HashAlgorithm, StateMachineProof, SubstrateStatePr
 use trie_db::{Recorder, Trie, TrieDBBuilder, TrieDBMutBuilder, TrieMut};
 
 use crate::runtime::{
-	new_test_ext, set_timestamp, Ismp, RuntimeCall, RuntimeOrigin, Test, MOCK_CONSENSUS_CLIENT_ID,
-	MOCK_CONSENSUS_STATE_ID,
+	assert_last_event, new_test_ext, set_timestamp, Ismp, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+	Test, MOCK_CONSENSUS_CLIENT_ID, MOCK_CONSENSUS_STATE_ID,
 };
 use ismp_bsc::BSC_CONSENSUS_ID;
 use ismp_sync_committee::BEACON_CONSENSUS_ID;
@@ -54,241 +57,7 @@ use pallet_ismp::{dispatcher::RequestMetadata, mmr::LeafIndexAndPos};
 fn test_withdrawal_proof() {
 	let mut ext = new_test_ext();
 	ext.execute_with(|| {
-		set_timestamp::<Test>(10_000_000_000);
-		let requests = (0u64..10)
-			.into_iter()
-			.map(|nonce| {
-				let post = PostRequest {
-					source: StateMachine::Kusama(2000),
-					dest: StateMachine::Kusama(2001),
-					nonce,
-					from: vec![],
-					to: vec![],
-					timeout_timestamp: 0,
-					body: vec![],
-				};
-				hash_request::<Ismp>(&Request::Post(post))
-			})
-			.collect::<Vec<_>>();
-
-		let responses = (0u64..10)
-			.into_iter()
-			.map(|nonce| {
-				let post = PostRequest {
-					source: StateMachine::Kusama(2001),
-					dest: StateMachine::Kusama(2000),
-					nonce,
-					from: vec![],
-					to: vec![],
-					timeout_timestamp: 0,
-					body: vec![],
-				};
-				let response = ismp::router::PostResponse {
-					post: post.clone(),
-					response: vec![0; 32],
-					timeout_timestamp: nonce,
-				};
-				(hash_request::<Ismp>(&Request::Post(post)), hash_post_response::<Ismp>(&response))
-			})
-			.collect::<Vec<_>>();
-
-		let mut source_root = H256::default();
-
-		let mut source_db = MemoryDB::<KeccakHasher>::default();
-		let mut source_trie =
-			TrieDBMutBuilder::<LayoutV0<KeccakHasher>>::new(&mut source_db, &mut source_root)
-				.build();
-		let mut dest_root = H256::default();
-
-		let mut dest_db = MemoryDB::<KeccakHasher>::default();
-		let mut dest_trie =
-			TrieDBMutBuilder::<LayoutV0<KeccakHasher>>::new(&mut dest_db, &mut dest_root).build();
-
-		// Insert requests and responses
-		for request in &requests {
-			let request_commitment_key = RequestCommitments::<Test>::storage_key(*request);
-			let request_receipt_key = RequestReceipts::<Test>::storage_key(*request);
-			let fee_metadata = FeeMetadata::<Test> { payer: [0; 32].into(), fee: 1000u128.into() };
-			let leaf_meta = RequestMetadata {
-				mmr: LeafIndexAndPos { leaf_index: 0, pos: 0 },
-				fee: fee_metadata,
-				claimed: false,
-			};
-			RequestCommitments::<Test>::insert(*request, leaf_meta.clone());
-			source_trie.insert(&request_commitment_key, &leaf_meta.encode()).unwrap();
-			dest_trie.insert(&request_receipt_key, &vec![1u8; 32].encode()).unwrap();
-		}
-
-		for (request, response) in &responses {
-			let response_commitment_key = ResponseCommitments::<Test>::storage_key(*response);
-			let response_receipt_key = ResponseReceipts::<Test>::storage_key(*request);
-			let fee_metadata = FeeMetadata::<Test> { payer: [0; 32].into(), fee: 1000u128.into() };
-			let leaf_meta = RequestMetadata {
-				mmr: LeafIndexAndPos { leaf_index: 0, pos: 0 },
-				fee: fee_metadata,
-				claimed: false,
-			};
-			ResponseCommitments::<Test>::insert(*response, leaf_meta.clone());
-			source_trie.insert(&response_commitment_key, &leaf_meta.encode()).unwrap();
-			let receipt = ResponseReceipt { response: *response, relayer: vec![2; 32] };
-			dest_trie.insert(&response_receipt_key, &receipt.encode()).unwrap();
-		}
-		drop(source_trie);
-		drop(dest_trie);
-
-		let mut source_recorder = Recorder::<LayoutV0<KeccakHasher>>::default();
-		let mut dest_recorder = Recorder::<LayoutV0<KeccakHasher>>::default();
-		let source_trie = TrieDBBuilder::<LayoutV0<KeccakHasher>>::new(&source_db, &source_root)
-			.with_recorder(&mut source_recorder)
-			.build();
-
-		let dest_trie = TrieDBBuilder::<LayoutV0<KeccakHasher>>::new(&dest_db, &dest_root)
-			.with_recorder(&mut dest_recorder)
-			.build();
-
-		let mut keys = vec![];
-
-		for (index, request) in requests.iter().enumerate() {
-			if index % 2 == 0 {
-				let request_commitment_key = RequestCommitments::<Test>::storage_key(*request);
-				let request_receipt_key = RequestReceipts::<Test>::storage_key(*request);
-				source_trie.get(&request_commitment_key).unwrap();
-				dest_trie.get(&request_receipt_key).unwrap();
-				keys.push(Key::Request(*request));
-			}
-		}
-
-		for (index, (request, response)) in responses.iter().enumerate() {
-			if index % 2 == 0 {
-				let response_commitment_key = ResponseCommitments::<Test>::storage_key(*response);
-				let response_receipt_key = ResponseReceipts::<Test>::storage_key(*request);
-				source_trie.get(&response_commitment_key).unwrap();
-				dest_trie.get(&response_receipt_key).unwrap();
-				keys.push(Key::Response {
-					response_commitment: *response,
-					request_commitment: *request,
-				});
-			}
-		}
-
-		let source_keys_proof =
-			source_recorder.drain().into_iter().map(|f| f.data).collect::<Vec<_>>();
-		let dest_keys_proof = dest_recorder.drain().into_iter().map(|f| f.data).collect::<Vec<_>>();
-
-		let source_state_proof = SubstrateStateProof::OverlayProof(StateMachineProof {
-			hasher: HashAlgorithm::Keccak,
-			storage_proof: source_keys_proof,
-		});
-
-		let dest_state_proof = SubstrateStateProof::OverlayProof(StateMachineProof {
-			hasher: HashAlgorithm::Keccak,
-			storage_proof: dest_keys_proof,
-		});
-
-		let host = Ismp::default();
-		host.store_state_machine_commitment(
-			StateMachineHeight {
-				id: StateMachineId {
-					state_id: StateMachine::Kusama(2000),
-					consensus_state_id: MOCK_CONSENSUS_STATE_ID,
-				},
-				height: 1,
-			},
-			StateCommitment {
-				timestamp: 100,
-				overlay_root: Some(source_root),
-				state_root: Default::default(),
-			},
-		)
-		.unwrap();
-
-		host.store_state_machine_commitment(
-			StateMachineHeight {
-				id: StateMachineId {
-					state_id: StateMachine::Kusama(2001),
-					consensus_state_id: MOCK_CONSENSUS_STATE_ID,
-				},
-				height: 1,
-			},
-			StateCommitment {
-				timestamp: 100,
-				overlay_root: Some(dest_root),
-				state_root: Default::default(),
-			},
-		)
-		.unwrap();
-
-		host.store_state_machine_update_time(
-			StateMachineHeight {
-				id: StateMachineId {
-					state_id: StateMachine::Kusama(2000),
-					consensus_state_id: MOCK_CONSENSUS_STATE_ID,
-				},
-				height: 1,
-			},
-			Duration::from_secs(100),
-		)
-		.unwrap();
-
-		host.store_state_machine_update_time(
-			StateMachineHeight {
-				id: StateMachineId {
-					state_id: StateMachine::Kusama(2001),
-					consensus_state_id: MOCK_CONSENSUS_STATE_ID,
-				},
-				height: 1,
-			},
-			Duration::from_secs(100),
-		)
-		.unwrap();
-		host.store_consensus_state(MOCK_CONSENSUS_STATE_ID, Default::default()).unwrap();
-
-		host.store_consensus_state_id(MOCK_CONSENSUS_STATE_ID, MOCK_CONSENSUS_CLIENT_ID)
-			.unwrap();
-
-		host.store_unbonding_period(MOCK_CONSENSUS_STATE_ID, 10_000_000_000).unwrap();
-
-		host.store_challenge_period(
-			StateMachineId {
-				state_id: StateMachine::Kusama(2001),
-				consensus_state_id: MOCK_CONSENSUS_STATE_ID,
-			},
-			0,
-		)
-		.unwrap();
-
-		host.store_challenge_period(
-			StateMachineId {
-				state_id: StateMachine::Kusama(2000),
-				consensus_state_id: MOCK_CONSENSUS_STATE_ID,
-			},
-			0,
-		)
-		.unwrap();
-
-		let withdrawal_proof = WithdrawalProof {
-			commitments: keys,
-			source_proof: Proof {
-				height: StateMachineHeight {
-					id: StateMachineId {
-						state_id: StateMachine::Kusama(2000),
-						consensus_state_id: MOCK_CONSENSUS_STATE_ID,
-					},
-					height: 1,
-				},
-				proof: source_state_proof.encode(),
-			},
-			dest_proof: Proof {
-				height: StateMachineHeight {
-					id: StateMachineId {
-						state_id: StateMachine::Kusama(2001),
-						consensus_state_id: MOCK_CONSENSUS_STATE_ID,
-					},
-					height: 1,
-				},
-				proof: dest_state_proof.encode(),
-			},
-		};
+		let withdrawal_proof = setup_substrate_withdrawal_proof(vec![1; 32], vec![2; 32]);
 
 		pallet_ismp_relayer::Pallet::<Test>::accumulate_fees(
 			RuntimeOrigin::none(),
@@ -396,6 +165,267 @@ fn test_withdrawal_fees_evm() {
 	})
 }
 
+#[test]
+fn test_batch_withdrawal_fees() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		let pair = sp_core::ecdsa::Pair::from_seed_slice(H256::random().as_bytes()).unwrap();
+		let address = pair.public().to_eth_address().unwrap();
+		let chains = vec![StateMachine::Evm(84532), StateMachine::Evm(97)];
+		for chain in &chains {
+			pallet_ismp_host_executive::HostParams::<Test>::insert(
+				chain,
+				HostParam::EvmHostParam(EvmHostParam::default()),
+			);
+			pallet_ismp_relayer::Fees::<Test>::insert(
+				chain,
+				address.to_vec(),
+				U256::from(250_000_000_000_000_000_000u128),
+			);
+		}
+
+		let duplicates = BatchWithdrawalInputData {
+			signature: Signature::Evm { address: address.to_vec(), signature: vec![0u8; 65] },
+			dest_chains: vec![StateMachine::Evm(84532), StateMachine::Evm(84532)]
+				.try_into()
+				.unwrap(),
+		};
+		assert_eq!(
+			pallet_ismp_relayer::Pallet::<Test>::withdraw_fees_batch(
+				RuntimeOrigin::none(),
+				duplicates
+			),
+			Err(pallet_ismp_relayer::Error::<Test>::DuplicateChain.into())
+		);
+
+		// Batches larger than the maximum size can't be decoded
+		let oversized = (
+			Signature::Evm { address: address.to_vec(), signature: vec![0u8; 65] },
+			(0..=MAX_WITHDRAWAL_BATCH_SIZE).map(StateMachine::Kusama).collect::<Vec<_>>(),
+		)
+			.encode();
+		assert!(BatchWithdrawalInputData::decode(&mut &oversized[..]).is_err());
+
+		let nonces = chains.iter().map(|chain| (0u64, *chain)).collect::<Vec<_>>();
+		let signature = pair.sign_prehashed(&batch_message(&nonces)).0.to_vec();
+		let withdrawal_input = BatchWithdrawalInputData {
+			signature: Signature::Evm { address: address.to_vec(), signature },
+			dest_chains: chains.clone().try_into().unwrap(),
+		};
+
+		pallet_ismp_relayer::Pallet::<Test>::withdraw_fees_batch(
+			RuntimeOrigin::none(),
+			withdrawal_input.clone(),
+		)
+		.unwrap();
+		for chain in &chains {
+			assert_eq!(
+				pallet_ismp_relayer::Fees::<Test>::get(chain, address.to_vec()),
+				U256::zero()
+			);
+			assert_eq!(pallet_ismp_relayer::Nonce::<Test>::get(address.to_vec(), chain), 1);
+		}
+
+		// The signature can't be replayed once the nonces have been incremented
+		assert!(pallet_ismp_relayer::Pallet::<Test>::withdraw_fees_batch(
+			RuntimeOrigin::none(),
+			withdrawal_input
+		)
+		.is_err());
+	})
+}
+
+#[test]
+fn test_fees_are_credited_to_the_beneficiary() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		let hot = sp_core::sr25519::Pair::from_seed_slice(H256::random().as_bytes()).unwrap();
+		let cold = sp_core::sr25519::Pair::from_seed_slice(H256::random().as_bytes()).unwrap();
+		let address = hot.public().0.to_vec();
+		let beneficiary = cold.public().0.to_vec();
+
+		let signature = hot.sign(&beneficiary_message(0, &address, &beneficiary)).0.to_vec();
+		pallet_ismp_relayer::Pallet::<Test>::set_beneficiary(
+			RuntimeOrigin::none(),
+			BeneficiaryData {
+				address: address.clone(),
+				beneficiary: beneficiary.clone(),
+				signature: Signature::Sr25519 { public_key: address.clone(), signature },
+			},
+		)
+		.unwrap();
+		assert_eq!(
+			pallet_ismp_relayer::Beneficiaries::<Test>::get(&address),
+			Some(beneficiary.clone())
+		);
+
+		pallet_ismp_relayer::Pallet::<Test>::accumulate_fee(
+			StateMachine::Kusama(2000),
+			address.clone(),
+			U256::from(5000u128),
+		);
+		assert_eq!(
+			pallet_ismp_relayer::Fees::<Test>::get(StateMachine::Kusama(2000), address.clone()),
+			U256::zero()
+		);
+		assert_eq!(
+			pallet_ismp_relayer::Fees::<Test>::get(StateMachine::Kusama(2000), beneficiary.clone()),
+			U256::from(5000u128)
+		);
+
+		// The delivery key can no longer change the beneficiary
+		let signature = hot.sign(&beneficiary_message(1, &address, &address)).0.to_vec();
+		assert_eq!(
+			pallet_ismp_relayer::Pallet::<Test>::set_beneficiary(
+				RuntimeOrigin::none(),
+				BeneficiaryData {
+					address: address.clone(),
+					beneficiary: address.clone(),
+					signature: Signature::Sr25519 { public_key: address.clone(), signature },
+				},
+			),
+			Err(pallet_ismp_relayer::Error::<Test>::InvalidPublicKey.into())
+		);
+
+		// The beneficiary can remove itself
+		let signature = cold.sign(&beneficiary_message(1, &address, &address)).0.to_vec();
+		pallet_ismp_relayer::Pallet::<Test>::set_beneficiary(
+			RuntimeOrigin::none(),
+			BeneficiaryData {
+				address: address.clone(),
+				beneficiary: address.clone(),
+				signature: Signature::Sr25519 { public_key: beneficiary, signature },
+			},
+		)
+		.unwrap();
+		assert_eq!(pallet_ismp_relayer::Beneficiaries::<Test>::get(&address), None);
+	})
+}
+
+#[test]
+fn test_balance_held_before_the_beneficiary_is_set_is_paid_out_to_it() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		let hot = sp_core::sr25519::Pair::from_seed_slice(H256::random().as_bytes()).unwrap();
+		let cold = sp_core::sr25519::Pair::from_seed_slice(H256::random().as_bytes()).unwrap();
+		let address = hot.public().0.to_vec();
+		let beneficiary = cold.public().0.to_vec();
+		let amount = U256::from(250_000_000_000_000_000_000u128);
+		pallet_ismp_relayer::Fees::<Test>::insert(
+			StateMachine::Kusama(2000),
+			address.clone(),
+			amount,
+		);
+
+		let signature = hot.sign(&beneficiary_message(0, &address, &beneficiary)).0.to_vec();
+		pallet_ismp_relayer::Pallet::<Test>::set_beneficiary(
+			RuntimeOrigin::none(),
+			BeneficiaryData {
+				address: address.clone(),
+				beneficiary: beneficiary.clone(),
+				signature: Signature::Sr25519 { public_key: address.clone(), signature },
+			},
+		)
+		.unwrap();
+
+		// The delivery key still withdraws its own balance, which is paid out to the beneficiary
+		let signature = hot.sign(&message(0, StateMachine::Kusama(2000))).0.to_vec();
+		pallet_ismp_relayer::Pallet::<Test>::withdraw_fees(
+			RuntimeOrigin::none(),
+			WithdrawalInputData {
+				signature: Signature::Sr25519 { public_key: address.clone(), signature },
+				dest_chain: StateMachine::Kusama(2000),
+			},
+		)
+		.unwrap();
+		assert_eq!(
+			pallet_ismp_relayer::Fees::<Test>::get(StateMachine::Kusama(2000), address.clone()),
+			U256::zero()
+		);
+		assert_last_event::<Test>(RuntimeEvent::Relayer(pallet_ismp_relayer::Event::Withdraw {
+			address: address.try_into().unwrap(),
+			state_machine: StateMachine::Kusama(2000),
+			amount,
+			payee: beneficiary.try_into().unwrap(),
+		}));
+	})
+}
+
+#[test]
+fn test_beneficiary_withdraws_the_proven_fees_of_the_delivery_address() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		let hot = sp_core::sr25519::Pair::from_seed_slice(H256::random().as_bytes()).unwrap();
+		let cold = sp_core::sr25519::Pair::from_seed_slice(H256::random().as_bytes()).unwrap();
+		let address = hot.public().0.to_vec();
+		let beneficiary = cold.public().0.to_vec();
+
+		let signature = hot.sign(&beneficiary_message(0, &address, &beneficiary)).0.to_vec();
+		pallet_ismp_relayer::Pallet::<Test>::set_beneficiary(
+			RuntimeOrigin::none(),
+			BeneficiaryData {
+				address: address.clone(),
+				beneficiary: beneficiary.clone(),
+				signature: Signature::Sr25519 { public_key: address.clone(), signature },
+			},
+		)
+		.unwrap();
+
+		let withdrawal_proof = setup_substrate_withdrawal_proof(address.clone(), vec![2; 32]);
+		pallet_ismp_relayer::Pallet::<Test>::accumulate_fees(
+			RuntimeOrigin::none(),
+			withdrawal_proof,
+		)
+		.unwrap();
+		assert_eq!(
+			pallet_ismp_relayer::Fees::<Test>::get(StateMachine::Kusama(2000), address.clone()),
+			U256::zero()
+		);
+		assert_eq!(
+			pallet_ismp_relayer::Fees::<Test>::get(StateMachine::Kusama(2000), beneficiary.clone()),
+			U256::from(5000u128)
+		);
+		assert_eq!(
+			pallet_ismp_relayer::Fees::<Test>::get(StateMachine::Kusama(2000), vec![2; 32]),
+			U256::from(5000u128)
+		);
+
+		pallet_ismp_relayer::MinimumWithdrawalAmount::<Test>::put(U256::from(1000u128));
+
+		// The delivery key has nothing left to withdraw
+		let signature = hot.sign(&message(0, StateMachine::Kusama(2000))).0.to_vec();
+		assert_eq!(
+			pallet_ismp_relayer::Pallet::<Test>::withdraw_fees(
+				RuntimeOrigin::none(),
+				WithdrawalInputData {
+					signature: Signature::Sr25519 { public_key: address.clone(), signature },
+					dest_chain: StateMachine::Kusama(2000),
+				},
+			),
+			Err(pallet_ismp_relayer::Error::<Test>::NotEnoughBalance.into())
+		);
+
+		// The beneficiary withdraws with its own signature
+		let signature = cold.sign(&message(0, StateMachine::Kusama(2000))).0.to_vec();
+		pallet_ismp_relayer::Pallet::<Test>::withdraw_fees(
+			RuntimeOrigin::none(),
+			WithdrawalInputData {
+				signature: Signature::Sr25519 { public_key: beneficiary.clone(), signature },
+				dest_chain: StateMachine::Kusama(2000),
+			},
+		)
+		.unwrap();
+		assert_eq!(
+			pallet_ismp_relayer::Fees::<Test>::get(StateMachine::Kusama(2000), beneficiary.clone()),
+			U256::zero()
+		);
+		assert_eq!(
+			pallet_ismp_relayer::Nonce::<Test>::get(beneficiary, StateMachine::Kusama(2000)),
+			1
+		);
+	})
+}
+
 #[test]
 #[ignore]
 fn test_evm_accumulate_fees() {
@@ -607,6 +637,247 @@ fn test_evm_accumulate_fees_with_zero_fee_values() {
 	})
 }
 
+/// Builds a proof of 5 requests delivered by `request_relayer` and 5 responses delivered by
+/// `response_relayer` from Kusama(2000) to Kusama(2001), each with a fee of 1000
+fn setup_substrate_withdrawal_proof(
+	request_relayer: Vec<u8>,
+	response_relayer: Vec<u8>,
+) -> WithdrawalProof {
+	set_timestamp::<Test>(10_000_000_000);
+	let requests = (0u64..10)
+		.into_iter()
+		.map(|nonce| {
+			let post = PostRequest {
+				source: StateMachine::Kusama(2000),
+				dest: StateMachine::Kusama(2001),
+				nonce,
+				from: vec![],
+				to: vec![],
+				timeout_timestamp: 0,
+				body: vec![],
+			};
+			hash_request::<Ismp>(&Request::Post(post))
+		})
+		.collect::<Vec<_>>();
+
+	let responses = (0u64..10)
+		.into_iter()
+		.map(|nonce| {
+			let post = PostRequest {
+				source: StateMachine::Kusama(2001),
+				dest: StateMachine::Kusama(2000),
+				nonce,
+				from: vec![],
+				to: vec![],
+				timeout_timestamp: 0,
+				body: vec![],
+			};
+			let response = ismp::router::PostResponse {
+				post: post.clone(),
+				response: vec![0; 32],
+				timeout_timestamp: nonce,
+			};
+			(hash_request::<Ismp>(&Request::Post(post)), hash_post_response::<Ismp>(&response))
+		})
+		.collect::<Vec<_>>();
+
+	let mut source_root = H256::default();
+
+	let mut source_db = MemoryDB::<KeccakHasher>::default();
+	let mut source_trie =
+		TrieDBMutBuilder::<LayoutV0<KeccakHasher>>::new(&mut source_db, &mut source_root).build();
+	let mut dest_root = H256::default();
+
+	let mut dest_db = MemoryDB::<KeccakHasher>::default();
+	let mut dest_trie =
+		TrieDBMutBuilder::<LayoutV0<KeccakHasher>>::new(&mut dest_db, &mut dest_root).build();
+
+	// Insert requests and responses
+	for request in &requests {
+		let request_commitment_key = RequestCommitments::<Test>::storage_key(*request);
+		let request_receipt_key = RequestReceipts::<Test>::storage_key(*request);
+		let fee_metadata = FeeMetadata::<Test> { payer: [0; 32].into(), fee: 1000u128.into() };
+		let leaf_meta = RequestMetadata {
+			mmr: LeafIndexAndPos { leaf_index: 0, pos: 0 },
+			fee: fee_metadata,
+			claimed: false,
+		};
+		RequestCommitments::<Test>::insert(*request, leaf_meta.clone());
+		source_trie.insert(&request_commitment_key, &leaf_meta.encode()).unwrap();
+		dest_trie.insert(&request_receipt_key, &request_relayer.encode()).unwrap();
+	}
+
+	for (request, response) in &responses {
+		let response_commitment_key = ResponseCommitments::<Test>::storage_key(*response);
+		let response_receipt_key = ResponseReceipts::<Test>::storage_key(*request);
+		let fee_metadata = FeeMetadata::<Test> { payer: [0; 32].into(), fee: 1000u128.into() };
+		let leaf_meta = RequestMetadata {
+			mmr: LeafIndexAndPos { leaf_index: 0, pos: 0 },
+			fee: fee_metadata,
+			claimed: false,
+		};
+		ResponseCommitments::<Test>::insert(*response, leaf_meta.clone());
+		source_trie.insert(&response_commitment_key, &leaf_meta.encode()).unwrap();
+		let receipt = ResponseReceipt { response: *response, relayer: response_relayer.clone() };
+		dest_trie.insert(&response_receipt_key, &receipt.encode()).unwrap();
+	}
+	drop(source_trie);
+	drop(dest_trie);
+
+	let mut source_recorder = Recorder::<LayoutV0<KeccakHasher>>::default();
+	let mut dest_recorder = Recorder::<LayoutV0<KeccakHasher>>::default();
+	let source_trie = TrieDBBuilder::<LayoutV0<KeccakHasher>>::new(&source_db, &source_root)
+		.with_recorder(&mut source_recorder)
+		.build();
+
+	let dest_trie = TrieDBBuilder::<LayoutV0<KeccakHasher>>::new(&dest_db, &dest_root)
+		.with_recorder(&mut dest_recorder)
+		.build();
+
+	let mut keys = vec![];
+
+	for (index, request) in requests.iter().enumerate() {
+		if index % 2 == 0 {
+			let request_commitment_key = RequestCommitments::<Test>::storage_key(*request);
+			let request_receipt_key = RequestReceipts::<Test>::storage_key(*request);
+			source_trie.get(&request_commitment_key).unwrap();
+			dest_trie.get(&request_receipt_key).unwrap();
+			keys.push(Key::Request(*request));
+		}
+	}
+
+	for (index, (request, response)) in responses.iter().enumerate() {
+		if index % 2 == 0 {
+			let response_commitment_key = ResponseCommitments::<Test>::storage_key(*response);
+			let response_receipt_key = ResponseReceipts::<Test>::storage_key(*request);
+			source_trie.get(&response_commitment_key).unwrap();
+			dest_trie.get(&response_receipt_key).unwrap();
+			keys.push(Key::Response {
+				response_commitment: *response,
+				request_commitment: *request,
+			});
+		}
+	}
+
+	let source_keys_proof = source_recorder.drain().into_iter().map(|f| f.data).collect::<Vec<_>>();
+	let dest_keys_proof = dest_recorder.drain().into_iter().map(|f| f.data).collect::<Vec<_>>();
+
+	let source_state_proof = SubstrateStateProof::OverlayProof(StateMachineProof {
+		hasher: HashAlgorithm::Keccak,
+		storage_proof: source_keys_proof,
+	});
+
+	let dest_state_proof = SubstrateStateProof::OverlayProof(StateMachineProof {
+		hasher: HashAlgorithm::Keccak,
+		storage_proof: dest_keys_proof,
+	});
+
+	let host = Ismp::default();
+	host.store_state_machine_commitment(
+		StateMachineHeight {
+			id: StateMachineId {
+				state_id: StateMachine::Kusama(2000),
+				consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+			},
+			height: 1,
+		},
+		StateCommitment {
+			timestamp: 100,
+			overlay_root: Some(source_root),
+			state_root: Default::default(),
+		},
+	)
+	.unwrap();
+
+	host.store_state_machine_commitment(
+		StateMachineHeight {
+			id: StateMachineId {
+				state_id: StateMachine::Kusama(2001),
+				consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+			},
+			height: 1,
+		},
+		StateCommitment {
+			timestamp: 100,
+			overlay_root: Some(dest_root),
+			state_root: Default::default(),
+		},
+	)
+	.unwrap();
+
+	host.store_state_machine_update_time(
+		StateMachineHeight {
+			id: StateMachineId {
+				state_id: StateMachine::Kusama(2000),
+				consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+			},
+			height: 1,
+		},
+		Duration::from_secs(100),
+	)
+	.unwrap();
+
+	host.store_state_machine_update_time(
+		StateMachineHeight {
+			id: StateMachineId {
+				state_id: StateMachine::Kusama(2001),
+				consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+			},
+			height: 1,
+		},
+		Duration::from_secs(100),
+	)
+	.unwrap();
+	host.store_consensus_state(MOCK_CONSENSUS_STATE_ID, Default::default()).unwrap();
+
+	host.store_consensus_state_id(MOCK_CONSENSUS_STATE_ID, MOCK_CONSENSUS_CLIENT_ID)
+		.unwrap();
+
+	host.store_unbonding_period(MOCK_CONSENSUS_STATE_ID, 10_000_000_000).unwrap();
+
+	host.store_challenge_period(
+		StateMachineId {
+			state_id: StateMachine::Kusama(2001),
+			consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+		},
+		0,
+	)
+	.unwrap();
+
+	host.store_challenge_period(
+		StateMachineId {
+			state_id: StateMachine::Kusama(2000),
+			consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+		},
+		0,
+	)
+	.unwrap();
+
+	WithdrawalProof {
+		commitments: keys,
+		source_proof: Proof {
+			height: StateMachineHeight {
+				id: StateMachineId {
+					state_id: StateMachine::Kusama(2000),
+					consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+				},
+				height: 1,
+			},
+			proof: source_state_proof.encode(),
+		},
+		dest_proof: Proof {
+			height: StateMachineHeight {
+				id: StateMachineId {
+					state_id: StateMachine::Kusama(2001),
+					consensus_state_id: MOCK_CONSENSUS_STATE_ID,
+				},
+				height: 1,
+			},
+			proof: dest_state_proof.encode(),
+		},
+	}
+}
+
 fn setup_host_for_accumulate_fees() -> WithdrawalProof {
 	set_timestamp::<Test>(10_000_000_000);
 	let bsc_root = H256::from_slice(
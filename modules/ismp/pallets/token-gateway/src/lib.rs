@@ -22,7 +22,6 @@ pub mod impls;
 pub mod types;
 use crate::impls::{convert_to_balance, convert_to_erc20};
 use alloy_sol_types::SolValue;
-use anyhow::anyhow;
 use codec::Decode;
 use frame_support::{
 	ensure,
@@ -34,10 +33,7 @@ use frame_support::{
 	},
 };
 
-use ismp::{
-	events::Meta,
-	router::{PostRequest, Request, Response, Timeout},
-};
+use ismp::router::{PostRequest, Request, Response, Timeout};
 
 use sp_core::{Get, U256};
 use token_gateway_primitives::{
@@ -45,8 +41,8 @@ use token_gateway_primitives::{
 };
 pub use types::*;
 
-use alloc::{string::ToString, vec, vec::Vec};
-use ismp::module::IsmpModule;
+use alloc::{format, string::ToString, vec, vec::Vec};
+use ismp::module::{IsmpModule, ModuleError, ModuleErrorCode};
 use primitive_types::H256;
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
@@ -432,8 +428,8 @@ where
 {
 	fn on_accept(
 		&self,
-		PostRequest { body, from, source, dest, nonce, .. }: PostRequest,
-	) -> Result<(), anyhow::Error> {
+		PostRequest { body, from, source, .. }: PostRequest,
+	) -> Result<(), ModuleError> {
 		// The only requests allowed from token governor on Hyperbridge is asset creation, updating
 		// and deregistering
 		if from == token_governor_id() && Some(source) == T::Coprocessor::get() {
@@ -451,20 +447,21 @@ where
 							local_asset_id.clone(),
 						),
 					)
-					.map_err(|e| anyhow!("{e:?}"))?;
+					.map_err(|e| module_error(ModuleErrorCode::Internal, format!("{e:?}")))?;
 					// Note the asset's ERC counterpart decimal
 					Decimals::<T>::insert(local_asset_id, metadata.decimals);
 				} else {
 					let min_balance = metadata.minimum_balance.unwrap_or(MIN_BALANCE);
 					let local_asset_id =
-						T::AssetIdFactory::create_asset_id(metadata.symbol.to_vec())?;
+						T::AssetIdFactory::create_asset_id(metadata.symbol.to_vec())
+							.map_err(|e| module_error(ModuleErrorCode::Internal, e))?;
 					<T::Assets as fungibles::Create<T::AccountId>>::create(
 						local_asset_id.clone(),
 						T::AssetAdmin::get(),
 						true,
 						min_balance.into(),
 					)
-					.map_err(|e| anyhow!("{e:?}"))?;
+					.map_err(|e| module_error(ModuleErrorCode::Internal, format!("{e:?}")))?;
 					<T::Assets as fungibles::metadata::Mutate<T::AccountId>>::set(
 						local_asset_id.clone(),
 						&T::AssetAdmin::get(),
@@ -472,7 +469,7 @@ where
 						metadata.symbol.to_vec(),
 						18,
 					)
-					.map_err(|e| anyhow!("{e:?}"))?;
+					.map_err(|e| module_error(ModuleErrorCode::Internal, format!("{e:?}")))?;
 					SupportedAssets::<T>::insert(local_asset_id.clone(), asset_id.clone());
 					LocalAssets::<T>::insert(asset_id, local_asset_id.clone());
 					// Note the asset's ERC counterpart decimal
@@ -494,25 +491,22 @@ where
 		ensure!(
 			from == TokenGatewayAddresses::<T>::get(source).unwrap_or_default().to_vec() ||
 				from == token_gateway_id().0.to_vec(),
-			ismp::error::Error::ModuleDispatchError {
-				msg: "Token Gateway: Unknown source contract address".to_string(),
-				meta: Meta { source, dest, nonce },
-			}
+			module_error(
+				ModuleErrorCode::Unauthorized,
+				"Token Gateway: Unknown source contract address"
+			)
 		);
 
 		let body = Body::abi_decode(&mut &body[1..], true).map_err(|_| {
-			ismp::error::Error::ModuleDispatchError {
-				msg: "Token Gateway: Failed to decode request body".to_string(),
-				meta: Meta { source, dest, nonce },
-			}
+			module_error(
+				ModuleErrorCode::DecodeFailed,
+				"Token Gateway: Failed to decode request body",
+			)
 		})?;
 
 		let local_asset_id =
 			LocalAssets::<T>::get(H256::from(body.asset_id.0)).ok_or_else(|| {
-				ismp::error::Error::ModuleDispatchError {
-					msg: "Token Gateway: Unknown asset".to_string(),
-					meta: Meta { source, dest, nonce },
-				}
+				module_error(ModuleErrorCode::InvalidRequest, "Token Gateway: Unknown asset")
 			})?;
 
 		let decimals = if local_asset_id == T::NativeAssetId::get() {
@@ -522,16 +516,22 @@ where
 				local_asset_id.clone(),
 			)
 		};
-		let erc_decimals = Decimals::<T>::get(local_asset_id.clone())
-			.ok_or_else(|| anyhow!("Asset decimals not configured"))?;
+		let erc_decimals = Decimals::<T>::get(local_asset_id.clone()).ok_or_else(|| {
+			module_error(
+				ModuleErrorCode::InvalidRequest,
+				"Token Gateway: Asset decimals not configured",
+			)
+		})?;
 		let amount = convert_to_balance(
 			U256::from_big_endian(&body.amount.to_be_bytes::<32>()),
 			erc_decimals,
 			decimals,
 		)
-		.map_err(|_| ismp::error::Error::ModuleDispatchError {
-			msg: "Token Gateway: Trying to withdraw Invalid amount".to_string(),
-			meta: Meta { source, dest, nonce },
+		.map_err(|_| {
+			module_error(
+				ModuleErrorCode::InvalidRequest,
+				"Token Gateway: Trying to withdraw Invalid amount",
+			)
 		})?;
 		let beneficiary: T::AccountId = body.to.0.into();
 		if local_asset_id == T::NativeAssetId::get() {
@@ -541,9 +541,11 @@ where
 				amount.into(),
 				ExistenceRequirement::AllowDeath,
 			)
-			.map_err(|_| ismp::error::Error::ModuleDispatchError {
-				msg: "Token Gateway: Failed to complete asset transfer".to_string(),
-				meta: Meta { source, dest, nonce },
+			.map_err(|_| {
+				module_error(
+					ModuleErrorCode::Unavailable,
+					"Token Gateway: Failed to complete asset transfer",
+				)
 			})?;
 		} else {
 			<T as Config>::Assets::transfer(
@@ -553,9 +555,11 @@ where
 				amount.into(),
 				Preservation::Protect,
 			)
-			.map_err(|_| ismp::error::Error::ModuleDispatchError {
-				msg: "Token Gateway: Failed to complete asset transfer".to_string(),
-				meta: Meta { source, dest, nonce },
+			.map_err(|_| {
+				module_error(
+					ModuleErrorCode::Unavailable,
+					"Token Gateway: Failed to complete asset transfer",
+				)
 			})?;
 		}
 
@@ -567,24 +571,29 @@ where
 		Ok(())
 	}
 
-	fn on_response(&self, _response: Response) -> Result<(), anyhow::Error> {
-		Err(anyhow!("Module does not accept responses".to_string()))
+	fn on_response(&self, _response: Response) -> Result<(), ModuleError> {
+		Err(module_error(
+			ModuleErrorCode::Unsupported,
+			"Token Gateway: Module does not accept responses",
+		))
 	}
 
-	fn on_timeout(&self, request: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, request: Timeout) -> Result<(), ModuleError> {
 		match request {
-			Timeout::Request(Request::Post(PostRequest { body, source, dest, nonce, .. })) => {
+			Timeout::Request(Request::Post(PostRequest { body, dest, .. })) => {
 				let body = Body::abi_decode(&mut &body[1..], true).map_err(|_| {
-					ismp::error::Error::ModuleDispatchError {
-						msg: "Token Gateway: Failed to decode request body".to_string(),
-						meta: Meta { source, dest, nonce },
-					}
+					module_error(
+						ModuleErrorCode::DecodeFailed,
+						"Token Gateway: Failed to decode request body",
+					)
 				})?;
 				let beneficiary = body.from.0.into();
 				let local_asset_id = LocalAssets::<T>::get(H256::from(body.asset_id.0))
-					.ok_or_else(|| ismp::error::Error::ModuleDispatchError {
-						msg: "Token Gateway: Unknown asset".to_string(),
-						meta: Meta { source, dest, nonce },
+					.ok_or_else(|| {
+						module_error(
+							ModuleErrorCode::InvalidRequest,
+							"Token Gateway: Unknown asset",
+						)
 					})?;
 				let decimals = if local_asset_id == T::NativeAssetId::get() {
 					T::Decimals::get()
@@ -593,16 +602,22 @@ where
 						local_asset_id.clone(),
 					)
 				};
-				let erc_decimals = Decimals::<T>::get(local_asset_id.clone())
-					.ok_or_else(|| anyhow!("Asset decimals not configured"))?;
+				let erc_decimals = Decimals::<T>::get(local_asset_id.clone()).ok_or_else(|| {
+					module_error(
+						ModuleErrorCode::InvalidRequest,
+						"Token Gateway: Asset decimals not configured",
+					)
+				})?;
 				let amount = convert_to_balance(
 					U256::from_big_endian(&body.amount.to_be_bytes::<32>()),
 					erc_decimals,
 					decimals,
 				)
-				.map_err(|_| ismp::error::Error::ModuleDispatchError {
-					msg: "Token Gateway: Trying to withdraw Invalid amount".to_string(),
-					meta: Meta { source, dest, nonce },
+				.map_err(|_| {
+					module_error(
+						ModuleErrorCode::InvalidRequest,
+						"Token Gateway: Trying to withdraw Invalid amount",
+					)
 				})?;
 
 				if local_asset_id == T::NativeAssetId::get() {
@@ -612,9 +627,11 @@ where
 						amount.into(),
 						ExistenceRequirement::AllowDeath,
 					)
-					.map_err(|_| ismp::error::Error::ModuleDispatchError {
-						msg: "Token Gateway: Failed to complete asset transfer".to_string(),
-						meta: Meta { source, dest, nonce },
+					.map_err(|_| {
+						module_error(
+							ModuleErrorCode::Unavailable,
+							"Token Gateway: Failed to complete asset transfer",
+						)
 					})?;
 				} else {
 					<T as Config>::Assets::transfer(
@@ -624,9 +641,11 @@ where
 						amount.into(),
 						Preservation::Protect,
 					)
-					.map_err(|_| ismp::error::Error::ModuleDispatchError {
-						msg: "Token Gateway: Failed to complete asset transfer".to_string(),
-						meta: Meta { source, dest, nonce },
+					.map_err(|_| {
+						module_error(
+							ModuleErrorCode::Unavailable,
+							"Token Gateway: Failed to complete asset transfer",
+						)
 					})?;
 				}
 
@@ -636,19 +655,15 @@ where
 					source: dest,
 				});
 			},
-			Timeout::Request(Request::Get(get)) => Err(ismp::error::Error::ModuleDispatchError {
-				msg: "Tried to timeout unsupported request type".to_string(),
-				meta: Meta { source: get.source, dest: get.dest, nonce: get.nonce },
-			})?,
-
-			Timeout::Response(response) => Err(ismp::error::Error::ModuleDispatchError {
-				msg: "Tried to timeout unsupported request type".to_string(),
-				meta: Meta {
-					source: response.source_chain(),
-					dest: response.dest_chain(),
-					nonce: response.nonce(),
-				},
-			})?,
+			Timeout::Request(Request::Get(_)) => Err(module_error(
+				ModuleErrorCode::Unsupported,
+				"Token Gateway: Tried to timeout unsupported request type",
+			))?,
+
+			Timeout::Response(_) => Err(module_error(
+				ModuleErrorCode::Unsupported,
+				"Token Gateway: Tried to timeout unsupported request type",
+			))?,
 		}
 		Ok(())
 	}
@@ -659,6 +674,11 @@ fn weight() -> Weight {
 	Weight::from_parts(300_000_000, 0)
 }
 
+/// Returns a [`ModuleError`] originating from the token gateway
+fn module_error(code: ModuleErrorCode, message: impl ToString) -> ModuleError {
+	ModuleError::new(code, token_gateway_id().0.to_vec(), message)
+}
+
 impl<T: Config> Pallet<T> {
 	/// Ensure the signer is the asset admin
 	pub fn ensure_admin(who: T::AccountId, asset_id: AssetId<T>) -> Result<(), Error<T>> {
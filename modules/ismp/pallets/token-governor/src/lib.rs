@@ -22,17 +22,18 @@ extern crate alloc;
 mod impls;
 mod types;
 use alloy_sol_types::SolValue;
-use anyhow::anyhow;
 use frame_support::pallet_prelude::Weight;
 use ismp::router::{PostRequest, Response, Timeout};
 
 pub use types::*;
 
-use alloc::{format, vec};
+use alloc::{format, string::ToString, vec};
 use codec::Encode;
-use ismp::module::IsmpModule;
+use ismp::module::{IsmpModule, ModuleError, ModuleErrorCode};
 use primitive_types::{H160, H256};
-use token_gateway_primitives::{token_gateway_id, RemoteERC6160AssetRegistration};
+use token_gateway_primitives::{
+	token_gateway_id, token_governor_id, RemoteERC6160AssetRegistration,
+};
 
 pub use token_gateway_primitives::REGISTRY as PALLET_ID;
 
@@ -409,11 +410,13 @@ where
 	fn on_accept(
 		&self,
 		PostRequest { body: data, from, source, .. }: PostRequest,
-	) -> Result<(), anyhow::Error> {
+	) -> Result<(), ModuleError> {
 		// Only substrate chains are allowed to fully register assets remotely
 		if source.is_substrate() && from == token_gateway_id().0.to_vec() {
 			let remote_reg: RemoteERC6160AssetRegistration = codec::Decode::decode(&mut &*data)
-				.map_err(|_| ismp::error::Error::Custom(format!("Failed to decode data")))?;
+				.map_err(|_| {
+					module_error(ModuleErrorCode::DecodeFailed, "Failed to decode data")
+				})?;
 			match remote_reg {
 				RemoteERC6160AssetRegistration::CreateAsset(asset) => {
 					let asset_id: H256 = sp_io::hashing::keccak_256(asset.symbol.as_ref()).into();
@@ -422,32 +425,41 @@ where
 						sp_io::hashing::keccak_256(&source.encode()).into(),
 					)
 					.map_err(|e| {
-						ismp::error::Error::Custom(format!("Failed create asset {e:?}"))
+						module_error(
+							ModuleErrorCode::InvalidRequest,
+							format!("Failed create asset {e:?}"),
+						)
 					})?;
 					StandaloneChainAssets::<T>::insert(source, asset_id, true);
 				},
 				RemoteERC6160AssetRegistration::UpdateAsset(asset) => {
 					Pallet::<T>::update_erc6160_asset_impl(asset.into()).map_err(|e| {
-						ismp::error::Error::Custom(format!("Failed create asset {e:?}"))
+						module_error(
+							ModuleErrorCode::InvalidRequest,
+							format!("Failed update asset {e:?}"),
+						)
 					})?;
 				},
 			}
 
 			return Ok(())
 		}
-		let RegistrarParams { address, .. } = TokenRegistrarParams::<T>::get(&source)
-			.ok_or_else(|| ismp::error::Error::Custom(format!("Pallet is not initialized")))?;
+		let RegistrarParams { address, .. } =
+			TokenRegistrarParams::<T>::get(&source).ok_or_else(|| {
+				module_error(ModuleErrorCode::Unavailable, "Pallet is not initialized")
+			})?;
 		if from != address.as_bytes().to_vec() {
-			Err(ismp::error::Error::Custom(format!("Unauthorized action")))?
+			Err(module_error(ModuleErrorCode::Unauthorized, "Unauthorized action"))?
 		}
-		let body = SolRequestBody::abi_decode(&data[..], true)
-			.map_err(|err| ismp::error::Error::Custom(format!("Decode error: {err}")))?;
+		let body = SolRequestBody::abi_decode(&data[..], true).map_err(|err| {
+			module_error(ModuleErrorCode::DecodeFailed, format!("Decode error: {err}"))
+		})?;
 		let asset_id: H256 = body.assetId.0.into();
 		let owner: H160 = body.owner.0 .0.into();
 
 		// asset must not already exist
 		if AssetOwners::<T>::contains_key(&asset_id) || PendingAsset::<T>::contains_key(&asset_id) {
-			Err(ismp::error::Error::Custom(format!("Asset already exists")))?
+			Err(module_error(ModuleErrorCode::InvalidRequest, "Asset already exists"))?
 		}
 
 		PendingAsset::<T>::insert(asset_id, owner);
@@ -457,17 +469,22 @@ where
 		Ok(())
 	}
 
-	fn on_response(&self, _response: Response) -> Result<(), anyhow::Error> {
-		Err(anyhow!("Module does not expect responses"))
+	fn on_response(&self, _response: Response) -> Result<(), ModuleError> {
+		Err(module_error(ModuleErrorCode::Unsupported, "Module does not expect responses"))
 	}
 
-	fn on_timeout(&self, _request: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, _request: Timeout) -> Result<(), ModuleError> {
 		// The request lives forever, it's not exactly time-sensitive.
 		// There are no refunds for asset registration fees
-		Err(anyhow!("Module does not expect timeouts"))
+		Err(module_error(ModuleErrorCode::Unsupported, "Module does not expect timeouts"))
 	}
 }
 
+/// Returns a [`ModuleError`] originating from this pallet
+fn module_error(code: ModuleErrorCode, message: impl ToString) -> ModuleError {
+	ModuleError::new(code, token_governor_id(), message)
+}
+
 /// Static weights because benchmarks suck, and we'll be getting PolkaVM soon anyways
 fn weight() -> Weight {
 	Weight::from_parts(300_000_000, 0)
@@ -22,16 +22,13 @@ use core::marker::PhantomData;
 
 use alloc::{collections::BTreeMap, format, vec::Vec};
 use codec::Decode;
-use sp_runtime::{
-	traits::{BlakeTwo256, Keccak256, Zero},
-	Either,
-};
+use sp_runtime::traits::{BlakeTwo256, Keccak256, Zero};
 use sp_trie::{LayoutV0, StorageProof, Trie, TrieDBBuilder};
 
 use ismp::{
 	consensus::{StateCommitment, StateMachineClient},
 	host::{IsmpHost, StateMachine},
-	messaging::{hash_request, hash_response, Proof},
+	messaging::{hash_batch, hash_request, hash_response, Proof},
 	router::{Request, RequestResponse},
 	Error,
 };
@@ -91,11 +88,15 @@ where
 				.into_iter()
 				.map(|request| {
 					let commitment = hash_request::<H>(&request);
+					let dest = match request {
+						Request::Post(ref post) => Some(post.dest),
+						Request::Get(_) => None,
+					};
 					(
 						RequestCommitments::<T>::storage_key(commitment),
 						RequestPayments::storage_key(commitment),
 						request.body().unwrap_or_default().len() as u128,
-						Either::Left(request),
+						dest,
 					)
 				})
 				.collect::<Vec<_>>(),
@@ -107,7 +108,21 @@ where
 						ResponseCommitments::<T>::storage_key(commitment),
 						ResponsePayments::storage_key(commitment),
 						response.encode().len() as u128,
-						Either::Right(response),
+						Some(response.dest_chain()),
+					)
+				})
+				.collect::<Vec<_>>(),
+			RequestResponse::Batch(batches) => batches
+				.into_iter()
+				.map(|batch| {
+					let commitment = hash_batch::<H>(&batch);
+					// batches pay for the combined size of their items
+					let size = batch.items.iter().map(|item| item.body.len() as u128).sum();
+					(
+						RequestCommitments::<T>::storage_key(commitment),
+						RequestPayments::storage_key(commitment),
+						size,
+						Some(batch.dest),
 					)
 				})
 				.collect::<Vec<_>>(),
@@ -128,7 +143,7 @@ where
 					StorageProof::new(state_proof.storage_proof()).into_memory_db::<Keccak256>();
 				let trie = TrieDBBuilder::<LayoutV0<Keccak256>>::new(&db, &root).build();
 
-				for (commitment_key, payment_key, size, item) in commitments {
+				for (commitment_key, payment_key, size, dest) in commitments {
 					trie.get(&commitment_key)
 						.map_err(|e| Error::Custom(format!("Error reading state proof: {e:?}")))?
 						.ok_or_else(|| {
@@ -137,11 +152,7 @@ where
 							))
 						})?;
 
-					let dest = match item {
-						Either::Left(Request::Post(req)) => req.dest,
-						Either::Right(response) => response.dest_chain(),
-						_ => continue,
-					};
+					let Some(dest) = dest else { continue };
 					let per_byte_fee =
 						*params.per_byte_fees.get(&dest).unwrap_or(&params.default_per_byte_fee);
 
@@ -182,7 +193,7 @@ where
 					StorageProof::new(state_proof.storage_proof()).into_memory_db::<BlakeTwo256>();
 				let trie = TrieDBBuilder::<LayoutV0<BlakeTwo256>>::new(&db, &root).build();
 
-				for (commitment_key, payment_key, size, item) in commitments {
+				for (commitment_key, payment_key, size, dest) in commitments {
 					trie.get(&commitment_key)
 						.map_err(|e| Error::Custom(format!("Error reading state proof: {e:?}")))?
 						.ok_or_else(|| {
@@ -191,11 +202,7 @@ where
 							))
 						})?;
 
-					let dest = match item {
-						Either::Left(Request::Post(req)) => req.dest,
-						Either::Right(response) => response.dest_chain(),
-						_ => continue,
-					};
+					let Some(dest) = dest else { continue };
 					let per_byte_fee =
 						*params.per_byte_fees.get(&dest).unwrap_or(&params.default_per_byte_fee);
 
@@ -28,7 +28,7 @@ use ismp::{
 	consensus::{StateCommitment, StateMachineClient},
 	error::Error,
 	host::{IsmpHost, StateMachine},
-	messaging::{hash_post_response, hash_request, hash_response, Proof},
+	messaging::{hash_batch, hash_post_response, hash_request, hash_response, Proof},
 	router::{Request, RequestResponse, Response},
 };
 use pallet_ismp::{
@@ -150,6 +150,13 @@ where
 					ResponseCommitments::<T>::storage_key(commitment)
 				})
 				.collect::<Vec<Vec<u8>>>(),
+			RequestResponse::Batch(batches) => batches
+				.into_iter()
+				.map(|batch| {
+					let commitment = hash_batch::<pallet_ismp::Pallet<T>>(&batch);
+					RequestCommitments::<T>::storage_key(commitment)
+				})
+				.collect::<Vec<Vec<u8>>>(),
 		};
 		let _ = match state_proof.hasher() {
 			HashAlgorithm::Keccak => {
@@ -217,6 +224,11 @@ where
 						Response::Get(_) => continue,
 					}
 				},
+			RequestResponse::Batch(batches) =>
+				for batch in batches {
+					let commitment = hash_batch::<pallet_ismp::Pallet<T>>(&batch);
+					keys.push(RequestReceipts::<T>::storage_key(commitment));
+				},
 		};
 
 		keys
@@ -17,24 +17,33 @@
 
 use std::vec;
 
+use codec::Encode;
 use ismp::{
 	consensus::{
 		ConsensusStateId, IntermediateState, StateCommitment, StateMachineHeight, StateMachineId,
 	},
-	dispatcher::{DispatchPost, DispatchRequest, FeeMetadata, IsmpDispatcher},
+	dispatcher::{DispatchBatch, DispatchPost, DispatchRequest, FeeMetadata, IsmpDispatcher},
 	error::Error,
-	handlers::handle_incoming_message,
+	events::Event,
+	handlers::{handle_incoming_message, handle_incoming_messages, MessageResult, MessageStatus},
 	host::{IsmpHost, StateMachine},
 	messaging::{
-		hash_post_response, hash_request, ConsensusMessage, FraudProofMessage, Message, Proof,
-		RequestMessage, ResponseMessage, TimeoutMessage,
+		hash_batch, hash_post_response, hash_request, BatchMessage, ConsensusMessage,
+		FraudProofMessage, Message, Proof, RequestMessage, ResponseMessage, TimeoutMessage,
+	},
+	router::{
+		BatchItem, ChannelId, PostBatch, PostRequest, PostResponse, Request, RequestResponse,
+		Response,
 	},
-	router::{PostRequest, PostResponse, Request, RequestResponse, Response},
 };
+use sync_committee_primitives::constants::devnet::Devnet;
 
-use crate::mocks::{Host, MOCK_CONSENSUS_CLIENT_ID, MOCK_PROXY_CONSENSUS_CLIENT_ID};
+use crate::mocks::{
+	Host, MOCK_CONSENSUS_CLIENT_ID, MOCK_PROXY_CONSENSUS_CLIENT_ID, MOCK_SYNC_COMMITTEE_CLIENT_ID,
+};
 
 pub mod mocks;
+pub mod sync_committee;
 #[cfg(test)]
 mod tests;
 
@@ -46,6 +55,10 @@ fn mock_proxy_consensus_state_id() -> ConsensusStateId {
 	*b"prox"
 }
 
+fn mock_sync_committee_consensus_state_id() -> ConsensusStateId {
+	*b"sync"
+}
+
 fn setup_mock_client<H: IsmpHost>(host: &H) -> IntermediateState {
 	let intermediate_state = IntermediateState {
 		height: StateMachineHeight {
@@ -324,6 +337,67 @@ where
 	Ok(())
 }
 
+/// Ensure request batch timeouts are handled properly
+pub fn post_batch_timeout_check<H>(host: &H) -> Result<(), &'static str>
+where
+	H: IsmpHost + IsmpDispatcher,
+	H::Account: From<[u8; 32]>,
+	H::Balance: From<u32> + Default,
+{
+	let intermediate_state = setup_mock_client(host);
+	let challenge_period = host.challenge_period(intermediate_state.height.id).unwrap();
+	let previous_update_time = host.timestamp().saturating_sub(challenge_period * 2);
+	host.store_consensus_update_time(mock_consensus_state_id(), previous_update_time)
+		.unwrap();
+	host.store_state_machine_update_time(intermediate_state.height, previous_update_time)
+		.unwrap();
+	let items = vec![
+		BatchItem { to: vec![1u8; 32], body: vec![0u8; 64] },
+		BatchItem { to: vec![2u8; 32], body: vec![1u8; 64] },
+	];
+	let dispatch_batch = DispatchBatch {
+		dest: intermediate_state.height.id.state_id,
+		from: vec![0u8; 32],
+		timeout: intermediate_state.commitment.timestamp,
+		items: items.clone(),
+	};
+	let batch = PostBatch {
+		source: host.host_state_machine(),
+		dest: intermediate_state.height.id.state_id,
+		nonce: 0,
+		from: vec![0u8; 32],
+		timeout_timestamp: intermediate_state.commitment.timestamp,
+		items,
+	};
+	host.dispatch_request(
+		DispatchRequest::Batch(dispatch_batch),
+		FeeMetadata { payer: [0u8; 32].into(), fee: Default::default() },
+	)
+	.unwrap();
+
+	let timeout_message = Message::Timeout(TimeoutMessage::PostBatch {
+		batches: vec![batch.clone()],
+		timeout_proof: Proof { height: intermediate_state.height, proof: vec![] },
+	});
+
+	let res = handle_incoming_message(host, timeout_message.clone()).unwrap();
+	let MessageResult::Timeout(results) = res else { Err("Expected a timeout result")? };
+	let commitment = hash_batch::<H>(&batch);
+	match &results[..] {
+		[Ok(Event::PostBatchTimeoutHandled(handled))] if handled.commitment == commitment => {},
+		_ => Err("Batch timeout should be handled in a single result")?,
+	}
+
+	// Assert that the batch commitment was deleted, so the batch can't be timed out again
+	if host.request_commitment(commitment).is_ok() {
+		Err("Timed out batch commitment should be deleted")?
+	}
+	let res = handle_incoming_message(host, timeout_message).map_err(|e| e.downcast().unwrap());
+	assert!(matches!(res, Err(Error::UnknownRequest { .. })));
+
+	Ok(())
+}
+
 pub fn fraud_proof_checks<H>(host: &H)
 where
 	H: IsmpHost,
@@ -345,6 +419,57 @@ where
 	));
 }
 
+/// Ensure sync committee fraud proofs are verified before the consensus client is frozen
+pub fn sync_committee_fraud_proof_check<H>(host: &H) -> Result<(), &'static str>
+where
+	H: IsmpHost,
+{
+	let consensus_state_id = mock_sync_committee_consensus_state_id();
+	host.store_consensus_state(
+		consensus_state_id,
+		sync_committee::trusted_state::<Devnet>().encode(),
+	)
+	.unwrap();
+	host.store_consensus_state_id(consensus_state_id, MOCK_SYNC_COMMITTEE_CLIENT_ID)
+		.unwrap();
+
+	let update = sync_committee::signed_update::<Devnet>([1u8; 32]);
+	let conflicting_update = sync_committee::signed_update::<Devnet>([2u8; 32]);
+	let mut forged_update = conflicting_update.clone();
+	forged_update.sync_aggregate.sync_committee_bits.set(0, false);
+
+	let invalid_proofs = vec![
+		// identical headers do not conflict
+		(update.encode(), update.encode()),
+		// the conflicting header was not signed by the sync committee
+		(update.encode(), forged_update.encode()),
+		// garbage
+		(vec![], vec![]),
+	];
+	for (proof_1, proof_2) in invalid_proofs {
+		let fraud_proof = FraudProofMessage { proof_1, proof_2, consensus_state_id };
+		if handle_incoming_message(host, Message::FraudProof(fraud_proof)).is_ok() {
+			Err("Invalid fraud proof should not freeze the consensus client")?
+		}
+	}
+	host.is_consensus_client_frozen(consensus_state_id)
+		.map_err(|_| "Consensus client should not be frozen")?;
+
+	let fraud_proof = FraudProofMessage {
+		proof_1: update.encode(),
+		proof_2: conflicting_update.encode(),
+		consensus_state_id,
+	};
+	handle_incoming_message(host, Message::FraudProof(fraud_proof))
+		.map_err(|_| "Valid fraud proof should freeze the consensus client")?;
+
+	if host.is_consensus_client_frozen(consensus_state_id).is_ok() {
+		Err("Consensus client should be frozen")?
+	}
+
+	Ok(())
+}
+
 /// Ensure post request timeouts are handled properly
 pub fn post_response_timeout_check<H>(host: &H) -> Result<(), &'static str>
 where
@@ -401,6 +526,261 @@ where
 	Ok(())
 }
 
+/// Ensure messages in a batch are handled independently of each other
+pub fn batch_message_outcomes_check<H: IsmpHost>(host: &H) -> Result<(), &'static str> {
+	let intermediate_state = setup_mock_client(host);
+	let challenge_period = host.challenge_period(intermediate_state.height.id).unwrap();
+	let previous_update_time = host.timestamp() - (challenge_period * 2);
+	host.store_consensus_update_time(mock_consensus_state_id(), previous_update_time)
+		.unwrap();
+	host.store_state_machine_update_time(intermediate_state.height, previous_update_time)
+		.unwrap();
+
+	// a state commitment that is still within its challenge period
+	let recent_height = StateMachineHeight { height: 2, ..intermediate_state.height };
+	host.store_state_machine_commitment(recent_height, intermediate_state.commitment)
+		.unwrap();
+	host.store_state_machine_update_time(recent_height, host.timestamp()).unwrap();
+
+	let consensus_message = Message::Consensus(ConsensusMessage {
+		consensus_proof: vec![1u8; 32],
+		consensus_state_id: mock_consensus_state_id(),
+		signer: vec![],
+	});
+	let post = PostRequest {
+		source: intermediate_state.height.id.state_id,
+		dest: host.host_state_machine(),
+		nonce: 0,
+		from: vec![0u8; 32],
+		to: vec![0u8; 32],
+		timeout_timestamp: 0,
+		body: vec![0u8; 64],
+	};
+	let request_message = |post: PostRequest, height: StateMachineHeight| {
+		Message::Request(RequestMessage {
+			requests: vec![post],
+			proof: Proof { height, proof: vec![] },
+			signer: vec![],
+		})
+	};
+	let pending = PostRequest { nonce: 1, ..post.clone() };
+	let misrouted = PostRequest { nonce: 2, source: StateMachine::Kusama(13000), ..post.clone() };
+
+	let outcomes = handle_incoming_messages(
+		host,
+		vec![
+			consensus_message.clone(),
+			consensus_message,
+			request_message(post.clone(), intermediate_state.height),
+			request_message(post.clone(), intermediate_state.height),
+			request_message(pending.clone(), recent_height),
+			request_message(misrouted, intermediate_state.height),
+		],
+	);
+
+	let statuses = outcomes.iter().map(|outcome| outcome.status()).collect::<Vec<_>>();
+	assert_eq!(
+		statuses,
+		vec![
+			MessageStatus::Success,
+			MessageStatus::AlreadyProcessed,
+			MessageStatus::Success,
+			MessageStatus::AlreadyProcessed,
+			MessageStatus::ChallengePeriodNotElapsed,
+			MessageStatus::Failed,
+		]
+	);
+
+	// Failed messages do not affect the successful ones
+	if host.request_receipt(&Request::Post(post)).is_none() {
+		Err("Request in a successful message should be acknowledged")?
+	}
+	if host.request_receipt(&Request::Post(pending)).is_some() {
+		Err("Request in a failed message should not be acknowledged")?
+	}
+
+	// A duplicate request does not prevent the other requests in its message from being delivered
+	let fresh = PostRequest { nonce: 3, ..post.clone() };
+	let res = handle_incoming_message(
+		host,
+		Message::Request(RequestMessage {
+			requests: vec![post, fresh.clone()],
+			proof: Proof { height: intermediate_state.height, proof: vec![] },
+			signer: vec![],
+		}),
+	)
+	.map_err(|_| "Message with a single duplicate request should be handled")?;
+	let MessageResult::Request(results) = res else { Err("Expected a request result")? };
+	if results.len() != 1 || results.iter().any(|result| result.is_err()) {
+		Err("Only the undelivered request should be dispatched")?
+	}
+	if host.request_receipt(&Request::Post(fresh)).is_none() {
+		Err("Undelivered request should be acknowledged")?
+	}
+
+	Ok(())
+}
+
+/// Ensure request batches are delivered once and are committed to independently of their items
+pub fn request_batch_check<H: IsmpHost>(host: &H) -> Result<(), &'static str> {
+	let intermediate_state = setup_mock_client(host);
+	let challenge_period = host.challenge_period(intermediate_state.height.id).unwrap();
+	let previous_update_time = host.timestamp() - (challenge_period * 2);
+	host.store_consensus_update_time(mock_consensus_state_id(), previous_update_time)
+		.unwrap();
+	host.store_state_machine_update_time(intermediate_state.height, previous_update_time)
+		.unwrap();
+
+	let batch = PostBatch {
+		source: intermediate_state.height.id.state_id,
+		dest: host.host_state_machine(),
+		nonce: 0,
+		from: vec![0u8; 32],
+		timeout_timestamp: 0,
+		items: vec![
+			BatchItem { to: vec![1u8; 32], body: vec![0u8; 64] },
+			BatchItem { to: vec![2u8; 32], body: vec![1u8; 64] },
+		],
+	};
+	let batch_message = Message::Batch(BatchMessage {
+		batches: vec![batch.clone()],
+		proof: Proof { height: intermediate_state.height, proof: vec![] },
+		signer: vec![],
+	});
+
+	let res = handle_incoming_message(host, batch_message.clone()).unwrap();
+	let MessageResult::Request(results) = res else { Err("Expected a request result")? };
+	if results.len() != 1 || results.iter().any(|result| result.is_err()) {
+		Err("Batch should be delivered in a single result")?
+	}
+
+	if host.batch_receipt(&batch).is_none() {
+		Err("Delivered batch should be acknowledged")?
+	}
+
+	// The items of a batch are not acknowledged as individual requests
+	for request in batch.requests() {
+		if host.request_receipt(&Request::Post(request.clone())).is_some() {
+			Err("Batch items should not be acknowledged individually")?
+		}
+		if hash_request::<H>(&Request::Post(request)) == hash_batch::<H>(&batch) {
+			Err("Batch commitment should differ from the commitment of its items")?
+		}
+	}
+
+	let res = handle_incoming_message(host, batch_message).map_err(|e| e.downcast().unwrap());
+	assert!(matches!(res, Err(Error::DuplicateRequest { .. })));
+
+	Ok(())
+}
+
+/// Ensure requests on ordered channels are only delivered in nonce order
+pub fn ordered_channel_check<H: IsmpHost>(host: &H) -> Result<(), &'static str> {
+	let intermediate_state = setup_mock_client(host);
+	let challenge_period = host.challenge_period(intermediate_state.height.id).unwrap();
+	let previous_update_time = host.timestamp() - (challenge_period * 2);
+	host.store_consensus_update_time(mock_consensus_state_id(), previous_update_time)
+		.unwrap();
+	host.store_state_machine_update_time(intermediate_state.height, previous_update_time)
+		.unwrap();
+
+	let post = |nonce: u64, to: Vec<u8>| PostRequest {
+		source: intermediate_state.height.id.state_id,
+		dest: host.host_state_machine(),
+		nonce,
+		from: vec![0u8; 32],
+		to,
+		timeout_timestamp: 0,
+		body: vec![0u8; 64],
+	};
+	let request_message = |requests: Vec<PostRequest>| {
+		Message::Request(RequestMessage {
+			requests,
+			proof: Proof { height: intermediate_state.height, proof: vec![] },
+			signer: vec![],
+		})
+	};
+
+	let channel = ChannelId::from(&post(0, vec![1u8; 32]));
+	host.store_next_channel_nonce(&channel, 5).unwrap();
+
+	// Requests with a nonce below the next channel nonce are rejected
+	let res = handle_incoming_message(host, request_message(vec![post(4, vec![1u8; 32])]))
+		.map_err(|e| e.downcast().unwrap());
+	assert!(matches!(res, Err(Error::RequestOutOfOrder { expected: 5, .. })));
+
+	// Requests that skip a nonce are rejected, so no request on the channel can be stranded
+	let res = handle_incoming_message(
+		host,
+		request_message(vec![post(5, vec![1u8; 32]), post(8, vec![1u8; 32])]),
+	)
+	.map_err(|e| e.downcast().unwrap());
+	assert!(matches!(res, Err(Error::RequestOutOfOrder { expected: 6, .. })));
+
+	handle_incoming_message(host, request_message(vec![post(5, vec![1u8; 32])])).unwrap();
+	let res = handle_incoming_message(host, request_message(vec![post(8, vec![1u8; 32])]))
+		.map_err(|e| e.downcast().unwrap());
+	assert!(matches!(res, Err(Error::RequestOutOfOrder { expected: 6, .. })));
+	if host.next_channel_nonce(&channel) != Some(6) {
+		Err("Ordered channel nonce should not advance past a gap")?
+	}
+
+	// Consecutive requests in a single message are delivered in order
+	handle_incoming_message(
+		host,
+		request_message(vec![post(6, vec![1u8; 32]), post(7, vec![1u8; 32])]),
+	)
+	.unwrap();
+	if host.next_channel_nonce(&channel) != Some(8) {
+		Err("Ordered channel nonce should advance for every delivered request")?
+	}
+
+	// Requests within a message must also be in order
+	let res = handle_incoming_message(
+		host,
+		request_message(vec![post(9, vec![1u8; 32]), post(8, vec![1u8; 32])]),
+	)
+	.map_err(|e| e.downcast().unwrap());
+	assert!(matches!(res, Err(Error::RequestOutOfOrder { expected: 8, .. })));
+
+	// Batches follow the ordering of the channels of their items
+	let batch = |nonce: u64| PostBatch {
+		source: intermediate_state.height.id.state_id,
+		dest: host.host_state_machine(),
+		nonce,
+		from: vec![0u8; 32],
+		timeout_timestamp: 0,
+		items: vec![
+			BatchItem { to: vec![2u8; 32], body: vec![0u8; 64] },
+			BatchItem { to: vec![1u8; 32], body: vec![1u8; 64] },
+		],
+	};
+	let batch_message = |batches: Vec<PostBatch>| {
+		Message::Batch(BatchMessage {
+			batches,
+			proof: Proof { height: intermediate_state.height, proof: vec![] },
+			signer: vec![],
+		})
+	};
+	let res = handle_incoming_message(host, batch_message(vec![batch(9)]))
+		.map_err(|e| e.downcast().unwrap());
+	assert!(matches!(res, Err(Error::RequestOutOfOrder { expected: 8, .. })));
+
+	handle_incoming_message(host, batch_message(vec![batch(8)])).unwrap();
+	if host.next_channel_nonce(&channel) != Some(9) {
+		Err("Ordered channel nonce should advance for every delivered batch")?
+	}
+
+	// Unordered channels accept requests with any nonce
+	let unordered = post(7, vec![2u8; 32]);
+	handle_incoming_message(host, request_message(vec![unordered.clone()])).unwrap();
+	if host.next_channel_nonce(&ChannelId::from(&unordered)).is_some() {
+		Err("Unordered channels should not track nonces")?
+	}
+
+	Ok(())
+}
+
 /*
 	Check correctness of router implementation
 */
@@ -6,8 +6,12 @@ use std::{
 	time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use primitive_types::H256;
+use sync_committee_primitives::{
+	constants::devnet::Devnet,
+	types::{VerifierState, VerifierStateUpdate},
+};
 
 use ismp::{
 	consensus::{
@@ -17,11 +21,11 @@ use ismp::{
 	dispatcher::{DispatchRequest, FeeMetadata, IsmpDispatcher},
 	error::Error,
 	host::{IsmpHost, StateMachine},
-	messaging::{hash_post_response, hash_request, hash_response, Keccak256, Proof},
-	module::IsmpModule,
+	messaging::{hash_batch, hash_post_response, hash_request, hash_response, Keccak256, Proof},
+	module::{IsmpModule, ModuleError},
 	router::{
-		GetRequest, IsmpRouter, PostRequest, PostResponse, Request, RequestResponse, Response,
-		Timeout,
+		ChannelId, GetRequest, IsmpRouter, PostBatch, PostRequest, PostResponse, Request,
+		RequestResponse, Response, Timeout,
 	},
 };
 
@@ -29,9 +33,13 @@ use ismp::{
 pub struct MockClient;
 #[derive(Default)]
 pub struct MockProxyClient;
+/// Verifies fraud proofs with the sync committee verifier
+#[derive(Default)]
+pub struct MockSyncCommitteeClient;
 
 pub const MOCK_CONSENSUS_CLIENT_ID: [u8; 4] = [1u8; 4];
 pub const MOCK_PROXY_CONSENSUS_CLIENT_ID: [u8; 4] = [2u8; 4];
+pub const MOCK_SYNC_COMMITTEE_CLIENT_ID: [u8; 4] = [3u8; 4];
 
 #[derive(codec::Encode, codec::Decode)]
 pub struct MockConsensusState {
@@ -104,6 +112,44 @@ impl ConsensusClient for MockProxyClient {
 	}
 }
 
+impl ConsensusClient for MockSyncCommitteeClient {
+	fn verify_consensus(
+		&self,
+		_host: &dyn IsmpHost,
+		_consensus_state_id: ConsensusStateId,
+		_trusted_consensus_state: Vec<u8>,
+		_proof: Vec<u8>,
+	) -> Result<(Vec<u8>, VerifiedCommitments), Error> {
+		Err(Error::Custom("Consensus updates are not supported".to_string()))
+	}
+
+	fn verify_fraud_proof(
+		&self,
+		_host: &dyn IsmpHost,
+		trusted_consensus_state: Vec<u8>,
+		proof_1: Vec<u8>,
+		proof_2: Vec<u8>,
+	) -> Result<(), Error> {
+		let trusted_state = VerifierState::decode(&mut &trusted_consensus_state[..])
+			.map_err(|_| Error::Custom("Cannot decode trusted consensus state".to_string()))?;
+		let update_1 = VerifierStateUpdate::decode(&mut &proof_1[..])
+			.map_err(|_| Error::Custom("Cannot decode update for proof 1".to_string()))?;
+		let update_2 = VerifierStateUpdate::decode(&mut &proof_2[..])
+			.map_err(|_| Error::Custom("Cannot decode update for proof 2".to_string()))?;
+
+		sync_committee_verifier::verify_fraud_proof::<Devnet>(&trusted_state, update_1, update_2)
+			.map_err(|e| Error::Custom(e.to_string()))
+	}
+
+	fn consensus_client_id(&self) -> ConsensusClientId {
+		MOCK_SYNC_COMMITTEE_CLIENT_ID
+	}
+
+	fn state_machine(&self, _id: StateMachine) -> Result<Box<dyn StateMachineClient>, Error> {
+		Err(Error::Custom("Invalid state machine".to_string()))
+	}
+}
+
 pub struct MockStateMachineClient;
 
 impl StateMachineClient for MockStateMachineClient {
@@ -141,8 +187,10 @@ pub struct Host {
 	consensus_states: Rc<RefCell<HashMap<ConsensusStateId, Vec<u8>>>>,
 	state_commitments: Rc<RefCell<HashMap<StateMachineHeight, StateCommitment>>>,
 	consensus_update_time: Rc<RefCell<HashMap<ConsensusStateId, Duration>>>,
+	state_machine_update_time: Rc<RefCell<HashMap<StateMachineHeight, Duration>>>,
 	frozen_consensus_clients: Rc<RefCell<HashMap<ConsensusStateId, bool>>>,
 	latest_state_height: Rc<RefCell<HashMap<StateMachineId, u64>>>,
+	channel_nonces: Rc<RefCell<BTreeMap<ChannelId, u64>>>,
 	nonce: Rc<RefCell<u64>>,
 	pub proxy: Option<StateMachine>,
 }
@@ -183,6 +231,10 @@ impl IsmpHost for Host {
 		&self,
 		state_machine_height: StateMachineHeight,
 	) -> Result<Duration, Error> {
+		// fall back to the consensus update time for heights without an update time
+		if let Some(time) = self.state_machine_update_time.borrow().get(&state_machine_height) {
+			return Ok(*time);
+		}
 		self.consensus_update_time
 			.borrow()
 			.get(&state_machine_height.id.consensus_state_id)
@@ -251,6 +303,11 @@ impl IsmpHost for Host {
 		self.receipts.borrow().get(&hash).map(|_| ())
 	}
 
+	fn batch_receipt(&self, batch: &PostBatch) -> Option<()> {
+		let hash = hash_batch::<Self>(batch);
+		self.receipts.borrow().get(&hash).map(|_| ())
+	}
+
 	fn store_consensus_state_id(
 		&self,
 		consensus_state_id: ConsensusStateId,
@@ -284,9 +341,12 @@ impl IsmpHost for Host {
 
 	fn store_state_machine_update_time(
 		&self,
-		_state_machine_height: StateMachineHeight,
-		_timestamp: Duration,
+		state_machine_height: StateMachineHeight,
+		timestamp: Duration,
 	) -> Result<(), Error> {
+		self.state_machine_update_time
+			.borrow_mut()
+			.insert(state_machine_height, timestamp);
 		Ok(())
 	}
 
@@ -326,6 +386,12 @@ impl IsmpHost for Host {
 		Ok(val.encode())
 	}
 
+	fn delete_batch_commitment(&self, batch: &PostBatch) -> Result<Vec<u8>, Error> {
+		let hash = hash_batch::<Self>(batch);
+		let val = self.requests.borrow_mut().remove(&hash);
+		Ok(val.encode())
+	}
+
 	fn delete_request_receipt(&self, req: &Request) -> Result<Vec<u8>, Error> {
 		let hash = hash_request::<Self>(req);
 		let val = self.receipts.borrow_mut().remove(&hash);
@@ -338,12 +404,24 @@ impl IsmpHost for Host {
 		Ok(val.encode())
 	}
 
+	fn delete_batch_receipt(&self, batch: &PostBatch) -> Result<Vec<u8>, Error> {
+		let hash = hash_batch::<Self>(batch);
+		let val = self.receipts.borrow_mut().remove(&hash);
+		Ok(val.encode())
+	}
+
 	fn store_request_receipt(&self, req: &Request, _signer: &Vec<u8>) -> Result<(), Error> {
 		let hash = hash_request::<Self>(req);
 		self.receipts.borrow_mut().insert(hash, ());
 		Ok(())
 	}
 
+	fn store_batch_receipt(&self, batch: &PostBatch, _signer: &Vec<u8>) -> Result<(), Error> {
+		let hash = hash_batch::<Self>(batch);
+		self.receipts.borrow_mut().insert(hash, ());
+		Ok(())
+	}
+
 	fn store_response_receipt(&self, res: &Response, _signer: &Vec<u8>) -> Result<(), Error> {
 		let hash = hash_response::<Self>(res);
 		self.receipts.borrow_mut().insert(hash, ());
@@ -362,8 +440,14 @@ impl IsmpHost for Host {
 		Ok(())
 	}
 
+	fn store_batch_commitment(&self, batch: &PostBatch, _meta: Vec<u8>) -> Result<(), Error> {
+		let hash = hash_batch::<Self>(batch);
+		self.requests.borrow_mut().insert(hash);
+		Ok(())
+	}
+
 	fn consensus_clients(&self) -> Vec<Box<dyn ConsensusClient>> {
-		vec![Box::new(MockClient), Box::new(MockProxyClient)]
+		vec![Box::new(MockClient), Box::new(MockProxyClient), Box::new(MockSyncCommitteeClient)]
 	}
 
 	fn challenge_period(&self, _state_machine: StateMachineId) -> Option<Duration> {
@@ -389,6 +473,15 @@ impl IsmpHost for Host {
 	fn ismp_router(&self) -> Box<dyn IsmpRouter> {
 		Box::new(MockRouter(self.clone()))
 	}
+
+	fn next_channel_nonce(&self, channel: &ChannelId) -> Option<u64> {
+		self.channel_nonces.borrow().get(channel).copied()
+	}
+
+	fn store_next_channel_nonce(&self, channel: &ChannelId, nonce: u64) -> Result<(), Error> {
+		self.channel_nonces.borrow_mut().insert(channel.clone(), nonce);
+		Ok(())
+	}
 }
 
 impl Keccak256 for Host {
@@ -404,15 +497,15 @@ impl Keccak256 for Host {
 pub struct MockModule;
 
 impl IsmpModule for MockModule {
-	fn on_accept(&self, _request: PostRequest) -> Result<(), anyhow::Error> {
+	fn on_accept(&self, _request: PostRequest) -> Result<(), ModuleError> {
 		Ok(())
 	}
 
-	fn on_response(&self, _response: Response) -> Result<(), anyhow::Error> {
+	fn on_response(&self, _response: Response) -> Result<(), ModuleError> {
 		Ok(())
 	}
 
-	fn on_timeout(&self, _request: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, _request: Timeout) -> Result<(), ModuleError> {
 		Ok(())
 	}
 }
@@ -464,6 +557,19 @@ impl IsmpDispatcher for Host {
 				};
 				Request::Post(post)
 			},
+			DispatchRequest::Batch(dispatch_batch) => {
+				let batch = PostBatch {
+					source: host.host_state_machine(),
+					dest: dispatch_batch.dest,
+					nonce: host.next_nonce(),
+					from: dispatch_batch.from,
+					timeout_timestamp: dispatch_batch.timeout,
+					items: dispatch_batch.items,
+				};
+				let hash = hash_batch::<Host>(&batch);
+				host.requests.borrow_mut().insert(hash);
+				return Ok(hash);
+			},
 		};
 		let hash = hash_request::<Host>(&request);
 		host.requests.borrow_mut().insert(hash);
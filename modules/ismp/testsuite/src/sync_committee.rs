@@ -0,0 +1,174 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sync committee fixtures, signed by a deterministic mock sync committee.
+
+use bls::{point_to_pubkey, types::G1ProjectivePoint, DST_ETHEREUM};
+use ssz_rs::{Bitvector, Merkleized, Node, Vector};
+use sync_committee_primitives::{
+	consensus_types::{BeaconBlockHeader, Checkpoint, SyncAggregate, SyncCommittee},
+	constants::{
+		BlsPublicKey, Config, Root, DOMAIN_SYNC_COMMITTEE, FINALIZED_ROOT_INDEX,
+		FINALIZED_ROOT_INDEX_LOG2, SYNC_COMMITTEE_SIZE,
+	},
+	types::{FinalityProof, VerifierState, VerifierStateUpdate},
+	util::{
+		compute_domain, compute_epoch_at_slot, compute_fork_version, compute_signing_root,
+		compute_sync_committee_period_at_slot,
+	},
+};
+use sync_committee_verifier::crypto::pubkey_to_projective;
+
+/// Number of distinct keys in the mock sync committee, members are assigned keys round-robin.
+const DISTINCT_KEYS: usize = 4;
+
+/// Number of sync committee members that sign the fixture updates.
+const PARTICIPANTS: usize = 400;
+
+/// Slot of the finalized header in the trusted state.
+pub const TRUSTED_FINALIZED_SLOT: u64 = 100;
+
+fn secret_key_seed(index: usize) -> Vec<u8> {
+	vec![index as u8 + 1; 32]
+}
+
+fn sync_committee() -> SyncCommittee<SYNC_COMMITTEE_SIZE> {
+	let keys = (0..DISTINCT_KEYS)
+		.map(|index| {
+			let public_key = bls::sk_to_pk(bls::keygen(&secret_key_seed(index)));
+			BlsPublicKey::try_from(public_key.as_slice()).expect("Public key is valid")
+		})
+		.collect::<Vec<_>>();
+	let public_keys = (0..SYNC_COMMITTEE_SIZE)
+		.map(|index| keys[index % DISTINCT_KEYS].clone())
+		.collect::<Vec<_>>();
+
+	let aggregate = public_keys
+		.iter()
+		.map(|key| pubkey_to_projective(key).expect("Public key is valid"))
+		.fold(G1ProjectivePoint::default(), |acc, point| acc + point);
+
+	SyncCommittee {
+		public_keys: Vector::try_from(public_keys).expect("Sync committee has the right size"),
+		aggregate_public_key: BlsPublicKey::try_from(point_to_pubkey(aggregate.into()).as_slice())
+			.expect("Aggregate public key is valid"),
+	}
+}
+
+/// Computes the root of a merkle branch for a leaf at the given generalized index.
+fn merkle_root(leaf: Node, branch: &[Node], generalized_index: u64) -> Node {
+	branch.iter().enumerate().fold(leaf, |node, (depth, sibling)| {
+		let mut preimage = Vec::with_capacity(64);
+		if (generalized_index >> depth) & 1 == 1 {
+			preimage.extend_from_slice(sibling.as_bytes());
+			preimage.extend_from_slice(node.as_bytes());
+		} else {
+			preimage.extend_from_slice(node.as_bytes());
+			preimage.extend_from_slice(sibling.as_bytes());
+		}
+		Node::from_bytes(sp_core::hashing::sha2_256(&preimage))
+	})
+}
+
+/// The trusted light client state, the current and next sync committee are the mock committee.
+pub fn trusted_state<C: Config>() -> VerifierState {
+	let sync_committee = sync_committee();
+	VerifierState {
+		finalized_header: BeaconBlockHeader { slot: TRUSTED_FINALIZED_SLOT, ..Default::default() },
+		latest_finalized_epoch: compute_epoch_at_slot::<C>(TRUSTED_FINALIZED_SLOT),
+		current_sync_committee: sync_committee.clone(),
+		next_sync_committee: sync_committee,
+		state_period: compute_sync_committee_period_at_slot::<C>(TRUSTED_FINALIZED_SLOT),
+	}
+}
+
+/// An update signed by a super majority of the mock sync committee, finalizing a header with the
+/// given state root. Updates with different state roots conflict with each other.
+pub fn signed_update<C: Config>(finalized_state_root: [u8; 32]) -> VerifierStateUpdate {
+	let mut finalized_header = BeaconBlockHeader {
+		slot: TRUSTED_FINALIZED_SLOT + 10,
+		proposer_index: 1,
+		state_root: Node::from_bytes(finalized_state_root),
+		..Default::default()
+	};
+	let mut checkpoint = Checkpoint {
+		epoch: compute_epoch_at_slot::<C>(finalized_header.slot),
+		root: finalized_header.hash_tree_root().expect("Header can be hashed"),
+	};
+	let finality_branch = (0..FINALIZED_ROOT_INDEX_LOG2)
+		.map(|index| Node::from_bytes([index as u8 + 1; 32]))
+		.collect::<Vec<_>>();
+	let attested_state_root = merkle_root(
+		checkpoint.hash_tree_root().expect("Checkpoint can be hashed"),
+		&finality_branch,
+		FINALIZED_ROOT_INDEX,
+	);
+
+	let mut attested_header = BeaconBlockHeader {
+		slot: finalized_header.slot + 10,
+		proposer_index: 2,
+		state_root: attested_state_root,
+		..Default::default()
+	};
+	let signature_slot = attested_header.slot + 1;
+
+	let domain = compute_domain(
+		DOMAIN_SYNC_COMMITTEE,
+		Some(compute_fork_version::<C>(compute_epoch_at_slot::<C>(signature_slot))),
+		Some(Root::from_bytes(C::GENESIS_VALIDATORS_ROOT)),
+		C::GENESIS_FORK_VERSION,
+	)
+	.expect("Domain can be computed");
+	let signing_root =
+		compute_signing_root(&mut attested_header, domain).expect("Signing root can be computed");
+
+	let signatures = (0..DISTINCT_KEYS)
+		.map(|index| {
+			bls::sign(
+				bls::keygen(&secret_key_seed(index)),
+				&signing_root.as_bytes().to_vec(),
+				&DST_ETHEREUM.as_bytes().to_vec(),
+			)
+			.expect("Message can be signed")
+		})
+		.collect::<Vec<_>>();
+
+	let mut sync_committee_bits = Bitvector::<SYNC_COMMITTEE_SIZE>::default();
+	for index in 0..PARTICIPANTS {
+		sync_committee_bits.set(index, true);
+	}
+	let signature = bls::aggregate(
+		&(0..PARTICIPANTS)
+			.map(|index| signatures[index % DISTINCT_KEYS].clone())
+			.collect::<Vec<_>>(),
+	)
+	.expect("Signatures can be aggregated");
+
+	VerifierStateUpdate {
+		attested_header,
+		sync_committee_update: None,
+		finalized_header,
+		execution_payload: Default::default(),
+		finality_proof: FinalityProof { epoch: checkpoint.epoch, finality_branch },
+		sync_aggregate: SyncAggregate {
+			sync_committee_bits,
+			sync_committee_signature: signature
+				.as_slice()
+				.try_into()
+				.expect("Signature has the right size"),
+		},
+		signature_slot,
+	}
+}
@@ -3,12 +3,14 @@ use std::sync::Arc;
 use ismp::host::StateMachine;
 
 use crate::{
-	check_challenge_period, check_client_expiry, check_request_source_and_destination,
-	check_response_source, fraud_proof_checks, frozen_consensus_client_check,
-	missing_state_commitment_check, mocks::Host, post_request_timeout_check,
+	batch_message_outcomes_check, check_challenge_period, check_client_expiry,
+	check_request_source_and_destination, check_response_source, fraud_proof_checks,
+	frozen_consensus_client_check, missing_state_commitment_check, mocks::Host,
+	ordered_channel_check, post_batch_timeout_check, post_request_timeout_check,
 	post_response_timeout_check, prevent_request_processing_on_proxy_with_known_state_machine,
 	prevent_request_timeout_on_proxy_with_known_state_machine,
-	prevent_response_timeout_on_proxy_with_known_state_machine, write_outgoing_commitments,
+	prevent_response_timeout_on_proxy_with_known_state_machine, request_batch_check,
+	sync_committee_fraud_proof_check, write_outgoing_commitments,
 };
 
 #[test]
@@ -46,18 +48,48 @@ fn should_process_post_request_timeouts_correctly() {
 	post_request_timeout_check(&*host).unwrap()
 }
 
+#[test]
+fn should_process_post_batch_timeouts_correctly() {
+	let host = Arc::new(Host::default());
+	post_batch_timeout_check(&*host).unwrap()
+}
+
 #[test]
 fn should_process_post_response_timeouts_correctly() {
 	let host = Arc::new(Host::default());
 	post_response_timeout_check(&*host).unwrap()
 }
 
+#[test]
+fn should_handle_batched_messages_independently() {
+	let host = Host::default();
+	batch_message_outcomes_check(&host).unwrap()
+}
+
+#[test]
+fn should_deliver_request_batches_once() {
+	let host = Host::default();
+	request_batch_check(&host).unwrap()
+}
+
+#[test]
+fn should_deliver_ordered_channel_requests_in_order() {
+	let host = Host::default();
+	ordered_channel_check(&host).unwrap()
+}
+
 #[test]
 fn should_reject_duplicate_fraud_proofs() {
 	let host = Arc::new(Host::default());
 	fraud_proof_checks(&*host);
 }
 
+#[test]
+fn should_freeze_sync_committee_client_with_valid_fraud_proof() {
+	let host = Arc::new(Host::default());
+	sync_committee_fraud_proof_check(&*host).unwrap();
+}
+
 #[test]
 fn should_prevent_request_timeout_on_proxy_with_known_state_machine() {
 	let direct_conn_state_machine = StateMachine::Evm(11155111);
@@ -0,0 +1,394 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utilities for spreading rpc requests across multiple endpoints of the same chain.
+//!
+//! The [`EndpointPool`] keeps a health record for every endpoint. Requests are sent to the
+//! healthiest endpoint first and fail over to the others, failed endpoints are put on a cooldown
+//! and endpoints that fall behind the others on block height are only used as a last resort.
+
+#![deny(missing_docs)]
+
+use anyhow::anyhow;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+	future::Future,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Weight of the latest sample in the moving averages of latency and error rate
+const SMOOTHING_FACTOR: f64 = 0.2;
+/// Cooldown of an endpoint after its first consecutive failure, this doubles with every
+/// subsequent failure.
+const BASE_COOLDOWN: Duration = Duration::from_secs(5);
+/// Maximum cooldown of a failing endpoint
+const MAX_COOLDOWN: Duration = Duration::from_secs(300);
+
+/// How the healthy endpoints of a pool are ordered for each request
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Strategy {
+	/// Prefer the endpoint with the lowest latency and error rate
+	#[default]
+	Scored,
+	/// Use the endpoints in the order they were configured, falling over to the next one
+	Failover,
+	/// Rotate the first endpoint on every request
+	RoundRobin,
+}
+
+/// Configuration for an [`EndpointPool`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolConfig {
+	/// How endpoints are chosen, defaults to [`Strategy::Scored`]
+	pub strategy: Option<Strategy>,
+	/// Number of times a request is retried after it failed on every endpoint, defaults to 3
+	pub max_retries: Option<u32>,
+	/// Backoff in milliseconds before the first retry, this doubles with every retry up to
+	/// `max_backoff`. Defaults to 500ms
+	pub initial_backoff: Option<u64>,
+	/// Maximum backoff in milliseconds between retries, defaults to 10s
+	pub max_backoff: Option<u64>,
+	/// Number of blocks an endpoint can fall behind the most up to date endpoint before it is
+	/// considered unhealthy, defaults to 10
+	pub max_block_lag: Option<u64>,
+	/// Interval in seconds at which the block height of every endpoint is checked, defaults to
+	/// 30s
+	pub health_check_interval: Option<u64>,
+}
+
+impl PoolConfig {
+	fn strategy(&self) -> Strategy {
+		self.strategy.unwrap_or_default()
+	}
+
+	fn max_retries(&self) -> u32 {
+		self.max_retries.unwrap_or(3)
+	}
+
+	fn initial_backoff(&self) -> Duration {
+		Duration::from_millis(self.initial_backoff.unwrap_or(500))
+	}
+
+	fn max_backoff(&self) -> Duration {
+		Duration::from_millis(self.max_backoff.unwrap_or(10_000))
+	}
+
+	fn max_block_lag(&self) -> u64 {
+		self.max_block_lag.unwrap_or(10)
+	}
+
+	/// Interval at which the block height of every endpoint is checked
+	pub fn health_check_interval(&self) -> Duration {
+		Duration::from_secs(self.health_check_interval.unwrap_or(30))
+	}
+}
+
+/// How a failed request should be handled by the pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+	/// The endpoint failed to serve the request, e.g a network error, a timeout or a rate limit.
+	/// The endpoint is penalized and the request is retried on the other endpoints.
+	Transient,
+	/// The endpoint is healthy but could not serve the request, e.g it has not synced the
+	/// requested block yet. The request is tried on the other endpoints but is not retried.
+	Unavailable,
+	/// The request itself is invalid, e.g a reverted call. The error is returned as is.
+	Fatal,
+}
+
+/// Health record of an endpoint
+#[derive(Debug, Clone, Default)]
+struct Health {
+	/// Moving average of the latency in milliseconds
+	latency: Option<f64>,
+	/// Moving average of the ratio of failed requests
+	error_rate: f64,
+	/// Number of requests that failed in a row
+	consecutive_failures: u32,
+	/// The endpoint is not used until this instant unless every endpoint is unhealthy
+	cooldown: Option<Instant>,
+	/// Latest block height reported by the endpoint
+	height: Option<u64>,
+}
+
+impl Health {
+	fn score(&self) -> f64 {
+		self.latency.unwrap_or_default() * (1.0 + 10.0 * self.error_rate)
+	}
+}
+
+/// The health of an endpoint at a point in time
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointStatus {
+	/// Url of the endpoint
+	pub url: String,
+	/// Moving average of the latency in milliseconds
+	pub latency: Option<f64>,
+	/// Moving average of the ratio of failed requests
+	pub error_rate: f64,
+	/// Latest block height reported by the endpoint
+	pub height: Option<u64>,
+	/// Whether the endpoint is currently preferred over unhealthy endpoints
+	pub healthy: bool,
+}
+
+/// A set of endpoints serving the same chain
+#[derive(Debug)]
+pub struct EndpointPool {
+	/// Endpoint urls
+	urls: Vec<String>,
+	/// Health record of each endpoint
+	health: Vec<Mutex<Health>>,
+	/// Pool configuration
+	config: PoolConfig,
+	/// Counter used to rotate endpoints
+	next: AtomicUsize,
+}
+
+impl EndpointPool {
+	/// Create a pool over the given endpoints
+	pub fn new(urls: Vec<String>, config: PoolConfig) -> Result<Self, anyhow::Error> {
+		if urls.is_empty() {
+			Err(anyhow!("At least one rpc endpoint is required"))?
+		}
+
+		Ok(Self {
+			health: urls.iter().map(|_| Mutex::new(Health::default())).collect(),
+			urls,
+			config,
+			next: AtomicUsize::new(0),
+		})
+	}
+
+	/// Endpoint urls in the order they were configured
+	pub fn urls(&self) -> &[String] {
+		&self.urls
+	}
+
+	/// Pool configuration
+	pub fn config(&self) -> &PoolConfig {
+		&self.config
+	}
+
+	/// The health of every endpoint
+	pub fn status(&self) -> Vec<EndpointStatus> {
+		let now = Instant::now();
+		let best = self.best_height();
+		self.urls
+			.iter()
+			.zip(&self.health)
+			.map(|(url, health)| {
+				let health = health.lock();
+				EndpointStatus {
+					url: url.clone(),
+					latency: health.latency,
+					error_rate: health.error_rate,
+					height: health.height,
+					healthy: self.is_healthy(&health, now, best),
+				}
+			})
+			.collect()
+	}
+
+	/// Indices of the endpoints in the order they should be tried. Healthy endpoints are ordered
+	/// by the configured strategy, unhealthy endpoints are only tried after all healthy ones.
+	pub fn ordered(&self) -> Vec<usize> {
+		let now = Instant::now();
+		let best = self.best_height();
+		let health = self.health.iter().map(|health| health.lock().clone()).collect::<Vec<_>>();
+		let (mut healthy, mut unhealthy): (Vec<_>, Vec<_>) =
+			(0..self.urls.len()).partition(|index| self.is_healthy(&health[*index], now, best));
+
+		match self.config.strategy() {
+			Strategy::Scored =>
+				healthy.sort_by(|a, b| health[*a].score().total_cmp(&health[*b].score())),
+			Strategy::Failover => {},
+			Strategy::RoundRobin =>
+				if !healthy.is_empty() {
+					let offset = self.next.fetch_add(1, Ordering::Relaxed) % healthy.len();
+					healthy.rotate_left(offset);
+				},
+		}
+		unhealthy.sort_by(|a, b| health[*a].score().total_cmp(&health[*b].score()));
+		healthy.extend(unhealthy);
+
+		healthy
+	}
+
+	/// Record a request that was served by the endpoint
+	pub fn record_success(&self, index: usize, latency: Duration) {
+		let mut health = self.health[index].lock();
+		let latency = latency.as_secs_f64() * 1000.0;
+		health.latency = Some(match health.latency {
+			Some(average) => average + SMOOTHING_FACTOR * (latency - average),
+			None => latency,
+		});
+		health.error_rate -= SMOOTHING_FACTOR * health.error_rate;
+		health.consecutive_failures = 0;
+		health.cooldown = None;
+	}
+
+	/// Record a request that the endpoint failed to serve, the endpoint is put on a cooldown
+	/// that grows with the number of consecutive failures.
+	pub fn record_failure(&self, index: usize) {
+		let mut health = self.health[index].lock();
+		health.error_rate += SMOOTHING_FACTOR * (1.0 - health.error_rate);
+		health.consecutive_failures = health.consecutive_failures.saturating_add(1);
+		let cooldown = BASE_COOLDOWN
+			.saturating_mul(2u32.saturating_pow(health.consecutive_failures - 1))
+			.min(MAX_COOLDOWN);
+		health.cooldown = Some(Instant::now() + cooldown);
+	}
+
+	/// Record the latest block height of the endpoint
+	pub fn record_height(&self, index: usize, height: u64) {
+		self.health[index].lock().height = Some(height);
+	}
+
+	/// Run a request against the pool. `request` is called with the index of the endpoint that
+	/// should serve the request and `classify` decides how its errors are handled, see
+	/// [`ErrorKind`]. When every endpoint fails with a transient error the request is retried
+	/// with an exponential backoff.
+	pub async fn execute<T, E, F, Fut>(
+		&self,
+		request: F,
+		classify: impl Fn(&E) -> ErrorKind,
+	) -> Result<T, E>
+	where
+		F: Fn(usize) -> Fut,
+		Fut: Future<Output = Result<T, E>>,
+	{
+		let mut backoff = self.config.initial_backoff();
+		let mut retries = 0;
+		loop {
+			let mut last_error = None;
+			let mut transient = false;
+			for index in self.ordered() {
+				let start = Instant::now();
+				match request(index).await {
+					Ok(response) => {
+						self.record_success(index, start.elapsed());
+						return Ok(response)
+					},
+					Err(err) => match classify(&err) {
+						ErrorKind::Fatal => {
+							self.record_success(index, start.elapsed());
+							return Err(err)
+						},
+						ErrorKind::Unavailable => {
+							self.record_success(index, start.elapsed());
+							last_error = Some(err);
+						},
+						ErrorKind::Transient => {
+							log::trace!(
+								"Request to {} failed, trying the next endpoint",
+								self.urls[index]
+							);
+							self.record_failure(index);
+							transient = true;
+							last_error = Some(err);
+						},
+					},
+				}
+			}
+
+			let err = last_error.expect("Pool has at least one endpoint; qed");
+			if !transient || retries >= self.config.max_retries() {
+				return Err(err)
+			}
+			retries += 1;
+			log::trace!("Request failed on all endpoints, retrying in {backoff:?}");
+			tokio::time::sleep(backoff).await;
+			backoff = (backoff * 2).min(self.config.max_backoff());
+		}
+	}
+
+	/// Run a request on the best endpoint only. Requests that must not be repeated, such as
+	/// submitting a transaction, may have reached an endpoint that failed to respond, so they are
+	/// never retried on the other endpoints.
+	pub async fn execute_once<T, E, F, Fut>(
+		&self,
+		request: F,
+		classify: impl Fn(&E) -> ErrorKind,
+	) -> Result<T, E>
+	where
+		F: FnOnce(usize) -> Fut,
+		Fut: Future<Output = Result<T, E>>,
+	{
+		let index = self.ordered()[0];
+		let start = Instant::now();
+		let result = request(index).await;
+		match &result {
+			Err(err) if matches!(classify(err), ErrorKind::Transient) => self.record_failure(index),
+			_ => self.record_success(index, start.elapsed()),
+		}
+
+		result
+	}
+
+	/// Periodically query the block height of every endpoint with `fetch_height`, endpoints that
+	/// fail the check are penalized. The task stops once the pool is dropped.
+	pub fn spawn_health_check<F, Fut>(self: &Arc<Self>, fetch_height: F)
+	where
+		F: Fn(usize) -> Fut + Send + 'static,
+		Fut: Future<Output = Result<u64, anyhow::Error>> + Send,
+	{
+		let pool = Arc::downgrade(self);
+		let interval = self.config.health_check_interval();
+		tokio::spawn(async move {
+			let mut interval = tokio::time::interval(interval);
+			loop {
+				interval.tick().await;
+				let Some(pool) = pool.upgrade() else { return };
+				for index in 0..pool.urls.len() {
+					let start = Instant::now();
+					match fetch_height(index).await {
+						Ok(height) => {
+							pool.record_success(index, start.elapsed());
+							pool.record_height(index, height);
+						},
+						Err(err) => {
+							log::trace!("Health check for {} failed: {err:?}", pool.urls[index]);
+							pool.record_failure(index);
+						},
+					}
+				}
+			}
+		});
+	}
+
+	fn best_height(&self) -> Option<u64> {
+		self.health.iter().filter_map(|health| health.lock().height).max()
+	}
+
+	fn is_healthy(&self, health: &Health, now: Instant, best_height: Option<u64>) -> bool {
+		let cooling_down = health.cooldown.map(|cooldown| cooldown > now).unwrap_or(false);
+		let lagging = match (health.height, best_height) {
+			(Some(height), Some(best)) => best.saturating_sub(height) > self.config.max_block_lag(),
+			_ => false,
+		};
+
+		!cooling_down && !lagging
+	}
+}
@@ -0,0 +1,191 @@
+use crate::{EndpointPool, ErrorKind, PoolConfig, Strategy};
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+fn pool(strategy: Strategy) -> EndpointPool {
+	let urls = vec!["http://a".to_string(), "http://b".to_string(), "http://c".to_string()];
+	let config = PoolConfig {
+		strategy: Some(strategy),
+		initial_backoff: Some(1),
+		max_backoff: Some(4),
+		max_block_lag: Some(5),
+		..Default::default()
+	};
+	EndpointPool::new(urls, config).unwrap()
+}
+
+#[test]
+fn should_require_an_endpoint() {
+	assert!(EndpointPool::new(vec![], Default::default()).is_err());
+}
+
+#[test]
+fn should_prefer_endpoints_with_a_better_score() {
+	let pool = pool(Strategy::Scored);
+	pool.record_success(0, Duration::from_millis(300));
+	pool.record_success(1, Duration::from_millis(100));
+	pool.record_success(2, Duration::from_millis(200));
+	assert_eq!(pool.ordered(), vec![1, 2, 0]);
+}
+
+#[test]
+fn should_move_failed_endpoints_to_the_back() {
+	let pool = pool(Strategy::Failover);
+	assert_eq!(pool.ordered(), vec![0, 1, 2]);
+
+	pool.record_failure(0);
+	assert_eq!(pool.ordered(), vec![1, 2, 0]);
+	assert!(!pool.status()[0].healthy);
+
+	pool.record_success(0, Duration::from_millis(10));
+	assert_eq!(pool.ordered(), vec![0, 1, 2]);
+}
+
+#[test]
+fn should_move_lagging_endpoints_to_the_back() {
+	let pool = pool(Strategy::Failover);
+	pool.record_height(0, 100);
+	pool.record_height(1, 110);
+	pool.record_height(2, 108);
+	assert_eq!(pool.ordered(), vec![1, 2, 0]);
+
+	pool.record_height(0, 106);
+	assert_eq!(pool.ordered(), vec![0, 1, 2]);
+}
+
+#[test]
+fn should_rotate_endpoints() {
+	let pool = pool(Strategy::RoundRobin);
+	assert_eq!(pool.ordered(), vec![0, 1, 2]);
+	assert_eq!(pool.ordered(), vec![1, 2, 0]);
+	assert_eq!(pool.ordered(), vec![2, 0, 1]);
+	assert_eq!(pool.ordered(), vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn should_fail_over_to_the_next_endpoint() {
+	let pool = pool(Strategy::Failover);
+	let result = pool
+		.execute(
+			|index| async move {
+				if index == 0 {
+					Err("connection refused")
+				} else {
+					Ok(index)
+				}
+			},
+			|_| ErrorKind::Transient,
+		)
+		.await;
+	assert_eq!(result, Ok(1));
+	assert_eq!(pool.ordered(), vec![1, 2, 0]);
+}
+
+#[tokio::test]
+async fn should_retry_transient_errors_with_backoff() {
+	let pool = pool(Strategy::Failover);
+	let attempts = Arc::new(AtomicUsize::new(0));
+	let result = pool
+		.execute(
+			|_| {
+				let attempts = attempts.clone();
+				async move {
+					// every endpoint fails during the first round
+					if attempts.fetch_add(1, Ordering::SeqCst) < 3 {
+						Err("rate limited")
+					} else {
+						Ok(())
+					}
+				}
+			},
+			|_| ErrorKind::Transient,
+		)
+		.await;
+	assert_eq!(result, Ok(()));
+	assert_eq!(attempts.load(Ordering::SeqCst), 4);
+
+	// gives up after the configured number of retries
+	let attempts = Arc::new(AtomicUsize::new(0));
+	let result = pool
+		.execute(
+			|_| {
+				let attempts = attempts.clone();
+				async move {
+					attempts.fetch_add(1, Ordering::SeqCst);
+					Err::<(), _>("rate limited")
+				}
+			},
+			|_| ErrorKind::Transient,
+		)
+		.await;
+	assert_eq!(result, Err("rate limited"));
+	assert_eq!(attempts.load(Ordering::SeqCst), 12);
+}
+
+#[tokio::test]
+async fn should_not_retry_fatal_or_unavailable_errors() {
+	let pool = pool(Strategy::Failover);
+	let attempts = Arc::new(AtomicUsize::new(0));
+	let result = pool
+		.execute(
+			|_| {
+				let attempts = attempts.clone();
+				async move {
+					attempts.fetch_add(1, Ordering::SeqCst);
+					Err::<(), _>("execution reverted")
+				}
+			},
+			|_| ErrorKind::Fatal,
+		)
+		.await;
+	assert_eq!(result, Err("execution reverted"));
+	assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+	let attempts = Arc::new(AtomicUsize::new(0));
+	let result = pool
+		.execute(
+			|_| {
+				let attempts = attempts.clone();
+				async move {
+					attempts.fetch_add(1, Ordering::SeqCst);
+					Err::<(), _>("not found")
+				}
+			},
+			|_| ErrorKind::Unavailable,
+		)
+		.await;
+	assert_eq!(result, Err("not found"));
+	assert_eq!(attempts.load(Ordering::SeqCst), 3);
+	// endpoints that could not serve the request are not penalized
+	assert!(pool.status().iter().all(|status| status.healthy));
+}
+
+#[tokio::test]
+async fn should_not_fail_over_requests_that_run_once() {
+	let pool = pool(Strategy::Failover);
+	let attempts = Arc::new(AtomicUsize::new(0));
+	let result = pool
+		.execute_once(
+			|index| {
+				let attempts = attempts.clone();
+				async move {
+					attempts.fetch_add(1, Ordering::SeqCst);
+					Err::<usize, _>(format!("connection to {index} reset"))
+				}
+			},
+			|_| ErrorKind::Transient,
+		)
+		.await;
+	assert_eq!(result, Err("connection to 0 reset".to_string()));
+	assert_eq!(attempts.load(Ordering::SeqCst), 1);
+	// the failed endpoint is still penalized
+	assert_eq!(pool.ordered(), vec![1, 2, 0]);
+
+	let result = pool.execute_once(|index| async move { Ok::<_, ()>(index) }, |_| ErrorKind::Fatal);
+	assert_eq!(result.await, Ok(1));
+}
@@ -200,14 +200,16 @@ pub mod as_string {
 	}
 
 	/// Deserialize from string
-	pub fn deserialize<'de, D, T: FromStr>(deserializer: D) -> Result<T, D::Error>
+	pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 	where
 		D: serde::Deserializer<'de>,
+		T: FromStr,
+		T::Err: fmt::Display,
 	{
 		let s: String = <String>::deserialize(deserializer)?;
-		let inner: T = s
-			.parse()
-			.map_err(|_| serde::de::Error::custom("failure to parse string data"))?;
+		let inner: T = s.parse().map_err(|err| {
+			serde::de::Error::custom(format!("failure to parse string data: {err}"))
+		})?;
 		Ok(inner)
 	}
 }
@@ -1,7 +1,9 @@
 use anyhow::Context;
 use futures::{StreamExt, TryStreamExt};
 pub use reconnecting_jsonrpsee_ws_client::Error as RpcError;
-use reconnecting_jsonrpsee_ws_client::{Client, FixedInterval, SubscriptionId};
+use reconnecting_jsonrpsee_ws_client::{Client, FixedInterval, Subscription, SubscriptionId};
+#[cfg(feature = "std")]
+use rpc_utils::{EndpointPool, ErrorKind, PoolConfig};
 use std::{ops::Deref, sync::Arc, time::Duration};
 use subxt::{
 	rpc::{RawValue, RpcClientT, RpcFuture, RpcSubscription},
@@ -14,6 +16,47 @@ pub async fn ws_client<T: subxt::Config>(
 	rpc_ws: &str,
 	max_rpc_payload_size: u32,
 ) -> Result<OnlineClient<T>, anyhow::Error> {
+	let raw_client = connect(rpc_ws, max_rpc_payload_size).await?;
+	let client = OnlineClient::<T>::from_rpc_client(Arc::new(ClientWrapper(raw_client)))
+		.await
+		.context("Failed to query from substrate rpc: {rpc_ws}")?;
+
+	Ok(client)
+}
+
+#[cfg(feature = "std")]
+/// Create a client that spreads requests across multiple websocket endpoints of the same chain,
+/// endpoints that can't be reached on startup are skipped. The health records of the endpoints
+/// are returned along with the client.
+pub async fn ws_client_with_failover<T: subxt::Config>(
+	rpc_ws: Vec<String>,
+	max_rpc_payload_size: u32,
+	config: PoolConfig,
+) -> Result<(OnlineClient<T>, Arc<EndpointPool>), anyhow::Error> {
+	let mut urls = vec![];
+	let mut clients = vec![];
+	for url in rpc_ws {
+		match connect(&url, max_rpc_payload_size).await {
+			Ok(client) => {
+				urls.push(url);
+				clients.push(client);
+			},
+			Err(err) => log::warn!("Skipping substrate rpc endpoint: {err:?}"),
+		}
+	}
+	let client = FailoverClient::new(urls, clients, config)
+		.context("Failed to connect to any substrate rpc endpoint")?;
+	client.spawn_health_check();
+	let pool = client.pool();
+	let client = OnlineClient::<T>::from_rpc_client(Arc::new(client))
+		.await
+		.context("Failed to query from substrate rpc")?;
+
+	Ok((client, pool))
+}
+
+#[cfg(feature = "std")]
+async fn connect(rpc_ws: &str, max_rpc_payload_size: u32) -> Result<Client, anyhow::Error> {
 	let raw_client = Client::builder()
 		// retry every second
 		.retry_policy(FixedInterval::new(Duration::from_secs(1)))
@@ -27,11 +70,8 @@ pub async fn ws_client<T: subxt::Config>(
 		.build(rpc_ws.to_owned())
 		.await
 		.context(format!("Failed to connect to substrate rpc {rpc_ws}"))?;
-	let client = OnlineClient::<T>::from_rpc_client(Arc::new(ClientWrapper(raw_client)))
-		.await
-		.context("Failed to query from substrate rpc: {rpc_ws}")?;
 
-	Ok(client)
+	Ok(raw_client)
 }
 
 #[cfg(feature = "wasm")]
@@ -94,14 +134,226 @@ impl RpcClientT for ClientWrapper {
 				.await
 				.map_err(|e| subxt::error::RpcError::ClientError(Box::new(e)))?;
 
-			let id = match stream.id() {
-				SubscriptionId::Str(id) => Some(id.clone().into_owned()),
-				SubscriptionId::Num(id) => Some(id.to_string()),
+			Ok(into_subscription(stream))
+		})
+	}
+}
+
+fn into_subscription_id(id: &SubscriptionId) -> Option<String> {
+	match id {
+		SubscriptionId::Str(id) => Some(id.clone().into_owned()),
+		SubscriptionId::Num(id) => Some(id.to_string()),
+	}
+}
+
+fn into_subscription(stream: Subscription) -> RpcSubscription {
+	let id = into_subscription_id(stream.id());
+	let stream = stream.map_err(|e| subxt::error::RpcError::ClientError(Box::new(e))).boxed();
+	RpcSubscription { stream, id }
+}
+
+/// An rpc client that fails over between multiple websocket endpoints of the same chain, see
+/// [`EndpointPool`].
+#[cfg(feature = "std")]
+pub struct FailoverClient {
+	/// Health records of the endpoints
+	pool: Arc<EndpointPool>,
+	/// Client for each endpoint
+	clients: Arc<Vec<Client>>,
+}
+
+#[cfg(feature = "std")]
+impl FailoverClient {
+	/// Create a client over already connected endpoints
+	pub fn new(
+		urls: Vec<String>,
+		clients: Vec<Client>,
+		config: PoolConfig,
+	) -> Result<Self, anyhow::Error> {
+		let pool = Arc::new(EndpointPool::new(urls, config)?);
+		Ok(Self { pool, clients: Arc::new(clients) })
+	}
+
+	/// The endpoints of this client
+	pub fn pool(&self) -> Arc<EndpointPool> {
+		self.pool.clone()
+	}
+
+	/// Periodically check the best block of every endpoint so that endpoints lagging behind the
+	/// others are avoided.
+	pub fn spawn_health_check(&self) {
+		let clients = self.clients.clone();
+		self.pool.spawn_health_check(move |index| {
+			let clients = clients.clone();
+			async move {
+				let header =
+					clients[index].request_raw("chain_getHeader".to_string(), None).await?;
+				let header = serde_json::from_str::<serde_json::Value>(header.get())?;
+				let number = header["number"]
+					.as_str()
+					.ok_or_else(|| anyhow::anyhow!("Invalid header response"))?;
+				Ok(u64::from_str_radix(number.trim_start_matches("0x"), 16)?)
+			}
+		});
+	}
+}
+
+/// Errors returned by substrate nodes that have not yet imported, or have already pruned, the
+/// block a call was made at. Another endpoint may still be able to serve the call.
+#[cfg(feature = "std")]
+const UNAVAILABLE_BLOCK_ERRORS: [&str; 4] =
+	["unknown block", "unknownblock", "state already discarded", "header was not found"];
+
+#[cfg(feature = "std")]
+fn classify(err: &RpcError) -> ErrorKind {
+	match err {
+		// The node returned an error for this call
+		RpcError::RpcError(jsonrpsee_core::ClientError::Call(err)) => {
+			let data = err.data().map(|data| data.get()).unwrap_or_default();
+			let details = format!("{} {data}", err.message()).to_lowercase();
+			if UNAVAILABLE_BLOCK_ERRORS.iter().any(|msg| details.contains(msg)) {
+				ErrorKind::Unavailable
+			} else {
+				ErrorKind::Fatal
+			}
+		},
+		_ => ErrorKind::Transient,
+	}
+}
+
+/// A subscription that moves to the next healthy endpoint whenever the stream of its current
+/// endpoint fails or ends.
+#[cfg(feature = "std")]
+struct FailoverSubscription {
+	/// Health records of the endpoints
+	pool: Arc<EndpointPool>,
+	/// Client for each endpoint
+	clients: Arc<Vec<Client>>,
+	sub: String,
+	params: Option<Box<RawValue>>,
+	unsub: String,
+	/// Index of the endpoint and the stream of the active subscription
+	current: Option<(usize, Subscription)>,
+	/// Consecutive subscriptions that ended before yielding an item
+	failures: usize,
+}
+
+#[cfg(feature = "std")]
+impl FailoverSubscription {
+	/// Subscribe on the first endpoint, in order of health, that accepts the subscription
+	async fn subscribe(&self) -> Result<(usize, Subscription), RpcError> {
+		let mut last_error = None;
+		for index in self.pool.ordered() {
+			match self.clients[index]
+				.subscribe_raw(self.sub.clone(), self.params.clone(), self.unsub.clone())
+				.await
+			{
+				Ok(stream) => return Ok((index, stream)),
+				Err(err) => {
+					match classify(&err) {
+						ErrorKind::Fatal => return Err(err),
+						ErrorKind::Unavailable => {},
+						ErrorKind::Transient => self.pool.record_failure(index),
+					}
+					last_error = Some(err);
+				},
+			}
+		}
+
+		Err(last_error.expect("Pool has at least one endpoint; qed"))
+	}
+
+	/// Stream the items of the subscription, resubscribing on another endpoint when the current
+	/// one fails. The stream ends with an error once every endpoint has failed in a row.
+	fn into_stream(
+		self,
+	) -> futures::stream::BoxStream<'static, Result<Box<RawValue>, subxt::error::RpcError>> {
+		futures::stream::unfold(Some(self), |state| async move {
+			let mut state = state?;
+			loop {
+				let (index, mut stream) = match state.current.take() {
+					Some(current) => current,
+					None => match state.subscribe().await {
+						Ok(current) => current,
+						Err(err) =>
+							return Some((
+								Err(subxt::error::RpcError::ClientError(Box::new(err))),
+								None,
+							)),
+					},
+				};
+
+				if let Some(Ok(item)) = stream.next().await {
+					state.failures = 0;
+					state.current = Some((index, stream));
+					return Some((Ok(item), Some(state)));
+				}
+
+				log::warn!(
+					"Subscription {} failed on {}, resubscribing",
+					state.sub,
+					state.pool.urls()[index]
+				);
+				state.pool.record_failure(index);
+				state.failures += 1;
+				if state.failures > state.clients.len() {
+					let err =
+						anyhow::anyhow!("Subscription {} failed on every endpoint", state.sub);
+					return Some((Err(subxt::error::RpcError::ClientError(err.into())), None));
+				}
+			}
+		})
+		.boxed()
+	}
+}
+
+#[cfg(feature = "std")]
+impl RpcClientT for FailoverClient {
+	fn request_raw<'a>(
+		&'a self,
+		method: &'a str,
+		params: Option<Box<RawValue>>,
+	) -> RpcFuture<'a, Box<RawValue>> {
+		Box::pin(async move {
+			let res = self
+				.pool
+				.execute(
+					move |index| {
+						self.clients[index].request_raw(method.to_string(), params.clone())
+					},
+					classify,
+				)
+				.await
+				.map_err(|e| subxt::error::RpcError::ClientError(Box::new(e)))?;
+			Ok(res)
+		})
+	}
+
+	fn subscribe_raw<'a>(
+		&'a self,
+		sub: &'a str,
+		params: Option<Box<RawValue>>,
+		unsub: &'a str,
+	) -> RpcFuture<'a, RpcSubscription> {
+		Box::pin(async move {
+			let mut subscription = FailoverSubscription {
+				pool: self.pool.clone(),
+				clients: self.clients.clone(),
+				sub: sub.to_string(),
+				params,
+				unsub: unsub.to_string(),
+				current: None,
+				failures: 0,
 			};
+			let (index, stream) = subscription
+				.subscribe()
+				.await
+				.map_err(|e| subxt::error::RpcError::ClientError(Box::new(e)))?;
+			// the id of the first subscription, later subscriptions are only used internally
+			let id = into_subscription_id(stream.id());
+			subscription.current = Some((index, stream));
 
-			let stream =
-				stream.map_err(|e| subxt::error::RpcError::ClientError(Box::new(e))).boxed();
-			Ok(RpcSubscription { stream, id })
+			Ok(RpcSubscription { stream: subscription.into_stream(), id })
 		})
 	}
 }
@@ -14303,6 +14303,9 @@ pub mod api {
 				>,
 				pub state_machine: runtime_types::ismp::host::StateMachine,
 				pub amount: runtime_types::primitive_types::U256,
+				pub payee: runtime_types::bounded_collections::bounded_vec::BoundedVec<
+					::core::primitive::u8,
+				>,
 			}
 			impl ::subxt::events::StaticEvent for Withdraw {
 				const PALLET: &'static str = "Relayer";
@@ -21675,6 +21678,20 @@ pub mod api {
 				# [codec (crate = :: subxt :: ext :: codec)]
 				#[decode_as_type(crate_path = ":: subxt :: ext :: scale_decode")]
 				#[encode_as_type(crate_path = ":: subxt :: ext :: scale_encode")]
+				pub struct ChainId(pub ::std::vec::Vec<::core::primitive::u8>);
+				#[derive(
+					:: subxt :: ext :: codec :: Decode,
+					:: subxt :: ext :: codec :: Encode,
+					:: subxt :: ext :: scale_decode :: DecodeAsType,
+					:: subxt :: ext :: scale_encode :: EncodeAsType,
+					Clone,
+					Debug,
+					Eq,
+					PartialEq,
+				)]
+				# [codec (crate = :: subxt :: ext :: codec)]
+				#[decode_as_type(crate_path = ":: subxt :: ext :: scale_decode")]
+				#[encode_as_type(crate_path = ":: subxt :: ext :: scale_encode")]
 				pub enum StateMachine {
 					#[codec(index = 0)]
 					Evm(::core::primitive::u32),
@@ -21686,6 +21703,8 @@ pub mod api {
 					Substrate([::core::primitive::u8; 4usize]),
 					#[codec(index = 4)]
 					Tendermint([::core::primitive::u8; 4usize]),
+					#[codec(index = 5)]
+					Generic(runtime_types::ismp::host::ChainId),
 				}
 			}
 			pub mod messaging {
@@ -25389,6 +25408,9 @@ pub mod api {
 						>,
 						state_machine: runtime_types::ismp::host::StateMachine,
 						amount: runtime_types::primitive_types::U256,
+						payee: runtime_types::bounded_collections::bounded_vec::BoundedVec<
+							::core::primitive::u8,
+						>,
 					},
 				}
 			}
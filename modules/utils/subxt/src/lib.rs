@@ -27,7 +27,7 @@ mod gargantua_conversion {
 	use super::gargantua::api::runtime_types;
 	use ismp::{
 		consensus::{StateCommitment, StateMachineHeight, StateMachineId},
-		host::StateMachine,
+		host::{ChainId, ChainIdError, StateMachine},
 	};
 	use pallet_ismp_host_executive::{EvmHostParam, HostParam, PerByteFee};
 
@@ -41,27 +41,39 @@ mod gargantua_conversion {
 		}
 	}
 
-	impl From<runtime_types::ismp::consensus::StateMachineHeight> for StateMachineHeight {
-		fn from(state_machine_height: runtime_types::ismp::consensus::StateMachineHeight) -> Self {
-			StateMachineHeight {
-				id: state_machine_height.id.into(),
+	impl TryFrom<runtime_types::ismp::consensus::StateMachineHeight> for StateMachineHeight {
+		type Error = ChainIdError;
+
+		fn try_from(
+			state_machine_height: runtime_types::ismp::consensus::StateMachineHeight,
+		) -> Result<Self, Self::Error> {
+			Ok(StateMachineHeight {
+				id: state_machine_height.id.try_into()?,
 				height: state_machine_height.height,
-			}
+			})
 		}
 	}
 
-	impl From<runtime_types::ismp::consensus::StateMachineId> for StateMachineId {
-		fn from(state_machine_id: runtime_types::ismp::consensus::StateMachineId) -> Self {
-			StateMachineId {
-				state_id: state_machine_id.state_id.into(),
+	impl TryFrom<runtime_types::ismp::consensus::StateMachineId> for StateMachineId {
+		type Error = ChainIdError;
+
+		fn try_from(
+			state_machine_id: runtime_types::ismp::consensus::StateMachineId,
+		) -> Result<Self, Self::Error> {
+			Ok(StateMachineId {
+				state_id: state_machine_id.state_id.try_into()?,
 				consensus_state_id: state_machine_id.consensus_state_id,
-			}
+			})
 		}
 	}
 
-	impl From<runtime_types::ismp::host::StateMachine> for StateMachine {
-		fn from(state_machine_id: runtime_types::ismp::host::StateMachine) -> Self {
-			match state_machine_id {
+	impl TryFrom<runtime_types::ismp::host::StateMachine> for StateMachine {
+		type Error = ChainIdError;
+
+		fn try_from(
+			state_machine_id: runtime_types::ismp::host::StateMachine,
+		) -> Result<Self, Self::Error> {
+			let state_machine = match state_machine_id {
 				runtime_types::ismp::host::StateMachine::Evm(id) => StateMachine::Evm(id),
 				runtime_types::ismp::host::StateMachine::Polkadot(id) => StateMachine::Polkadot(id),
 				runtime_types::ismp::host::StateMachine::Kusama(id) => StateMachine::Kusama(id),
@@ -69,7 +81,11 @@ mod gargantua_conversion {
 					StateMachine::Substrate(consensus_state_id),
 				runtime_types::ismp::host::StateMachine::Tendermint(id) =>
 					StateMachine::Tendermint(id),
-			}
+				runtime_types::ismp::host::StateMachine::Generic(id) =>
+					StateMachine::Generic(ChainId::try_from(&id.0[..])?),
+			};
+
+			Ok(state_machine)
 		}
 	}
 
@@ -101,6 +117,9 @@ mod gargantua_conversion {
 					runtime_types::ismp::host::StateMachine::Substrate(consensus_state_id),
 				StateMachine::Tendermint(id) =>
 					runtime_types::ismp::host::StateMachine::Tendermint(id),
+				StateMachine::Generic(id) => runtime_types::ismp::host::StateMachine::Generic(
+					runtime_types::ismp::host::ChainId(id.as_bytes().to_vec()),
+				),
 			}
 		}
 	}
@@ -331,7 +350,7 @@ pub mod signer {
 			sp_core::{crypto, sr25519, Pair},
 			sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner},
 		},
-		tx::Signer,
+		tx::{Signer, SubmittableExtrinsic},
 		OnlineClient,
 	};
 
@@ -386,6 +405,13 @@ pub mod signer {
 	{
 		let other_params = BaseExtrinsicParamsBuilder::new().tip(tip.unwrap_or_default());
 		let ext = client.tx().create_signed(&payload, &signer, other_params.into()).await?;
+		submit_extrinsic(ext).await
+	}
+
+	/// Submit a signed extrinsic and wait for it to be successfully executed in a block
+	pub async fn submit_extrinsic<T: subxt::Config>(
+		ext: SubmittableExtrinsic<T, OnlineClient<T>>,
+	) -> Result<T::Hash, anyhow::Error> {
 		let progress = ext.submit_and_watch().await.context("Failed to submit signed extrinsic")?;
 		let ext_hash = progress.extrinsic_hash();
 
@@ -428,6 +454,24 @@ pub fn relayer_account_balance_storage_key(
 	[pallet_prefix, storage_prefix, key_1, state_machine.encode(), key_2, address.encode()].concat()
 }
 
+pub fn relayer_beneficiary_storage_key(address: Vec<u8>) -> Vec<u8> {
+	let pallet_prefix = twox_128(b"Relayer").to_vec();
+
+	let storage_prefix = twox_128(b"Beneficiaries").to_vec();
+	let key_1 = blake2_128(&address.encode()).to_vec();
+
+	[pallet_prefix, storage_prefix, key_1, address.encode()].concat()
+}
+
+pub fn relayer_beneficiary_nonce_storage_key(address: Vec<u8>) -> Vec<u8> {
+	let pallet_prefix = twox_128(b"Relayer").to_vec();
+
+	let storage_prefix = twox_128(b"BeneficiaryNonce").to_vec();
+	let key_1 = blake2_128(&address.encode()).to_vec();
+
+	[pallet_prefix, storage_prefix, key_1, address.encode()].concat()
+}
+
 pub fn relayer_nonce_storage_key(address: Vec<u8>, state_machine: StateMachine) -> Vec<u8> {
 	let pallet_prefix = twox_128(b"Relayer").to_vec();
 
@@ -50,8 +50,8 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 use ::ismp::{
-	consensus::{ConsensusClientId, StateMachineHeight, StateMachineId},
-	router::{Request, Response},
+	consensus::{ConsensusClientId, ConsensusStateId, StateMachineHeight, StateMachineId},
+	router::{ChannelId, Request, Response},
 };
 
 use frame_support::{
@@ -71,7 +71,7 @@ use frame_system::{
 	EnsureRoot, EnsureRootWithSuccess,
 };
 
-use pallet_ismp::mmr::Proof;
+use pallet_ismp::{mmr::Proof, ConsensusClientInfo};
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_mmr_primitives::{LeafIndex, INDEXING_PREFIX};
 pub use sp_runtime::{MultiAddress, Perbill, Permill};
@@ -164,6 +164,9 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (pallet_ismp::migrations::v1::MigrateV0ToV1<Runtime>,);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -171,6 +174,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -233,7 +237,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("gargantua"),
 	impl_name: create_runtime_str!("gargantua"),
 	authoring_version: 1,
-	spec_version: 1150,
+	spec_version: 1160,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -757,6 +761,7 @@ mod benches {
 		[pallet_collective, TechnicalCollective]
 		[cumulus_pallet_parachain_system, ParachainSystem]
 		[pallet_session, SessionBench::<Runtime>]
+		[pallet_ismp_relayer, Relayer]
 	);
 }
 
@@ -979,6 +984,21 @@ impl_runtime_apis! {
 		fn responses(commitments: Vec<H256>) -> Vec<Response> {
 			Ismp::responses(commitments)
 		}
+
+		/// Return the next nonce of the channel, if it is ordered
+		fn next_channel_nonce(channel: ChannelId) -> Option<u64> {
+			Ismp::channel_nonce(channel)
+		}
+
+		/// Return the registry information about the consensus client of a consensus state
+		fn consensus_client_info(id: ConsensusStateId) -> Option<ConsensusClientInfo> {
+			Ismp::consensus_client_info(id)
+		}
+
+		/// Return the registry information about the consensus clients of all consensus states
+		fn consensus_client_registry() -> Vec<ConsensusClientInfo> {
+			Ismp::consensus_client_registry()
+		}
 	}
 
 	impl ismp_parachain_runtime_api::IsmpParachainApi<Block> for Runtime {
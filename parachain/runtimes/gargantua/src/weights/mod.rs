@@ -30,6 +30,7 @@ pub mod pallet_asset_rate;
 pub mod pallet_assets;
 pub mod pallet_balances;
 pub mod pallet_collective;
+pub mod pallet_ismp_relayer;
 pub mod pallet_message_queue;
 pub mod pallet_session;
 pub mod pallet_sudo;
@@ -15,8 +15,8 @@
 
 use crate::{
 	alloc::{boxed::Box, string::ToString},
-	AccountId, Assets, Balance, Balances, Gateway, Ismp, IsmpParachain, Mmr, ParachainInfo,
-	Runtime, RuntimeEvent, Timestamp, EXISTENTIAL_DEPOSIT,
+	weights, AccountId, Assets, Balance, Balances, Gateway, Ismp, IsmpParachain, Mmr,
+	ParachainInfo, Runtime, RuntimeEvent, Timestamp, EXISTENTIAL_DEPOSIT,
 };
 use frame_support::{
 	pallet_prelude::{ConstU32, Get},
@@ -27,14 +27,13 @@ use frame_support::{
 use frame_system::EnsureRoot;
 use hyperbridge_client_machine::HyperbridgeClientMachine;
 use ismp::{
-	error::Error,
 	host::StateMachine,
-	module::IsmpModule,
+	module::{IsmpModule, ModuleError, ModuleErrorCode},
 	router::{IsmpRouter, PostRequest, Request, Response},
 };
-use pallet_xcm_gateway::AssetGatewayParams;
 #[cfg(feature = "runtime-benchmarks")]
 use pallet_assets::BenchmarkHelper;
+use pallet_xcm_gateway::AssetGatewayParams;
 use sp_core::crypto::AccountId32;
 use sp_runtime::Permill;
 
@@ -43,7 +42,6 @@ use ismp_sync_committee::constants::mainnet::Mainnet;
 use pallet_ismp::{dispatcher::FeeMetadata, ModuleId};
 use sp_std::prelude::*;
 use staging_xcm::latest::Location;
-use anyhow::anyhow;
 
 #[derive(Default)]
 pub struct ProxyModule;
@@ -94,6 +92,7 @@ impl pallet_ismp::Config for Runtime {
 impl pallet_ismp_relayer::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IsmpHost = Ismp;
+	type WeightInfo = weights::pallet_ismp_relayer::WeightInfo<Runtime>;
 }
 
 impl pallet_ismp_host_executive::Config for Runtime {
@@ -173,46 +172,60 @@ impl pallet_assets::Config for Runtime {
 }
 
 impl IsmpModule for ProxyModule {
-	fn on_accept(&self, request: PostRequest) -> Result<(), anyhow::Error> {
+	fn on_accept(&self, request: PostRequest) -> Result<(), ModuleError> {
 		if request.dest != HostStateMachine::get() {
+			let module = request.to.clone();
 			Ismp::dispatch_request(
 				Request::Post(request),
 				FeeMetadata::<Runtime> { payer: [0u8; 32].into(), fee: Default::default() },
-			)?;
+			)
+			.map_err(|err| ModuleError::new(ModuleErrorCode::Internal, module, err))?;
 			return Ok(());
 		}
 
-		let pallet_id =
-			ModuleId::from_bytes(&request.to).map_err(|err| Error::Custom(err.to_string()))?;
+		let pallet_id = ModuleId::from_bytes(&request.to).map_err(|err| {
+			ModuleError::new(ModuleErrorCode::ModuleNotFound, request.to.clone(), err)
+		})?;
 
 		let token_gateway = ModuleId::Evm(Gateway::token_gateway_address(&request.source));
 		match pallet_id {
 			id if id == token_gateway =>
 				pallet_xcm_gateway::Module::<Runtime>::default().on_accept(request),
-			_ => Err(anyhow!("Destination module not found")),
+			_ => Err(ModuleError::new(
+				ModuleErrorCode::ModuleNotFound,
+				request.to,
+				"Destination module not found",
+			)),
 		}
 	}
 
-	fn on_response(&self, response: Response) -> Result<(), anyhow::Error> {
+	fn on_response(&self, response: Response) -> Result<(), ModuleError> {
 		if response.dest_chain() != HostStateMachine::get() {
+			let module = response.destination_module();
 			Ismp::dispatch_response(
 				response,
 				FeeMetadata::<Runtime> { payer: [0u8; 32].into(), fee: Default::default() },
-			)?;
+			)
+			.map_err(|err| ModuleError::new(ModuleErrorCode::Internal, module, err))?;
 			return Ok(());
 		}
 
-		Err(anyhow!("Destination module not found"))
+		Err(ModuleError::new(
+			ModuleErrorCode::ModuleNotFound,
+			response.destination_module(),
+			"Destination module not found",
+		))
 	}
 
-	fn on_timeout(&self, timeout: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, timeout: Timeout) -> Result<(), ModuleError> {
 		let (from, source) = match &timeout {
 			Timeout::Request(Request::Post(post)) => (&post.from, &post.source),
 			Timeout::Request(Request::Get(get)) => (&get.from, &get.source),
 			Timeout::Response(res) => (&res.post.to, &res.post.dest),
 		};
 
-		let pallet_id = ModuleId::from_bytes(from).map_err(|err| Error::Custom(err.to_string()))?;
+		let pallet_id = ModuleId::from_bytes(from)
+			.map_err(|err| ModuleError::new(ModuleErrorCode::ModuleNotFound, from.clone(), err))?;
 		let token_gateway = ModuleId::Evm(Gateway::token_gateway_address(source));
 		match pallet_id {
 			id if id == token_gateway =>
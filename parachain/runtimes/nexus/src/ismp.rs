@@ -17,20 +17,20 @@ use crate::{
 	alloc::{boxed::Box, string::ToString},
 	weights, AccountId, Assets, Balance, Balances, Ismp, IsmpParachain, Mmr, ParachainInfo,
 	Runtime, RuntimeEvent, Timestamp, TokenGatewayInspector, TokenGovernor, TreasuryPalletId,
-	XcmGateway, EXISTENTIAL_DEPOSIT,
+	XcmGateway, EXISTENTIAL_DEPOSIT, UNIT,
 };
 use frame_support::{
 	pallet_prelude::{ConstU32, Get},
 	parameter_types,
 	traits::AsEnsureOriginWithArg,
+	weights::Weight,
 	PalletId,
 };
 use frame_system::EnsureRoot;
 use hyperbridge_client_machine::HyperbridgeClientMachine;
 use ismp::{
-	error::Error,
 	host::StateMachine,
-	module::IsmpModule,
+	module::{IsmpModule, ModuleError, ModuleErrorCode},
 	router::{IsmpRouter, PostRequest, Request, Response},
 };
 #[cfg(feature = "runtime-benchmarks")]
@@ -38,7 +38,6 @@ use pallet_assets::BenchmarkHelper;
 use pallet_xcm_gateway::AssetGatewayParams;
 use sp_core::{crypto::AccountId32, H256};
 
-use anyhow::anyhow;
 use ismp::router::Timeout;
 use ismp_sync_committee::constants::{gnosis, mainnet::Mainnet};
 use pallet_ismp::{dispatcher::FeeMetadata, ModuleId};
@@ -108,6 +107,7 @@ impl ismp_grandpa::Config for Runtime {
 impl pallet_ismp_relayer::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IsmpHost = Ismp;
+	type WeightInfo = weights::pallet_ismp_relayer::WeightInfo<Runtime>;
 }
 
 impl pallet_ismp_host_executive::Config for Runtime {
@@ -119,9 +119,18 @@ impl pallet_call_decompressor::Config for Runtime {
 	type MaxCallSize = ConstU32<3>;
 }
 
+parameter_types! {
+	pub const FishermanBond: Balance = 1_000 * UNIT;
+	pub const VetoReward: Balance = 100 * UNIT;
+	pub const FraudProofWeightPerByte: Weight = Weight::from_parts(100_000, 1);
+}
+
 impl pallet_fishermen::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IsmpHost = Ismp;
+	type FishermanBond = FishermanBond;
+	type VetoReward = VetoReward;
+	type FraudProofWeightPerByte = FraudProofWeightPerByte;
 }
 
 impl ismp_parachain::Config for Runtime {
@@ -194,19 +203,24 @@ impl pallet_token_gateway_inspector::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 impl IsmpModule for ProxyModule {
-	fn on_accept(&self, request: PostRequest) -> Result<(), anyhow::Error> {
+	fn on_accept(&self, request: PostRequest) -> Result<(), ModuleError> {
 		if request.dest != HostStateMachine::get() {
-			TokenGatewayInspector::inspect_request(&request)?;
+			TokenGatewayInspector::inspect_request(&request).map_err(|err| {
+				ModuleError::new(ModuleErrorCode::InvalidRequest, request.to.clone(), err)
+			})?;
 
+			let module = request.to.clone();
 			Ismp::dispatch_request(
 				Request::Post(request),
 				FeeMetadata::<Runtime> { payer: [0u8; 32].into(), fee: Default::default() },
-			)?;
+			)
+			.map_err(|err| ModuleError::new(ModuleErrorCode::Internal, module, err))?;
 			return Ok(());
 		}
 
-		let pallet_id =
-			ModuleId::from_bytes(&request.to).map_err(|err| Error::Custom(err.to_string()))?;
+		let pallet_id = ModuleId::from_bytes(&request.to).map_err(|err| {
+			ModuleError::new(ModuleErrorCode::ModuleNotFound, request.to.clone(), err)
+		})?;
 
 		let xcm_gateway = ModuleId::Evm(XcmGateway::token_gateway_address(&request.source));
 		let token_governor = ModuleId::Pallet(PalletId(pallet_token_governor::PALLET_ID));
@@ -215,27 +229,39 @@ impl IsmpModule for ProxyModule {
 			id if id == xcm_gateway =>
 				pallet_xcm_gateway::Module::<Runtime>::default().on_accept(request),
 			id if id == token_governor => TokenGovernor::default().on_accept(request),
-			_ => Err(anyhow!("Destination module not found")),
+			_ => Err(ModuleError::new(
+				ModuleErrorCode::ModuleNotFound,
+				request.to,
+				"Destination module not found",
+			)),
 		}
 	}
 
-	fn on_response(&self, response: Response) -> Result<(), anyhow::Error> {
+	fn on_response(&self, response: Response) -> Result<(), ModuleError> {
 		if response.dest_chain() != HostStateMachine::get() {
+			let module = response.destination_module();
 			Ismp::dispatch_response(
 				response,
 				FeeMetadata::<Runtime> { payer: [0u8; 32].into(), fee: Default::default() },
-			)?;
+			)
+			.map_err(|err| ModuleError::new(ModuleErrorCode::Internal, module, err))?;
 			return Ok(());
 		}
 
-		Err(anyhow!("Destination module not found"))
+		Err(ModuleError::new(
+			ModuleErrorCode::ModuleNotFound,
+			response.destination_module(),
+			"Destination module not found",
+		))
 	}
 
-	fn on_timeout(&self, timeout: Timeout) -> Result<(), anyhow::Error> {
+	fn on_timeout(&self, timeout: Timeout) -> Result<(), ModuleError> {
 		let (from, _source, dest) = match &timeout {
 			Timeout::Request(Request::Post(post)) => {
 				if post.source != HostStateMachine::get() {
-					TokenGatewayInspector::handle_timeout(post)?;
+					TokenGatewayInspector::handle_timeout(post).map_err(|err| {
+						ModuleError::new(ModuleErrorCode::InvalidRequest, post.from.clone(), err)
+					})?;
 				}
 				(&post.from, &post.source, &post.dest)
 			},
@@ -243,7 +269,8 @@ impl IsmpModule for ProxyModule {
 			Timeout::Response(res) => (&res.post.to, &res.post.dest, &res.post.dest),
 		};
 
-		let pallet_id = ModuleId::from_bytes(from).map_err(|err| Error::Custom(err.to_string()))?;
+		let pallet_id = ModuleId::from_bytes(from)
+			.map_err(|err| ModuleError::new(ModuleErrorCode::ModuleNotFound, from.clone(), err))?;
 		let xcm_gateway = ModuleId::Evm(XcmGateway::token_gateway_address(dest));
 		match pallet_id {
 			id if id == xcm_gateway =>
@@ -30,6 +30,7 @@ pub mod pallet_assets;
 pub mod pallet_balances;
 pub mod pallet_collator_selection;
 pub mod pallet_collective;
+pub mod pallet_ismp_relayer;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_proxy;
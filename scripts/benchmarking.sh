@@ -12,6 +12,7 @@ declare -a arr=(
 "pallet_utility"
 "cumulus_pallet_parachain_system"
 "pallet_session"
+"pallet_ismp_relayer"
 )
 
 # nexus runtime
@@ -16,7 +16,7 @@
 use std::sync::Arc;
 use substrate_state_machine::HashAlgorithm;
 use tesseract_evm::{EvmClient, EvmConfig};
-use tesseract_primitives::IsmpProvider;
+use tesseract_primitives::{signer::SignerConfig, IsmpProvider, TransactionStore};
 use tesseract_substrate::{
 	config::{Blake2SubstrateChain, KeccakSubstrateChain},
 	SubstrateClient, SubstrateConfig,
@@ -39,13 +39,23 @@ impl AnyConfig {
 			Self::Evm(config) => config.state_machine,
 		}
 	}
+
+	/// Replace the signer of the relayer account
+	pub fn set_signer(&mut self, signer: SignerConfig) {
+		match self {
+			Self::Substrate(config) => config.signer = Some(signer),
+			Self::Evm(config) => config.signer = signer,
+		}
+	}
 }
 
 impl AnyConfig {
-	/// Convert the [`AnyConfig`] into an implementation of an [`IsmpProvider`]
+	/// Convert the [`AnyConfig`] into an implementation of an [`IsmpProvider`]. In-flight
+	/// transactions of evm clients are persisted in the `store` if one is provided.
 	pub async fn into_client(
 		self,
 		hyperbridge: Arc<dyn IsmpProvider>,
+		store: Option<Arc<dyn TransactionStore>>,
 	) -> Result<Arc<dyn IsmpProvider>, anyhow::Error> {
 		let client = match self {
 			AnyConfig::Substrate(config) => {
@@ -66,6 +76,9 @@ impl AnyConfig {
 			},
 			AnyConfig::Evm(config) => {
 				let mut client = EvmClient::new(config).await?;
+				if let Some(store) = store {
+					client.set_transaction_store(store).await?;
+				}
 				client.set_latest_finalized_height(hyperbridge).await?;
 				Arc::new(client) as Arc<dyn IsmpProvider>
 			},
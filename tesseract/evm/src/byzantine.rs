@@ -8,6 +8,7 @@ use ismp::{
 	events::{Event, StateMachineUpdated},
 	host::StateMachine,
 };
+use sp_core::H256;
 use tesseract_primitives::{BoxStream, ByzantineHandler, IsmpProvider};
 
 use crate::EvmClient;
@@ -16,7 +17,7 @@ use crate::EvmClient;
 impl ByzantineHandler for EvmClient {
 	async fn check_for_byzantine_attack(
 		&self,
-		_coprocessor: StateMachine,
+		coprocessor: StateMachine,
 		counterparty: Arc<dyn IsmpProvider>,
 		event: StateMachineUpdated,
 	) -> Result<(), anyhow::Error> {
@@ -27,7 +28,8 @@ impl ByzantineHandler for EvmClient {
 			},
 			height: event.latest_height,
 		};
-		let Some(header) = self.client.get_block(event.latest_height).await? else {
+		let Some(state_root) = self.query_state_root(coprocessor, event.latest_height).await?
+		else {
 			// If block header is not found veto the state commitment
 			log::info!(
 				"Vetoing State Machine Update for {} on {}",
@@ -39,7 +41,7 @@ impl ByzantineHandler for EvmClient {
 		};
 
 		let state_machine_commitment = counterparty.query_state_machine_commitment(height).await?;
-		if header.state_root != state_machine_commitment.state_root {
+		if state_root != state_machine_commitment.state_root {
 			log::info!(
 				"Vetoing State Machine Update for {} on {}",
 				self.state_machine,
@@ -51,6 +53,14 @@ impl ByzantineHandler for EvmClient {
 		Ok(())
 	}
 
+	async fn query_state_root(
+		&self,
+		_coprocessor: StateMachine,
+		height: u64,
+	) -> Result<Option<H256>, Error> {
+		Ok(self.client.get_block(height).await?.map(|header| header.state_root))
+	}
+
 	async fn state_machine_updates(
 		&self,
 		_counterparty_state_id: StateMachineId,
@@ -1,8 +1,11 @@
-use crate::abi::{arb_gas_info::ArbGasInfo, ovm_gas_price_oracle::OVM_gasPriceOracle};
+use crate::{
+	abi::{arb_gas_info::ArbGasInfo, ovm_gas_price_oracle::OVM_gasPriceOracle},
+	transport::FailoverHttp,
+	PriorityFeeStrategy,
+};
 use anyhow::{anyhow, Error};
 use ethers::{
-	prelude::{Bytes, Middleware, Provider},
-	providers::Http,
+	prelude::{BlockNumber, Bytes, Middleware, Provider},
 	utils::parse_units,
 };
 use frame_support::Deserialize;
@@ -56,6 +59,9 @@ pub struct EthPriceResponse {
 	pub result: EthPriceResult,
 }
 
+/// Number of recent blocks sampled by the percentile priority fee strategy
+const FEE_HISTORY_BLOCKS: u64 = 20;
+
 const ARB_GAS_INFO: [u8; 20] = hex!("000000000000000000000000000000000000006c");
 const OP_GAS_ORACLE: [u8; 20] = hex!("420000000000000000000000000000000000000F");
 
@@ -91,18 +97,33 @@ pub fn is_op_stack(id: u32) -> bool {
 pub struct GasBreakdown {
 	/// Gas price in wei
 	pub gas_price: U256,
+	/// Base fee of the latest block in wei, `None` if the chain does not support EIP-1559
+	pub base_fee: Option<U256>,
+	/// Priority fee (tip) in wei for EIP-1559 transactions
+	pub priority_fee: U256,
 	/// Gas price cost
 	pub gas_price_cost: Cost,
 	/// Unit wei cost in 27 decimals
 	pub unit_wei_cost: U256,
 }
 
+impl GasBreakdown {
+	/// The max fee per gas for EIP-1559 transactions. This leaves room for the base fee to
+	/// double before the transaction is priced out, `None` if the chain does not support EIP-1559
+	pub fn max_fee_per_gas(&self) -> Option<U256> {
+		self.base_fee.map(|base_fee| base_fee * 2 + self.priority_fee)
+	}
+}
+
 /// Function gets current gas price (for execution) in wei and return the equivalent in USD,
+/// alongside the base fee and priority fee components for EIP-1559 transactions. The fee
+/// components are only queried if a priority fee strategy is given, i.e EIP-1559 is enabled.
 pub async fn get_current_gas_cost_in_usd(
 	chain: StateMachine,
 	api_keys: &str,
-	client: Arc<Provider<Http>>,
+	client: Arc<Provider<FailoverHttp>>,
 	gas_price_buffer: Option<u32>,
+	priority_fee_strategy: Option<&PriorityFeeStrategy>,
 ) -> Result<GasBreakdown, Error> {
 	let mut gas_price_cost = U256::zero();
 	let mut gas_price = U256::zero();
@@ -288,12 +309,73 @@ pub async fn get_current_gas_cost_in_usd(
 		ethers::utils::format_units(gas_price, "gwei").unwrap()
 	);
 
-	let buffer = gas_price_buffer
-		.map(|buffer| (U256::from(buffer) * gas_price) / U256::from(100u32))
-		.unwrap_or_default();
-	gas_price = gas_price + buffer;
+	let (base_fee, mut priority_fee) = match priority_fee_strategy {
+		Some(strategy) => get_fee_components(client, strategy).await.unwrap_or_else(|err| {
+			// transactions fall back to the legacy gas price without a base fee
+			log::warn!(
+				"Failed to query the EIP-1559 fees for {chain:?}, using the gas price: {err:?}"
+			);
+			(None, U256::zero())
+		}),
+		None => (None, U256::zero()),
+	};
+
+	let buffer = |price: U256| {
+		gas_price_buffer
+			.map(|buffer| (U256::from(buffer) * price) / U256::from(100u32))
+			.unwrap_or_default()
+	};
+	gas_price = gas_price + buffer(gas_price);
+	priority_fee = priority_fee + buffer(priority_fee);
+
+	Ok(GasBreakdown {
+		gas_price,
+		base_fee,
+		priority_fee,
+		gas_price_cost: gas_price_cost.into(),
+		unit_wei_cost: unit_wei,
+	})
+}
+
+/// Returns the base fee of the latest block and the priority fee chosen by the given strategy.
+/// The base fee is `None` and the priority fee is zero for chains that do not support EIP-1559.
+pub async fn get_fee_components(
+	client: Arc<Provider<FailoverHttp>>,
+	strategy: &PriorityFeeStrategy,
+) -> Result<(Option<U256>, U256), Error> {
+	let Some(base_fee) = client
+		.get_block(BlockNumber::Latest)
+		.await?
+		.and_then(|block| block.base_fee_per_gas)
+	else {
+		return Ok((None, U256::zero()))
+	};
+
+	let priority_fee = match strategy {
+		PriorityFeeStrategy::Node =>
+			client.request::<_, U256>("eth_maxPriorityFeePerGas", ()).await?,
+		PriorityFeeStrategy::Percentile(percentile) => {
+			let history = client
+				.fee_history(
+					FEE_HISTORY_BLOCKS,
+					BlockNumber::Latest,
+					&[(*percentile).min(100) as f64],
+				)
+				.await?;
+			// empty blocks report a zero reward, leave them out of the sample
+			let mut rewards = history
+				.reward
+				.into_iter()
+				.filter_map(|rewards| rewards.first().copied())
+				.filter(|reward| !reward.is_zero())
+				.collect::<Vec<_>>();
+			rewards.sort();
+			rewards.get(rewards.len() / 2).copied().unwrap_or_default()
+		},
+		PriorityFeeStrategy::Fixed(fee) => parse_units(fee, "gwei")?.into(),
+	};
 
-	Ok(GasBreakdown { gas_price, gas_price_cost: gas_price_cost.into(), unit_wei_cost: unit_wei })
+	Ok((Some(base_fee), priority_fee))
 }
 
 fn get_cost_of_one_wei(eth_usd: U256) -> U256 {
@@ -305,7 +387,7 @@ fn get_cost_of_one_wei(eth_usd: U256) -> U256 {
 pub async fn get_l2_data_cost(
 	rlp_tx: Bytes,
 	chain: StateMachine,
-	client: Arc<Provider<Http>>,
+	client: Arc<Provider<FailoverHttp>>,
 	// Unit wei cost in 27 decimals
 	unit_wei_cost: U256,
 ) -> Result<Cost, anyhow::Error> {
@@ -398,12 +480,16 @@ pub fn convert_27_decimals_to_18_decimals(value: U256) -> Result<U256, Error> {
 
 #[cfg(test)]
 mod test {
-	use crate::gas_oracle::{
-		convert_27_decimals_to_18_decimals, get_cost_of_one_wei, get_current_gas_cost_in_usd,
-		get_l2_data_cost, parse_to_27_decimals, ARBITRUM_SEPOLIA_CHAIN_ID, BSC_TESTNET_CHAIN_ID,
-		GNOSIS_CHAIN_ID, OPTIMISM_SEPOLIA_CHAIN_ID, POLYGON_TESTNET_CHAIN_ID, SEPOLIA_CHAIN_ID,
+	use crate::{
+		gas_oracle::{
+			convert_27_decimals_to_18_decimals, get_cost_of_one_wei, get_current_gas_cost_in_usd,
+			get_l2_data_cost, parse_to_27_decimals, ARBITRUM_SEPOLIA_CHAIN_ID,
+			BSC_TESTNET_CHAIN_ID, GNOSIS_CHAIN_ID, OPTIMISM_SEPOLIA_CHAIN_ID,
+			POLYGON_TESTNET_CHAIN_ID, SEPOLIA_CHAIN_ID,
+		},
+		transport::FailoverHttp,
 	};
-	use ethers::{prelude::Provider, providers::Http, utils::parse_units};
+	use ethers::{prelude::Provider, utils::parse_units};
 	use ismp::host::StateMachine;
 	use primitive_types::U256;
 	use std::sync::Arc;
@@ -416,7 +502,9 @@ mod test {
 		let ethereum_etherscan_api_key = std::env::var("ETHERSCAN_ETHEREUM_KEY")
 			.expect("Etherscan ethereum key is not set in .env.");
 		let ethereum_rpc_uri = std::env::var("GETH_URL").expect("get url is not set in .env.");
-		let provider = Provider::<Http>::try_from(ethereum_rpc_uri).unwrap();
+		let provider = Provider::new(
+			FailoverHttp::new(vec![ethereum_rpc_uri], Default::default(), None).unwrap(),
+		);
 		let client = Arc::new(provider.clone());
 
 		let ethereum_gas_cost_in_usd = get_current_gas_cost_in_usd(
@@ -424,6 +512,7 @@ mod test {
 			&ethereum_etherscan_api_key,
 			client.clone(),
 			None,
+			Some(&Default::default()),
 		)
 		.await
 		.unwrap();
@@ -439,7 +528,9 @@ mod test {
 			.expect("Etherscan ethereum key is not set in .env.");
 		let ethereum_rpc_uri = std::env::var("GETH_URL").expect("get url is not set in .env.");
 		// Client is unused in this test
-		let provider = Provider::<Http>::try_from(ethereum_rpc_uri).unwrap();
+		let provider = Provider::new(
+			FailoverHttp::new(vec![ethereum_rpc_uri], Default::default(), None).unwrap(),
+		);
 		let client = Arc::new(provider.clone());
 
 		let ethereum_gas_cost_in_usd = get_current_gas_cost_in_usd(
@@ -447,6 +538,7 @@ mod test {
 			&ethereum_etherscan_api_key,
 			client.clone(),
 			None,
+			Some(&Default::default()),
 		)
 		.await
 		.unwrap();
@@ -462,7 +554,9 @@ mod test {
 			.expect("Polygon ethereum key is not set in .env.");
 		let ethereum_rpc_uri = std::env::var("GETH_URL").expect("get url is not set in .env.");
 		// Client is unused in this test
-		let provider = Provider::<Http>::try_from(ethereum_rpc_uri).unwrap();
+		let provider = Provider::new(
+			FailoverHttp::new(vec![ethereum_rpc_uri], Default::default(), None).unwrap(),
+		);
 		let client = Arc::new(provider.clone());
 
 		let ethereum_gas_cost_in_usd = get_current_gas_cost_in_usd(
@@ -470,6 +564,7 @@ mod test {
 			&ethereum_etherscan_api_key,
 			client.clone(),
 			None,
+			Some(&Default::default()),
 		)
 		.await
 		.unwrap();
@@ -483,7 +578,9 @@ mod test {
 		dotenv::dotenv().ok();
 		let ethereum_rpc_uri = std::env::var("CHIADO_URL").expect("get url is not set in .env.");
 		// Client is unused in this test
-		let provider = Provider::<Http>::try_from(ethereum_rpc_uri).unwrap();
+		let provider = Provider::new(
+			FailoverHttp::new(vec![ethereum_rpc_uri], Default::default(), None).unwrap(),
+		);
 		let client = Arc::new(provider.clone());
 
 		let ethereum_gas_cost_in_usd = get_current_gas_cost_in_usd(
@@ -491,6 +588,7 @@ mod test {
 			"",
 			client.clone(),
 			None,
+			Some(&Default::default()),
 		)
 		.await
 		.unwrap();
@@ -506,7 +604,9 @@ mod test {
 			.expect("Polygon ethereum key is not set in .env.");
 		let ethereum_rpc_uri = std::env::var("GETH_URL").expect("get url is not set in .env.");
 		// Client is unused in this test
-		let provider = Provider::<Http>::try_from(ethereum_rpc_uri).unwrap();
+		let provider = Provider::new(
+			FailoverHttp::new(vec![ethereum_rpc_uri], Default::default(), None).unwrap(),
+		);
 		let client = Arc::new(provider.clone());
 
 		let ethereum_gas_cost_in_usd = get_current_gas_cost_in_usd(
@@ -514,6 +614,7 @@ mod test {
 			&ethereum_etherscan_api_key,
 			client.clone(),
 			None,
+			Some(&Default::default()),
 		)
 		.await
 		.unwrap();
@@ -529,7 +630,9 @@ mod test {
 			std::env::var("ETHERSCAN_BSC_KEY").expect("Polygon ethereum key is not set in .env.");
 		let ethereum_rpc_uri = std::env::var("GETH_URL").expect("get url is not set in .env.");
 		// Client is unused in this test
-		let provider = Provider::<Http>::try_from(ethereum_rpc_uri).unwrap();
+		let provider = Provider::new(
+			FailoverHttp::new(vec![ethereum_rpc_uri], Default::default(), None).unwrap(),
+		);
 		let client = Arc::new(provider.clone());
 
 		let ethereum_gas_cost_in_usd = get_current_gas_cost_in_usd(
@@ -537,6 +640,7 @@ mod test {
 			&ethereum_etherscan_api_key,
 			client.clone(),
 			None,
+			Some(&Default::default()),
 		)
 		.await
 		.unwrap();
@@ -551,7 +655,9 @@ mod test {
 		let ethereum_etherscan_api_key = std::env::var("ETHERSCAN_ETHEREUM_KEY")
 			.expect("Ethereum ethereum key is not set in .env.");
 		let ethereum_rpc_uri = std::env::var("ARB_URL").expect("arb url is not set in .env.");
-		let provider = Provider::<Http>::try_from(ethereum_rpc_uri).unwrap();
+		let provider = Provider::new(
+			FailoverHttp::new(vec![ethereum_rpc_uri], Default::default(), None).unwrap(),
+		);
 		let client = Arc::new(provider.clone());
 
 		let ethereum_gas_cost_in_usd = get_current_gas_cost_in_usd(
@@ -559,6 +665,7 @@ mod test {
 			&ethereum_etherscan_api_key,
 			client.clone(),
 			None,
+			Some(&Default::default()),
 		)
 		.await
 		.unwrap();
@@ -573,7 +680,9 @@ mod test {
 		let ethereum_etherscan_api_key = std::env::var("ETHERSCAN_ETHEREUM_KEY")
 			.expect("Ethereum ethereum key is not set in .env.");
 		let ethereum_rpc_uri = std::env::var("OP_URL").expect("op url is not set in .env.");
-		let provider = Provider::<Http>::try_from(ethereum_rpc_uri).unwrap();
+		let provider = Provider::new(
+			FailoverHttp::new(vec![ethereum_rpc_uri], Default::default(), None).unwrap(),
+		);
 		let client = Arc::new(provider.clone());
 
 		let ethereum_gas_cost_in_usd = get_current_gas_cost_in_usd(
@@ -581,6 +690,7 @@ mod test {
 			&ethereum_etherscan_api_key,
 			client.clone(),
 			None,
+			Some(&Default::default()),
 		)
 		.await
 		.unwrap();
@@ -595,13 +705,16 @@ mod test {
 		let ethereum_etherscan_api_key = std::env::var("ETHERSCAN_ETHEREUM_KEY")
 			.expect("Ethereum ethereum key is not set in .env.");
 		let ethereum_rpc_uri = std::env::var("OP_URL").expect("op url is not set in .env.");
-		let provider = Provider::<Http>::try_from(ethereum_rpc_uri).unwrap();
+		let provider = Provider::new(
+			FailoverHttp::new(vec![ethereum_rpc_uri], Default::default(), None).unwrap(),
+		);
 		let client = Arc::new(provider.clone());
 		let ethereum_gas_cost_in_usd = get_current_gas_cost_in_usd(
 			StateMachine::Evm(OPTIMISM_SEPOLIA_CHAIN_ID),
 			&ethereum_etherscan_api_key,
 			client.clone(),
 			None,
+			Some(&Default::default()),
 		)
 		.await
 		.unwrap();
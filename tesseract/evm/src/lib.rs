@@ -2,10 +2,8 @@ use crate::abi::{EvmHost, PingModule};
 
 use ethabi::ethereum_types::{H256, U256};
 use ethers::{
-	core::k256::ecdsa::SigningKey,
-	prelude::{k256::SecretKey, LocalWallet, MiddlewareBuilder, SignerMiddleware, Wallet},
-	providers::{Http, Middleware, Provider},
-	signers::Signer,
+	prelude::{transaction::eip2718::TypedTransaction, MiddlewareBuilder, SignerMiddleware},
+	providers::{Middleware, Provider},
 };
 use frame_support::crypto::ecdsa::ECDSAExt;
 use ismp::{consensus::ConsensusStateId, events::Event, host::StateMachine, messaging::Message};
@@ -16,19 +14,27 @@ use evm_common::presets::{
 };
 
 use ismp_solidity_abi::shared_types::{StateCommitment, StateMachineHeight};
+use nonce::NonceManager;
+use rpc_utils::PoolConfig;
 use serde::{Deserialize, Serialize};
-use sp_core::{bytes::from_hex, keccak_256, Pair, H160};
+use signer::EvmSigner;
+use sp_core::{keccak_256, H160};
 use std::{sync::Arc, time::Duration};
 use tesseract_primitives::{
 	queue::{start_pipeline, PipelineQueue},
-	IsmpProvider, StateMachineUpdated, StreamError, TxReceipt,
+	signer::{KeyType, SignerConfig},
+	IsmpProvider, StateMachineUpdated, StreamError, TransactionStore, TxReceipt,
 };
-use tx::handle_message_submission;
+use transport::FailoverHttp;
+use tx::{broadcast, current_fees, handle_message_submission, sync_nonces};
 
 pub mod abi;
 mod byzantine;
 mod gas_oracle;
+pub mod nonce;
 pub mod provider;
+pub mod signer;
+pub mod transport;
 
 #[cfg(test)]
 mod test;
@@ -55,10 +61,31 @@ impl ClientType {
 	}
 }
 
+/// Strategy for choosing the priority fee (tip) of EIP-1559 transactions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriorityFeeStrategy {
+	/// Use the priority fee suggested by the node through `eth_maxPriorityFeePerGas`
+	Node,
+	/// Use the median of the given reward percentile paid in recent blocks through
+	/// `eth_feeHistory`
+	Percentile(u8),
+	/// Use a fixed priority fee in gwei
+	Fixed(f64),
+}
+
+impl Default for PriorityFeeStrategy {
+	fn default() -> Self {
+		Self::Node
+	}
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvmConfig {
 	/// RPC urls for the execution client
 	pub rpc_urls: Vec<String>,
+	/// How requests are spread across the rpc urls, by default the healthiest endpoint is used
+	pub rpc_pool: Option<PoolConfig>,
 	/// State machine Identifier for this client on it's counterparties.
 	#[serde(with = "serde_hex_utils::as_string")]
 	pub state_machine: StateMachine,
@@ -66,8 +93,8 @@ pub struct EvmConfig {
 	pub consensus_state_id: String,
 	/// Ismp Host contract address
 	pub ismp_host: H160,
-	/// Relayer account private key
-	pub signer: String,
+	/// Relayer account signer, either a private key or a keystore/remote signer backend
+	pub signer: SignerConfig,
 	/// Etherscan API key
 	pub etherscan_api_key: String,
 	/// Batch size to parallelize tracing
@@ -81,6 +108,15 @@ pub struct EvmConfig {
 	pub gas_price_buffer: Option<u32>,
 	/// The client type the rpc is running, defaults to Geth
 	pub client_type: Option<ClientType>,
+	/// Submit EIP-1559 transactions on chains that report a base fee, defaults to false
+	pub eip1559: Option<bool>,
+	/// The strategy used to choose the priority fee of EIP-1559 transactions, defaults to the
+	/// priority fee suggested by the node
+	pub priority_fee_strategy: Option<PriorityFeeStrategy>,
+	/// Percentage by which the fees of a stuck transaction are bumped when it is replaced,
+	/// defaults to 25%. Nodes reject replacements that bump the fees by less than 10%, so lower
+	/// values are raised to 10%
+	pub fee_bump_percentage: Option<u32>,
 }
 
 impl EvmConfig {
@@ -100,6 +136,7 @@ impl Default for EvmConfig {
 	fn default() -> Self {
 		Self {
 			rpc_urls: Default::default(),
+			rpc_pool: Default::default(),
 			state_machine: StateMachine::Evm(1),
 			consensus_state_id: Default::default(),
 			ismp_host: Default::default(),
@@ -110,6 +147,9 @@ impl Default for EvmConfig {
 			poll_interval: Default::default(),
 			gas_price_buffer: Default::default(),
 			client_type: Default::default(),
+			eip1559: Default::default(),
+			priority_fee_strategy: Default::default(),
+			fee_bump_percentage: Default::default(),
 		}
 	}
 }
@@ -117,9 +157,9 @@ impl Default for EvmConfig {
 /// Core EVM client.
 pub struct EvmClient {
 	/// Execution Rpc client
-	pub client: Arc<Provider<Http>>,
+	pub client: Arc<Provider<FailoverHttp>>,
 	/// Transaction signer
-	pub signer: Arc<SignerMiddleware<Provider<Http>, Wallet<SigningKey>>>,
+	pub signer: Arc<SignerMiddleware<Provider<FailoverHttp>, EvmSigner>>,
 	/// Public Key Address
 	pub address: Vec<u8>,
 	/// Consensus state Id
@@ -142,31 +182,30 @@ pub struct EvmClient {
 	>,
 	/// Tx submission pipeline
 	queue: Option<Arc<PipelineQueue<Vec<Message>, anyhow::Result<Vec<TxReceipt>>>>>,
+	/// Nonce manager for the relayer account
+	pub nonce_manager: Arc<NonceManager>,
 }
 
 impl EvmClient {
 	pub async fn new(config: EvmConfig) -> Result<Self, anyhow::Error> {
 		let config_clone = config.clone();
-		let bytes = match from_hex(config.signer.as_str()) {
-			Ok(bytes) => bytes,
-			Err(_) => {
-				// it's probably a file.
-				let contents = tokio::fs::read_to_string(config.signer.as_str()).await?;
-				from_hex(contents.as_str())?
-			},
-		};
-		let signer = sp_core::ecdsa::Pair::from_seed_slice(&bytes)?;
-		let address = signer.public().to_eth_address().expect("Infallible").to_vec();
-
-		let http_client = Http::new_client_with_chain_middleware(
-			config.rpc_urls.into_iter().map(|url| url.parse()).collect::<Result<_, _>>()?,
+		let signer = config.signer.clone().into_signer(KeyType::Secp256k1).await?;
+		let address = sp_core::ecdsa::Public::try_from(signer.public_key().as_slice())
+			.ok()
+			.and_then(|public| public.to_eth_address().ok())
+			.ok_or_else(|| anyhow::anyhow!("Signer returned an invalid secp256k1 public key"))?
+			.to_vec();
+
+		let transport = FailoverHttp::new(
+			config.rpc_urls,
+			config.rpc_pool.unwrap_or_default(),
 			Some(Duration::from_secs(180)),
-		);
-		let provider = Provider::new(http_client);
+		)?;
+		transport.spawn_health_check();
+		let provider = Provider::new(transport);
 		let client = Arc::new(provider.clone());
 		let chain_id = client.get_chainid().await?.low_u64();
-		let signer = LocalWallet::from(SecretKey::from_slice(signer.seed().as_slice())?)
-			.with_chain_id(chain_id);
+		let signer = EvmSigner::new(signer, H160::from_slice(&address), chain_id);
 		let signer = Arc::new(provider.with_signer(signer));
 		let consensus_state_id = {
 			let mut consensus_state_id: ConsensusStateId = Default::default();
@@ -175,6 +214,8 @@ impl EvmClient {
 		};
 
 		let latest_height = client.get_block_number().await?.as_u64();
+		let nonce_manager =
+			NonceManager::shared(config.state_machine, H160::from_slice(&address), client.clone());
 		let mut partial_client = Self {
 			client,
 			signer,
@@ -187,6 +228,7 @@ impl EvmClient {
 			client_type: config.client_type.unwrap_or_default(),
 			state_machine_update_sender: Arc::new(tokio::sync::Mutex::new(None)),
 			queue: None,
+			nonce_manager,
 		};
 
 		let partial_client_clone = partial_client.clone();
@@ -198,6 +240,16 @@ impl EvmClient {
 		Ok(partial_client)
 	}
 
+	/// Persist the in-flight transactions of the relayer account in the given store, any
+	/// transactions left over from a previous run are reconciled with the chain.
+	pub async fn set_transaction_store(
+		&self,
+		store: Arc<dyn TransactionStore>,
+	) -> Result<(), anyhow::Error> {
+		self.nonce_manager.set_store(store).await;
+		sync_nonces(self).await
+	}
+
 	pub async fn events(&self, from: u64, to: u64) -> Result<Vec<Event>, anyhow::Error> {
 		let client = Arc::new(self.client.clone());
 		let contract = EvmHost::new(self.config.ismp_host, client);
@@ -225,9 +277,7 @@ impl EvmClient {
 		let call = contract.set_consensus_state(consensus_state.clone().into(), height, commitment);
 
 		let gas = call.estimate_gas().await?;
-		call.gas(gas).send().await?.await?;
-
-		Ok(())
+		self.send_call(call.gas(gas).tx).await
 	}
 
 	/// Dispatch a test request to the parachain.
@@ -240,7 +290,14 @@ impl EvmClient {
 		let call = contract.dispatch_to_parachain(para_id.into());
 
 		let gas = call.estimate_gas().await?;
-		call.gas(gas).send().await?.await?;
+		self.send_call(call.gas(gas).tx).await
+	}
+
+	/// Sign and send a transaction from the relayer account with a nonce from the nonce manager
+	/// and wait for it to be mined.
+	async fn send_call(&self, mut tx: TypedTransaction) -> Result<(), anyhow::Error> {
+		current_fees(self).await?.apply(&mut tx);
+		broadcast(self, &mut tx).await?.await?;
 
 		Ok(())
 	}
@@ -364,6 +421,7 @@ impl Clone for EvmClient {
 			client_type: self.client_type.clone(),
 			state_machine_update_sender: self.state_machine_update_sender.clone(),
 			queue: self.queue.clone(),
+			nonce_manager: self.nonce_manager.clone(),
 		}
 	}
 }
@@ -0,0 +1,302 @@
+//! Local nonce management for the relayer account.
+//!
+//! Transactions are signed locally and tracked until they are mined, this lets the relayer
+//! rebroadcast transactions that were dropped from the mempool and resume from the right nonce
+//! after a restart without leaving gaps or submitting the same nonce twice.
+
+use crate::transport::FailoverHttp;
+use ethers::{
+	providers::{Middleware, Provider},
+	types::{BlockNumber, Bytes},
+};
+use ismp::host::StateMachine;
+use primitive_types::{H160, H256};
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	sync::{Arc, OnceLock},
+};
+use tesseract_primitives::{TrackedTransaction, TransactionStore};
+
+/// The nonce managers of every relayer account, shared by all the clients of the account
+static NONCE_MANAGERS: OnceLock<
+	parking_lot::Mutex<BTreeMap<(StateMachine, H160), Arc<NonceManager>>>,
+> = OnceLock::new();
+
+/// The nonces assigned to a relayer account
+#[derive(Default)]
+struct Nonces {
+	/// The next nonce to be assigned, this is unset until the first nonce is requested.
+	next: Option<u64>,
+	/// Nonces that were assigned but never broadcast, the lowest of them is assigned first.
+	released: BTreeSet<u64>,
+}
+
+impl Nonces {
+	/// Assign a nonce, `fresh` is the lowest nonce that is neither pending on the node nor
+	/// tracked as in-flight.
+	fn assign(&mut self, pending: u64, fresh: impl FnOnce() -> u64) -> u64 {
+		// released nonces below the pending nonce have been used since
+		self.released = self.released.split_off(&pending);
+		if let Some(nonce) = self.released.pop_first() {
+			return nonce
+		}
+
+		let nonce = self.next.unwrap_or_else(fresh).max(pending);
+		self.next = Some(nonce + 1);
+		nonce
+	}
+
+	/// Release an assigned nonce that was never broadcast
+	fn release(&mut self, nonce: u64) {
+		if self.next.map_or(false, |next| nonce < next) {
+			self.released.insert(nonce);
+		}
+	}
+}
+
+/// Keeps track of the next nonce and the in-flight transactions of a relayer account
+pub struct NonceManager {
+	/// The chain the account submits transactions to
+	chain: StateMachine,
+	/// The relayer account
+	address: H160,
+	/// Execution Rpc client
+	client: parking_lot::RwLock<Arc<Provider<FailoverHttp>>>,
+	/// Storage for the in-flight transactions
+	store: parking_lot::RwLock<Arc<dyn TransactionStore>>,
+	/// The nonces assigned to the account
+	nonces: tokio::sync::Mutex<Nonces>,
+}
+
+impl NonceManager {
+	/// Create a nonce manager that keeps the in-flight transactions in memory
+	pub fn new(chain: StateMachine, address: H160, client: Arc<Provider<FailoverHttp>>) -> Self {
+		Self {
+			chain,
+			address,
+			client: parking_lot::RwLock::new(client),
+			store: parking_lot::RwLock::new(Arc::new(InMemoryTransactionStore::default())),
+			nonces: tokio::sync::Mutex::new(Nonces::default()),
+		}
+	}
+
+	/// The nonce manager of the relayer account on the chain. Clients of the same account, for
+	/// instance the clients recreated when the config is reloaded, share one nonce manager so
+	/// they never assign the same nonce. The manager uses the rpc client of the latest client.
+	pub fn shared(
+		chain: StateMachine,
+		address: H160,
+		client: Arc<Provider<FailoverHttp>>,
+	) -> Arc<Self> {
+		let mut managers = NONCE_MANAGERS.get_or_init(Default::default).lock();
+		match managers.get(&(chain, address)) {
+			Some(manager) => {
+				*manager.client.write() = client;
+				manager.clone()
+			},
+			None => {
+				let manager = Arc::new(Self::new(chain, address, client));
+				managers.insert((chain, address), manager.clone());
+				manager
+			},
+		}
+	}
+
+	/// Swap the storage for in-flight transactions, the next nonce is recomputed from the new
+	/// store. Released nonces are kept, they are still unused.
+	pub async fn set_store(&self, store: Arc<dyn TransactionStore>) {
+		let mut nonces = self.nonces.lock().await;
+		*self.store.write() = store;
+		nonces.next = None;
+	}
+
+	fn client(&self) -> Arc<Provider<FailoverHttp>> {
+		self.client.read().clone()
+	}
+
+	fn store(&self) -> Arc<dyn TransactionStore> {
+		self.store.read().clone()
+	}
+
+	/// All the transactions currently in-flight for the relayer account
+	pub async fn tracked(&self) -> anyhow::Result<Vec<TrackedTransaction>> {
+		self.store().tracked_transactions(self.chain, self.address.0.to_vec()).await
+	}
+
+	/// Reserve the next nonce for a transaction. Released nonces are assigned first, lowest
+	/// first, so they do not leave gaps. Otherwise this is the highest of the last assigned
+	/// nonce, the pending nonce reported by the node and the nonce after the latest tracked
+	/// transaction.
+	pub async fn next_nonce(&self) -> anyhow::Result<u64> {
+		let mut nonces = self.nonces.lock().await;
+		let pending = self
+			.client()
+			.get_transaction_count(self.address, Some(BlockNumber::Pending.into()))
+			.await?
+			.low_u64();
+		let tracked = match nonces.next {
+			Some(_) => 0,
+			None => self
+				.tracked()
+				.await?
+				.into_iter()
+				.map(|tx| tx.nonce + 1)
+				.max()
+				.unwrap_or_default(),
+		};
+
+		Ok(nonces.assign(pending, || tracked))
+	}
+
+	/// Release a freshly assigned nonce whose transaction could not be broadcast, so that it is
+	/// assigned again to the next transaction.
+	pub async fn release(&self, nonce: u64) {
+		self.nonces.lock().await.release(nonce);
+	}
+
+	/// Track a signed transaction that has been broadcast, replacing any transaction that was
+	/// previously tracked with the same nonce.
+	pub async fn track(&self, nonce: u64, hash: H256, payload: Vec<u8>) -> anyhow::Result<()> {
+		self.store()
+			.track_transaction(TrackedTransaction {
+				chain: self.chain,
+				signer: self.address.0.to_vec(),
+				nonce,
+				hash,
+				payload,
+			})
+			.await
+	}
+
+	/// Reconcile the tracked transactions with the chain. Transactions whose nonce has been used
+	/// by a mined transaction are either included or were replaced, so they are no longer
+	/// tracked. Transactions that are unknown to the node were dropped from the mempool and
+	/// are rebroadcast with the same payload.
+	///
+	/// Returns the dropped transactions that could not be rebroadcast. They stay tracked so
+	/// their nonce is not assigned again, they should be replaced with a newly signed
+	/// transaction or the nonces after them can never be mined.
+	pub async fn sync(&self) -> anyhow::Result<Vec<TrackedTransaction>> {
+		let mut stuck = vec![];
+		let client = self.client();
+		let latest = client
+			.get_transaction_count(self.address, Some(BlockNumber::Latest.into()))
+			.await?
+			.low_u64();
+
+		for tx in self.tracked().await? {
+			if tx.nonce < latest {
+				if client.get_transaction_receipt(tx.hash).await?.is_none() {
+					log::info!(
+						"Transaction {:?} with nonce {} on {:?} was replaced",
+						tx.hash,
+						tx.nonce,
+						self.chain
+					);
+				}
+				self.store()
+					.untrack_transaction(self.chain, self.address.0.to_vec(), tx.nonce)
+					.await?;
+				continue
+			}
+
+			if client.get_transaction(tx.hash).await?.is_some() {
+				continue
+			}
+
+			log::info!(
+				"Transaction {:?} with nonce {} was dropped by {:?}, rebroadcasting",
+				tx.hash,
+				tx.nonce,
+				self.chain
+			);
+			if let Err(err) = client.send_raw_transaction(Bytes::from(tx.payload.clone())).await {
+				log::error!(
+					"Failed to rebroadcast transaction {:?} to {:?}: {err:?}",
+					tx.hash,
+					self.chain
+				);
+				stuck.push(tx);
+			}
+		}
+
+		Ok(stuck)
+	}
+}
+
+/// A [`TransactionStore`] that does not survive restarts
+#[derive(Default)]
+pub struct InMemoryTransactionStore {
+	transactions: parking_lot::Mutex<BTreeMap<(String, Vec<u8>, u64), TrackedTransaction>>,
+}
+
+#[async_trait::async_trait]
+impl TransactionStore for InMemoryTransactionStore {
+	async fn tracked_transactions(
+		&self,
+		chain: StateMachine,
+		signer: Vec<u8>,
+	) -> anyhow::Result<Vec<TrackedTransaction>> {
+		let chain = chain.to_string();
+		let tracked = self
+			.transactions
+			.lock()
+			.values()
+			.filter(|tx| tx.chain.to_string() == chain && tx.signer == signer)
+			.cloned()
+			.collect();
+
+		Ok(tracked)
+	}
+
+	async fn track_transaction(&self, tx: TrackedTransaction) -> anyhow::Result<()> {
+		self.transactions
+			.lock()
+			.insert((tx.chain.to_string(), tx.signer.clone(), tx.nonce), tx);
+		Ok(())
+	}
+
+	async fn untrack_transaction(
+		&self,
+		chain: StateMachine,
+		signer: Vec<u8>,
+		nonce: u64,
+	) -> anyhow::Result<()> {
+		self.transactions.lock().remove(&(chain.to_string(), signer, nonce));
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Nonces;
+
+	#[test]
+	fn released_nonces_are_assigned_first() {
+		let mut nonces = Nonces::default();
+		assert_eq!(nonces.assign(5, || 0), 5);
+		assert_eq!(nonces.assign(5, || 0), 6);
+		assert_eq!(nonces.assign(5, || 0), 7);
+
+		// nonce 5 failed while 6 and 7 are in flight
+		nonces.release(5);
+		assert_eq!(nonces.assign(5, || 0), 5);
+		assert_eq!(nonces.assign(5, || 0), 8);
+
+		// released nonces that have been used since are skipped
+		nonces.release(8);
+		nonces.release(6);
+		assert_eq!(nonces.assign(7, || 0), 8);
+		assert_eq!(nonces.assign(7, || 0), 9);
+	}
+
+	#[test]
+	fn first_nonce_follows_the_tracked_transactions() {
+		let mut nonces = Nonces::default();
+		assert_eq!(nonces.assign(3, || 10), 10);
+		assert_eq!(nonces.assign(3, || 20), 11);
+		// nonces that were never assigned are not released
+		nonces.release(12);
+		assert_eq!(nonces.assign(3, || 0), 12);
+	}
+}
@@ -39,6 +39,7 @@ use ismp::{
 	messaging::CreateConsensusState,
 };
 use primitive_types::U256;
+use rpc_utils::EndpointStatus;
 use sp_core::{H160, H256};
 use std::{collections::BTreeMap, sync::Arc, time::Duration};
 use tesseract_primitives::{
@@ -372,11 +373,13 @@ impl IsmpProvider for EvmClient {
 		};
 
 		let calls = generate_contract_calls(self, messages, true).await?;
+		let strategy = self.config.priority_fee_strategy.clone().unwrap_or_default();
 		let gas_breakdown = get_current_gas_cost_in_usd(
 			self.state_machine,
 			&self.config.etherscan_api_key.clone(),
 			self.client.clone(),
 			self.config.gas_price_buffer,
+			self.config.eip1559.unwrap_or_default().then_some(&strategy),
 		)
 		.await?;
 		let mut gas_estimates = vec![];
@@ -465,6 +468,7 @@ impl IsmpProvider for EvmClient {
 							gas_cost_for_data_in_usd;
 						Ok::<_, Error>(EstimateGasReturnParams {
 							execution_cost,
+							gas: gas_to_be_used,
 							successful_execution,
 						})
 					})
@@ -751,14 +755,9 @@ impl IsmpProvider for EvmClient {
 		self.address.clone()
 	}
 
-	fn sign(&self, msg: &[u8]) -> Signature {
-		let signature = self
-			.signer
-			.signer()
-			.sign_hash(H256::from_slice(msg))
-			.expect("Infallible")
-			.to_vec();
-		Signature::Evm { address: self.address.clone(), signature }
+	async fn sign(&self, msg: &[u8]) -> Result<Signature, anyhow::Error> {
+		let signature = self.signer.signer().sign_hash(H256::from_slice(msg)).await?.to_vec();
+		Ok(Signature::Evm { address: self.address.clone(), signature })
 	}
 
 	async fn set_latest_finalized_height(
@@ -847,6 +846,10 @@ impl IsmpProvider for EvmClient {
 	fn max_concurrent_queries(&self) -> usize {
 		self.config.tracing_batch_size.unwrap_or(10)
 	}
+
+	fn rpc_endpoints(&self) -> Vec<EndpointStatus> {
+		self.client.as_ref().as_ref().pool().status()
+	}
 }
 
 pub enum CheckTraceForEventParams {
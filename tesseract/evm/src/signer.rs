@@ -0,0 +1,156 @@
+//! Adapter that lets the pluggable relayer signers sign ethereum transactions.
+
+use ethers::{
+	signers::to_eip155_v,
+	types::{
+		transaction::{eip2718::TypedTransaction, eip712::Eip712},
+		Address, Signature, H256, U256,
+	},
+	utils::hash_message,
+};
+use std::{fmt, sync::Arc};
+use tesseract_primitives::signer::Signer;
+
+/// Error returned when the relayer signer fails to produce a signature
+#[derive(Debug)]
+pub struct SignerError(pub String);
+
+impl fmt::Display for SignerError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+impl std::error::Error for SignerError {}
+
+/// An ethereum signer backed by a secp256k1 relayer [`Signer`]
+#[derive(Clone)]
+pub struct EvmSigner {
+	/// The underlying signer
+	signer: Arc<dyn Signer>,
+	/// The address of the signer
+	address: Address,
+	/// The chain id transactions are signed for
+	chain_id: u64,
+}
+
+impl fmt::Debug for EvmSigner {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("EvmSigner")
+			.field("address", &self.address)
+			.field("chain_id", &self.chain_id)
+			.finish()
+	}
+}
+
+impl EvmSigner {
+	pub fn new(signer: Arc<dyn Signer>, address: Address, chain_id: u64) -> Self {
+		Self { signer, address, chain_id }
+	}
+
+	/// Signs the provided hash, `v` is set to the recovery id + 27. Signers that already offset `v`
+	/// by 27 are left as is.
+	pub async fn sign_hash(&self, hash: H256) -> Result<Signature, SignerError> {
+		let signature = self
+			.signer
+			.sign(hash.as_bytes())
+			.await
+			.map_err(|err| SignerError(err.to_string()))?;
+		if signature.len() != 65 {
+			Err(SignerError(format!("Invalid signature length {}", signature.len())))?
+		}
+
+		Ok(Signature {
+			r: U256::from_big_endian(&signature[..32]),
+			s: U256::from_big_endian(&signature[32..64]),
+			v: if signature[64] < 27 { signature[64] as u64 + 27 } else { signature[64] as u64 },
+		})
+	}
+}
+
+#[async_trait::async_trait]
+impl ethers::signers::Signer for EvmSigner {
+	type Error = SignerError;
+
+	async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+		&self,
+		message: S,
+	) -> Result<Signature, Self::Error> {
+		self.sign_hash(hash_message(message.as_ref())).await
+	}
+
+	async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, Self::Error> {
+		// rlp (for sighash) must have the same chain id as v in the signature
+		let chain_id = tx.chain_id().map(|id| id.as_u64()).unwrap_or(self.chain_id);
+		let mut tx = tx.clone();
+		tx.set_chain_id(chain_id);
+
+		let mut signature = self.sign_hash(tx.sighash()).await?;
+		signature.v = to_eip155_v(signature.v as u8 - 27, chain_id);
+		Ok(signature)
+	}
+
+	async fn sign_typed_data<T: Eip712 + Send + Sync>(
+		&self,
+		payload: &T,
+	) -> Result<Signature, Self::Error> {
+		let encoded = payload.encode_eip712().map_err(|err| SignerError(err.to_string()))?;
+		self.sign_hash(H256::from(encoded)).await
+	}
+
+	fn address(&self) -> Address {
+		self.address
+	}
+
+	fn chain_id(&self) -> u64 {
+		self.chain_id
+	}
+
+	fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+		self.chain_id = chain_id.into();
+		self
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tesseract_primitives::signer::{KeyType, LocalSigner};
+
+	/// Offsets `v` by 27 like most ethereum signers
+	struct OffsetSigner(LocalSigner);
+
+	#[async_trait::async_trait]
+	impl Signer for OffsetSigner {
+		fn key_type(&self) -> KeyType {
+			self.0.key_type()
+		}
+
+		fn public_key(&self) -> Vec<u8> {
+			self.0.public_key()
+		}
+
+		async fn sign(&self, message: &[u8]) -> anyhow::Result<Vec<u8>> {
+			let mut signature = self.0.sign(message).await?;
+			signature[64] += 27;
+			Ok(signature)
+		}
+	}
+
+	#[tokio::test]
+	async fn should_offset_v_once() {
+		let local = LocalSigner::from_seed(KeyType::Secp256k1, &[7u8; 32]).unwrap();
+		let hash = H256::repeat_byte(1);
+		let expected = EvmSigner::new(Arc::new(local.clone()), Address::zero(), 1)
+			.sign_hash(hash)
+			.await
+			.unwrap();
+		assert!(expected.v == 27 || expected.v == 28);
+
+		let signature = EvmSigner::new(Arc::new(OffsetSigner(local)), Address::zero(), 1)
+			.sign_hash(hash)
+			.await
+			.unwrap();
+		assert_eq!(signature, expected);
+	}
+}
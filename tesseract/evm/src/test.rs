@@ -15,7 +15,7 @@ use ismp_solidity_abi::evm_host::EvmHost;
 use ismp_testsuite::mocks::{Host, Keccak256Hasher};
 use primitive_types::H160;
 use std::str::FromStr;
-use tesseract_primitives::{IsmpProvider, Query};
+use tesseract_primitives::{signer::SignerConfig, IsmpProvider, Query};
 
 // source :
 // 45544845
@@ -45,7 +45,9 @@ async fn test_ismp_state_proof() {
 		state_machine: StateMachine::Evm(1),
 		consensus_state_id: "SYNC".to_string(),
 		ismp_host: ISMP_HOST,
-		signer: "2e0834786285daccd064ca17f1654f67b4aef298acbb82cef9ec422fb4975622".to_string(),
+		signer: SignerConfig::PrivateKey(
+			"2e0834786285daccd064ca17f1654f67b4aef298acbb82cef9ec422fb4975622".to_string(),
+		),
 		..Default::default()
 	};
 
@@ -140,7 +142,9 @@ async fn fetch_state_commitment() -> anyhow::Result<()> {
 		state_machine: StateMachine::Evm(1),
 		consensus_state_id: "ETH0".to_string(),
 		ismp_host: NEW_HOST,
-		signer: "2e0834786285daccd064ca17f1654f67b4aef298acbb82cef9ec422fb4975622".to_string(),
+		signer: SignerConfig::PrivateKey(
+			"2e0834786285daccd064ca17f1654f67b4aef298acbb82cef9ec422fb4975622".to_string(),
+		),
 		..Default::default()
 	};
 
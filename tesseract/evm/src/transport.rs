@@ -0,0 +1,95 @@
+//! Http transport that spreads requests across all the configured rpc endpoints.
+
+use ethers::{
+	providers::{Http, JsonRpcClient, RpcError},
+	types::U64,
+};
+use rpc_utils::{EndpointPool, ErrorKind, PoolConfig};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+/// Json rpc error codes returned by providers when a rate limit is hit
+const RATE_LIMIT_ERROR_CODES: [i64; 2] = [429, -32005];
+
+/// Methods that broadcast a transaction. A transaction that reached an endpoint which failed to
+/// respond may still be broadcast, so these are never retried on the other endpoints.
+const SEND_METHODS: [&str; 2] = ["eth_sendRawTransaction", "eth_sendTransaction"];
+
+/// A [`JsonRpcClient`] that fails over between multiple http endpoints of the same chain. Each
+/// request is sent to the healthiest endpoint, rate limited or unreachable endpoints are put on a
+/// cooldown and the request is retried on the others with a backoff. Transactions are only sent
+/// to the healthiest endpoint.
+#[derive(Debug, Clone)]
+pub struct FailoverHttp {
+	/// Health records of the endpoints
+	pool: Arc<EndpointPool>,
+	/// Http client for each endpoint
+	clients: Arc<Vec<Http>>,
+}
+
+impl FailoverHttp {
+	/// Create a transport over the given endpoints
+	pub fn new(
+		urls: Vec<String>,
+		config: PoolConfig,
+		timeout: Option<Duration>,
+	) -> Result<Self, anyhow::Error> {
+		let clients = urls
+			.iter()
+			.map(|url| Ok(Http::new_client_with_chain_middleware(vec![url.parse()?], timeout)))
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+		let pool = Arc::new(EndpointPool::new(urls, config)?);
+
+		Ok(Self { pool, clients: Arc::new(clients) })
+	}
+
+	/// The endpoints of this transport
+	pub fn pool(&self) -> Arc<EndpointPool> {
+		self.pool.clone()
+	}
+
+	/// Periodically check the block height of every endpoint so that endpoints lagging behind
+	/// the others are avoided.
+	pub fn spawn_health_check(&self) {
+		let clients = self.clients.clone();
+		self.pool.spawn_health_check(move |index| {
+			let clients = clients.clone();
+			async move {
+				let number: U64 = clients[index].request("eth_blockNumber", ()).await?;
+				Ok(number.low_u64())
+			}
+		});
+	}
+}
+
+fn classify<E: RpcError>(err: &E) -> ErrorKind {
+	match err.as_error_response() {
+		Some(response) if RATE_LIMIT_ERROR_CODES.contains(&response.code) => ErrorKind::Transient,
+		// The endpoint has not imported the requested block yet
+		Some(response) if response.message.contains("header not found") => ErrorKind::Unavailable,
+		Some(_) => ErrorKind::Fatal,
+		None => ErrorKind::Transient,
+	}
+}
+
+#[async_trait::async_trait]
+impl JsonRpcClient for FailoverHttp {
+	type Error = <Http as JsonRpcClient>::Error;
+
+	async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+	where
+		T: Debug + Serialize + Send + Sync,
+		R: DeserializeOwned + Send,
+	{
+		let params = &params;
+		if SEND_METHODS.contains(&method) {
+			return self
+				.pool
+				.execute_once(move |index| self.clients[index].request(method, params), classify)
+				.await
+		}
+		self.pool
+			.execute(move |index| self.clients[index].request(method, params), classify)
+			.await
+	}
+}
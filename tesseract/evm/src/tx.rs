@@ -1,19 +1,20 @@
 use crate::{
 	gas_oracle::{ARBITRUM_CHAIN_ID, ARBITRUM_SEPOLIA_CHAIN_ID, CHIADO_CHAIN_ID, GNOSIS_CHAIN_ID},
+	signer::EvmSigner,
+	transport::FailoverHttp,
 	EvmClient,
 };
 use anyhow::anyhow;
 use codec::Decode;
 use ethers::{
 	contract::{parse_log, FunctionCall},
-	core::k256::ecdsa,
 	middleware::SignerMiddleware,
 	prelude::{
-		signer::SignerMiddlewareError, transaction::eip2718::TypedTransaction, ContractError, Log,
-		NameOrAddress, Provider, ProviderError, Wallet,
+		transaction::eip2718::TypedTransaction, Log, NameOrAddress, Provider, ProviderError,
 	},
-	providers::{Http, Middleware, PendingTransaction},
-	types::{TransactionReceipt, TransactionRequest},
+	providers::{Middleware, PendingTransaction},
+	signers::Signer,
+	types::{Eip1559TransactionRequest, TransactionReceipt, TransactionRequest},
 };
 use ismp::{
 	host::StateMachine,
@@ -32,61 +33,304 @@ use mmr_primitives::mmr_position_to_k_index;
 use pallet_ismp::mmr::{LeafIndexAndPos, Proof as MmrProof};
 use primitive_types::{H160, H256, U256};
 use sp_mmr_primitives::utils::NodesUtils;
-use std::{collections::BTreeSet, sync::Arc, time::Duration};
-use tesseract_primitives::{Hasher, Query, TxReceipt};
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+use tesseract_primitives::{GasUsage, Hasher, Query, TxReceipt};
 
-use crate::gas_oracle::get_current_gas_cost_in_usd;
+use crate::gas_oracle::{get_current_gas_cost_in_usd, GasBreakdown};
 
 /// Type alias
 type SolidityFunctionCall = FunctionCall<
-	Arc<SignerMiddleware<Provider<Http>, Wallet<ecdsa::SigningKey>>>,
-	SignerMiddleware<Provider<Http>, Wallet<ecdsa::SigningKey>>,
+	Arc<SignerMiddleware<Provider<FailoverHttp>, EvmSigner>>,
+	SignerMiddleware<Provider<FailoverHttp>, EvmSigner>,
 	(),
 >;
 
-#[async_recursion::async_recursion]
-pub async fn submit_messages(
-	client: &EvmClient,
-	messages: Vec<Message>,
-) -> anyhow::Result<BTreeSet<H256>> {
-	let calls = generate_contract_calls(client, messages.clone(), false).await?;
-	let mut events = BTreeSet::new();
-	for (index, call) in calls.into_iter().enumerate() {
-		let gas_price = call.tx.gas_price();
-		match call.clone().send().await {
-			Ok(progress) => {
-				let retry = if matches!(messages[index], Message::Consensus(_)) {
-					Some(call)
-				} else {
-					None
-				};
-				let evs = wait_for_success(client, progress, gas_price, retry).await?;
-				events.extend(evs);
+/// Default percentage by which the fees of a stuck transaction are bumped when it is replaced
+const DEFAULT_FEE_BUMP_PERCENTAGE: u32 = 25;
+
+/// Minimum percentage by which nodes require every fee of a replacement transaction to be bumped
+const MIN_FEE_BUMP_PERCENTAGE: u32 = 10;
+
+/// The fees a transaction is submitted with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionFees {
+	/// Legacy transaction paying a single gas price
+	Legacy { gas_price: U256 },
+	/// EIP-1559 transaction paying the base fee plus a priority fee, capped at the max fee
+	Eip1559 { max_fee_per_gas: U256, max_priority_fee_per_gas: U256 },
+}
+
+impl TransactionFees {
+	/// Select the fees for a transaction from the gas breakdown. EIP-1559 fees are only used if
+	/// they are enabled and the chain reports a base fee.
+	pub fn new(breakdown: &GasBreakdown, eip1559: bool) -> Self {
+		match breakdown.max_fee_per_gas() {
+			Some(max_fee_per_gas) if eip1559 => TransactionFees::Eip1559 {
+				max_fee_per_gas,
+				max_priority_fee_per_gas: breakdown.priority_fee,
 			},
-			Err(err) => {
-				match err {
-					ContractError::MiddlewareError {
-						e:
-							SignerMiddlewareError::MiddlewareError(ProviderError::JsonRpcClientError(
-								ref error,
-							)),
-					} => {
-						if let Some(err) = error.as_error_response() {
-							// https://docs.alchemy.com/reference/error-reference#http-status-codes
-							if err.code == 429 {
-								// we should retry.
-								log::info!("Retrying tx submission, got error: {err:?}");
-								return submit_messages(&client, messages).await;
-							}
-						}
-					},
-					_ => {},
+			_ => TransactionFees::Legacy { gas_price: breakdown.gas_price },
+		}
+	}
+
+	/// Returns the fees of a transaction, if they have been set
+	pub fn of(tx: &TypedTransaction) -> Option<Self> {
+		match tx {
+			TypedTransaction::Eip1559(tx) => Some(TransactionFees::Eip1559 {
+				max_fee_per_gas: tx.max_fee_per_gas?,
+				max_priority_fee_per_gas: tx.max_priority_fee_per_gas?,
+			}),
+			tx => Some(TransactionFees::Legacy { gas_price: tx.gas_price()? }),
+		}
+	}
+
+	/// Returns the fees bumped by the given percentage, rounded up
+	pub fn bump(self, percentage: u32) -> Self {
+		let bump = |fee: U256| fee + (fee * U256::from(percentage) + 99u32) / U256::from(100u32);
+		match self {
+			TransactionFees::Legacy { gas_price } =>
+				TransactionFees::Legacy { gas_price: bump(gas_price) },
+			TransactionFees::Eip1559 { max_fee_per_gas, max_priority_fee_per_gas } =>
+				TransactionFees::Eip1559 {
+					max_fee_per_gas: bump(max_fee_per_gas),
+					max_priority_fee_per_gas: bump(max_priority_fee_per_gas),
+				},
+		}
+	}
+
+	/// Returns the fees for replacing a pending transaction that pays these fees. Nodes only
+	/// accept a replacement with the same nonce if every fee is bumped, so the replacement pays
+	/// the higher of the bumped fees and the `current` fees of the network. The fees are bumped
+	/// by at least the minimum percentage nodes accept.
+	pub fn replacement(self, current: Self, percentage: u32) -> Self {
+		match (self.bump(percentage.max(MIN_FEE_BUMP_PERCENTAGE)), current) {
+			(
+				TransactionFees::Legacy { gas_price: bumped },
+				TransactionFees::Legacy { gas_price: current },
+			) => TransactionFees::Legacy { gas_price: bumped.max(current) },
+			(
+				TransactionFees::Eip1559 {
+					max_fee_per_gas: bumped_max_fee,
+					max_priority_fee_per_gas: bumped_priority_fee,
+				},
+				TransactionFees::Eip1559 { max_fee_per_gas, max_priority_fee_per_gas },
+			) => {
+				let max_priority_fee_per_gas = bumped_priority_fee.max(max_priority_fee_per_gas);
+				TransactionFees::Eip1559 {
+					max_fee_per_gas: bumped_max_fee
+						.max(max_fee_per_gas)
+						.max(max_priority_fee_per_gas),
+					max_priority_fee_per_gas,
 				}
+			},
+			(bumped, _) => bumped,
+		}
+	}
 
-				Err(err)?
+	/// The maximum price per gas the transaction can pay
+	pub fn max_gas_price(&self) -> U256 {
+		match self {
+			TransactionFees::Legacy { gas_price } => *gas_price,
+			TransactionFees::Eip1559 { max_fee_per_gas, .. } => *max_fee_per_gas,
+		}
+	}
+
+	/// Sets the fees on a transaction, converting it to the matching transaction type
+	pub fn apply(&self, tx: &mut TypedTransaction) {
+		match *self {
+			TransactionFees::Legacy { gas_price } => {
+				if !matches!(tx, TypedTransaction::Legacy(_)) {
+					*tx = TypedTransaction::Legacy(TransactionRequest {
+						from: tx.from().copied(),
+						to: tx.to().cloned(),
+						gas: tx.gas().copied(),
+						value: tx.value().copied(),
+						data: tx.data().cloned(),
+						nonce: tx.nonce().copied(),
+						chain_id: tx.chain_id(),
+						..Default::default()
+					});
+				}
+				tx.set_gas_price(gas_price);
+			},
+			TransactionFees::Eip1559 { max_fee_per_gas, max_priority_fee_per_gas } => {
+				let mut request = match tx {
+					TypedTransaction::Eip1559(request) => request.clone(),
+					tx => Eip1559TransactionRequest {
+						from: tx.from().copied(),
+						to: tx.to().cloned(),
+						gas: tx.gas().copied(),
+						value: tx.value().copied(),
+						data: tx.data().cloned(),
+						nonce: tx.nonce().copied(),
+						access_list: tx.access_list().cloned().unwrap_or_default(),
+						chain_id: tx.chain_id(),
+						..Default::default()
+					},
+				};
+				request.max_fee_per_gas = Some(max_fee_per_gas);
+				request.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+				*tx = TypedTransaction::Eip1559(request);
 			},
 		}
 	}
+}
+
+/// Returns the fees new transactions should be submitted with
+pub async fn current_fees(client: &EvmClient) -> anyhow::Result<TransactionFees> {
+	let eip1559 = client.config.eip1559.unwrap_or_default();
+	let strategy = client.config.priority_fee_strategy.clone().unwrap_or_default();
+	let breakdown = get_current_gas_cost_in_usd(
+		client.state_machine,
+		&client.config.etherscan_api_key.clone(),
+		client.client.clone(),
+		client.config.gas_price_buffer,
+		eip1559.then_some(&strategy),
+	)
+	.await?;
+
+	Ok(TransactionFees::new(&breakdown, eip1559))
+}
+
+/// Returns the fees for replacing the pending transaction with the same nonce
+async fn replacement_fees(
+	client: &EvmClient,
+	tx: &TypedTransaction,
+) -> anyhow::Result<TransactionFees> {
+	let current = current_fees(client).await?;
+	let percentage = client.config.fee_bump_percentage.unwrap_or(DEFAULT_FEE_BUMP_PERCENTAGE);
+
+	Ok(TransactionFees::of(tx)
+		.map(|fees| fees.replacement(current, percentage))
+		.unwrap_or(current))
+}
+
+/// Signs and broadcasts the transaction from the relayer account. Transactions without a nonce
+/// are assigned the next nonce from the nonce manager, transactions with a nonce replace the
+/// in-flight transaction with the same nonce. The signed payload is tracked until the
+/// transaction is mined so it can be rebroadcast if it gets dropped.
+pub async fn broadcast<'a>(
+	client: &'a EvmClient,
+	tx: &mut TypedTransaction,
+) -> Result<PendingTransaction<'a, FailoverHttp>, ProviderError> {
+	let fresh = tx.nonce().is_none();
+	if fresh {
+		let nonce = client
+			.nonce_manager
+			.next_nonce()
+			.await
+			.map_err(|err| ProviderError::CustomError(err.to_string()))?;
+		tx.set_nonce(nonce);
+	}
+	let nonce = tx.nonce().copied().unwrap_or_default().low_u64();
+	tx.set_from(H160::from_slice(&client.address));
+	tx.set_chain_id(client.chain_id);
+
+	let signature = client
+		.signer
+		.signer()
+		.sign_transaction(tx)
+		.await
+		.map_err(|err| ProviderError::CustomError(err.to_string()))?;
+	let payload = tx.rlp_signed(&signature);
+
+	match client.client.send_raw_transaction(payload.clone()).await {
+		Ok(pending) => {
+			if let Err(err) =
+				client.nonce_manager.track(nonce, pending.tx_hash(), payload.to_vec()).await
+			{
+				log::error!("Failed to track transaction on {:?}: {err:?}", client.state_machine);
+			}
+			Ok(pending)
+		},
+		Err(err) => {
+			if fresh {
+				client.nonce_manager.release(nonce).await;
+			}
+			Err(err)
+		},
+	}
+}
+
+/// Returns the commitments of the requests and responses handled in the transaction, along with
+/// their share of the gas spent by the transaction
+fn receipt_events(receipt: &TransactionReceipt) -> BTreeMap<H256, GasUsage> {
+	let commitments = receipt
+		.logs
+		.iter()
+		.filter_map(|l| {
+			let log = Log { topics: l.clone().topics, data: l.clone().data, ..Default::default() };
+			if let Some(ev) = parse_log::<PostRequestHandledFilter>(log.clone()).ok() {
+				return Some(ev.commitment.into())
+			}
+			if let Some(ev) = parse_log::<PostResponseHandledFilter>(log.clone()).ok() {
+				return Some(ev.commitment.into())
+			}
+			None
+		})
+		.collect::<Vec<H256>>();
+	if commitments.is_empty() {
+		return Default::default()
+	}
+
+	let gas = GasUsage {
+		gas_used: receipt.gas_used.unwrap_or_default() / commitments.len(),
+		gas_price: receipt.effective_gas_price.unwrap_or_default(),
+	};
+	commitments.into_iter().map(|commitment| (commitment, gas)).collect()
+}
+
+/// Rebroadcasts the transactions that were dropped from the mempool. Dropped transactions that
+/// can no longer be broadcast are replaced with a cancellation signed with the current fees, so
+/// that their nonce is still used and the transactions after them can be mined.
+pub async fn sync_nonces(client: &EvmClient) -> anyhow::Result<()> {
+	for stuck in client.nonce_manager.sync().await? {
+		let mut cancellation = TypedTransaction::Legacy(TransactionRequest {
+			to: Some(NameOrAddress::Address(H160::from_slice(&client.address))),
+			value: Some(Default::default()),
+			nonce: Some(stuck.nonce.into()),
+			gas: Some(21_000u64.into()),
+			..Default::default()
+		});
+		current_fees(client).await?.apply(&mut cancellation);
+		match broadcast(client, &mut cancellation).await {
+			Ok(pending) => log::info!(
+				"Replaced dropped transaction {:?} with nonce {} on {:?} with {:?}",
+				stuck.hash,
+				stuck.nonce,
+				client.state_machine,
+				pending.tx_hash()
+			),
+			// The transaction stays tracked and is replaced on the next sync
+			Err(err) => log::error!(
+				"Failed to replace dropped transaction {:?} on {:?}: {err:?}",
+				stuck.hash,
+				client.state_machine
+			),
+		}
+	}
+
+	Ok(())
+}
+
+pub async fn submit_messages(
+	client: &EvmClient,
+	messages: Vec<Message>,
+) -> anyhow::Result<BTreeMap<H256, GasUsage>> {
+	// Rebroadcast any dropped transactions before new nonces are assigned
+	if let Err(err) = sync_nonces(client).await {
+		log::error!("Failed to sync nonce manager for {:?}: {err:?}", client.state_machine);
+	}
+	let calls = generate_contract_calls(client, messages.clone(), false).await?;
+	let mut events = BTreeMap::new();
+	for (index, call) in calls.into_iter().enumerate() {
+		let mut tx = call.tx;
+		// Rate limited requests are retried by the transport
+		let progress = broadcast(client, &mut tx).await?;
+		let retry = matches!(messages[index], Message::Consensus(_));
+		let evs = wait_for_success(client, progress, tx, retry).await?;
+		events.extend(evs);
+	}
 
 	if !events.is_empty() {
 		log::trace!("Got {} receipts from executing on {:?}", events.len(), client.state_machine);
@@ -97,11 +341,11 @@ pub async fn submit_messages(
 
 #[async_recursion::async_recursion]
 async fn wait_for_success<'a>(
-	client: &EvmClient,
-	tx: PendingTransaction<'a, Http>,
-	gas_price: Option<U256>,
-	retry: Option<SolidityFunctionCall>,
-) -> Result<BTreeSet<H256>, anyhow::Error>
+	client: &'a EvmClient,
+	pending: PendingTransaction<'a, FailoverHttp>,
+	tx: TypedTransaction,
+	retry: bool,
+) -> Result<BTreeMap<H256, GasUsage>, anyhow::Error>
 where
 	'a: 'async_recursion,
 {
@@ -121,45 +365,47 @@ where
 		Ok(())
 	};
 
+	let tx_hash = pending.tx_hash();
 	let client_clone = client.clone();
 
 	let handle_failed_tx = move || async move {
 		log::info!("No receipt for transaction on {:?}", client_clone.state_machine);
 
-		if let Some(call) = retry {
-			// lets retry
-			let gas_price = get_current_gas_cost_in_usd(
-				client.state_machine,
-				&client.config.etherscan_api_key.clone(),
-				client.client.clone(),
-				client.config.gas_price_buffer,
-			)
-			.await?
-			.gas_price * 2; // for good measure
+		let fees = replacement_fees(client, &tx).await?;
+		if retry {
+			// lets replace the transaction, it keeps its nonce
+			let mut tx = tx;
+			fees.apply(&mut tx);
 			log::info!(
-				"Retrying consensus message on {:?} with gas {}",
+				"Replacing consensus message on {:?} with max gas price {}",
 				client_clone.state_machine,
-				ethers::utils::format_units(gas_price, "gwei")?
+				ethers::utils::format_units(fees.max_gas_price(), "gwei")?
 			);
-			let call = call.gas_price(gas_price);
-			let pending = call.send().await?;
+			match broadcast(client, &mut tx).await {
+				// don't retry in the next callstack
+				Ok(pending) => wait_for_success(client, pending, tx, false).await,
+				Err(err) => {
+					// the original transaction may have been included in the meantime
+					if let Some(receipt) = client.client.get_transaction_receipt(tx_hash).await? {
+						let events = receipt_events(&receipt);
+						log_receipt(receipt, false)?;
+						return Ok(events)
+					}
 
-			// don't retry in the next callstack
-			wait_for_success(client, pending, Some(gas_price), None).await
+					Err(err)?
+				},
+			}
 		} else {
-			// cancel the transaction here
-			let pending = client_clone
-				.signer
-				.send_transaction(
-					TypedTransaction::Legacy(TransactionRequest {
-						to: Some(NameOrAddress::Address(H160::from_slice(&client_clone.address))),
-						value: Some(Default::default()),
-						gas_price: gas_price.map(|price| price * 10), // experiment with higher?
-						..Default::default()
-					}),
-					None,
-				)
-				.await;
+			// cancel the transaction here by replacing it with an empty transfer
+			let mut cancellation = TypedTransaction::Legacy(TransactionRequest {
+				to: Some(NameOrAddress::Address(H160::from_slice(&client_clone.address))),
+				value: Some(Default::default()),
+				nonce: tx.nonce().copied(),
+				gas: Some(21_000u64.into()),
+				..Default::default()
+			});
+			fees.apply(&mut cancellation);
+			let pending = broadcast(client, &mut cancellation).await;
 
 			if let Ok(pending) = pending {
 				if let Ok(Some(receipt)) = pending.await {
@@ -179,23 +425,10 @@ where
 		_ = sleep => {
 			return handle_failed_tx().await;
 		},
-		result = tx => {
+		result = pending => {
 			match result {
 				Ok(Some(receipt)) => {
-					let events =  receipt.logs.iter().filter_map(|l| {
-						let log = Log {
-							topics: l.clone().topics,
-							data: l.clone().data,
-							..Default::default()
-						};
-						if let Some(ev) = parse_log::<PostRequestHandledFilter>(log.clone()).ok() {
-							return Some(ev.commitment.into())
-						}
-						if let Some(ev) = parse_log::<PostResponseHandledFilter>(log.clone()).ok() {
-							return Some(ev.commitment.into())
-						}
-						None
-					}).collect();
+					let events = receipt_events(&receipt);
 					log_receipt(receipt, false)?;
 					Ok(events)
 				},
@@ -227,18 +460,13 @@ pub async fn generate_contract_calls(
 	// does https://github.com/ledgerwatch/erigon/blob/cfb55a3cd44736ac092003be41659cc89061d1be/core/state_transition.go#L246
 	// Erigon does not support block overrides when tracing so we don't have the option of omiting
 	// the gas price by overriding the base fee
-	let set_gas_price = || !debug_trace || client.client_type.erigon();
-	let gas_price = if set_gas_price() {
-		get_current_gas_cost_in_usd(
-			client.state_machine,
-			&client.config.etherscan_api_key.clone(),
-			client.client.clone(),
-			client.config.gas_price_buffer,
-		)
-		.await?
-		.gas_price
-	} else {
-		Default::default()
+	let set_gas_price = !debug_trace || client.client_type.erigon();
+	let fees = if set_gas_price { Some(current_fees(client).await?) } else { None };
+	let with_fees = |mut call: SolidityFunctionCall| {
+		if let Some(fees) = fees {
+			fees.apply(&mut call.tx);
+		}
+		call
 	};
 
 	for message in messages {
@@ -249,9 +477,7 @@ pub async fn generate_contract_calls(
 					.estimate_gas()
 					.await
 					.unwrap_or(get_chain_gas_limit(client.state_machine).into());
-				let call = call.gas_price(gas_price).gas(gas_limit);
-
-				calls.push(call);
+				calls.push(with_fees(call.gas(gas_limit)));
 			},
 			Message::Request(msg) => {
 				let membership_proof = MmrProof::<H256>::decode(&mut msg.proof.proof.as_slice())?;
@@ -297,15 +523,8 @@ pub async fn generate_contract_calls(
 					requests: leaves,
 				};
 
-				let call = if set_gas_price() {
-					contract
-						.handle_post_requests(ismp_host, post_message)
-						.gas_price(gas_price)
-						.gas(gas_limit)
-				} else {
-					contract.handle_post_requests(ismp_host, post_message).gas(gas_limit)
-				};
-				calls.push(call)
+				let call = contract.handle_post_requests(ismp_host, post_message).gas(gas_limit);
+				calls.push(with_fees(call))
 			},
 			Message::Response(ResponseMessage { datagram, proof, .. }) => {
 				let membership_proof = MmrProof::<H256>::decode(&mut proof.proof.as_slice())?;
@@ -361,23 +580,19 @@ pub async fn generate_contract_calls(
 								responses: leaves,
 							};
 
-						if set_gas_price() {
-							contract
-								.handle_post_responses(ismp_host, message)
-								.gas_price(gas_price)
-								.gas(gas_limit)
-						} else {
-							contract.handle_post_responses(ismp_host, message).gas(gas_limit)
-						}
+						with_fees(contract.handle_post_responses(ismp_host, message).gas(gas_limit))
 					},
 					RequestResponse::Request(..) =>
 						Err(anyhow!("Get requests are not supported by relayer"))?,
+					RequestResponse::Batch(..) =>
+						Err(anyhow!("Request batches are not supported by the EVM host"))?,
 				};
 
 				calls.push(call);
 			},
 			Message::Timeout(_) => Err(anyhow!("Timeout messages not supported by relayer"))?,
 			Message::FraudProof(_) => Err(anyhow!("Unexpected fraud proof message"))?,
+			Message::Batch(_) => Err(anyhow!("Batch messages are not supported by the EVM host"))?,
 		}
 	}
 
@@ -407,7 +622,7 @@ pub async fn handle_message_submission(
 				for post in req_msg.requests {
 					let req = Request::Post(post);
 					let commitment = hash_request::<Hasher>(&req);
-					if receipts.contains(&commitment) {
+					if let Some(gas) = receipts.get(&commitment) {
 						let tx_receipt = TxReceipt::Request {
 							query: Query {
 								source_chain: req.source_chain(),
@@ -416,6 +631,7 @@ pub async fn handle_message_submission(
 								commitment,
 							},
 							height,
+							gas: *gas,
 						};
 
 						results.push(tx_receipt);
@@ -428,7 +644,7 @@ pub async fn handle_message_submission(
 				for res in resp {
 					let commitment = hash_response::<Hasher>(&res);
 					let request_commitment = hash_request::<Hasher>(&res.request());
-					if receipts.contains(&commitment) {
+					if let Some(gas) = receipts.get(&commitment) {
 						let tx_receipt = TxReceipt::Response {
 							query: Query {
 								source_chain: res.source_chain(),
@@ -438,6 +654,7 @@ pub async fn handle_message_submission(
 							},
 							request_commitment,
 							height,
+							gas: *gas,
 						};
 
 						results.push(tx_receipt);
@@ -449,3 +666,138 @@ pub async fn handle_message_submission(
 
 	Ok(results)
 }
+
+#[cfg(test)]
+mod test {
+	use crate::tx::TransactionFees;
+	use ethers::{
+		prelude::transaction::eip2718::TypedTransaction,
+		types::{TransactionRequest, U256},
+	};
+
+	#[test]
+	fn replacement_fees_should_bump_every_fee() {
+		let legacy = TransactionFees::Legacy { gas_price: 100u64.into() };
+		assert_eq!(
+			legacy.replacement(TransactionFees::Legacy { gas_price: 90u64.into() }, 25),
+			TransactionFees::Legacy { gas_price: 125u64.into() }
+		);
+		// the current network fees are used if they are higher than the bumped fees
+		assert_eq!(
+			legacy.replacement(TransactionFees::Legacy { gas_price: 200u64.into() }, 25),
+			TransactionFees::Legacy { gas_price: 200u64.into() }
+		);
+
+		let eip1559 = TransactionFees::Eip1559 {
+			max_fee_per_gas: 100u64.into(),
+			max_priority_fee_per_gas: 10u64.into(),
+		};
+		let current = TransactionFees::Eip1559 {
+			max_fee_per_gas: 80u64.into(),
+			max_priority_fee_per_gas: 20u64.into(),
+		};
+		assert_eq!(
+			eip1559.replacement(current, 10),
+			TransactionFees::Eip1559 {
+				max_fee_per_gas: 110u64.into(),
+				max_priority_fee_per_gas: 20u64.into(),
+			}
+		);
+	}
+
+	#[test]
+	fn bump_should_round_up_every_fee() {
+		assert_eq!(
+			TransactionFees::Legacy { gas_price: 100u64.into() }.bump(25),
+			TransactionFees::Legacy { gas_price: 125u64.into() }
+		);
+		// a small fee is still bumped
+		assert_eq!(
+			TransactionFees::Legacy { gas_price: 7u64.into() }.bump(10),
+			TransactionFees::Legacy { gas_price: 8u64.into() }
+		);
+		assert_eq!(
+			TransactionFees::Eip1559 {
+				max_fee_per_gas: 1_000u64.into(),
+				max_priority_fee_per_gas: 1u64.into(),
+			}
+			.bump(10),
+			TransactionFees::Eip1559 {
+				max_fee_per_gas: 1_100u64.into(),
+				max_priority_fee_per_gas: 2u64.into(),
+			}
+		);
+	}
+
+	#[test]
+	fn replacement_fees_should_apply_the_minimum_bump() {
+		// legacy replacements bump the gas price by at least 10%
+		let legacy = TransactionFees::Legacy { gas_price: 100u64.into() };
+		assert_eq!(
+			legacy.replacement(TransactionFees::Legacy { gas_price: 90u64.into() }, 0),
+			TransactionFees::Legacy { gas_price: 110u64.into() }
+		);
+
+		// eip-1559 replacements bump both the max fee and the priority fee by at least 10%
+		let eip1559 = TransactionFees::Eip1559 {
+			max_fee_per_gas: 100u64.into(),
+			max_priority_fee_per_gas: 10u64.into(),
+		};
+		let current = TransactionFees::Eip1559 {
+			max_fee_per_gas: 50u64.into(),
+			max_priority_fee_per_gas: 5u64.into(),
+		};
+		assert_eq!(
+			eip1559.replacement(current, 5),
+			TransactionFees::Eip1559 {
+				max_fee_per_gas: 110u64.into(),
+				max_priority_fee_per_gas: 11u64.into(),
+			}
+		);
+
+		// the max fee always covers the priority fee
+		let current = TransactionFees::Eip1559 {
+			max_fee_per_gas: 50u64.into(),
+			max_priority_fee_per_gas: 200u64.into(),
+		};
+		assert_eq!(
+			eip1559.replacement(current, 25),
+			TransactionFees::Eip1559 {
+				max_fee_per_gas: 200u64.into(),
+				max_priority_fee_per_gas: 200u64.into(),
+			}
+		);
+
+		// a pending transaction keeps its type when the network fees are of another type
+		assert_eq!(
+			legacy.replacement(current, 25),
+			TransactionFees::Legacy { gas_price: 125u64.into() }
+		);
+	}
+
+	#[test]
+	fn applying_fees_should_preserve_the_transaction() {
+		let mut tx = TypedTransaction::Legacy(TransactionRequest {
+			gas: Some(21_000u64.into()),
+			nonce: Some(7u64.into()),
+			value: Some(U256::zero()),
+			..Default::default()
+		});
+		let fees = TransactionFees::Eip1559 {
+			max_fee_per_gas: 100u64.into(),
+			max_priority_fee_per_gas: 10u64.into(),
+		};
+		fees.apply(&mut tx);
+
+		assert!(matches!(tx, TypedTransaction::Eip1559(_)));
+		assert_eq!(TransactionFees::of(&tx), Some(fees));
+		assert_eq!(tx.nonce(), Some(&7u64.into()));
+		assert_eq!(tx.gas(), Some(&21_000u64.into()));
+
+		let fees = TransactionFees::Legacy { gas_price: 50u64.into() };
+		fees.apply(&mut tx);
+		assert!(matches!(tx, TypedTransaction::Legacy(_)));
+		assert_eq!(TransactionFees::of(&tx), Some(fees));
+		assert_eq!(tx.nonce(), Some(&7u64.into()));
+	}
+}
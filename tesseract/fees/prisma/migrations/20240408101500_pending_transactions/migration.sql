@@ -0,0 +1,9 @@
+-- CreateTable
+CREATE TABLE "PendingTransactions" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "chain" TEXT NOT NULL,
+    "signer" TEXT NOT NULL,
+    "nonce" INTEGER NOT NULL,
+    "hash" TEXT NOT NULL,
+    "encoded" BLOB NOT NULL
+);
@@ -0,0 +1,24 @@
+-- CreateTable
+CREATE TABLE "DeliveryLedger" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "hash" TEXT NOT NULL,
+    "source_chain" TEXT NOT NULL,
+    "dest_chain" TEXT NOT NULL,
+    "delivery_type" INTEGER NOT NULL,
+    "gas_used" TEXT NOT NULL,
+    "gas_price" TEXT NOT NULL,
+    "cost" TEXT NOT NULL,
+    "fee" TEXT NOT NULL,
+    "created_at" INTEGER NOT NULL,
+    "claimed_at" INTEGER NOT NULL
+);
+
+-- CreateTable
+CREATE TABLE "Withdrawals" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "chain" TEXT NOT NULL,
+    "hash" TEXT NOT NULL,
+    "amount" TEXT NOT NULL,
+    "created_at" INTEGER NOT NULL,
+    "settled_at" INTEGER NOT NULL
+);
@@ -0,0 +1,9 @@
+-- CreateTable
+CREATE TABLE "Checkpoints" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "source_chain" TEXT NOT NULL,
+    "dest_chain" TEXT NOT NULL,
+    "update_height" INTEGER NOT NULL,
+    "scanned_height" INTEGER NOT NULL,
+    "updated_at" INTEGER NOT NULL
+);
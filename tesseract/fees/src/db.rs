@@ -2150,6 +2150,4224 @@ pub mod unprofitable_messages {
 		}
 	}
 }
+pub mod pending_transactions {
+	use super::{_prisma::*, *};
+	pub const NAME: &str = "PendingTransactions";
+	pub mod id {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "id";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetId(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Id(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Id(direction)
+		}
+		pub fn equals<T: From<UniqueWhereParam>>(value: i32) -> T {
+			UniqueWhereParam::IdEquals(value).into()
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::IntFilter, Id, {
+			fn in_vec(_: Vec<i32>) -> InVec;
+			fn not_in_vec(_: Vec<i32>) -> NotInVec;
+			fn lt(_: i32) -> Lt;
+			fn lte(_: i32) -> Lte;
+			fn gt(_: i32) -> Gt;
+			fn gte(_: i32) -> Gte;
+			fn not(_: i32) -> Not;
+		});
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementId(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementId(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyId(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideId(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Id(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Id(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod chain {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "chain";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetChain(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Chain(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Chain(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::Chain(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			Chain,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Chain(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Chain(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod signer {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "signer";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetSigner(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Signer(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Signer(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::Signer(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			Signer,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Signer(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Signer(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod nonce {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "nonce";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetNonce(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Nonce(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Nonce(direction)
+		}
+		pub fn equals(value: i32) -> WhereParam {
+			WhereParam::Nonce(_prisma::read_filters::IntFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::IntFilter, Nonce, {
+			fn in_vec(_: Vec<i32>) -> InVec;
+			fn not_in_vec(_: Vec<i32>) -> NotInVec;
+			fn lt(_: i32) -> Lt;
+			fn lte(_: i32) -> Lte;
+			fn gt(_: i32) -> Gt;
+			fn gte(_: i32) -> Gte;
+			fn not(_: i32) -> Not;
+		});
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementNonce(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementNonce(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyNonce(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideNonce(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Nonce(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Nonce(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod hash {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "hash";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetHash(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Hash(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Hash(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::Hash(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::StringFilter, Hash, {
+			fn in_vec(_: Vec<String>) -> InVec;
+			fn not_in_vec(_: Vec<String>) -> NotInVec;
+			fn lt(_: String) -> Lt;
+			fn lte(_: String) -> Lte;
+			fn gt(_: String) -> Gt;
+			fn gte(_: String) -> Gte;
+			fn contains(_: String) -> Contains;
+			fn starts_with(_: String) -> StartsWith;
+			fn ends_with(_: String) -> EndsWith;
+			fn not(_: String) -> Not;
+		});
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Hash(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Hash(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod encoded {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "encoded";
+		pub struct Set(pub Vec<u8>);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetEncoded(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Encoded(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: Vec<u8>) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Encoded(direction)
+		}
+		pub fn equals(value: Vec<u8>) -> WhereParam {
+			WhereParam::Encoded(_prisma::read_filters::BytesFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::BytesFilter,
+			Encoded,
+			{
+				fn in_vec(_: Vec<Vec<u8>>) -> InVec;
+				fn not_in_vec(_: Vec<Vec<u8>>) -> NotInVec;
+				fn not(_: Vec<u8>) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Encoded(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Encoded(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub fn create(
+		chain: String,
+		signer: String,
+		nonce: i32,
+		hash: String,
+		encoded: Vec<u8>,
+		_params: Vec<SetParam>,
+	) -> (String, String, i32, String, Vec<u8>, Vec<SetParam>) {
+		(chain, signer, nonce, hash, encoded, _params)
+	}
+	pub fn create_unchecked(
+		chain: String,
+		signer: String,
+		nonce: i32,
+		hash: String,
+		encoded: Vec<u8>,
+		_params: Vec<SetParam>,
+	) -> (String, String, i32, String, Vec<u8>, Vec<SetParam>) {
+		(chain, signer, nonce, hash, encoded, _params)
+	}
+	#[macro_export]
+	macro_rules ! _select_pending_transactions { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: pending_transactions :: select ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: pending_transactions :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: pending_transactions :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: pending_transactions :: select ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: pending_transactions :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: pending_transactions :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , chain , signer , nonce , hash , encoded } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : crate :: prisma :: pending_transactions :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) +] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: pending_transactions :: $ field :: NAME , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: pending_transactions :: $ field :: NAME) , + ,] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: pending_transactions :: $ field :: NAME => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: pending_transactions :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: pending_transactions :: $ field :: NAME)) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "chain" , "signer" , "nonce" , "hash" , "encoded"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: pending_transactions :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { i32 } ; (@ field_type ; chain) => { String } ; (@ field_type ; signer) => { String } ; (@ field_type ; nonce) => { i32 } ; (@ field_type ; hash) => { String } ; (@ field_type ; encoded) => { Vec < u8 > } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "PendingTransactions" , available relations are "id, chain, signer, nonce, hash, encoded")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < crate :: prisma :: pending_transactions :: SelectParam > :: into (crate :: prisma :: pending_transactions :: id :: Select) } ; (@ selection_field_to_selection_param ; chain) => { Into :: < crate :: prisma :: pending_transactions :: SelectParam > :: into (crate :: prisma :: pending_transactions :: chain :: Select) } ; (@ selection_field_to_selection_param ; signer) => { Into :: < crate :: prisma :: pending_transactions :: SelectParam > :: into (crate :: prisma :: pending_transactions :: signer :: Select) } ; (@ selection_field_to_selection_param ; nonce) => { Into :: < crate :: prisma :: pending_transactions :: SelectParam > :: into (crate :: prisma :: pending_transactions :: nonce :: Select) } ; (@ selection_field_to_selection_param ; hash) => { Into :: < crate :: prisma :: pending_transactions :: SelectParam > :: into (crate :: prisma :: pending_transactions :: hash :: Select) } ; (@ selection_field_to_selection_param ; encoded) => { Into :: < crate :: prisma :: pending_transactions :: SelectParam > :: into (crate :: prisma :: pending_transactions :: encoded :: Select) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: pending_transactions :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; chain) => { "chain" } ; (@ field_serde_name ; signer) => { "signer" } ; (@ field_serde_name ; nonce) => { "nonce" } ; (@ field_serde_name ; hash) => { "hash" } ; (@ field_serde_name ; encoded) => { "encoded" } ; }
+	pub use _select_pending_transactions as select;
+	pub enum SelectParam {
+		Id(id::Select),
+		Chain(chain::Select),
+		Signer(signer::Select),
+		Nonce(nonce::Select),
+		Hash(hash::Select),
+		Encoded(encoded::Select),
+	}
+	impl SelectParam {
+		pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+			match self {
+				Self::Id(data) => data.to_selection(),
+				Self::Chain(data) => data.to_selection(),
+				Self::Signer(data) => data.to_selection(),
+				Self::Nonce(data) => data.to_selection(),
+				Self::Hash(data) => data.to_selection(),
+				Self::Encoded(data) => data.to_selection(),
+			}
+		}
+	}
+	#[macro_export]
+	macro_rules ! _include_pending_transactions { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: pending_transactions :: include ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: pending_transactions :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: pending_transactions :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: pending_transactions :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: pending_transactions :: include ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: pending_transactions :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: pending_transactions :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: pending_transactions :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : i32 , pub chain : String , pub signer : String , pub nonce : i32 , pub hash : String , pub encoded : Vec < u8 > , $ (pub $ field : crate :: prisma :: pending_transactions :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (chain) , stringify ! (signer) , stringify ! (nonce) , stringify ! (hash) , stringify ! (encoded)] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: pending_transactions :: $ field :: NAME , & self . $ field) ? ;) * state . serialize_field (crate :: prisma :: pending_transactions :: id :: NAME , & self . id) ? ; state . serialize_field (crate :: prisma :: pending_transactions :: chain :: NAME , & self . chain) ? ; state . serialize_field (crate :: prisma :: pending_transactions :: signer :: NAME , & self . signer) ? ; state . serialize_field (crate :: prisma :: pending_transactions :: nonce :: NAME , & self . nonce) ? ; state . serialize_field (crate :: prisma :: pending_transactions :: hash :: NAME , & self . hash) ? ; state . serialize_field (crate :: prisma :: pending_transactions :: encoded :: NAME , & self . encoded) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , chain , signer , nonce , hash , encoded } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: pending_transactions :: $ field :: NAME) , + , crate :: prisma :: pending_transactions :: id :: NAME , crate :: prisma :: pending_transactions :: chain :: NAME , crate :: prisma :: pending_transactions :: signer :: NAME , crate :: prisma :: pending_transactions :: nonce :: NAME , crate :: prisma :: pending_transactions :: hash :: NAME , crate :: prisma :: pending_transactions :: encoded :: NAME] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: pending_transactions :: $ field :: NAME => Ok (Field :: $ field)) , * , crate :: prisma :: pending_transactions :: id :: NAME => Ok (Field :: id) , crate :: prisma :: pending_transactions :: chain :: NAME => Ok (Field :: chain) , crate :: prisma :: pending_transactions :: signer :: NAME => Ok (Field :: signer) , crate :: prisma :: pending_transactions :: nonce :: NAME => Ok (Field :: nonce) , crate :: prisma :: pending_transactions :: hash :: NAME => Ok (Field :: hash) , crate :: prisma :: pending_transactions :: encoded :: NAME => Ok (Field :: encoded) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut chain = None ; let mut signer = None ; let mut nonce = None ; let mut hash = None ; let mut encoded = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: pending_transactions :: id :: NAME)) ; } id = Some (map . next_value () ?) ; } Field :: chain => { if chain . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: pending_transactions :: chain :: NAME)) ; } chain = Some (map . next_value () ?) ; } Field :: signer => { if signer . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: pending_transactions :: signer :: NAME)) ; } signer = Some (map . next_value () ?) ; } Field :: nonce => { if nonce . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: pending_transactions :: nonce :: NAME)) ; } nonce = Some (map . next_value () ?) ; } Field :: hash => { if hash . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: pending_transactions :: hash :: NAME)) ; } hash = Some (map . next_value () ?) ; } Field :: encoded => { if encoded . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: pending_transactions :: encoded :: NAME)) ; } encoded = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: pending_transactions :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: pending_transactions :: $ field :: NAME)) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: pending_transactions :: id :: NAME)) ? ; let chain = chain . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: pending_transactions :: chain :: NAME)) ? ; let signer = signer . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: pending_transactions :: signer :: NAME)) ? ; let nonce = nonce . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: pending_transactions :: nonce :: NAME)) ? ; let hash = hash . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: pending_transactions :: hash :: NAME)) ? ; let encoded = encoded . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: pending_transactions :: encoded :: NAME)) ? ; Ok (Data { id , chain , signer , nonce , hash , encoded , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "chain" , "signer" , "nonce" , "hash" , "encoded"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: pending_transactions :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "PendingTransactions" , available relations are "")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: pending_transactions :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; chain) => { "chain" } ; (@ field_serde_name ; signer) => { "signer" } ; (@ field_serde_name ; nonce) => { "nonce" } ; (@ field_serde_name ; hash) => { "hash" } ; (@ field_serde_name ; encoded) => { "encoded" } ; }
+	pub use _include_pending_transactions as include;
+	pub enum IncludeParam {
+		Id(id::Include),
+		Chain(chain::Include),
+		Signer(signer::Include),
+		Nonce(nonce::Include),
+		Hash(hash::Include),
+		Encoded(encoded::Include),
+	}
+	impl IncludeParam {
+		pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+			match self {
+				Self::Id(data) => data.to_selection(),
+				Self::Chain(data) => data.to_selection(),
+				Self::Signer(data) => data.to_selection(),
+				Self::Nonce(data) => data.to_selection(),
+				Self::Hash(data) => data.to_selection(),
+				Self::Encoded(data) => data.to_selection(),
+			}
+		}
+	}
+	#[macro_export]
+	macro_rules ! _partial_unchecked_pending_transactions { ($ struct_name : ident { $ ($ scalar_field : ident) + }) => { :: prisma_client_rust :: macros :: partial_unchecked ! { crate :: prisma :: pending_transactions struct $ struct_name { # [serde (rename = "id")] pub id : i32 , # [serde (rename = "chain")] pub chain : String , # [serde (rename = "signer")] pub signer : String , # [serde (rename = "nonce")] pub nonce : i32 , # [serde (rename = "hash")] pub hash : String , # [serde (rename = "encoded")] pub encoded : Vec < u8 > } [$ ($ scalar_field) , +] } } ; }
+	pub use _partial_unchecked_pending_transactions as partial_unchecked;
+	#[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+	pub struct Data {
+		#[serde(rename = "id")]
+		pub id: i32,
+		#[serde(rename = "chain")]
+		pub chain: String,
+		#[serde(rename = "signer")]
+		pub signer: String,
+		#[serde(rename = "nonce")]
+		pub nonce: i32,
+		#[serde(rename = "hash")]
+		pub hash: String,
+		#[serde(rename = "encoded")]
+		pub encoded: Vec<u8>,
+	}
+	impl Data {}
+	#[derive(Clone)]
+	pub enum WithParam {}
+	impl Into<::prisma_client_rust::Selection> for WithParam {
+		fn into(self) -> ::prisma_client_rust::Selection {
+			match self {}
+		}
+	}
+	#[derive(Clone)]
+	pub enum SetParam {
+		SetId(i32),
+		IncrementId(i32),
+		DecrementId(i32),
+		MultiplyId(i32),
+		DivideId(i32),
+		SetChain(String),
+		SetSigner(String),
+		SetNonce(i32),
+		IncrementNonce(i32),
+		DecrementNonce(i32),
+		MultiplyNonce(i32),
+		DivideNonce(i32),
+		SetHash(String),
+		SetEncoded(Vec<u8>),
+	}
+	impl From<SetParam> for (String, ::prisma_client_rust::PrismaValue) {
+		fn from(param: SetParam) -> Self {
+			match param {
+				SetParam::SetId(value) =>
+					(id::NAME.to_string(), ::prisma_client_rust::PrismaValue::Int(value as i64)),
+				SetParam::IncrementId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetChain(value) =>
+					(chain::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetSigner(value) =>
+					(signer::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetNonce(value) =>
+					(nonce::NAME.to_string(), ::prisma_client_rust::PrismaValue::Int(value as i64)),
+				SetParam::IncrementNonce(value) => (
+					nonce::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementNonce(value) => (
+					nonce::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyNonce(value) => (
+					nonce::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideNonce(value) => (
+					nonce::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetHash(value) =>
+					(hash::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetEncoded(value) =>
+					(encoded::NAME.to_string(), ::prisma_client_rust::PrismaValue::Bytes(value)),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum UncheckedSetParam {
+		Id(i32),
+		Chain(String),
+		Signer(String),
+		Nonce(i32),
+		Hash(String),
+		Encoded(Vec<u8>),
+	}
+	impl From<UncheckedSetParam> for SetParam {
+		fn from(param: UncheckedSetParam) -> Self {
+			match param {
+				UncheckedSetParam::Id(value) => Self::SetId(value),
+				UncheckedSetParam::Chain(value) => Self::SetChain(value),
+				UncheckedSetParam::Signer(value) => Self::SetSigner(value),
+				UncheckedSetParam::Nonce(value) => Self::SetNonce(value),
+				UncheckedSetParam::Hash(value) => Self::SetHash(value),
+				UncheckedSetParam::Encoded(value) => Self::SetEncoded(value),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum OrderByParam {
+		Id(::prisma_client_rust::Direction),
+		Chain(::prisma_client_rust::Direction),
+		Signer(::prisma_client_rust::Direction),
+		Nonce(::prisma_client_rust::Direction),
+		Hash(::prisma_client_rust::Direction),
+		Encoded(::prisma_client_rust::Direction),
+	}
+	impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+		fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+			match self {
+				Self::Id(direction) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Chain(direction) => (
+					chain::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Signer(direction) => (
+					signer::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Nonce(direction) => (
+					nonce::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Hash(direction) => (
+					hash::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Encoded(direction) => (
+					encoded::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum WhereParam {
+		Not(Vec<WhereParam>),
+		Or(Vec<WhereParam>),
+		And(Vec<WhereParam>),
+		Id(_prisma::read_filters::IntFilter),
+		Chain(_prisma::read_filters::StringFilter),
+		Signer(_prisma::read_filters::StringFilter),
+		Nonce(_prisma::read_filters::IntFilter),
+		Hash(_prisma::read_filters::StringFilter),
+		Encoded(_prisma::read_filters::BytesFilter),
+	}
+	impl ::prisma_client_rust::WhereInput for WhereParam {
+		fn serialize(self) -> ::prisma_client_rust::SerializedWhereInput {
+			let (name, value) = match self {
+				Self::Not(value) => (
+					"NOT",
+					::prisma_client_rust::SerializedWhereValue::Object(
+						::prisma_client_rust::merge_fields(
+							value
+								.into_iter()
+								.map(::prisma_client_rust::WhereInput::serialize)
+								.map(Into::into)
+								.collect(),
+						),
+					),
+				),
+				Self::Or(value) => (
+					"OR",
+					::prisma_client_rust::SerializedWhereValue::List(
+						value
+							.into_iter()
+							.map(::prisma_client_rust::WhereInput::serialize)
+							.map(Into::into)
+							.map(|v| vec![v])
+							.map(::prisma_client_rust::PrismaValue::Object)
+							.collect(),
+					),
+				),
+				Self::And(value) => (
+					"AND",
+					::prisma_client_rust::SerializedWhereValue::Object(
+						::prisma_client_rust::merge_fields(
+							value
+								.into_iter()
+								.map(::prisma_client_rust::WhereInput::serialize)
+								.map(Into::into)
+								.collect(),
+						),
+					),
+				),
+				Self::Id(value) => (id::NAME, value.into()),
+				Self::Chain(value) => (chain::NAME, value.into()),
+				Self::Signer(value) => (signer::NAME, value.into()),
+				Self::Nonce(value) => (nonce::NAME, value.into()),
+				Self::Hash(value) => (hash::NAME, value.into()),
+				Self::Encoded(value) => (encoded::NAME, value.into()),
+			};
+			::prisma_client_rust::SerializedWhereInput::new(name, value.into())
+		}
+	}
+	#[derive(Clone)]
+	pub enum UniqueWhereParam {
+		IdEquals(i32),
+	}
+	impl From<UniqueWhereParam> for WhereParam {
+		fn from(value: UniqueWhereParam) -> Self {
+			match value {
+				UniqueWhereParam::IdEquals(value) =>
+					Self::Id(_prisma::read_filters::IntFilter::Equals(value)),
+			}
+		}
+	}
+	impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+		fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+			match op {
+				::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+				::prisma_client_rust::Operator::And(value) => Self::And(value),
+				::prisma_client_rust::Operator::Or(value) => Self::Or(value),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub struct Types;
+	impl ::prisma_client_rust::ModelTypes for Types {
+		type Data = Data;
+		type Where = WhereParam;
+		type UncheckedSet = UncheckedSetParam;
+		type Set = SetParam;
+		type With = WithParam;
+		type OrderBy = OrderByParam;
+		type Cursor = UniqueWhereParam;
+		const MODEL: &'static str = NAME;
+		fn scalar_selections() -> Vec<::prisma_client_rust::Selection> {
+			vec![
+				::prisma_client_rust::sel(id::NAME),
+				::prisma_client_rust::sel(chain::NAME),
+				::prisma_client_rust::sel(signer::NAME),
+				::prisma_client_rust::sel(nonce::NAME),
+				::prisma_client_rust::sel(hash::NAME),
+				::prisma_client_rust::sel(encoded::NAME),
+			]
+		}
+	}
+	pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<Types>;
+	pub type ManyArgs = ::prisma_client_rust::ManyArgs<Types>;
+	pub type Count<'a> = ::prisma_client_rust::Count<'a, Types>;
+	pub type Create<'a> = ::prisma_client_rust::Create<'a, Types>;
+	pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, Types>;
+	pub type FindUnique<'a> = ::prisma_client_rust::FindUnique<'a, Types>;
+	pub type FindMany<'a> = ::prisma_client_rust::FindMany<'a, Types>;
+	pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<'a, Types>;
+	pub type Update<'a> = ::prisma_client_rust::Update<'a, Types>;
+	pub type UpdateMany<'a> = ::prisma_client_rust::UpdateMany<'a, Types>;
+	pub type Upsert<'a> = ::prisma_client_rust::Upsert<'a, Types>;
+	pub type Delete<'a> = ::prisma_client_rust::Delete<'a, Types>;
+	pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, Types>;
+	#[derive(Clone)]
+	pub struct Actions<'a> {
+		pub client: &'a ::prisma_client_rust::PrismaClientInternals,
+	}
+	impl<'a> Actions<'a> {
+		pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+			FindUnique::new(self.client, _where.into())
+		}
+		pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+			FindFirst::new(self.client, _where)
+		}
+		pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+			FindMany::new(self.client, _where)
+		}
+		pub fn create(
+			self,
+			chain: String,
+			signer: String,
+			nonce: i32,
+			hash: String,
+			encoded: Vec<u8>,
+			mut _params: Vec<SetParam>,
+		) -> Create<'a> {
+			_params.extend([
+				chain::set(chain),
+				signer::set(signer),
+				nonce::set(nonce),
+				hash::set(hash),
+				encoded::set(encoded),
+			]);
+			Create::new(self.client, _params)
+		}
+		pub fn create_unchecked(
+			self,
+			chain: String,
+			signer: String,
+			nonce: i32,
+			hash: String,
+			encoded: Vec<u8>,
+			mut _params: Vec<UncheckedSetParam>,
+		) -> Create<'a> {
+			_params.extend([
+				chain::set(chain),
+				signer::set(signer),
+				nonce::set(nonce),
+				hash::set(hash),
+				encoded::set(encoded),
+			]);
+			Create::new(self.client, _params.into_iter().map(Into::into).collect())
+		}
+		pub fn update(self, _where: UniqueWhereParam, _params: Vec<SetParam>) -> Update<'a> {
+			Update::new(self.client, _where.into(), _params, vec![])
+		}
+		pub fn update_unchecked(
+			self,
+			_where: UniqueWhereParam,
+			_params: Vec<UncheckedSetParam>,
+		) -> Update<'a> {
+			Update::new(
+				self.client,
+				_where.into(),
+				_params.into_iter().map(Into::into).collect(),
+				vec![],
+			)
+		}
+		pub fn update_many(
+			self,
+			_where: Vec<WhereParam>,
+			_params: Vec<SetParam>,
+		) -> UpdateMany<'a> {
+			UpdateMany::new(self.client, _where, _params)
+		}
+		pub fn upsert(
+			self,
+			_where: UniqueWhereParam,
+			(chain, signer, nonce, hash, encoded, mut _params): (
+				String,
+				String,
+				i32,
+				String,
+				Vec<u8>,
+				Vec<SetParam>,
+			),
+			_update: Vec<SetParam>,
+		) -> Upsert<'a> {
+			_params.extend([
+				chain::set(chain),
+				signer::set(signer),
+				nonce::set(nonce),
+				hash::set(hash),
+				encoded::set(encoded),
+			]);
+			Upsert::new(self.client, _where.into(), _params, _update)
+		}
+		pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+			Delete::new(self.client, _where.into(), vec![])
+		}
+		pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+			DeleteMany::new(self.client, _where)
+		}
+		pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+			Count::new(self.client, _where)
+		}
+		pub fn find_raw<T: ::prisma_client_rust::Data>(
+			self,
+		) -> ::prisma_client_rust::FindRaw<'a, Types, T> {
+			::prisma_client_rust::FindRaw::new(self.client)
+		}
+		pub fn aggregate_raw<T: ::prisma_client_rust::Data>(
+			self,
+		) -> ::prisma_client_rust::AggregateRaw<'a, Types, T> {
+			::prisma_client_rust::AggregateRaw::new(self.client)
+		}
+	}
+}
+pub mod delivery_ledger {
+	use super::{_prisma::*, *};
+	pub const NAME: &str = "DeliveryLedger";
+	pub mod id {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "id";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetId(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Id(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Id(direction)
+		}
+		pub fn equals<T: From<UniqueWhereParam>>(value: i32) -> T {
+			UniqueWhereParam::IdEquals(value).into()
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::IntFilter, Id, {
+			fn in_vec(_: Vec<i32>) -> InVec;
+			fn not_in_vec(_: Vec<i32>) -> NotInVec;
+			fn lt(_: i32) -> Lt;
+			fn lte(_: i32) -> Lte;
+			fn gt(_: i32) -> Gt;
+			fn gte(_: i32) -> Gte;
+			fn not(_: i32) -> Not;
+		});
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementId(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementId(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyId(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideId(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Id(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Id(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod hash {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "hash";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetHash(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Hash(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Hash(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::Hash(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::StringFilter, Hash, {
+			fn in_vec(_: Vec<String>) -> InVec;
+			fn not_in_vec(_: Vec<String>) -> NotInVec;
+			fn lt(_: String) -> Lt;
+			fn lte(_: String) -> Lte;
+			fn gt(_: String) -> Gt;
+			fn gte(_: String) -> Gte;
+			fn contains(_: String) -> Contains;
+			fn starts_with(_: String) -> StartsWith;
+			fn ends_with(_: String) -> EndsWith;
+			fn not(_: String) -> Not;
+		});
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Hash(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Hash(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod source_chain {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "source_chain";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetSourceChain(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SourceChain(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::SourceChain(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::SourceChain(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			SourceChain,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::SourceChain(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::SourceChain(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod dest_chain {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "dest_chain";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetDestChain(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::DestChain(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::DestChain(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::DestChain(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			DestChain,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::DestChain(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::DestChain(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod delivery_type {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "delivery_type";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetDeliveryType(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::DeliveryType(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::DeliveryType(direction)
+		}
+		pub fn equals(value: i32) -> WhereParam {
+			WhereParam::DeliveryType(_prisma::read_filters::IntFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::IntFilter,
+			DeliveryType,
+			{
+				fn in_vec(_: Vec<i32>) -> InVec;
+				fn not_in_vec(_: Vec<i32>) -> NotInVec;
+				fn lt(_: i32) -> Lt;
+				fn lte(_: i32) -> Lte;
+				fn gt(_: i32) -> Gt;
+				fn gte(_: i32) -> Gte;
+				fn not(_: i32) -> Not;
+			}
+		);
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementDeliveryType(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementDeliveryType(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyDeliveryType(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideDeliveryType(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::DeliveryType(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::DeliveryType(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod gas_used {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "gas_used";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetGasUsed(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::GasUsed(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::GasUsed(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::GasUsed(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			GasUsed,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::GasUsed(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::GasUsed(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod gas_price {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "gas_price";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetGasPrice(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::GasPrice(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::GasPrice(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::GasPrice(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			GasPrice,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::GasPrice(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::GasPrice(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod cost {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "cost";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetCost(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Cost(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Cost(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::Cost(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::StringFilter, Cost, {
+			fn in_vec(_: Vec<String>) -> InVec;
+			fn not_in_vec(_: Vec<String>) -> NotInVec;
+			fn lt(_: String) -> Lt;
+			fn lte(_: String) -> Lte;
+			fn gt(_: String) -> Gt;
+			fn gte(_: String) -> Gte;
+			fn contains(_: String) -> Contains;
+			fn starts_with(_: String) -> StartsWith;
+			fn ends_with(_: String) -> EndsWith;
+			fn not(_: String) -> Not;
+		});
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Cost(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Cost(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod fee {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "fee";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetFee(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Fee(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Fee(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::Fee(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::StringFilter, Fee, {
+			fn in_vec(_: Vec<String>) -> InVec;
+			fn not_in_vec(_: Vec<String>) -> NotInVec;
+			fn lt(_: String) -> Lt;
+			fn lte(_: String) -> Lte;
+			fn gt(_: String) -> Gt;
+			fn gte(_: String) -> Gte;
+			fn contains(_: String) -> Contains;
+			fn starts_with(_: String) -> StartsWith;
+			fn ends_with(_: String) -> EndsWith;
+			fn not(_: String) -> Not;
+		});
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Fee(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Fee(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod created_at {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "created_at";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetCreatedAt(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::CreatedAt(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::CreatedAt(direction)
+		}
+		pub fn equals(value: i32) -> WhereParam {
+			WhereParam::CreatedAt(_prisma::read_filters::IntFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::IntFilter,
+			CreatedAt,
+			{
+				fn in_vec(_: Vec<i32>) -> InVec;
+				fn not_in_vec(_: Vec<i32>) -> NotInVec;
+				fn lt(_: i32) -> Lt;
+				fn lte(_: i32) -> Lte;
+				fn gt(_: i32) -> Gt;
+				fn gte(_: i32) -> Gte;
+				fn not(_: i32) -> Not;
+			}
+		);
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementCreatedAt(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementCreatedAt(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyCreatedAt(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideCreatedAt(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::CreatedAt(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::CreatedAt(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod claimed_at {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "claimed_at";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetClaimedAt(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::ClaimedAt(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::ClaimedAt(direction)
+		}
+		pub fn equals(value: i32) -> WhereParam {
+			WhereParam::ClaimedAt(_prisma::read_filters::IntFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::IntFilter,
+			ClaimedAt,
+			{
+				fn in_vec(_: Vec<i32>) -> InVec;
+				fn not_in_vec(_: Vec<i32>) -> NotInVec;
+				fn lt(_: i32) -> Lt;
+				fn lte(_: i32) -> Lte;
+				fn gt(_: i32) -> Gt;
+				fn gte(_: i32) -> Gte;
+				fn not(_: i32) -> Not;
+			}
+		);
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementClaimedAt(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementClaimedAt(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyClaimedAt(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideClaimedAt(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::ClaimedAt(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::ClaimedAt(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub fn create(
+		hash: String,
+		source_chain: String,
+		dest_chain: String,
+		delivery_type: i32,
+		gas_used: String,
+		gas_price: String,
+		cost: String,
+		fee: String,
+		created_at: i32,
+		claimed_at: i32,
+		_params: Vec<SetParam>,
+	) -> (String, String, String, i32, String, String, String, String, i32, i32, Vec<SetParam>) {
+		(
+			hash,
+			source_chain,
+			dest_chain,
+			delivery_type,
+			gas_used,
+			gas_price,
+			cost,
+			fee,
+			created_at,
+			claimed_at,
+			_params,
+		)
+	}
+	pub fn create_unchecked(
+		hash: String,
+		source_chain: String,
+		dest_chain: String,
+		delivery_type: i32,
+		gas_used: String,
+		gas_price: String,
+		cost: String,
+		fee: String,
+		created_at: i32,
+		claimed_at: i32,
+		_params: Vec<SetParam>,
+	) -> (String, String, String, i32, String, String, String, String, i32, i32, Vec<SetParam>) {
+		(
+			hash,
+			source_chain,
+			dest_chain,
+			delivery_type,
+			gas_used,
+			gas_price,
+			cost,
+			fee,
+			created_at,
+			claimed_at,
+			_params,
+		)
+	}
+	#[macro_export]
+	macro_rules ! _select_delivery_ledger { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: delivery_ledger :: select ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: delivery_ledger :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: delivery_ledger :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: delivery_ledger :: select ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: delivery_ledger :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: delivery_ledger :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , hash , source_chain , dest_chain , delivery_type , gas_used , gas_price , cost , fee , created_at , claimed_at } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : crate :: prisma :: delivery_ledger :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) +] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: delivery_ledger :: $ field :: NAME , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: delivery_ledger :: $ field :: NAME) , + ,] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: delivery_ledger :: $ field :: NAME => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: $ field :: NAME)) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "hash" , "source_chain" , "dest_chain" , "delivery_type" , "gas_used" , "gas_price" , "cost" , "fee" , "created_at" , "claimed_at"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: delivery_ledger :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { i32 } ; (@ field_type ; hash) => { String } ; (@ field_type ; source_chain) => { String } ; (@ field_type ; dest_chain) => { String } ; (@ field_type ; delivery_type) => { i32 } ; (@ field_type ; gas_used) => { String } ; (@ field_type ; gas_price) => { String } ; (@ field_type ; cost) => { String } ; (@ field_type ; fee) => { String } ; (@ field_type ; created_at) => { i32 } ; (@ field_type ; claimed_at) => { i32 } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "DeliveryLedger" , available relations are "id, hash, source_chain, dest_chain, delivery_type, gas_used, gas_price, cost, fee, created_at, claimed_at")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: id :: Select) } ; (@ selection_field_to_selection_param ; hash) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: hash :: Select) } ; (@ selection_field_to_selection_param ; source_chain) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: source_chain :: Select) } ; (@ selection_field_to_selection_param ; dest_chain) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: dest_chain :: Select) } ; (@ selection_field_to_selection_param ; delivery_type) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: delivery_type :: Select) } ; (@ selection_field_to_selection_param ; gas_used) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: gas_used :: Select) } ; (@ selection_field_to_selection_param ; gas_price) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: gas_price :: Select) } ; (@ selection_field_to_selection_param ; cost) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: cost :: Select) } ; (@ selection_field_to_selection_param ; fee) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: fee :: Select) } ; (@ selection_field_to_selection_param ; created_at) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: created_at :: Select) } ; (@ selection_field_to_selection_param ; claimed_at) => { Into :: < crate :: prisma :: delivery_ledger :: SelectParam > :: into (crate :: prisma :: delivery_ledger :: claimed_at :: Select) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: delivery_ledger :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; hash) => { "hash" } ; (@ field_serde_name ; source_chain) => { "source_chain" } ; (@ field_serde_name ; dest_chain) => { "dest_chain" } ; (@ field_serde_name ; delivery_type) => { "delivery_type" } ; (@ field_serde_name ; gas_used) => { "gas_used" } ; (@ field_serde_name ; gas_price) => { "gas_price" } ; (@ field_serde_name ; cost) => { "cost" } ; (@ field_serde_name ; fee) => { "fee" } ; (@ field_serde_name ; created_at) => { "created_at" } ; (@ field_serde_name ; claimed_at) => { "claimed_at" } ; }
+	pub use _select_delivery_ledger as select;
+	pub enum SelectParam {
+		Id(id::Select),
+		Hash(hash::Select),
+		SourceChain(source_chain::Select),
+		DestChain(dest_chain::Select),
+		DeliveryType(delivery_type::Select),
+		GasUsed(gas_used::Select),
+		GasPrice(gas_price::Select),
+		Cost(cost::Select),
+		Fee(fee::Select),
+		CreatedAt(created_at::Select),
+		ClaimedAt(claimed_at::Select),
+	}
+	impl SelectParam {
+		pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+			match self {
+				Self::Id(data) => data.to_selection(),
+				Self::Hash(data) => data.to_selection(),
+				Self::SourceChain(data) => data.to_selection(),
+				Self::DestChain(data) => data.to_selection(),
+				Self::DeliveryType(data) => data.to_selection(),
+				Self::GasUsed(data) => data.to_selection(),
+				Self::GasPrice(data) => data.to_selection(),
+				Self::Cost(data) => data.to_selection(),
+				Self::Fee(data) => data.to_selection(),
+				Self::CreatedAt(data) => data.to_selection(),
+				Self::ClaimedAt(data) => data.to_selection(),
+			}
+		}
+	}
+	#[macro_export]
+	macro_rules ! _include_delivery_ledger { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: delivery_ledger :: include ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: delivery_ledger :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: delivery_ledger :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: delivery_ledger :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: delivery_ledger :: include ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: delivery_ledger :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: delivery_ledger :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: delivery_ledger :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : i32 , pub hash : String , pub source_chain : String , pub dest_chain : String , pub delivery_type : i32 , pub gas_used : String , pub gas_price : String , pub cost : String , pub fee : String , pub created_at : i32 , pub claimed_at : i32 , $ (pub $ field : crate :: prisma :: delivery_ledger :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (hash) , stringify ! (source_chain) , stringify ! (dest_chain) , stringify ! (delivery_type) , stringify ! (gas_used) , stringify ! (gas_price) , stringify ! (cost) , stringify ! (fee) , stringify ! (created_at) , stringify ! (claimed_at)] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: delivery_ledger :: $ field :: NAME , & self . $ field) ? ;) * state . serialize_field (crate :: prisma :: delivery_ledger :: id :: NAME , & self . id) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: hash :: NAME , & self . hash) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: source_chain :: NAME , & self . source_chain) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: dest_chain :: NAME , & self . dest_chain) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: delivery_type :: NAME , & self . delivery_type) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: gas_used :: NAME , & self . gas_used) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: gas_price :: NAME , & self . gas_price) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: cost :: NAME , & self . cost) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: fee :: NAME , & self . fee) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: created_at :: NAME , & self . created_at) ? ; state . serialize_field (crate :: prisma :: delivery_ledger :: claimed_at :: NAME , & self . claimed_at) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , hash , source_chain , dest_chain , delivery_type , gas_used , gas_price , cost , fee , created_at , claimed_at } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: delivery_ledger :: $ field :: NAME) , + , crate :: prisma :: delivery_ledger :: id :: NAME , crate :: prisma :: delivery_ledger :: hash :: NAME , crate :: prisma :: delivery_ledger :: source_chain :: NAME , crate :: prisma :: delivery_ledger :: dest_chain :: NAME , crate :: prisma :: delivery_ledger :: delivery_type :: NAME , crate :: prisma :: delivery_ledger :: gas_used :: NAME , crate :: prisma :: delivery_ledger :: gas_price :: NAME , crate :: prisma :: delivery_ledger :: cost :: NAME , crate :: prisma :: delivery_ledger :: fee :: NAME , crate :: prisma :: delivery_ledger :: created_at :: NAME , crate :: prisma :: delivery_ledger :: claimed_at :: NAME] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: delivery_ledger :: $ field :: NAME => Ok (Field :: $ field)) , * , crate :: prisma :: delivery_ledger :: id :: NAME => Ok (Field :: id) , crate :: prisma :: delivery_ledger :: hash :: NAME => Ok (Field :: hash) , crate :: prisma :: delivery_ledger :: source_chain :: NAME => Ok (Field :: source_chain) , crate :: prisma :: delivery_ledger :: dest_chain :: NAME => Ok (Field :: dest_chain) , crate :: prisma :: delivery_ledger :: delivery_type :: NAME => Ok (Field :: delivery_type) , crate :: prisma :: delivery_ledger :: gas_used :: NAME => Ok (Field :: gas_used) , crate :: prisma :: delivery_ledger :: gas_price :: NAME => Ok (Field :: gas_price) , crate :: prisma :: delivery_ledger :: cost :: NAME => Ok (Field :: cost) , crate :: prisma :: delivery_ledger :: fee :: NAME => Ok (Field :: fee) , crate :: prisma :: delivery_ledger :: created_at :: NAME => Ok (Field :: created_at) , crate :: prisma :: delivery_ledger :: claimed_at :: NAME => Ok (Field :: claimed_at) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut hash = None ; let mut source_chain = None ; let mut dest_chain = None ; let mut delivery_type = None ; let mut gas_used = None ; let mut gas_price = None ; let mut cost = None ; let mut fee = None ; let mut created_at = None ; let mut claimed_at = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: id :: NAME)) ; } id = Some (map . next_value () ?) ; } Field :: hash => { if hash . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: hash :: NAME)) ; } hash = Some (map . next_value () ?) ; } Field :: source_chain => { if source_chain . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: source_chain :: NAME)) ; } source_chain = Some (map . next_value () ?) ; } Field :: dest_chain => { if dest_chain . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: dest_chain :: NAME)) ; } dest_chain = Some (map . next_value () ?) ; } Field :: delivery_type => { if delivery_type . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: delivery_type :: NAME)) ; } delivery_type = Some (map . next_value () ?) ; } Field :: gas_used => { if gas_used . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: gas_used :: NAME)) ; } gas_used = Some (map . next_value () ?) ; } Field :: gas_price => { if gas_price . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: gas_price :: NAME)) ; } gas_price = Some (map . next_value () ?) ; } Field :: cost => { if cost . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: cost :: NAME)) ; } cost = Some (map . next_value () ?) ; } Field :: fee => { if fee . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: fee :: NAME)) ; } fee = Some (map . next_value () ?) ; } Field :: created_at => { if created_at . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: created_at :: NAME)) ; } created_at = Some (map . next_value () ?) ; } Field :: claimed_at => { if claimed_at . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: claimed_at :: NAME)) ; } claimed_at = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: delivery_ledger :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: $ field :: NAME)) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: id :: NAME)) ? ; let hash = hash . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: hash :: NAME)) ? ; let source_chain = source_chain . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: source_chain :: NAME)) ? ; let dest_chain = dest_chain . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: dest_chain :: NAME)) ? ; let delivery_type = delivery_type . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: delivery_type :: NAME)) ? ; let gas_used = gas_used . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: gas_used :: NAME)) ? ; let gas_price = gas_price . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: gas_price :: NAME)) ? ; let cost = cost . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: cost :: NAME)) ? ; let fee = fee . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: fee :: NAME)) ? ; let created_at = created_at . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: created_at :: NAME)) ? ; let claimed_at = claimed_at . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: delivery_ledger :: claimed_at :: NAME)) ? ; Ok (Data { id , hash , source_chain , dest_chain , delivery_type , gas_used , gas_price , cost , fee , created_at , claimed_at , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "hash" , "source_chain" , "dest_chain" , "delivery_type" , "gas_used" , "gas_price" , "cost" , "fee" , "created_at" , "claimed_at"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: delivery_ledger :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "DeliveryLedger" , available relations are "")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: delivery_ledger :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; hash) => { "hash" } ; (@ field_serde_name ; source_chain) => { "source_chain" } ; (@ field_serde_name ; dest_chain) => { "dest_chain" } ; (@ field_serde_name ; delivery_type) => { "delivery_type" } ; (@ field_serde_name ; gas_used) => { "gas_used" } ; (@ field_serde_name ; gas_price) => { "gas_price" } ; (@ field_serde_name ; cost) => { "cost" } ; (@ field_serde_name ; fee) => { "fee" } ; (@ field_serde_name ; created_at) => { "created_at" } ; (@ field_serde_name ; claimed_at) => { "claimed_at" } ; }
+	pub use _include_delivery_ledger as include;
+	pub enum IncludeParam {
+		Id(id::Include),
+		Hash(hash::Include),
+		SourceChain(source_chain::Include),
+		DestChain(dest_chain::Include),
+		DeliveryType(delivery_type::Include),
+		GasUsed(gas_used::Include),
+		GasPrice(gas_price::Include),
+		Cost(cost::Include),
+		Fee(fee::Include),
+		CreatedAt(created_at::Include),
+		ClaimedAt(claimed_at::Include),
+	}
+	impl IncludeParam {
+		pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+			match self {
+				Self::Id(data) => data.to_selection(),
+				Self::Hash(data) => data.to_selection(),
+				Self::SourceChain(data) => data.to_selection(),
+				Self::DestChain(data) => data.to_selection(),
+				Self::DeliveryType(data) => data.to_selection(),
+				Self::GasUsed(data) => data.to_selection(),
+				Self::GasPrice(data) => data.to_selection(),
+				Self::Cost(data) => data.to_selection(),
+				Self::Fee(data) => data.to_selection(),
+				Self::CreatedAt(data) => data.to_selection(),
+				Self::ClaimedAt(data) => data.to_selection(),
+			}
+		}
+	}
+	#[macro_export]
+	macro_rules ! _partial_unchecked_delivery_ledger { ($ struct_name : ident { $ ($ scalar_field : ident) + }) => { :: prisma_client_rust :: macros :: partial_unchecked ! { crate :: prisma :: delivery_ledger struct $ struct_name { # [serde (rename = "id")] pub id : i32 , # [serde (rename = "hash")] pub hash : String , # [serde (rename = "source_chain")] pub source_chain : String , # [serde (rename = "dest_chain")] pub dest_chain : String , # [serde (rename = "delivery_type")] pub delivery_type : i32 , # [serde (rename = "gas_used")] pub gas_used : String , # [serde (rename = "gas_price")] pub gas_price : String , # [serde (rename = "cost")] pub cost : String , # [serde (rename = "fee")] pub fee : String , # [serde (rename = "created_at")] pub created_at : i32 , # [serde (rename = "claimed_at")] pub claimed_at : i32 } [$ ($ scalar_field) , +] } } ; }
+	pub use _partial_unchecked_delivery_ledger as partial_unchecked;
+	#[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+	pub struct Data {
+		#[serde(rename = "id")]
+		pub id: i32,
+		#[serde(rename = "hash")]
+		pub hash: String,
+		#[serde(rename = "source_chain")]
+		pub source_chain: String,
+		#[serde(rename = "dest_chain")]
+		pub dest_chain: String,
+		#[serde(rename = "delivery_type")]
+		pub delivery_type: i32,
+		#[serde(rename = "gas_used")]
+		pub gas_used: String,
+		#[serde(rename = "gas_price")]
+		pub gas_price: String,
+		#[serde(rename = "cost")]
+		pub cost: String,
+		#[serde(rename = "fee")]
+		pub fee: String,
+		#[serde(rename = "created_at")]
+		pub created_at: i32,
+		#[serde(rename = "claimed_at")]
+		pub claimed_at: i32,
+	}
+	impl Data {}
+	#[derive(Clone)]
+	pub enum WithParam {}
+	impl Into<::prisma_client_rust::Selection> for WithParam {
+		fn into(self) -> ::prisma_client_rust::Selection {
+			match self {}
+		}
+	}
+	#[derive(Clone)]
+	pub enum SetParam {
+		SetId(i32),
+		IncrementId(i32),
+		DecrementId(i32),
+		MultiplyId(i32),
+		DivideId(i32),
+		SetHash(String),
+		SetSourceChain(String),
+		SetDestChain(String),
+		SetDeliveryType(i32),
+		IncrementDeliveryType(i32),
+		DecrementDeliveryType(i32),
+		MultiplyDeliveryType(i32),
+		DivideDeliveryType(i32),
+		SetGasUsed(String),
+		SetGasPrice(String),
+		SetCost(String),
+		SetFee(String),
+		SetCreatedAt(i32),
+		IncrementCreatedAt(i32),
+		DecrementCreatedAt(i32),
+		MultiplyCreatedAt(i32),
+		DivideCreatedAt(i32),
+		SetClaimedAt(i32),
+		IncrementClaimedAt(i32),
+		DecrementClaimedAt(i32),
+		MultiplyClaimedAt(i32),
+		DivideClaimedAt(i32),
+	}
+	impl From<SetParam> for (String, ::prisma_client_rust::PrismaValue) {
+		fn from(param: SetParam) -> Self {
+			match param {
+				SetParam::SetId(value) =>
+					(id::NAME.to_string(), ::prisma_client_rust::PrismaValue::Int(value as i64)),
+				SetParam::IncrementId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetHash(value) =>
+					(hash::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetSourceChain(value) => (
+					source_chain::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(value),
+				),
+				SetParam::SetDestChain(value) =>
+					(dest_chain::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetDeliveryType(value) => (
+					delivery_type::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Int(value as i64),
+				),
+				SetParam::IncrementDeliveryType(value) => (
+					delivery_type::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementDeliveryType(value) => (
+					delivery_type::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyDeliveryType(value) => (
+					delivery_type::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideDeliveryType(value) => (
+					delivery_type::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetGasUsed(value) =>
+					(gas_used::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetGasPrice(value) =>
+					(gas_price::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetCost(value) =>
+					(cost::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetFee(value) =>
+					(fee::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Int(value as i64),
+				),
+				SetParam::IncrementCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetClaimedAt(value) => (
+					claimed_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Int(value as i64),
+				),
+				SetParam::IncrementClaimedAt(value) => (
+					claimed_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementClaimedAt(value) => (
+					claimed_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyClaimedAt(value) => (
+					claimed_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideClaimedAt(value) => (
+					claimed_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum UncheckedSetParam {
+		Id(i32),
+		Hash(String),
+		SourceChain(String),
+		DestChain(String),
+		DeliveryType(i32),
+		GasUsed(String),
+		GasPrice(String),
+		Cost(String),
+		Fee(String),
+		CreatedAt(i32),
+		ClaimedAt(i32),
+	}
+	impl From<UncheckedSetParam> for SetParam {
+		fn from(param: UncheckedSetParam) -> Self {
+			match param {
+				UncheckedSetParam::Id(value) => Self::SetId(value),
+				UncheckedSetParam::Hash(value) => Self::SetHash(value),
+				UncheckedSetParam::SourceChain(value) => Self::SetSourceChain(value),
+				UncheckedSetParam::DestChain(value) => Self::SetDestChain(value),
+				UncheckedSetParam::DeliveryType(value) => Self::SetDeliveryType(value),
+				UncheckedSetParam::GasUsed(value) => Self::SetGasUsed(value),
+				UncheckedSetParam::GasPrice(value) => Self::SetGasPrice(value),
+				UncheckedSetParam::Cost(value) => Self::SetCost(value),
+				UncheckedSetParam::Fee(value) => Self::SetFee(value),
+				UncheckedSetParam::CreatedAt(value) => Self::SetCreatedAt(value),
+				UncheckedSetParam::ClaimedAt(value) => Self::SetClaimedAt(value),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum OrderByParam {
+		Id(::prisma_client_rust::Direction),
+		Hash(::prisma_client_rust::Direction),
+		SourceChain(::prisma_client_rust::Direction),
+		DestChain(::prisma_client_rust::Direction),
+		DeliveryType(::prisma_client_rust::Direction),
+		GasUsed(::prisma_client_rust::Direction),
+		GasPrice(::prisma_client_rust::Direction),
+		Cost(::prisma_client_rust::Direction),
+		Fee(::prisma_client_rust::Direction),
+		CreatedAt(::prisma_client_rust::Direction),
+		ClaimedAt(::prisma_client_rust::Direction),
+	}
+	impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+		fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+			match self {
+				Self::Id(direction) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Hash(direction) => (
+					hash::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::SourceChain(direction) => (
+					source_chain::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::DestChain(direction) => (
+					dest_chain::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::DeliveryType(direction) => (
+					delivery_type::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::GasUsed(direction) => (
+					gas_used::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::GasPrice(direction) => (
+					gas_price::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Cost(direction) => (
+					cost::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Fee(direction) => (
+					fee::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::CreatedAt(direction) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::ClaimedAt(direction) => (
+					claimed_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum WhereParam {
+		Not(Vec<WhereParam>),
+		Or(Vec<WhereParam>),
+		And(Vec<WhereParam>),
+		Id(_prisma::read_filters::IntFilter),
+		Hash(_prisma::read_filters::StringFilter),
+		SourceChain(_prisma::read_filters::StringFilter),
+		DestChain(_prisma::read_filters::StringFilter),
+		DeliveryType(_prisma::read_filters::IntFilter),
+		GasUsed(_prisma::read_filters::StringFilter),
+		GasPrice(_prisma::read_filters::StringFilter),
+		Cost(_prisma::read_filters::StringFilter),
+		Fee(_prisma::read_filters::StringFilter),
+		CreatedAt(_prisma::read_filters::IntFilter),
+		ClaimedAt(_prisma::read_filters::IntFilter),
+	}
+	impl ::prisma_client_rust::WhereInput for WhereParam {
+		fn serialize(self) -> ::prisma_client_rust::SerializedWhereInput {
+			let (name, value) = match self {
+				Self::Not(value) => (
+					"NOT",
+					::prisma_client_rust::SerializedWhereValue::Object(
+						::prisma_client_rust::merge_fields(
+							value
+								.into_iter()
+								.map(::prisma_client_rust::WhereInput::serialize)
+								.map(Into::into)
+								.collect(),
+						),
+					),
+				),
+				Self::Or(value) => (
+					"OR",
+					::prisma_client_rust::SerializedWhereValue::List(
+						value
+							.into_iter()
+							.map(::prisma_client_rust::WhereInput::serialize)
+							.map(Into::into)
+							.map(|v| vec![v])
+							.map(::prisma_client_rust::PrismaValue::Object)
+							.collect(),
+					),
+				),
+				Self::And(value) => (
+					"AND",
+					::prisma_client_rust::SerializedWhereValue::Object(
+						::prisma_client_rust::merge_fields(
+							value
+								.into_iter()
+								.map(::prisma_client_rust::WhereInput::serialize)
+								.map(Into::into)
+								.collect(),
+						),
+					),
+				),
+				Self::Id(value) => (id::NAME, value.into()),
+				Self::Hash(value) => (hash::NAME, value.into()),
+				Self::SourceChain(value) => (source_chain::NAME, value.into()),
+				Self::DestChain(value) => (dest_chain::NAME, value.into()),
+				Self::DeliveryType(value) => (delivery_type::NAME, value.into()),
+				Self::GasUsed(value) => (gas_used::NAME, value.into()),
+				Self::GasPrice(value) => (gas_price::NAME, value.into()),
+				Self::Cost(value) => (cost::NAME, value.into()),
+				Self::Fee(value) => (fee::NAME, value.into()),
+				Self::CreatedAt(value) => (created_at::NAME, value.into()),
+				Self::ClaimedAt(value) => (claimed_at::NAME, value.into()),
+			};
+			::prisma_client_rust::SerializedWhereInput::new(name, value.into())
+		}
+	}
+	#[derive(Clone)]
+	pub enum UniqueWhereParam {
+		IdEquals(i32),
+	}
+	impl From<UniqueWhereParam> for WhereParam {
+		fn from(value: UniqueWhereParam) -> Self {
+			match value {
+				UniqueWhereParam::IdEquals(value) =>
+					Self::Id(_prisma::read_filters::IntFilter::Equals(value)),
+			}
+		}
+	}
+	impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+		fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+			match op {
+				::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+				::prisma_client_rust::Operator::And(value) => Self::And(value),
+				::prisma_client_rust::Operator::Or(value) => Self::Or(value),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub struct Types;
+	impl ::prisma_client_rust::ModelTypes for Types {
+		type Data = Data;
+		type Where = WhereParam;
+		type UncheckedSet = UncheckedSetParam;
+		type Set = SetParam;
+		type With = WithParam;
+		type OrderBy = OrderByParam;
+		type Cursor = UniqueWhereParam;
+		const MODEL: &'static str = NAME;
+		fn scalar_selections() -> Vec<::prisma_client_rust::Selection> {
+			vec![
+				::prisma_client_rust::sel(id::NAME),
+				::prisma_client_rust::sel(hash::NAME),
+				::prisma_client_rust::sel(source_chain::NAME),
+				::prisma_client_rust::sel(dest_chain::NAME),
+				::prisma_client_rust::sel(delivery_type::NAME),
+				::prisma_client_rust::sel(gas_used::NAME),
+				::prisma_client_rust::sel(gas_price::NAME),
+				::prisma_client_rust::sel(cost::NAME),
+				::prisma_client_rust::sel(fee::NAME),
+				::prisma_client_rust::sel(created_at::NAME),
+				::prisma_client_rust::sel(claimed_at::NAME),
+			]
+		}
+	}
+	pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<Types>;
+	pub type ManyArgs = ::prisma_client_rust::ManyArgs<Types>;
+	pub type Count<'a> = ::prisma_client_rust::Count<'a, Types>;
+	pub type Create<'a> = ::prisma_client_rust::Create<'a, Types>;
+	pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, Types>;
+	pub type FindUnique<'a> = ::prisma_client_rust::FindUnique<'a, Types>;
+	pub type FindMany<'a> = ::prisma_client_rust::FindMany<'a, Types>;
+	pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<'a, Types>;
+	pub type Update<'a> = ::prisma_client_rust::Update<'a, Types>;
+	pub type UpdateMany<'a> = ::prisma_client_rust::UpdateMany<'a, Types>;
+	pub type Upsert<'a> = ::prisma_client_rust::Upsert<'a, Types>;
+	pub type Delete<'a> = ::prisma_client_rust::Delete<'a, Types>;
+	pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, Types>;
+	#[derive(Clone)]
+	pub struct Actions<'a> {
+		pub client: &'a ::prisma_client_rust::PrismaClientInternals,
+	}
+	impl<'a> Actions<'a> {
+		pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+			FindUnique::new(self.client, _where.into())
+		}
+		pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+			FindFirst::new(self.client, _where)
+		}
+		pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+			FindMany::new(self.client, _where)
+		}
+		pub fn create(
+			self,
+			hash: String,
+			source_chain: String,
+			dest_chain: String,
+			delivery_type: i32,
+			gas_used: String,
+			gas_price: String,
+			cost: String,
+			fee: String,
+			created_at: i32,
+			claimed_at: i32,
+			mut _params: Vec<SetParam>,
+		) -> Create<'a> {
+			_params.extend([
+				hash::set(hash),
+				source_chain::set(source_chain),
+				dest_chain::set(dest_chain),
+				delivery_type::set(delivery_type),
+				gas_used::set(gas_used),
+				gas_price::set(gas_price),
+				cost::set(cost),
+				fee::set(fee),
+				created_at::set(created_at),
+				claimed_at::set(claimed_at),
+			]);
+			Create::new(self.client, _params)
+		}
+		pub fn create_unchecked(
+			self,
+			hash: String,
+			source_chain: String,
+			dest_chain: String,
+			delivery_type: i32,
+			gas_used: String,
+			gas_price: String,
+			cost: String,
+			fee: String,
+			created_at: i32,
+			claimed_at: i32,
+			mut _params: Vec<UncheckedSetParam>,
+		) -> Create<'a> {
+			_params.extend([
+				hash::set(hash),
+				source_chain::set(source_chain),
+				dest_chain::set(dest_chain),
+				delivery_type::set(delivery_type),
+				gas_used::set(gas_used),
+				gas_price::set(gas_price),
+				cost::set(cost),
+				fee::set(fee),
+				created_at::set(created_at),
+				claimed_at::set(claimed_at),
+			]);
+			Create::new(self.client, _params.into_iter().map(Into::into).collect())
+		}
+		pub fn update(self, _where: UniqueWhereParam, _params: Vec<SetParam>) -> Update<'a> {
+			Update::new(self.client, _where.into(), _params, vec![])
+		}
+		pub fn update_unchecked(
+			self,
+			_where: UniqueWhereParam,
+			_params: Vec<UncheckedSetParam>,
+		) -> Update<'a> {
+			Update::new(
+				self.client,
+				_where.into(),
+				_params.into_iter().map(Into::into).collect(),
+				vec![],
+			)
+		}
+		pub fn update_many(
+			self,
+			_where: Vec<WhereParam>,
+			_params: Vec<SetParam>,
+		) -> UpdateMany<'a> {
+			UpdateMany::new(self.client, _where, _params)
+		}
+		pub fn upsert(
+			self,
+			_where: UniqueWhereParam,
+			(
+				hash,
+				source_chain,
+				dest_chain,
+				delivery_type,
+				gas_used,
+				gas_price,
+				cost,
+				fee,
+				created_at,
+				claimed_at,
+				mut _params,
+			): (
+				String,
+				String,
+				String,
+				i32,
+				String,
+				String,
+				String,
+				String,
+				i32,
+				i32,
+				Vec<SetParam>,
+			),
+			_update: Vec<SetParam>,
+		) -> Upsert<'a> {
+			_params.extend([
+				hash::set(hash),
+				source_chain::set(source_chain),
+				dest_chain::set(dest_chain),
+				delivery_type::set(delivery_type),
+				gas_used::set(gas_used),
+				gas_price::set(gas_price),
+				cost::set(cost),
+				fee::set(fee),
+				created_at::set(created_at),
+				claimed_at::set(claimed_at),
+			]);
+			Upsert::new(self.client, _where.into(), _params, _update)
+		}
+		pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+			Delete::new(self.client, _where.into(), vec![])
+		}
+		pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+			DeleteMany::new(self.client, _where)
+		}
+		pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+			Count::new(self.client, _where)
+		}
+		pub fn find_raw<T: ::prisma_client_rust::Data>(
+			self,
+		) -> ::prisma_client_rust::FindRaw<'a, Types, T> {
+			::prisma_client_rust::FindRaw::new(self.client)
+		}
+		pub fn aggregate_raw<T: ::prisma_client_rust::Data>(
+			self,
+		) -> ::prisma_client_rust::AggregateRaw<'a, Types, T> {
+			::prisma_client_rust::AggregateRaw::new(self.client)
+		}
+	}
+}
+pub mod withdrawals {
+	use super::{_prisma::*, *};
+	pub const NAME: &str = "Withdrawals";
+	pub mod id {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "id";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetId(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Id(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Id(direction)
+		}
+		pub fn equals<T: From<UniqueWhereParam>>(value: i32) -> T {
+			UniqueWhereParam::IdEquals(value).into()
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::IntFilter, Id, {
+			fn in_vec(_: Vec<i32>) -> InVec;
+			fn not_in_vec(_: Vec<i32>) -> NotInVec;
+			fn lt(_: i32) -> Lt;
+			fn lte(_: i32) -> Lte;
+			fn gt(_: i32) -> Gt;
+			fn gte(_: i32) -> Gte;
+			fn not(_: i32) -> Not;
+		});
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementId(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementId(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyId(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideId(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Id(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Id(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod chain {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "chain";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetChain(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Chain(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Chain(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::Chain(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			Chain,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Chain(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Chain(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod hash {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "hash";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetHash(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Hash(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Hash(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::Hash(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::StringFilter, Hash, {
+			fn in_vec(_: Vec<String>) -> InVec;
+			fn not_in_vec(_: Vec<String>) -> NotInVec;
+			fn lt(_: String) -> Lt;
+			fn lte(_: String) -> Lte;
+			fn gt(_: String) -> Gt;
+			fn gte(_: String) -> Gte;
+			fn contains(_: String) -> Contains;
+			fn starts_with(_: String) -> StartsWith;
+			fn ends_with(_: String) -> EndsWith;
+			fn not(_: String) -> Not;
+		});
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Hash(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Hash(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod amount {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "amount";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetAmount(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Amount(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Amount(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::Amount(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			Amount,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Amount(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Amount(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod created_at {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "created_at";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetCreatedAt(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::CreatedAt(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::CreatedAt(direction)
+		}
+		pub fn equals(value: i32) -> WhereParam {
+			WhereParam::CreatedAt(_prisma::read_filters::IntFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::IntFilter,
+			CreatedAt,
+			{
+				fn in_vec(_: Vec<i32>) -> InVec;
+				fn not_in_vec(_: Vec<i32>) -> NotInVec;
+				fn lt(_: i32) -> Lt;
+				fn lte(_: i32) -> Lte;
+				fn gt(_: i32) -> Gt;
+				fn gte(_: i32) -> Gte;
+				fn not(_: i32) -> Not;
+			}
+		);
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementCreatedAt(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementCreatedAt(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyCreatedAt(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideCreatedAt(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::CreatedAt(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::CreatedAt(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod settled_at {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "settled_at";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetSettledAt(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SettledAt(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::SettledAt(direction)
+		}
+		pub fn equals(value: i32) -> WhereParam {
+			WhereParam::SettledAt(_prisma::read_filters::IntFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::IntFilter,
+			SettledAt,
+			{
+				fn in_vec(_: Vec<i32>) -> InVec;
+				fn not_in_vec(_: Vec<i32>) -> NotInVec;
+				fn lt(_: i32) -> Lt;
+				fn lte(_: i32) -> Lte;
+				fn gt(_: i32) -> Gt;
+				fn gte(_: i32) -> Gte;
+				fn not(_: i32) -> Not;
+			}
+		);
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementSettledAt(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementSettledAt(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplySettledAt(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideSettledAt(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::SettledAt(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::SettledAt(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub fn create(
+		chain: String,
+		hash: String,
+		amount: String,
+		created_at: i32,
+		settled_at: i32,
+		_params: Vec<SetParam>,
+	) -> (String, String, String, i32, i32, Vec<SetParam>) {
+		(chain, hash, amount, created_at, settled_at, _params)
+	}
+	pub fn create_unchecked(
+		chain: String,
+		hash: String,
+		amount: String,
+		created_at: i32,
+		settled_at: i32,
+		_params: Vec<SetParam>,
+	) -> (String, String, String, i32, i32, Vec<SetParam>) {
+		(chain, hash, amount, created_at, settled_at, _params)
+	}
+	#[macro_export]
+	macro_rules ! _select_withdrawals { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: withdrawals :: select ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: withdrawals :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: withdrawals :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: withdrawals :: select ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: withdrawals :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: withdrawals :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , chain , hash , amount , created_at , settled_at } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : crate :: prisma :: withdrawals :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) +] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: withdrawals :: $ field :: NAME , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: withdrawals :: $ field :: NAME) , + ,] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: withdrawals :: $ field :: NAME => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: withdrawals :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: withdrawals :: $ field :: NAME)) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "chain" , "hash" , "amount" , "created_at" , "settled_at"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: withdrawals :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { i32 } ; (@ field_type ; chain) => { String } ; (@ field_type ; hash) => { String } ; (@ field_type ; amount) => { String } ; (@ field_type ; created_at) => { i32 } ; (@ field_type ; settled_at) => { i32 } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Withdrawals" , available relations are "id, chain, hash, amount, created_at, settled_at")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < crate :: prisma :: withdrawals :: SelectParam > :: into (crate :: prisma :: withdrawals :: id :: Select) } ; (@ selection_field_to_selection_param ; chain) => { Into :: < crate :: prisma :: withdrawals :: SelectParam > :: into (crate :: prisma :: withdrawals :: chain :: Select) } ; (@ selection_field_to_selection_param ; hash) => { Into :: < crate :: prisma :: withdrawals :: SelectParam > :: into (crate :: prisma :: withdrawals :: hash :: Select) } ; (@ selection_field_to_selection_param ; amount) => { Into :: < crate :: prisma :: withdrawals :: SelectParam > :: into (crate :: prisma :: withdrawals :: amount :: Select) } ; (@ selection_field_to_selection_param ; created_at) => { Into :: < crate :: prisma :: withdrawals :: SelectParam > :: into (crate :: prisma :: withdrawals :: created_at :: Select) } ; (@ selection_field_to_selection_param ; settled_at) => { Into :: < crate :: prisma :: withdrawals :: SelectParam > :: into (crate :: prisma :: withdrawals :: settled_at :: Select) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: withdrawals :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; chain) => { "chain" } ; (@ field_serde_name ; hash) => { "hash" } ; (@ field_serde_name ; amount) => { "amount" } ; (@ field_serde_name ; created_at) => { "created_at" } ; (@ field_serde_name ; settled_at) => { "settled_at" } ; }
+	pub use _select_withdrawals as select;
+	pub enum SelectParam {
+		Id(id::Select),
+		Chain(chain::Select),
+		Hash(hash::Select),
+		Amount(amount::Select),
+		CreatedAt(created_at::Select),
+		SettledAt(settled_at::Select),
+	}
+	impl SelectParam {
+		pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+			match self {
+				Self::Id(data) => data.to_selection(),
+				Self::Chain(data) => data.to_selection(),
+				Self::Hash(data) => data.to_selection(),
+				Self::Amount(data) => data.to_selection(),
+				Self::CreatedAt(data) => data.to_selection(),
+				Self::SettledAt(data) => data.to_selection(),
+			}
+		}
+	}
+	#[macro_export]
+	macro_rules ! _include_withdrawals { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: withdrawals :: include ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: withdrawals :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: withdrawals :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: withdrawals :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: withdrawals :: include ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: withdrawals :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: withdrawals :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: withdrawals :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : i32 , pub chain : String , pub hash : String , pub amount : String , pub created_at : i32 , pub settled_at : i32 , $ (pub $ field : crate :: prisma :: withdrawals :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (chain) , stringify ! (hash) , stringify ! (amount) , stringify ! (created_at) , stringify ! (settled_at)] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: withdrawals :: $ field :: NAME , & self . $ field) ? ;) * state . serialize_field (crate :: prisma :: withdrawals :: id :: NAME , & self . id) ? ; state . serialize_field (crate :: prisma :: withdrawals :: chain :: NAME , & self . chain) ? ; state . serialize_field (crate :: prisma :: withdrawals :: hash :: NAME , & self . hash) ? ; state . serialize_field (crate :: prisma :: withdrawals :: amount :: NAME , & self . amount) ? ; state . serialize_field (crate :: prisma :: withdrawals :: created_at :: NAME , & self . created_at) ? ; state . serialize_field (crate :: prisma :: withdrawals :: settled_at :: NAME , & self . settled_at) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , chain , hash , amount , created_at , settled_at } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: withdrawals :: $ field :: NAME) , + , crate :: prisma :: withdrawals :: id :: NAME , crate :: prisma :: withdrawals :: chain :: NAME , crate :: prisma :: withdrawals :: hash :: NAME , crate :: prisma :: withdrawals :: amount :: NAME , crate :: prisma :: withdrawals :: created_at :: NAME , crate :: prisma :: withdrawals :: settled_at :: NAME] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: withdrawals :: $ field :: NAME => Ok (Field :: $ field)) , * , crate :: prisma :: withdrawals :: id :: NAME => Ok (Field :: id) , crate :: prisma :: withdrawals :: chain :: NAME => Ok (Field :: chain) , crate :: prisma :: withdrawals :: hash :: NAME => Ok (Field :: hash) , crate :: prisma :: withdrawals :: amount :: NAME => Ok (Field :: amount) , crate :: prisma :: withdrawals :: created_at :: NAME => Ok (Field :: created_at) , crate :: prisma :: withdrawals :: settled_at :: NAME => Ok (Field :: settled_at) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut chain = None ; let mut hash = None ; let mut amount = None ; let mut created_at = None ; let mut settled_at = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: withdrawals :: id :: NAME)) ; } id = Some (map . next_value () ?) ; } Field :: chain => { if chain . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: withdrawals :: chain :: NAME)) ; } chain = Some (map . next_value () ?) ; } Field :: hash => { if hash . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: withdrawals :: hash :: NAME)) ; } hash = Some (map . next_value () ?) ; } Field :: amount => { if amount . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: withdrawals :: amount :: NAME)) ; } amount = Some (map . next_value () ?) ; } Field :: created_at => { if created_at . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: withdrawals :: created_at :: NAME)) ; } created_at = Some (map . next_value () ?) ; } Field :: settled_at => { if settled_at . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: withdrawals :: settled_at :: NAME)) ; } settled_at = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: withdrawals :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: withdrawals :: $ field :: NAME)) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: withdrawals :: id :: NAME)) ? ; let chain = chain . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: withdrawals :: chain :: NAME)) ? ; let hash = hash . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: withdrawals :: hash :: NAME)) ? ; let amount = amount . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: withdrawals :: amount :: NAME)) ? ; let created_at = created_at . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: withdrawals :: created_at :: NAME)) ? ; let settled_at = settled_at . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: withdrawals :: settled_at :: NAME)) ? ; Ok (Data { id , chain , hash , amount , created_at , settled_at , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "chain" , "hash" , "amount" , "created_at" , "settled_at"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: withdrawals :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Withdrawals" , available relations are "")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: withdrawals :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; chain) => { "chain" } ; (@ field_serde_name ; hash) => { "hash" } ; (@ field_serde_name ; amount) => { "amount" } ; (@ field_serde_name ; created_at) => { "created_at" } ; (@ field_serde_name ; settled_at) => { "settled_at" } ; }
+	pub use _include_withdrawals as include;
+	pub enum IncludeParam {
+		Id(id::Include),
+		Chain(chain::Include),
+		Hash(hash::Include),
+		Amount(amount::Include),
+		CreatedAt(created_at::Include),
+		SettledAt(settled_at::Include),
+	}
+	impl IncludeParam {
+		pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+			match self {
+				Self::Id(data) => data.to_selection(),
+				Self::Chain(data) => data.to_selection(),
+				Self::Hash(data) => data.to_selection(),
+				Self::Amount(data) => data.to_selection(),
+				Self::CreatedAt(data) => data.to_selection(),
+				Self::SettledAt(data) => data.to_selection(),
+			}
+		}
+	}
+	#[macro_export]
+	macro_rules ! _partial_unchecked_withdrawals { ($ struct_name : ident { $ ($ scalar_field : ident) + }) => { :: prisma_client_rust :: macros :: partial_unchecked ! { crate :: prisma :: withdrawals struct $ struct_name { # [serde (rename = "id")] pub id : i32 , # [serde (rename = "chain")] pub chain : String , # [serde (rename = "hash")] pub hash : String , # [serde (rename = "amount")] pub amount : String , # [serde (rename = "created_at")] pub created_at : i32 , # [serde (rename = "settled_at")] pub settled_at : i32 } [$ ($ scalar_field) , +] } } ; }
+	pub use _partial_unchecked_withdrawals as partial_unchecked;
+	#[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+	pub struct Data {
+		#[serde(rename = "id")]
+		pub id: i32,
+		#[serde(rename = "chain")]
+		pub chain: String,
+		#[serde(rename = "hash")]
+		pub hash: String,
+		#[serde(rename = "amount")]
+		pub amount: String,
+		#[serde(rename = "created_at")]
+		pub created_at: i32,
+		#[serde(rename = "settled_at")]
+		pub settled_at: i32,
+	}
+	impl Data {}
+	#[derive(Clone)]
+	pub enum WithParam {}
+	impl Into<::prisma_client_rust::Selection> for WithParam {
+		fn into(self) -> ::prisma_client_rust::Selection {
+			match self {}
+		}
+	}
+	#[derive(Clone)]
+	pub enum SetParam {
+		SetId(i32),
+		IncrementId(i32),
+		DecrementId(i32),
+		MultiplyId(i32),
+		DivideId(i32),
+		SetChain(String),
+		SetHash(String),
+		SetAmount(String),
+		SetCreatedAt(i32),
+		IncrementCreatedAt(i32),
+		DecrementCreatedAt(i32),
+		MultiplyCreatedAt(i32),
+		DivideCreatedAt(i32),
+		SetSettledAt(i32),
+		IncrementSettledAt(i32),
+		DecrementSettledAt(i32),
+		MultiplySettledAt(i32),
+		DivideSettledAt(i32),
+	}
+	impl From<SetParam> for (String, ::prisma_client_rust::PrismaValue) {
+		fn from(param: SetParam) -> Self {
+			match param {
+				SetParam::SetId(value) =>
+					(id::NAME.to_string(), ::prisma_client_rust::PrismaValue::Int(value as i64)),
+				SetParam::IncrementId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetChain(value) =>
+					(chain::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetHash(value) =>
+					(hash::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetAmount(value) =>
+					(amount::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Int(value as i64),
+				),
+				SetParam::IncrementCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideCreatedAt(value) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetSettledAt(value) => (
+					settled_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Int(value as i64),
+				),
+				SetParam::IncrementSettledAt(value) => (
+					settled_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementSettledAt(value) => (
+					settled_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplySettledAt(value) => (
+					settled_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideSettledAt(value) => (
+					settled_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum UncheckedSetParam {
+		Id(i32),
+		Chain(String),
+		Hash(String),
+		Amount(String),
+		CreatedAt(i32),
+		SettledAt(i32),
+	}
+	impl From<UncheckedSetParam> for SetParam {
+		fn from(param: UncheckedSetParam) -> Self {
+			match param {
+				UncheckedSetParam::Id(value) => Self::SetId(value),
+				UncheckedSetParam::Chain(value) => Self::SetChain(value),
+				UncheckedSetParam::Hash(value) => Self::SetHash(value),
+				UncheckedSetParam::Amount(value) => Self::SetAmount(value),
+				UncheckedSetParam::CreatedAt(value) => Self::SetCreatedAt(value),
+				UncheckedSetParam::SettledAt(value) => Self::SetSettledAt(value),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum OrderByParam {
+		Id(::prisma_client_rust::Direction),
+		Chain(::prisma_client_rust::Direction),
+		Hash(::prisma_client_rust::Direction),
+		Amount(::prisma_client_rust::Direction),
+		CreatedAt(::prisma_client_rust::Direction),
+		SettledAt(::prisma_client_rust::Direction),
+	}
+	impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+		fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+			match self {
+				Self::Id(direction) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Chain(direction) => (
+					chain::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Hash(direction) => (
+					hash::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::Amount(direction) => (
+					amount::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::CreatedAt(direction) => (
+					created_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::SettledAt(direction) => (
+					settled_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum WhereParam {
+		Not(Vec<WhereParam>),
+		Or(Vec<WhereParam>),
+		And(Vec<WhereParam>),
+		Id(_prisma::read_filters::IntFilter),
+		Chain(_prisma::read_filters::StringFilter),
+		Hash(_prisma::read_filters::StringFilter),
+		Amount(_prisma::read_filters::StringFilter),
+		CreatedAt(_prisma::read_filters::IntFilter),
+		SettledAt(_prisma::read_filters::IntFilter),
+	}
+	impl ::prisma_client_rust::WhereInput for WhereParam {
+		fn serialize(self) -> ::prisma_client_rust::SerializedWhereInput {
+			let (name, value) = match self {
+				Self::Not(value) => (
+					"NOT",
+					::prisma_client_rust::SerializedWhereValue::Object(
+						::prisma_client_rust::merge_fields(
+							value
+								.into_iter()
+								.map(::prisma_client_rust::WhereInput::serialize)
+								.map(Into::into)
+								.collect(),
+						),
+					),
+				),
+				Self::Or(value) => (
+					"OR",
+					::prisma_client_rust::SerializedWhereValue::List(
+						value
+							.into_iter()
+							.map(::prisma_client_rust::WhereInput::serialize)
+							.map(Into::into)
+							.map(|v| vec![v])
+							.map(::prisma_client_rust::PrismaValue::Object)
+							.collect(),
+					),
+				),
+				Self::And(value) => (
+					"AND",
+					::prisma_client_rust::SerializedWhereValue::Object(
+						::prisma_client_rust::merge_fields(
+							value
+								.into_iter()
+								.map(::prisma_client_rust::WhereInput::serialize)
+								.map(Into::into)
+								.collect(),
+						),
+					),
+				),
+				Self::Id(value) => (id::NAME, value.into()),
+				Self::Chain(value) => (chain::NAME, value.into()),
+				Self::Hash(value) => (hash::NAME, value.into()),
+				Self::Amount(value) => (amount::NAME, value.into()),
+				Self::CreatedAt(value) => (created_at::NAME, value.into()),
+				Self::SettledAt(value) => (settled_at::NAME, value.into()),
+			};
+			::prisma_client_rust::SerializedWhereInput::new(name, value.into())
+		}
+	}
+	#[derive(Clone)]
+	pub enum UniqueWhereParam {
+		IdEquals(i32),
+	}
+	impl From<UniqueWhereParam> for WhereParam {
+		fn from(value: UniqueWhereParam) -> Self {
+			match value {
+				UniqueWhereParam::IdEquals(value) =>
+					Self::Id(_prisma::read_filters::IntFilter::Equals(value)),
+			}
+		}
+	}
+	impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+		fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+			match op {
+				::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+				::prisma_client_rust::Operator::And(value) => Self::And(value),
+				::prisma_client_rust::Operator::Or(value) => Self::Or(value),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub struct Types;
+	impl ::prisma_client_rust::ModelTypes for Types {
+		type Data = Data;
+		type Where = WhereParam;
+		type UncheckedSet = UncheckedSetParam;
+		type Set = SetParam;
+		type With = WithParam;
+		type OrderBy = OrderByParam;
+		type Cursor = UniqueWhereParam;
+		const MODEL: &'static str = NAME;
+		fn scalar_selections() -> Vec<::prisma_client_rust::Selection> {
+			vec![
+				::prisma_client_rust::sel(id::NAME),
+				::prisma_client_rust::sel(chain::NAME),
+				::prisma_client_rust::sel(hash::NAME),
+				::prisma_client_rust::sel(amount::NAME),
+				::prisma_client_rust::sel(created_at::NAME),
+				::prisma_client_rust::sel(settled_at::NAME),
+			]
+		}
+	}
+	pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<Types>;
+	pub type ManyArgs = ::prisma_client_rust::ManyArgs<Types>;
+	pub type Count<'a> = ::prisma_client_rust::Count<'a, Types>;
+	pub type Create<'a> = ::prisma_client_rust::Create<'a, Types>;
+	pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, Types>;
+	pub type FindUnique<'a> = ::prisma_client_rust::FindUnique<'a, Types>;
+	pub type FindMany<'a> = ::prisma_client_rust::FindMany<'a, Types>;
+	pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<'a, Types>;
+	pub type Update<'a> = ::prisma_client_rust::Update<'a, Types>;
+	pub type UpdateMany<'a> = ::prisma_client_rust::UpdateMany<'a, Types>;
+	pub type Upsert<'a> = ::prisma_client_rust::Upsert<'a, Types>;
+	pub type Delete<'a> = ::prisma_client_rust::Delete<'a, Types>;
+	pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, Types>;
+	#[derive(Clone)]
+	pub struct Actions<'a> {
+		pub client: &'a ::prisma_client_rust::PrismaClientInternals,
+	}
+	impl<'a> Actions<'a> {
+		pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+			FindUnique::new(self.client, _where.into())
+		}
+		pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+			FindFirst::new(self.client, _where)
+		}
+		pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+			FindMany::new(self.client, _where)
+		}
+		pub fn create(
+			self,
+			chain: String,
+			hash: String,
+			amount: String,
+			created_at: i32,
+			settled_at: i32,
+			mut _params: Vec<SetParam>,
+		) -> Create<'a> {
+			_params.extend([
+				chain::set(chain),
+				hash::set(hash),
+				amount::set(amount),
+				created_at::set(created_at),
+				settled_at::set(settled_at),
+			]);
+			Create::new(self.client, _params)
+		}
+		pub fn create_unchecked(
+			self,
+			chain: String,
+			hash: String,
+			amount: String,
+			created_at: i32,
+			settled_at: i32,
+			mut _params: Vec<UncheckedSetParam>,
+		) -> Create<'a> {
+			_params.extend([
+				chain::set(chain),
+				hash::set(hash),
+				amount::set(amount),
+				created_at::set(created_at),
+				settled_at::set(settled_at),
+			]);
+			Create::new(self.client, _params.into_iter().map(Into::into).collect())
+		}
+		pub fn update(self, _where: UniqueWhereParam, _params: Vec<SetParam>) -> Update<'a> {
+			Update::new(self.client, _where.into(), _params, vec![])
+		}
+		pub fn update_unchecked(
+			self,
+			_where: UniqueWhereParam,
+			_params: Vec<UncheckedSetParam>,
+		) -> Update<'a> {
+			Update::new(
+				self.client,
+				_where.into(),
+				_params.into_iter().map(Into::into).collect(),
+				vec![],
+			)
+		}
+		pub fn update_many(
+			self,
+			_where: Vec<WhereParam>,
+			_params: Vec<SetParam>,
+		) -> UpdateMany<'a> {
+			UpdateMany::new(self.client, _where, _params)
+		}
+		pub fn upsert(
+			self,
+			_where: UniqueWhereParam,
+			(chain, hash, amount, created_at, settled_at, mut _params): (
+				String,
+				String,
+				String,
+				i32,
+				i32,
+				Vec<SetParam>,
+			),
+			_update: Vec<SetParam>,
+		) -> Upsert<'a> {
+			_params.extend([
+				chain::set(chain),
+				hash::set(hash),
+				amount::set(amount),
+				created_at::set(created_at),
+				settled_at::set(settled_at),
+			]);
+			Upsert::new(self.client, _where.into(), _params, _update)
+		}
+		pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+			Delete::new(self.client, _where.into(), vec![])
+		}
+		pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+			DeleteMany::new(self.client, _where)
+		}
+		pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+			Count::new(self.client, _where)
+		}
+		pub fn find_raw<T: ::prisma_client_rust::Data>(
+			self,
+		) -> ::prisma_client_rust::FindRaw<'a, Types, T> {
+			::prisma_client_rust::FindRaw::new(self.client)
+		}
+		pub fn aggregate_raw<T: ::prisma_client_rust::Data>(
+			self,
+		) -> ::prisma_client_rust::AggregateRaw<'a, Types, T> {
+			::prisma_client_rust::AggregateRaw::new(self.client)
+		}
+	}
+}
+pub mod checkpoints {
+	use super::{_prisma::*, *};
+	pub const NAME: &str = "Checkpoints";
+	pub mod id {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "id";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetId(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::Id(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::Id(direction)
+		}
+		pub fn equals<T: From<UniqueWhereParam>>(value: i32) -> T {
+			UniqueWhereParam::IdEquals(value).into()
+		}
+		::prisma_client_rust::scalar_where_param_fns!(_prisma::read_filters::IntFilter, Id, {
+			fn in_vec(_: Vec<i32>) -> InVec;
+			fn not_in_vec(_: Vec<i32>) -> NotInVec;
+			fn lt(_: i32) -> Lt;
+			fn lte(_: i32) -> Lte;
+			fn gt(_: i32) -> Gt;
+			fn gte(_: i32) -> Gte;
+			fn not(_: i32) -> Not;
+		});
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementId(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementId(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyId(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideId(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::Id(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::Id(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod source_chain {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "source_chain";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetSourceChain(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SourceChain(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::SourceChain(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::SourceChain(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			SourceChain,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::SourceChain(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::SourceChain(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod dest_chain {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "dest_chain";
+		pub struct Set(pub String);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetDestChain(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::DestChain(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: String) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::DestChain(direction)
+		}
+		pub fn equals(value: String) -> WhereParam {
+			WhereParam::DestChain(_prisma::read_filters::StringFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::StringFilter,
+			DestChain,
+			{
+				fn in_vec(_: Vec<String>) -> InVec;
+				fn not_in_vec(_: Vec<String>) -> NotInVec;
+				fn lt(_: String) -> Lt;
+				fn lte(_: String) -> Lte;
+				fn gt(_: String) -> Gt;
+				fn gte(_: String) -> Gte;
+				fn contains(_: String) -> Contains;
+				fn starts_with(_: String) -> StartsWith;
+				fn ends_with(_: String) -> EndsWith;
+				fn not(_: String) -> Not;
+			}
+		);
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::DestChain(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::DestChain(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod update_height {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "update_height";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetUpdateHeight(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::UpdateHeight(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::UpdateHeight(direction)
+		}
+		pub fn equals(value: i32) -> WhereParam {
+			WhereParam::UpdateHeight(_prisma::read_filters::IntFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::IntFilter,
+			UpdateHeight,
+			{
+				fn in_vec(_: Vec<i32>) -> InVec;
+				fn not_in_vec(_: Vec<i32>) -> NotInVec;
+				fn lt(_: i32) -> Lt;
+				fn lte(_: i32) -> Lte;
+				fn gt(_: i32) -> Gt;
+				fn gte(_: i32) -> Gte;
+				fn not(_: i32) -> Not;
+			}
+		);
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementUpdateHeight(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementUpdateHeight(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyUpdateHeight(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideUpdateHeight(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::UpdateHeight(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::UpdateHeight(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod scanned_height {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "scanned_height";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetScannedHeight(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::ScannedHeight(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::ScannedHeight(direction)
+		}
+		pub fn equals(value: i32) -> WhereParam {
+			WhereParam::ScannedHeight(_prisma::read_filters::IntFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::IntFilter,
+			ScannedHeight,
+			{
+				fn in_vec(_: Vec<i32>) -> InVec;
+				fn not_in_vec(_: Vec<i32>) -> NotInVec;
+				fn lt(_: i32) -> Lt;
+				fn lte(_: i32) -> Lte;
+				fn gt(_: i32) -> Gt;
+				fn gte(_: i32) -> Gte;
+				fn not(_: i32) -> Not;
+			}
+		);
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementScannedHeight(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementScannedHeight(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyScannedHeight(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideScannedHeight(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::ScannedHeight(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::ScannedHeight(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub mod updated_at {
+		use super::{
+			super::*, OrderByParam, SetParam, UncheckedSetParam, UniqueWhereParam, WhereParam,
+			WithParam, _prisma::*,
+		};
+		pub const NAME: &str = "updated_at";
+		pub struct Set(pub i32);
+		impl From<Set> for SetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::SetUpdatedAt(v)
+			}
+		}
+		impl From<Set> for UncheckedSetParam {
+			fn from(Set(v): Set) -> Self {
+				Self::UpdatedAt(v)
+			}
+		}
+		pub fn set<T: From<Set>>(value: i32) -> T {
+			Set(value).into()
+		}
+		pub fn order(direction: ::prisma_client_rust::Direction) -> OrderByParam {
+			OrderByParam::UpdatedAt(direction)
+		}
+		pub fn equals(value: i32) -> WhereParam {
+			WhereParam::UpdatedAt(_prisma::read_filters::IntFilter::Equals(value))
+		}
+		::prisma_client_rust::scalar_where_param_fns!(
+			_prisma::read_filters::IntFilter,
+			UpdatedAt,
+			{
+				fn in_vec(_: Vec<i32>) -> InVec;
+				fn not_in_vec(_: Vec<i32>) -> NotInVec;
+				fn lt(_: i32) -> Lt;
+				fn lte(_: i32) -> Lte;
+				fn gt(_: i32) -> Gt;
+				fn gte(_: i32) -> Gte;
+				fn not(_: i32) -> Not;
+			}
+		);
+		pub fn increment(value: i32) -> SetParam {
+			SetParam::IncrementUpdatedAt(value)
+		}
+		pub fn decrement(value: i32) -> SetParam {
+			SetParam::DecrementUpdatedAt(value)
+		}
+		pub fn multiply(value: i32) -> SetParam {
+			SetParam::MultiplyUpdatedAt(value)
+		}
+		pub fn divide(value: i32) -> SetParam {
+			SetParam::DivideUpdatedAt(value)
+		}
+		pub struct Include;
+		impl Into<super::IncludeParam> for Include {
+			fn into(self) -> super::IncludeParam {
+				super::IncludeParam::UpdatedAt(self)
+			}
+		}
+		impl Include {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+		pub struct Select;
+		impl Into<super::SelectParam> for Select {
+			fn into(self) -> super::SelectParam {
+				super::SelectParam::UpdatedAt(self)
+			}
+		}
+		impl Select {
+			pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+				::prisma_client_rust::sel(NAME)
+			}
+		}
+	}
+	pub fn create(
+		source_chain: String,
+		dest_chain: String,
+		update_height: i32,
+		scanned_height: i32,
+		updated_at: i32,
+		_params: Vec<SetParam>,
+	) -> (String, String, i32, i32, i32, Vec<SetParam>) {
+		(source_chain, dest_chain, update_height, scanned_height, updated_at, _params)
+	}
+	pub fn create_unchecked(
+		source_chain: String,
+		dest_chain: String,
+		update_height: i32,
+		scanned_height: i32,
+		updated_at: i32,
+		_params: Vec<SetParam>,
+	) -> (String, String, i32, i32, i32, Vec<SetParam>) {
+		(source_chain, dest_chain, update_height, scanned_height, updated_at, _params)
+	}
+	#[macro_export]
+	macro_rules ! _select_checkpoints { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: checkpoints :: select ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: checkpoints :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn select ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: checkpoints :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: checkpoints :: select ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: SelectType for Selection { type Data = Data ; type ModelData = crate :: prisma :: checkpoints :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: checkpoints :: select ! (@ selections_to_params ; : select { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () ,] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { id , source_chain , dest_chain , update_height , scanned_height , updated_at } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { $ (pub $ field : crate :: prisma :: checkpoints :: select ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) +] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: checkpoints :: $ field :: NAME , & self . $ field) ? ;) * state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: checkpoints :: $ field :: NAME) , + ,] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: checkpoints :: $ field :: NAME => Ok (Field :: $ field)) , * , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * while let Some (key) = map . next_key () ? { match key { $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: checkpoints :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: checkpoints :: $ field :: NAME)) ? ;) * Ok (Data { $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "source_chain" , "dest_chain" , "update_height" , "scanned_height" , "updated_at"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: checkpoints :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; id) => { i32 } ; (@ field_type ; source_chain) => { String } ; (@ field_type ; dest_chain) => { String } ; (@ field_type ; update_height) => { i32 } ; (@ field_type ; scanned_height) => { i32 } ; (@ field_type ; updated_at) => { i32 } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Checkpoints" , available relations are "id, source_chain, dest_chain, update_height, scanned_height, updated_at")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; id) => { Into :: < crate :: prisma :: checkpoints :: SelectParam > :: into (crate :: prisma :: checkpoints :: id :: Select) } ; (@ selection_field_to_selection_param ; source_chain) => { Into :: < crate :: prisma :: checkpoints :: SelectParam > :: into (crate :: prisma :: checkpoints :: source_chain :: Select) } ; (@ selection_field_to_selection_param ; dest_chain) => { Into :: < crate :: prisma :: checkpoints :: SelectParam > :: into (crate :: prisma :: checkpoints :: dest_chain :: Select) } ; (@ selection_field_to_selection_param ; update_height) => { Into :: < crate :: prisma :: checkpoints :: SelectParam > :: into (crate :: prisma :: checkpoints :: update_height :: Select) } ; (@ selection_field_to_selection_param ; scanned_height) => { Into :: < crate :: prisma :: checkpoints :: SelectParam > :: into (crate :: prisma :: checkpoints :: scanned_height :: Select) } ; (@ selection_field_to_selection_param ; updated_at) => { Into :: < crate :: prisma :: checkpoints :: SelectParam > :: into (crate :: prisma :: checkpoints :: updated_at :: Select) } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: checkpoints :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; source_chain) => { "source_chain" } ; (@ field_serde_name ; dest_chain) => { "dest_chain" } ; (@ field_serde_name ; update_height) => { "update_height" } ; (@ field_serde_name ; scanned_height) => { "scanned_height" } ; (@ field_serde_name ; updated_at) => { "updated_at" } ; }
+	pub use _select_checkpoints as select;
+	pub enum SelectParam {
+		Id(id::Select),
+		SourceChain(source_chain::Select),
+		DestChain(dest_chain::Select),
+		UpdateHeight(update_height::Select),
+		ScannedHeight(scanned_height::Select),
+		UpdatedAt(updated_at::Select),
+	}
+	impl SelectParam {
+		pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+			match self {
+				Self::Id(data) => data.to_selection(),
+				Self::SourceChain(data) => data.to_selection(),
+				Self::DestChain(data) => data.to_selection(),
+				Self::UpdateHeight(data) => data.to_selection(),
+				Self::ScannedHeight(data) => data.to_selection(),
+				Self::UpdatedAt(data) => data.to_selection(),
+			}
+		}
+	}
+	#[macro_export]
+	macro_rules ! _include_checkpoints { ($ (($ ($ func_arg : ident : $ func_arg_ty : ty) , +) =>) ? $ module_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { # [allow (warnings)] pub mod $ module_name { crate :: prisma :: checkpoints :: include ! (@ definitions ; $ module_name ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; use super :: * ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: checkpoints :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } pub fn include ($ ($ ($ func_arg : $ func_arg_ty) , +) ?) -> Selection { Selection ([crate :: prisma :: checkpoints :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: checkpoints :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } } ; ({ $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { { crate :: prisma :: checkpoints :: include ! (@ definitions ; ; $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) +) ; pub struct Selection (Vec < :: prisma_client_rust :: Selection >) ; impl :: prisma_client_rust :: IncludeType for Selection { type Data = Data ; type ModelData = crate :: prisma :: checkpoints :: Data ; fn to_selections (self) -> Vec < :: prisma_client_rust :: Selection > { self . 0 } } Selection ([crate :: prisma :: checkpoints :: include ! (@ selections_to_params ; : include { $ ($ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) + }) . into_iter () . map (| p | p . to_selection ()) . collect :: < Vec < _ >> () , < crate :: prisma :: checkpoints :: Types as :: prisma_client_rust :: ModelTypes > :: scalar_selections ()] . into_iter () . flatten () . collect :: < Vec < _ >> ()) } } ; (@ definitions ; $ ($ module_name : ident) ? ; $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) +) => { # [allow (warnings)] enum Fields { } # [allow (warnings)] impl Fields { fn selections () { $ (let _ = Fields :: $ field ;) + } } # [allow (warnings)] # [derive (std :: fmt :: Debug , Clone)] pub struct Data { pub id : i32 , pub source_chain : String , pub dest_chain : String , pub update_height : i32 , pub scanned_height : i32 , pub updated_at : i32 , $ (pub $ field : crate :: prisma :: checkpoints :: include ! (@ field_type ; $ field $ (: $ selection_mode { $ ($ selections) + }) ?) ,) + } impl :: serde :: Serialize for Data { fn serialize < S > (& self , serializer : S) -> Result < S :: Ok , S :: Error > where S : :: serde :: Serializer , { use :: serde :: ser :: SerializeStruct ; let mut state = serializer . serialize_struct ("Data" , [$ (stringify ! ($ field) ,) + stringify ! (id) , stringify ! (source_chain) , stringify ! (dest_chain) , stringify ! (update_height) , stringify ! (scanned_height) , stringify ! (updated_at)] . len ()) ? ; $ (state . serialize_field (crate :: prisma :: checkpoints :: $ field :: NAME , & self . $ field) ? ;) * state . serialize_field (crate :: prisma :: checkpoints :: id :: NAME , & self . id) ? ; state . serialize_field (crate :: prisma :: checkpoints :: source_chain :: NAME , & self . source_chain) ? ; state . serialize_field (crate :: prisma :: checkpoints :: dest_chain :: NAME , & self . dest_chain) ? ; state . serialize_field (crate :: prisma :: checkpoints :: update_height :: NAME , & self . update_height) ? ; state . serialize_field (crate :: prisma :: checkpoints :: scanned_height :: NAME , & self . scanned_height) ? ; state . serialize_field (crate :: prisma :: checkpoints :: updated_at :: NAME , & self . updated_at) ? ; state . end () } } impl < 'de > :: serde :: Deserialize < 'de > for Data { fn deserialize < D > (deserializer : D) -> Result < Self , D :: Error > where D : :: serde :: Deserializer < 'de > , { # [allow (warnings)] enum Field { $ ($ field) , + , id , source_chain , dest_chain , update_height , scanned_height , updated_at } impl < 'de > :: serde :: Deserialize < 'de > for Field { fn deserialize < D > (deserializer : D) -> Result < Field , D :: Error > where D : :: serde :: Deserializer < 'de > , { struct FieldVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for FieldVisitor { type Value = Field ; fn expecting (& self , formatter : & mut :: std :: fmt :: Formatter) -> :: std :: fmt :: Result { formatter . write_str (& [$ (crate :: prisma :: checkpoints :: $ field :: NAME) , + , crate :: prisma :: checkpoints :: id :: NAME , crate :: prisma :: checkpoints :: source_chain :: NAME , crate :: prisma :: checkpoints :: dest_chain :: NAME , crate :: prisma :: checkpoints :: update_height :: NAME , crate :: prisma :: checkpoints :: scanned_height :: NAME , crate :: prisma :: checkpoints :: updated_at :: NAME] . into_iter () . collect :: < Vec < _ >> () . join (", ")) } fn visit_str < E > (self , value : & str) -> Result < Field , E > where E : :: serde :: de :: Error , { match value { $ (crate :: prisma :: checkpoints :: $ field :: NAME => Ok (Field :: $ field)) , * , crate :: prisma :: checkpoints :: id :: NAME => Ok (Field :: id) , crate :: prisma :: checkpoints :: source_chain :: NAME => Ok (Field :: source_chain) , crate :: prisma :: checkpoints :: dest_chain :: NAME => Ok (Field :: dest_chain) , crate :: prisma :: checkpoints :: update_height :: NAME => Ok (Field :: update_height) , crate :: prisma :: checkpoints :: scanned_height :: NAME => Ok (Field :: scanned_height) , crate :: prisma :: checkpoints :: updated_at :: NAME => Ok (Field :: updated_at) , _ => Err (:: serde :: de :: Error :: unknown_field (value , FIELDS)) , } } } deserializer . deserialize_identifier (FieldVisitor) } } struct DataVisitor ; impl < 'de > :: serde :: de :: Visitor < 'de > for DataVisitor { type Value = Data ; fn expecting (& self , formatter : & mut std :: fmt :: Formatter) -> std :: fmt :: Result { formatter . write_str ("struct Data") } fn visit_map < V > (self , mut map : V) -> Result < Data , V :: Error > where V : :: serde :: de :: MapAccess < 'de > , { $ (let mut $ field = None ;) * let mut id = None ; let mut source_chain = None ; let mut dest_chain = None ; let mut update_height = None ; let mut scanned_height = None ; let mut updated_at = None ; while let Some (key) = map . next_key () ? { match key { Field :: id => { if id . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: checkpoints :: id :: NAME)) ; } id = Some (map . next_value () ?) ; } Field :: source_chain => { if source_chain . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: checkpoints :: source_chain :: NAME)) ; } source_chain = Some (map . next_value () ?) ; } Field :: dest_chain => { if dest_chain . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: checkpoints :: dest_chain :: NAME)) ; } dest_chain = Some (map . next_value () ?) ; } Field :: update_height => { if update_height . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: checkpoints :: update_height :: NAME)) ; } update_height = Some (map . next_value () ?) ; } Field :: scanned_height => { if scanned_height . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: checkpoints :: scanned_height :: NAME)) ; } scanned_height = Some (map . next_value () ?) ; } Field :: updated_at => { if updated_at . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: checkpoints :: updated_at :: NAME)) ; } updated_at = Some (map . next_value () ?) ; } $ (Field :: $ field => { if $ field . is_some () { return Err (:: serde :: de :: Error :: duplicate_field (crate :: prisma :: checkpoints :: $ field :: NAME)) ; } $ field = Some (map . next_value () ?) ; }) * } } $ (let $ field = $ field . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: checkpoints :: $ field :: NAME)) ? ;) * let id = id . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: checkpoints :: id :: NAME)) ? ; let source_chain = source_chain . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: checkpoints :: source_chain :: NAME)) ? ; let dest_chain = dest_chain . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: checkpoints :: dest_chain :: NAME)) ? ; let update_height = update_height . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: checkpoints :: update_height :: NAME)) ? ; let scanned_height = scanned_height . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: checkpoints :: scanned_height :: NAME)) ? ; let updated_at = updated_at . ok_or_else (|| serde :: de :: Error :: missing_field (crate :: prisma :: checkpoints :: updated_at :: NAME)) ? ; Ok (Data { id , source_chain , dest_chain , update_height , scanned_height , updated_at , $ ($ field) , * }) } } const FIELDS : & 'static [& 'static str] = & ["id" , "source_chain" , "dest_chain" , "update_height" , "scanned_height" , "updated_at"] ; deserializer . deserialize_struct ("Data" , FIELDS , DataVisitor) } } $ ($ (pub mod $ field { crate :: prisma :: checkpoints :: $ selection_mode ! (@ field_module ; $ field : $ selection_mode { $ ($ selections) + }) ; }) ?) + } ; (@ field_type ; $ field : ident $ ($ tokens : tt) *) => { compile_error ! (stringify ! (Cannot include nonexistent relation $ field on model "Checkpoints" , available relations are "")) } ; (@ field_module ; $ ($ tokens : tt) *) => { } ; (@ selection_field_to_selection_param ; $ ($ tokens : tt) *) => { compile_error ! (stringify ! ($ ($ tokens) *)) } ; (@ selections_to_params ; : $ macro_name : ident { $ ($ field : ident $ (($ ($ filters : tt) +) $ (. $ arg : ident ($ ($ arg_params : tt) *)) *) ? $ (: $ selection_mode : ident { $ ($ selections : tt) + }) ?) + }) => { [$ (crate :: prisma :: checkpoints :: $ macro_name ! (@ selection_field_to_selection_param ; $ field $ (($ ($ filters) +) $ (. $ arg ($ ($ arg_params) *)) *) ? $ (: $ selection_mode { $ ($ selections) + }) ?) ,) +] } ; (@ filters_to_args ;) => { vec ! [] } ; (@ filters_to_args ; $ ($ t : tt) *) => { $ ($ t) * } ; (@ field_serde_name ; id) => { "id" } ; (@ field_serde_name ; source_chain) => { "source_chain" } ; (@ field_serde_name ; dest_chain) => { "dest_chain" } ; (@ field_serde_name ; update_height) => { "update_height" } ; (@ field_serde_name ; scanned_height) => { "scanned_height" } ; (@ field_serde_name ; updated_at) => { "updated_at" } ; }
+	pub use _include_checkpoints as include;
+	pub enum IncludeParam {
+		Id(id::Include),
+		SourceChain(source_chain::Include),
+		DestChain(dest_chain::Include),
+		UpdateHeight(update_height::Include),
+		ScannedHeight(scanned_height::Include),
+		UpdatedAt(updated_at::Include),
+	}
+	impl IncludeParam {
+		pub fn to_selection(self) -> ::prisma_client_rust::Selection {
+			match self {
+				Self::Id(data) => data.to_selection(),
+				Self::SourceChain(data) => data.to_selection(),
+				Self::DestChain(data) => data.to_selection(),
+				Self::UpdateHeight(data) => data.to_selection(),
+				Self::ScannedHeight(data) => data.to_selection(),
+				Self::UpdatedAt(data) => data.to_selection(),
+			}
+		}
+	}
+	#[macro_export]
+	macro_rules ! _partial_unchecked_checkpoints { ($ struct_name : ident { $ ($ scalar_field : ident) + }) => { :: prisma_client_rust :: macros :: partial_unchecked ! { crate :: prisma :: checkpoints struct $ struct_name { # [serde (rename = "id")] pub id : i32 , # [serde (rename = "source_chain")] pub source_chain : String , # [serde (rename = "dest_chain")] pub dest_chain : String , # [serde (rename = "update_height")] pub update_height : i32 , # [serde (rename = "scanned_height")] pub scanned_height : i32 , # [serde (rename = "updated_at")] pub updated_at : i32 } [$ ($ scalar_field) , +] } } ; }
+	pub use _partial_unchecked_checkpoints as partial_unchecked;
+	#[derive(Debug, Clone, :: serde :: Serialize, :: serde :: Deserialize)]
+	pub struct Data {
+		#[serde(rename = "id")]
+		pub id: i32,
+		#[serde(rename = "source_chain")]
+		pub source_chain: String,
+		#[serde(rename = "dest_chain")]
+		pub dest_chain: String,
+		#[serde(rename = "update_height")]
+		pub update_height: i32,
+		#[serde(rename = "scanned_height")]
+		pub scanned_height: i32,
+		#[serde(rename = "updated_at")]
+		pub updated_at: i32,
+	}
+	impl Data {}
+	#[derive(Clone)]
+	pub enum WithParam {}
+	impl Into<::prisma_client_rust::Selection> for WithParam {
+		fn into(self) -> ::prisma_client_rust::Selection {
+			match self {}
+		}
+	}
+	#[derive(Clone)]
+	pub enum SetParam {
+		SetId(i32),
+		IncrementId(i32),
+		DecrementId(i32),
+		MultiplyId(i32),
+		DivideId(i32),
+		SetSourceChain(String),
+		SetDestChain(String),
+		SetUpdateHeight(i32),
+		IncrementUpdateHeight(i32),
+		DecrementUpdateHeight(i32),
+		MultiplyUpdateHeight(i32),
+		DivideUpdateHeight(i32),
+		SetScannedHeight(i32),
+		IncrementScannedHeight(i32),
+		DecrementScannedHeight(i32),
+		MultiplyScannedHeight(i32),
+		DivideScannedHeight(i32),
+		SetUpdatedAt(i32),
+		IncrementUpdatedAt(i32),
+		DecrementUpdatedAt(i32),
+		MultiplyUpdatedAt(i32),
+		DivideUpdatedAt(i32),
+	}
+	impl From<SetParam> for (String, ::prisma_client_rust::PrismaValue) {
+		fn from(param: SetParam) -> Self {
+			match param {
+				SetParam::SetId(value) =>
+					(id::NAME.to_string(), ::prisma_client_rust::PrismaValue::Int(value as i64)),
+				SetParam::IncrementId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideId(value) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetSourceChain(value) => (
+					source_chain::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(value),
+				),
+				SetParam::SetDestChain(value) =>
+					(dest_chain::NAME.to_string(), ::prisma_client_rust::PrismaValue::String(value)),
+				SetParam::SetUpdateHeight(value) => (
+					update_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Int(value as i64),
+				),
+				SetParam::IncrementUpdateHeight(value) => (
+					update_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementUpdateHeight(value) => (
+					update_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyUpdateHeight(value) => (
+					update_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideUpdateHeight(value) => (
+					update_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetScannedHeight(value) => (
+					scanned_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Int(value as i64),
+				),
+				SetParam::IncrementScannedHeight(value) => (
+					scanned_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementScannedHeight(value) => (
+					scanned_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyScannedHeight(value) => (
+					scanned_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideScannedHeight(value) => (
+					scanned_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::SetUpdatedAt(value) => (
+					updated_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Int(value as i64),
+				),
+				SetParam::IncrementUpdatedAt(value) => (
+					updated_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"increment".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DecrementUpdatedAt(value) => (
+					updated_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"decrement".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::MultiplyUpdatedAt(value) => (
+					updated_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"multiply".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+				SetParam::DivideUpdatedAt(value) => (
+					updated_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::Object(vec![(
+						"divide".to_string(),
+						::prisma_client_rust::PrismaValue::Int(value as i64),
+					)]),
+				),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum UncheckedSetParam {
+		Id(i32),
+		SourceChain(String),
+		DestChain(String),
+		UpdateHeight(i32),
+		ScannedHeight(i32),
+		UpdatedAt(i32),
+	}
+	impl From<UncheckedSetParam> for SetParam {
+		fn from(param: UncheckedSetParam) -> Self {
+			match param {
+				UncheckedSetParam::Id(value) => Self::SetId(value),
+				UncheckedSetParam::SourceChain(value) => Self::SetSourceChain(value),
+				UncheckedSetParam::DestChain(value) => Self::SetDestChain(value),
+				UncheckedSetParam::UpdateHeight(value) => Self::SetUpdateHeight(value),
+				UncheckedSetParam::ScannedHeight(value) => Self::SetScannedHeight(value),
+				UncheckedSetParam::UpdatedAt(value) => Self::SetUpdatedAt(value),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum OrderByParam {
+		Id(::prisma_client_rust::Direction),
+		SourceChain(::prisma_client_rust::Direction),
+		DestChain(::prisma_client_rust::Direction),
+		UpdateHeight(::prisma_client_rust::Direction),
+		ScannedHeight(::prisma_client_rust::Direction),
+		UpdatedAt(::prisma_client_rust::Direction),
+	}
+	impl Into<(String, ::prisma_client_rust::PrismaValue)> for OrderByParam {
+		fn into(self) -> (String, ::prisma_client_rust::PrismaValue) {
+			match self {
+				Self::Id(direction) => (
+					id::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::SourceChain(direction) => (
+					source_chain::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::DestChain(direction) => (
+					dest_chain::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::UpdateHeight(direction) => (
+					update_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::ScannedHeight(direction) => (
+					scanned_height::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+				Self::UpdatedAt(direction) => (
+					updated_at::NAME.to_string(),
+					::prisma_client_rust::PrismaValue::String(direction.to_string()),
+				),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub enum WhereParam {
+		Not(Vec<WhereParam>),
+		Or(Vec<WhereParam>),
+		And(Vec<WhereParam>),
+		Id(_prisma::read_filters::IntFilter),
+		SourceChain(_prisma::read_filters::StringFilter),
+		DestChain(_prisma::read_filters::StringFilter),
+		UpdateHeight(_prisma::read_filters::IntFilter),
+		ScannedHeight(_prisma::read_filters::IntFilter),
+		UpdatedAt(_prisma::read_filters::IntFilter),
+	}
+	impl ::prisma_client_rust::WhereInput for WhereParam {
+		fn serialize(self) -> ::prisma_client_rust::SerializedWhereInput {
+			let (name, value) = match self {
+				Self::Not(value) => (
+					"NOT",
+					::prisma_client_rust::SerializedWhereValue::Object(
+						::prisma_client_rust::merge_fields(
+							value
+								.into_iter()
+								.map(::prisma_client_rust::WhereInput::serialize)
+								.map(Into::into)
+								.collect(),
+						),
+					),
+				),
+				Self::Or(value) => (
+					"OR",
+					::prisma_client_rust::SerializedWhereValue::List(
+						value
+							.into_iter()
+							.map(::prisma_client_rust::WhereInput::serialize)
+							.map(Into::into)
+							.map(|v| vec![v])
+							.map(::prisma_client_rust::PrismaValue::Object)
+							.collect(),
+					),
+				),
+				Self::And(value) => (
+					"AND",
+					::prisma_client_rust::SerializedWhereValue::Object(
+						::prisma_client_rust::merge_fields(
+							value
+								.into_iter()
+								.map(::prisma_client_rust::WhereInput::serialize)
+								.map(Into::into)
+								.collect(),
+						),
+					),
+				),
+				Self::Id(value) => (id::NAME, value.into()),
+				Self::SourceChain(value) => (source_chain::NAME, value.into()),
+				Self::DestChain(value) => (dest_chain::NAME, value.into()),
+				Self::UpdateHeight(value) => (update_height::NAME, value.into()),
+				Self::ScannedHeight(value) => (scanned_height::NAME, value.into()),
+				Self::UpdatedAt(value) => (updated_at::NAME, value.into()),
+			};
+			::prisma_client_rust::SerializedWhereInput::new(name, value.into())
+		}
+	}
+	#[derive(Clone)]
+	pub enum UniqueWhereParam {
+		IdEquals(i32),
+	}
+	impl From<UniqueWhereParam> for WhereParam {
+		fn from(value: UniqueWhereParam) -> Self {
+			match value {
+				UniqueWhereParam::IdEquals(value) =>
+					Self::Id(_prisma::read_filters::IntFilter::Equals(value)),
+			}
+		}
+	}
+	impl From<::prisma_client_rust::Operator<Self>> for WhereParam {
+		fn from(op: ::prisma_client_rust::Operator<Self>) -> Self {
+			match op {
+				::prisma_client_rust::Operator::Not(value) => Self::Not(value),
+				::prisma_client_rust::Operator::And(value) => Self::And(value),
+				::prisma_client_rust::Operator::Or(value) => Self::Or(value),
+			}
+		}
+	}
+	#[derive(Clone)]
+	pub struct Types;
+	impl ::prisma_client_rust::ModelTypes for Types {
+		type Data = Data;
+		type Where = WhereParam;
+		type UncheckedSet = UncheckedSetParam;
+		type Set = SetParam;
+		type With = WithParam;
+		type OrderBy = OrderByParam;
+		type Cursor = UniqueWhereParam;
+		const MODEL: &'static str = NAME;
+		fn scalar_selections() -> Vec<::prisma_client_rust::Selection> {
+			vec![
+				::prisma_client_rust::sel(id::NAME),
+				::prisma_client_rust::sel(source_chain::NAME),
+				::prisma_client_rust::sel(dest_chain::NAME),
+				::prisma_client_rust::sel(update_height::NAME),
+				::prisma_client_rust::sel(scanned_height::NAME),
+				::prisma_client_rust::sel(updated_at::NAME),
+			]
+		}
+	}
+	pub type UniqueArgs = ::prisma_client_rust::UniqueArgs<Types>;
+	pub type ManyArgs = ::prisma_client_rust::ManyArgs<Types>;
+	pub type Count<'a> = ::prisma_client_rust::Count<'a, Types>;
+	pub type Create<'a> = ::prisma_client_rust::Create<'a, Types>;
+	pub type CreateMany<'a> = ::prisma_client_rust::CreateMany<'a, Types>;
+	pub type FindUnique<'a> = ::prisma_client_rust::FindUnique<'a, Types>;
+	pub type FindMany<'a> = ::prisma_client_rust::FindMany<'a, Types>;
+	pub type FindFirst<'a> = ::prisma_client_rust::FindFirst<'a, Types>;
+	pub type Update<'a> = ::prisma_client_rust::Update<'a, Types>;
+	pub type UpdateMany<'a> = ::prisma_client_rust::UpdateMany<'a, Types>;
+	pub type Upsert<'a> = ::prisma_client_rust::Upsert<'a, Types>;
+	pub type Delete<'a> = ::prisma_client_rust::Delete<'a, Types>;
+	pub type DeleteMany<'a> = ::prisma_client_rust::DeleteMany<'a, Types>;
+	#[derive(Clone)]
+	pub struct Actions<'a> {
+		pub client: &'a ::prisma_client_rust::PrismaClientInternals,
+	}
+	impl<'a> Actions<'a> {
+		pub fn find_unique(self, _where: UniqueWhereParam) -> FindUnique<'a> {
+			FindUnique::new(self.client, _where.into())
+		}
+		pub fn find_first(self, _where: Vec<WhereParam>) -> FindFirst<'a> {
+			FindFirst::new(self.client, _where)
+		}
+		pub fn find_many(self, _where: Vec<WhereParam>) -> FindMany<'a> {
+			FindMany::new(self.client, _where)
+		}
+		pub fn create(
+			self,
+			source_chain: String,
+			dest_chain: String,
+			update_height: i32,
+			scanned_height: i32,
+			updated_at: i32,
+			mut _params: Vec<SetParam>,
+		) -> Create<'a> {
+			_params.extend([
+				source_chain::set(source_chain),
+				dest_chain::set(dest_chain),
+				update_height::set(update_height),
+				scanned_height::set(scanned_height),
+				updated_at::set(updated_at),
+			]);
+			Create::new(self.client, _params)
+		}
+		pub fn create_unchecked(
+			self,
+			source_chain: String,
+			dest_chain: String,
+			update_height: i32,
+			scanned_height: i32,
+			updated_at: i32,
+			mut _params: Vec<UncheckedSetParam>,
+		) -> Create<'a> {
+			_params.extend([
+				source_chain::set(source_chain),
+				dest_chain::set(dest_chain),
+				update_height::set(update_height),
+				scanned_height::set(scanned_height),
+				updated_at::set(updated_at),
+			]);
+			Create::new(self.client, _params.into_iter().map(Into::into).collect())
+		}
+		pub fn update(self, _where: UniqueWhereParam, _params: Vec<SetParam>) -> Update<'a> {
+			Update::new(self.client, _where.into(), _params, vec![])
+		}
+		pub fn update_unchecked(
+			self,
+			_where: UniqueWhereParam,
+			_params: Vec<UncheckedSetParam>,
+		) -> Update<'a> {
+			Update::new(
+				self.client,
+				_where.into(),
+				_params.into_iter().map(Into::into).collect(),
+				vec![],
+			)
+		}
+		pub fn update_many(
+			self,
+			_where: Vec<WhereParam>,
+			_params: Vec<SetParam>,
+		) -> UpdateMany<'a> {
+			UpdateMany::new(self.client, _where, _params)
+		}
+		pub fn upsert(
+			self,
+			_where: UniqueWhereParam,
+			(source_chain, dest_chain, update_height, scanned_height, updated_at, mut _params): (
+				String,
+				String,
+				i32,
+				i32,
+				i32,
+				Vec<SetParam>,
+			),
+			_update: Vec<SetParam>,
+		) -> Upsert<'a> {
+			_params.extend([
+				source_chain::set(source_chain),
+				dest_chain::set(dest_chain),
+				update_height::set(update_height),
+				scanned_height::set(scanned_height),
+				updated_at::set(updated_at),
+			]);
+			Upsert::new(self.client, _where.into(), _params, _update)
+		}
+		pub fn delete(self, _where: UniqueWhereParam) -> Delete<'a> {
+			Delete::new(self.client, _where.into(), vec![])
+		}
+		pub fn delete_many(self, _where: Vec<WhereParam>) -> DeleteMany<'a> {
+			DeleteMany::new(self.client, _where)
+		}
+		pub fn count(self, _where: Vec<WhereParam>) -> Count<'a> {
+			Count::new(self.client, _where)
+		}
+		pub fn find_raw<T: ::prisma_client_rust::Data>(
+			self,
+		) -> ::prisma_client_rust::FindRaw<'a, Types, T> {
+			::prisma_client_rust::FindRaw::new(self.client)
+		}
+		pub fn aggregate_raw<T: ::prisma_client_rust::Data>(
+			self,
+		) -> ::prisma_client_rust::AggregateRaw<'a, Types, T> {
+			::prisma_client_rust::AggregateRaw::new(self.client)
+		}
+	}
+}
 pub mod _prisma {
 	pub struct PrismaClientBuilder {
 		url: Option<String>,
@@ -2246,6 +6464,18 @@ pub mod _prisma {
 		pub fn unprofitable_messages(&self) -> super::unprofitable_messages::Actions {
 			super::unprofitable_messages::Actions { client: &self.0 }
 		}
+		pub fn pending_transactions(&self) -> super::pending_transactions::Actions {
+			super::pending_transactions::Actions { client: &self.0 }
+		}
+		pub fn delivery_ledger(&self) -> super::delivery_ledger::Actions {
+			super::delivery_ledger::Actions { client: &self.0 }
+		}
+		pub fn withdrawals(&self) -> super::withdrawals::Actions {
+			super::withdrawals::Actions { client: &self.0 }
+		}
+		pub fn checkpoints(&self) -> super::checkpoints::Actions {
+			super::checkpoints::Actions { client: &self.0 }
+		}
 	}
 	impl ::prisma_client_rust::PrismaClient for PrismaClient {
 		fn internals(&self) -> &::prisma_client_rust::PrismaClientInternals {
@@ -2259,6 +6489,33 @@ pub mod _prisma {
 		}
 	}
 	#[derive(Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize, PartialEq, Eq)]
+	pub enum CheckpointsScalarFieldEnum {
+		#[serde(rename = "id")]
+		Id,
+		#[serde(rename = "source_chain")]
+		SourceChain,
+		#[serde(rename = "dest_chain")]
+		DestChain,
+		#[serde(rename = "update_height")]
+		UpdateHeight,
+		#[serde(rename = "scanned_height")]
+		ScannedHeight,
+		#[serde(rename = "updated_at")]
+		UpdatedAt,
+	}
+	impl ToString for CheckpointsScalarFieldEnum {
+		fn to_string(&self) -> String {
+			match self {
+				Self::Id => "id".to_string(),
+				Self::SourceChain => "source_chain".to_string(),
+				Self::DestChain => "dest_chain".to_string(),
+				Self::UpdateHeight => "update_height".to_string(),
+				Self::ScannedHeight => "scanned_height".to_string(),
+				Self::UpdatedAt => "updated_at".to_string(),
+			}
+		}
+	}
+	#[derive(Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize, PartialEq, Eq)]
 	pub enum DeliveriesScalarFieldEnum {
 		#[serde(rename = "id")]
 		Id,
@@ -2289,6 +6546,75 @@ pub mod _prisma {
 		}
 	}
 	#[derive(Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize, PartialEq, Eq)]
+	pub enum DeliveryLedgerScalarFieldEnum {
+		#[serde(rename = "id")]
+		Id,
+		#[serde(rename = "hash")]
+		Hash,
+		#[serde(rename = "source_chain")]
+		SourceChain,
+		#[serde(rename = "dest_chain")]
+		DestChain,
+		#[serde(rename = "delivery_type")]
+		DeliveryType,
+		#[serde(rename = "gas_used")]
+		GasUsed,
+		#[serde(rename = "gas_price")]
+		GasPrice,
+		#[serde(rename = "cost")]
+		Cost,
+		#[serde(rename = "fee")]
+		Fee,
+		#[serde(rename = "created_at")]
+		CreatedAt,
+		#[serde(rename = "claimed_at")]
+		ClaimedAt,
+	}
+	impl ToString for DeliveryLedgerScalarFieldEnum {
+		fn to_string(&self) -> String {
+			match self {
+				Self::Id => "id".to_string(),
+				Self::Hash => "hash".to_string(),
+				Self::SourceChain => "source_chain".to_string(),
+				Self::DestChain => "dest_chain".to_string(),
+				Self::DeliveryType => "delivery_type".to_string(),
+				Self::GasUsed => "gas_used".to_string(),
+				Self::GasPrice => "gas_price".to_string(),
+				Self::Cost => "cost".to_string(),
+				Self::Fee => "fee".to_string(),
+				Self::CreatedAt => "created_at".to_string(),
+				Self::ClaimedAt => "claimed_at".to_string(),
+			}
+		}
+	}
+	#[derive(Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize, PartialEq, Eq)]
+	pub enum PendingTransactionsScalarFieldEnum {
+		#[serde(rename = "id")]
+		Id,
+		#[serde(rename = "chain")]
+		Chain,
+		#[serde(rename = "signer")]
+		Signer,
+		#[serde(rename = "nonce")]
+		Nonce,
+		#[serde(rename = "hash")]
+		Hash,
+		#[serde(rename = "encoded")]
+		Encoded,
+	}
+	impl ToString for PendingTransactionsScalarFieldEnum {
+		fn to_string(&self) -> String {
+			match self {
+				Self::Id => "id".to_string(),
+				Self::Chain => "chain".to_string(),
+				Self::Signer => "signer".to_string(),
+				Self::Nonce => "nonce".to_string(),
+				Self::Hash => "hash".to_string(),
+				Self::Encoded => "encoded".to_string(),
+			}
+		}
+	}
+	#[derive(Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize, PartialEq, Eq)]
 	pub enum PendingWithdrawalScalarFieldEnum {
 		#[serde(rename = "id")]
 		Id,
@@ -2352,6 +6678,33 @@ pub mod _prisma {
 			}
 		}
 	}
+	#[derive(Debug, Clone, Copy, :: serde :: Serialize, :: serde :: Deserialize, PartialEq, Eq)]
+	pub enum WithdrawalsScalarFieldEnum {
+		#[serde(rename = "id")]
+		Id,
+		#[serde(rename = "chain")]
+		Chain,
+		#[serde(rename = "hash")]
+		Hash,
+		#[serde(rename = "amount")]
+		Amount,
+		#[serde(rename = "created_at")]
+		CreatedAt,
+		#[serde(rename = "settled_at")]
+		SettledAt,
+	}
+	impl ToString for WithdrawalsScalarFieldEnum {
+		fn to_string(&self) -> String {
+			match self {
+				Self::Id => "id".to_string(),
+				Self::Chain => "chain".to_string(),
+				Self::Hash => "hash".to_string(),
+				Self::Amount => "amount".to_string(),
+				Self::CreatedAt => "created_at".to_string(),
+				Self::SettledAt => "settled_at".to_string(),
+			}
+		}
+	}
 	pub mod read_filters {
 		#[derive(Clone)]
 		pub enum IntFilter {
@@ -18,13 +18,17 @@ use ismp::{
 };
 use itertools::Itertools;
 use pallet_ismp_relayer::withdrawal::{Key, WithdrawalProof};
-use primitive_types::H256;
+use primitive_types::{H256, U256};
 use prisma_client_rust::{query_core::RawQuery, BatchItem, Direction, PrismaValue, Raw};
 use serde::{Deserialize, Serialize};
 use sp_core::keccak_256;
-use std::{collections::BTreeSet, sync::Arc};
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	sync::Arc,
+};
 use tesseract_primitives::{
-	HyperbridgeClaim, IsmpProvider, StateProofQueryType, TxReceipt, WithdrawFundsResult,
+	Cost, Hasher, HyperbridgeClaim, IsmpProvider, StateProofQueryType, TrackedTransaction,
+	TransactionStore, TxReceipt, WithdrawFundsResult,
 };
 
 mod db;
@@ -36,6 +40,70 @@ pub struct TransactionPayment {
 	pub db: Arc<PrismaClient>,
 }
 
+/// A delivered message, along with what it cost to deliver and the fee it earned
+#[derive(Debug, Clone, Copy)]
+pub struct LedgerEntry {
+	/// Receipt of the delivery
+	pub receipt: TxReceipt,
+	/// Estimated cost of the delivery in usd at the time it was submitted
+	pub cost: Cost,
+	/// The relayer fee provided for the message in usd
+	pub fee: Cost,
+}
+
+/// Filters the ledger entries included in a report
+#[derive(Debug, Clone, Default)]
+pub struct ReportFilter {
+	/// Only include deliveries from this chain
+	pub source: Option<StateMachine>,
+	/// Only include deliveries and withdrawals to this chain
+	pub dest: Option<StateMachine>,
+	/// Only include entries created at or after this unix timestamp
+	pub from: Option<i64>,
+	/// Only include entries created at or before this unix timestamp
+	pub to: Option<i64>,
+}
+
+/// Profit and loss of the deliveries from a source to a destination chain
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RouteProfitAndLoss {
+	pub source_chain: String,
+	pub dest_chain: String,
+	/// Number of messages delivered
+	pub deliveries: u64,
+	/// Total gas used by the deliveries
+	pub gas_used: U256,
+	/// Gas spent in the native token of the destination
+	pub gas_spent: U256,
+	/// Estimated cost of the deliveries in usd
+	pub cost: Cost,
+	/// Relayer fees earned in usd
+	pub fees: Cost,
+	/// Relayer fees that have been accumulated on hyperbridge
+	pub claimed: Cost,
+}
+
+/// Withdrawals of relayer fees to a chain
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WithdrawalSummary {
+	pub chain: String,
+	/// Number of withdrawal requests
+	pub withdrawals: u64,
+	/// Amount requested from hyperbridge in usd
+	pub requested: Cost,
+	/// Amount of the requests that have been delivered to the chain in usd
+	pub settled: Cost,
+}
+
+/// Progress of the messaging task for a route, used to resume after a restart
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Checkpoint {
+	/// Height of the source in the last state machine update that was handled
+	pub update_height: u64,
+	/// Height of the source up to which events have been delivered
+	pub scanned_height: u64,
+}
+
 impl TransactionPayment {
 	/// Create the local database if it does not exist
 	pub async fn initialize(url: &str) -> anyhow::Result<Self> {
@@ -65,46 +133,191 @@ impl TransactionPayment {
 
 	/// Store entries for delivered post requests and responses
 	pub async fn store_messages(&self, receipts: Vec<TxReceipt>) -> anyhow::Result<()> {
-		let mut actions = vec![];
-		for receipt in receipts {
-			match receipt {
-				TxReceipt::Request { query, height } => {
-					let action = self.db.deliveries().create(
-						hex::encode(query.commitment.as_bytes()),
-						query.source_chain.to_string(),
-						query.dest_chain.to_string(),
-						DeliveryType::PostRequest as i32,
-						chrono::Utc::now().timestamp() as i32,
-						height as i32,
-						Default::default(),
-					);
-
-					actions.push(action);
-				},
+		let actions = receipts
+			.into_iter()
+			.map(|receipt| {
+				let (hash, delivery_type) = delivery_key(&receipt);
+				let query = receipt.query();
+				self.db.deliveries().create(
+					hash,
+					query.source_chain.to_string(),
+					query.dest_chain.to_string(),
+					delivery_type as i32,
+					chrono::Utc::now().timestamp() as i32,
+					receipt.height() as i32,
+					Default::default(),
+				)
+			})
+			.collect::<Vec<_>>();
+		self.db._batch(actions).await?;
+		Ok(())
+	}
 
-				TxReceipt::Response { query, request_commitment, height } => {
-					// When inserting the hash for responses we concatenate the response
-					// commitment with the request commitment
-					let mut commitment = vec![];
-					commitment.extend_from_slice(query.commitment.as_bytes());
-					commitment.extend_from_slice(request_commitment.as_bytes());
-					let action = self.db.deliveries().create(
-						hex::encode(commitment.as_slice()),
-						query.source_chain.to_string(),
-						query.dest_chain.to_string(),
-						DeliveryType::PostResponse as i32,
-						chrono::Utc::now().timestamp() as i32,
-						height as i32,
-						Default::default(),
-					);
-					actions.push(action);
-				},
+	/// Record the gas used, cost and fee of delivered messages in the ledger
+	pub async fn record_deliveries(&self, entries: Vec<LedgerEntry>) -> anyhow::Result<()> {
+		let now = chrono::Utc::now().timestamp() as i32;
+		let actions = entries
+			.into_iter()
+			.map(|entry| {
+				let (hash, delivery_type) = delivery_key(&entry.receipt);
+				let query = entry.receipt.query();
+				let gas = entry.receipt.gas();
+				self.db.delivery_ledger().create(
+					hash,
+					query.source_chain.to_string(),
+					query.dest_chain.to_string(),
+					delivery_type as i32,
+					gas.gas_used.to_string(),
+					gas.gas_price.to_string(),
+					entry.cost.0.to_string(),
+					entry.fee.0.to_string(),
+					now,
+					0,
+					vec![],
+				)
+			})
+			.collect::<Vec<_>>();
+		self.db._batch(actions).await?;
+		Ok(())
+	}
+
+	/// Mark the fees of the deliveries with the provided hashes as claimed in the ledger
+	async fn settle_deliveries(&self, hashes: Vec<String>) -> anyhow::Result<()> {
+		self.db
+			.delivery_ledger()
+			.update_many(
+				vec![
+					db::delivery_ledger::WhereParam::Hash(StringFilter::InVec(hashes)),
+					db::delivery_ledger::WhereParam::ClaimedAt(IntFilter::Equals(0)),
+				],
+				vec![db::delivery_ledger::claimed_at::set(chrono::Utc::now().timestamp() as i32)],
+			)
+			.exec()
+			.await?;
+		Ok(())
+	}
+
+	/// Profit and loss of the deliveries in the ledger, grouped by their source and destination
+	pub async fn profit_and_loss(
+		&self,
+		filter: &ReportFilter,
+	) -> anyhow::Result<Vec<RouteProfitAndLoss>> {
+		let mut params = vec![];
+		if let Some(source) = filter.source {
+			params.push(db::delivery_ledger::WhereParam::SourceChain(StringFilter::Equals(
+				source.to_string(),
+			)));
+		}
+		if let Some(dest) = filter.dest {
+			params.push(db::delivery_ledger::WhereParam::DestChain(StringFilter::Equals(
+				dest.to_string(),
+			)));
+		}
+		if let Some(from) = filter.from {
+			params.push(db::delivery_ledger::WhereParam::CreatedAt(IntFilter::Gte(from as i32)));
+		}
+		if let Some(to) = filter.to {
+			params.push(db::delivery_ledger::WhereParam::CreatedAt(IntFilter::Lte(to as i32)));
+		}
+
+		let mut routes = BTreeMap::new();
+		for record in self.db.delivery_ledger().find_many(params).exec().await? {
+			let route = routes
+				.entry((record.source_chain.clone(), record.dest_chain.clone()))
+				.or_insert_with(|| RouteProfitAndLoss {
+					source_chain: record.source_chain.clone(),
+					dest_chain: record.dest_chain.clone(),
+					..Default::default()
+				});
+			let gas_used = parse_amount(&record.gas_used)?;
+			let fee = Cost(parse_amount(&record.fee)?);
+			route.deliveries += 1;
+			route.gas_used = route.gas_used.saturating_add(gas_used);
+			route.gas_spent = route
+				.gas_spent
+				.saturating_add(gas_used.saturating_mul(parse_amount(&record.gas_price)?));
+			route.cost = route.cost + parse_amount(&record.cost)?;
+			route.fees = route.fees + fee;
+			if record.claimed_at != 0 {
+				route.claimed = route.claimed + fee;
 			}
 		}
-		self.db._batch(actions).await?;
+
+		Ok(routes.into_values().collect())
+	}
+
+	/// Record a withdrawal of `amount` from hyperbridge in the ledger
+	pub async fn record_withdrawal(
+		&self,
+		withdrawal: &WithdrawFundsResult,
+		amount: U256,
+	) -> anyhow::Result<()> {
+		self.db
+			.withdrawals()
+			.create(
+				withdrawal.post.dest.to_string(),
+				withdrawal_hash(withdrawal),
+				amount.to_string(),
+				chrono::Utc::now().timestamp() as i32,
+				0,
+				vec![],
+			)
+			.exec()
+			.await?;
+		Ok(())
+	}
+
+	/// Mark a withdrawal as settled once its request has been delivered to the destination
+	pub async fn settle_withdrawal(&self, withdrawal: &WithdrawFundsResult) -> anyhow::Result<()> {
+		self.db
+			.withdrawals()
+			.update_many(
+				vec![
+					db::withdrawals::WhereParam::Hash(StringFilter::Equals(withdrawal_hash(
+						withdrawal,
+					))),
+					db::withdrawals::WhereParam::SettledAt(IntFilter::Equals(0)),
+				],
+				vec![db::withdrawals::settled_at::set(chrono::Utc::now().timestamp() as i32)],
+			)
+			.exec()
+			.await?;
 		Ok(())
 	}
 
+	/// Withdrawals in the ledger grouped by their destination
+	pub async fn withdrawals(
+		&self,
+		filter: &ReportFilter,
+	) -> anyhow::Result<Vec<WithdrawalSummary>> {
+		let mut params = vec![];
+		if let Some(dest) = filter.dest {
+			params.push(db::withdrawals::WhereParam::Chain(StringFilter::Equals(dest.to_string())));
+		}
+		if let Some(from) = filter.from {
+			params.push(db::withdrawals::WhereParam::CreatedAt(IntFilter::Gte(from as i32)));
+		}
+		if let Some(to) = filter.to {
+			params.push(db::withdrawals::WhereParam::CreatedAt(IntFilter::Lte(to as i32)));
+		}
+
+		let mut chains = BTreeMap::new();
+		for record in self.db.withdrawals().find_many(params).exec().await? {
+			let summary = chains.entry(record.chain.clone()).or_insert_with(|| WithdrawalSummary {
+				chain: record.chain.clone(),
+				..Default::default()
+			});
+			let amount = Cost(parse_amount(&record.amount)?);
+			summary.withdrawals += 1;
+			summary.requested = summary.requested + amount;
+			if record.settled_at != 0 {
+				summary.settled = summary.settled + amount;
+			}
+		}
+
+		Ok(chains.into_values().collect())
+	}
+
 	/// Delete the requests with the provided hashes from the database
 	pub async fn delete_entries(&self, reqs: Vec<Vec<u8>>) -> anyhow::Result<()> {
 		let actions = reqs
@@ -241,13 +454,13 @@ impl TransactionPayment {
 			.iter()
 			.map(|data| {
 				match data {
-					TxReceipt::Request { query, height } => {
+					TxReceipt::Request { query, height, .. } => {
 						let source_key = source.request_commitment_full_key(query.commitment);
 						//Get request receipt keys on dest chain
 						let dest_key = dest.request_receipt_full_key(query.commitment);
 						(Key::Request(query.commitment), source_key, dest_key)
 					},
-					TxReceipt::Response { query, request_commitment, height } => {
+					TxReceipt::Response { query, request_commitment, height, .. } => {
 						let source_key = source.response_commitment_full_key(query.commitment);
 						//Get response receipt keys on dest chain
 						let dest_key = dest.response_receipt_full_key(*request_commitment);
@@ -385,6 +598,88 @@ impl TransactionPayment {
 		Ok(())
 	}
 
+	/// Fetch the checkpoint of the route from `source` to `dest`
+	pub async fn checkpoint(
+		&self,
+		source: StateMachine,
+		dest: StateMachine,
+	) -> anyhow::Result<Option<Checkpoint>> {
+		let data = self
+			.db
+			.checkpoints()
+			.find_first(vec![
+				db::checkpoints::WhereParam::SourceChain(StringFilter::Equals(source.to_string())),
+				db::checkpoints::WhereParam::DestChain(StringFilter::Equals(dest.to_string())),
+			])
+			.exec()
+			.await?;
+
+		Ok(data.map(|data| Checkpoint {
+			update_height: data.update_height as u64,
+			scanned_height: data.scanned_height as u64,
+		}))
+	}
+
+	/// Replace the checkpoint of the route from `source` to `dest`
+	pub async fn store_checkpoint(
+		&self,
+		source: StateMachine,
+		dest: StateMachine,
+		checkpoint: Checkpoint,
+	) -> anyhow::Result<()> {
+		let delete = self.db.checkpoints().delete_many(vec![
+			db::checkpoints::WhereParam::SourceChain(StringFilter::Equals(source.to_string())),
+			db::checkpoints::WhereParam::DestChain(StringFilter::Equals(dest.to_string())),
+		]);
+		let create = self.db.checkpoints().create(
+			source.to_string(),
+			dest.to_string(),
+			checkpoint.update_height as i32,
+			checkpoint.scanned_height as i32,
+			chrono::Utc::now().timestamp() as i32,
+			vec![],
+		);
+		self.db._batch((delete, create)).await?;
+
+		Ok(())
+	}
+
+	/// Move the checkpoints of all routes from `source` back by `blocks`, so that the events in
+	/// those blocks are scanned again when the relayer starts
+	pub async fn rewind_checkpoints(
+		&self,
+		source: StateMachine,
+		blocks: u64,
+	) -> anyhow::Result<()> {
+		let checkpoints = self
+			.db
+			.checkpoints()
+			.find_many(vec![db::checkpoints::WhereParam::SourceChain(StringFilter::Equals(
+				source.to_string(),
+			))])
+			.exec()
+			.await?;
+		let actions = checkpoints
+			.into_iter()
+			.map(|data| {
+				self.db.checkpoints().update(
+					db::checkpoints::UniqueWhereParam::IdEquals(data.id),
+					vec![
+						db::checkpoints::update_height::set(
+							(data.update_height as u64).saturating_sub(blocks) as i32,
+						),
+						db::checkpoints::scanned_height::set(
+							(data.scanned_height as u64).saturating_sub(blocks) as i32,
+						),
+					],
+				)
+			})
+			.collect::<Vec<_>>();
+		self.db._batch(actions).await?;
+
+		Ok(())
+	}
+
 	/// Store unprofitable messages
 	pub async fn store_unprofitable_messages(
 		&self,
@@ -490,7 +785,7 @@ impl TransactionPayment {
 	pub async fn delete_claimed_entries(&self, commitments: Vec<Key>) -> anyhow::Result<()> {
 		if !commitments.is_empty() {
 			// Remove claimed entries from db
-			let entries = commitments
+			let entries: Vec<Vec<u8>> = commitments
 				.into_iter()
 				.map(|key| match key {
 					Key::Request(req) => req.0.to_vec(),
@@ -503,12 +798,121 @@ impl TransactionPayment {
 				})
 				.collect();
 
+			self.settle_deliveries(entries.iter().map(hex::encode).collect()).await?;
 			self.delete_entries(entries).await?;
 		}
 		Ok(())
 	}
 }
 
+#[async_trait::async_trait]
+impl TransactionStore for TransactionPayment {
+	async fn tracked_transactions(
+		&self,
+		chain: StateMachine,
+		signer: Vec<u8>,
+	) -> anyhow::Result<Vec<TrackedTransaction>> {
+		let tracked = self
+			.db
+			.pending_transactions()
+			.find_many(vec![
+				db::pending_transactions::WhereParam::Chain(StringFilter::Equals(
+					chain.to_string(),
+				)),
+				db::pending_transactions::WhereParam::Signer(StringFilter::Equals(hex::encode(
+					&signer,
+				))),
+			])
+			.order_by(db::pending_transactions::OrderByParam::Nonce(Direction::Asc))
+			.exec()
+			.await?
+			.into_iter()
+			.map(|record| {
+				Ok(TrackedTransaction {
+					chain,
+					signer: signer.clone(),
+					nonce: record.nonce as u64,
+					hash: H256::from_slice(&hex::decode(&record.hash)?),
+					payload: record.encoded,
+				})
+			})
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+		Ok(tracked)
+	}
+
+	async fn track_transaction(&self, tx: TrackedTransaction) -> anyhow::Result<()> {
+		let chain = tx.chain.to_string();
+		let signer = hex::encode(&tx.signer);
+		// Only a single transaction can occupy a nonce, so any replaced transaction is removed
+		let delete = self.db.pending_transactions().delete_many(vec![
+			db::pending_transactions::WhereParam::Chain(StringFilter::Equals(chain.clone())),
+			db::pending_transactions::WhereParam::Signer(StringFilter::Equals(signer.clone())),
+			db::pending_transactions::WhereParam::Nonce(IntFilter::Equals(tx.nonce as i32)),
+		]);
+		let create = self.db.pending_transactions().create(
+			chain,
+			signer,
+			tx.nonce as i32,
+			hex::encode(tx.hash.as_bytes()),
+			tx.payload,
+			vec![],
+		);
+		self.db._batch((delete, create)).await?;
+
+		Ok(())
+	}
+
+	async fn untrack_transaction(
+		&self,
+		chain: StateMachine,
+		signer: Vec<u8>,
+		nonce: u64,
+	) -> anyhow::Result<()> {
+		self.db
+			.pending_transactions()
+			.delete_many(vec![
+				db::pending_transactions::WhereParam::Chain(StringFilter::Equals(
+					chain.to_string(),
+				)),
+				db::pending_transactions::WhereParam::Signer(StringFilter::Equals(hex::encode(
+					&signer,
+				))),
+				db::pending_transactions::WhereParam::Nonce(IntFilter::Equals(nonce as i32)),
+			])
+			.exec()
+			.await?;
+
+		Ok(())
+	}
+}
+
+/// Returns the hash a delivery is stored with and its type. When storing responses we concatenate
+/// the response commitment with the request commitment
+fn delivery_key(receipt: &TxReceipt) -> (String, DeliveryType) {
+	match receipt {
+		TxReceipt::Request { query, .. } =>
+			(hex::encode(query.commitment.as_bytes()), DeliveryType::PostRequest),
+		TxReceipt::Response { query, request_commitment, .. } => {
+			let mut commitment = vec![];
+			commitment.extend_from_slice(query.commitment.as_bytes());
+			commitment.extend_from_slice(request_commitment.as_bytes());
+			(hex::encode(commitment.as_slice()), DeliveryType::PostResponse)
+		},
+	}
+}
+
+/// Returns the hash a withdrawal is stored with, this is the commitment of its post request
+fn withdrawal_hash(withdrawal: &WithdrawFundsResult) -> String {
+	let commitment = hash_request::<Hasher>(&Request::Post(withdrawal.post.clone()));
+	hex::encode(commitment.as_bytes())
+}
+
+/// Parses an amount stored as a decimal string
+fn parse_amount(amount: &str) -> anyhow::Result<U256> {
+	U256::from_dec_str(amount).map_err(|err| anyhow!("Invalid amount {amount} in ledger: {err:?}"))
+}
+
 #[derive(Ord, PartialOrd, Eq, PartialEq)]
 pub enum DeliveryType {
 	PostRequest = 0,
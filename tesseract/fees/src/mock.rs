@@ -189,7 +189,7 @@ impl IsmpProvider for MockHost {
 		Default::default()
 	}
 
-	fn sign(&self, msg: &[u8]) -> Signature {
+	async fn sign(&self, msg: &[u8]) -> Result<Signature, Error> {
 		todo!()
 	}
 
@@ -1,12 +1,20 @@
-use crate::TransactionPayment;
+use crate::{
+	Checkpoint, LedgerEntry, ReportFilter, RouteProfitAndLoss, TransactionPayment,
+	WithdrawalSummary,
+};
 use ismp::{
 	consensus::{StateMachineHeight, StateMachineId},
 	host::StateMachine,
 	messaging::{hash_request, hash_response, Message, Proof, RequestMessage, ResponseMessage},
 	router::{PostRequest, PostResponse, Request, RequestResponse, Response},
 };
+use pallet_ismp_relayer::withdrawal::Key;
+use primitive_types::{H256, U256};
 use std::sync::Arc;
-use tesseract_primitives::{mocks::MockHost, Hasher, Query, TxReceipt};
+use tesseract_primitives::{
+	mocks::MockHost, Cost, GasUsage, Hasher, Query, TrackedTransaction, TransactionStore,
+	TxReceipt, WithdrawFundsResult,
+};
 
 #[tokio::test]
 async fn transaction_payments_flow() {
@@ -31,6 +39,7 @@ async fn transaction_payments_flow() {
 				commitment,
 			},
 			height: Default::default(),
+			gas: Default::default(),
 		}
 	});
 
@@ -61,6 +70,7 @@ async fn transaction_payments_flow() {
 			},
 			request_commitment,
 			height: Default::default(),
+			gas: Default::default(),
 		}
 	});
 
@@ -116,6 +126,7 @@ async fn test_unique_deliveries() -> anyhow::Result<()> {
 				commitment,
 			},
 			height: Default::default(),
+			gas: Default::default(),
 		}
 	});
 
@@ -146,6 +157,7 @@ async fn test_unique_deliveries() -> anyhow::Result<()> {
 			},
 			request_commitment,
 			height: Default::default(),
+			gas: Default::default(),
 		}
 	});
 
@@ -169,6 +181,7 @@ async fn test_unique_deliveries() -> anyhow::Result<()> {
 				commitment,
 			},
 			height: Default::default(),
+			gas: Default::default(),
 		}
 	});
 
@@ -192,6 +205,7 @@ async fn test_unique_deliveries() -> anyhow::Result<()> {
 				commitment,
 			},
 			height: Default::default(),
+			gas: Default::default(),
 		}
 	});
 
@@ -215,6 +229,7 @@ async fn test_unique_deliveries() -> anyhow::Result<()> {
 				commitment,
 			},
 			height: Default::default(),
+			gas: Default::default(),
 		}
 	});
 
@@ -238,6 +253,7 @@ async fn test_unique_deliveries() -> anyhow::Result<()> {
 				commitment,
 			},
 			height: Default::default(),
+			gas: Default::default(),
 		}
 	});
 
@@ -285,6 +301,7 @@ async fn highest_delivery_height() {
 				commitment,
 			},
 			height: i,
+			gas: Default::default(),
 		}
 	});
 
@@ -315,6 +332,7 @@ async fn highest_delivery_height() {
 			},
 			request_commitment,
 			height: i,
+			gas: Default::default(),
 		}
 	});
 
@@ -331,3 +349,185 @@ async fn highest_delivery_height() {
 
 	assert_eq!(height, 499);
 }
+
+#[tokio::test]
+async fn should_track_pending_transactions() {
+	let tx_payment = TransactionPayment::initialize("./dev_3.db").await.unwrap();
+	let chain = StateMachine::Evm(97);
+	let signer = vec![1u8; 20];
+	let tracked = (0..3)
+		.map(|nonce| TrackedTransaction {
+			chain,
+			signer: signer.clone(),
+			nonce,
+			hash: H256::repeat_byte(nonce as u8),
+			payload: vec![nonce as u8; 32],
+		})
+		.collect::<Vec<_>>();
+
+	for tx in tracked.clone() {
+		tx_payment.track_transaction(tx).await.unwrap();
+	}
+
+	// Other signers should not be affected
+	tx_payment
+		.track_transaction(TrackedTransaction { signer: vec![2u8; 20], ..tracked[0].clone() })
+		.await
+		.unwrap();
+
+	assert_eq!(tx_payment.tracked_transactions(chain, signer.clone()).await.unwrap(), tracked);
+
+	// Replacing a transaction at the same nonce should overwrite the previous entry
+	let replacement = TrackedTransaction {
+		hash: H256::repeat_byte(10),
+		payload: vec![10u8; 32],
+		..tracked[1].clone()
+	};
+	tx_payment.track_transaction(replacement.clone()).await.unwrap();
+	tx_payment.untrack_transaction(chain, signer.clone(), 0).await.unwrap();
+
+	assert_eq!(
+		tx_payment.tracked_transactions(chain, signer.clone()).await.unwrap(),
+		vec![replacement, tracked[2].clone()]
+	);
+}
+
+#[tokio::test]
+async fn should_report_profit_and_loss() {
+	let _ = std::fs::remove_file("./dev_4.db");
+	let tx_payment = TransactionPayment::initialize("./dev_4.db").await.unwrap();
+	let usd = |amount: u128| Cost(U256::from(amount * 10u128.pow(18)));
+	let entries = (0..4)
+		.map(|i| {
+			let post = PostRequest {
+				source: StateMachine::Evm(97),
+				dest: if i < 3 { StateMachine::Evm(8002) } else { StateMachine::Evm(11155111) },
+				nonce: i,
+				from: vec![],
+				to: vec![],
+				timeout_timestamp: 0,
+				body: vec![],
+			};
+			let req = Request::Post(post);
+			LedgerEntry {
+				receipt: TxReceipt::Request {
+					query: Query {
+						source_chain: req.source_chain(),
+						dest_chain: req.dest_chain(),
+						nonce: req.nonce(),
+						commitment: hash_request::<Hasher>(&req),
+					},
+					height: 0,
+					gas: GasUsage { gas_used: 100_000u64.into(), gas_price: 2u64.into() },
+				},
+				cost: usd(2),
+				fee: usd(5),
+			}
+		})
+		.collect::<Vec<_>>();
+	tx_payment
+		.store_messages(entries.iter().map(|entry| entry.receipt).collect())
+		.await
+		.unwrap();
+	tx_payment.record_deliveries(entries.clone()).await.unwrap();
+
+	// accumulating fees settles the deliveries
+	tx_payment
+		.delete_claimed_entries(vec![Key::Request(entries[0].receipt.query().commitment)])
+		.await
+		.unwrap();
+
+	let report = tx_payment.profit_and_loss(&Default::default()).await.unwrap();
+	assert_eq!(
+		report,
+		vec![
+			RouteProfitAndLoss {
+				source_chain: StateMachine::Evm(97).to_string(),
+				dest_chain: StateMachine::Evm(11155111).to_string(),
+				deliveries: 1,
+				gas_used: 100_000u64.into(),
+				gas_spent: 200_000u64.into(),
+				cost: usd(2),
+				fees: usd(5),
+				claimed: Cost::default(),
+			},
+			RouteProfitAndLoss {
+				source_chain: StateMachine::Evm(97).to_string(),
+				dest_chain: StateMachine::Evm(8002).to_string(),
+				deliveries: 3,
+				gas_used: 300_000u64.into(),
+				gas_spent: 600_000u64.into(),
+				cost: usd(6),
+				fees: usd(15),
+				claimed: usd(5),
+			},
+		]
+	);
+
+	let filter = ReportFilter { dest: Some(StateMachine::Evm(8002)), ..Default::default() };
+	assert_eq!(tx_payment.profit_and_loss(&filter).await.unwrap().len(), 1);
+	let filter = ReportFilter { to: Some(0), ..Default::default() };
+	assert!(tx_payment.profit_and_loss(&filter).await.unwrap().is_empty());
+
+	let withdrawal = WithdrawFundsResult {
+		post: PostRequest {
+			source: StateMachine::Kusama(2000),
+			dest: StateMachine::Evm(8002),
+			nonce: 0,
+			from: vec![],
+			to: vec![],
+			timeout_timestamp: 0,
+			body: vec![],
+		},
+		block: 0,
+	};
+	tx_payment.record_withdrawal(&withdrawal, usd(10).0).await.unwrap();
+	let summary = WithdrawalSummary {
+		chain: StateMachine::Evm(8002).to_string(),
+		withdrawals: 1,
+		requested: usd(10),
+		settled: Cost::default(),
+	};
+	assert_eq!(tx_payment.withdrawals(&Default::default()).await.unwrap(), vec![summary.clone()]);
+
+	tx_payment.settle_withdrawal(&withdrawal).await.unwrap();
+	assert_eq!(
+		tx_payment.withdrawals(&Default::default()).await.unwrap(),
+		vec![WithdrawalSummary { settled: usd(10), ..summary }]
+	);
+}
+
+#[tokio::test]
+async fn should_store_and_rewind_checkpoints() {
+	let _ = std::fs::remove_file("./dev_5.db");
+	let tx_payment = TransactionPayment::initialize("./dev_5.db").await.unwrap();
+	let source = StateMachine::Evm(97);
+	let dest = StateMachine::Kusama(2000);
+	let other_dest = StateMachine::Evm(11155111);
+	assert_eq!(tx_payment.checkpoint(source, dest).await.unwrap(), None);
+
+	let checkpoint = Checkpoint { update_height: 120, scanned_height: 100 };
+	tx_payment.store_checkpoint(source, dest, checkpoint).await.unwrap();
+	let other_checkpoint = Checkpoint { update_height: 30, scanned_height: 30 };
+	tx_payment.store_checkpoint(source, other_dest, other_checkpoint).await.unwrap();
+	let reverse_checkpoint = Checkpoint { update_height: 500, scanned_height: 500 };
+	tx_payment.store_checkpoint(dest, source, reverse_checkpoint).await.unwrap();
+	assert_eq!(tx_payment.checkpoint(source, dest).await.unwrap(), Some(checkpoint));
+
+	// a new checkpoint replaces the previous one
+	let checkpoint = Checkpoint { update_height: 150, scanned_height: 150 };
+	tx_payment.store_checkpoint(source, dest, checkpoint).await.unwrap();
+	assert_eq!(tx_payment.checkpoint(source, dest).await.unwrap(), Some(checkpoint));
+
+	// only the routes from the rewound source are affected
+	tx_payment.rewind_checkpoints(source, 50).await.unwrap();
+	assert_eq!(
+		tx_payment.checkpoint(source, dest).await.unwrap(),
+		Some(Checkpoint { update_height: 100, scanned_height: 100 })
+	);
+	assert_eq!(
+		tx_payment.checkpoint(source, other_dest).await.unwrap(),
+		Some(Checkpoint::default())
+	);
+	assert_eq!(tx_payment.checkpoint(dest, source).await.unwrap(), Some(reverse_checkpoint));
+}
@@ -0,0 +1,97 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Audit log of the decisions made by the fisherman
+
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::{fs::File, io::AsyncWriteExt, sync::Mutex};
+
+use crate::witness::{Decision, Observation};
+
+/// What a single rpc endpoint reported for a state commitment
+#[derive(Debug, Clone, Serialize)]
+pub struct EndpointObservation {
+	pub endpoint: String,
+	#[serde(flatten)]
+	pub observation: Observation,
+}
+
+/// A decision made on a state commitment
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditRecord {
+	/// Unix timestamp at which the decision was made
+	pub timestamp: u64,
+	/// State machine the state commitment belongs to
+	pub state_machine: String,
+	/// Chain the state commitment was stored on
+	pub host: String,
+	pub height: u64,
+	/// State root of the state commitment
+	pub state_root: String,
+	pub observations: Vec<EndpointObservation>,
+	pub decision: Decision,
+	/// Error returned when submitting the veto, if it failed
+	pub error: Option<String>,
+}
+
+/// The header finalized by the consensus proof of an rpc endpoint
+#[derive(Debug, Clone, Serialize)]
+pub struct ConflictingHeader {
+	pub endpoint: String,
+	pub hash: String,
+}
+
+/// A fraud proof submitted for conflicting consensus proofs
+#[derive(Debug, Clone, Serialize)]
+pub struct FraudProofRecord {
+	/// Unix timestamp at which the fraud proof was submitted
+	pub timestamp: u64,
+	/// Consensus state id of the client the fraud proof was submitted for
+	pub consensus_state_id: String,
+	/// Chain the fraud proof was submitted to
+	pub host: String,
+	/// Height of the conflicting headers
+	pub height: u64,
+	pub headers: Vec<ConflictingHeader>,
+	/// Error returned when submitting the fraud proof, if it failed
+	pub error: Option<String>,
+}
+
+/// Records every decision of the fisherman as json lines in a log file.
+#[derive(Clone)]
+pub struct AuditLog {
+	file: Arc<Mutex<File>>,
+}
+
+impl AuditLog {
+	/// Open the audit log, new records are appended if it already exists
+	pub async fn open(path: &str) -> anyhow::Result<Self> {
+		let file = tokio::fs::OpenOptions::new().create(true).append(true).open(path).await?;
+		Ok(Self { file: Arc::new(Mutex::new(file)) })
+	}
+
+	/// Append a record to the audit log
+	pub async fn record<R: Serialize>(&self, record: &R) -> anyhow::Result<()> {
+		let mut line = json::to_string(record)?;
+		line.push('\n');
+
+		let mut file = self.file.lock().await;
+		file.write_all(line.as_bytes()).await?;
+		file.flush().await?;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,125 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tesseract fisherman cli
+
+use crate::{
+	audit::AuditLog,
+	config::{endpoint_configs, substrate_endpoint_configs, FishermanConfig},
+	consensus::ConsensusWatcher,
+	cross_check, detect_fraud,
+	witness::Witness,
+};
+use clap::Parser;
+use sc_service::TaskManager;
+use std::sync::Arc;
+use tesseract_primitives::IsmpProvider;
+use tesseract_substrate::{config::KeccakSubstrateChain, SubstrateClient};
+use tracing_subscriber::{filter::LevelFilter, util::SubscriberInitExt};
+
+/// CLI interface for the tesseract fisherman.
+#[derive(Parser, Debug)]
+pub struct Cli {
+	/// Path to the fisherman config file
+	#[arg(short, long)]
+	pub config: String,
+}
+
+impl Cli {
+	/// Run the fisherman
+	pub async fn run(self) -> Result<(), anyhow::Error> {
+		let filter = tracing_subscriber::EnvFilter::from_default_env()
+			.add_directive(LevelFilter::INFO.into());
+		tracing_subscriber::fmt().with_env_filter(filter).finish().try_init()?;
+		log::info!("🎣 Initializing tesseract fisherman");
+
+		let config = FishermanConfig::parse_conf(&self.config).await?;
+		let quorum = config.fisherman.quorum();
+		let audit = AuditLog::open(&config.fisherman.audit_log).await?;
+		let coprocessor = config.hyperbridge.state_machine;
+
+		let hyperbridge =
+			SubstrateClient::<KeccakSubstrateChain>::new(config.hyperbridge.clone()).await?;
+		let hyperbridge: Arc<dyn IsmpProvider> = Arc::new(hyperbridge);
+		let mut hyperbridge_witnesses = vec![];
+		for (endpoint, config) in substrate_endpoint_configs(&config.hyperbridge) {
+			match SubstrateClient::<KeccakSubstrateChain>::new(config).await {
+				Ok(client) =>
+					hyperbridge_witnesses.push(Witness { endpoint, client: Arc::new(client) }),
+				Err(err) => log::error!("Failed to connect to hyperbridge rpc {endpoint}: {err:?}"),
+			}
+		}
+
+		let tokio_handle = tokio::runtime::Handle::current();
+		let mut task_manager = TaskManager::new(tokio_handle, None)?;
+
+		for (state_machine, chain) in config.chains {
+			let client = chain.clone().into_client(hyperbridge.clone(), None).await?;
+			let mut witnesses = vec![];
+			for (endpoint, config) in endpoint_configs(&chain) {
+				match config.into_client(hyperbridge.clone(), None).await {
+					Ok(client) => witnesses.push(Witness { endpoint, client }),
+					Err(err) =>
+						log::error!("Failed to connect to {state_machine} rpc {endpoint}: {err:?}"),
+				}
+			}
+			if witnesses.len() < quorum {
+				log::warn!(
+					"{state_machine} has {} rpc endpoints, its state commitments can't reach the quorum of {quorum} needed for a veto",
+					witnesses.len()
+				);
+			}
+
+			// State commitments of the chain on hyperbridge
+			cross_check(
+				hyperbridge.clone(),
+				client.state_machine_id(),
+				witnesses,
+				coprocessor,
+				config.fisherman.clone(),
+				audit.clone(),
+				&task_manager,
+			)
+			.await?;
+
+			// State commitments of hyperbridge on the evm host
+			if state_machine.is_evm() {
+				cross_check(
+					client,
+					hyperbridge.state_machine_id(),
+					hyperbridge_witnesses.clone(),
+					coprocessor,
+					config.fisherman.clone(),
+					audit.clone(),
+					&task_manager,
+				)
+				.await?;
+			}
+		}
+
+		// Consensus updates of the clients on hyperbridge
+		for consensus in &config.consensus {
+			let watcher =
+				ConsensusWatcher::from_config(hyperbridge.clone(), consensus, audit.clone())
+					.await?;
+			detect_fraud(watcher, consensus.consensus_state_id.clone(), &task_manager);
+		}
+
+		log::info!("🎣 Watching state commitments with a quorum of {quorum} endpoints");
+		task_manager.future().await?;
+
+		Ok(())
+	}
+}
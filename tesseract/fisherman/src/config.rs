@@ -0,0 +1,290 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tesseract fisherman config
+
+use anyhow::anyhow;
+use ismp::host::StateMachine;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tesseract_config::AnyConfig;
+use tesseract_evm::EvmConfig;
+use tesseract_substrate::SubstrateConfig;
+use toml::Table;
+
+/// Number of rpc endpoints that must agree before a state commitment is vetoed, when it is not
+/// configured
+pub const DEFAULT_QUORUM: usize = 2;
+
+/// Defines the format of the fisherman config.toml file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FishermanConfig {
+	/// Configuration options for hyperbridge.
+	pub hyperbridge: SubstrateConfig,
+	/// Fisherman options
+	pub fisherman: FishermanOptions,
+	/// Chains whose state commitments are checked
+	pub chains: HashMap<StateMachine, AnyConfig>,
+	/// Consensus clients on hyperbridge whose updates are checked for conflicts
+	pub consensus: Vec<ConsensusConfig>,
+}
+
+/// Options for cross-checking state commitments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FishermanOptions {
+	/// Number of rpc endpoints that must independently observe a conflicting state before a
+	/// state commitment is vetoed, defaults to 2
+	pub quorum: Option<usize>,
+	/// Allow a quorum of 1, which lets a single faulty rpc endpoint veto state commitments
+	pub allow_single_endpoint_quorum: Option<bool>,
+	/// Path to the audit log, every decision is appended to it as a json line
+	pub audit_log: String,
+	/// Seconds between rechecks of inconclusive state commitments, defaults to 30
+	pub recheck_interval: Option<u64>,
+}
+
+/// Seconds between rechecks of inconclusive state commitments, when it is not configured
+pub const DEFAULT_RECHECK_INTERVAL: u64 = 30;
+
+impl FishermanOptions {
+	pub fn quorum(&self) -> usize {
+		self.quorum.unwrap_or(DEFAULT_QUORUM)
+	}
+
+	pub fn recheck_interval(&self) -> u64 {
+		self.recheck_interval.unwrap_or(DEFAULT_RECHECK_INTERVAL)
+	}
+}
+
+/// Seconds between checks for conflicting consensus proofs, when it is not configured
+pub const DEFAULT_CONSENSUS_INTERVAL: u64 = 60;
+
+/// A consensus client on hyperbridge whose updates are checked for conflicts, by comparing the
+/// consensus proofs built from each rpc endpoint of its chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsensusConfig {
+	/// Consensus state id of the client on hyperbridge
+	pub consensus_state_id: String,
+	/// Consensus protocol of the chain
+	pub protocol: ConsensusProtocol,
+	/// State machine of the chain
+	pub state_machine: StateMachine,
+	/// Rpc endpoints of the chain, http for bsc and websockets for grandpa
+	pub rpc_urls: Vec<String>,
+	/// Seconds between checks, defaults to 60
+	pub interval: Option<u64>,
+}
+
+impl ConsensusConfig {
+	pub fn interval(&self) -> u64 {
+		self.interval.unwrap_or(DEFAULT_CONSENSUS_INTERVAL)
+	}
+}
+
+/// Consensus protocols for which conflicting updates can be proven
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsensusProtocol {
+	/// Fast finality of the BNB smart chain
+	Bsc,
+	/// Grandpa finality of a relay chain or standalone chain
+	Grandpa,
+}
+
+const HYPERBRIDGE: &str = "hyperbridge";
+const FISHERMAN: &str = "fisherman";
+const CONSENSUS: &str = "consensus";
+
+impl FishermanConfig {
+	pub async fn parse_conf(config: &str) -> Result<Self, anyhow::Error> {
+		let toml = tokio::fs::read_to_string(config)
+			.await
+			.map_err(|err| anyhow!("Error occured while reading config file: {err:?}"))?;
+		let table = toml.parse::<Table>()?;
+		let mut chains: HashMap<StateMachine, AnyConfig> = HashMap::new();
+
+		let hyperbridge: SubstrateConfig = table
+			.get(HYPERBRIDGE)
+			.cloned()
+			.ok_or_else(|| anyhow!("Missing Hyperbridge Config, Check your toml file"))?
+			.try_into()
+			.map_err(|err| anyhow!("Failed to parse hyperbridge config: {err:?}"))?;
+		let fisherman: FishermanOptions = table
+			.get(FISHERMAN)
+			.cloned()
+			.ok_or_else(|| anyhow!("Missing Fisherman Config, Check your toml file"))?
+			.try_into()
+			.map_err(|err| anyhow!("Failed to parse fisherman config: {err:?}"))?;
+		match fisherman.quorum() {
+			0 => Err(anyhow!("The fisherman quorum must be at least 1"))?,
+			1 if !fisherman.allow_single_endpoint_quorum.unwrap_or_default() => Err(anyhow!(
+				"A fisherman quorum of 1 lets a single rpc endpoint veto state commitments, set allow_single_endpoint_quorum = true to use it"
+			))?,
+			_ => {},
+		}
+		let mut consensus: Vec<ConsensusConfig> = table
+			.get(CONSENSUS)
+			.cloned()
+			.map(|val| val.try_into())
+			.transpose()
+			.map_err(|err| anyhow!("Failed to parse consensus config: {err:?}"))?
+			.unwrap_or_default();
+		for config in &mut consensus {
+			if config.consensus_state_id.len() != 4 {
+				Err(anyhow!(
+					"Consensus state id {} must be exactly 4 bytes",
+					config.consensus_state_id
+				))?
+			}
+			dedup_urls(&mut config.rpc_urls);
+		}
+		for (key, val) in table {
+			if key != HYPERBRIDGE && key != FISHERMAN && key != CONSENSUS {
+				let mut any_conf: AnyConfig = val
+					.try_into()
+					.map_err(|err| anyhow!("Failed to parse {key} config: {err:?}"))?;
+				// The same endpoint listed twice must not count twice towards the quorum
+				match any_conf {
+					AnyConfig::Evm(ref mut config) => dedup_urls(&mut config.rpc_urls),
+					AnyConfig::Substrate(ref mut config) =>
+						if let Some(ref mut fallbacks) = config.fallback_rpc_ws {
+							dedup_urls(fallbacks);
+							fallbacks
+								.retain(|url| normalize_url(url) != normalize_url(&config.rpc_ws));
+						},
+				}
+				chains.insert(any_conf.state_machine(), any_conf);
+			}
+		}
+		Ok(Self { hyperbridge, fisherman, chains, consensus })
+	}
+}
+
+/// Strip surrounding whitespace and trailing slashes, so that the same endpoint is recognised
+fn normalize_url(url: &str) -> &str {
+	url.trim().trim_end_matches('/')
+}
+
+/// Remove repeated rpc urls, keeping the first occurrence of each
+fn dedup_urls(urls: &mut Vec<String>) {
+	let mut seen = HashSet::new();
+	urls.retain(|url| seen.insert(normalize_url(url).to_string()));
+}
+
+/// Split the config of a chain into a config for each of its rpc endpoints, so that every
+/// endpoint can be queried independently.
+pub fn endpoint_configs(config: &AnyConfig) -> Vec<(String, AnyConfig)> {
+	match config {
+		AnyConfig::Evm(config) => config
+			.rpc_urls
+			.iter()
+			.map(|url| {
+				let config =
+					EvmConfig { rpc_urls: vec![url.clone()], rpc_pool: None, ..config.clone() };
+				(url.clone(), AnyConfig::Evm(config))
+			})
+			.collect(),
+		AnyConfig::Substrate(config) => substrate_endpoint_configs(config)
+			.into_iter()
+			.map(|(url, config)| (url, AnyConfig::Substrate(config)))
+			.collect(),
+	}
+}
+
+/// Split the config of a substrate chain into a config for each of its rpc endpoints
+pub fn substrate_endpoint_configs(config: &SubstrateConfig) -> Vec<(String, SubstrateConfig)> {
+	std::iter::once(&config.rpc_ws)
+		.chain(config.fallback_rpc_ws.iter().flatten())
+		.map(|url| {
+			let config = SubstrateConfig {
+				rpc_ws: url.clone(),
+				fallback_rpc_ws: None,
+				rpc_pool: None,
+				..config.clone()
+			};
+			(url.clone(), config)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const HYPERBRIDGE_CONFIG: &str = r#"
+[hyperbridge]
+state_machine = "KUSAMA-4009"
+hashing = "Keccak"
+rpc_ws = "ws://127.0.0.1:9933"
+signer = ""
+"#;
+
+	async fn parse(name: &str, config: &str) -> Result<FishermanConfig, anyhow::Error> {
+		let path = std::env::temp_dir().join(name);
+		tokio::fs::write(&path, format!("{HYPERBRIDGE_CONFIG}{config}")).await.unwrap();
+		FishermanConfig::parse_conf(path.to_str().unwrap()).await
+	}
+
+	#[tokio::test]
+	async fn should_reject_a_quorum_of_one_unless_allowed() {
+		let config = r#"
+[fisherman]
+quorum = 1
+audit_log = "audit.jsonl"
+"#;
+		let err = parse("fisherman-quorum.toml", config).await.unwrap_err();
+		assert!(err.to_string().contains("allow_single_endpoint_quorum"));
+
+		let config = r#"
+[fisherman]
+quorum = 1
+allow_single_endpoint_quorum = true
+audit_log = "audit.jsonl"
+"#;
+		let config = parse("fisherman-allowed-quorum.toml", config).await.unwrap();
+		assert_eq!(config.fisherman.quorum(), 1);
+	}
+
+	#[tokio::test]
+	async fn should_dedupe_rpc_urls() {
+		let config = r#"
+[fisherman]
+audit_log = "audit.jsonl"
+
+[[consensus]]
+consensus_state_id = "BSC0"
+protocol = "bsc"
+state_machine = "EVM-56"
+rpc_urls = ["https://bsc.example", "https://bsc.example/", "https://other.example"]
+
+[bsc]
+type = "evm"
+state_machine = "EVM-56"
+rpc_urls = ["https://bsc.example", " https://bsc.example/", "https://other.example"]
+etherscan_api_key = ""
+ismp_host = "0x8Ac39DfC1F2616e5e19B93420C6d008a8a8EE65f"
+consensus_state_id = "BSC0"
+signer = "0x8Ac39DfC1F2616e5e19B93420C6d008a8a8EE65f008a8a8EE65f"
+"#;
+		let config = parse("fisherman-dedupe.toml", config).await.unwrap();
+		let expected = vec!["https://bsc.example".to_string(), "https://other.example".to_string()];
+		assert_eq!(config.consensus[0].rpc_urls, expected);
+		let endpoints = endpoint_configs(&config.chains[&StateMachine::Evm(56)])
+			.into_iter()
+			.map(|(url, _)| url)
+			.collect::<Vec<_>>();
+		assert_eq!(endpoints, expected);
+	}
+}
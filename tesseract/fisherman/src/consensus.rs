@@ -0,0 +1,746 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Detection of conflicting consensus updates
+
+use std::{
+	sync::Arc,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::anyhow;
+use bsc_prover::BscPosProver;
+use bsc_verifier::primitives::{
+	parse_extra, BscClientUpdate, Config as BscConfig, Mainnet, Testnet,
+};
+use codec::{Decode, Encode};
+use ethers::providers::{Http, Provider};
+use geth_primitives::Header;
+use grandpa_prover::{GrandpaJustification, GrandpaProver};
+use grandpa_verifier_primitives::{ConsensusState as GrandpaConsensusState, FinalityProof};
+use ismp::{
+	consensus::ConsensusStateId,
+	host::StateMachine,
+	messaging::{FraudProofMessage, Message},
+};
+use ismp_bsc::ConsensusState as BscConsensusState;
+use ismp_grandpa::messages::ConsensusMessage as GrandpaConsensusMessage;
+use sp_core::H256;
+use subxt::config::substrate::{BlakeTwo256, SubstrateHeader};
+use subxt_utils::BlakeSubstrateChain;
+use tesseract_primitives::{Hasher, IsmpProvider};
+
+use crate::{
+	audit::{AuditLog, ConflictingHeader, FraudProofRecord},
+	config::{ConsensusConfig, ConsensusProtocol},
+};
+
+/// Chain id of the bsc testnet
+const BSC_TESTNET_CHAIN_ID: u32 = 97;
+
+/// Maximum number of heights compared in a single check, the rest are compared in the following
+/// checks
+const MAX_HEIGHTS_PER_CHECK: u64 = 256;
+
+/// A consensus proof built from one of the rpc endpoints of a chain
+#[derive(Debug, Clone)]
+pub struct ConsensusProof {
+	/// Height of the header finalized by the proof
+	pub height: u64,
+	/// Hash of the header finalized by the proof
+	pub hash: H256,
+	/// Encoded proof, in the format expected by `verify_fraud_proof` of the consensus client
+	pub proof: Vec<u8>,
+}
+
+/// Builds consensus proofs from a single rpc endpoint of a chain
+#[async_trait::async_trait]
+pub trait ConsensusProver: Send + Sync {
+	/// Latest height finalized on the endpoint
+	async fn finalized_height(&self) -> Result<u64, anyhow::Error>;
+
+	/// Build a proof that finalizes the header at `height`, which can be verified against the
+	/// consensus state trusted by the host. Returns `None` if no proof can be built for it.
+	async fn consensus_proof(
+		&self,
+		height: u64,
+		trusted_consensus_state: &[u8],
+	) -> Result<Option<ConsensusProof>, anyhow::Error>;
+
+	/// Decode a consensus proof accepted by the host into the header it finalizes
+	fn decode_proof(&self, proof: &[u8]) -> Result<ConsensusProof, anyhow::Error>;
+
+	/// Build a proof of the header finalized on the endpoint at the height of a proof accepted by
+	/// the host, which can be submitted alongside it as a fraud proof. The returned proof carries
+	/// the hash of the header at `accepted.height`. Returns `None` if no proof can be built for
+	/// it.
+	async fn canonical_proof(
+		&self,
+		accepted: &ConsensusProof,
+		trusted_consensus_state: &[u8],
+	) -> Result<Option<ConsensusProof>, anyhow::Error>;
+}
+
+/// Builds bsc client updates whose attested header is at the requested height. Only heights that
+/// have been finalized by a vote attestation are compared, since endpoints may legitimately
+/// disagree about blocks that were not.
+pub struct BscProver<C: BscConfig> {
+	prover: BscPosProver<C>,
+}
+
+impl<C: BscConfig> BscProver<C> {
+	pub fn new(rpc_url: &str) -> Result<Self, anyhow::Error> {
+		let provider = Provider::<Http>::try_from(rpc_url)?;
+		Ok(Self { prover: BscPosProver::new(provider) })
+	}
+}
+
+#[async_trait::async_trait]
+impl<C: BscConfig + 'static> ConsensusProver for BscProver<C> {
+	/// The source of the vote attestation in the latest header, which has been finalized by fast
+	/// finality
+	async fn finalized_height(&self) -> Result<u64, anyhow::Error> {
+		let latest_header = self.prover.latest_header().await?;
+		let extra_data = parse_extra::<Hasher, C>(&latest_header)
+			.map_err(|_| anyhow!("Extra data not found in header {:?}", latest_header.number))?;
+		if extra_data.vote_data.source_hash == Default::default() {
+			Err(anyhow!("Header {:?} has no vote attestation", latest_header.number))?
+		}
+		Ok(extra_data.vote_data.source_number)
+	}
+
+	async fn consensus_proof(
+		&self,
+		height: u64,
+		trusted_consensus_state: &[u8],
+	) -> Result<Option<ConsensusProof>, anyhow::Error> {
+		// Both proofs of a fraud proof are verified against the validators of the consensus state
+		// trusted by the host, so updates must be built for its validator set and epoch rather
+		// than the ones reported by the endpoint.
+		let consensus_state = BscConsensusState::decode(&mut &trusted_consensus_state[..])?;
+		let Some(attested_header) = self.prover.fetch_header(height).await? else {
+			return Ok(None)
+		};
+		let hash = Header::from(&attested_header).hash::<Hasher>();
+		let update = self
+			.prover
+			.fetch_bsc_update::<Hasher>(
+				attested_header,
+				consensus_state.current_validators.len() as u64,
+				consensus_state.current_epoch,
+				false,
+			)
+			.await?;
+
+		Ok(update.map(|update| ConsensusProof { height, hash, proof: update.encode() }))
+	}
+
+	fn decode_proof(&self, proof: &[u8]) -> Result<ConsensusProof, anyhow::Error> {
+		let update = BscClientUpdate::decode(&mut &proof[..])?;
+		Ok(ConsensusProof {
+			height: update.attested_header.number.low_u64(),
+			hash: Header::from(&update.attested_header).hash::<Hasher>(),
+			proof: proof.to_vec(),
+		})
+	}
+
+	async fn canonical_proof(
+		&self,
+		accepted: &ConsensusProof,
+		trusted_consensus_state: &[u8],
+	) -> Result<Option<ConsensusProof>, anyhow::Error> {
+		self.consensus_proof(accepted.height, trusted_consensus_state).await
+	}
+}
+
+/// Builds grandpa finality proofs that descend from the latest height finalized by the host
+pub struct GrandpaFinalityProver {
+	prover: GrandpaProver<BlakeSubstrateChain>,
+}
+
+impl GrandpaFinalityProver {
+	pub async fn new(rpc_url: &str, state_machine: StateMachine) -> Result<Self, anyhow::Error> {
+		let prover = GrandpaProver::new(rpc_url, vec![], state_machine, vec![], vec![]).await?;
+		Ok(Self { prover })
+	}
+}
+
+#[async_trait::async_trait]
+impl ConsensusProver for GrandpaFinalityProver {
+	async fn finalized_height(&self) -> Result<u64, anyhow::Error> {
+		let hash = self.prover.client.rpc().finalized_head().await?;
+		let header = self
+			.prover
+			.client
+			.rpc()
+			.header(Some(hash))
+			.await?
+			.ok_or_else(|| anyhow!("Finalized header {hash:?} not found"))?;
+		Ok(header.number.into())
+	}
+
+	async fn consensus_proof(
+		&self,
+		height: u64,
+		trusted_consensus_state: &[u8],
+	) -> Result<Option<ConsensusProof>, anyhow::Error> {
+		let consensus_state = GrandpaConsensusState::decode(&mut &trusted_consensus_state[..])?;
+		// Both proofs of a fraud proof must descend from the same block, which is the latest
+		// block finalized by the host
+		if height <= consensus_state.latest_height as u64 {
+			return Ok(None)
+		}
+		let proof = self
+			.prover
+			.query_finality_proof::<SubstrateHeader<u32, BlakeTwo256>>(
+				consensus_state.latest_height,
+				height as u32,
+			)
+			.await?;
+		let justification = GrandpaJustification::<SubstrateHeader<u32, BlakeTwo256>>::decode(
+			&mut &proof.justification[..],
+		)?;
+
+		Ok(Some(ConsensusProof {
+			height: justification.commit.target_number.into(),
+			hash: proof.block,
+			proof: proof.encode(),
+		}))
+	}
+
+	/// Consensus messages carry the finality proof alongside parachain headers, only the
+	/// finality proof is compared
+	fn decode_proof(&self, proof: &[u8]) -> Result<ConsensusProof, anyhow::Error> {
+		let finality_proof = match GrandpaConsensusMessage::decode(&mut &proof[..])? {
+			GrandpaConsensusMessage::StandaloneChainMessage(message) => message.finality_proof,
+			GrandpaConsensusMessage::RelayChainMessage(message) => message.finality_proof,
+		};
+		let justification = GrandpaJustification::<SubstrateHeader<u32, BlakeTwo256>>::decode(
+			&mut &finality_proof.justification[..],
+		)?;
+
+		Ok(ConsensusProof {
+			height: justification.commit.target_number.into(),
+			hash: finality_proof.block,
+			proof: finality_proof.encode(),
+		})
+	}
+
+	async fn canonical_proof(
+		&self,
+		accepted: &ConsensusProof,
+		_trusted_consensus_state: &[u8],
+	) -> Result<Option<ConsensusProof>, anyhow::Error> {
+		// Both proofs of a fraud proof must descend from the same block, so the canonical proof is
+		// built from the base of the accepted one
+		let finality_proof =
+			FinalityProof::<SubstrateHeader<u32, BlakeTwo256>>::decode(&mut &accepted.proof[..])?;
+		let Some(base) = finality_proof.unknown_headers.iter().map(|header| header.number).min()
+		else {
+			return Ok(None)
+		};
+		let Some(hash) = self
+			.prover
+			.client
+			.rpc()
+			.block_hash(Some((accepted.height as u32).into()))
+			.await?
+		else {
+			return Ok(None)
+		};
+		let proof = self
+			.prover
+			.query_finality_proof::<SubstrateHeader<u32, BlakeTwo256>>(base, accepted.height as u32)
+			.await?;
+
+		Ok(Some(ConsensusProof { height: accepted.height, hash, proof: proof.encode() }))
+	}
+}
+
+/// Returns the first two proofs that finalize different headers at the same height
+pub fn find_conflict(
+	proofs: &[(String, ConsensusProof)],
+) -> Option<(&(String, ConsensusProof), &(String, ConsensusProof))> {
+	proofs.iter().enumerate().find_map(|(i, first)| {
+		proofs[i + 1..]
+			.iter()
+			.find(|second| first.1.height == second.1.height && first.1.hash != second.1.hash)
+			.map(|second| (first, second))
+	})
+}
+
+/// Compares the consensus proofs built from every rpc endpoint of a chain with each other, and the
+/// consensus proofs accepted by the host with the chain, and submits a fraud proof to the host
+/// when two of them finalize conflicting headers.
+pub struct ConsensusWatcher {
+	host: Arc<dyn IsmpProvider>,
+	consensus_state_id: ConsensusStateId,
+	/// Provers for each rpc endpoint of the chain
+	provers: Vec<(String, Box<dyn ConsensusProver>)>,
+	interval: Duration,
+	audit: AuditLog,
+}
+
+impl ConsensusWatcher {
+	pub fn new(
+		host: Arc<dyn IsmpProvider>,
+		consensus_state_id: ConsensusStateId,
+		provers: Vec<(String, Box<dyn ConsensusProver>)>,
+		interval: Duration,
+		audit: AuditLog,
+	) -> Self {
+		Self { host, consensus_state_id, provers, interval, audit }
+	}
+
+	/// Create a watcher with a prover for every rpc endpoint in the config. Endpoints that can't
+	/// be connected to are skipped.
+	pub async fn from_config(
+		host: Arc<dyn IsmpProvider>,
+		config: &ConsensusConfig,
+		audit: AuditLog,
+	) -> Result<Self, anyhow::Error> {
+		let mut consensus_state_id: ConsensusStateId = Default::default();
+		consensus_state_id.copy_from_slice(config.consensus_state_id.as_bytes());
+
+		let mut provers = vec![];
+		for rpc_url in &config.rpc_urls {
+			let prover: Result<Box<dyn ConsensusProver>, anyhow::Error> =
+				match (config.protocol, config.state_machine) {
+					(ConsensusProtocol::Bsc, StateMachine::Evm(BSC_TESTNET_CHAIN_ID)) =>
+						BscProver::<Testnet>::new(rpc_url).map(|prover| Box::new(prover) as _),
+					(ConsensusProtocol::Bsc, _) =>
+						BscProver::<Mainnet>::new(rpc_url).map(|prover| Box::new(prover) as _),
+					(ConsensusProtocol::Grandpa, state_machine) =>
+						GrandpaFinalityProver::new(rpc_url, state_machine)
+							.await
+							.map(|prover| Box::new(prover) as _),
+				};
+			match prover {
+				Ok(prover) => provers.push((rpc_url.clone(), prover)),
+				Err(err) => log::error!(
+					"Failed to connect to {} rpc {rpc_url}: {err:?}",
+					config.state_machine
+				),
+			}
+		}
+		if provers.len() < 2 {
+			log::warn!(
+				"{} has {} rpc endpoints, at least two are needed to compare their consensus proofs",
+				config.consensus_state_id,
+				provers.len()
+			);
+		}
+
+		Ok(Self::new(
+			host,
+			consensus_state_id,
+			provers,
+			Duration::from_secs(config.interval()),
+			audit,
+		))
+	}
+
+	/// Check for conflicting consensus proofs at every interval
+	pub async fn run(self) -> Result<(), anyhow::Error> {
+		let mut interval = tokio::time::interval(self.interval);
+		let mut last_checked = 0;
+		let mut last_host_height = self.host.query_finalized_height().await?;
+		loop {
+			interval.tick().await;
+			match self.check_accepted_updates(last_host_height).await {
+				Ok(Some(height)) => last_host_height = height,
+				Ok(None) => {},
+				Err(err) => log::error!(
+					"Failed to check consensus updates of {} accepted by {}: {err:?}",
+					self.name(),
+					self.host.name()
+				),
+			}
+			match self.check(last_checked).await {
+				Ok(Some(height)) => last_checked = height,
+				Ok(None) => {},
+				Err(err) => log::error!(
+					"Failed to check consensus proofs of {} for conflicts: {err:?}",
+					self.name()
+				),
+			}
+		}
+	}
+
+	/// Compare the consensus proofs accepted by the host since `last_host_height` with the proofs
+	/// built from every endpoint at the same heights, and submit a fraud proof if any of them
+	/// conflict. Returns the height of the host that was checked.
+	async fn check_accepted_updates(
+		&self,
+		last_host_height: u64,
+	) -> Result<Option<u64>, anyhow::Error> {
+		let host_height = self.host.query_finalized_height().await?;
+		if host_height <= last_host_height {
+			return Ok(None)
+		}
+		let Some((_, decoder)) = self.provers.first() else { return Ok(Some(host_height)) };
+
+		let updates = self
+			.host
+			.query_consensus_updates(self.consensus_state_id, last_host_height + 1, host_height)
+			.await?;
+		if updates.is_empty() {
+			return Ok(Some(host_height))
+		}
+
+		let trusted_consensus_state =
+			self.host.query_consensus_state(None, self.consensus_state_id).await?;
+		for update in updates {
+			let accepted = match decoder.decode_proof(&update) {
+				Ok(accepted) => accepted,
+				Err(err) => {
+					log::error!(
+						"Failed to decode consensus update of {} accepted by {}: {err:?}",
+						self.name(),
+						self.host.name()
+					);
+					continue;
+				},
+			};
+			for (endpoint, prover) in &self.provers {
+				let canonical =
+					match prover.canonical_proof(&accepted, &trusted_consensus_state).await {
+						Ok(Some(canonical)) => canonical,
+						Ok(None) => continue,
+						Err(err) => {
+							log::error!(
+								"Failed to build consensus proof of {} at {} from {endpoint}: {err:?}",
+								self.name(),
+								accepted.height
+							);
+							continue;
+						},
+					};
+				if canonical.hash != accepted.hash {
+					let host = format!("{} (accepted)", self.host.name());
+					self.submit_fraud_proof((&host, &accepted), (endpoint, &canonical)).await?;
+					return Ok(Some(host_height))
+				}
+			}
+		}
+
+		Ok(Some(host_height))
+	}
+
+	/// Build a proof from every endpoint at every height they have all finalized since
+	/// `last_checked`, and submit a fraud proof if any two of them conflict. Proofs are only
+	/// compared within a check, so that all of them are built against the same trusted consensus
+	/// state. Returns the height that was checked up to.
+	async fn check(&self, last_checked: u64) -> Result<Option<u64>, anyhow::Error> {
+		let heights = futures::future::join_all(
+			self.provers.iter().map(|(_, prover)| prover.finalized_height()),
+		)
+		.await;
+		let Some(height) = heights.into_iter().filter_map(|height| height.ok()).min() else {
+			return Ok(None)
+		};
+		if height <= last_checked {
+			return Ok(None)
+		}
+
+		let trusted_consensus_state =
+			self.host.query_consensus_state(None, self.consensus_state_id).await?;
+		// The first check starts from the latest height, since earlier heights were finalized
+		// before the watcher started
+		let mut next = if last_checked == 0 { height } else { last_checked + 1 };
+		let end = height.min(next + MAX_HEIGHTS_PER_CHECK - 1);
+		while next <= end {
+			let proofs = self.consensus_proofs(next, &trusted_consensus_state).await;
+			if let Some(((endpoint_1, proof_1), (endpoint_2, proof_2))) = find_conflict(&proofs) {
+				self.submit_fraud_proof((endpoint_1, proof_1), (endpoint_2, proof_2)).await?;
+				return Ok(Some(next))
+			}
+			// Proofs may finalize a later header than the one requested, which covers the
+			// heights in between
+			let covered = proofs.iter().map(|(_, proof)| proof.height).min().unwrap_or(next);
+			next = covered.max(next) + 1;
+		}
+
+		Ok(Some(next - 1))
+	}
+
+	/// Build a proof from every endpoint at `height`
+	async fn consensus_proofs(
+		&self,
+		height: u64,
+		trusted_consensus_state: &[u8],
+	) -> Vec<(String, ConsensusProof)> {
+		let results = futures::future::join_all(
+			self.provers
+				.iter()
+				.map(|(_, prover)| prover.consensus_proof(height, trusted_consensus_state)),
+		)
+		.await;
+		let mut proofs = vec![];
+		for ((endpoint, _), result) in self.provers.iter().zip(results) {
+			match result {
+				Ok(Some(proof)) => proofs.push((endpoint.clone(), proof)),
+				Ok(None) => {},
+				Err(err) => log::error!(
+					"Failed to build consensus proof of {} at {height} from {endpoint}: {err:?}",
+					self.name()
+				),
+			}
+		}
+		proofs
+	}
+
+	/// Submit two conflicting consensus proofs to the host as a fraud proof, and record it in the
+	/// audit log
+	async fn submit_fraud_proof(
+		&self,
+		(endpoint_1, proof_1): (&String, &ConsensusProof),
+		(endpoint_2, proof_2): (&String, &ConsensusProof),
+	) -> Result<(), anyhow::Error> {
+		log::warn!(
+			"🚨 {endpoint_1} and {endpoint_2} finalized conflicting headers {:?} and {:?} at {} of {}, submitting fraud proof to {}",
+			proof_1.hash,
+			proof_2.hash,
+			proof_1.height,
+			self.name(),
+			self.host.name()
+		);
+		let message = Message::FraudProof(FraudProofMessage {
+			proof_1: proof_1.proof.clone(),
+			proof_2: proof_2.proof.clone(),
+			consensus_state_id: self.consensus_state_id,
+		});
+		let error = match self.host.submit(vec![message]).await {
+			Ok(_) => None,
+			Err(err) => {
+				log::error!("Failed to submit fraud proof to {}: {err:?}", self.host.name());
+				Some(format!("{err:?}"))
+			},
+		};
+
+		let record = FraudProofRecord {
+			timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+			consensus_state_id: self.name(),
+			host: self.host.name(),
+			height: proof_1.height,
+			headers: vec![
+				ConflictingHeader {
+					endpoint: endpoint_1.clone(),
+					hash: format!("{:?}", proof_1.hash),
+				},
+				ConflictingHeader {
+					endpoint: endpoint_2.clone(),
+					hash: format!("{:?}", proof_2.hash),
+				},
+			],
+			error,
+		};
+		self.audit.record(&record).await
+	}
+
+	fn name(&self) -> String {
+		String::from_utf8_lossy(&self.consensus_state_id).to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::Mutex;
+	use tesseract_primitives::mocks::MockHost;
+
+	fn proof(height: u64, byte: u8) -> ConsensusProof {
+		ConsensusProof { height, hash: H256::repeat_byte(byte), proof: (height, byte).encode() }
+	}
+
+	/// Finalizes headers hashed with `1`, or with `2` from the height at which it forked
+	struct MockProver {
+		finalized_height: u64,
+		forked_at: Arc<Mutex<Option<u64>>>,
+	}
+
+	#[async_trait::async_trait]
+	impl ConsensusProver for MockProver {
+		async fn finalized_height(&self) -> Result<u64, anyhow::Error> {
+			Ok(self.finalized_height)
+		}
+
+		async fn consensus_proof(
+			&self,
+			height: u64,
+			_trusted_consensus_state: &[u8],
+		) -> Result<Option<ConsensusProof>, anyhow::Error> {
+			let forked = self.forked_at.lock().unwrap().is_some_and(|fork| height >= fork);
+			Ok(Some(proof(height, if forked { 2 } else { 1 })))
+		}
+
+		fn decode_proof(&self, proof: &[u8]) -> Result<ConsensusProof, anyhow::Error> {
+			let (height, byte) = <(u64, u8)>::decode(&mut &proof[..])?;
+			Ok(self::proof(height, byte))
+		}
+
+		async fn canonical_proof(
+			&self,
+			accepted: &ConsensusProof,
+			trusted_consensus_state: &[u8],
+		) -> Result<Option<ConsensusProof>, anyhow::Error> {
+			self.consensus_proof(accepted.height, trusted_consensus_state).await
+		}
+	}
+
+	async fn watcher(
+		host: Arc<MockHost<()>>,
+		provers: Vec<(String, Box<dyn ConsensusProver>)>,
+		name: &str,
+	) -> (ConsensusWatcher, std::path::PathBuf) {
+		let path = std::env::temp_dir().join(format!("fisherman-{name}-audit.jsonl"));
+		let audit = AuditLog::open(path.to_str().unwrap()).await.unwrap();
+		(ConsensusWatcher::new(host, *b"BSC0", provers, Duration::from_secs(1), audit), path)
+	}
+
+	fn fraud_proof(messages: &[Message]) -> &FraudProofMessage {
+		match messages {
+			[Message::FraudProof(message)] => message,
+			messages => panic!("Unexpected messages {messages:?}"),
+		}
+	}
+
+	#[tokio::test]
+	async fn should_only_submit_fraud_proofs_for_equivocations() {
+		let host = Arc::new(MockHost::new((), 0, StateMachine::Evm(BSC_TESTNET_CHAIN_ID)));
+		let submitted = host.submitted.clone();
+		let forked_at = Arc::new(Mutex::new(None));
+		let provers: Vec<(String, Box<dyn ConsensusProver>)> = vec![
+			(
+				"a".to_string(),
+				Box::new(MockProver { finalized_height: 10, forked_at: Default::default() }),
+			),
+			(
+				"b".to_string(),
+				Box::new(MockProver { finalized_height: 12, forked_at: forked_at.clone() }),
+			),
+		];
+		let (watcher, path) = watcher(host, provers, "consensus").await;
+
+		// Endpoints that agree at the lowest height they have both finalized
+		assert_eq!(watcher.check(0).await.unwrap(), Some(10));
+		assert!(submitted.lock().unwrap().is_empty());
+
+		// A fork above the height both endpoints have finalized is not compared
+		*forked_at.lock().unwrap() = Some(11);
+		assert_eq!(watcher.check(0).await.unwrap(), Some(10));
+		assert!(submitted.lock().unwrap().is_empty());
+
+		// Heights that were already checked are skipped
+		*forked_at.lock().unwrap() = Some(10);
+		assert_eq!(watcher.check(10).await.unwrap(), None);
+		assert!(submitted.lock().unwrap().is_empty());
+
+		// Conflicting headers at the same finalized height are an equivocation
+		assert_eq!(watcher.check(0).await.unwrap(), Some(10));
+		let submitted = submitted.lock().unwrap();
+		assert_eq!(submitted.len(), 1);
+		let message = fraud_proof(&submitted[0]);
+		assert_eq!(message.proof_1, proof(10, 1).proof);
+		assert_eq!(message.proof_2, proof(10, 2).proof);
+		assert_eq!(message.consensus_state_id, *b"BSC0");
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[tokio::test]
+	async fn should_check_every_height_since_the_last_check() {
+		let host = Arc::new(MockHost::new((), 0, StateMachine::Evm(BSC_TESTNET_CHAIN_ID)));
+		let submitted = host.submitted.clone();
+		let forked_at = Arc::new(Mutex::new(None));
+		let provers: Vec<(String, Box<dyn ConsensusProver>)> = vec![
+			(
+				"a".to_string(),
+				Box::new(MockProver { finalized_height: 20, forked_at: Default::default() }),
+			),
+			(
+				"b".to_string(),
+				Box::new(MockProver { finalized_height: 20, forked_at: forked_at.clone() }),
+			),
+		];
+		let (watcher, path) = watcher(host, provers, "heights").await;
+
+		// Every height up to the latest one is compared
+		assert_eq!(watcher.check(5).await.unwrap(), Some(20));
+		assert!(submitted.lock().unwrap().is_empty());
+
+		// A fork between checks is found at the first height it conflicts
+		*forked_at.lock().unwrap() = Some(8);
+		assert_eq!(watcher.check(5).await.unwrap(), Some(8));
+		let submitted = submitted.lock().unwrap();
+		assert_eq!(submitted.len(), 1);
+		assert_eq!(fraud_proof(&submitted[0]).proof_2, proof(8, 2).proof);
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[tokio::test]
+	async fn should_submit_fraud_proofs_for_forged_updates_accepted_by_the_host() {
+		let host = Arc::new(MockHost::new((), 5, StateMachine::Evm(BSC_TESTNET_CHAIN_ID)));
+		let submitted = host.submitted.clone();
+		let accepted = host.consensus_updates.clone();
+		let provers: Vec<(String, Box<dyn ConsensusProver>)> = vec![(
+			"a".to_string(),
+			Box::new(MockProver { finalized_height: 20, forked_at: Default::default() }),
+		)];
+		let (watcher, path) = watcher(host.clone(), provers, "accepted").await;
+
+		// Canonical updates accepted by the host
+		accepted.lock().unwrap().push((4, proof(10, 1).proof));
+		assert_eq!(watcher.check_accepted_updates(3).await.unwrap(), Some(5));
+		assert!(submitted.lock().unwrap().is_empty());
+
+		// Heights of the host that were already checked are skipped
+		accepted.lock().unwrap().push((5, proof(12, 3).proof));
+		assert_eq!(watcher.check_accepted_updates(5).await.unwrap(), None);
+		assert!(submitted.lock().unwrap().is_empty());
+
+		// A forged update is submitted alongside the canonical header at the same height
+		*host.latest_height.lock().unwrap() = 6;
+		accepted.lock().unwrap().push((6, proof(15, 3).proof));
+		assert_eq!(watcher.check_accepted_updates(5).await.unwrap(), Some(6));
+		let submitted = submitted.lock().unwrap();
+		assert_eq!(submitted.len(), 1);
+		let message = fraud_proof(&submitted[0]);
+		assert_eq!(message.proof_1, proof(15, 3).proof);
+		assert_eq!(message.proof_2, proof(15, 1).proof);
+		assert_eq!(message.consensus_state_id, *b"BSC0");
+
+		std::fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn finds_conflicting_headers_at_the_same_height() {
+		let proofs = vec![
+			("a".to_string(), proof(10, 1)),
+			("b".to_string(), proof(10, 1)),
+			("c".to_string(), proof(10, 2)),
+		];
+		let (first, second) = find_conflict(&proofs).unwrap();
+		assert_eq!(first.0, "a");
+		assert_eq!(second.0, "c");
+	}
+
+	#[test]
+	fn ignores_headers_at_different_heights() {
+		let proofs = vec![("a".to_string(), proof(10, 1)), ("b".to_string(), proof(11, 2))];
+		assert!(find_conflict(&proofs).is_none());
+	}
+}
@@ -15,14 +15,31 @@
 
 //! Tesseract Fisherman
 
-use std::sync::Arc;
+use std::{
+	sync::Arc,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use anyhow::anyhow;
 use futures::StreamExt;
-use ismp::host::StateMachine;
+use ismp::{
+	consensus::{StateMachineHeight, StateMachineId},
+	host::StateMachine,
+};
 use sc_service::TaskManager;
 use tesseract_primitives::IsmpProvider;
 
+use audit::{AuditLog, AuditRecord, EndpointObservation};
+use config::FishermanOptions;
+use consensus::ConsensusWatcher;
+use witness::{decide, Decision, Witness};
+
+pub mod audit;
+pub mod cli;
+pub mod config;
+pub mod consensus;
+pub mod witness;
+
 pub async fn fish(
 	chain_a: Arc<dyn IsmpProvider>,
 	chain_b: Arc<dyn IsmpProvider>,
@@ -84,3 +101,257 @@ async fn handle_notification(
 		chain_a.name()
 	))?
 }
+
+/// Spawn a task that cross-checks every state commitment of `state_machine` stored on `host`
+/// against the `witnesses`, vetoing it once a quorum of them observe a conflicting state.
+pub async fn cross_check(
+	host: Arc<dyn IsmpProvider>,
+	state_machine: StateMachineId,
+	witnesses: Vec<Witness>,
+	coprocessor: StateMachine,
+	options: FishermanOptions,
+	audit: AuditLog,
+	task_manager: &TaskManager,
+) -> Result<(), anyhow::Error> {
+	let name = format!("fisherman-{}-{}", host.name(), state_machine.state_id);
+	task_manager.spawn_essential_handle().spawn(
+		Box::leak(Box::new(name.clone())),
+		"fisherman",
+		async move {
+			let res = watch(host, state_machine, witnesses, coprocessor, options, audit).await;
+			tracing::error!(target: "tesseract", "{name} has terminated with result {res:?}")
+		},
+	);
+
+	Ok(())
+}
+
+/// Spawn a task that compares the consensus proofs of a chain built from each of its rpc
+/// endpoints with each other, and with the consensus updates accepted by the host of its consensus
+/// client, submitting a fraud proof to the host when they conflict.
+pub fn detect_fraud(watcher: ConsensusWatcher, name: String, task_manager: &TaskManager) {
+	let name = format!("fraud-proofs-{name}");
+	task_manager.spawn_essential_handle().spawn(
+		Box::leak(Box::new(name.clone())),
+		"fisherman",
+		async move {
+			let res = watcher.run().await;
+			tracing::error!(target: "tesseract", "{name} has terminated with result {res:?}")
+		},
+	);
+}
+
+/// Cross-check every state commitment of `state_machine` on its update. Inconclusive state
+/// commitments are rechecked until the witnesses reach a decision, or their challenge period
+/// elapses.
+async fn watch(
+	host: Arc<dyn IsmpProvider>,
+	state_machine: StateMachineId,
+	witnesses: Vec<Witness>,
+	coprocessor: StateMachine,
+	options: FishermanOptions,
+	audit: AuditLog,
+) -> Result<(), anyhow::Error> {
+	let mut state_machine_update_stream = host
+		.state_machine_updates(state_machine)
+		.await
+		.map_err(|err| anyhow!("StateMachineUpdated stream subscription failed: {err:?}"))?;
+	let quorum = options.quorum();
+	let mut recheck = tokio::time::interval(Duration::from_secs(options.recheck_interval()));
+	let mut inconclusive = Inconclusive::default();
+
+	loop {
+		tokio::select! {
+			item = state_machine_update_stream.next() => {
+				let Some(item) = item else { break };
+				match item {
+					Ok(state_machine_updates) =>
+						for state_machine_update in state_machine_updates {
+							// Some hosts report the updates of every state machine
+							if state_machine_update.state_machine_id.state_id != state_machine.state_id {
+								continue
+							}
+							let height = StateMachineHeight {
+								id: state_machine_update.state_machine_id,
+								height: state_machine_update.latest_height,
+							};
+							let res = check_state_commitment(
+								&host,
+								height,
+								&witnesses,
+								coprocessor,
+								quorum,
+								&audit,
+							)
+							.await;
+							match res {
+								Ok(Decision::Inconclusive) => inconclusive.push(&host, height).await,
+								Ok(_) => {},
+								Err(err) => log::error!(
+									"Failed to cross-check the state commitment of {} on {}: {err:?}",
+									state_machine.state_id,
+									host.name()
+								),
+							}
+						},
+					Err(e) => {
+						log::error!(target: "tesseract","Fisherman task {}-{} encountered an error: {e:?}", host.name(), state_machine.state_id)
+					},
+				}
+			},
+			_ = recheck.tick(), if !inconclusive.is_empty() => {
+				inconclusive.recheck(&host, &witnesses, coprocessor, quorum, &audit).await
+			},
+		}
+	}
+
+	Err(anyhow!(
+		"{}-{} fisherman task has failed, Please restart the fisherman",
+		host.name(),
+		state_machine.state_id
+	))?
+}
+
+/// State commitments whose cross-check was inconclusive, along with the host timestamp at which
+/// their challenge period elapses
+#[derive(Default)]
+struct Inconclusive {
+	heights: Vec<(StateMachineHeight, Duration)>,
+}
+
+impl Inconclusive {
+	fn is_empty(&self) -> bool {
+		self.heights.is_empty()
+	}
+
+	/// Queue the state commitment at `height` to be rechecked until its challenge period elapses
+	async fn push(&mut self, host: &Arc<dyn IsmpProvider>, height: StateMachineHeight) {
+		let deadline = async {
+			let update_time = host.query_state_machine_update_time(height).await?;
+			let challenge_period = host.query_challenge_period(height.id).await?;
+			Ok::<_, anyhow::Error>(update_time + challenge_period)
+		};
+		match deadline.await {
+			Ok(deadline) => self.heights.push((height, deadline)),
+			Err(err) => log::error!(
+				"Failed to query the challenge period of the state commitment of {} at {} on {}, it won't be rechecked: {err:?}",
+				height.id.state_id,
+				height.height,
+				host.name()
+			),
+		}
+	}
+
+	/// Cross-check the queued state commitments again, keeping the ones that are still
+	/// inconclusive. State commitments whose challenge period has elapsed can no longer be
+	/// vetoed, so they are dropped.
+	async fn recheck(
+		&mut self,
+		host: &Arc<dyn IsmpProvider>,
+		witnesses: &[Witness],
+		coprocessor: StateMachine,
+		quorum: usize,
+		audit: &AuditLog,
+	) {
+		let now = match host.query_timestamp().await {
+			Ok(now) => now,
+			Err(err) => {
+				log::error!("Failed to query the timestamp of {}: {err:?}", host.name());
+				return
+			},
+		};
+
+		let mut remaining = vec![];
+		for (height, deadline) in self.heights.drain(..) {
+			if now >= deadline {
+				log::warn!(
+					"Challenge period of the state commitment of {} at {} on {} elapsed before its endpoints reached a decision",
+					height.id.state_id,
+					height.height,
+					host.name()
+				);
+				continue
+			}
+			let res =
+				check_state_commitment(host, height, witnesses, coprocessor, quorum, audit).await;
+			match res {
+				Ok(Decision::Inconclusive) => remaining.push((height, deadline)),
+				Ok(_) => {},
+				Err(err) => {
+					log::error!(
+						"Failed to recheck the state commitment of {} at {} on {}: {err:?}",
+						height.id.state_id,
+						height.height,
+						host.name()
+					);
+					remaining.push((height, deadline))
+				},
+			}
+		}
+		self.heights = remaining;
+	}
+}
+
+/// Query the state root at `height` from every witness, veto the state commitment if enough of
+/// them conflict with it and record the decision in the audit log
+async fn check_state_commitment(
+	host: &Arc<dyn IsmpProvider>,
+	height: StateMachineHeight,
+	witnesses: &[Witness],
+	coprocessor: StateMachine,
+	quorum: usize,
+	audit: &AuditLog,
+) -> Result<Decision, anyhow::Error> {
+	let commitment = host.query_state_machine_commitment(height).await?;
+	let observations = futures::future::join_all(
+		witnesses
+			.iter()
+			.map(|witness| witness.observe(coprocessor, height.height, commitment.state_root)),
+	)
+	.await;
+
+	let decision = decide(&observations, quorum);
+	let mut error = None;
+	match decision {
+		Decision::Veto => {
+			log::info!(
+				"Vetoing state commitment for {} on {} at {}, a quorum of endpoints observed a conflicting state",
+				height.id.state_id,
+				host.name(),
+				height.height
+			);
+			if let Err(err) = host.veto_state_commitment(height).await {
+				log::error!("Failed to veto state commitment on {}: {err:?}", host.name());
+				error = Some(format!("{err:?}"));
+			}
+		},
+		Decision::Inconclusive => log::warn!(
+			"Endpoints of {} did not agree on the state commitment at {} on {}",
+			height.id.state_id,
+			height.height,
+			host.name()
+		),
+		Decision::Accept => {},
+	}
+
+	let record = AuditRecord {
+		timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+		state_machine: height.id.state_id.to_string(),
+		host: host.name(),
+		height: height.height,
+		state_root: format!("{:?}", commitment.state_root),
+		observations: witnesses
+			.iter()
+			.zip(observations)
+			.map(|(witness, observation)| EndpointObservation {
+				endpoint: witness.endpoint.clone(),
+				observation,
+			})
+			.collect(),
+		decision,
+		error,
+	};
+	audit.record(&record).await?;
+
+	Ok(decision)
+}
@@ -0,0 +1,174 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Independent rpc endpoints that state commitments are cross-checked against
+
+use serde::Serialize;
+use sp_core::H256;
+use std::{collections::HashMap, sync::Arc};
+
+use ismp::host::StateMachine;
+use tesseract_primitives::IsmpProvider;
+
+/// A client connected to a single rpc endpoint of a chain
+#[derive(Clone)]
+pub struct Witness {
+	/// Url of the rpc endpoint
+	pub endpoint: String,
+	pub client: Arc<dyn IsmpProvider>,
+}
+
+/// What an rpc endpoint reported for the height of a state commitment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Observation {
+	/// The endpoint reported the same state root as the state commitment
+	Agrees,
+	/// The endpoint reported a different state root
+	Conflicts { state_root: String },
+	/// The endpoint has synced past the height, but has no block at it. This may be a pruned
+	/// or misbehaving endpoint, so it is never enough to veto.
+	Missing,
+	/// The endpoint has not synced up to the height yet
+	Lagging,
+	/// The endpoint could not be queried
+	Unavailable { error: String },
+}
+
+/// Outcome of cross-checking a state commitment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+	/// Every endpoint that responded agrees with the state commitment
+	Accept,
+	/// Enough endpoints observed the same conflicting state root to veto the state commitment
+	Veto,
+	/// The endpoints disagree, or too few of them responded to reach a decision
+	Inconclusive,
+}
+
+impl Witness {
+	/// Compare the state root of the chain at `height` reported by this endpoint with
+	/// `state_root`
+	pub async fn observe(
+		&self,
+		coprocessor: StateMachine,
+		height: u64,
+		state_root: H256,
+	) -> Observation {
+		match self.client.query_state_root(coprocessor, height).await {
+			Ok(Some(observed)) if observed == state_root => Observation::Agrees,
+			Ok(Some(observed)) => Observation::Conflicts { state_root: format!("{observed:?}") },
+			// A lagging endpoint has no block at the height either, which is not a conflict
+			Ok(None) => match self.client.query_finalized_height().await {
+				Ok(latest) if latest >= height => Observation::Missing,
+				Ok(_) => Observation::Lagging,
+				Err(err) => Observation::Unavailable { error: format!("{err:?}") },
+			},
+			Err(err) => Observation::Unavailable { error: format!("{err:?}") },
+		}
+	}
+}
+
+/// A state commitment is vetoed once at least `quorum` endpoints observe the same conflicting
+/// state root and they outnumber the endpoints that agree with it, regardless of endpoints that
+/// report other conflicting roots. Otherwise, any conflicting root or missing block makes the
+/// decision inconclusive, and it is only accepted once an endpoint agrees with it. Lagging and
+/// unavailable endpoints are ignored.
+pub fn decide(observations: &[Observation], quorum: usize) -> Decision {
+	let mut conflicts = HashMap::<&str, usize>::new();
+	for observation in observations {
+		if let Observation::Conflicts { state_root } = observation {
+			*conflicts.entry(state_root.as_str()).or_default() += 1;
+		}
+	}
+	let conflicting = conflicts.values().copied().max().unwrap_or_default();
+	let agrees = observations
+		.iter()
+		.filter(|observation| **observation == Observation::Agrees)
+		.count();
+	let missing = observations.iter().any(|observation| *observation == Observation::Missing);
+
+	if conflicting >= quorum && conflicting > agrees {
+		Decision::Veto
+	} else if conflicts.is_empty() && !missing && agrees > 0 {
+		Decision::Accept
+	} else {
+		Decision::Inconclusive
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn conflicts() -> Observation {
+		conflicts_with(1)
+	}
+
+	fn conflicts_with(byte: u8) -> Observation {
+		Observation::Conflicts { state_root: format!("{:?}", H256::repeat_byte(byte)) }
+	}
+
+	#[test]
+	fn single_endpoint_cannot_veto() {
+		let observations = vec![conflicts(), Observation::Lagging, Observation::Agrees];
+		assert_eq!(decide(&observations, 2), Decision::Inconclusive);
+
+		let observations = vec![Observation::Missing, Observation::Lagging];
+		assert_eq!(decide(&observations, 2), Decision::Inconclusive);
+	}
+
+	#[test]
+	fn missing_blocks_are_inconclusive() {
+		let observations = vec![Observation::Missing, Observation::Missing, Observation::Missing];
+		assert_eq!(decide(&observations, 2), Decision::Inconclusive);
+
+		// missing blocks don't count towards the quorum, nor are they an agreement
+		let observations = vec![conflicts(), Observation::Missing, Observation::Agrees];
+		assert_eq!(decide(&observations, 2), Decision::Inconclusive);
+	}
+
+	#[test]
+	fn quorum_must_agree_on_the_conflicting_root() {
+		let observations = vec![conflicts_with(1), conflicts_with(2), Observation::Agrees];
+		assert_eq!(decide(&observations, 2), Decision::Inconclusive);
+
+		let observations =
+			vec![conflicts_with(1), conflicts_with(2), conflicts_with(1), Observation::Agrees];
+		assert_eq!(decide(&observations, 2), Decision::Veto);
+	}
+
+	#[test]
+	fn quorum_of_conflicts_vetoes() {
+		let observations = vec![conflicts(), conflicts(), Observation::Agrees];
+		assert_eq!(decide(&observations, 2), Decision::Veto);
+
+		// conflicts must outnumber the endpoints that agree
+		let observations = vec![conflicts(), conflicts(), Observation::Agrees, Observation::Agrees];
+		assert_eq!(decide(&observations, 2), Decision::Inconclusive);
+	}
+
+	#[test]
+	fn agreement_accepts() {
+		let observations = vec![
+			Observation::Agrees,
+			Observation::Lagging,
+			Observation::Unavailable { error: "timeout".into() },
+		];
+		assert_eq!(decide(&observations, 2), Decision::Accept);
+		assert_eq!(decide(&[Observation::Lagging], 2), Decision::Inconclusive);
+	}
+}
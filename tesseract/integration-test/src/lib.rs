@@ -37,7 +37,7 @@ use subxt_utils::{
 };
 use tesseract::logging::setup as log_setup;
 use tesseract_messaging::relay;
-use tesseract_primitives::{config::RelayerConfig, IsmpProvider};
+use tesseract_primitives::{config::RelayerConfig, signer::SignerConfig, IsmpProvider};
 use tesseract_substrate::{
 	config::KeccakSubstrateChain, extrinsic::send_unsigned_extrinsic, SubstrateClient,
 	SubstrateConfig,
@@ -127,10 +127,12 @@ async fn create_clients(
 		hashing: None,
 		consensus_state_id: Some("PARA".to_string()),
 		rpc_ws: "ws://127.0.0.1:9990".to_string(), // url from local-testnet zombienet config
+		fallback_rpc_ws: None,
+		rpc_pool: None,
 		max_rpc_payload_size: None,
-		signer: Some(
+		signer: Some(SignerConfig::PrivateKey(
 			"0xe5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a".to_string(),
-		),
+		)),
 		latest_height: None,
 		max_concurent_queries: None,
 	};
@@ -140,10 +142,12 @@ async fn create_clients(
 		hashing: None,
 		consensus_state_id: Some("PARA".to_string()),
 		rpc_ws: "ws://127.0.0.1:9991".to_string(),
+		fallback_rpc_ws: None,
+		rpc_pool: None,
 		max_rpc_payload_size: None,
-		signer: Some(
+		signer: Some(SignerConfig::PrivateKey(
 			"0xe5be9a5092b81bca64be81d212e7f2f9eba183bb7a90954f7b76361f6edb5c0a".to_string(),
-		),
+		)),
 		latest_height: None,
 		max_concurent_queries: None,
 	};
@@ -253,11 +257,15 @@ async fn parachain_messaging() -> Result<(), anyhow::Error> {
 	relay(
 		chain_a_sub_client.clone(),
 		chain_b_client.clone(),
-		relayer_config.clone(),
+		tokio::sync::watch::channel(relayer_config.clone()).1,
 		StateMachine::Kusama(3000), // random coprocessor id
 		tx_payment,
-		client_map.clone(),
+		tokio::sync::watch::channel(client_map.clone()).1,
 		&task_manager,
+		None,
+		None,
+		Default::default(),
+		Default::default(),
 	)
 	.await?;
 	// =========================== Accounts & keys =====================================
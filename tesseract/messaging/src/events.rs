@@ -7,14 +7,19 @@ use ismp::{
 		Event as IsmpEvent, Meta, RequestResponseHandled, StateMachineUpdated, TimeoutHandled,
 	},
 	host::StateMachine,
-	messaging::{hash_request, hash_response, Message, Proof, RequestMessage, ResponseMessage},
-	router::{PostRequest, Request, RequestResponse, Response},
+	messaging::{
+		hash_batch, hash_request, hash_response, BatchMessage, Message, Proof, RequestMessage,
+		ResponseMessage,
+	},
+	router::{PostBatch, PostRequest, Request, RequestResponse, Response},
 };
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 use std::{collections::HashMap, sync::Arc};
 use tesseract_primitives::{config::RelayerConfig, Cost, Hasher, IsmpProvider, Query};
 use tokio_stream::StreamExt;
 
+use crate::policy::{message_metadata, BudgetReservation, GasBudgets};
+
 #[derive(Debug)]
 pub enum Event {
 	/// Emitted when a state machine is successfully updated to a new height after the challenge
@@ -40,6 +45,10 @@ pub enum Event {
 	GetRequestHandled(RequestResponseHandled),
 	/// Emitted when a get request timeout is handled
 	GetRequestTimeoutHandled(TimeoutHandled),
+	/// An event that is emitted when a post request batch is dispatched
+	PostBatch(Meta),
+	/// Emitted when a post request batch timeout is handled
+	PostBatchTimeoutHandled(TimeoutHandled),
 	/// State Commitment Vetoed
 	StateCommitmentVetoed,
 }
@@ -71,6 +80,8 @@ impl From<IsmpEvent> for Event {
 			IsmpEvent::GetRequestHandled(handled) => Event::GetRequestHandled(handled),
 			IsmpEvent::GetRequestTimeoutHandled(handled) =>
 				Event::GetRequestTimeoutHandled(handled),
+			IsmpEvent::PostBatch(batch) => Event::PostBatch((&batch).into()),
+			IsmpEvent::PostBatchTimeoutHandled(handled) => Event::PostBatchTimeoutHandled(handled),
 			IsmpEvent::StateCommitmentVetoed(_) => Event::StateCommitmentVetoed,
 		}
 	}
@@ -79,8 +90,9 @@ impl From<IsmpEvent> for Event {
 /// Translates events emitted from [`source`] into messages to be submitted to the counterparty
 /// The [`state_machine_height`] parameter is the latest available height of [`source`] on
 /// the counterparty chain
-/// Returns a tuple where the first item are messages to be submitted to the sink
-/// and the second tuple are currently unprofitable messages
+/// Returns a tuple where the first item are messages to be submitted to the sink,
+/// the second are currently unprofitable messages and the third are the estimates of the
+/// messages to be submitted, keyed by their commitment
 pub async fn translate_events_to_messages(
 	source: Arc<dyn IsmpProvider>,
 	sink: Arc<dyn IsmpProvider>,
@@ -89,15 +101,19 @@ pub async fn translate_events_to_messages(
 	config: RelayerConfig,
 	coprocessor: StateMachine,
 	client_map: &HashMap<StateMachine, Arc<dyn IsmpProvider>>,
-) -> Result<(Vec<Message>, Vec<Message>), anyhow::Error> {
+	budgets: &GasBudgets,
+) -> Result<(Vec<Message>, Vec<Message>, HashMap<H256, Estimate>), anyhow::Error> {
 	let mut post_request_queries = vec![];
 	let mut response_queries = vec![];
+	let mut batch_queries = vec![];
 
 	let mut post_requests = vec![];
 	let mut post_responses = vec![];
+	let mut post_batches = vec![];
 
 	let mut request_messages = vec![];
 	let mut response_messages = vec![];
+	let mut batch_messages = vec![];
 
 	let counterparty_timestamp = sink.query_timestamp().await?;
 
@@ -206,6 +222,58 @@ pub async fn translate_events_to_messages(
 							};
 							Ok(Some((Message::Response(_msg), query)))
 						},
+						IsmpEvent::PostBatch(batch) => {
+							// Request batches can only be delivered to substrate based hosts
+							if sink.state_machine_id().state_id.is_evm() {
+								tracing::trace!(
+									"Skipping request batch {} to an EVM host",
+									batch.nonce
+								);
+								return Ok(None);
+							}
+
+							// Skip timed out batches
+							if batch.timeout_timestamp != 0 &&
+								batch.timeout_timestamp <= counterparty_timestamp.as_secs()
+							{
+								tracing::trace!(
+									"Found timed out request batch, batch: {}, counterparty: {}",
+									batch.timeout_timestamp,
+									counterparty_timestamp.as_secs()
+								);
+								return Ok(None);
+							}
+
+							if !is_allowed_module(&config, &batch.from) {
+								tracing::trace!(
+									"Request batch from module {}, filtered by module filter",
+									hex::encode(&batch.from),
+								);
+								return Ok(None);
+							}
+
+							let query = Query {
+								source_chain: batch.source,
+								dest_chain: batch.dest,
+								nonce: batch.nonce,
+								commitment: hash_batch::<Hasher>(&batch),
+							};
+
+							let proof = source
+								.query_requests_proof(
+									state_machine_height.height,
+									vec![query],
+									sink.state_machine_id().state_id,
+								)
+								.await?;
+
+							let _msg = BatchMessage {
+								batches: vec![batch.clone()],
+								proof: Proof { height: state_machine_height, proof },
+								signer: sink.address(),
+							};
+							Ok(Some((Message::Batch(_msg), query)))
+						},
 						_ => Ok(None),
 					}
 				}
@@ -239,12 +307,42 @@ pub async fn translate_events_to_messages(
 					post_responses.push(response);
 					response_messages.push(Message::Response(resp_msg))
 				},
+				Message::Batch(batch_msg) => {
+					batch_queries.push(query);
+					post_batches.push(
+						batch_msg
+							.batches
+							.get(0)
+							.cloned()
+							.ok_or_else(|| anyhow!("Expected a batch to be present"))?,
+					);
+					batch_messages.push(Message::Batch(batch_msg))
+				},
 				_ => Err(anyhow!("Unexpected message: {msg:?}"))?,
 			}
 		}
 	}
 
 	let mut unprofitable = vec![];
+	let mut estimates = HashMap::new();
+
+	let batch_profitability = return_successful_queries(
+		sink.clone(),
+		batch_messages,
+		batch_queries,
+		&config,
+		coprocessor,
+		&client_map,
+		budgets,
+	)
+	.await?;
+	unprofitable.extend(batch_profitability.retriable_messages);
+	estimates.extend(batch_profitability.estimates);
+	let (post_batches, batch_queries): (Vec<_>, Vec<_>) = post_batches
+		.into_iter()
+		.zip(batch_profitability.queries)
+		.filter_map(|(batch, query)| Some((batch, query?)))
+		.unzip();
 
 	let (post_requests, post_request_queries, post_responses, response_queries) = {
 		if !request_messages.is_empty() || !response_messages.is_empty() {
@@ -255,18 +353,27 @@ pub async fn translate_events_to_messages(
 			);
 		}
 
-		let post_request_queries_to_push_with_option = return_successful_queries(
+		// The gas reserved for the messages checked so far is refunded if any check fails
+		let post_request_queries_to_push_with_option = match return_successful_queries(
 			sink.clone(),
 			request_messages,
 			post_request_queries,
-			config.minimum_profit_percentage,
+			&config,
 			coprocessor,
 			&client_map,
-			config.deliver_failed.unwrap_or_default(),
+			budgets,
 		)
-		.await?;
+		.await
+		{
+			Ok(result) => result,
+			Err(err) => {
+				budgets.refund_undelivered(&[], &estimates).await;
+				Err(err)?
+			},
+		};
 
 		unprofitable.extend(post_request_queries_to_push_with_option.retriable_messages);
+		estimates.extend(post_request_queries_to_push_with_option.estimates);
 
 		let post_request_to_push: Vec<PostRequest> = post_requests
 			.into_iter()
@@ -288,18 +395,26 @@ pub async fn translate_events_to_messages(
 			.filter_map(|query| query)
 			.collect();
 
-		let post_response_successful_query = return_successful_queries(
+		let post_response_successful_query = match return_successful_queries(
 			sink.clone(),
 			response_messages,
 			response_queries,
-			config.minimum_profit_percentage,
+			&config,
 			coprocessor,
 			&client_map,
-			config.deliver_failed.unwrap_or_default(),
+			budgets,
 		)
-		.await?;
+		.await
+		{
+			Ok(result) => result,
+			Err(err) => {
+				budgets.refund_undelivered(&[], &estimates).await;
+				Err(err)?
+			},
+		};
 
 		unprofitable.extend(post_response_successful_query.retriable_messages);
+		estimates.extend(post_response_successful_query.estimates);
 
 		let post_response_to_push: Vec<Response> = post_responses
 			.into_iter()
@@ -374,7 +489,47 @@ pub async fn translate_events_to_messages(
 		}
 	}
 
-	Ok((messages, unprofitable))
+	messages.extend(
+		batch_messages_with_proof(source, sink, state_machine_height, post_batches, batch_queries)
+			.await?,
+	);
+
+	Ok((messages, unprofitable, estimates))
+}
+
+/// Queries a proof for the given request batches in chunks and returns the batch messages to be
+/// submitted to the sink
+pub async fn batch_messages_with_proof(
+	source: Arc<dyn IsmpProvider>,
+	sink: Arc<dyn IsmpProvider>,
+	state_machine_height: StateMachineHeight,
+	batches: Vec<PostBatch>,
+	queries: Vec<Query>,
+) -> Result<Vec<Message>, anyhow::Error> {
+	let mut messages = vec![];
+	if queries.is_empty() {
+		return Ok(messages);
+	}
+
+	tracing::trace!("Querying request batch proof for batch length {}", queries.len());
+	let chunks = chunk_size(sink.state_machine_id().state_id);
+	for (queries, batches) in queries.chunks(chunks).zip(batches.chunks(chunks)) {
+		let proof = source
+			.query_requests_proof(
+				state_machine_height.height,
+				queries.to_vec(),
+				sink.state_machine_id().state_id,
+			)
+			.await?;
+		let msg = BatchMessage {
+			batches: batches.to_vec(),
+			proof: Proof { height: state_machine_height, proof },
+			signer: sink.address(),
+		};
+		messages.push(Message::Batch(msg));
+	}
+
+	Ok(messages)
 }
 
 /// Return true for Request and Response events designated for the counterparty
@@ -387,6 +542,18 @@ pub fn filter_events(
 	// Is the counterparty the routing chain?
 	let is_router = router_id == counterparty;
 
+	let denied = match ev {
+		IsmpEvent::PostRequest(post) => config.denies(post.source, post.dest, &post.from),
+		IsmpEvent::PostBatch(batch) => config.denies(batch.source, batch.dest, &batch.from),
+		IsmpEvent::PostResponse(resp) =>
+			config.denies(resp.source_chain(), resp.dest_chain(), &resp.source_module()),
+		_ => false,
+	};
+	if denied {
+		tracing::trace!("Skipping message from a sender denied by the relay policies: {ev:?}");
+		return false;
+	}
+
 	let allow_module = |module: &[u8]| {
 		config.module_filter.as_ref().is_some_and(|inner| !inner.is_empty()) &&
 			is_allowed_module(config, module)
@@ -399,6 +566,11 @@ pub fn filter_events(
 				(post.source != router_id ||
 					(post.source == router_id && allow_module(&post.from)))) ||
 				is_router,
+		IsmpEvent::PostBatch(batch) =>
+			(batch.dest == counterparty &&
+				(batch.source != router_id ||
+					(batch.source == router_id && allow_module(&batch.from)))) ||
+				is_router,
 		IsmpEvent::PostResponse(resp) =>
 			(resp.dest_chain() == counterparty &&
 				(resp.source_chain() != router_id ||
@@ -416,20 +588,33 @@ pub fn chunk_size(state_machine: StateMachine) -> usize {
 	}
 }
 
+/// Estimated execution cost of a message and the fee provided for it
+#[derive(Clone, Debug, Default)]
+pub struct Estimate {
+	/// Estimated execution cost in usd
+	pub execution_cost: Cost,
+	/// Relayer fee in usd
+	pub fee: Cost,
+	/// Gas reserved for the message from the daily budget of its relay policy
+	pub budget: Option<BudgetReservation>,
+}
+
 #[derive(Default)]
 pub struct ProfitabilityResult {
 	pub queries: Vec<Option<Query>>,
 	pub retriable_messages: Vec<Message>,
+	/// Estimates of the messages whose fees were checked, keyed by their commitment
+	pub estimates: HashMap<H256, Estimate>,
 }
 
 pub async fn return_successful_queries(
 	sink: Arc<dyn IsmpProvider>,
 	messages: Vec<Message>,
 	queries: Vec<Query>,
-	minimum_profit_percentage: u32,
+	config: &RelayerConfig,
 	coprocessor: StateMachine,
 	client_map: &HashMap<StateMachine, Arc<dyn IsmpProvider>>,
-	deliver_failed: bool,
+	budgets: &GasBudgets,
 ) -> Result<ProfitabilityResult, anyhow::Error> {
 	if messages.is_empty() {
 		return Ok(Default::default());
@@ -437,7 +622,20 @@ pub async fn return_successful_queries(
 
 	let mut queries_to_be_relayed = Vec::new();
 	let mut retriable_messages = Vec::new();
+	let mut estimates = HashMap::new();
 	let gas_estimates = sink.estimate_gas(messages.clone()).await?;
+	let deliver_failed = config.deliver_failed.unwrap_or_default();
+	// The timestamp is only needed to relay messages that will time out soon at a loss
+	let counterparty_timestamp = if config
+		.policies
+		.iter()
+		.flatten()
+		.any(|policy| policy.relay_expiring_within.is_some())
+	{
+		sink.query_timestamp().await?.as_secs()
+	} else {
+		0
+	};
 
 	// We'll be querying from possibly multiple chains, Let's use the lowest tracing batch size
 	// from all clients(except the coprocessor) and use that as the max concurrency
@@ -465,7 +663,7 @@ pub async fn return_successful_queries(
 						tracing::info!("Skipping Failed tx");
 						// if msg has not been delivered return the message as retriable
 						let relayer = match &msg {
-							Message::Request(_) => {
+							Message::Request(_) | Message::Batch(_) => {
 								sink.query_request_receipt(query.commitment).await?
 							}
 							Message::Response(_) => {
@@ -475,50 +673,76 @@ pub async fn return_successful_queries(
 						};
 
 						if relayer == H160::zero().0.to_vec() && coprocessor != sink.state_machine_id().state_id {
-							return Ok((None, Some(msg)))
+							return Ok((None, Some(msg), None))
 						} else {
-							return Ok((None, None))
+							return Ok((None, None, None))
 						}
 					}
 
 					let value = if coprocessor != sink.state_machine_id().state_id {
 						let total_gas_to_be_expended_in_usd = est.execution_cost;
+						let (module, timeout) = message_metadata(&msg).unwrap_or_default();
+						let policy = config.policy(query.source_chain, query.dest_chain, &module);
+						if let Some(maximum_gas) = policy.and_then(|policy| policy.maximum_gas) {
+							if est.gas > U256::from(maximum_gas) {
+								tracing::info!("Skipping tx that exceeds the maximum gas of its relay policy. Expected at most {maximum_gas}, estimated {}", est.gas);
+								return Ok((None, None, None))
+							}
+						}
+
 						// what kind of message is this?
 						let Some(og_source)  = client_map.get(&query.source_chain) else {
 							tracing::info!("Skipping tx because fee metadata cannot be queried, client for {:?} was not provided", query.source_chain);
-							return Ok((None, None))
+							return Ok((None, None, None))
 						};
 
-						let mut fee_metadata: Cost = match msg {
-							Message::Request(_) => og_source.query_request_fee_metadata(query.commitment).await?.into(),
+						let fee_metadata: Cost = match msg {
+							Message::Request(_) | Message::Batch(_) => og_source.query_request_fee_metadata(query.commitment).await?.into(),
 							Message::Response(_) => og_source.query_response_fee_metadata(query.commitment).await?.into(),
 							_ => Err(anyhow!("Unexpected message: {msg:?}"))?
 						};
 
+						let estimate = Estimate { execution_cost: total_gas_to_be_expended_in_usd, fee: fee_metadata, budget: None };
+						let minimum_profit_percentage = policy
+							.and_then(|policy| policy.minimum_profit_percentage)
+							.unwrap_or(config.minimum_profit_percentage);
+						let minimum_fee = policy
+							.and_then(|policy| policy.minimum_fee)
+							.map(Cost::from_usd)
+							.unwrap_or_default();
 						let profit = (U256::from(minimum_profit_percentage) *
 							total_gas_to_be_expended_in_usd.0) /
 							U256::from(100);
 						// 0 profit percentage means we want to relay all requests for free
-						let fee_with_profit: Cost = total_gas_to_be_expended_in_usd + profit;
-						if minimum_profit_percentage == 0 {
-							fee_metadata = U256::MAX.into()
+						let fee_with_profit: Cost = if minimum_profit_percentage == 0 {
+							minimum_fee
+						} else {
+							std::cmp::max(total_gas_to_be_expended_in_usd + profit, minimum_fee)
 						};
+						let relay_at_loss = policy
+							.is_some_and(|policy| policy.relay_at_loss(timeout, counterparty_timestamp));
 
-						if fee_metadata < fee_with_profit {
+						if fee_metadata < fee_with_profit && !relay_at_loss {
 							tracing::info!("Skipping unprofitable tx. Expected ${fee_with_profit}, user provided ${fee_metadata}");
-							(None, Some(msg))
-						} else {
+							(None, Some(msg), Some((query.commitment, estimate)))
+						} else if let Some(reservation) = budgets.reserve(policy, total_gas_to_be_expended_in_usd).await {
+							if fee_metadata < fee_with_profit {
+								tracing::info!("Relaying tx that times out soon at a loss. Expected ${fee_with_profit}, user provided ${fee_metadata}");
+							}
 							tracing::trace!(
 								"Pushing tx to {:?} with cost ${fee_with_profit} and profit: ${}",
 									sink.state_machine_id().state_id, Cost(profit)
 							);
-							(Some(query), None)
+							(Some(query), None, Some((query.commitment, Estimate { budget: Some(reservation), ..estimate })))
+						} else {
+							tracing::info!("Skipping tx, the daily gas budget of its relay policy has been exhausted");
+							(None, Some(msg), Some((query.commitment, estimate)))
 						}
 
 					} else {
 						// We only deliver sucessful messages to hyperbridge
 						tracing::trace!("Pushing tx to {:?}", sink.state_machine_id().state_id);
-						(Some(query), None)
+						(Some(query), None, None)
 					};
 
 					return Ok::<_, anyhow::Error>(value)
@@ -526,17 +750,33 @@ pub async fn return_successful_queries(
 			})
 			.collect::<FuturesOrdered<_>>();
 
-		let results = processes.collect::<Result<Vec<_>, _>>().await?;
+		let results = processes.collect::<Vec<_>>().await;
 
-		for (query, unprofitable_msg) in results {
+		let mut error = None;
+		for result in results {
+			let (query, unprofitable_msg, estimate) = match result {
+				Ok(result) => result,
+				Err(err) => {
+					error = Some(err);
+					continue;
+				},
+			};
+			if let Some((commitment, estimate)) = estimate {
+				estimates.insert(commitment, estimate);
+			}
 			queries_to_be_relayed.push(query);
 			if let Some(msg) = unprofitable_msg {
 				retriable_messages.push(msg);
 			}
 		}
+		// None of the messages are delivered, so the gas reserved for them is refunded
+		if let Some(err) = error {
+			budgets.refund_undelivered(&[], &estimates).await;
+			Err(err)?
+		}
 	}
 
-	Ok(ProfitabilityResult { queries: queries_to_be_relayed, retriable_messages })
+	Ok(ProfitabilityResult { queries: queries_to_be_relayed, retriable_messages, estimates })
 }
 
 fn is_allowed_module(config: &RelayerConfig, module: &[u8]) -> bool {
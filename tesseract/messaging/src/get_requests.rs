@@ -4,7 +4,6 @@ use anyhow::anyhow;
 use futures::StreamExt;
 use ismp::{
 	consensus::StateMachineHeight,
-	host::StateMachine,
 	messaging::{hash_request, Proof},
 	router::{GetRequest, Request},
 };
@@ -15,18 +14,21 @@ use tesseract_primitives::{
 };
 use tokio::sync::mpsc::Receiver;
 
+use crate::ClientMap;
+
 pub async fn process_get_request_events<
 	A: IsmpProvider + HandleGetResponse + Clone + Clone + 'static,
 >(
 	mut receiver: Receiver<(Vec<GetRequest>, StateMachineUpdated)>,
 	source: Arc<dyn IsmpProvider>,
 	hyperbridge: A,
-	client_map: HashMap<StateMachine, Arc<dyn IsmpProvider>>,
+	client_map: ClientMap,
 ) -> Result<(), anyhow::Error> {
 	while let Some((get_requests, state_machine_update)) = receiver.recv().await {
 		if get_requests.is_empty() {
 			continue;
 		}
+		let client_map = client_map.borrow().clone();
 
 		tracing::info!(target: "tesseract", "Got {} get_requests from {}", get_requests.len(), state_machine_update.state_machine_id.state_id);
 
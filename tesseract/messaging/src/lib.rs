@@ -17,39 +17,62 @@
 
 mod events;
 mod get_requests;
+pub mod metrics;
+pub mod policy;
 mod retries;
+pub mod simulation;
 
 use anyhow::anyhow;
 use get_requests::process_get_request_events;
 use itertools::Itertools;
 use sc_service::TaskManager;
+use sp_core::H256;
 use std::{collections::HashMap, sync::Arc};
-use tokio::sync::mpsc::{Receiver, Sender};
+use tokio::sync::{
+	mpsc::{Receiver, Sender},
+	watch, RwLock,
+};
 
 use crate::{
-	events::{filter_events, translate_events_to_messages},
+	events::{filter_events, translate_events_to_messages, Estimate},
+	metrics::{to_usd, Metrics},
+	policy::GasBudgets,
 	retries::retry_unprofitable_messages,
+	simulation::DryRun,
 };
 use futures::{FutureExt, StreamExt};
 use ismp::{consensus::StateMachineHeight, events::Event, host::StateMachine, router::GetRequest};
 
 use tesseract_primitives::{
-	config::RelayerConfig, observe_challenge_period, wait_for_state_machine_update,
+	config::RelayerConfig, observe_challenge_period, wait_for_state_machine_update, Cost,
 	HandleGetResponse, HyperbridgeClaim, IsmpProvider, StateMachineUpdated, TxReceipt,
 };
-use transaction_fees::TransactionPayment;
+use transaction_fees::{Checkpoint, LedgerEntry, TransactionPayment};
 
 type FeeAccSender = Sender<Vec<TxReceipt>>;
 type GetReqSender = Sender<(Vec<GetRequest>, StateMachineUpdated)>;
 
+/// Held for reading by the messaging tasks of a route while they deliver messages, and for
+/// writing to stop the route. A route that is stopped while holding it for writing is never
+/// interrupted between submitting messages and storing their receipts for fee accumulation.
+pub type DeliveryLock = Arc<RwLock<()>>;
+
+/// The clients of every configured chain. Messaging tasks read the latest clients for each
+/// update, so a route keeps running when the config of another chain is reloaded.
+pub type ClientMap = watch::Receiver<HashMap<StateMachine, Arc<dyn IsmpProvider>>>;
+
 pub async fn relay<A>(
 	hyperbridge: A,
 	chain_b: Arc<dyn IsmpProvider>,
-	config: RelayerConfig,
+	config: watch::Receiver<RelayerConfig>,
 	coprocessor: StateMachine,
 	tx_payment: Arc<TransactionPayment>,
-	client_map: HashMap<StateMachine, Arc<dyn IsmpProvider>>,
+	client_map: ClientMap,
 	task_manager: &TaskManager,
+	metrics: Option<Metrics>,
+	dry_run: Option<DryRun>,
+	budgets: GasBudgets,
+	delivery_lock: DeliveryLock,
 ) -> Result<(), anyhow::Error>
 where
 	A: IsmpProvider + Clone + HyperbridgeClaim + HandleGetResponse + 'static,
@@ -63,7 +86,12 @@ where
 		let client_map = client_map.clone();
 		let tx_payment = tx_payment.clone();
 		let config = config.clone();
-		let get_request_sender = get_request_sender.clone();
+		// Get requests are answered by submitting responses to hyperbridge
+		let get_request_sender = dry_run.is_none().then(|| get_request_sender.clone());
+		let metrics = metrics.clone();
+		let dry_run = dry_run.clone();
+		let budgets = budgets.clone();
+		let delivery_lock = delivery_lock.clone();
 		let name = format!("messaging-{}-{}", hyperbridge.name(), chain_b.name());
 		task_manager.spawn_essential_handle().spawn_blocking(
 			Box::leak(Box::new(name.clone())),
@@ -77,7 +105,11 @@ where
 					coprocessor,
 					client_map,
 					None,
-					Some(get_request_sender),
+					get_request_sender,
+					metrics,
+					dry_run,
+					budgets,
+					delivery_lock,
 				)
 				.await;
 				tracing::error!(target: "tesseract", "{name} has terminated with result {res:?}")
@@ -93,6 +125,10 @@ where
 		let tx_payment = tx_payment.clone();
 		let config = config.clone();
 		let sender = sender.clone();
+		let metrics = metrics.clone();
+		let dry_run = dry_run.clone();
+		let budgets = budgets.clone();
+		let delivery_lock = delivery_lock.clone();
 		let name = format!("messaging-{}-{}", chain_b.name(), hyperbridge.name());
 		task_manager.spawn_essential_handle().spawn_blocking(
 			Box::leak(Box::new(name.clone())),
@@ -107,6 +143,10 @@ where
 					client_map,
 					Some(sender),
 					None,
+					metrics,
+					dry_run,
+					budgets,
+					delivery_lock,
 				)
 				.await;
 				tracing::error!(target: "tesseract", "{name} has terminated with result {res:?}")
@@ -115,19 +155,26 @@ where
 		);
 	}
 
+	// Nothing is delivered in a dry run, so there are no fees to accumulate or messages to retry
+	if dry_run.is_some() {
+		return Ok(());
+	}
+
 	// Fee accumulation background task
 	{
 		let hyperbridge = hyperbridge.clone();
 		let dest = chain_b.clone();
 		let client_map = client_map.clone();
 		let tx_payment = tx_payment.clone();
+		let metrics = metrics.clone();
 		let name = format!("fee-acc-{}-{}", dest.name(), hyperbridge.name());
 		task_manager.spawn_essential_handle().spawn_blocking(
 			Box::leak(Box::new(name.clone())),
 			"fees",
 			async move {
 				let res =
-					fee_accumulation(receiver, dest, hyperbridge, client_map, tx_payment).await;
+					fee_accumulation(receiver, dest, hyperbridge, client_map, tx_payment, metrics)
+						.await;
 				tracing::error!("{name} terminated with result {res:?}");
 			}
 			.boxed(),
@@ -136,13 +183,16 @@ where
 
 	{
 		// Spawn retries for unprofitable messages
-		if config.unprofitable_retry_frequency.is_some() {
+		if config.borrow().unprofitable_retry_frequency.is_some() {
 			let hyperbridge = Arc::new(hyperbridge.clone());
 			let dest = chain_b.clone();
 			let client_map = client_map.clone();
 			let tx_payment = tx_payment.clone();
 			let config = config.clone();
 			let sender = sender.clone();
+			let metrics = metrics.clone();
+			let budgets = budgets.clone();
+			let delivery_lock = delivery_lock.clone();
 			let name = format!("retries-{}-{}", dest.name(), hyperbridge.name());
 			task_manager.spawn_essential_handle().spawn_blocking(
 				Box::leak(Box::new(name.clone())),
@@ -156,6 +206,9 @@ where
 						config,
 						coprocessor,
 						sender,
+						metrics,
+						budgets,
+						delivery_lock,
 					)
 					.await;
 					tracing::error!("{name} terminated with result {res:?}");
@@ -195,33 +248,65 @@ async fn handle_notification(
 	chain_a: Arc<dyn IsmpProvider>,
 	chain_b: Arc<dyn IsmpProvider>,
 	tx_payment: Arc<TransactionPayment>,
-	config: RelayerConfig,
+	config: watch::Receiver<RelayerConfig>,
 	coprocessor: StateMachine,
-	client_map: HashMap<StateMachine, Arc<dyn IsmpProvider>>,
+	client_map: ClientMap,
 	fee_acc_sender: Option<FeeAccSender>,
 	get_request_sender: Option<GetReqSender>,
+	metrics: Option<Metrics>,
+	dry_run: Option<DryRun>,
+	budgets: GasBudgets,
+	delivery_lock: DeliveryLock,
 ) -> Result<(), anyhow::Error> {
 	let mut state_machine_update_stream = chain_a
 		.state_machine_update_notification(chain_b.state_machine_id())
 		.await
 		.map_err(|err| anyhow!("StateMachineUpdated stream subscription failed: {err:?}"))?;
 
-	let mut previous_height = chain_b.initial_height();
+	let source = chain_b.state_machine_id().state_id;
+	let dest = chain_a.state_machine_id().state_id;
+	// Resume from the last height scanned before the relayer was restarted
+	let mut previous_height = match tx_payment.checkpoint(source, dest).await {
+		Ok(Some(checkpoint)) => {
+			tracing::info!(
+				target: "tesseract",
+				"Resuming {}->{} from checkpoint at height {}, the last update handled was at height {}",
+				chain_b.name(), chain_a.name(), checkpoint.scanned_height, checkpoint.update_height
+			);
+			checkpoint.scanned_height
+		},
+		Ok(None) => chain_b.initial_height(),
+		Err(err) => {
+			tracing::error!(
+				"Failed to fetch the checkpoint for {}->{}: {err:?}",
+				chain_b.name(),
+				chain_a.name()
+			);
+			chain_b.initial_height()
+		},
+	};
 
 	while let Some(item) = state_machine_update_stream.next().await {
 		match item {
 			Ok(state_machine_update) => {
+				let _delivery = delivery_lock.read().await;
+				// Use the latest config, it may have been reloaded since the last update
+				let config = config.borrow().clone();
+				let client_map = client_map.borrow().clone();
 				if let Err(err) = handle_update(
 					chain_a.clone(),
 					chain_b.clone(),
 					tx_payment.clone(),
 					state_machine_update.clone(),
 					&mut previous_height,
-					config.clone(),
+					config,
 					coprocessor,
 					&client_map,
 					fee_acc_sender.clone(),
 					get_request_sender.clone(),
+					metrics.as_ref(),
+					dry_run.as_ref(),
+					&budgets,
 				)
 				.await
 				{
@@ -230,6 +315,18 @@ async fn handle_notification(
 						state_machine_update.state_machine_id.state_id,
 						chain_a.name()
 					);
+				} else if dry_run.is_none() {
+					let checkpoint = Checkpoint {
+						update_height: state_machine_update.latest_height,
+						scanned_height: previous_height,
+					};
+					if let Err(err) = tx_payment.store_checkpoint(source, dest, checkpoint).await {
+						tracing::error!(
+							"Failed to persist the checkpoint for {}->{}: {err:?}",
+							chain_b.name(),
+							chain_a.name()
+						)
+					}
 				}
 			},
 			Err(e) => {
@@ -257,45 +354,53 @@ async fn handle_update(
 	client_map: &HashMap<StateMachine, Arc<dyn IsmpProvider>>,
 	fee_acc_sender: Option<FeeAccSender>,
 	get_request_sender: Option<GetReqSender>,
+	metrics: Option<&Metrics>,
+	dry_run: Option<&DryRun>,
+	budgets: &GasBudgets,
 ) -> Result<(), anyhow::Error> {
 	// Chain B's state machine has been updated to a new height on chain A
 	// We query all the events that have been emitted on chain B that can be submitted to
-	// chain A filter events list to contain only Request and Response events
-	let result = chain_b.query_ismp_events(*previous_height, state_machine_update.clone()).await;
-
-	let events = match result {
-		Ok(events) => {
-			if let Some(sender) = get_request_sender {
-				let get_requests = events
-					.clone()
-					.into_iter()
-					.filter_map(|e| match e {
-						Event::GetRequest(req) => Some(req),
-						_ => None,
-					})
-					.collect::<Vec<_>>();
-				if !get_requests.is_empty() {
-					let _ = sender.send((get_requests, state_machine_update.clone())).await;
-				}
-			}
+	// chain A filter events list to contain only Request and Response events.
+	// The previous height is only advanced once the events have been handled, so that they are
+	// queried again with the next update if anything fails
+	let events = chain_b
+		.query_ismp_events(*previous_height, state_machine_update.clone())
+		.await
+		.map_err(|err| anyhow!("Error querying events from {}: {err:?}", chain_b.name()))?;
+
+	if let Some(sender) = get_request_sender {
+		let get_requests = events
+			.clone()
+			.into_iter()
+			.filter_map(|e| match e {
+				Event::GetRequest(req) => Some(req),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		if !get_requests.is_empty() {
+			let _ = sender.send((get_requests, state_machine_update.clone())).await;
+		}
+	}
 
-			events
-				.into_iter()
-				.filter(|ev| {
-					filter_events(&config, coprocessor, chain_a.state_machine_id().state_id, ev)
-				})
-				.collect::<Vec<_>>()
-		},
-		Err(err) => {
-			tracing::error!(
-				"Encountered an error querying events from {}: {err:?}",
-				chain_b.name()
-			);
-			Default::default()
-		},
-	};
+	let events = events
+		.into_iter()
+		.filter(|ev| filter_events(&config, coprocessor, chain_a.state_machine_id().state_id, ev))
+		.collect::<Vec<_>>();
 
 	let state_machine = state_machine_update.state_machine_id.state_id;
+	if let Some(metrics) = metrics {
+		match chain_b.query_finalized_height().await {
+			Ok(finalized) => metrics
+				.state_machine_update_lag
+				.with_label_values(&[&chain_b.name(), &chain_a.name()])
+				.set(finalized.saturating_sub(state_machine_update.latest_height)),
+			Err(err) => tracing::trace!(
+				"Failed to query the finalized height of {}: {err:?}",
+				chain_b.name()
+			),
+		}
+	}
+
 	if events.is_empty() {
 		tracing::info!(
 			"Skipping latest finalized height {} on {}, no new messages from {state_machine} in range {:?}",
@@ -306,12 +411,11 @@ async fn handle_update(
 		*previous_height = state_machine_update.latest_height;
 		return Ok(());
 	}
-	// Advance latest known height by relayer
-	*previous_height = state_machine_update.latest_height;
 	let log_events = events
 		.iter()
 		.chunk_by(|event| match event {
 			ismp::events::Event::PostRequest(req) => req.dest,
+			ismp::events::Event::PostBatch(batch) => batch.dest,
 			ismp::events::Event::PostResponse(res) => res.dest_chain(),
 			event => {
 				unreachable!("Only application messages filtered; qed. Unexpected event: {event:?}")
@@ -327,7 +431,7 @@ async fn handle_update(
 		height: state_machine_update.latest_height,
 	};
 
-	let (messages, unprofitable) = translate_events_to_messages(
+	let (messages, unprofitable, estimates) = translate_events_to_messages(
 		chain_b.clone(),
 		chain_a.clone(),
 		events,
@@ -335,10 +439,24 @@ async fn handle_update(
 		config.clone(),
 		coprocessor,
 		&client_map,
+		budgets,
 	)
 	.await?;
 
-	if !messages.is_empty() {
+	if let Some(dry_run) = dry_run {
+		// Messages are only recorded, they are never submitted in a dry run, so they never spend
+		// the gas budgets
+		budgets.refund_undelivered(&[], &estimates).await;
+		if let Err(err) = dry_run
+			.record(&chain_b.name(), &chain_a.name(), &messages, &unprofitable, &estimates)
+			.await
+		{
+			tracing::error!(
+				"Failed to record the dry run of messages to {}: {err:?}",
+				chain_a.name()
+			)
+		}
+	} else if !messages.is_empty() {
 		tracing::info!(
 			target: "tesseract",
 			"🛰️ Transmitting ismp messages from {} to {}",
@@ -346,8 +464,14 @@ async fn handle_update(
 		);
 
 		let res = chain_a.submit(messages.clone()).await;
+		budgets
+			.refund_undelivered(res.as_deref().unwrap_or_default(), &estimates)
+			.await;
 		match res {
 			Ok(receipts) => {
+				if let Some(metrics) = metrics {
+					report_deliveries(metrics, &chain_b, &chain_a, &receipts, &estimates);
+				}
 				if let Some(sender) = fee_acc_sender {
 					// We should not store messages when they are delivered to hyperbridge
 					if chain_a.state_machine_id().state_id != coprocessor {
@@ -365,6 +489,7 @@ async fn handle_update(
 									receipts.len()
 								)
 							}
+							record_deliveries(&tx_payment, &receipts, &estimates).await;
 							// Send receipts to the fee accumulation task
 							match sender.send(receipts).await {
 								Err(_sent) => {
@@ -379,13 +504,29 @@ async fn handle_update(
 				}
 			},
 			Err(err) => {
-				tracing::error!("Failed to submit transaction to {}: {err:?}", chain_a.name())
+				if let Some(metrics) = metrics {
+					metrics
+						.submission_failures
+						.with_label_values(&[&chain_b.name(), &chain_a.name()])
+						.inc();
+				}
+				Err(anyhow!("Failed to submit transaction to {}: {err:?}", chain_a.name()))?
 			},
 		}
 	}
 
+	if let Some(metrics) = metrics {
+		metrics
+			.messages_unprofitable
+			.with_label_values(&[&chain_b.name(), &chain_a.name()])
+			.inc_by(unprofitable.len() as u64);
+	}
+
 	// Store currently unprofitable in messages in db
-	if !unprofitable.is_empty() && config.unprofitable_retry_frequency.is_some() {
+	if !unprofitable.is_empty() &&
+		config.unprofitable_retry_frequency.is_some() &&
+		dry_run.is_none()
+	{
 		tracing::trace!(target: "tesseract", "Persisting {} unprofitable messages going to {} to the db", unprofitable.len(), chain_a.name());
 		if let Err(err) = tx_payment
 			.store_unprofitable_messages(unprofitable, chain_a.state_machine_id().state_id)
@@ -397,6 +538,9 @@ async fn handle_update(
 		}
 	}
 
+	// Advance latest known height by relayer
+	*previous_height = state_machine_update.latest_height;
+
 	Ok(())
 }
 
@@ -404,13 +548,15 @@ async fn fee_accumulation<A: IsmpProvider + Clone + Clone + HyperbridgeClaim + '
 	mut receiver: Receiver<Vec<TxReceipt>>,
 	dest: Arc<dyn IsmpProvider>,
 	hyperbridge: A,
-	client_map: HashMap<StateMachine, Arc<dyn IsmpProvider>>,
+	client_map: ClientMap,
 	tx_payment: Arc<TransactionPayment>,
+	metrics: Option<Metrics>,
 ) -> Result<(), anyhow::Error> {
 	while let Some(receipts) = receiver.recv().await {
 		if receipts.is_empty() {
 			continue;
 		}
+		let client_map = client_map.borrow().clone();
 
 		let hyperbridge = Arc::new(hyperbridge.clone());
 
@@ -468,6 +614,7 @@ async fn fee_accumulation<A: IsmpProvider + Clone + Clone + HyperbridgeClaim + '
 					let source_chain = client_map.get(&source).cloned();
 					let dest = dest.clone();
 					let tx_payment = tx_payment.clone();
+					let metrics = metrics.clone();
 					async move {
 						let lambda = || async {
 							let source_chain = source_chain.ok_or_else(|| anyhow!("Client for {source} not found in config, fees cannot be accumulated"))?;
@@ -485,6 +632,9 @@ async fn fee_accumulation<A: IsmpProvider + Clone + Clone + HyperbridgeClaim + '
 								hyperbridge.accumulate_fees(proof).await?;
 							}
 							tracing::info!("Fee accumulation was sucessful");
+							if let Some(metrics) = metrics.as_ref() {
+								metrics.fees_accumulated.with_label_values(&[&dest.name()]).inc_by(receipts.len() as u64);
+							}
 							// If delete fails, not an issue, they'll be deleted whenever manual accumulation is triggered
 							let _ = tx_payment.delete_claimed_entries(commitments).await;
 							Ok::<_, anyhow::Error>(())
@@ -501,3 +651,160 @@ async fn fee_accumulation<A: IsmpProvider + Clone + Clone + HyperbridgeClaim + '
 	}
 	Ok::<_, anyhow::Error>(())
 }
+
+/// Report the messages delivered from `source` to `dest`, along with their cost and fees
+fn report_deliveries(
+	metrics: &Metrics,
+	source: &Arc<dyn IsmpProvider>,
+	dest: &Arc<dyn IsmpProvider>,
+	receipts: &[TxReceipt],
+	estimates: &HashMap<H256, Estimate>,
+) {
+	metrics
+		.messages_delivered
+		.with_label_values(&[&source.name(), &dest.name()])
+		.inc_by(receipts.len() as u64);
+	let (cost, fees) = receipts
+		.iter()
+		.filter_map(|receipt| estimates.get(&receipt.query().commitment))
+		.fold((Cost::default(), Cost::default()), |(cost, fees), estimate| {
+			(cost + estimate.execution_cost, fees + estimate.fee)
+		});
+	metrics.gas_spent.with_label_values(&[&dest.name()]).inc_by(to_usd(cost));
+	metrics.fees_earned.with_label_values(&[&dest.name()]).inc_by(to_usd(fees));
+}
+
+/// Record the deliveries in the profit and loss ledger, along with their estimated cost and fee
+async fn record_deliveries(
+	tx_payment: &TransactionPayment,
+	receipts: &[TxReceipt],
+	estimates: &HashMap<H256, Estimate>,
+) {
+	let entries = receipts
+		.iter()
+		.map(|receipt| {
+			let estimate = estimates.get(&receipt.query().commitment).cloned().unwrap_or_default();
+			LedgerEntry { receipt: *receipt, cost: estimate.execution_cost, fee: estimate.fee }
+		})
+		.collect();
+	if let Err(err) = tx_payment.record_deliveries(entries).await {
+		tracing::error!("Failed to record {} deliveries in the ledger: {err:?}", receipts.len())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{handle_notification, handle_update, policy::GasBudgets};
+	use ismp::{events::Event, host::StateMachine, router::PostRequest};
+	use sp_core::U256;
+	use std::{collections::HashMap, sync::Arc};
+	use tesseract_primitives::{
+		config::RelayerConfig, mocks::MockHost, Cost, IsmpProvider, StateMachineUpdated,
+	};
+	use tokio::sync::watch;
+	use transaction_fees::{Checkpoint, TransactionPayment};
+
+	async fn tx_payment(name: &str) -> Arc<TransactionPayment> {
+		let db = std::env::temp_dir().join(name);
+		let _ = std::fs::remove_file(&db);
+		Arc::new(TransactionPayment::initialize(db.to_str().unwrap()).await.unwrap())
+	}
+
+	#[tokio::test]
+	async fn should_resume_from_the_rewound_checkpoint() {
+		let tx_payment = tx_payment("tesseract-checkpoints.db").await;
+		let source = MockHost::new((), 200, StateMachine::Evm(97));
+		let queried_heights = source.queried_heights.clone();
+		let dest = MockHost::new((), 10, StateMachine::Evm(1));
+		let id = source.state_machine_id();
+		*dest.updates.lock().unwrap() = vec![
+			StateMachineUpdated { state_machine_id: id, latest_height: 150 },
+			StateMachineUpdated { state_machine_id: id, latest_height: 200 },
+		];
+
+		let checkpoint = Checkpoint { update_height: 100, scanned_height: 100 };
+		tx_payment.store_checkpoint(id.state_id, dest.state_machine, checkpoint).await.unwrap();
+		tx_payment.rewind_checkpoints(id.state_id, 40).await.unwrap();
+
+		let (_config, receiver) = watch::channel(RelayerConfig::default());
+		let (source, dest): (Arc<dyn IsmpProvider>, Arc<dyn IsmpProvider>) =
+			(Arc::new(source), Arc::new(dest));
+		// the task fails once the stream of updates ends
+		handle_notification(
+			dest.clone(),
+			source.clone(),
+			tx_payment.clone(),
+			receiver,
+			StateMachine::Kusama(4009),
+			Default::default(),
+			None,
+			None,
+			None,
+			None,
+			GasBudgets::default(),
+			Default::default(),
+		)
+		.await
+		.unwrap_err();
+
+		// events are scanned from the rewound checkpoint, which then follows the updates
+		assert_eq!(*queried_heights.lock().unwrap(), vec![60, 150]);
+		assert_eq!(
+			tx_payment
+				.checkpoint(id.state_id, dest.state_machine_id().state_id)
+				.await
+				.unwrap(),
+			Some(Checkpoint { update_height: 200, scanned_height: 200 })
+		);
+	}
+
+	#[tokio::test]
+	async fn should_not_advance_the_previous_height_when_submission_fails() {
+		let tx_payment = tx_payment("tesseract-failed-submission.db").await;
+		let mut source = MockHost::new((), 10, StateMachine::Evm(97));
+		source.fee = U256::from(2u128 * 10u128.pow(18));
+		let post = PostRequest {
+			source: StateMachine::Evm(97),
+			dest: StateMachine::Evm(1),
+			nonce: 0,
+			from: vec![1u8; 20],
+			to: vec![2u8; 20],
+			timeout_timestamp: 0,
+			body: vec![],
+		};
+		*source.events.lock().unwrap() = vec![Event::PostRequest(post)];
+		// submitting to the mock host fails
+		let mut dest = MockHost::new((), 10, StateMachine::Evm(1));
+		dest.execution_cost = Cost::from_usd(1.0);
+		let (source, dest): (Arc<dyn IsmpProvider>, Arc<dyn IsmpProvider>) =
+			(Arc::new(source), Arc::new(dest));
+		let client_map = HashMap::from([
+			(StateMachine::Evm(97), source.clone()),
+			(StateMachine::Evm(1), dest.clone()),
+		]);
+
+		let mut previous_height = 5;
+		let update =
+			StateMachineUpdated { state_machine_id: source.state_machine_id(), latest_height: 10 };
+		handle_update(
+			dest.clone(),
+			source.clone(),
+			tx_payment,
+			update,
+			&mut previous_height,
+			RelayerConfig::default(),
+			StateMachine::Kusama(4009),
+			&client_map,
+			None,
+			None,
+			None,
+			None,
+			&GasBudgets::default(),
+		)
+		.await
+		.unwrap_err();
+
+		// the events are queried again with the next update
+		assert_eq!(previous_height, 5);
+	}
+}
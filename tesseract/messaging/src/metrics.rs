@@ -0,0 +1,271 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prometheus metrics for the relayer tasks
+
+use ismp::host::StateMachine;
+use sp_core::U256;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use substrate_prometheus_endpoint::{
+	register, CounterVec, GaugeVec, Opts, PrometheusError, Registry, F64, U64,
+};
+use tesseract_primitives::{Cost, IsmpProvider};
+
+/// How often the health of the rpc endpoints is reported
+const RPC_REPORT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Metrics reported by the messaging, fee accumulation and withdrawal tasks.
+#[derive(Clone)]
+pub struct Metrics {
+	/// Messages delivered per route
+	pub messages_delivered: CounterVec<U64>,
+	/// Messages skipped for being unprofitable per route
+	pub messages_unprofitable: CounterVec<U64>,
+	/// Retries of previously unprofitable messages per route
+	pub message_retries: CounterVec<U64>,
+	/// Failed message submissions per route
+	pub submission_failures: CounterVec<U64>,
+	/// Estimated cost in usd of the messages delivered to a chain
+	pub gas_spent: CounterVec<F64>,
+	/// Relayer fees in usd of the messages delivered to a chain
+	pub fees_earned: CounterVec<F64>,
+	/// Messages whose fees were accumulated on hyperbridge per chain
+	pub fees_accumulated: CounterVec<U64>,
+	/// Number of blocks between the latest finalized height of a state machine and the latest
+	/// height of it committed on the counterparty
+	pub state_machine_update_lag: GaugeVec<U64>,
+	/// Error rate of each rpc endpoint of a chain
+	pub rpc_error_rate: GaugeVec<F64>,
+	/// Latency in milliseconds of each rpc endpoint of a chain
+	pub rpc_latency: GaugeVec<F64>,
+	/// Unclaimed fees in usd on hyperbridge that can be withdrawn to a chain
+	pub unclaimed_fees: GaugeVec<F64>,
+	/// Withdrawal requests that are yet to be delivered to a chain
+	pub pending_withdrawals: GaugeVec<U64>,
+}
+
+impl Metrics {
+	/// Create and register the relayer metrics
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			messages_delivered: register(
+				CounterVec::new(
+					Opts::new("tesseract_messages_delivered", "Number of messages delivered"),
+					&["source", "destination"],
+				)?,
+				registry,
+			)?,
+			messages_unprofitable: register(
+				CounterVec::new(
+					Opts::new(
+						"tesseract_messages_unprofitable",
+						"Number of messages skipped for being unprofitable",
+					),
+					&["source", "destination"],
+				)?,
+				registry,
+			)?,
+			message_retries: register(
+				CounterVec::new(
+					Opts::new(
+						"tesseract_message_retries",
+						"Number of retries of previously unprofitable messages",
+					),
+					&["source", "destination"],
+				)?,
+				registry,
+			)?,
+			submission_failures: register(
+				CounterVec::new(
+					Opts::new(
+						"tesseract_submission_failures",
+						"Number of failed message submissions",
+					),
+					&["source", "destination"],
+				)?,
+				registry,
+			)?,
+			gas_spent: register(
+				CounterVec::new(
+					Opts::new(
+						"tesseract_gas_spent_usd",
+						"Estimated cost in usd of the messages delivered to a chain",
+					),
+					&["chain"],
+				)?,
+				registry,
+			)?,
+			fees_earned: register(
+				CounterVec::new(
+					Opts::new(
+						"tesseract_fees_earned_usd",
+						"Relayer fees in usd of the messages delivered to a chain",
+					),
+					&["chain"],
+				)?,
+				registry,
+			)?,
+			fees_accumulated: register(
+				CounterVec::new(
+					Opts::new(
+						"tesseract_fees_accumulated",
+						"Number of deliveries whose fees were accumulated on hyperbridge",
+					),
+					&["chain"],
+				)?,
+				registry,
+			)?,
+			state_machine_update_lag: register(
+				GaugeVec::new(
+					Opts::new(
+						"tesseract_state_machine_update_lag",
+						"Blocks between the latest finalized height of a state machine and its latest height on the counterparty",
+					),
+					&["state_machine", "counterparty"],
+				)?,
+				registry,
+			)?,
+			rpc_error_rate: register(
+				GaugeVec::new(
+					Opts::new("tesseract_rpc_error_rate", "Error rate of an rpc endpoint"),
+					&["chain", "endpoint"],
+				)?,
+				registry,
+			)?,
+			rpc_latency: register(
+				GaugeVec::new(
+					Opts::new("tesseract_rpc_latency_ms", "Latency in milliseconds of an rpc endpoint"),
+					&["chain", "endpoint"],
+				)?,
+				registry,
+			)?,
+			unclaimed_fees: register(
+				GaugeVec::new(
+					Opts::new(
+						"tesseract_unclaimed_fees_usd",
+						"Unclaimed fees in usd on hyperbridge that can be withdrawn to a chain",
+					),
+					&["chain"],
+				)?,
+				registry,
+			)?,
+			pending_withdrawals: register(
+				GaugeVec::new(
+					Opts::new(
+						"tesseract_pending_withdrawals",
+						"Withdrawal requests that are yet to be delivered to a chain",
+					),
+					&["chain"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+}
+
+/// Periodically report the health of the rpc endpoints of every client
+pub async fn report_rpc_health(
+	clients: HashMap<StateMachine, Arc<dyn IsmpProvider>>,
+	metrics: Metrics,
+) {
+	let mut interval = tokio::time::interval(RPC_REPORT_INTERVAL);
+	loop {
+		interval.tick().await;
+		for client in clients.values() {
+			let name = client.name();
+			for endpoint in client.rpc_endpoints() {
+				metrics
+					.rpc_error_rate
+					.with_label_values(&[&name, &endpoint.url])
+					.set(endpoint.error_rate);
+				if let Some(latency) = endpoint.latency {
+					metrics.rpc_latency.with_label_values(&[&name, &endpoint.url]).set(latency);
+				}
+			}
+		}
+	}
+}
+
+/// Converts a usd amount with 18 decimals to a float
+pub fn to_usd(cost: Cost) -> f64 {
+	let precision = U256::from(10u128.pow(18));
+	let whole = (cost.0 / precision).min(U256::from(u64::MAX)).as_u64();
+	let fraction = (cost.0 % precision).as_u64();
+	whole as f64 + fraction as f64 / 1e18
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{to_usd, Metrics};
+	use crate::{events::Estimate, report_deliveries};
+	use ismp::host::StateMachine;
+	use sp_core::{H256, U256};
+	use std::{collections::HashMap, sync::Arc};
+	use substrate_prometheus_endpoint::Registry;
+	use tesseract_primitives::{mocks::MockHost, Cost, IsmpProvider, Query, TxReceipt};
+
+	#[test]
+	fn should_convert_costs_to_usd() {
+		assert_eq!(to_usd(Cost(U256::zero())), 0.0);
+		assert_eq!(to_usd(Cost(U256::from(15u128 * 10u128.pow(17)))), 1.5);
+		// amounts too large for a u64 saturate
+		assert!(to_usd(Cost(U256::MAX)) >= u64::MAX as f64);
+	}
+
+	#[test]
+	fn should_report_delivered_messages() {
+		let registry = Registry::new();
+		let metrics = Metrics::register(&registry).unwrap();
+		let source: Arc<dyn IsmpProvider> = Arc::new(MockHost::new((), 0, StateMachine::Evm(1)));
+		let dest: Arc<dyn IsmpProvider> =
+			Arc::new(MockHost::new((), 0, StateMachine::Kusama(2000)));
+
+		let receipt = |commitment| TxReceipt::Request {
+			query: Query {
+				source_chain: StateMachine::Evm(1),
+				dest_chain: StateMachine::Kusama(2000),
+				nonce: 0,
+				commitment,
+			},
+			height: 10,
+			gas: Default::default(),
+		};
+		let receipts = vec![receipt(H256::repeat_byte(1)), receipt(H256::repeat_byte(2))];
+		// only the first message had its fees checked
+		let estimates = HashMap::from([(
+			H256::repeat_byte(1),
+			Estimate {
+				execution_cost: Cost(U256::from(15u128 * 10u128.pow(17))),
+				fee: Cost(U256::from(2u128 * 10u128.pow(18))),
+				budget: None,
+			},
+		)]);
+
+		report_deliveries(&metrics, &source, &dest, &receipts, &estimates);
+		report_deliveries(&metrics, &source, &dest, &receipts[..1], &estimates);
+
+		let (source, dest) = (source.name(), dest.name());
+		assert_eq!(metrics.messages_delivered.with_label_values(&[&source, &dest]).get(), 3);
+		assert_eq!(metrics.gas_spent.with_label_values(&[&dest]).get(), 3.0);
+		assert_eq!(metrics.fees_earned.with_label_values(&[&dest]).get(), 4.0);
+
+		let families = registry.gather();
+		let delivered = families
+			.iter()
+			.find(|family| family.get_name() == "tesseract_messages_delivered")
+			.expect("Delivered messages are registered");
+		assert_eq!(delivered.get_metric()[0].get_counter().get_value(), 3.0);
+	}
+}
@@ -0,0 +1,193 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Enforcement of the relay policies
+
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use ismp::{
+	messaging::Message,
+	router::{RequestResponse, Response},
+};
+use sp_core::H256;
+use tesseract_primitives::{config::RelayPolicy, Cost, TxReceipt};
+use tokio::sync::Mutex;
+
+use crate::events::Estimate;
+
+/// Identifies a relay policy by the route and module it applies to
+type PolicyKey = (Option<String>, Option<String>, Option<String>);
+
+/// Tracks the gas spent per day on the messages of every relay policy. It is shared by all
+/// messaging tasks, so that budgets apply across routes.
+#[derive(Clone, Default)]
+pub struct GasBudgets {
+	/// The day and amount spent so far on that day for each policy
+	spent: Arc<Mutex<HashMap<PolicyKey, (u64, Cost)>>>,
+}
+
+/// Gas reserved from the daily budget of a relay policy for a message, which is refunded if the
+/// message is not delivered
+#[derive(Clone, Debug)]
+pub struct BudgetReservation {
+	/// The policy whose budget the gas was reserved from, `None` if it has no daily budget
+	key: Option<PolicyKey>,
+	/// The day the gas was reserved on
+	day: u64,
+	/// The reserved gas in usd
+	cost: Cost,
+}
+
+impl GasBudgets {
+	/// Reserve `cost` from the daily gas budget of `policy`. Returns `None` if it would exceed the
+	/// budget, in which case nothing is reserved. Messages are evaluated concurrently, so the cost
+	/// is reserved as soon as it fits, and must be refunded with
+	/// [`GasBudgets::refund_undelivered`] if the message is not delivered. Nothing is reserved
+	/// for policies without a daily budget.
+	pub async fn reserve(
+		&self,
+		policy: Option<&RelayPolicy>,
+		cost: Cost,
+	) -> Option<BudgetReservation> {
+		let day = today();
+		let Some((policy, budget)) =
+			policy.and_then(|policy| policy.daily_gas_budget.map(|budget| (policy, budget)))
+		else {
+			return Some(BudgetReservation { key: None, day, cost })
+		};
+		let key = (policy.source.clone(), policy.dest.clone(), policy.module.clone());
+
+		let mut spent = self.spent.lock().await;
+		let (current, total) = spent.entry(key.clone()).or_default();
+		if *current != day {
+			*current = day;
+			*total = Cost::default();
+		}
+		if *total + cost > Cost::from_usd(budget) {
+			return None
+		}
+		*total = *total + cost;
+		Some(BudgetReservation { key: Some(key), day, cost })
+	}
+
+	/// Refund the gas reserved for the messages in `estimates` that have no receipt in
+	/// `receipts`. Gas reserved on a previous day is not refunded, since the budget has been
+	/// reset since.
+	pub async fn refund_undelivered(
+		&self,
+		receipts: &[TxReceipt],
+		estimates: &HashMap<H256, Estimate>,
+	) {
+		let delivered = receipts
+			.iter()
+			.map(|receipt| receipt.query().commitment)
+			.collect::<HashSet<_>>();
+		let mut spent = self.spent.lock().await;
+		let reservations = estimates
+			.iter()
+			.filter(|(commitment, _)| !delivered.contains(*commitment))
+			.filter_map(|(_, estimate)| estimate.budget.as_ref());
+		for reservation in reservations {
+			let Some(ref key) = reservation.key else { continue };
+			if let Some((day, total)) = spent.get_mut(key) {
+				if *day == reservation.day {
+					*total = Cost(total.0.saturating_sub(reservation.cost.0));
+				}
+			}
+		}
+	}
+}
+
+/// The current day, budgets are reset at the start of every day
+fn today() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() / 86_400
+}
+
+/// Returns the module that dispatched the request or response in `msg` and its timeout
+/// timestamp. Messages are expected to contain a single post request or response.
+pub fn message_metadata(msg: &Message) -> Option<(Vec<u8>, u64)> {
+	match msg {
+		Message::Request(msg) =>
+			msg.requests.first().map(|post| (post.from.clone(), post.timeout_timestamp)),
+		Message::Batch(msg) =>
+			msg.batches.first().map(|batch| (batch.from.clone(), batch.timeout_timestamp)),
+		Message::Response(msg) => match msg.datagram {
+			RequestResponse::Response(ref responses) => match responses.first() {
+				Some(Response::Post(post)) =>
+					Some((post.source_module(), post.timeout().as_secs())),
+				_ => None,
+			},
+			_ => None,
+		},
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ismp::host::StateMachine;
+	use tesseract_primitives::{GasUsage, Query};
+
+	fn receipt(commitment: H256) -> TxReceipt {
+		TxReceipt::Request {
+			query: Query {
+				source_chain: StateMachine::Evm(97),
+				dest_chain: StateMachine::Evm(1),
+				nonce: 0,
+				commitment,
+			},
+			height: 0,
+			gas: GasUsage::default(),
+		}
+	}
+
+	#[tokio::test]
+	async fn concurrent_reservations_should_not_exceed_the_daily_budget() {
+		let budgets = GasBudgets::default();
+		// the budget only pays for a single message
+		let policy = RelayPolicy { daily_gas_budget: Some(1.5), ..Default::default() };
+		let cost = Cost::from_usd(1.0);
+
+		let (first, second) = futures::join!(
+			budgets.reserve(Some(&policy), cost),
+			budgets.reserve(Some(&policy), cost)
+		);
+		let reservation = match (first, second) {
+			(Some(reservation), None) | (None, Some(reservation)) => reservation,
+			reservations => panic!("Unexpected reservations {reservations:?}"),
+		};
+		let (delivered, undelivered) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		let estimate = |budget| Estimate { execution_cost: cost, fee: cost, budget };
+
+		// the gas of delivered messages stays reserved
+		let estimates = HashMap::from([(delivered, estimate(Some(reservation.clone())))]);
+		budgets.refund_undelivered(&[receipt(delivered)], &estimates).await;
+		assert!(budgets.reserve(Some(&policy), cost).await.is_none());
+
+		// the gas of undelivered messages is refunded
+		let estimates = HashMap::from([(undelivered, estimate(Some(reservation)))]);
+		budgets.refund_undelivered(&[receipt(delivered)], &estimates).await;
+		assert!(budgets.reserve(Some(&policy), cost).await.is_some());
+		assert!(budgets.reserve(Some(&policy), cost).await.is_none());
+
+		// policies without a budget are never exhausted
+		assert!(budgets.reserve(None, cost).await.is_some());
+	}
+}
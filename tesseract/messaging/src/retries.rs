@@ -3,33 +3,44 @@ use std::{collections::HashMap, sync::Arc, time::Duration};
 use ismp::{
 	consensus::StateMachineHeight,
 	host::StateMachine,
-	messaging::{hash_request, hash_response, Message, Proof, RequestMessage, ResponseMessage},
+	messaging::{
+		hash_batch, hash_request, hash_response, Message, Proof, RequestMessage, ResponseMessage,
+	},
 	router::{Request, RequestResponse, Response},
 };
 use tesseract_primitives::{config::RelayerConfig, Hasher, IsmpProvider, Query};
+use tokio::sync::watch;
 use transaction_fees::TransactionPayment;
 
 use crate::{
-	events::{chunk_size, return_successful_queries},
-	FeeAccSender,
+	events::{batch_messages_with_proof, chunk_size, return_successful_queries},
+	metrics::Metrics,
+	policy::GasBudgets,
+	record_deliveries, report_deliveries, ClientMap, DeliveryLock, FeeAccSender,
 };
 
 /// Pull retriable messages from the database periodically and retry them.
 pub async fn retry_unprofitable_messages(
 	dest: Arc<dyn IsmpProvider>,
 	hyperbridge: Arc<dyn IsmpProvider>,
-	client_map: HashMap<StateMachine, Arc<dyn IsmpProvider>>,
+	client_map: ClientMap,
 	tx_payment: Arc<TransactionPayment>,
-	config: RelayerConfig,
+	config: watch::Receiver<RelayerConfig>,
 	coprocessor: StateMachine,
 	fee_acc_sender: FeeAccSender,
+	metrics: Option<Metrics>,
+	budgets: GasBudgets,
+	delivery_lock: DeliveryLock,
 ) -> Result<(), anyhow::Error> {
 	// Default to every 10 minutes
 	let mut interval = tokio::time::interval(Duration::from_secs(
-		config.unprofitable_retry_frequency.unwrap_or(10 * 60),
+		config.borrow().unprofitable_retry_frequency.unwrap_or(10 * 60),
 	));
 	loop {
 		interval.tick().await;
+		let _delivery = delivery_lock.read().await;
+		let config = config.borrow().clone();
+		let client_map = client_map.borrow().clone();
 		let unprofitables =
 			match tx_payment.unprofitable_messages(&dest.state_machine_id().state_id).await {
 				Ok(messages) => messages,
@@ -40,6 +51,12 @@ pub async fn retry_unprofitable_messages(
 
 		if !unprofitables.is_empty() {
 			tracing::trace!("Starting retries of previously unprofitable or failed messages");
+			if let Some(metrics) = metrics.as_ref() {
+				metrics
+					.message_retries
+					.with_label_values(&[&hyperbridge.name(), &dest.name()])
+					.inc_by(unprofitables.len() as u64);
+			}
 			let mut request_messages = vec![];
 			let mut response_messages = vec![];
 			let mut ids = vec![];
@@ -47,6 +64,9 @@ pub async fn retry_unprofitable_messages(
 			let mut response_queries = vec![];
 			let mut post_requests = vec![];
 			let mut post_responses = vec![];
+			let mut batch_messages = vec![];
+			let mut batch_queries = vec![];
+			let mut post_batches = vec![];
 			// Store the highest proof height in this variable
 			let mut state_machine_height: Option<StateMachineHeight> = None;
 			unprofitables.into_iter().for_each(|(message, id)| {
@@ -110,20 +130,43 @@ pub async fn retry_unprofitable_messages(
 						},
 						_ => panic!("Inconsistent Db, withdraw all fees and restart relayer with a fresh database"),
 					},
+					Message::Batch(msg) => {
+						let batch = msg.batches.get(0).cloned().expect(
+							"Inconsistent Database, withdraw all fees and restart relayer with a fresh database",
+						);
+						let query = Query {
+							source_chain: batch.source,
+							dest_chain: batch.dest,
+							nonce: batch.nonce,
+							commitment: hash_batch::<Hasher>(&batch),
+						};
+						if let Some(state_machine_height) = state_machine_height.as_mut() {
+							if msg.proof.height.height > state_machine_height.height {
+								*state_machine_height = msg.proof.height
+							}
+						} else {
+							state_machine_height = Some(msg.proof.height)
+						}
+						post_batches.push(batch);
+						batch_messages.push(Message::Batch(msg));
+						batch_queries.push(query);
+						ids.push(id);
+					},
 					_ => panic!("Inconsistent Db, withdraw all fees and restart relayer with a fresh database"),
 				}
 			});
 
 			let mut outgoing_messages = vec![];
 			let mut new_unprofitable_messages = vec![];
+			let mut estimates = HashMap::new();
 			match return_successful_queries(
 				dest.clone(),
 				request_messages,
 				request_queries,
-				config.minimum_profit_percentage,
+				&config,
 				coprocessor,
 				&client_map,
-				config.deliver_failed.unwrap_or_default(),
+				&budgets,
 			)
 			.await
 			{
@@ -182,6 +225,7 @@ pub async fn retry_unprofitable_messages(
 					}
 
 					new_unprofitable_messages.extend(request_profitablility.retriable_messages);
+					estimates.extend(request_profitablility.estimates);
 				},
 				Err(err) => {
 					tracing::error!("Unprofitable Messages Retries: Debug tracing failed: {err:?}")
@@ -192,10 +236,10 @@ pub async fn retry_unprofitable_messages(
 				dest.clone(),
 				response_messages,
 				response_queries,
-				config.minimum_profit_percentage,
+				&config,
 				coprocessor,
 				&client_map,
-				config.deliver_failed.unwrap_or_default(),
+				&budgets,
 			)
 			.await
 			{
@@ -256,6 +300,50 @@ pub async fn retry_unprofitable_messages(
 					}
 
 					new_unprofitable_messages.extend(response_profitablility.retriable_messages);
+					estimates.extend(response_profitablility.estimates);
+				},
+				Err(err) => {
+					tracing::error!("Unprofitable Messages Retries: Debug tracing failed: {err:?}")
+				},
+			}
+
+			match return_successful_queries(
+				dest.clone(),
+				batch_messages,
+				batch_queries,
+				&config,
+				coprocessor,
+				&client_map,
+				&budgets,
+			)
+			.await
+			{
+				Ok(batch_profitability) => {
+					let (post_batches, successful_queries): (Vec<_>, Vec<_>) = post_batches
+						.into_iter()
+						.zip(batch_profitability.queries)
+						.filter_map(|(batch, query)| Some((batch, query?)))
+						.unzip();
+
+					if let Some(state_machine_height) = state_machine_height {
+						match batch_messages_with_proof(
+							hyperbridge.clone(),
+							dest.clone(),
+							state_machine_height,
+							post_batches,
+							successful_queries,
+						)
+						.await
+						{
+							Ok(messages) => outgoing_messages.extend(messages),
+							Err(err) => tracing::error!(
+								"Unprofitable Messages Retries: Failed to query batch proofs: {err:?}"
+							),
+						}
+					}
+
+					new_unprofitable_messages.extend(batch_profitability.retriable_messages);
+					estimates.extend(batch_profitability.estimates);
 				},
 				Err(err) => {
 					tracing::error!("Unprofitable Messages Retries: Debug tracing failed: {err:?}")
@@ -267,7 +355,12 @@ pub async fn retry_unprofitable_messages(
 					target: "tesseract",
 					"Unprofitable Messages Retries: 🛰️ Transmitting ismp messages from {} to {}", hyperbridge.name(), dest.name()
 				);
-				if let Ok(receipts) = dest.submit(outgoing_messages).await {
+				let res = dest.submit(outgoing_messages).await;
+				budgets.refund_undelivered(res.as_deref().unwrap_or_default(), &estimates).await;
+				if let Ok(receipts) = res {
+					if let Some(metrics) = metrics.as_ref() {
+						report_deliveries(metrics, &hyperbridge, &dest, &receipts, &estimates);
+					}
 					if !receipts.is_empty() {
 						// Store receipts in database before auto accumulation
 						tracing::trace!(target: "tesseract", "Persisting {} deliveries from {}->{} to the db", receipts.len(), hyperbridge.name(), dest.name());
@@ -277,6 +370,7 @@ pub async fn retry_unprofitable_messages(
 								receipts.len()
 							)
 						}
+						record_deliveries(&tx_payment, &receipts, &estimates).await;
 						// Send receipts to the fee accumulation task
 						match fee_acc_sender.send(receipts).await {
 							Err(_sent) => {
@@ -311,7 +405,8 @@ pub async fn retry_unprofitable_messages(
 						},
 						Message::Response(res_msg) => {
 							let res = match &res_msg.datagram {
-								RequestResponse::Request(_) => unreachable!(
+								RequestResponse::Request(_) | RequestResponse::Batch(_) =>
+									unreachable!(
 									"Relayer only ever processes post requests and post responses"
 								),
 								RequestResponse::Response(responses) => responses[0].clone(),
@@ -322,6 +417,12 @@ pub async fn retry_unprofitable_messages(
 								Some(msg.clone())
 							}
 						},
+						Message::Batch(batch_msg) =>
+							if batch_msg.batches[0].timed_out(dest_timestamp) {
+								None
+							} else {
+								Some(msg.clone())
+							},
 						_ => None,
 					})
 					.collect::<Vec<_>>();
@@ -0,0 +1,283 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dry runs of the message relay, where messages are recorded instead of submitted
+
+use serde::Serialize;
+use sp_core::H256;
+use std::{
+	collections::HashMap,
+	sync::Arc,
+	time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::{fs::File, io::AsyncWriteExt, sync::Mutex};
+
+use ismp::{
+	messaging::{hash_batch, hash_request, hash_response, Message},
+	router::{Request, RequestResponse},
+};
+use tesseract_primitives::{Hasher, Query};
+
+use crate::events::Estimate;
+
+/// Decision of the profitability check for a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+	/// The message would have been submitted
+	Submit,
+	/// The message was skipped for being unprofitable
+	Unprofitable,
+}
+
+/// A message observed during a dry run
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulatedMessage {
+	/// Unix timestamp at which the message was observed
+	pub timestamp: u64,
+	/// Chain the message was dispatched from
+	pub source: String,
+	/// Chain the message would have been submitted to
+	pub dest: String,
+	/// Either `request` or `response`
+	pub kind: &'static str,
+	/// Commitment of the request or response
+	pub commitment: String,
+	pub nonce: u64,
+	/// Height of the source the message would have been proven at
+	pub height: u64,
+	pub decision: Decision,
+	/// Estimated execution cost in usd, absent for messages delivered to the coprocessor
+	pub execution_cost: Option<String>,
+	/// Relayer fee in usd, absent for messages delivered to the coprocessor
+	pub fee: Option<String>,
+}
+
+/// Records the messages the relayer would submit as json lines in a report file.
+#[derive(Clone)]
+pub struct DryRun {
+	report: Arc<Mutex<File>>,
+}
+
+impl DryRun {
+	/// Open the report file, new records are appended if it already exists
+	pub async fn open(path: &str) -> anyhow::Result<Self> {
+		let file = tokio::fs::OpenOptions::new().create(true).append(true).open(path).await?;
+		Ok(Self { report: Arc::new(Mutex::new(file)) })
+	}
+
+	/// Record the messages that would have been submitted from `source` to `dest` and the ones
+	/// that were skipped for being unprofitable
+	pub async fn record(
+		&self,
+		source: &str,
+		dest: &str,
+		messages: &[Message],
+		unprofitable: &[Message],
+		estimates: &HashMap<H256, Estimate>,
+	) -> anyhow::Result<()> {
+		let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+		let mut records = vec![];
+		for (decision, messages) in
+			[(Decision::Submit, messages), (Decision::Unprofitable, unprofitable)]
+		{
+			for message in messages {
+				let height = message_height(message);
+				for (kind, query) in queries(message) {
+					let estimate = estimates.get(&query.commitment);
+					records.push(SimulatedMessage {
+						timestamp,
+						source: source.to_string(),
+						dest: dest.to_string(),
+						kind,
+						commitment: format!("0x{}", hex::encode(query.commitment)),
+						nonce: query.nonce,
+						height,
+						decision,
+						execution_cost: estimate
+							.map(|estimate| estimate.execution_cost.to_string()),
+						fee: estimate.map(|estimate| estimate.fee.to_string()),
+					});
+				}
+			}
+		}
+
+		let submitted = records.iter().filter(|record| record.decision == Decision::Submit).count();
+		tracing::info!(
+			target: "tesseract",
+			"🧪 Dry run: would submit {submitted} messages from {source} to {dest}, {} unprofitable",
+			records.len() - submitted
+		);
+
+		let mut lines = String::new();
+		for record in records {
+			lines.push_str(&json::to_string(&record)?);
+			lines.push('\n');
+		}
+		let mut report = self.report.lock().await;
+		report.write_all(lines.as_bytes()).await?;
+		report.flush().await?;
+
+		Ok(())
+	}
+}
+
+fn message_height(message: &Message) -> u64 {
+	match message {
+		Message::Request(msg) => msg.proof.height.height,
+		Message::Response(msg) => msg.proof.height.height,
+		Message::Batch(msg) => msg.proof.height.height,
+		_ => 0,
+	}
+}
+
+/// The requests and responses contained in a message
+fn queries(message: &Message) -> Vec<(&'static str, Query)> {
+	match message {
+		Message::Request(msg) => msg
+			.requests
+			.iter()
+			.map(|post| {
+				let req = Request::Post(post.clone());
+				let query = Query {
+					source_chain: req.source_chain(),
+					dest_chain: req.dest_chain(),
+					nonce: req.nonce(),
+					commitment: hash_request::<Hasher>(&req),
+				};
+				("request", query)
+			})
+			.collect(),
+		Message::Response(msg) => match msg.datagram {
+			RequestResponse::Response(ref responses) => responses
+				.iter()
+				.map(|resp| {
+					let query = Query {
+						source_chain: resp.source_chain(),
+						dest_chain: resp.dest_chain(),
+						nonce: resp.nonce(),
+						commitment: hash_response::<Hasher>(resp),
+					};
+					("response", query)
+				})
+				.collect(),
+			_ => vec![],
+		},
+		Message::Batch(msg) => msg
+			.batches
+			.iter()
+			.map(|batch| {
+				let query = Query {
+					source_chain: batch.source,
+					dest_chain: batch.dest,
+					nonce: batch.nonce,
+					commitment: hash_batch::<Hasher>(batch),
+				};
+				("batch", query)
+			})
+			.collect(),
+		_ => vec![],
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::DryRun;
+	use crate::{handle_update, policy::GasBudgets};
+	use ismp::{events::Event, host::StateMachine, router::PostRequest};
+	use sp_core::U256;
+	use std::{collections::HashMap, sync::Arc};
+	use tesseract_primitives::{
+		config::{RelayPolicy, RelayerConfig},
+		mocks::MockHost,
+		Cost, IsmpProvider, StateMachineUpdated,
+	};
+	use transaction_fees::TransactionPayment;
+
+	#[tokio::test]
+	async fn dry_runs_should_never_submit_or_spend_the_gas_budget() {
+		let dir = std::env::temp_dir();
+		let report = dir.join("tesseract-dry-run.jsonl");
+		let _ = std::fs::remove_file(&report);
+		let dry_run = DryRun::open(report.to_str().unwrap()).await.unwrap();
+		let tx_payment = Arc::new(
+			TransactionPayment::initialize(dir.join("tesseract-dry-run.db").to_str().unwrap())
+				.await
+				.unwrap(),
+		);
+
+		let mut source = MockHost::new((), 10, StateMachine::Evm(97));
+		source.fee = U256::from(2u128 * 10u128.pow(18));
+		let events = source.events.clone();
+		// submitting to the mock host fails
+		let mut dest = MockHost::new((), 10, StateMachine::Evm(1));
+		dest.execution_cost = Cost::from_usd(1.0);
+		let (source, dest): (Arc<dyn IsmpProvider>, Arc<dyn IsmpProvider>) =
+			(Arc::new(source), Arc::new(dest));
+		let client_map = HashMap::from([
+			(StateMachine::Evm(97), source.clone()),
+			(StateMachine::Evm(1), dest.clone()),
+		]);
+		// the budget only pays for a single message
+		let config = RelayerConfig {
+			policies: Some(vec![RelayPolicy { daily_gas_budget: Some(1.5), ..Default::default() }]),
+			..Default::default()
+		};
+		let budgets = GasBudgets::default();
+
+		let mut previous_height = 0;
+		for nonce in 0..2 {
+			let post = PostRequest {
+				source: StateMachine::Evm(97),
+				dest: StateMachine::Evm(1),
+				nonce,
+				from: vec![1u8; 20],
+				to: vec![2u8; 20],
+				timeout_timestamp: 0,
+				body: vec![],
+			};
+			*events.lock().unwrap() = vec![Event::PostRequest(post)];
+			let update = StateMachineUpdated {
+				state_machine_id: source.state_machine_id(),
+				latest_height: 10 * (nonce + 1),
+			};
+			handle_update(
+				dest.clone(),
+				source.clone(),
+				tx_payment.clone(),
+				update,
+				&mut previous_height,
+				config.clone(),
+				StateMachine::Kusama(4009),
+				&client_map,
+				None,
+				None,
+				None,
+				Some(&dry_run),
+				&budgets,
+			)
+			.await
+			.unwrap();
+		}
+
+		// both messages would have been submitted, the first did not spend the budget
+		let report = tokio::fs::read_to_string(&report).await.unwrap();
+		let decisions = report
+			.lines()
+			.map(|line| json::from_str::<json::Value>(line).unwrap()["decision"].clone())
+			.collect::<Vec<_>>();
+		assert_eq!(decisions, vec![json::json!("submit"), json::json!("submit")]);
+	}
+}
@@ -15,7 +15,10 @@
 
 //! Relayer configuration options
 
+use anyhow::anyhow;
+use ismp::host::StateMachine;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Configuration options for the relayer.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -37,4 +40,194 @@ pub struct RelayerConfig {
 	pub deliver_failed: Option<bool>,
 	/// Start fisherman task?
 	pub fisherman: Option<bool>,
+	/// Relay policies for specific routes and modules
+	pub policies: Option<Vec<RelayPolicy>>,
+}
+
+impl RelayerConfig {
+	/// Returns the most specific policy that applies to messages from `module` on `source` to
+	/// `dest`. Policies that specify more of the source, destination and module take precedence,
+	/// and the first one declared wins a tie.
+	pub fn policy(
+		&self,
+		source: StateMachine,
+		dest: StateMachine,
+		module: &[u8],
+	) -> Option<&RelayPolicy> {
+		self.policies
+			.iter()
+			.flatten()
+			.enumerate()
+			.filter(|(_, policy)| policy.matches(source, dest, module))
+			.max_by_key(|(index, policy)| (policy.specificity(), std::cmp::Reverse(*index)))
+			.map(|(_, policy)| policy)
+	}
+
+	/// Returns true if any policy that applies to messages from `module` on `source` to `dest`
+	/// denies the module
+	pub fn denies(&self, source: StateMachine, dest: StateMachine, module: &[u8]) -> bool {
+		self.policies
+			.iter()
+			.flatten()
+			.any(|policy| policy.matches(source, dest, module) && policy.denies(module))
+	}
+
+	/// Check that the relay policies are well formed
+	pub fn validate(&self) -> anyhow::Result<()> {
+		for policy in self.policies.iter().flatten() {
+			for chain in policy.source.iter().chain(policy.dest.iter()) {
+				StateMachine::from_str(chain).map_err(|err| {
+					anyhow!("Invalid state machine {chain} in relay policy: {err}")
+				})?;
+			}
+			for module in policy.module.iter().chain(policy.deny_senders.iter().flatten()) {
+				decode_module(module)
+					.ok_or_else(|| anyhow!("Invalid module {module} in relay policy"))?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Relay policy for the messages on a route. The source, destination and module select the
+/// messages it applies to, and match every message when they are not set.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct RelayPolicy {
+	/// Source state machine of the messages e.g "EVM-97"
+	pub source: Option<String>,
+	/// Destination state machine of the messages
+	pub dest: Option<String>,
+	/// Hex encoded identifier of the module that dispatched the messages
+	pub module: Option<String>,
+	/// Minimum profit percentage, overrides the global `minimum_profit_percentage`
+	pub minimum_profit_percentage: Option<u32>,
+	/// Minimum fee in usd that messages must provide
+	pub minimum_fee: Option<f64>,
+	/// Maximum gas a single message may consume on the destination
+	pub maximum_gas: Option<u64>,
+	/// Maximum amount in usd to spend on gas for these messages per day
+	pub daily_gas_budget: Option<f64>,
+	/// Hex encoded identifiers of modules whose messages should never be relayed
+	pub deny_senders: Option<Vec<String>>,
+	/// Relay messages that will time out within this many seconds, even at a loss
+	pub relay_expiring_within: Option<u64>,
+}
+
+impl RelayPolicy {
+	/// Returns true if the policy applies to messages from `module` on `source` to `dest`
+	pub fn matches(&self, source: StateMachine, dest: StateMachine, module: &[u8]) -> bool {
+		self.source.iter().all(|chain| *chain == source.to_string()) &&
+			self.dest.iter().all(|chain| *chain == dest.to_string()) &&
+			self.module.iter().all(|id| decode_module(id).is_some_and(|id| id == module))
+	}
+
+	/// Returns true if messages from `module` should never be relayed
+	pub fn denies(&self, module: &[u8]) -> bool {
+		self.deny_senders
+			.iter()
+			.flatten()
+			.any(|id| decode_module(id).is_some_and(|id| id == module))
+	}
+
+	/// Returns true if a message with the given timeout should be relayed even when it is
+	/// unprofitable
+	pub fn relay_at_loss(&self, timeout: u64, now: u64) -> bool {
+		match self.relay_expiring_within {
+			Some(window) => timeout != 0 && timeout > now && timeout - now <= window,
+			None => false,
+		}
+	}
+
+	fn specificity(&self) -> usize {
+		[self.source.is_some(), self.dest.is_some(), self.module.is_some()]
+			.into_iter()
+			.filter(|specified| *specified)
+			.count()
+	}
+}
+
+fn decode_module(id: &str) -> Option<Vec<u8>> {
+	hex::decode(id.trim_start_matches("0x")).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{RelayPolicy, RelayerConfig};
+	use ismp::host::StateMachine;
+
+	#[test]
+	fn should_select_the_most_specific_policy() {
+		let module = vec![1u8; 20];
+		let config = RelayerConfig {
+			policies: Some(vec![
+				RelayPolicy { minimum_profit_percentage: Some(1), ..Default::default() },
+				RelayPolicy {
+					dest: Some("EVM-97".to_string()),
+					minimum_profit_percentage: Some(2),
+					..Default::default()
+				},
+				RelayPolicy {
+					dest: Some("EVM-97".to_string()),
+					module: Some(format!("0x{}", hex::encode(&module))),
+					minimum_profit_percentage: Some(3),
+					..Default::default()
+				},
+			]),
+			..Default::default()
+		};
+		config.validate().unwrap();
+
+		let policy = |dest, module: &[u8]| {
+			config
+				.policy(StateMachine::Kusama(2000), dest, module)
+				.and_then(|policy| policy.minimum_profit_percentage)
+		};
+		assert_eq!(policy(StateMachine::Evm(97), &module), Some(3));
+		assert_eq!(policy(StateMachine::Evm(97), &[2u8; 20]), Some(2));
+		assert_eq!(policy(StateMachine::Evm(1), &module), Some(1));
+		assert_eq!(
+			RelayerConfig::default().policy(StateMachine::Evm(1), StateMachine::Evm(97), &module),
+			None
+		);
+	}
+
+	#[test]
+	fn should_deny_senders_and_relay_expiring_messages() {
+		let policy = RelayPolicy {
+			deny_senders: Some(vec![hex::encode([1u8; 20])]),
+			relay_expiring_within: Some(600),
+			..Default::default()
+		};
+		assert!(policy.denies(&[1u8; 20]));
+		assert!(!policy.denies(&[2u8; 20]));
+
+		// a more specific policy does not lift the deny list of a general one
+		let config = RelayerConfig {
+			policies: Some(vec![
+				policy.clone(),
+				RelayPolicy { dest: Some("EVM-97".to_string()), ..Default::default() },
+			]),
+			..Default::default()
+		};
+		assert!(config.denies(StateMachine::Evm(1), StateMachine::Evm(97), &[1u8; 20]));
+		assert!(!config.denies(StateMachine::Evm(1), StateMachine::Evm(97), &[2u8; 20]));
+
+		assert!(policy.relay_at_loss(1_500, 1_000));
+		assert!(!policy.relay_at_loss(2_000, 1_000));
+		// already timed out or without a timeout
+		assert!(!policy.relay_at_loss(900, 1_000));
+		assert!(!policy.relay_at_loss(0, 1_000));
+	}
+
+	#[test]
+	fn should_reject_malformed_policies() {
+		let config = |policy| RelayerConfig { policies: Some(vec![policy]), ..Default::default() };
+		assert!(config(RelayPolicy { source: Some("EVM".to_string()), ..Default::default() })
+			.validate()
+			.is_err());
+		assert!(config(RelayPolicy { module: Some("0xzz".to_string()), ..Default::default() })
+			.validate()
+			.is_err());
+	}
 }
@@ -15,9 +15,10 @@
 
 //! Traits and types required to compose the tesseract relayer
 pub mod config;
-#[cfg(feature = "testing")]
+#[cfg(any(test, feature = "testing"))]
 pub mod mocks;
 pub mod queue;
+pub mod signer;
 
 use anyhow::anyhow;
 use futures::{Stream, StreamExt};
@@ -35,6 +36,7 @@ pub use pallet_ismp_relayer::withdrawal::{Signature, WithdrawalProof};
 use pallet_state_coprocessor::impls::GetRequestsWithProof;
 use parity_scale_codec::{Decode, Encode};
 use primitive_types::{H256, U256};
+use rpc_utils::EndpointStatus;
 use sp_core::keccak_256;
 use std::{
 	fmt::{Debug, Display, Formatter},
@@ -109,9 +111,18 @@ impl From<U256> for Cost {
 	}
 }
 
+impl Cost {
+	/// Converts a usd amount to a [`Cost`], with a precision of 6 decimals
+	pub fn from_usd(usd: f64) -> Self {
+		Cost(U256::from((usd * 1e6) as u128) * U256::from(10u128.pow(12)))
+	}
+}
+
 #[derive(Copy, Clone, Debug, Default)]
 pub struct EstimateGasReturnParams {
 	pub execution_cost: Cost,
+	/// Gas the message is expected to consume on the destination
+	pub gas: U256,
 	pub successful_execution: bool,
 }
 
@@ -126,13 +137,22 @@ pub struct Query {
 	pub commitment: H256,
 }
 
+/// Gas spent delivering a message
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GasUsage {
+	/// Gas used by the message, messages delivered in the same transaction share its gas evenly
+	pub gas_used: U256,
+	/// The price paid per unit of gas in the native token of the destination
+	pub gas_price: U256,
+}
+
 /// A type tha should be returned when messages are submitted successfully
 #[derive(Debug, Clone, Copy)]
 pub enum TxReceipt {
 	/// Request variant
-	Request { query: Query, height: u64 },
+	Request { query: Query, height: u64, gas: GasUsage },
 	/// Response variant
-	Response { query: Query, request_commitment: H256, height: u64 },
+	Response { query: Query, request_commitment: H256, height: u64, gas: GasUsage },
 }
 
 impl TxReceipt {
@@ -149,6 +169,20 @@ impl TxReceipt {
 			TxReceipt::Response { query, .. } => query.source_chain,
 		}
 	}
+
+	pub fn query(&self) -> &Query {
+		match self {
+			TxReceipt::Request { query, .. } => query,
+			TxReceipt::Response { query, .. } => query,
+		}
+	}
+
+	pub fn gas(&self) -> GasUsage {
+		match self {
+			TxReceipt::Request { gas, .. } => *gas,
+			TxReceipt::Response { gas, .. } => *gas,
+		}
+	}
 }
 
 /// A type that represents the location where state proof queries should be directed
@@ -254,6 +288,18 @@ pub trait IsmpProvider: ByzantineHandler + Send + Sync {
 		event: StateMachineUpdated,
 	) -> Result<Vec<Event>, anyhow::Error>;
 
+	/// Query the consensus proofs of `consensus_state_id` accepted by this host in the blocks
+	/// from `from` to `to` inclusive, in the order they were handled. Hosts that can't observe
+	/// the consensus updates they handled return an error.
+	async fn query_consensus_updates(
+		&self,
+		_consensus_state_id: ConsensusStateId,
+		_from: u64,
+		_to: u64,
+	) -> Result<Vec<Vec<u8>>, anyhow::Error> {
+		Err(anyhow!("{} does not expose the consensus updates it has handled", self.name()))
+	}
+
 	/// Name of this chain, used in logs.
 	fn name(&self) -> String;
 
@@ -333,8 +379,8 @@ pub trait IsmpProvider: ByzantineHandler + Send + Sync {
 	/// Relayer's address on this chain
 	fn address(&self) -> Vec<u8>;
 
-	/// Sign a prehashed message using the Relayer's private key
-	fn sign(&self, msg: &[u8]) -> Signature;
+	/// Sign a prehashed message using the Relayer's signer
+	async fn sign(&self, msg: &[u8]) -> Result<Signature, anyhow::Error>;
 
 	/// Set the initial height with the finalized height on counterparty
 	async fn set_latest_finalized_height(
@@ -361,6 +407,11 @@ pub trait IsmpProvider: ByzantineHandler + Send + Sync {
 	fn max_concurrent_queries(&self) -> usize {
 		10
 	}
+
+	/// Health of the rpc endpoints used by this client
+	fn rpc_endpoints(&self) -> Vec<EndpointStatus> {
+		vec![]
+	}
 }
 
 /// Provides an interface for handling byzantine behaviour. Implementations of this should watch for
@@ -375,6 +426,14 @@ pub trait ByzantineHandler {
 		challenge_event: StateMachineUpdated,
 	) -> Result<(), anyhow::Error>;
 
+	/// Query the state root of this chain at `height` that its state commitments are checked
+	/// against. Returns `None` if the rpc has no block at that height.
+	async fn query_state_root(
+		&self,
+		coprocessor: StateMachine,
+		height: u64,
+	) -> Result<Option<H256>, anyhow::Error>;
+
 	/// Return a stream that watches for updates to [`counterparty_state_id`], yields when new
 	/// [`Vec<StateMachineUpdated>`] event is observed for [`counterparty_state_id`]
 	async fn state_machine_updates(
@@ -419,6 +478,22 @@ pub trait HyperbridgeClaim {
 		client: Arc<dyn IsmpProvider>,
 		chain: StateMachine,
 	) -> anyhow::Result<WithdrawFundsResult>;
+	/// Withdraw the fees accrued by the address of `client` on every chain in `chains` with a
+	/// single signature. A batch holds at most `MAX_WITHDRAWAL_BATCH_SIZE` chains.
+	async fn withdraw_funds_batch(
+		&self,
+		client: Arc<dyn IsmpProvider>,
+		chains: Vec<StateMachine>,
+	) -> anyhow::Result<Vec<WithdrawFundsResult>>;
+	/// Credit the fees accrued by the address of `client` to `beneficiary`. This is signed by
+	/// `client`, so it only succeeds while no beneficiary is set.
+	async fn set_beneficiary(
+		&self,
+		client: Arc<dyn IsmpProvider>,
+		beneficiary: Vec<u8>,
+	) -> anyhow::Result<()>;
+	/// The beneficiary credited with the fees accrued by `address` on every chain, if one is set
+	async fn beneficiary(&self, address: Vec<u8>) -> anyhow::Result<Option<Vec<u8>>>;
 	/// Check if this key has been claimed
 	async fn check_claimed(&self, key: Key) -> anyhow::Result<bool>;
 }
@@ -466,6 +541,45 @@ impl NonceProvider {
 	}
 }
 
+/// A signed transaction that has been broadcast but not yet observed in a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackedTransaction {
+	/// The chain the transaction was submitted to
+	pub chain: StateMachine,
+	/// The account that signed the transaction
+	pub signer: Vec<u8>,
+	/// The nonce the transaction occupies
+	pub nonce: u64,
+	/// The hash of the signed transaction
+	pub hash: H256,
+	/// The signed transaction payload, used for rebroadcasting
+	pub payload: Vec<u8>,
+}
+
+/// Persistent storage for in-flight transactions, this allows relayer accounts to survive
+/// restarts without leaving nonce gaps or double submitting transactions.
+#[async_trait::async_trait]
+pub trait TransactionStore: Send + Sync {
+	/// Return all the transactions currently tracked for this signer on the given chain
+	async fn tracked_transactions(
+		&self,
+		chain: StateMachine,
+		signer: Vec<u8>,
+	) -> anyhow::Result<Vec<TrackedTransaction>>;
+
+	/// Start tracking a transaction, replacing any transaction previously tracked at the same
+	/// nonce.
+	async fn track_transaction(&self, tx: TrackedTransaction) -> anyhow::Result<()>;
+
+	/// Stop tracking the transaction at this nonce
+	async fn untrack_transaction(
+		&self,
+		chain: StateMachine,
+		signer: Vec<u8>,
+		nonce: u64,
+	) -> anyhow::Result<()>;
+}
+
 pub async fn wait_for_challenge_period(
 	client: Arc<dyn IsmpProvider>,
 	last_consensus_update: Duration,
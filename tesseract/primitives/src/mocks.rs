@@ -1,6 +1,8 @@
 use crate::{
-	BoxStream, ByzantineHandler, EstimateGasReturnParams, HyperbridgeClaim, IsmpHost, IsmpProvider,
-	Query, Signature, StateMachineUpdated, StateProofQueryType, TxReceipt, WithdrawFundsResult,
+	signer::{LocalSigner, SignRequest, Signer},
+	BoxStream, ByzantineHandler, Cost, EstimateGasReturnParams, HyperbridgeClaim, IsmpHost,
+	IsmpProvider, Query, Signature, StateMachineUpdated, StateProofQueryType, TxReceipt,
+	WithdrawFundsResult,
 };
 use anyhow::{anyhow, Error};
 use ismp::{
@@ -13,15 +15,34 @@ use pallet_ismp_host_executive::HostParam;
 use pallet_ismp_relayer::withdrawal::{Key, WithdrawalProof};
 use parity_scale_codec::Codec;
 use primitive_types::{H256, U256};
+use sp_core::bytes::from_hex;
 use std::{
 	sync::{Arc, Mutex},
 	time::Duration,
 };
+use tokio::{
+	io::{AsyncReadExt, AsyncWriteExt},
+	net::{TcpListener, TcpStream},
+};
 
 pub struct MockHost<C> {
 	pub consensus_state: Arc<Mutex<C>>,
 	pub latest_height: Arc<Mutex<u64>>,
 	pub state_machine: StateMachine,
+	/// Events returned for every range of heights that is queried
+	pub events: Arc<Mutex<Vec<Event>>>,
+	/// Estimated execution cost of every message submitted to this host
+	pub execution_cost: Cost,
+	/// Relayer fee of every request and response dispatched from this host
+	pub fee: U256,
+	/// Updates yielded by the state machine update notification stream
+	pub updates: Arc<Mutex<Vec<StateMachineUpdated>>>,
+	/// The previous height of every query for events
+	pub queried_heights: Arc<Mutex<Vec<u64>>>,
+	/// Every batch of messages submitted to this host
+	pub submitted: Arc<Mutex<Vec<Vec<Message>>>>,
+	/// Consensus proofs accepted by this host, with the height at which they were accepted
+	pub consensus_updates: Arc<Mutex<Vec<(u64, Vec<u8>)>>>,
 }
 
 impl<C> MockHost<C> {
@@ -30,6 +51,13 @@ impl<C> MockHost<C> {
 			consensus_state: Arc::new(Mutex::new(consensus_state)),
 			latest_height: Arc::new(Mutex::new(latest_height)),
 			state_machine,
+			events: Default::default(),
+			execution_cost: Default::default(),
+			fee: Default::default(),
+			updates: Default::default(),
+			queried_heights: Default::default(),
+			submitted: Default::default(),
+			consensus_updates: Default::default(),
 		}
 	}
 }
@@ -48,6 +76,26 @@ impl<T: Codec + Send + Sync> HyperbridgeClaim for MockHost<T> {
 		Err(anyhow!("Unimplemented"))
 	}
 
+	async fn withdraw_funds_batch(
+		&self,
+		_client: Arc<dyn IsmpProvider>,
+		_chains: Vec<StateMachine>,
+	) -> anyhow::Result<Vec<WithdrawFundsResult>> {
+		Err(anyhow!("Unimplemented"))
+	}
+
+	async fn set_beneficiary(
+		&self,
+		_client: Arc<dyn IsmpProvider>,
+		_beneficiary: Vec<u8>,
+	) -> anyhow::Result<()> {
+		Err(anyhow!("Unimplemented"))
+	}
+
+	async fn beneficiary(&self, _address: Vec<u8>) -> anyhow::Result<Option<Vec<u8>>> {
+		Ok(None)
+	}
+
 	async fn check_claimed(&self, _key: Key) -> anyhow::Result<bool> {
 		Ok(false)
 	}
@@ -64,6 +112,14 @@ impl<C: Codec + Send + Sync> ByzantineHandler for MockHost<C> {
 		Err(anyhow!("No byzantine faults"))
 	}
 
+	async fn query_state_root(
+		&self,
+		_coprocessor: StateMachine,
+		_height: u64,
+	) -> Result<Option<H256>, Error> {
+		Err(anyhow!("No byzantine faults"))
+	}
+
 	async fn state_machine_updates(
 		&self,
 		_counterparty_state_id: StateMachineId,
@@ -97,6 +153,22 @@ impl<C: Codec + Send + Sync> IsmpProvider for MockHost<C> {
 		Ok(self.consensus_state.lock().unwrap().encode())
 	}
 
+	async fn query_consensus_updates(
+		&self,
+		_consensus_state_id: ConsensusStateId,
+		from: u64,
+		to: u64,
+	) -> Result<Vec<Vec<u8>>, Error> {
+		Ok(self
+			.consensus_updates
+			.lock()
+			.unwrap()
+			.iter()
+			.filter(|(height, _)| (from..=to).contains(height))
+			.map(|(_, proof)| proof.clone())
+			.collect())
+	}
+
 	async fn query_latest_height(&self, _id: StateMachineId) -> Result<u32, Error> {
 		Ok(*self.latest_height.lock().unwrap() as u32)
 	}
@@ -155,10 +227,11 @@ impl<C: Codec + Send + Sync> IsmpProvider for MockHost<C> {
 
 	async fn query_ismp_events(
 		&self,
-		_previous_height: u64,
+		previous_height: u64,
 		_event: StateMachineUpdated,
 	) -> Result<Vec<Event>, Error> {
-		todo!()
+		self.queried_heights.lock().unwrap().push(previous_height);
+		Ok(self.events.lock().unwrap().clone())
 	}
 
 	fn name(&self) -> String {
@@ -179,20 +252,28 @@ impl<C: Codec + Send + Sync> IsmpProvider for MockHost<C> {
 
 	async fn estimate_gas(
 		&self,
-		_msg: Vec<Message>,
+		msg: Vec<Message>,
 	) -> Result<Vec<EstimateGasReturnParams>, anyhow::Error> {
-		todo!()
+		Ok(msg
+			.iter()
+			.map(|_| EstimateGasReturnParams {
+				execution_cost: self.execution_cost,
+				gas: U256::zero(),
+				successful_execution: true,
+			})
+			.collect())
 	}
 
 	async fn query_request_fee_metadata(&self, _hash: H256) -> Result<U256, anyhow::Error> {
-		todo!()
+		Ok(self.fee)
 	}
 
 	async fn state_machine_update_notification(
 		&self,
 		_counterparty_state_id: StateMachineId,
 	) -> Result<BoxStream<StateMachineUpdated>, Error> {
-		todo!()
+		let updates = self.updates.lock().unwrap().clone();
+		Ok(Box::pin(futures::stream::iter(updates.into_iter().map(Ok))))
 	}
 
 	async fn state_commitment_vetoed_notification(
@@ -203,8 +284,9 @@ impl<C: Codec + Send + Sync> IsmpProvider for MockHost<C> {
 		todo!()
 	}
 
-	async fn submit(&self, _messages: Vec<Message>) -> Result<Vec<TxReceipt>, Error> {
-		todo!()
+	async fn submit(&self, messages: Vec<Message>) -> Result<Vec<TxReceipt>, Error> {
+		self.submitted.lock().unwrap().push(messages);
+		Err(anyhow!("Messages can not be submitted to the mock host"))
 	}
 
 	fn request_commitment_full_key(&self, _commitment: H256) -> Vec<Vec<u8>> {
@@ -227,7 +309,7 @@ impl<C: Codec + Send + Sync> IsmpProvider for MockHost<C> {
 		Default::default()
 	}
 
-	fn sign(&self, _msg: &[u8]) -> Signature {
+	async fn sign(&self, _msg: &[u8]) -> Result<Signature, anyhow::Error> {
 		todo!()
 	}
 
@@ -246,7 +328,7 @@ impl<C: Codec + Send + Sync> IsmpProvider for MockHost<C> {
 	}
 
 	async fn query_response_fee_metadata(&self, _hash: H256) -> Result<U256, Error> {
-		todo!()
+		Ok(self.fee)
 	}
 
 	async fn veto_state_commitment(&self, _height: StateMachineHeight) -> Result<(), Error> {
@@ -275,6 +357,112 @@ impl<C: Send + Sync> Clone for MockHost<C> {
 			consensus_state: self.consensus_state.clone(),
 			latest_height: self.latest_height.clone(),
 			state_machine: self.state_machine.clone(),
+			events: self.events.clone(),
+			execution_cost: self.execution_cost,
+			fee: self.fee,
+			updates: self.updates.clone(),
+			queried_heights: self.queried_heights.clone(),
 		}
 	}
 }
+
+/// A local stand-in for a remote signing service. It serves the
+/// [`RemoteSigner`](crate::signer::RemoteSigner) protocol using an in-memory key.
+pub struct MockRemoteSigner {
+	/// Base url the mock is listening on
+	pub url: String,
+	/// The server task
+	task: tokio::task::JoinHandle<()>,
+}
+
+impl MockRemoteSigner {
+	/// Start serving signing requests for this signer on a random local port
+	pub async fn spawn(signer: LocalSigner) -> Result<Self, anyhow::Error> {
+		let public_key = signer.public_key();
+		Self::spawn_for(signer, public_key).await
+	}
+
+	/// Start serving signing requests for the account with the given public key on a random
+	/// local port, signing them with `signer`
+	pub async fn spawn_for(
+		signer: LocalSigner,
+		public_key: Vec<u8>,
+	) -> Result<Self, anyhow::Error> {
+		let listener = TcpListener::bind("127.0.0.1:0").await?;
+		let url = format!("http://{}", listener.local_addr()?);
+		let signer = Arc::new(signer);
+		let path = format!("/api/v1/sign/0x{}", hex::encode(public_key));
+		let task = tokio::spawn(async move {
+			while let Ok((stream, _)) = listener.accept().await {
+				let signer = signer.clone();
+				let path = path.clone();
+				tokio::spawn(async move {
+					if let Err(err) = serve_sign_request(stream, signer, &path).await {
+						log::error!("Mock remote signer failed to serve request: {err:?}");
+					}
+				});
+			}
+		});
+
+		Ok(Self { url, task })
+	}
+}
+
+impl Drop for MockRemoteSigner {
+	fn drop(&mut self) {
+		self.task.abort();
+	}
+}
+
+async fn serve_sign_request(
+	mut stream: TcpStream,
+	signer: Arc<LocalSigner>,
+	expected: &str,
+) -> Result<(), Error> {
+	let mut buffer = vec![];
+	let mut chunk = [0u8; 1024];
+	let (head_len, content_length) = loop {
+		let read = stream.read(&mut chunk).await?;
+		if read == 0 {
+			return Ok(())
+		}
+		buffer.extend_from_slice(&chunk[..read]);
+		if let Some(pos) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+			let head = String::from_utf8_lossy(&buffer[..pos]).to_string();
+			let content_length = head
+				.lines()
+				.filter_map(|line| line.split_once(':'))
+				.find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+				.map(|(_, value)| value.trim().parse::<usize>())
+				.transpose()?
+				.unwrap_or_default();
+			break (pos + 4, content_length)
+		}
+	};
+	while buffer.len() < head_len + content_length {
+		let read = stream.read(&mut chunk).await?;
+		if read == 0 {
+			Err(anyhow!("Connection closed before the request body was received"))?
+		}
+		buffer.extend_from_slice(&chunk[..read]);
+	}
+
+	let head = String::from_utf8_lossy(&buffer[..head_len]).to_string();
+	let path = head.split_whitespace().nth(1).unwrap_or_default();
+	let (status, body) = if path == expected {
+		let request: SignRequest =
+			serde_json::from_slice(&buffer[head_len..head_len + content_length])?;
+		let signature = signer.sign(&from_hex(&request.data)?).await?;
+		("200 OK", format!("0x{}", hex::encode(signature)))
+	} else {
+		("404 Not Found", String::new())
+	};
+
+	let response = format!(
+		"HTTP/1.1 {status}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+		body.len()
+	);
+	stream.write_all(response.as_bytes()).await?;
+
+	Ok(())
+}
@@ -0,0 +1,415 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Signer backends for relayer accounts
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use sp_core::{bytes::from_hex, ecdsa, sr25519, Pair};
+use std::sync::Arc;
+
+/// The signature scheme of a relayer account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyType {
+	/// Used by evm chains
+	Secp256k1,
+	/// Used by substrate chains
+	Sr25519,
+}
+
+/// Signs messages on behalf of a relayer account
+#[async_trait::async_trait]
+pub trait Signer: Send + Sync {
+	/// The signature scheme of this signer
+	fn key_type(&self) -> KeyType;
+
+	/// The public key of the relayer account. This is the 33 byte compressed public key for
+	/// secp256k1 signers and the 32 byte public key for sr25519 signers.
+	fn public_key(&self) -> Vec<u8>;
+
+	/// Sign a message. Secp256k1 signers expect a 32 byte prehashed message and return a 65 byte
+	/// recoverable signature in the `r, s, v` format, where `v` is the recovery id. Sr25519
+	/// signers sign the message as is and return a 64 byte signature.
+	async fn sign(&self, message: &[u8]) -> anyhow::Result<Vec<u8>>;
+}
+
+/// Configuration for the signer of a relayer account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SignerConfig {
+	/// Hex-encoded private key, or the path to a file that contains it
+	PrivateKey(String),
+	/// Any of the other signer backends
+	Backend(SignerBackend),
+}
+
+impl Default for SignerConfig {
+	fn default() -> Self {
+		SignerConfig::PrivateKey(Default::default())
+	}
+}
+
+/// Signer backends that don't require the private key in the configuration file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SignerBackend {
+	/// Encrypted JSON keystore in the web3 secret storage format
+	Keystore {
+		/// Path to the keystore file
+		path: String,
+		/// Name of the environment variable that holds the passphrase
+		passphrase_env: Option<String>,
+		/// Path to a file that holds the passphrase
+		passphrase_file: Option<String>,
+	},
+	/// A remote signer reachable over http, see [`RemoteSigner`]
+	Remote {
+		/// Base url of the remote signer
+		url: String,
+		/// Hex-encoded public key of the relayer account on the remote signer
+		public_key: String,
+	},
+}
+
+impl SignerConfig {
+	/// Create the signer described by this config
+	pub async fn into_signer(self, key_type: KeyType) -> anyhow::Result<Arc<dyn Signer>> {
+		let signer: Arc<dyn Signer> = match self {
+			SignerConfig::PrivateKey(key) => {
+				let seed = match from_hex(key.as_str()) {
+					Ok(bytes) => bytes,
+					Err(_) => {
+						// it's probably a file.
+						let contents = tokio::fs::read_to_string(key.as_str()).await?;
+						from_hex(contents.trim())?
+					},
+				};
+				Arc::new(LocalSigner::from_seed(key_type, &seed)?)
+			},
+			SignerConfig::Backend(SignerBackend::Keystore {
+				path,
+				passphrase_env,
+				passphrase_file,
+			}) => {
+				let passphrase = match (passphrase_env, passphrase_file) {
+					(Some(var), _) => std::env::var(&var)
+						.map_err(|_| anyhow!("Keystore passphrase variable {var} is not set"))?,
+					(None, Some(file)) => tokio::fs::read_to_string(&file)
+						.await?
+						.trim_end_matches(&['\r', '\n'][..])
+						.to_string(),
+					(None, None) => Err(anyhow!(
+						"Either passphrase_env or passphrase_file is required for keystore {path}"
+					))?,
+				};
+				let seed = eth_keystore::decrypt_key(&path, passphrase)
+					.map_err(|err| anyhow!("Failed to decrypt keystore {path}: {err:?}"))?;
+				Arc::new(LocalSigner::from_seed(key_type, &seed)?)
+			},
+			SignerConfig::Backend(SignerBackend::Remote { url, public_key }) =>
+				Arc::new(RemoteSigner::new(url, key_type, from_hex(&public_key)?)),
+		};
+
+		Ok(signer)
+	}
+}
+
+/// A signer that holds the private key in memory
+#[derive(Clone)]
+pub enum LocalSigner {
+	/// A secp256k1 key pair
+	Secp256k1(ecdsa::Pair),
+	/// A sr25519 key pair
+	Sr25519(sr25519::Pair),
+}
+
+impl LocalSigner {
+	/// Create a signer from the 32 byte seed of the private key
+	pub fn from_seed(key_type: KeyType, seed: &[u8]) -> anyhow::Result<Self> {
+		let signer = match key_type {
+			KeyType::Secp256k1 => LocalSigner::Secp256k1(
+				ecdsa::Pair::from_seed_slice(seed).map_err(|err| anyhow!("{err:?}"))?,
+			),
+			KeyType::Sr25519 => LocalSigner::Sr25519(
+				sr25519::Pair::from_seed_slice(seed).map_err(|err| anyhow!("{err:?}"))?,
+			),
+		};
+
+		Ok(signer)
+	}
+}
+
+#[async_trait::async_trait]
+impl Signer for LocalSigner {
+	fn key_type(&self) -> KeyType {
+		match self {
+			LocalSigner::Secp256k1(_) => KeyType::Secp256k1,
+			LocalSigner::Sr25519(_) => KeyType::Sr25519,
+		}
+	}
+
+	fn public_key(&self) -> Vec<u8> {
+		match self {
+			LocalSigner::Secp256k1(pair) => pair.public().0.to_vec(),
+			LocalSigner::Sr25519(pair) => pair.public().0.to_vec(),
+		}
+	}
+
+	async fn sign(&self, message: &[u8]) -> anyhow::Result<Vec<u8>> {
+		let signature = match self {
+			LocalSigner::Secp256k1(pair) => {
+				let hash: [u8; 32] = message
+					.try_into()
+					.map_err(|_| anyhow!("Secp256k1 signers expect a 32 byte message hash"))?;
+				pair.sign_prehashed(&hash).0.to_vec()
+			},
+			LocalSigner::Sr25519(pair) => pair.sign(message).0.to_vec(),
+		};
+
+		Ok(signature)
+	}
+}
+
+/// Request body of the remote signer's signing endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignRequest {
+	/// Hex-encoded message to be signed
+	pub data: String,
+}
+
+/// A signer that delegates to a remote signing service over http, in the style of web3signer.
+///
+/// Signatures are requested with `POST {url}/api/v1/sign/{public_key}`, where the public key is
+/// hex-encoded, using a json body of `{ "data": "0x..." }`. The service must reply with the
+/// hex-encoded signature as plain text. The message and signature formats are the same as for
+/// [`Signer::sign`], except that `v` may also be offset by 27. Signatures are verified against
+/// the public key of the account before they are returned.
+pub struct RemoteSigner {
+	/// Base url of the remote signer
+	url: String,
+	/// The signature scheme of the account
+	key_type: KeyType,
+	/// The public key of the account
+	public_key: Vec<u8>,
+	/// Http client
+	client: reqwest::Client,
+}
+
+impl RemoteSigner {
+	pub fn new(url: String, key_type: KeyType, public_key: Vec<u8>) -> Self {
+		Self {
+			url: url.trim_end_matches('/').to_string(),
+			key_type,
+			public_key,
+			client: reqwest::Client::new(),
+		}
+	}
+}
+
+#[async_trait::async_trait]
+impl Signer for RemoteSigner {
+	fn key_type(&self) -> KeyType {
+		self.key_type
+	}
+
+	fn public_key(&self) -> Vec<u8> {
+		self.public_key.clone()
+	}
+
+	async fn sign(&self, message: &[u8]) -> anyhow::Result<Vec<u8>> {
+		let url = format!("{}/api/v1/sign/0x{}", self.url, hex::encode(&self.public_key));
+		let response = self
+			.client
+			.post(&url)
+			.json(&SignRequest { data: format!("0x{}", hex::encode(message)) })
+			.send()
+			.await?
+			.error_for_status()?
+			.text()
+			.await?;
+		let mut signature = from_hex(response.trim())?;
+
+		let expected = match self.key_type {
+			KeyType::Secp256k1 => 65,
+			KeyType::Sr25519 => 64,
+		};
+		if signature.len() != expected {
+			Err(anyhow!(
+				"Remote signer returned a {} byte signature, expected {expected} bytes",
+				signature.len()
+			))?
+		}
+
+		let valid = match self.key_type {
+			KeyType::Secp256k1 => {
+				// normalize `v` to the recovery id
+				if signature[64] >= 27 {
+					signature[64] -= 27;
+				}
+				let hash: [u8; 32] = message
+					.try_into()
+					.map_err(|_| anyhow!("Secp256k1 signers expect a 32 byte message hash"))?;
+				let signature = ecdsa::Signature::from_raw(
+					signature.clone().try_into().expect("Signature length is checked above; qed"),
+				);
+				signature
+					.recover_prehashed(&hash)
+					.map_or(false, |public| public.0.as_slice() == self.public_key.as_slice())
+			},
+			KeyType::Sr25519 => match <[u8; 32]>::try_from(self.public_key.as_slice()) {
+				Ok(public) => sr25519::Pair::verify(
+					&sr25519::Signature::from_raw(
+						signature
+							.clone()
+							.try_into()
+							.expect("Signature length is checked above; qed"),
+					),
+					message,
+					&sr25519::Public::from_raw(public),
+				),
+				Err(_) => false,
+			},
+		};
+		if !valid {
+			Err(anyhow!(
+				"Remote signer returned a signature that was not signed by 0x{}",
+				hex::encode(&self.public_key)
+			))?
+		}
+
+		Ok(signature)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mocks::MockRemoteSigner;
+	use sp_core::keccak_256;
+
+	const SEED: [u8; 32] = [7u8; 32];
+
+	#[tokio::test]
+	async fn remote_signer_should_match_local_signer() -> anyhow::Result<()> {
+		let message = keccak_256(b"hello world");
+
+		let local = LocalSigner::from_seed(KeyType::Secp256k1, &SEED)?;
+		let mock = MockRemoteSigner::spawn(local.clone()).await?;
+		let remote = SignerConfig::Backend(SignerBackend::Remote {
+			url: mock.url.clone(),
+			public_key: format!("0x{}", hex::encode(local.public_key())),
+		})
+		.into_signer(KeyType::Secp256k1)
+		.await?;
+		assert_eq!(remote.public_key(), local.public_key());
+		// ecdsa signatures are deterministic
+		assert_eq!(remote.sign(&message).await?, local.sign(&message).await?);
+
+		let local = LocalSigner::from_seed(KeyType::Sr25519, &SEED)?;
+		let mock = MockRemoteSigner::spawn(local.clone()).await?;
+		let remote = RemoteSigner::new(mock.url.clone(), KeyType::Sr25519, local.public_key());
+		let signature: [u8; 64] = remote.sign(b"hello world").await?.try_into().unwrap();
+		let LocalSigner::Sr25519(pair) = local else { unreachable!() };
+		assert!(sr25519::Pair::verify(
+			&sr25519::Signature::from_raw(signature),
+			b"hello world",
+			&pair.public()
+		));
+
+		// unknown accounts are rejected
+		let unknown = RemoteSigner::new(mock.url.clone(), KeyType::Sr25519, vec![1u8; 32]);
+		assert!(unknown.sign(b"hello world").await.is_err());
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn remote_signer_should_reject_signatures_of_other_accounts() -> anyhow::Result<()> {
+		let message = keccak_256(b"hello world");
+
+		for key_type in [KeyType::Secp256k1, KeyType::Sr25519] {
+			let account = LocalSigner::from_seed(key_type, &SEED)?;
+			let other = LocalSigner::from_seed(key_type, &[8u8; 32])?;
+			// the remote signer signs with the wrong key for this account
+			let mock = MockRemoteSigner::spawn_for(other, account.public_key()).await?;
+			let remote = RemoteSigner::new(mock.url.clone(), key_type, account.public_key());
+			let err = remote.sign(&message).await.unwrap_err();
+			assert!(err.to_string().contains("not signed by"), "{err:?}");
+		}
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn should_decrypt_keystore() -> anyhow::Result<()> {
+		let dir = std::env::temp_dir().join("tesseract-keystore-test");
+		std::fs::create_dir_all(&dir)?;
+		eth_keystore::encrypt_key(
+			&dir,
+			&mut rand::thread_rng(),
+			SEED,
+			"passphrase",
+			Some("relayer.json"),
+		)?;
+		let path = dir.join("relayer.json").to_string_lossy().to_string();
+		let expected = LocalSigner::from_seed(KeyType::Secp256k1, &SEED)?.public_key();
+
+		std::env::set_var("TESSERACT_KEYSTORE_TEST_PASSPHRASE", "passphrase");
+		let signer = SignerConfig::Backend(SignerBackend::Keystore {
+			path: path.clone(),
+			passphrase_env: Some("TESSERACT_KEYSTORE_TEST_PASSPHRASE".to_string()),
+			passphrase_file: None,
+		})
+		.into_signer(KeyType::Secp256k1)
+		.await?;
+		assert_eq!(signer.public_key(), expected);
+
+		let passphrase_file = dir.join("passphrase.txt");
+		std::fs::write(&passphrase_file, "passphrase\n")?;
+		let signer = SignerConfig::Backend(SignerBackend::Keystore {
+			path: path.clone(),
+			passphrase_env: None,
+			passphrase_file: Some(passphrase_file.to_string_lossy().to_string()),
+		})
+		.into_signer(KeyType::Secp256k1)
+		.await?;
+		assert_eq!(signer.public_key(), expected);
+
+		std::fs::write(&passphrase_file, "wrong")?;
+		let result = SignerConfig::Backend(SignerBackend::Keystore {
+			path,
+			passphrase_env: None,
+			passphrase_file: Some(passphrase_file.to_string_lossy().to_string()),
+		})
+		.into_signer(KeyType::Secp256k1)
+		.await;
+		assert!(result.is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn should_accept_plain_private_keys_in_config() {
+		let config: SignerConfig = serde_json::from_str(r#""0x0707""#).unwrap();
+		assert!(matches!(config, SignerConfig::PrivateKey(key) if key == "0x0707"));
+
+		let config: SignerConfig = serde_json::from_str(
+			r#"{ "type": "remote", "url": "http://127.0.0.1:9000", "public_key": "0x01" }"#,
+		)
+		.unwrap();
+		assert!(matches!(config, SignerConfig::Backend(SignerBackend::Remote { .. })));
+	}
+}
@@ -15,19 +15,25 @@
 
 //! Tesseract CLI utilities
 
-use crate::{config::HyperbridgeConfig, fees, fees::Subcommand, logging};
+use crate::{config::HyperbridgeConfig, fees::Subcommand, logging, tasks::RelayerTasks};
 use anyhow::{anyhow, Context};
 use clap::Parser;
 use codec::Encode;
-use ethers::prelude::H160;
 use futures::FutureExt;
 use ismp::host::StateMachine;
 use rust_socketio::asynchronous::ClientBuilder;
 use sc_service::TaskManager;
 use sp_core::{ecdsa, ByteArray, Pair};
-use std::{collections::HashMap, sync::Arc};
+use std::{
+	collections::HashMap,
+	net::{Ipv4Addr, SocketAddr},
+	str::FromStr,
+	sync::Arc,
+};
+use substrate_prometheus_endpoint::Registry;
 use telemetry_server::Message;
-use tesseract_primitives::IsmpProvider;
+use tesseract_messaging::{metrics::Metrics, simulation::DryRun};
+use tesseract_primitives::{IsmpProvider, TransactionStore};
 use tesseract_substrate::{config::KeccakSubstrateChain, SubstrateClient};
 use transaction_fees::TransactionPayment;
 
@@ -45,6 +51,38 @@ pub struct Cli {
 	/// e.g /home/root/dev.db
 	#[arg(short, long)]
 	pub db: String,
+
+	/// Serve prometheus metrics at `/metrics` on this port
+	#[arg(long)]
+	pub prometheus_port: Option<u16>,
+
+	/// Expose the prometheus endpoint on all interfaces instead of only localhost
+	#[arg(long)]
+	pub prometheus_external: bool,
+
+	/// Simulate the relayer without submitting any transactions, the messages it would deliver
+	/// and their profitability are written to this report file
+	#[arg(long, value_name = "REPORT")]
+	pub dry_run: Option<String>,
+
+	/// Move the checkpoints of the routes from a chain back by a number of blocks before
+	/// starting, so that recent events are scanned again. Chains have different block times, so
+	/// this is given per chain and can be repeated, eg `--rewind EVM-97=1000`
+	#[arg(long, value_name = "CHAIN=BLOCKS", value_parser = parse_rewind)]
+	pub rewind: Vec<(StateMachine, u64)>,
+}
+
+/// Parse a `CHAIN=BLOCKS` rewind argument
+fn parse_rewind(value: &str) -> Result<(StateMachine, u64), String> {
+	let (chain, blocks) = value
+		.split_once('=')
+		.ok_or_else(|| format!("Expected CHAIN=BLOCKS, found {value}"))?;
+	let chain = StateMachine::from_str(chain)
+		.map_err(|err| format!("Invalid state machine {chain}: {err}"))?;
+	let blocks = blocks
+		.parse()
+		.map_err(|err| format!("Invalid number of blocks {blocks}: {err}"))?;
+	Ok((chain, blocks))
 }
 
 impl Cli {
@@ -55,9 +93,37 @@ impl Cli {
 		let config = HyperbridgeConfig::parse_conf(&self.config).await?;
 		let HyperbridgeConfig { hyperbridge: hyperbridge_config, relayer, .. } = config.clone();
 
-		let mut metadata = vec![];
+		let registry = self.prometheus_port.map(|_| Registry::new());
+		let metrics = registry.as_ref().map(Metrics::register).transpose()?;
 		let tokio_handle = tokio::runtime::Handle::current();
-		let mut task_manager = TaskManager::new(tokio_handle, None)?;
+		let mut task_manager = TaskManager::new(tokio_handle, registry.as_ref())?;
+
+		if let Some((port, registry)) = self.prometheus_port.zip(registry) {
+			let interface =
+				if self.prometheus_external { Ipv4Addr::UNSPECIFIED } else { Ipv4Addr::LOCALHOST };
+			let address = SocketAddr::new(interface.into(), port);
+			task_manager.spawn_handle().spawn(
+				"prometheus-endpoint",
+				"metrics",
+				async move {
+					if let Err(err) =
+						substrate_prometheus_endpoint::init_prometheus(address, registry).await
+					{
+						tracing::error!("Prometheus endpoint has terminated with {err:?}");
+					}
+				}
+				.boxed(),
+			);
+			log::info!("📈 Serving metrics on http://{address}/metrics");
+		}
+
+		let dry_run = match self.dry_run {
+			Some(ref path) => {
+				log::info!("🧪 Running in dry run mode, messages will be recorded to {path}");
+				Some(DryRun::open(path).await?)
+			},
+			None => None,
+		};
 
 		if relayer.minimum_profit_percentage == 0 {
 			log::warn!(
@@ -70,71 +136,35 @@ impl Cli {
 				.await
 				.map_err(|err| anyhow!("Error initializing database: {err:?}"))?,
 		);
+		for (chain, blocks) in self.rewind.iter().copied() {
+			tx_payment
+				.rewind_checkpoints(chain, blocks)
+				.await
+				.map_err(|err| anyhow!("Error rewinding checkpoints: {err:?}"))?;
+			log::info!("⏪ Rewound the checkpoints of the routes from {chain} by {blocks} blocks");
+		}
 		// Add hyperbridge to the client map
 		let hyperbridge =
 			SubstrateClient::<KeccakSubstrateChain>::new(hyperbridge_config.clone()).await?;
-		let mut clients = create_client_map(config.clone(), Arc::new(hyperbridge.clone())).await?;
+		// Pending transactions from a previous run are rebroadcast once the store is attached,
+		// which must not happen in a dry run
+		let store = dry_run.is_none().then(|| tx_payment.clone() as Arc<dyn TransactionStore>);
+		let mut clients =
+			create_client_map(config.clone(), Arc::new(hyperbridge.clone()), store.clone()).await?;
 		clients.insert(hyperbridge.state_machine_id().state_id, Arc::new(hyperbridge.clone()));
 
-		if config.relayer.delivery_endpoints.is_empty() {
-			log::warn!(
-				"Delivery endpoints not specified in relayer config, will deliver to all chains."
-			);
-		}
-
-		// messaging tasks
-		for (state_machine, client) in &clients {
-			// If the delivery endpoint is not empty then we only spawn tasks for chains
-			// explicitly mentioned in the config
-			if !config.relayer.delivery_endpoints.is_empty() &&
-				!config.relayer.delivery_endpoints.contains(&state_machine.to_string())
-			{
-				continue;
-			}
-
-			let mut new_hyperbridge =
-				SubstrateClient::<KeccakSubstrateChain>::new(hyperbridge_config.clone()).await?;
-			new_hyperbridge.set_latest_finalized_height(client.clone()).await?;
-
-			let coprocessor = hyperbridge_config.state_machine;
-
-			tesseract_messaging::relay(
-				new_hyperbridge.clone(),
-				client.clone(),
-				relayer.clone(),
-				coprocessor,
-				tx_payment.clone(),
-				clients.clone(),
-				&task_manager,
-			)
-			.await?;
-
-			if relayer.fisherman.unwrap_or_default() {
-				tesseract_fisherman::fish(
-					Arc::new(new_hyperbridge),
-					client.clone(),
-					&task_manager,
-					coprocessor,
-				)
-				.await?
-			}
-
-			metadata.push((
-				state_machine.clone(),
-				H160::from_slice(&client.address().as_slice()[..20]),
-			));
-		}
-
-		task_manager.spawn_essential_handle().spawn(
-			"auto-withdraw",
-			"fees",
-			async move {
-				let _ =
-					fees::auto_withdraw(hyperbridge, clients, config.relayer.clone(), tx_payment)
-						.await;
-			}
-			.boxed(),
+		let mut tasks = RelayerTasks::new(
+			self.config.clone(),
+			config,
+			hyperbridge,
+			clients,
+			tx_payment,
+			store,
+			metrics,
+			dry_run,
 		);
+		tasks.start().await?;
+		let metadata = tasks.metadata();
 
 		log::info!("💬 Initialized messaging tasks");
 
@@ -171,7 +201,7 @@ impl Cli {
 			}
 		};
 
-		task_manager.future().await?;
+		tasks.run(&mut task_manager).await?;
 
 		if let Some(socket) = socket {
 			socket.disconnect().await?;
@@ -184,13 +214,14 @@ impl Cli {
 pub async fn create_client_map(
 	config: HyperbridgeConfig,
 	hyperbridge: Arc<dyn IsmpProvider>,
+	store: Option<Arc<dyn TransactionStore>>,
 ) -> anyhow::Result<HashMap<StateMachine, Arc<dyn IsmpProvider>>> {
 	let HyperbridgeConfig { chains, .. } = config.clone();
 	let mut clients = HashMap::new();
 
 	for (state_machine, config) in chains {
 		let client = config
-			.into_client(hyperbridge.clone())
+			.into_client(hyperbridge.clone(), store.clone())
 			.await
 			.context(format!("Failed to create client for {state_machine:?}"))?;
 		clients.insert(state_machine, client);
@@ -44,25 +44,25 @@ impl HyperbridgeConfig {
 			.map_err(|err| anyhow!("Error occured while reading config file: {err:?}"))?;
 		let table = toml.parse::<Table>()?;
 		let mut chains: HashMap<StateMachine, AnyConfig> = HashMap::new();
-		if !table.contains_key(HYPERRIDGE) || !table.contains_key(RELAYER) {
-			Err(anyhow!("Missing Hyperbridge or Relayer Config, Check your toml file"))?
-		}
 
 		let hyperbridge: SubstrateConfig = table
 			.get(HYPERRIDGE)
 			.cloned()
-			.expect("Hyperbridge Config is Present")
+			.ok_or_else(|| anyhow!("Missing Hyperbridge Config, Check your toml file"))?
 			.try_into()
-			.expect("Failed to parse hyperbridge config");
+			.map_err(|err| anyhow!("Failed to parse hyperbridge config: {err:?}"))?;
 		let relayer: RelayerConfig = table
 			.get(RELAYER)
 			.cloned()
-			.expect("Relayer Config is Present")
+			.ok_or_else(|| anyhow!("Missing Relayer Config, Check your toml file"))?
 			.try_into()
-			.expect("Failed to parse relayer config");
+			.map_err(|err| anyhow!("Failed to parse relayer config: {err:?}"))?;
+		relayer.validate()?;
 		for (key, val) in table {
 			if &key != HYPERRIDGE && key != RELAYER {
-				let any_conf: AnyConfig = val.try_into().unwrap();
+				let any_conf: AnyConfig = val
+					.try_into()
+					.map_err(|err| anyhow!("Failed to parse {key} config: {err:?}"))?;
 				chains.insert(any_conf.state_machine(), any_conf);
 			}
 		}
@@ -75,3 +75,18 @@ async fn test_parsing() {
 	let config = HyperbridgeConfig::parse_conf("../test-config.toml").await.unwrap();
 	dbg!(config);
 }
+
+#[tokio::test]
+async fn should_reject_configs_without_a_relayer_section() {
+	let path = std::env::temp_dir().join("tesseract-missing-relayer.toml");
+	let config = r#"
+[hyperbridge]
+state_machine = "KUSAMA-4009"
+hashing = "Keccak"
+rpc_ws = "ws://127.0.0.1:9933"
+signer = ""
+"#;
+	tokio::fs::write(&path, config).await.unwrap();
+	let err = HyperbridgeConfig::parse_conf(path.to_str().unwrap()).await.unwrap_err();
+	assert!(err.to_string().contains("Missing Relayer Config"));
+}
@@ -1,4 +1,4 @@
-use crate::{config::HyperbridgeConfig, create_client_map, logging};
+use crate::{config::HyperbridgeConfig, create_client_map, logging, pnl::ProfitAndLoss};
 use anyhow::anyhow;
 use ethers::providers::interval;
 use futures::StreamExt;
@@ -8,11 +8,17 @@ use ismp::{
 	messaging::{hash_request, Message, Proof, RequestMessage},
 	router::Request,
 };
+use pallet_ismp_relayer::withdrawal::MAX_WITHDRAWAL_BATCH_SIZE;
 use sp_core::U256;
 use std::{collections::HashMap, str::FromStr, sync::Arc, time::Duration};
+use tesseract_messaging::{
+	metrics::{to_usd, Metrics},
+	DeliveryLock,
+};
 use tesseract_primitives::{
-	config::RelayerConfig, observe_challenge_period, wait_for_state_machine_update, Cost, Hasher,
-	HyperbridgeClaim, IsmpProvider, Query, WithdrawFundsResult,
+	config::RelayerConfig, observe_challenge_period, signer::SignerConfig,
+	wait_for_state_machine_update, Cost, Hasher, HyperbridgeClaim, IsmpProvider, Query,
+	WithdrawFundsResult,
 };
 use tesseract_substrate::config::KeccakSubstrateChain;
 use tracing::instrument;
@@ -22,6 +28,12 @@ use transaction_fees::TransactionPayment;
 pub enum Subcommand {
 	/// Withdraw fees on hyperbridge
 	AccumulateFees(AccumulateFees),
+	/// Report the profit and loss of deliveries and withdrawals
+	Pnl(ProfitAndLoss),
+	/// Credit the fees earned by a relayer address on every chain to another address
+	SetBeneficiary(SetBeneficiary),
+	/// Withdraw the fees credited to a beneficiary, signed with the key of the beneficiary
+	WithdrawBeneficiaryFees(WithdrawBeneficiaryFees),
 }
 
 #[derive(Debug, clap::Parser)]
@@ -42,6 +54,28 @@ pub struct AccumulateFees {
 	pub wait: bool,
 }
 
+#[derive(Debug, clap::Parser)]
+pub struct SetBeneficiary {
+	/// Chain whose relayer address should credit its fees to the beneficiary. Beneficiaries are
+	/// set per address, so the fees this address earns on every other chain are credited to the
+	/// beneficiary as well.
+	#[arg(short, long)]
+	pub chain: String,
+	/// Hex encoded address of the beneficiary, it must be the same length as the relayer address
+	#[arg(short, long)]
+	pub beneficiary: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct WithdrawBeneficiaryFees {
+	/// Chains to withdraw the fees credited to the beneficiary on
+	#[arg(short, long, required = true)]
+	pub chain: Vec<String>,
+	/// Hex encoded private key of the beneficiary, or the path to a file that contains it
+	#[arg(short, long)]
+	pub signer: String,
+}
+
 impl AccumulateFees {
 	/// Accumulate fees accrued through deliveries from source to dest and dest to source
 
@@ -55,17 +89,21 @@ impl AccumulateFees {
 		)
 		.await?;
 
-		let clients = create_client_map(config, Arc::new(hyperbridge.clone())).await?;
+		let tx_payment = TransactionPayment::initialize(&db).await?;
+		log::info!("Initialized database");
+		let clients = create_client_map(
+			config,
+			Arc::new(hyperbridge.clone()),
+			Some(Arc::new(tx_payment.clone())),
+		)
+		.await?;
 
 		// early return if withdrawing
 		if self.withdraw {
-			let tx_payment = TransactionPayment::initialize(&db).await?;
 			self.withdraw(tx_payment, &hyperbridge, clients).await?;
 			return Ok(());
 		}
 
-		let tx_payment = TransactionPayment::initialize(&db).await?;
-		log::info!("Initialized database");
 		let stream = futures::stream::iter(tx_payment.distinct_deliveries().await?.into_iter());
 		stream.for_each_concurrent(None, |delivery| {
 			let source_chain = StateMachine::from_str(&delivery.source_chain)
@@ -241,13 +279,21 @@ impl AccumulateFees {
 						// lets try to deliver any pending requests in the db
 						let (pending_withdrawals, ids): (Vec<_>, Vec<_>) = tx.pending_withdrawals(&chain).await?.into_iter().unzip();
 						for pending in pending_withdrawals {
-							deliver_post_request(client.clone(), &hyperbridge, pending).await?;
+							deliver_post_request(client.clone(), &hyperbridge, pending.clone()).await?;
+							settle_withdrawal(&tx, &pending).await;
 						}
 						// can this fail?
 						if let Err(e) = tx.delete_pending_withdrawals(ids).await {
 							tracing::error!("Error encountered while deleting pending withdrawals from the db: {e:?}, \n NOTE: The withdrawal request was successfully delivered.");
 						}
 
+						// Fees earned after a beneficiary was set are credited to the beneficiary, the
+						// balance the relayer address accrued before that is still withdrawn here and
+						// paid out to the beneficiary
+						if let Some(beneficiary) = hyperbridge.beneficiary(client.address()).await? {
+							log::info!("Fees earned on {chain} are credited to 0x{}, they are withdrawn with withdraw-beneficiary-fees", hex::encode(beneficiary));
+						}
+
 						let amount = hyperbridge.available_amount(client.clone(), &chain).await?;
 
 						if amount < U256::from(10u128 * 10u128.pow(18)) {
@@ -267,9 +313,13 @@ impl AccumulateFees {
 						// Wait for state machine update
 						// persist the withdrawal in-case delivery fails, so it's not lost forever
 						let ids = tx.store_pending_withdrawals(vec![result.clone()]).await?;
+						if let Err(e) = tx.record_withdrawal(&result, amount).await {
+							tracing::error!("Failed to record withdrawal in the ledger: {e:?}");
+						}
 
 						match deliver_post_request(client.clone(), &hyperbridge, result.clone()).await {
 							Ok(_) => {
+								settle_withdrawal(&tx, &result).await;
 								if let Err(e) = tx.delete_pending_withdrawals(ids).await {
 									tracing::error!("Error encountered while deleting pending withdrawals from the db: {e:?}, \n NOTE: The withdrawal request was successfully delivered.");
 								}
@@ -293,6 +343,103 @@ impl AccumulateFees {
 	}
 }
 
+impl SetBeneficiary {
+	/// Register the beneficiary of the relayer address on the chain. Once registered, only the
+	/// beneficiary can change it.
+	pub async fn set_beneficiary(&self, config_path: String) -> anyhow::Result<()> {
+		logging::setup()?;
+		let config = HyperbridgeConfig::parse_conf(&config_path).await?;
+		let chain = StateMachine::from_str(&self.chain)
+			.map_err(|err| anyhow!("Invalid state machine {}: {err}", self.chain))?;
+		let beneficiary = hex::decode(self.beneficiary.trim_start_matches("0x"))?;
+
+		let hyperbridge = tesseract_substrate::SubstrateClient::<KeccakSubstrateChain>::new(
+			config.hyperbridge.clone(),
+		)
+		.await?;
+		let clients = create_client_map(config, Arc::new(hyperbridge.clone()), None).await?;
+		let client = clients
+			.get(&chain)
+			.cloned()
+			.ok_or_else(|| anyhow!("Client for {chain} not found in config"))?;
+
+		let address = client.address();
+		hyperbridge.set_beneficiary(client, beneficiary.clone()).await?;
+		log::info!(
+			"Fees earned by 0x{} on every chain will be credited to 0x{}",
+			hex::encode(&address),
+			hex::encode(&beneficiary)
+		);
+
+		Ok(())
+	}
+}
+
+impl WithdrawBeneficiaryFees {
+	/// Withdraw the fees credited to the beneficiary on every chain. The withdrawals are signed by
+	/// the beneficiary, while their requests are delivered by the relayer accounts in the config.
+	pub async fn withdraw(&self, config_path: String, db: String) -> anyhow::Result<()> {
+		logging::setup()?;
+		let config = HyperbridgeConfig::parse_conf(&config_path).await?;
+		let chains = self
+			.chain
+			.iter()
+			.map(|chain| {
+				StateMachine::from_str(chain)
+					.map_err(|err| anyhow!("Invalid state machine {chain}: {err}"))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let hyperbridge = tesseract_substrate::SubstrateClient::<KeccakSubstrateChain>::new(
+			config.hyperbridge.clone(),
+		)
+		.await?;
+		let tx_payment = TransactionPayment::initialize(&db).await?;
+		let clients = create_client_map(
+			config.clone(),
+			Arc::new(hyperbridge.clone()),
+			Some(Arc::new(tx_payment.clone())),
+		)
+		.await?;
+
+		for chain in chains {
+			let lambda = || async {
+				let client = clients
+					.get(&chain)
+					.cloned()
+					.ok_or_else(|| anyhow!("Client for {chain} not found in config"))?;
+				let mut beneficiary_config = config.chains[&chain].clone();
+				beneficiary_config.set_signer(SignerConfig::PrivateKey(self.signer.clone()));
+				let beneficiary =
+					beneficiary_config.into_client(Arc::new(hyperbridge.clone()), None).await?;
+
+				let amount = hyperbridge.available_amount(beneficiary.clone(), &chain).await?;
+				if amount.is_zero() {
+					log::info!(
+						"Beneficiary 0x{} has no fees to withdraw on {chain}",
+						hex::encode(beneficiary.address())
+					);
+					return Ok::<_, anyhow::Error>(());
+				}
+
+				log::info!("Submitting withdrawal request to {chain} for amount ${}", Cost(amount));
+				let result = hyperbridge.withdraw_funds(beneficiary, chain).await?;
+				log::info!("Request submitted to hyperbridge successfully");
+				let ids = persist_withdrawal(&tx_payment, &result, amount).await?;
+				deliver_withdrawal(client, &hyperbridge, &tx_payment, None, result, ids).await;
+
+				Ok(())
+			};
+
+			if let Err(e) = lambda().await {
+				log::error!("Failed to withdraw the fees of the beneficiary on {chain}: {e:?}");
+			}
+		}
+
+		Ok(())
+	}
+}
+
 /// For every configured `withdrawal_frequency`, will attempt to withdraw all unclaimed fees on
 /// hyperbridge.
 pub async fn auto_withdraw<C>(
@@ -300,6 +447,8 @@ pub async fn auto_withdraw<C>(
 	clients: HashMap<StateMachine, Arc<dyn IsmpProvider>>,
 	config: RelayerConfig,
 	db: Arc<TransactionPayment>,
+	metrics: Option<Metrics>,
+	withdrawal_lock: DeliveryLock,
 ) -> anyhow::Result<()>
 where
 	C: IsmpProvider + HyperbridgeClaim + Clone,
@@ -319,69 +468,182 @@ where
 	let mut interval = interval(frequency);
 
 	while let Some(_) = interval.next().await {
-		let stream = futures::stream::iter(clients.keys().cloned().into_iter());
-		stream
-			.for_each_concurrent(None, |chain| {
-				let client =
-					clients.get(&chain).expect(&format!("Client not found for {chain}")).clone();
-				let hyperbridge = hyperbridge.clone();
-				let moved_db = db.clone();
-				async move {
-					let lambda = || async {
-						// lets try to deliver any pending requests in the db
-						let (pending_withdrawals, ids): (Vec<_>, Vec<_>) = moved_db.pending_withdrawals(&chain).await?.into_iter().unzip();
-						for pending in pending_withdrawals {
-							deliver_post_request(client.clone(), &hyperbridge, pending).await?;
-						}
-						// can this fail?
-						if let Err(e) = moved_db.delete_pending_withdrawals(ids).await {
-							tracing::error!("Error encountered while deleting pending withdrawals from the db: {e:?}, \n NOTE: The withdrawal request was successfully delivered.");
+		// Deliver pending withdrawals and find the chains with enough unclaimed fees
+		let withdrawable = futures::future::join_all(clients.iter().map(|(chain, client)| {
+			let hyperbridge = hyperbridge.clone();
+			let moved_db = db.clone();
+			let metrics = metrics.clone();
+			async move {
+				let lambda = || async {
+					// lets try to deliver any pending requests in the db
+					let (pending_withdrawals, ids): (Vec<_>, Vec<_>) = moved_db.pending_withdrawals(chain).await?.into_iter().unzip();
+					let pending_gauge = metrics.as_ref().map(|metrics| metrics.pending_withdrawals.with_label_values(&[&client.name()]));
+					if let Some(gauge) = pending_gauge.as_ref() {
+						gauge.set(pending_withdrawals.len() as u64);
+					}
+					for pending in pending_withdrawals {
+						deliver_post_request(client.clone(), &hyperbridge, pending.clone()).await?;
+						settle_withdrawal(&moved_db, &pending).await;
+						if let Some(gauge) = pending_gauge.as_ref() {
+							gauge.dec();
 						}
+					}
+					// can this fail?
+					if let Err(e) = moved_db.delete_pending_withdrawals(ids).await {
+						tracing::error!("Error encountered while deleting pending withdrawals from the db: {e:?}, \n NOTE: The withdrawal request was successfully delivered.");
+					}
 
-						let amount = hyperbridge.available_amount(client.clone(), &chain).await?;
-						if amount < min_amount {
-							tracing::info!("Unclaimed balance {amount} on {chain} is < minimum_withdrawal_amount: {min_amount}, exiting");
-							return Ok::<_, anyhow::Error>(());
-						}
+					// Fees earned after a beneficiary was set are credited to the beneficiary, the
+					// balance the relayer address accrued before that is still withdrawn here and
+					// paid out to the beneficiary
+					if let Some(beneficiary) = hyperbridge.beneficiary(client.address()).await? {
+						tracing::info!("Fees earned on {chain} are credited to 0x{}, they are withdrawn with withdraw-beneficiary-fees", hex::encode(beneficiary));
+					}
 
-						tracing::info!(
-							"Submitting withdrawal request to hyperbridge for amount ${} on {chain}",
-							Cost(amount)
-						);
-						let result = hyperbridge
-							.withdraw_funds(client.clone(), chain)
-							.await?;
-						tracing::info!("Request submitted to hyperbridge successfully");
-						tracing::info!("Starting delivery of withdrawal message to {}", chain);
+					let amount = hyperbridge.available_amount(client.clone(), chain).await?;
+					let unclaimed_gauge = metrics.as_ref().map(|metrics| metrics.unclaimed_fees.with_label_values(&[&client.name()]));
+					if let Some(gauge) = unclaimed_gauge.as_ref() {
+						gauge.set(to_usd(Cost(amount)));
+					}
+					if amount < min_amount {
+						tracing::info!("Unclaimed balance {amount} on {chain} is < minimum_withdrawal_amount: {min_amount}, exiting");
+						return Ok::<_, anyhow::Error>(None);
+					}
 
-						// persist the withdrawal in-case delivery fails, so it's not lost forever
-						let ids = moved_db.store_pending_withdrawals(vec![result.clone()]).await?;
+					Ok(Some((*chain, amount)))
+				};
 
-						match deliver_post_request(client.clone(), &hyperbridge, result.clone()).await {
-							Ok(_) => {
-								if let Err(e) = moved_db.delete_pending_withdrawals(ids).await {
-									tracing::error!("Error encountered while deleting pending withdrawals from the db: {e:?}, \n NOTE: The withdrawal request was successfully delivered.");
-								}
-							},
-							Err(err) => {
-								tracing::info!("Failed to deliver withdrawal request: {err:?}, they will be retried.");
-							}
-						};
-						Ok(())
+				match lambda().await {
+					Ok(withdrawable) => withdrawable,
+					Err(e) => {
+						log::error!("Failed to complete an auto-withdrawal: {e:?}");
+						None
+					},
+				}
+			}
+		}))
+		.await;
+
+		// Chains whose clients share a signer are withdrawn with a single signature
+		let mut batches = HashMap::<Vec<u8>, Vec<(StateMachine, U256)>>::new();
+		for (chain, amount) in withdrawable.into_iter().flatten() {
+			batches.entry(clients[&chain].address()).or_default().push((chain, amount));
+		}
+
+		futures::future::join_all(batches.into_values().map(|batch| {
+			let hyperbridge = hyperbridge.clone();
+			let clients = &clients;
+			let db = &db;
+			let metrics = &metrics;
+			let withdrawal_lock = &withdrawal_lock;
+			async move {
+				// Each chunk is persisted before the next one is submitted, so the withdrawals
+				// that were already submitted are delivered even if a later chunk fails
+				let withdrawal = withdrawal_lock.read().await;
+				let mut submitted = vec![];
+				for chunk in batch.chunks(MAX_WITHDRAWAL_BATCH_SIZE as usize) {
+					let chains = chunk.iter().map(|(chain, _)| *chain).collect::<Vec<_>>();
+					let total =
+						chunk.iter().fold(U256::zero(), |total, (_, amount)| total + *amount);
+					tracing::info!(
+						"Submitting withdrawal request to hyperbridge for amount ${} on {chains:?}",
+						Cost(total)
+					);
+					let signer = clients[&chains[0]].clone();
+					let results = if chains.len() == 1 {
+						hyperbridge
+							.withdraw_funds(signer, chains[0])
+							.await
+							.map(|result| vec![result])
+					} else {
+						hyperbridge.withdraw_funds_batch(signer, chains.clone()).await
 					};
+					let results = match results {
+						Ok(results) => results,
+						Err(e) => {
+							log::error!("Failed to complete an auto-withdrawal: {e:?}");
+							break
+						},
+					};
+					tracing::info!("Request submitted to hyperbridge successfully");
 
-					match lambda().await {
-						Ok(_) => {},
-						Err(e) => log::error!("Failed to complete an auto-withdrawal: {e:?}"),
+					for ((chain, amount), result) in chunk.iter().zip(results) {
+						match persist_withdrawal(db, &result, *amount).await {
+							Ok(ids) => submitted.push((*chain, result, ids)),
+							Err(e) => log::error!("Failed to complete an auto-withdrawal: {e:?}"),
+						}
 					}
 				}
-			})
-			.await;
+
+				// Persisted withdrawals are delivered again if the task is stopped
+				drop(withdrawal);
+
+				futures::future::join_all(submitted.into_iter().map(|(chain, result, ids)| {
+					let client = clients[&chain].clone();
+					deliver_withdrawal(client, &hyperbridge, db, metrics.as_ref(), result, ids)
+				}))
+				.await;
+			}
+		}))
+		.await;
 	}
 
 	Ok(())
 }
 
+/// Persist a withdrawal submitted to hyperbridge in-case delivery fails, so it's not lost
+/// forever, and return the ids of its pending withdrawals
+async fn persist_withdrawal(
+	db: &TransactionPayment,
+	result: &WithdrawFundsResult,
+	amount: U256,
+) -> anyhow::Result<Vec<i32>> {
+	let ids = db.store_pending_withdrawals(vec![result.clone()]).await?;
+	if let Err(e) = db.record_withdrawal(result, amount).await {
+		tracing::error!("Failed to record withdrawal in the ledger: {e:?}");
+	}
+
+	Ok(ids)
+}
+
+/// Deliver the post request of a persisted withdrawal to the chain the fees are withdrawn on
+async fn deliver_withdrawal<C: IsmpProvider>(
+	client: Arc<dyn IsmpProvider>,
+	hyperbridge: &C,
+	db: &TransactionPayment,
+	metrics: Option<&Metrics>,
+	result: WithdrawFundsResult,
+	ids: Vec<i32>,
+) {
+	let chain = client.state_machine_id().state_id;
+	tracing::info!("Starting delivery of withdrawal message to {}", chain);
+	if let Some(metrics) = metrics {
+		metrics.unclaimed_fees.with_label_values(&[&client.name()]).set(0.0);
+	}
+
+	match deliver_post_request(client.clone(), hyperbridge, result.clone()).await {
+		Ok(_) => {
+			settle_withdrawal(db, &result).await;
+			if let Err(e) = db.delete_pending_withdrawals(ids).await {
+				tracing::error!("Error encountered while deleting pending withdrawals from the db: {e:?}, \n NOTE: The withdrawal request was successfully delivered.");
+			}
+		},
+		Err(err) => {
+			tracing::info!("Failed to deliver withdrawal request: {err:?}, they will be retried.");
+			if let Some(metrics) = metrics {
+				metrics.pending_withdrawals.with_label_values(&[&client.name()]).inc();
+			}
+		},
+	};
+}
+
+/// Mark a delivered withdrawal as settled in the ledger
+async fn settle_withdrawal(db: &TransactionPayment, withdrawal: &WithdrawFundsResult) {
+	if let Err(e) = db.settle_withdrawal(withdrawal).await {
+		tracing::error!("Failed to settle withdrawal in the ledger: {e:?}");
+	}
+}
+
 #[instrument(name = "Delivering post request to ", skip_all, fields(destination = dest_chain.state_machine_id().state_id.to_string()))]
 async fn deliver_post_request<D: IsmpProvider>(
 	dest_chain: Arc<dyn IsmpProvider>,
@@ -507,7 +769,8 @@ mod tests {
 			SubstrateClient::<KeccakSubstrateChain>::new(config.hyperbridge.clone()).await?;
 
 		tracing::info!("Creating clients");
-		let clients = create_client_map(config.clone(), Arc::new(hyperbridge.clone())).await?;
+		let clients =
+			create_client_map(config.clone(), Arc::new(hyperbridge.clone()), None).await?;
 		tracing::info!("Created clients");
 		tracing::info!("Hyperbridge connected");
 		let latest_height: u64 = hyperbridge
@@ -1,7 +1,9 @@
 mod cli;
 mod config;
 pub mod logging;
+mod tasks;
 
 pub mod fees;
+pub mod pnl;
 
 pub use cli::*;
@@ -23,6 +23,10 @@ async fn main() -> Result<(), anyhow::Error> {
 		match command {
 			Subcommand::AccumulateFees(cmd) =>
 				cmd.accumulate_fees(cli.config.clone(), cli.db.clone()).await?,
+			Subcommand::Pnl(cmd) => cmd.report(cli.db.clone()).await?,
+			Subcommand::SetBeneficiary(cmd) => cmd.set_beneficiary(cli.config.clone()).await?,
+			Subcommand::WithdrawBeneficiaryFees(cmd) =>
+				cmd.withdraw(cli.config.clone(), cli.db.clone()).await?,
 		}
 		return Ok(());
 	}
@@ -0,0 +1,279 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Profit and loss reports from the relayer database
+
+use anyhow::anyhow;
+use ismp::host::StateMachine;
+use serde::Serialize;
+use std::{
+	str::FromStr,
+	time::{SystemTime, UNIX_EPOCH},
+};
+use tesseract_primitives::Cost;
+use transaction_fees::{ReportFilter, RouteProfitAndLoss, TransactionPayment, WithdrawalSummary};
+
+/// Format of the profit and loss report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+	Table,
+	Csv,
+	Json,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ProfitAndLoss {
+	/// Only report deliveries from this chain, e.g EVM-97
+	#[arg(long)]
+	pub source: Option<String>,
+	/// Only report deliveries and withdrawals to this chain
+	#[arg(long)]
+	pub dest: Option<String>,
+	/// Start of the time window as a unix timestamp
+	#[arg(long, conflicts_with = "days")]
+	pub from: Option<i64>,
+	/// End of the time window as a unix timestamp
+	#[arg(long)]
+	pub to: Option<i64>,
+	/// Only report the last number of days
+	#[arg(long)]
+	pub days: Option<u64>,
+	/// Format of the report
+	#[arg(long, value_enum, default_value_t = Format::Table)]
+	pub format: Format,
+	/// Write the report to this file instead of stdout
+	#[arg(short, long)]
+	pub output: Option<String>,
+}
+
+/// Profit and loss of the deliveries on a route
+#[derive(Serialize)]
+struct RouteRow {
+	source: String,
+	destination: String,
+	deliveries: u64,
+	gas_used: String,
+	/// Gas spent in the native token of the destination
+	gas_spent: String,
+	cost_usd: String,
+	fees_usd: String,
+	claimed_usd: String,
+	unclaimed_usd: String,
+	profit_usd: String,
+}
+
+/// Withdrawals to a chain
+#[derive(Serialize)]
+struct WithdrawalRow {
+	chain: String,
+	withdrawals: u64,
+	requested_usd: String,
+	settled_usd: String,
+	pending_usd: String,
+}
+
+#[derive(Serialize)]
+struct Report {
+	routes: Vec<RouteRow>,
+	withdrawals: Vec<WithdrawalRow>,
+}
+
+const ROUTE_HEADERS: [&str; 10] = [
+	"source",
+	"destination",
+	"deliveries",
+	"gas_used",
+	"gas_spent",
+	"cost_usd",
+	"fees_usd",
+	"claimed_usd",
+	"unclaimed_usd",
+	"profit_usd",
+];
+
+const WITHDRAWAL_HEADERS: [&str; 5] =
+	["chain", "withdrawals", "requested_usd", "settled_usd", "pending_usd"];
+
+impl From<RouteProfitAndLoss> for RouteRow {
+	fn from(route: RouteProfitAndLoss) -> Self {
+		Self {
+			source: route.source_chain,
+			destination: route.dest_chain,
+			deliveries: route.deliveries,
+			gas_used: route.gas_used.to_string(),
+			gas_spent: Cost(route.gas_spent).to_string(),
+			cost_usd: route.cost.to_string(),
+			fees_usd: route.fees.to_string(),
+			claimed_usd: route.claimed.to_string(),
+			unclaimed_usd: Cost(route.fees.0.saturating_sub(route.claimed.0)).to_string(),
+			profit_usd: difference(route.fees, route.cost),
+		}
+	}
+}
+
+impl RouteRow {
+	fn fields(&self) -> Vec<String> {
+		vec![
+			self.source.clone(),
+			self.destination.clone(),
+			self.deliveries.to_string(),
+			self.gas_used.clone(),
+			self.gas_spent.clone(),
+			self.cost_usd.clone(),
+			self.fees_usd.clone(),
+			self.claimed_usd.clone(),
+			self.unclaimed_usd.clone(),
+			self.profit_usd.clone(),
+		]
+	}
+}
+
+impl From<WithdrawalSummary> for WithdrawalRow {
+	fn from(summary: WithdrawalSummary) -> Self {
+		Self {
+			chain: summary.chain,
+			withdrawals: summary.withdrawals,
+			requested_usd: summary.requested.to_string(),
+			settled_usd: summary.settled.to_string(),
+			pending_usd: Cost(summary.requested.0.saturating_sub(summary.settled.0)).to_string(),
+		}
+	}
+}
+
+impl WithdrawalRow {
+	fn fields(&self) -> Vec<String> {
+		vec![
+			self.chain.clone(),
+			self.withdrawals.to_string(),
+			self.requested_usd.clone(),
+			self.settled_usd.clone(),
+			self.pending_usd.clone(),
+		]
+	}
+}
+
+impl ProfitAndLoss {
+	/// Print the profit and loss of the deliveries and withdrawals recorded in the database
+	pub async fn report(&self, db: String) -> anyhow::Result<()> {
+		let tx_payment = TransactionPayment::initialize(&db).await?;
+		let filter = self.filter()?;
+
+		let report = Report {
+			routes: tx_payment
+				.profit_and_loss(&filter)
+				.await?
+				.into_iter()
+				.map(Into::into)
+				.collect(),
+			withdrawals: tx_payment
+				.withdrawals(&filter)
+				.await?
+				.into_iter()
+				.map(Into::into)
+				.collect(),
+		};
+
+		let output = match self.format {
+			Format::Json => json::to_string_pretty(&report)?,
+			Format::Csv => format!(
+				"{}\n{}",
+				csv(&ROUTE_HEADERS, report.routes.iter().map(RouteRow::fields).collect()),
+				csv(
+					&WITHDRAWAL_HEADERS,
+					report.withdrawals.iter().map(WithdrawalRow::fields).collect()
+				),
+			),
+			Format::Table => format!(
+				"Deliveries\n{}\nWithdrawals\n{}",
+				table(&ROUTE_HEADERS, report.routes.iter().map(RouteRow::fields).collect()),
+				table(
+					&WITHDRAWAL_HEADERS,
+					report.withdrawals.iter().map(WithdrawalRow::fields).collect()
+				),
+			),
+		};
+
+		match self.output {
+			Some(ref path) => std::fs::write(path, output)?,
+			None => println!("{output}"),
+		}
+
+		Ok(())
+	}
+
+	fn filter(&self) -> anyhow::Result<ReportFilter> {
+		let parse = |chain: &Option<String>| {
+			chain
+				.as_ref()
+				.map(|chain| {
+					StateMachine::from_str(chain)
+						.map_err(|err| anyhow!("Invalid state machine {chain}: {err:?}"))
+				})
+				.transpose()
+		};
+		let from = match self.days {
+			Some(days) => {
+				let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+				Some(now.saturating_sub(days * 24 * 60 * 60) as i64)
+			},
+			None => self.from,
+		};
+
+		Ok(ReportFilter {
+			source: parse(&self.source)?,
+			dest: parse(&self.dest)?,
+			from,
+			to: self.to,
+		})
+	}
+}
+
+/// Formats `a - b`, which may be negative
+fn difference(a: Cost, b: Cost) -> String {
+	if a >= b {
+		Cost(a.0 - b.0).to_string()
+	} else {
+		format!("-{}", Cost(b.0 - a.0))
+	}
+}
+
+fn csv(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+	let mut lines = vec![headers.join(",")];
+	lines.extend(rows.into_iter().map(|row| row.join(",")));
+	lines.join("\n") + "\n"
+}
+
+fn table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+	let mut widths = headers.iter().map(|header| header.len()).collect::<Vec<_>>();
+	for row in &rows {
+		for (width, field) in widths.iter_mut().zip(row) {
+			*width = (*width).max(field.len());
+		}
+	}
+	let line = |fields: Vec<String>| {
+		fields
+			.iter()
+			.zip(&widths)
+			.map(|(field, width)| format!("{field:<width$}"))
+			.collect::<Vec<_>>()
+			.join("  ")
+			.trim_end()
+			.to_string()
+	};
+
+	let mut lines = vec![line(headers.iter().map(|header| header.to_string()).collect())];
+	lines.extend(rows.into_iter().map(line));
+	lines.join("\n") + "\n"
+}
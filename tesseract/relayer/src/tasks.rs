@@ -0,0 +1,516 @@
+// Copyright (C) Polytope Labs Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Relayer tasks that are started and stopped as the configuration is reloaded
+
+use crate::{config::HyperbridgeConfig, fees};
+use anyhow::{anyhow, Context};
+use ethers::prelude::H160;
+use futures::{stream::FuturesUnordered, FutureExt, StreamExt};
+use ismp::host::StateMachine;
+use sc_service::TaskManager;
+use sp_core::ByteArray;
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+};
+use tesseract_messaging::{
+	metrics::{report_rpc_health, Metrics},
+	policy::GasBudgets,
+	simulation::DryRun,
+	DeliveryLock,
+};
+use tesseract_primitives::{config::RelayerConfig, IsmpProvider, TransactionStore};
+use tesseract_substrate::{config::KeccakSubstrateChain, SubstrateClient};
+use tokio::{
+	signal::unix::{signal, SignalKind},
+	sync::watch,
+};
+use transaction_fees::TransactionPayment;
+
+/// The messaging and fisherman tasks between hyperbridge and a chain
+struct Route {
+	tasks: TaskManager,
+	/// Taken for writing when stopping the route, see [`DeliveryLock`]
+	delivery_lock: DeliveryLock,
+}
+
+impl Route {
+	/// Stop the tasks once they are done delivering the messages they are submitting
+	async fn stop(self) {
+		let _delivery = self.delivery_lock.write().await;
+		drop(self.tasks);
+	}
+}
+
+/// The auto withdrawal and rpc health tasks that run across all chains
+struct Shared {
+	tasks: TaskManager,
+	/// Held for reading by the auto withdrawal while it submits withdrawals, and for writing to
+	/// stop the tasks, so that submitted withdrawals are always persisted
+	withdrawal_lock: DeliveryLock,
+}
+
+impl Shared {
+	/// Stop the tasks once they are done submitting the withdrawals in progress
+	async fn stop(self) {
+		let _withdrawal = self.withdrawal_lock.write().await;
+		drop(self.tasks);
+	}
+}
+
+/// Differences between the running config and a reloaded one, which decide the tasks that are
+/// restarted
+#[derive(Debug, Default, PartialEq)]
+struct ConfigChanges {
+	/// Chains that were added or whose config changed, their routes are restarted
+	changed_chains: HashSet<StateMachine>,
+	/// Chains that were removed, their routes are stopped
+	removed_chains: HashSet<StateMachine>,
+	/// Every route must be restarted
+	restart_routes: bool,
+	/// The tasks that run across all chains must be restarted
+	restart_shared: bool,
+}
+
+impl ConfigChanges {
+	fn new(previous: &HyperbridgeConfig, config: &HyperbridgeConfig) -> anyhow::Result<Self> {
+		let mut changed_chains = HashSet::new();
+		for (state_machine, chain) in &config.chains {
+			let running = previous.chains.get(state_machine).map(json::to_value).transpose()?;
+			if running.as_ref() != Some(&json::to_value(chain)?) {
+				changed_chains.insert(*state_machine);
+			}
+		}
+		let removed_chains = previous
+			.chains
+			.keys()
+			.filter(|state_machine| !config.chains.contains_key(state_machine))
+			.copied()
+			.collect::<HashSet<_>>();
+
+		// Routes read the latest clients of other chains, but only read some options when they
+		// start, so all routes are restarted when these change
+		let restart_routes = previous.relayer.unprofitable_retry_frequency !=
+			config.relayer.unprofitable_retry_frequency ||
+			previous.relayer.fisherman != config.relayer.fisherman;
+		// The tasks that run across all chains hold a copy of the clients
+		let restart_shared = restart_routes ||
+			!changed_chains.is_empty() ||
+			!removed_chains.is_empty() ||
+			previous.relayer.withdrawal_frequency != config.relayer.withdrawal_frequency ||
+			previous.relayer.minimum_withdrawal_amount !=
+				config.relayer.minimum_withdrawal_amount;
+
+		Ok(Self { changed_chains, removed_chains, restart_routes, restart_shared })
+	}
+}
+
+/// The messaging tasks of every route and the tasks that run across all chains. Each group of
+/// tasks runs on its own [`TaskManager`], so that it can be stopped by dropping it.
+pub struct RelayerTasks {
+	/// Path to the config file
+	path: String,
+	/// The config the tasks are currently running with
+	config: HyperbridgeConfig,
+	/// Sends the latest relayer config to the messaging tasks
+	relayer: watch::Sender<RelayerConfig>,
+	hyperbridge: SubstrateClient<KeccakSubstrateChain>,
+	clients: HashMap<StateMachine, Arc<dyn IsmpProvider>>,
+	/// Sends the latest clients to the messaging tasks
+	client_map: watch::Sender<HashMap<StateMachine, Arc<dyn IsmpProvider>>>,
+	/// Messaging and fisherman tasks for each destination
+	routes: HashMap<StateMachine, Route>,
+	/// Auto withdrawal and rpc health tasks
+	shared: Option<Shared>,
+	tx_payment: Arc<TransactionPayment>,
+	store: Option<Arc<dyn TransactionStore>>,
+	metrics: Option<Metrics>,
+	dry_run: Option<DryRun>,
+	budgets: GasBudgets,
+	tokio_handle: tokio::runtime::Handle,
+}
+
+impl RelayerTasks {
+	pub fn new(
+		path: String,
+		config: HyperbridgeConfig,
+		hyperbridge: SubstrateClient<KeccakSubstrateChain>,
+		clients: HashMap<StateMachine, Arc<dyn IsmpProvider>>,
+		tx_payment: Arc<TransactionPayment>,
+		store: Option<Arc<dyn TransactionStore>>,
+		metrics: Option<Metrics>,
+		dry_run: Option<DryRun>,
+	) -> Self {
+		let (relayer, _) = watch::channel(config.relayer.clone());
+		let (client_map, _) = watch::channel(clients.clone());
+		Self {
+			path,
+			config,
+			relayer,
+			hyperbridge,
+			clients,
+			client_map,
+			routes: Default::default(),
+			shared: None,
+			tx_payment,
+			store,
+			metrics,
+			dry_run,
+			// Daily gas budgets of the relay policies are shared between all routes
+			budgets: GasBudgets::default(),
+			tokio_handle: tokio::runtime::Handle::current(),
+		}
+	}
+
+	/// Start the tasks for every route in the config
+	pub async fn start(&mut self) -> anyhow::Result<()> {
+		if self.config.relayer.delivery_endpoints.is_empty() {
+			log::warn!(
+				"Delivery endpoints not specified in relayer config, will deliver to all chains."
+			);
+		}
+
+		for state_machine in self.destinations() {
+			self.spawn_route(state_machine).await?;
+		}
+		self.spawn_shared().await?;
+
+		Ok(())
+	}
+
+	/// The chains and relayer addresses of the running routes
+	pub fn metadata(&self) -> Vec<(StateMachine, H160)> {
+		self.routes
+			.keys()
+			.filter_map(|state_machine| self.clients.get(state_machine).map(|c| (state_machine, c)))
+			.map(|(state_machine, client)| {
+				(*state_machine, H160::from_slice(&client.address().as_slice()[..20]))
+			})
+			.collect()
+	}
+
+	/// Run until an essential task fails, reloading the config whenever a SIGHUP is received
+	pub async fn run(mut self, task_manager: &mut TaskManager) -> anyhow::Result<()> {
+		let mut hangup = signal(SignalKind::hangup())?;
+
+		loop {
+			{
+				let mut failures = self
+					.routes
+					.values_mut()
+					.map(|route| &mut route.tasks)
+					.chain(self.shared.iter_mut().map(|shared| &mut shared.tasks))
+					.map(|tasks| tasks.future())
+					.collect::<FuturesUnordered<_>>();
+				let failure = async {
+					match failures.next().await {
+						Some(result) => result,
+						None => futures::future::pending().await,
+					}
+				};
+
+				tokio::select! {
+					result = task_manager.future() => return Ok(result?),
+					result = failure => return Ok(result?),
+					_ = hangup.recv() => {},
+				}
+			}
+
+			log::info!("🔄 Reloading config from {}", self.path);
+			if let Err(err) = self.reload().await {
+				log::error!("Failed to reload config, the previous config remains in use: {err:?}")
+			}
+		}
+	}
+
+	/// Apply the changes in the config file. Changes to the relayer config are applied in place,
+	/// while only the routes of chains that were added, changed or removed, or whose delivery
+	/// endpoint was added or removed, are started or stopped. Routes are only stopped between
+	/// deliveries.
+	async fn reload(&mut self) -> anyhow::Result<()> {
+		let config = HyperbridgeConfig::parse_conf(&self.path).await?;
+
+		if json::to_value(&config.hyperbridge)? != json::to_value(&self.config.hyperbridge)? {
+			log::warn!("Changes to the hyperbridge config will only be applied after a restart");
+		}
+		let changes = ConfigChanges::new(&self.config, &config)?;
+
+		// Create the clients before changing anything, so an invalid chain config leaves the
+		// relayer as it was
+		let mut clients = HashMap::new();
+		for state_machine in &changes.changed_chains {
+			let client = config.chains[state_machine]
+				.clone()
+				.into_client(Arc::new(self.hyperbridge.clone()), self.store.clone())
+				.await
+				.context(format!("Failed to create client for {state_machine:?}"))?;
+			clients.insert(*state_machine, client);
+		}
+
+		self.config = config;
+		let _ = self.relayer.send_replace(self.config.relayer.clone());
+		for state_machine in &changes.removed_chains {
+			log::info!("Removed {state_machine} from the relayer");
+			self.clients.remove(state_machine);
+		}
+		for (state_machine, client) in clients {
+			log::info!("Updated the client for {state_machine}");
+			self.clients.insert(state_machine, client);
+		}
+		let _ = self.client_map.send_replace(self.clients.clone());
+
+		let destinations = self.destinations();
+		let stopped = self
+			.routes
+			.keys()
+			.filter(|state_machine| {
+				changes.restart_routes ||
+					changes.changed_chains.contains(state_machine) ||
+					!destinations.contains(state_machine)
+			})
+			.copied()
+			.collect::<Vec<_>>();
+		for state_machine in stopped {
+			if let Some(route) = self.routes.remove(&state_machine) {
+				log::info!("Waiting for the deliveries to {state_machine} to complete");
+				route.stop().await;
+				log::info!("Stopped delivering to {state_machine}");
+			}
+		}
+		for state_machine in destinations {
+			if self.routes.contains_key(&state_machine) {
+				continue;
+			}
+			if let Err(err) = self.spawn_route(state_machine).await {
+				log::error!("Failed to start delivering to {state_machine}: {err:?}")
+			}
+		}
+
+		if changes.restart_shared {
+			self.spawn_shared().await?;
+		}
+
+		log::info!("✅ Config reloaded");
+
+		Ok(())
+	}
+
+	/// The chains that messages should be delivered to
+	fn destinations(&self) -> Vec<StateMachine> {
+		let endpoints = &self.config.relayer.delivery_endpoints;
+		self.clients
+			.keys()
+			// If the delivery endpoint is not empty then we only spawn tasks for chains
+			// explicitly mentioned in the config
+			.filter(|state_machine| {
+				endpoints.is_empty() || endpoints.contains(&state_machine.to_string())
+			})
+			.copied()
+			.collect()
+	}
+
+	/// Spawn the messaging and fisherman tasks between hyperbridge and `state_machine`
+	async fn spawn_route(&mut self, state_machine: StateMachine) -> anyhow::Result<()> {
+		let client = self
+			.clients
+			.get(&state_machine)
+			.cloned()
+			.ok_or_else(|| anyhow!("Client for {state_machine} not found"))?;
+		let task_manager = TaskManager::new(self.tokio_handle.clone(), None)?;
+		let delivery_lock = DeliveryLock::default();
+
+		let mut new_hyperbridge =
+			SubstrateClient::<KeccakSubstrateChain>::new(self.config.hyperbridge.clone()).await?;
+		new_hyperbridge.set_latest_finalized_height(client.clone()).await?;
+
+		let coprocessor = self.config.hyperbridge.state_machine;
+
+		tesseract_messaging::relay(
+			new_hyperbridge.clone(),
+			client.clone(),
+			self.relayer.subscribe(),
+			coprocessor,
+			self.tx_payment.clone(),
+			self.client_map.subscribe(),
+			&task_manager,
+			self.metrics.clone(),
+			self.dry_run.clone(),
+			self.budgets.clone(),
+			delivery_lock.clone(),
+		)
+		.await?;
+
+		if self.config.relayer.fisherman.unwrap_or_default() && self.dry_run.is_none() {
+			tesseract_fisherman::fish(
+				Arc::new(new_hyperbridge),
+				client.clone(),
+				&task_manager,
+				coprocessor,
+			)
+			.await?
+		}
+
+		if let Some(previous) =
+			self.routes.insert(state_machine, Route { tasks: task_manager, delivery_lock })
+		{
+			previous.stop().await;
+		}
+
+		Ok(())
+	}
+
+	/// Spawn the tasks that run across all chains, replacing any that are running once they are
+	/// done submitting withdrawals
+	async fn spawn_shared(&mut self) -> anyhow::Result<()> {
+		// Stop the running tasks first, so that withdrawals never run concurrently
+		if let Some(shared) = self.shared.take() {
+			log::info!("Waiting for the withdrawals in progress to be submitted");
+			shared.stop().await;
+		}
+		let task_manager = TaskManager::new(self.tokio_handle.clone(), None)?;
+		let withdrawal_lock = DeliveryLock::default();
+
+		if let Some(metrics) = self.metrics.clone() {
+			task_manager.spawn_handle().spawn(
+				"rpc-health",
+				"metrics",
+				report_rpc_health(self.clients.clone(), metrics).boxed(),
+			);
+		}
+
+		if self.dry_run.is_none() {
+			let hyperbridge = self.hyperbridge.clone();
+			let clients = self.clients.clone();
+			let config = self.config.relayer.clone();
+			let tx_payment = self.tx_payment.clone();
+			let metrics = self.metrics.clone();
+			let withdrawal_lock = withdrawal_lock.clone();
+			task_manager.spawn_essential_handle().spawn(
+				"auto-withdraw",
+				"fees",
+				async move {
+					let _ = fees::auto_withdraw(
+						hyperbridge,
+						clients,
+						config,
+						tx_payment,
+						metrics,
+						withdrawal_lock,
+					)
+					.await;
+				}
+				.boxed(),
+			);
+		}
+
+		self.shared = Some(Shared { tasks: task_manager, withdrawal_lock });
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ConfigChanges;
+	use crate::config::HyperbridgeConfig;
+	use ismp::host::StateMachine;
+	use std::collections::HashSet;
+
+	const HYPERBRIDGE: &str = r#"
+[hyperbridge]
+state_machine = "KUSAMA-4009"
+hashing = "Keccak"
+rpc_ws = "ws://127.0.0.1:9933"
+signer = ""
+"#;
+
+	const CHAIN: &str = r#"
+[bsc]
+type = "evm"
+state_machine = "EVM-97"
+rpc_urls = ["http://127.0.0.1:8345"]
+ismp_host = "0x8Ac39DfC1F2616e5e19B93420C6d008a8a8EE65f"
+consensus_state_id = "BSC0"
+etherscan_api_key = ""
+signer = "0x8Ac39DfC1F2616e5e19B93420C6d008a8a8EE65f008a8a8EE65f"
+"#;
+
+	async fn parse(name: &str, relayer: &str, chains: &str) -> HyperbridgeConfig {
+		let path = std::env::temp_dir().join(format!("tesseract-reload-{name}.toml"));
+		tokio::fs::write(
+			&path,
+			format!("{HYPERBRIDGE}\n[relayer]\ndelivery_endpoints = []\n{relayer}\n{chains}"),
+		)
+		.await
+		.unwrap();
+		HyperbridgeConfig::parse_conf(path.to_str().unwrap()).await.unwrap()
+	}
+
+	#[tokio::test]
+	async fn should_only_restart_the_tasks_affected_by_a_reload() {
+		let running = parse("running", "minimum_profit_percentage = 0", CHAIN).await;
+
+		// relayer options that are read on every update are applied in place
+		let config = parse("profit", "minimum_profit_percentage = 5", CHAIN).await;
+		assert_eq!(ConfigChanges::new(&running, &config).unwrap(), ConfigChanges::default());
+
+		let config =
+			parse("withdrawals", "minimum_profit_percentage = 0\nwithdrawal_frequency = 60", CHAIN)
+				.await;
+		let changes = ConfigChanges::new(&running, &config).unwrap();
+		assert!(!changes.restart_routes && changes.restart_shared);
+
+		let config = parse(
+			"retries",
+			"minimum_profit_percentage = 0\nunprofitable_retry_frequency = 60",
+			CHAIN,
+		)
+		.await;
+		let changes = ConfigChanges::new(&running, &config).unwrap();
+		assert!(changes.restart_routes && changes.restart_shared);
+		assert!(changes.changed_chains.is_empty());
+
+		let chain = CHAIN.replace("8345", "8346");
+		let config = parse("chain", "minimum_profit_percentage = 0", &chain).await;
+		let changes = ConfigChanges::new(&running, &config).unwrap();
+		assert_eq!(changes.changed_chains, HashSet::from([StateMachine::Evm(97)]));
+		// only the route of the changed chain is restarted
+		assert!(!changes.restart_routes && changes.restart_shared);
+
+		let config = parse("removed", "minimum_profit_percentage = 0", "").await;
+		let changes = ConfigChanges::new(&running, &config).unwrap();
+		assert_eq!(changes.removed_chains, HashSet::from([StateMachine::Evm(97)]));
+		assert!(!changes.restart_routes && changes.restart_shared);
+	}
+
+	#[tokio::test]
+	async fn should_stop_routes_between_deliveries() {
+		let lock = tesseract_messaging::DeliveryLock::default();
+		let delivery = lock.clone().read_owned().await;
+		let route = super::Route {
+			tasks: sc_service::TaskManager::new(tokio::runtime::Handle::current(), None).unwrap(),
+			delivery_lock: lock.clone(),
+		};
+		let stop = tokio::spawn(route.stop());
+
+		// the route is stopped once the delivery in progress completes
+		tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+		assert!(!stop.is_finished());
+		drop(delivery);
+		stop.await.unwrap();
+		assert!(lock.try_write().is_ok());
+	}
+}
@@ -47,8 +47,7 @@ where
 			height: event.latest_height,
 		};
 
-		let Some(block_hash) =
-			self.client.rpc().block_hash(Some(event.latest_height.into())).await?
+		let Some(state_root) = self.query_state_root(coprocessor, event.latest_height).await?
 		else {
 			// If block header is not found veto the state commitment
 
@@ -62,6 +61,29 @@ where
 
 			return Ok(())
 		};
+		let finalized_state_commitment =
+			counterparty.query_state_machine_commitment(height).await?;
+
+		if finalized_state_commitment.state_root != state_root {
+			log::info!(
+				"Vetoing state commitment for {} on {}, state commitment mismatch",
+				self.state_machine_id().state_id,
+				counterparty.state_machine_id().state_id
+			);
+			counterparty.veto_state_commitment(height).await?;
+		}
+
+		Ok(())
+	}
+
+	async fn query_state_root(
+		&self,
+		coprocessor: StateMachine,
+		height: u64,
+	) -> Result<Option<H256>, anyhow::Error> {
+		let Some(block_hash) = self.client.rpc().block_hash(Some(height.into())).await? else {
+			return Ok(None)
+		};
 		let header = self
 			.client
 			.rpc()
@@ -80,19 +102,8 @@ where
 		} else {
 			header.state_root.into()
 		};
-		let finalized_state_commitment =
-			counterparty.query_state_machine_commitment(height).await?;
-
-		if finalized_state_commitment.state_root != state_root.into() {
-			log::info!(
-				"Vetoing state commitment for {} on {}, state commitment mismatch",
-				self.state_machine_id().state_id,
-				counterparty.state_machine_id().state_id
-			);
-			counterparty.veto_state_commitment(height).await?;
-		}
 
-		Ok(())
+		Ok(Some(state_root))
 	}
 
 	async fn state_machine_updates(
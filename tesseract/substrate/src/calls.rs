@@ -1,7 +1,10 @@
 //! Functions for updating configuration on pallets
 
 use crate::{
-	extrinsic::{send_unsigned_extrinsic, system_dry_run_unsigned, Extrinsic, InMemorySigner},
+	extrinsic::{
+		send_extrinsic, send_unsigned_extrinsic, send_unsigned_extrinsic_events,
+		system_dry_run_unsigned, Extrinsic,
+	},
 	SubstrateClient,
 };
 use anyhow::anyhow;
@@ -9,16 +12,22 @@ use codec::{Decode, Encode};
 use ismp::{
 	events::{Event, StateMachineUpdated},
 	host::StateMachine,
-	messaging::CreateConsensusState,
+	messaging::{hash_request, CreateConsensusState},
+	router::{PostRequest, Request},
 };
+use pallet_hyperbridge::{Message, WithdrawalRequest};
 use pallet_ismp::{child_trie::CHILD_TRIE_PREFIX, mmr::LeafIndexAndPos};
 use pallet_ismp_host_executive::HostParam;
 use pallet_ismp_relayer::{
-	message,
-	withdrawal::{Key, WithdrawalInputData, WithdrawalParams, WithdrawalProof},
+	batch_message, beneficiary_message, message,
+	withdrawal::{
+		BatchWithdrawalInputData, BeneficiaryData, Key, WithdrawalInputData, WithdrawalProof,
+		MAX_WITHDRAWAL_BATCH_SIZE,
+	},
 };
 use pallet_state_coprocessor::impls::GetRequestsWithProof;
 use sp_core::{
+	hexdisplay::HexDisplay,
 	storage::{ChildInfo, StorageData, StorageKey},
 	H256, U256,
 };
@@ -27,19 +36,19 @@ use subxt::{
 	config::{
 		extrinsic_params::BaseExtrinsicParamsBuilder, polkadot::PlainTip, ExtrinsicParams, Header,
 	},
-	ext::{
-		sp_core::{crypto, Pair},
-		sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner},
-	},
+	ext::{sp_core::crypto, sp_runtime::MultiSignature},
 	rpc::types::DryRunResult,
 	rpc_params,
 	tx::TxPayload,
 	utils::AccountId32,
 	OnlineClient,
 };
-use subxt_utils::{relayer_account_balance_storage_key, relayer_nonce_storage_key, send_extrinsic};
+use subxt_utils::{
+	relayer_account_balance_storage_key, relayer_beneficiary_nonce_storage_key,
+	relayer_beneficiary_storage_key, relayer_nonce_storage_key,
+};
 use tesseract_primitives::{
-	HandleGetResponse, HyperbridgeClaim, IsmpProvider, WithdrawFundsResult,
+	HandleGetResponse, Hasher, HyperbridgeClaim, IsmpProvider, WithdrawFundsResult,
 };
 
 #[derive(codec::Encode, codec::Decode)]
@@ -67,16 +76,11 @@ where
 		&self,
 		message: CreateConsensusState,
 	) -> Result<(), anyhow::Error> {
-		let signer = InMemorySigner {
-			account_id: MultiSigner::Sr25519(self.signer.public()).into_account().into(),
-			signer: self.signer.clone(),
-		};
-
 		let call = message.encode();
 		let call = Extrinsic::new("Ismp", "create_consensus_client", call)
 			.encode_call_data(&self.client.metadata())?;
 		let tx = Extrinsic::new("Sudo", "sudo", call);
-		send_extrinsic(&self.client, signer, tx, None).await?;
+		send_extrinsic(&self.client, &self.signer, tx, None).await?;
 
 		Ok(())
 	}
@@ -88,13 +92,118 @@ where
 		let encoded_call = Extrinsic::new("HostExecutive", "set_host_params", params.encode())
 			.encode_call_data(&self.client.metadata())?;
 		let tx = Extrinsic::new("Sudo", "sudo", encoded_call);
-		let signer = InMemorySigner::new(self.signer());
-		send_extrinsic(&self.client, signer, tx, None).await?;
+		send_extrinsic(&self.client, &self.signer, tx, None).await?;
 
 		Ok(())
 	}
 }
 
+impl<C> SubstrateClient<C>
+where
+	C: subxt::Config + Send + Sync + Clone,
+	C::Header: Send + Sync,
+	<C::ExtrinsicParams as ExtrinsicParams<C::Hash>>::OtherParams:
+		Default + Send + Sync + From<BaseExtrinsicParamsBuilder<C, PlainTip>>,
+	C::AccountId:
+		From<crypto::AccountId32> + Into<C::Address> + Encode + Clone + 'static + Send + Sync,
+	C::Signature: From<MultiSignature> + Send + Sync,
+	H256: From<<C as subxt::Config>::Hash>,
+{
+	/// Current withdrawal nonce of `address` on `chain`
+	async fn relayer_nonce(&self, address: Vec<u8>, chain: StateMachine) -> anyhow::Result<u64> {
+		let key = relayer_nonce_storage_key(address, chain);
+		let raw_value = self.client.storage().at_latest().await?.fetch_raw(&key).await?;
+		let nonce =
+			if let Some(raw_value) = raw_value { Decode::decode(&mut &*raw_value)? } else { 0u64 };
+
+		Ok(nonce)
+	}
+
+	/// The beneficiary credited with the fees of `address`, if one is set
+	async fn relayer_beneficiary(&self, address: Vec<u8>) -> anyhow::Result<Option<Vec<u8>>> {
+		let key = relayer_beneficiary_storage_key(address);
+		let raw_value = self.client.storage().at_latest().await?.fetch_raw(&key).await?;
+		let beneficiary =
+			raw_value.map(|raw_value| Decode::decode(&mut &*raw_value)).transpose()?;
+
+		Ok(beneficiary)
+	}
+
+	/// Submit a withdrawal extrinsic and find the post request it emitted for each of `chains`
+	async fn submit_withdrawal(
+		&self,
+		tx: Extrinsic,
+		counterparty: Arc<dyn IsmpProvider>,
+		chains: &[StateMachine],
+	) -> anyhow::Result<Vec<WithdrawFundsResult>> {
+		// Wait for finalization so we still get the correct block with the post request event even
+		// if a reorg happens
+		let extrinsic_events = send_unsigned_extrinsic_events(&self.client, tx, true).await?;
+		// Only the requests dispatched by this extrinsic are withdrawals of `counterparty`
+		let commitments = extrinsic_events
+			.find::<subxt_utils::gargantua::api::ismp::events::Request>()
+			.map(|event| event.map(|event| event.commitment))
+			.collect::<Result<Vec<_>, _>>()?;
+		let block_number = self
+			.client
+			.rpc()
+			.header(Some(extrinsic_events.block_hash()))
+			.await?
+			.ok_or_else(|| anyhow!("Header should exists"))?
+			.number()
+			.into();
+		let mock_state_update = StateMachineUpdated {
+			state_machine_id: counterparty.state_machine_id(),
+			latest_height: block_number,
+		};
+		let events = self.query_ismp_events(block_number - 1, mock_state_update).await?;
+
+		chains
+			.iter()
+			.map(|chain| {
+				let post = events
+					.iter()
+					.find_map(|event| match event {
+						Event::PostRequest(post)
+							if post.dest == *chain &&
+								&post.from == &pallet_ismp_relayer::MODULE_ID &&
+								commitments.contains(&hash_request::<Hasher>(
+									&Request::Post(post.clone()),
+								)) =>
+							Some(post.clone()),
+						_ => None,
+					})
+					.ok_or_else(|| anyhow!("Post Event should be present in block"))?;
+
+				// Fees are paid out to the beneficiary of the address if it had one when the
+				// withdrawal was executed
+				let payee = withdrawal_payee(&post)
+					.ok_or_else(|| anyhow!("Withdrawal request to {chain} has no payee"))?;
+				log::info!(
+					"Fees withdrawn on {chain} will be paid out to 0x{}",
+					HexDisplay::from(&payee)
+				);
+
+				Ok(WithdrawFundsResult { post, block: block_number })
+			})
+			.collect()
+	}
+}
+
+/// Decode the address that a withdrawal request pays out to
+fn withdrawal_payee(post: &PostRequest) -> Option<Vec<u8>> {
+	match post.dest {
+		s if s.is_substrate() => match Message::<[u8; 32], u128>::decode(&mut &*post.body).ok()? {
+			Message::WithdrawRelayerFees(WithdrawalRequest { account, .. }) =>
+				Some(account.to_vec()),
+			_ => None,
+		},
+		// abi encoding will pad address with 12 bytes
+		s if s.is_evm() => post.body.get(13..33).map(|address| address.to_vec()),
+		_ => None,
+	}
+}
+
 #[async_trait::async_trait]
 impl<C> HyperbridgeClaim for SubstrateClient<C>
 where
@@ -144,68 +253,70 @@ where
 		counterparty: Arc<dyn IsmpProvider>,
 		chain: StateMachine,
 	) -> anyhow::Result<WithdrawFundsResult> {
-		let key = relayer_nonce_storage_key(counterparty.address(), chain);
-		let raw_value = self.client.storage().at_latest().await?.fetch_raw(&key).await?;
-		let nonce =
-			if let Some(raw_value) = raw_value { Decode::decode(&mut &*raw_value)? } else { 0u64 };
-
+		let nonce = self.relayer_nonce(counterparty.address(), chain).await?;
 		let signature = {
 			let message = message(nonce, chain);
-			counterparty.sign(&message)
+			counterparty.sign(&message).await?
 		};
 
 		let input_data = WithdrawalInputData { signature, dest_chain: chain };
 
 		let tx = Extrinsic::new("Relayer", "withdraw_fees", input_data.encode());
-		// Wait for finalization so we still get the correct block with the post request event even
-		// if a reorg happens
-		let (hash, _) = send_unsigned_extrinsic(&self.client, tx, true)
-			.await?
-			.ok_or_else(|| anyhow!("Transaction submission failed"))?;
-		let block_number = self
-			.client
-			.rpc()
-			.header(Some(hash))
-			.await?
-			.ok_or_else(|| anyhow!("Header should exists"))?
-			.number()
-			.into();
-		let mock_state_update = StateMachineUpdated {
-			state_machine_id: counterparty.state_machine_id(),
-			latest_height: block_number,
+		let mut results = self.submit_withdrawal(tx, counterparty, &[chain]).await?;
+
+		Ok(results.remove(0))
+	}
+
+	/// Withdraw funds on several chains with a single signature and return the emitted post
+	/// requests, in the order of `chains`
+	async fn withdraw_funds_batch(
+		&self,
+		counterparty: Arc<dyn IsmpProvider>,
+		chains: Vec<StateMachine>,
+	) -> anyhow::Result<Vec<WithdrawFundsResult>> {
+		let mut nonces = vec![];
+		for chain in &chains {
+			nonces.push((self.relayer_nonce(counterparty.address(), *chain).await?, *chain));
+		}
+		let signature = counterparty.sign(&batch_message(&nonces)).await?;
+
+		let input_data = BatchWithdrawalInputData {
+			signature,
+			dest_chains: chains.clone().try_into().map_err(|_| {
+				anyhow!("At most {MAX_WITHDRAWAL_BATCH_SIZE} chains can be withdrawn in a batch")
+			})?,
 		};
-		let event = self
-			.query_ismp_events(block_number - 1, mock_state_update)
+
+		let tx = Extrinsic::new("Relayer", "withdraw_fees_batch", input_data.encode());
+		self.submit_withdrawal(tx, counterparty, &chains).await
+	}
+
+	/// Credit the fees accrued by the address of `counterparty` to `beneficiary`
+	async fn set_beneficiary(
+		&self,
+		counterparty: Arc<dyn IsmpProvider>,
+		beneficiary: Vec<u8>,
+	) -> anyhow::Result<()> {
+		let address = counterparty.address();
+		let key = relayer_beneficiary_nonce_storage_key(address.clone());
+		let raw_value = self.client.storage().at_latest().await?.fetch_raw(&key).await?;
+		let nonce =
+			if let Some(raw_value) = raw_value { Decode::decode(&mut &*raw_value)? } else { 0u64 };
+
+		let signature =
+			counterparty.sign(&beneficiary_message(nonce, &address, &beneficiary)).await?;
+		let beneficiary_data = BeneficiaryData { address, beneficiary, signature };
+
+		let tx = Extrinsic::new("Relayer", "set_beneficiary", beneficiary_data.encode());
+		send_unsigned_extrinsic(&self.client, tx, true)
 			.await?
-			.into_iter()
-			.find(|event| match event {
-				Event::PostRequest(post) => {
-					let condition =
-						post.dest == chain && &post.from == &pallet_ismp_relayer::MODULE_ID;
-					match post.dest {
-						s if s.is_substrate() => {
-							if let Ok(decoded_data) = WithdrawalParams::decode(&mut &*post.body) {
-								decoded_data.beneficiary_address == counterparty.address() &&
-									condition
-							} else {
-								false
-							}
-						},
-						s if s.is_evm() => {
-							let address = &post.body[1..33].to_vec();
-							// abi encoding will pad address with 12 bytes
-							address.ends_with(&counterparty.address()) && condition
-						},
-						_ => false,
-					}
-				},
-				_ => false,
-			})
-			.ok_or_else(|| anyhow!("Post Event should be present in block"))?;
+			.ok_or_else(|| anyhow!("Transaction submission failed"))?;
 
-		let Event::PostRequest(post) = event else { unreachable!() };
+		Ok(())
+	}
 
-		Ok(WithdrawFundsResult { post, block: block_number })
+	async fn beneficiary(&self, address: Vec<u8>) -> anyhow::Result<Option<Vec<u8>>> {
+		self.relayer_beneficiary(address).await
 	}
 
 	async fn check_claimed(&self, key: Key) -> anyhow::Result<bool> {
@@ -15,25 +15,97 @@
 
 //! Extrinsic utilities
 
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use sp_core::H256;
+use std::sync::Arc;
 use subxt::{
+	blocks::ExtrinsicEvents,
 	config::{extrinsic_params::BaseExtrinsicParamsBuilder, polkadot::PlainTip, ExtrinsicParams},
-	ext::sp_runtime::MultiSignature,
+	ext::{
+		sp_core::{crypto, sr25519},
+		sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner},
+	},
 	rpc::types::DryRunResult,
 	tx::TxPayload,
 	OnlineClient,
 };
+use tesseract_primitives::signer::Signer;
 
-use subxt_utils::refine_subxt_error;
+use subxt_utils::{refine_subxt_error, submit_extrinsic};
 pub use subxt_utils::{Extrinsic, InMemorySigner};
 
+/// The account id of an sr25519 relayer signer
+pub fn signer_account<T: subxt::Config>(
+	signer: &Arc<dyn Signer>,
+) -> Result<T::AccountId, anyhow::Error>
+where
+	T::AccountId: From<crypto::AccountId32>,
+{
+	let public = sr25519::Public::try_from(signer.public_key().as_slice())
+		.map_err(|_| anyhow!("Signer returned an invalid sr25519 public key"))?;
+	Ok(MultiSigner::Sr25519(public).into_account().into())
+}
+
+/// Send an extrinsic signed by the relayer signer and wait for it to be executed
+pub async fn send_extrinsic<T: subxt::Config, Tx: TxPayload>(
+	client: &OnlineClient<T>,
+	signer: &Arc<dyn Signer>,
+	payload: Tx,
+	tip: Option<PlainTip>,
+) -> Result<T::Hash, anyhow::Error>
+where
+	<T::ExtrinsicParams as ExtrinsicParams<T::Hash>>::OtherParams:
+		Default + Send + Sync + From<BaseExtrinsicParamsBuilder<T, PlainTip>>,
+	T::Signature: From<MultiSignature> + Send + Sync,
+	T::AccountId: From<crypto::AccountId32> + Into<T::Address> + Clone,
+{
+	let account_id = signer_account::<T>(signer)?;
+	let other_params = BaseExtrinsicParamsBuilder::new().tip(tip.unwrap_or_default());
+	let partial = client
+		.tx()
+		.create_partial_signed(&payload, &account_id, other_params.into())
+		.await?;
+	let signature = signer.sign(&partial.signer_payload()).await?;
+	let signature = sr25519::Signature::try_from(signature.as_slice())
+		.map_err(|_| anyhow!("Signer returned an invalid sr25519 signature"))?;
+	let ext = partial.sign_with_address_and_signature(
+		&account_id.into(),
+		&MultiSignature::Sr25519(signature).into(),
+	);
+
+	submit_extrinsic(ext).await
+}
+
 /// Send an unsigned extrinsic for ISMP messages.
 pub async fn send_unsigned_extrinsic<T: subxt::Config, Tx: TxPayload>(
 	client: &OnlineClient<T>,
 	payload: Tx,
 	wait_for_finalization: bool,
 ) -> Result<Option<(T::Hash, Vec<H256>)>, anyhow::Error>
+where
+	<T::ExtrinsicParams as ExtrinsicParams<T::Hash>>::OtherParams:
+		Default + Send + Sync + From<BaseExtrinsicParamsBuilder<T, PlainTip>>,
+	T::Signature: From<MultiSignature> + Send + Sync,
+{
+	let events = send_unsigned_extrinsic_events(client, payload, wait_for_finalization).await?;
+	let mut receipts = events
+		.find::<subxt_utils::gargantua::api::ismp::events::PostRequestHandled>()
+		.filter_map(|ev| ev.ok().map(|e| e.0.commitment))
+		.collect::<Vec<_>>();
+	let temp_2 = events
+		.find::<subxt_utils::gargantua::api::ismp::events::PostResponseHandled>()
+		.filter_map(|ev| ev.ok().map(|e| e.0.commitment))
+		.collect::<Vec<_>>();
+	receipts.extend(temp_2);
+	Ok(Some((events.block_hash(), receipts)))
+}
+
+/// Send an unsigned extrinsic and return the events it emitted once it's executed
+pub async fn send_unsigned_extrinsic_events<T: subxt::Config, Tx: TxPayload>(
+	client: &OnlineClient<T>,
+	payload: Tx,
+	wait_for_finalization: bool,
+) -> Result<ExtrinsicEvents<T>, anyhow::Error>
 where
 	<T::ExtrinsicParams as ExtrinsicParams<T::Hash>>::OtherParams:
 		Default + Send + Sync + From<BaseExtrinsicParamsBuilder<T, PlainTip>>,
@@ -67,24 +139,14 @@ where
 		))?,
 	};
 
-	let (hash, receipts) = match extrinsic.wait_for_success().await {
+	match extrinsic.wait_for_success().await {
 		Ok(p) => {
 			log::info!("Successfully executed unsigned extrinsic {ext_hash:?}");
-			let mut receipts = p
-				.find::<subxt_utils::gargantua::api::ismp::events::PostRequestHandled>()
-				.filter_map(|ev| ev.ok().map(|e| e.0.commitment))
-				.collect::<Vec<_>>();
-			let temp_2 = p
-				.find::<subxt_utils::gargantua::api::ismp::events::PostResponseHandled>()
-				.filter_map(|ev| ev.ok().map(|e| e.0.commitment))
-				.collect::<Vec<_>>();
-			receipts.extend(temp_2);
-			(p.block_hash(), receipts)
+			Ok(p)
 		},
 		Err(err) => Err(refine_subxt_error(err))
-			.context(format!("Error executing unsigned extrinsic {ext_hash:?}"))?,
-	};
-	Ok(Some((hash, receipts)))
+			.context(format!("Error executing unsigned extrinsic {ext_hash:?}")),
+	}
 }
 
 /// Dry run extrinsic
@@ -21,17 +21,21 @@ use pallet_ismp::child_trie::{
 	request_commitment_storage_key, request_receipt_storage_key, response_commitment_storage_key,
 	response_receipt_storage_key,
 };
-use tesseract_primitives::{IsmpProvider, StateMachineUpdated, StreamError};
+use tesseract_primitives::{
+	signer::{KeyType, LocalSigner, Signer, SignerConfig},
+	IsmpProvider, StateMachineUpdated, StreamError,
+};
 
+use rpc_utils::{EndpointPool, PoolConfig};
 use serde::{Deserialize, Serialize};
-use subxt::ext::sp_core::{bytes::from_hex, crypto, sr25519, Pair, H256};
+use subxt::ext::sp_core::{crypto, H256};
 
 use substrate_state_machine::HashAlgorithm;
 use subxt::{
 	config::{
 		extrinsic_params::BaseExtrinsicParamsBuilder, polkadot::PlainTip, ExtrinsicParams, Header,
 	},
-	ext::sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner},
+	ext::sp_runtime::MultiSignature,
 	OnlineClient,
 };
 
@@ -57,10 +61,15 @@ pub struct SubstrateConfig {
 	pub consensus_state_id: Option<String>,
 	/// Websocket RPC url for the chain
 	pub rpc_ws: String,
+	/// Additional websocket RPC urls for the chain that requests fail over to
+	pub fallback_rpc_ws: Option<Vec<String>>,
+	/// How requests are spread across the rpc urls, by default the healthiest endpoint is used
+	pub rpc_pool: Option<PoolConfig>,
 	/// Maximum size in bytes for the rpc payloads, both requests & responses.
 	pub max_rpc_payload_size: Option<u32>,
-	/// Relayer account seed
-	pub signer: Option<String>,
+	/// Relayer account signer, either a seed or a keystore/remote signer backend. A random
+	/// account is used when this is not provided.
+	pub signer: Option<SignerConfig>,
 	/// Latest state machine height
 	pub latest_height: Option<u64>,
 	/// Max concurrent rpc requests allowed
@@ -71,14 +80,16 @@ pub struct SubstrateConfig {
 pub struct SubstrateClient<C: subxt::Config> {
 	/// Subxt client for the substrate chain
 	pub client: OnlineClient<C>,
+	/// Health records of the rpc endpoints
+	pub rpc_pool: Arc<EndpointPool>,
 	/// Consensus state Id
 	consensus_state_id: ConsensusStateId,
 	/// State machine Identifier for this client.
 	state_machine: StateMachine,
 	/// The hashing algorithm that substrate chain uses.
 	hashing: HashAlgorithm,
-	/// Private key of the signing account
-	pub signer: sr25519::Pair,
+	/// Signer of the relayer account
+	pub signer: Arc<dyn Signer>,
 	/// Public Address
 	pub address: Vec<u8>,
 	/// Latest state machine height.
@@ -104,8 +115,15 @@ where
 {
 	pub async fn new(config: SubstrateConfig) -> Result<Self, anyhow::Error> {
 		let max_rpc_payload_size = config.max_rpc_payload_size.unwrap_or(300u32 * 1024 * 1024);
-		let client =
-			subxt_utils::client::ws_client::<C>(&config.rpc_ws, max_rpc_payload_size).await?;
+		let rpc_ws = std::iter::once(config.rpc_ws.clone())
+			.chain(config.fallback_rpc_ws.clone().unwrap_or_default())
+			.collect();
+		let (client, rpc_pool) = subxt_utils::client::ws_client_with_failover::<C>(
+			rpc_ws,
+			max_rpc_payload_size,
+			config.rpc_pool.clone().unwrap_or_default(),
+		)
+		.await?;
 		// If latest height of the state machine on the counterparty is not provided in config
 		// Set it to the latest parachain height
 		let latest_height = if let Some(latest_height) = config.latest_height {
@@ -119,17 +137,19 @@ where
 				.number()
 				.into()
 		};
-		let bytes = config
-			.signer
-			.and_then(|seed| from_hex(&seed).ok())
-			.unwrap_or(H256::random().0.to_vec());
-		let signer = sr25519::Pair::from_seed_slice(&bytes)?;
+		let signer = match config.signer {
+			Some(signer) => signer.into_signer(KeyType::Sr25519).await?,
+			None => Arc::new(LocalSigner::from_seed(KeyType::Sr25519, &H256::random().0)?),
+		};
 		let mut consensus_state_id: ConsensusStateId = Default::default();
 		consensus_state_id
 			.copy_from_slice(config.consensus_state_id.unwrap_or("PARA".into()).as_bytes());
-		let address = signer.public().0.to_vec();
+		// Ensures the signer is an sr25519 account
+		extrinsic::signer_account::<C>(&signer)?;
+		let address = signer.public_key();
 		Ok(Self {
 			client,
+			rpc_pool,
 			consensus_state_id,
 			state_machine: config.state_machine,
 			hashing: config.hashing.clone().unwrap_or(HashAlgorithm::Keccak),
@@ -141,12 +161,12 @@ where
 		})
 	}
 
-	pub fn signer(&self) -> sr25519::Pair {
+	pub fn signer(&self) -> Arc<dyn Signer> {
 		self.signer.clone()
 	}
 
 	pub fn account(&self) -> C::AccountId {
-		MultiSigner::Sr25519(self.signer.public()).into_account().into()
+		extrinsic::signer_account::<C>(&self.signer).expect("Public key is checked on creation")
 	}
 
 	pub async fn set_latest_finalized_height(
@@ -184,6 +204,7 @@ impl<C: subxt::Config> Clone for SubstrateClient<C> {
 	fn clone(&self) -> Self {
 		Self {
 			client: self.client.clone(),
+			rpc_pool: self.rpc_pool.clone(),
 			consensus_state_id: self.consensus_state_id,
 			state_machine: self.state_machine,
 			hashing: self.hashing.clone(),
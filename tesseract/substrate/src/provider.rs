@@ -25,7 +25,9 @@ use ismp::{
 	consensus::{ConsensusClientId, StateCommitment, StateMachineHeight, StateMachineId},
 	events::{Event, StateCommitmentVetoed},
 	host::StateMachine,
-	messaging::{hash_request, hash_response, CreateConsensusState, Message, ResponseMessage},
+	messaging::{
+		hash_batch, hash_request, hash_response, CreateConsensusState, Message, ResponseMessage,
+	},
 	router::{Request, RequestResponse},
 };
 use pallet_ismp::{
@@ -37,9 +39,10 @@ use pallet_ismp::{
 use pallet_ismp_host_executive::HostParam;
 use pallet_ismp_relayer::withdrawal::Signature;
 use pallet_ismp_rpc::BlockNumberOrHash;
+use rpc_utils::EndpointStatus;
 use subxt::ext::sp_core::{
 	storage::{ChildInfo, StorageData, StorageKey},
-	Pair, H160, H256, U256,
+	H160, H256, U256,
 };
 
 use substrate_state_machine::{StateMachineProof, SubstrateStateProof};
@@ -47,18 +50,14 @@ use subxt::{
 	config::{
 		extrinsic_params::BaseExtrinsicParamsBuilder, polkadot::PlainTip, ExtrinsicParams, Header,
 	},
-	ext::{
-		sp_core::crypto::AccountId32,
-		sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner},
-	},
+	ext::{sp_core::crypto::AccountId32, sp_runtime::MultiSignature},
 	rpc::types::DryRunResult,
 	rpc_params,
 	tx::TxPayload,
 };
 
 use subxt_utils::{
-	fisherman_storage_key, host_params_storage_key, send_extrinsic,
-	state_machine_update_time_storage_key,
+	fisherman_storage_key, host_params_storage_key, state_machine_update_time_storage_key,
 };
 use tesseract_primitives::{
 	wait_for_challenge_period, BoxStream, EstimateGasReturnParams, Hasher, IsmpProvider, Query,
@@ -67,10 +66,14 @@ use tesseract_primitives::{
 
 use crate::{
 	calls::RequestMetadata,
-	extrinsic::{send_unsigned_extrinsic, system_dry_run_unsigned, Extrinsic, InMemorySigner},
+	extrinsic::{send_extrinsic, send_unsigned_extrinsic, system_dry_run_unsigned, Extrinsic},
 	SubstrateClient,
 };
 
+/// Calls of the ismp pallet that handle a batch of messages
+const ISMP_HANDLE_CALLS: [&str; 4] =
+	["handle_unsigned", "handle", "handle_unsigned_partial", "handle_partial"];
+
 #[async_trait::async_trait]
 impl<C> IsmpProvider for SubstrateClient<C>
 where
@@ -290,6 +293,51 @@ where
 		Ok(events)
 	}
 
+	async fn query_consensus_updates(
+		&self,
+		consensus_state_id: ConsensusClientId,
+		from: u64,
+		to: u64,
+	) -> Result<Vec<Vec<u8>>, anyhow::Error> {
+		let mut proofs = vec![];
+		for number in from..=to {
+			let hash = self
+				.client
+				.rpc()
+				.block_hash(Some((number as u32).into()))
+				.await?
+				.ok_or_else(|| anyhow!("Block {number} not found"))?;
+			let block = self.client.blocks().at(hash).await?;
+			for extrinsic in block.extrinsics().await?.iter() {
+				let extrinsic = extrinsic?;
+				if extrinsic.pallet_name()? != "Ismp" ||
+					!ISMP_HANDLE_CALLS.contains(&extrinsic.variant_name()?)
+				{
+					continue;
+				}
+				// The messages of failed extrinsics were reverted
+				let failed = extrinsic.events().await?.iter().any(|event| {
+					event.is_ok_and(|event| {
+						event.pallet_name() == "System" && event.variant_name() == "ExtrinsicFailed"
+					})
+				});
+				if failed {
+					continue;
+				}
+
+				let messages = Vec::<Message>::decode(&mut &extrinsic.field_bytes()[..])?;
+				proofs.extend(messages.into_iter().filter_map(|message| match message {
+					Message::Consensus(message)
+						if message.consensus_state_id == consensus_state_id =>
+						Some(message.consensus_proof),
+					_ => None,
+				}));
+			}
+		}
+
+		Ok(proofs)
+	}
+
 	fn name(&self) -> String {
 		format!("{:?}", self.state_machine)
 	}
@@ -327,10 +375,12 @@ where
 						match result {
 							DryRunResult::Success => Ok::<_, Error>(EstimateGasReturnParams {
 								execution_cost: Default::default(),
+								gas: Default::default(),
 								successful_execution: true,
 							}),
 							_ => Ok(EstimateGasReturnParams {
 								execution_cost: Default::default(),
+								gas: Default::default(),
 								successful_execution: false,
 							}),
 						}
@@ -649,15 +699,19 @@ where
 
 	async fn submit(&self, messages: Vec<Message>) -> Result<Vec<TxReceipt>, anyhow::Error> {
 		let mut futs = vec![];
-		for msg in messages.clone() {
-			let is_consensus_message = matches!(&msg, Message::Consensus(_));
-			let call = vec![msg].encode();
-			let extrinsic = Extrinsic::new("Ismp", "handle_unsigned", call);
-			// We don't compress consensus messages
-			if is_consensus_message {
-				futs.push(send_unsigned_extrinsic(&self.client, extrinsic, false));
-				continue;
-			}
+		// Consensus messages are submitted on their own so that they are applied before the
+		// messages proven against them. We don't compress consensus messages.
+		let (consensus_messages, messages): (Vec<_>, Vec<_>) =
+			messages.into_iter().partition(|msg| matches!(msg, Message::Consensus(_)));
+		for msg in consensus_messages {
+			let extrinsic = Extrinsic::new("Ismp", "handle_unsigned", vec![msg].encode());
+			futs.push(send_unsigned_extrinsic(&self.client, extrinsic, false));
+		}
+		// The remaining messages are handled independently of each other in a single extrinsic,
+		// a failing message does not revert the others and is reported by its missing receipt.
+		if !messages.is_empty() {
+			let extrinsic =
+				Extrinsic::new("Ismp", "handle_unsigned_partial", messages.clone().encode());
 			let encoded_call = extrinsic.encode_call_data(&self.client.metadata())?;
 			let uncompressed_len = encoded_call.len();
 			let max_compressed_size = zstd_safe::compress_bound(uncompressed_len);
@@ -678,15 +732,22 @@ where
 				futs.push(send_unsigned_extrinsic(&self.client, extrinsic, false))
 			}
 		}
-		let results = futures::future::join_all(futs)
-			.await
-			.into_iter()
-			.collect::<Result<Vec<_>, _>>()?;
-		let receipts = results
-			.into_iter()
-			.filter_map(|val| val.map(|(_, receipts)| receipts))
-			.flatten()
-			.collect::<Vec<_>>();
+		let results = futures::future::join_all(futs).await;
+		let total = results.len();
+		let mut failed = 0;
+		let mut receipts = vec![];
+		for result in results {
+			match result {
+				Ok(val) => receipts.extend(val.map(|(_, receipts)| receipts).unwrap_or_default()),
+				Err(err) => {
+					failed += 1;
+					log::error!(target: "tesseract", "Failed to submit messages to {}: {err:?}", self.name());
+				},
+			}
+		}
+		if total > 0 && failed == total {
+			Err(anyhow!("All {total} extrinsics submitted to {} failed", self.name()))?
+		}
 
 		let mut results = vec![];
 		let height = {
@@ -698,6 +759,7 @@ where
 				.ok_or_else(|| anyhow!("Failed to get latest height"))?;
 			block.number().into()
 		};
+		// Messages are submitted in unsigned extrinsics, so their delivery costs no gas
 		for msg in messages {
 			match msg {
 				Message::Request(req_msg) =>
@@ -713,6 +775,7 @@ where
 									commitment,
 								},
 								height,
+								gas: Default::default(),
 							};
 
 							results.push(tx_receipt);
@@ -735,6 +798,25 @@ where
 								},
 								request_commitment,
 								height,
+								gas: Default::default(),
+							};
+
+							results.push(tx_receipt);
+						}
+					},
+				Message::Batch(batch_msg) =>
+					for batch in batch_msg.batches {
+						let commitment = hash_batch::<Hasher>(&batch);
+						if receipts.contains(&commitment) {
+							let tx_receipt = TxReceipt::Request {
+								query: Query {
+									source_chain: batch.source,
+									dest_chain: batch.dest,
+									nonce: batch.nonce,
+									commitment,
+								},
+								height,
+								gas: Default::default(),
 							};
 
 							results.push(tx_receipt);
@@ -787,9 +869,9 @@ where
 		self.address.clone()
 	}
 
-	fn sign(&self, msg: &[u8]) -> tesseract_primitives::Signature {
-		let signature = self.signer.sign(msg).0.to_vec();
-		Signature::Sr25519 { public_key: self.address.clone(), signature }
+	async fn sign(&self, msg: &[u8]) -> Result<tesseract_primitives::Signature, Error> {
+		let signature = self.signer.sign(msg).await?;
+		Ok(Signature::Sr25519 { public_key: self.address.clone(), signature })
 	}
 
 	async fn set_latest_finalized_height(
@@ -832,14 +914,9 @@ where
 			return Ok(())
 		}
 
-		let signer = InMemorySigner {
-			account_id: MultiSigner::Sr25519(self.signer.public()).into_account().into(),
-			signer: self.signer.clone(),
-		};
-
 		let call = height.encode();
 		let call = Extrinsic::new("Fishermen", "veto_state_commitment", call);
-		send_extrinsic(&self.client, signer, call, Some(PlainTip::new(100))).await?;
+		send_extrinsic(&self.client, &self.signer, call, Some(PlainTip::new(100))).await?;
 		Ok(())
 	}
 
@@ -864,6 +941,10 @@ where
 	fn max_concurrent_queries(&self) -> usize {
 		self.max_concurent_queries.unwrap_or(10) as usize
 	}
+
+	fn rpc_endpoints(&self) -> Vec<EndpointStatus> {
+		self.rpc_pool.status()
+	}
 }
 
 // The storage key needed to access events.
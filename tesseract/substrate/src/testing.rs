@@ -16,7 +16,7 @@
 //! Testing utilities
 
 use crate::{
-	extrinsic::{Extrinsic, InMemorySigner},
+	extrinsic::{send_extrinsic, Extrinsic},
 	SubstrateClient,
 };
 use codec::Encode;
@@ -32,8 +32,6 @@ use subxt::{
 	tx::TxPayload,
 };
 
-use subxt_utils::send_extrinsic;
-
 impl<C> SubstrateClient<C>
 where
 	C: subxt::Config + Send + Sync + Clone,
@@ -56,16 +54,14 @@ where
 		let call = params.encode();
 		let tx = Extrinsic::new("IsmpDemo", "transfer", call);
 
-		let signer = InMemorySigner::new(self.signer());
-		let tx_block_hash = send_extrinsic(&self.client, signer, tx, None).await?;
+		let tx_block_hash = send_extrinsic(&self.client, &self.signer, tx, None).await?;
 		Ok(tx_block_hash)
 	}
 
 	pub async fn dispatch_to_evm(&self, params: EvmParams) -> Result<(), anyhow::Error> {
 		let call = params.encode();
 		let tx = Extrinsic::new("IsmpDemo", "dispatch_to_evm", call);
-		let signer = InMemorySigner::new(self.signer());
-		send_extrinsic(&self.client, signer, tx, None).await?;
+		send_extrinsic(&self.client, &self.signer, tx, None).await?;
 
 		Ok(())
 	}
@@ -73,8 +69,7 @@ where
 	pub async fn get_request(&self, get_req: GetRequest) -> Result<C::Hash, anyhow::Error> {
 		let call = get_req.encode();
 		let tx = Extrinsic::new("IsmpDemo", "get_request", call);
-		let signer = InMemorySigner::new(self.signer());
-		let tx_block_hash = send_extrinsic(&self.client, signer, tx, None).await?;
+		let tx_block_hash = send_extrinsic(&self.client, &self.signer, tx, None).await?;
 
 		Ok(tx_block_hash)
 	}
@@ -127,8 +122,7 @@ where
 		let encoded_call = Extrinsic::new("System", "set_code", code_blob.encode())
 			.encode_call_data(&self.client.metadata())?;
 		let tx = Extrinsic::new("Sudo", "sudo", encoded_call);
-		let signer = InMemorySigner::new(self.signer());
-		send_extrinsic(&self.client, signer, tx, None).await?;
+		send_extrinsic(&self.client, &self.signer, tx, None).await?;
 
 		Ok(())
 	}
@@ -138,8 +132,7 @@ where
 			Extrinsic::new("CollatorSelection", "set_invulnerables", accounts.encode())
 				.encode_call_data(&self.client.metadata())?;
 		let tx = Extrinsic::new("Sudo", "sudo", encoded_call);
-		let signer = InMemorySigner::new(self.signer());
-		send_extrinsic(&self.client, signer, tx, None).await?;
+		send_extrinsic(&self.client, &self.signer, tx, None).await?;
 
 		Ok(())
 	}